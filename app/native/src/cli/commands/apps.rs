@@ -0,0 +1,91 @@
+//! Apps CLI commands.
+//!
+//! This module contains the apps subcommands for discovering installed
+//! applications and their bundle ids, which can be passed to `open_app` and
+//! used in rules. The application folders are read directly, so the app
+//! doesn't need to be running.
+
+use clap::Subcommand;
+use colored::Colorize;
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::cli::output;
+use crate::error::StacheError;
+use crate::modules::bar::components::apps::{self, InstalledApp};
+
+/// Apps subcommands.
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum AppsCommands {
+    /// List the applications in /Applications and ~/Applications.
+    ///
+    /// Shows each application's name, bundle id and path.
+    List,
+}
+
+/// Execute apps subcommands.
+pub fn execute(cmd: AppsCommands, json: bool) -> Result<(), StacheError> {
+    match cmd {
+        AppsCommands::List => {
+            let installed = apps::list_installed_apps();
+            if json {
+                let value = serde_json::to_value(&installed).map_err(|e| {
+                    StacheError::CommandError(format!("JSON serialization error: {e}"))
+                })?;
+                output::print_highlighted_json(&value);
+            } else {
+                print_apps_table(&installed);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn print_apps_table(installed: &[InstalledApp]) {
+    #[derive(Tabled)]
+    struct AppRow {
+        #[tabled(rename = "Name")]
+        name: String,
+        #[tabled(rename = "Bundle ID")]
+        bundle_id: String,
+        #[tabled(rename = "Path")]
+        path: String,
+    }
+
+    if installed.is_empty() {
+        println!("{}", "No applications found.".dimmed());
+        return;
+    }
+
+    let rows = installed.iter().map(|app| AppRow {
+        name: app.name.clone(),
+        bundle_id: app.bundle_id.clone().unwrap_or_else(|| "-".to_string()),
+        path: app.path.clone(),
+    });
+
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    println!("{}", format!("Applications ({})", installed.len()).bold());
+    println!("{table}");
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: AppsCommands,
+    }
+
+    #[test]
+    fn test_apps_commands_parse() {
+        let cli = TestCli::try_parse_from(["test", "list"]).unwrap();
+        assert_eq!(cli.command, AppsCommands::List);
+
+        assert!(TestCli::try_parse_from(["test", "open"]).is_err());
+    }
+}
@@ -1,11 +1,16 @@
 //! Audio CLI commands.
 //!
-//! This module contains the audio subcommands for managing audio devices.
+//! This module contains the audio subcommands for managing audio devices,
+//! switching the default devices and the volume of the default output device.
+//! `audio status` asks the running app whether automatic switching is paused
+//! by quiet hours.
 
 use clap::Subcommand;
 
 use crate::audio;
+use crate::cli::output;
 use crate::error::StacheError;
+use crate::utils::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
 
 /// Audio subcommands for listing and inspecting audio devices.
 #[derive(Subcommand, Debug)]
@@ -13,7 +18,8 @@ use crate::error::StacheError;
 pub enum AudioCommands {
     /// List all audio devices on the system.
     ///
-    /// Shows audio input and output devices with their names and types.
+    /// Shows audio input and output devices with their IDs, names and types,
+    /// and marks the default input and output devices.
     /// By default, displays a human-readable table format.
     #[command(after_long_help = r#"Examples:
   stache audio list              # List all devices in table format
@@ -22,10 +28,6 @@ pub enum AudioCommands {
   stache audio list --output     # List only output devices
   stache audio list -io --json   # List all devices in JSON (explicit)"#)]
     List {
-        /// Output in JSON format instead of table format.
-        #[arg(long, short = 'j')]
-        json: bool,
-
         /// Show only input devices.
         #[arg(long, short = 'i')]
         input: bool,
@@ -34,12 +36,128 @@ pub enum AudioCommands {
         #[arg(long, short = 'o')]
         output: bool,
     },
+
+    /// Switch the default output device.
+    ///
+    /// With `proxyAudio` enabled, the next device change may switch back to
+    /// the highest priority device.
+    #[command(after_long_help = r#"Examples:
+  stache audio output set airpods      # Switch to the device named like "airpods"
+  stache audio output set "AirPods"    # An exact name wins over longer matches"#)]
+    Output {
+        #[command(subcommand)]
+        action: DeviceCommands,
+    },
+
+    /// Switch the default input device.
+    ///
+    /// With `proxyAudio` enabled, the next device change may switch back to
+    /// the highest priority device.
+    #[command(after_long_help = r#"Examples:
+  stache audio input set minifuse      # Switch to the device named like "minifuse""#)]
+    Input {
+        #[command(subcommand)]
+        action: DeviceCommands,
+    },
+
+    /// Change the volume of the default output device.
+    ///
+    /// Prints the resulting volume and mute state. Values are percentages and
+    /// are clamped to 0 - 100.
+    #[command(after_long_help = r#"Examples:
+  stache audio volume set 40     # Set the volume to 40%
+  stache audio volume up         # Raise the volume by 5%
+  stache audio volume down 10    # Lower the volume by 10%"#)]
+    Volume {
+        #[command(subcommand)]
+        action: VolumeCommands,
+    },
+
+    /// Change the mute state of the default output device.
+    ///
+    /// Prints the resulting volume and mute state.
+    Mute {
+        #[command(subcommand)]
+        action: MuteCommands,
+    },
+
+    /// Show whether automatic device switching is running.
+    ///
+    /// Also reports the configured `proxyAudio.quietHours` and whether they
+    /// are currently keeping devices from being switched. Requires the app to
+    /// be running.
+    Status,
 }
 
+/// Default device subcommands.
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum DeviceCommands {
+    /// Make a device the default.
+    ///
+    /// Fails, listing the candidates, when no device or several devices
+    /// match the name.
+    Set {
+        /// Case-insensitive substring of the device name.
+        name: String,
+    },
+}
+
+/// Volume subcommands.
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum VolumeCommands {
+    /// Set the volume to an absolute value (0 - 100).
+    Set {
+        /// Volume in percent.
+        #[arg(allow_negative_numbers = true)]
+        value: i32,
+    },
+
+    /// Raise the volume by a step.
+    Up {
+        /// Step in percent.
+        #[arg(default_value_t = DEFAULT_VOLUME_STEP, allow_negative_numbers = true)]
+        step: i32,
+    },
+
+    /// Lower the volume by a step.
+    Down {
+        /// Step in percent.
+        #[arg(default_value_t = DEFAULT_VOLUME_STEP, allow_negative_numbers = true)]
+        step: i32,
+    },
+}
+
+impl VolumeCommands {
+    /// Returns the volume change for this subcommand.
+    #[must_use]
+    pub const fn change(self) -> audio::VolumeChange {
+        match self {
+            Self::Set { value } => audio::VolumeChange::Set(value),
+            Self::Up { step } => audio::VolumeChange::Adjust(step),
+            Self::Down { step } => audio::VolumeChange::Adjust(step.saturating_neg()),
+        }
+    }
+}
+
+/// Mute subcommands.
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum MuteCommands {
+    /// Mute the device if it's unmuted, unmute it otherwise.
+    Toggle,
+}
+
+/// Default step for `audio volume up` and `audio volume down`, in percent.
+const DEFAULT_VOLUME_STEP: i32 = 5;
+
 /// Execute audio subcommands.
-pub fn execute(cmd: &AudioCommands) -> Result<(), StacheError> {
+///
+/// `json` selects JSON output instead of a table.
+pub fn execute(cmd: &AudioCommands, json: bool) -> Result<(), StacheError> {
     match cmd {
-        AudioCommands::List { json, input, output } => {
+        AudioCommands::List { input, output } => {
             let filter = match (input, output) {
                 (true, false) => audio::DeviceFilter::InputOnly,
                 (false, true) => audio::DeviceFilter::OutputOnly,
@@ -48,7 +166,7 @@ pub fn execute(cmd: &AudioCommands) -> Result<(), StacheError> {
 
             let devices = audio::list_devices(filter);
 
-            if *json {
+            if json {
                 let json_output = serde_json::to_string_pretty(&devices).map_err(|e| {
                     StacheError::AudioError(format!("JSON serialization error: {e}"))
                 })?;
@@ -58,6 +176,97 @@ pub fn execute(cmd: &AudioCommands) -> Result<(), StacheError> {
                 println!("{table}");
             }
         }
+        AudioCommands::Output {
+            action: DeviceCommands::Set { name },
+        } => {
+            set_default_device(audio::DeviceDirection::Output, name, json)?;
+        }
+        AudioCommands::Input {
+            action: DeviceCommands::Set { name },
+        } => {
+            set_default_device(audio::DeviceDirection::Input, name, json)?;
+        }
+        AudioCommands::Volume { action } => {
+            let state = audio::change_volume(action.change())
+                .map_err(|e| StacheError::AudioError(e.to_string()))?;
+            print_volume_state(&state, json)?;
+        }
+        AudioCommands::Mute { action: MuteCommands::Toggle } => {
+            let state = audio::toggle_mute().map_err(|e| StacheError::AudioError(e.to_string()))?;
+            print_volume_state(&state, json)?;
+        }
+        AudioCommands::Status => print_status(json)?,
+    }
+    Ok(())
+}
+
+/// Asks the running app for the automatic switching state and prints it.
+fn print_status(json: bool) -> Result<(), StacheError> {
+    let data = match ipc_socket::send_query(IpcQuery::AudioStatus) {
+        Ok(IpcResponse::Success { data }) => data,
+        Ok(IpcResponse::Error { error }) => return Err(StacheError::AudioError(error)),
+        Err(IpcError::AppNotRunning) => {
+            return Err(StacheError::IpcError("Stache app is not running".to_string()));
+        }
+        Err(e) => return Err(StacheError::IpcError(e.to_string())),
+    };
+
+    if json {
+        output::print_highlighted_json(&data);
+        return Ok(());
+    }
+
+    let auto_switching = data["autoSwitching"].as_bool().unwrap_or_default();
+    println!(
+        "Automatic switching: {}",
+        if auto_switching { "on" } else { "off" }
+    );
+
+    match (
+        data["quietHours"]["from"].as_str(),
+        data["quietHours"]["to"].as_str(),
+    ) {
+        (Some(from), Some(to)) => {
+            let state = if data["quietHoursActive"].as_bool().unwrap_or_default() {
+                "active, devices are not switched"
+            } else {
+                "inactive"
+            };
+            println!("Quiet hours: {from} - {to} ({state})");
+        }
+        _ => println!("Quiet hours: not configured"),
+    }
+    Ok(())
+}
+
+/// Switches the default device and prints it, as JSON or a single line.
+fn set_default_device(
+    direction: audio::DeviceDirection,
+    name: &str,
+    json: bool,
+) -> Result<(), StacheError> {
+    let device = audio::set_default_device(direction, name)
+        .map_err(|e| StacheError::AudioError(e.to_string()))?;
+
+    if json {
+        let json_output = serde_json::to_string_pretty(&device)
+            .map_err(|e| StacheError::AudioError(format!("JSON serialization error: {e}")))?;
+        println!("{json_output}");
+    } else {
+        println!("Default {}: {}", direction.as_str(), device.name);
+    }
+    Ok(())
+}
+
+/// Prints the volume and mute state, as JSON or a single line.
+fn print_volume_state(state: &audio::VolumeState, json: bool) -> Result<(), StacheError> {
+    if json {
+        let json_output = serde_json::to_string_pretty(state)
+            .map_err(|e| StacheError::AudioError(format!("JSON serialization error: {e}")))?;
+        println!("{json_output}");
+    } else {
+        let muted = if state.muted { " (muted)" } else { "" };
+        println!("{}: {}%{muted}", state.device, state.volume);
     }
     Ok(())
 }
@@ -77,44 +286,101 @@ mod tests {
     #[test]
     fn test_audio_list_parse() {
         let cli = TestCli::try_parse_from(["test", "list"]).unwrap();
-        match cli.command {
-            AudioCommands::List { json, input, output } => {
-                assert!(!json);
-                assert!(!input);
-                assert!(!output);
-            }
-        }
-    }
-
-    #[test]
-    fn test_audio_list_json_parse() {
-        let cli = TestCli::try_parse_from(["test", "list", "--json"]).unwrap();
-        match cli.command {
-            AudioCommands::List { json, .. } => {
-                assert!(json);
-            }
-        }
+        let AudioCommands::List { input, output } = cli.command else {
+            panic!("expected list command");
+        };
+        assert!(!input);
+        assert!(!output);
     }
 
     #[test]
     fn test_audio_list_input_parse() {
         let cli = TestCli::try_parse_from(["test", "list", "--input"]).unwrap();
-        match cli.command {
-            AudioCommands::List { input, output, .. } => {
-                assert!(input);
-                assert!(!output);
-            }
-        }
+        let AudioCommands::List { input, output } = cli.command else {
+            panic!("expected list command");
+        };
+        assert!(input);
+        assert!(!output);
     }
 
     #[test]
     fn test_audio_list_output_parse() {
         let cli = TestCli::try_parse_from(["test", "list", "--output"]).unwrap();
-        match cli.command {
-            AudioCommands::List { input, output, .. } => {
-                assert!(!input);
-                assert!(output);
-            }
-        }
+        let AudioCommands::List { input, output } = cli.command else {
+            panic!("expected list command");
+        };
+        assert!(!input);
+        assert!(output);
+    }
+
+    #[test]
+    fn test_audio_device_set_parse() {
+        let cli = TestCli::try_parse_from(["test", "output", "set", "AirPods"]).unwrap();
+        let AudioCommands::Output { action } = cli.command else {
+            panic!("expected output command");
+        };
+        assert_eq!(action, DeviceCommands::Set { name: "AirPods".to_string() });
+
+        let cli = TestCli::try_parse_from(["test", "input", "set", "minifuse"]).unwrap();
+        let AudioCommands::Input { action } = cli.command else {
+            panic!("expected input command");
+        };
+        assert_eq!(action, DeviceCommands::Set { name: "minifuse".to_string() });
+
+        assert!(TestCli::try_parse_from(["test", "output", "set"]).is_err());
+    }
+
+    #[test]
+    fn test_audio_volume_parse() {
+        let cli = TestCli::try_parse_from(["test", "volume", "set", "40"]).unwrap();
+        let AudioCommands::Volume { action } = cli.command else {
+            panic!("expected volume command");
+        };
+        assert_eq!(action, VolumeCommands::Set { value: 40 });
+        assert_eq!(action.change(), audio::VolumeChange::Set(40));
+
+        let cli = TestCli::try_parse_from(["test", "volume", "up"]).unwrap();
+        let AudioCommands::Volume { action } = cli.command else {
+            panic!("expected volume command");
+        };
+        assert_eq!(action.change(), audio::VolumeChange::Adjust(5));
+
+        let cli = TestCli::try_parse_from(["test", "volume", "down", "10"]).unwrap();
+        let AudioCommands::Volume { action } = cli.command else {
+            panic!("expected volume command");
+        };
+        assert_eq!(action.change(), audio::VolumeChange::Adjust(-10));
+    }
+
+    #[test]
+    fn test_audio_volume_accepts_out_of_range_values() {
+        // Clamped when applied rather than rejected
+        let cli = TestCli::try_parse_from(["test", "volume", "set", "-20"]).unwrap();
+        let AudioCommands::Volume { action } = cli.command else {
+            panic!("expected volume command");
+        };
+        assert_eq!(action.change().apply(50), 0);
+
+        assert!(TestCli::try_parse_from(["test", "volume", "set"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "volume", "set", "loud"]).is_err());
+    }
+
+    #[test]
+    fn test_audio_mute_parse() {
+        let cli = TestCli::try_parse_from(["test", "mute", "toggle"]).unwrap();
+        let AudioCommands::Mute { action } = cli.command else {
+            panic!("expected mute command");
+        };
+        assert_eq!(action, MuteCommands::Toggle);
+
+        assert!(TestCli::try_parse_from(["test", "mute"]).is_err());
+    }
+
+    #[test]
+    fn test_audio_status_parse() {
+        let cli = TestCli::try_parse_from(["test", "status"]).unwrap();
+        assert!(matches!(cli.command, AudioCommands::Status));
+
+        assert!(TestCli::try_parse_from(["test", "status", "now"]).is_err());
     }
 }
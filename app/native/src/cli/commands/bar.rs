@@ -0,0 +1,89 @@
+//! Bar CLI commands.
+//!
+//! This module contains the bar subcommands for showing and hiding the status
+//! bar at runtime. The request is sent to the running app over IPC, which
+//! reports whether the bar is visible afterwards.
+
+use clap::Subcommand;
+
+use crate::cli::output;
+use crate::error::StacheError;
+use crate::utils::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
+
+/// Bar subcommands.
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum BarCommands {
+    /// Show the status bar.
+    Show,
+
+    /// Hide the status bar until it is shown again.
+    Hide,
+
+    /// Show the status bar if it is hidden, or hide it otherwise.
+    Toggle,
+}
+
+impl BarCommands {
+    /// Returns the action as sent over IPC.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Show => "show",
+            Self::Hide => "hide",
+            Self::Toggle => "toggle",
+        }
+    }
+}
+
+/// Execute bar subcommands.
+pub fn execute(cmd: BarCommands, json: bool) -> Result<(), StacheError> {
+    let query = IpcQuery::Bar {
+        action: cmd.as_str().to_string(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            if json {
+                output::print_highlighted_json(&data);
+            } else if data["visible"].as_bool().unwrap_or_default() {
+                println!("The bar is visible");
+            } else {
+                println!("The bar is hidden");
+            }
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::CommandError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: BarCommands,
+    }
+
+    #[test]
+    fn test_bar_commands_parse() {
+        let cli = TestCli::try_parse_from(["test", "show"]).unwrap();
+        assert_eq!(cli.command, BarCommands::Show);
+
+        let cli = TestCli::try_parse_from(["test", "hide"]).unwrap();
+        assert_eq!(cli.command, BarCommands::Hide);
+
+        let cli = TestCli::try_parse_from(["test", "toggle"]).unwrap();
+        assert_eq!(cli.command, BarCommands::Toggle);
+
+        assert!(TestCli::try_parse_from(["test", "flip"]).is_err());
+    }
+}
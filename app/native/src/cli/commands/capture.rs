@@ -0,0 +1,93 @@
+//! Capture CLI commands.
+//!
+//! This module contains the capture subcommands for saving the focused window
+//! or workspace as a PNG file. The capture is taken by the running app, which
+//! reports the written path over IPC.
+
+use clap::Subcommand;
+
+use crate::cli::output;
+use crate::error::StacheError;
+use crate::utils::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
+
+/// Capture subcommands.
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum CaptureCommands {
+    /// Capture the focused window.
+    ///
+    /// Saves the focused window's frame as a PNG file in `capture.outputDir`.
+    Window,
+
+    /// Capture the screen of the focused workspace.
+    ///
+    /// Saves the full screen the focused workspace is on as a PNG file in
+    /// `capture.outputDir`.
+    Workspace,
+}
+
+impl CaptureCommands {
+    /// Returns the capture target as sent over IPC.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Window => "window",
+            Self::Workspace => "workspace",
+        }
+    }
+}
+
+/// Execute capture subcommands.
+///
+/// Prints the path of the written PNG file.
+pub fn execute(cmd: CaptureCommands, json: bool) -> Result<(), StacheError> {
+    let query = IpcQuery::Capture {
+        target: cmd.as_str().to_string(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            if json {
+                output::print_highlighted_json(&data);
+            } else {
+                println!("{}", data["path"].as_str().unwrap_or_default());
+            }
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::CaptureError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: CaptureCommands,
+    }
+
+    #[test]
+    fn test_capture_commands_parse() {
+        let cli = TestCli::try_parse_from(["test", "window"]).unwrap();
+        assert_eq!(cli.command, CaptureCommands::Window);
+
+        let cli = TestCli::try_parse_from(["test", "workspace"]).unwrap();
+        assert_eq!(cli.command, CaptureCommands::Workspace);
+
+        assert!(TestCli::try_parse_from(["test", "screen"]).is_err());
+    }
+
+    #[test]
+    fn test_capture_commands_as_str() {
+        assert_eq!(CaptureCommands::Window.as_str(), "window");
+        assert_eq!(CaptureCommands::Workspace.as_str(), "workspace");
+    }
+}
@@ -0,0 +1,92 @@
+//! Focus mode CLI commands.
+//!
+//! This module contains the focus mode subcommands for turning the
+//! do-not-disturb style focus mode on and off. The request is sent to the
+//! running app over IPC, which reports the resulting state.
+
+use clap::Subcommand;
+
+use crate::cli::output;
+use crate::error::StacheError;
+use crate::utils::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
+
+/// Focus mode subcommands.
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum FocusModeCommands {
+    /// Turn focus mode on.
+    ///
+    /// Hides the bar, switches the focused workspace to monocle and keeps the
+    /// system awake, as enabled in the `focusMode` config.
+    On,
+
+    /// Turn focus mode off, restoring what it changed.
+    Off,
+
+    /// Print whether focus mode is on.
+    Status,
+}
+
+impl FocusModeCommands {
+    /// Returns the action as sent over IPC.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::On => "on",
+            Self::Off => "off",
+            Self::Status => "status",
+        }
+    }
+}
+
+/// Execute focus mode subcommands.
+pub fn execute(cmd: FocusModeCommands, json: bool) -> Result<(), StacheError> {
+    let query = IpcQuery::FocusMode {
+        action: cmd.as_str().to_string(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            if json {
+                output::print_highlighted_json(&data);
+            } else if data["active"].as_bool().unwrap_or_default() {
+                println!("Focus mode is on");
+            } else {
+                println!("Focus mode is off");
+            }
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::CommandError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: FocusModeCommands,
+    }
+
+    #[test]
+    fn test_focus_mode_commands_parse() {
+        let cli = TestCli::try_parse_from(["test", "on"]).unwrap();
+        assert_eq!(cli.command, FocusModeCommands::On);
+
+        let cli = TestCli::try_parse_from(["test", "off"]).unwrap();
+        assert_eq!(cli.command, FocusModeCommands::Off);
+
+        let cli = TestCli::try_parse_from(["test", "status"]).unwrap();
+        assert_eq!(cli.command, FocusModeCommands::Status);
+
+        assert!(TestCli::try_parse_from(["test", "toggle"]).is_err());
+    }
+}
@@ -0,0 +1,57 @@
+//! Idle CLI commands.
+//!
+//! This module contains the idle subcommands for reading how long the user
+//! has been away from the keyboard and mouse. The idle time is read directly
+//! from the system, so the app doesn't need to be running.
+
+use clap::Subcommand;
+
+use crate::cli::output;
+use crate::error::StacheError;
+use crate::modules::idle;
+
+/// Idle subcommands.
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum IdleCommands {
+    /// Print the number of seconds since the last keyboard or mouse input.
+    ///
+    /// Prints whole seconds, or the exact value with --json.
+    Seconds,
+}
+
+/// Execute idle subcommands.
+pub fn execute(cmd: IdleCommands, json: bool) -> Result<(), StacheError> {
+    match cmd {
+        IdleCommands::Seconds => {
+            let seconds = idle::idle_seconds();
+            if json {
+                output::print_highlighted_json(&serde_json::json!({ "seconds": seconds }));
+            } else {
+                println!("{}", seconds.floor());
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: IdleCommands,
+    }
+
+    #[test]
+    fn test_idle_commands_parse() {
+        let cli = TestCli::try_parse_from(["test", "seconds"]).unwrap();
+        assert_eq!(cli.command, IdleCommands::Seconds);
+
+        assert!(TestCli::try_parse_from(["test", "minutes"]).is_err());
+    }
+}
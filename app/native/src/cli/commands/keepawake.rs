@@ -0,0 +1,71 @@
+//! Keep-awake CLI command.
+//!
+//! This module contains the command for keeping the system awake for a
+//! limited time. The request is sent to the running app over IPC.
+
+use crate::error::StacheError;
+use crate::utils::duration::parse_duration;
+use crate::utils::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
+
+/// Arguments for the keepawake command.
+#[derive(Debug, Clone, clap::Args)]
+pub struct KeepAwakeArgs {
+    /// How long to keep the system awake, e.g. `90m`, `2h` or `1h30m`.
+    #[arg(long = "for", value_name = "DURATION", value_parser = parse_duration_arg)]
+    pub duration: String,
+}
+
+/// Validates a duration argument, keeping it as typed.
+fn parse_duration_arg(value: &str) -> Result<String, String> {
+    parse_duration(value)
+        .map(|_| value.trim().to_string())
+        .ok_or_else(|| format!("invalid duration '{value}' (expected e.g. 90m, 2h or 1h30m)"))
+}
+
+/// Execute the keepawake command.
+pub fn execute(args: &KeepAwakeArgs) -> Result<(), StacheError> {
+    let query = IpcQuery::KeepAwake {
+        duration: args.duration.clone(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { .. }) => {
+            println!("Keeping the system awake for {}", args.duration);
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::CommandError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        args: KeepAwakeArgs,
+    }
+
+    #[test]
+    fn test_keepawake_args_parse() {
+        let cli = TestCli::try_parse_from(["test", "--for", "90m"]).unwrap();
+        assert_eq!(cli.args.duration, "90m");
+
+        let cli = TestCli::try_parse_from(["test", "--for", "1h30m"]).unwrap();
+        assert_eq!(cli.args.duration, "1h30m");
+    }
+
+    #[test]
+    fn test_keepawake_args_reject_invalid_duration() {
+        assert!(TestCli::try_parse_from(["test", "--for", "forever"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "--for", "90"]).is_err());
+        assert!(TestCli::try_parse_from(["test"]).is_err());
+    }
+}
@@ -0,0 +1,57 @@
+//! Media CLI commands.
+//!
+//! This module contains the media subcommands for controlling playback of the
+//! active media app. Commands are sent to the running app over IPC.
+
+use crate::cli::output;
+use crate::error::StacheError;
+pub use crate::modules::bar::components::media::MediaCommand;
+use crate::utils::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
+
+/// Execute media subcommands.
+///
+/// Prints the updated media info reported by the app.
+pub fn execute(cmd: MediaCommand) -> Result<(), StacheError> {
+    let query = IpcQuery::MediaControl {
+        command: cmd.as_str().to_string(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            output::print_highlighted_json(&data);
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::MediaError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: MediaCommand,
+    }
+
+    #[test]
+    fn test_media_commands_parse() {
+        let cli = TestCli::try_parse_from(["test", "play-pause"]).unwrap();
+        assert_eq!(cli.command, MediaCommand::PlayPause);
+
+        let cli = TestCli::try_parse_from(["test", "next"]).unwrap();
+        assert_eq!(cli.command, MediaCommand::Next);
+
+        let cli = TestCli::try_parse_from(["test", "previous"]).unwrap();
+        assert_eq!(cli.command, MediaCommand::Previous);
+
+        assert!(TestCli::try_parse_from(["test", "stop"]).is_err());
+    }
+}
@@ -3,8 +3,17 @@
 //! This module defines all CLI commands and their arguments, organized into
 //! domain-specific submodules:
 //!
+//! - `apps` - Installed application discovery commands
 //! - `audio` - Audio device management commands
+//! - `bar` - Status bar visibility commands
 //! - `cache` - Cache management commands
+//! - `capture` - Screen capture commands
+//! - `focus_mode` - Focus mode commands
+//! - `idle` - User idle time commands
+//! - `keepawake` - Timed keep-awake command
+//! - `media` - Media playback commands
+//! - `response` - Shared output for IPC query responses
+//! - `status` - App and subsystem status command
 //! - `tiling` - Tiling window manager commands
 //! - `types` - Shared types used across commands
 //! - `wallpaper` - Wallpaper management commands
@@ -18,17 +27,33 @@ use crate::error::StacheError;
 use crate::utils::ipc::{self, StacheNotification};
 use crate::{config, schema};
 
+pub mod apps;
 pub mod audio;
+pub mod bar;
 pub mod cache;
+pub mod capture;
 pub mod config_cmd;
+pub mod focus_mode;
+pub mod idle;
+pub mod keepawake;
+pub mod media;
+mod response;
+pub mod status;
 pub mod tiling;
 pub mod types;
 pub mod wallpaper;
 
 // Re-export commonly used types for convenience
+pub use apps::AppsCommands;
 pub use audio::AudioCommands;
+pub use bar::BarCommands;
 pub use cache::CacheCommands;
+pub use capture::CaptureCommands;
 pub use config_cmd::ConfigCommands;
+pub use focus_mode::FocusModeCommands;
+pub use idle::IdleCommands;
+pub use keepawake::KeepAwakeArgs;
+pub use media::MediaCommand;
 pub use tiling::TilingCommands;
 pub use wallpaper::WallpaperCommands;
 
@@ -48,6 +73,12 @@ pub struct Cli {
     #[arg(long, short, global = true, value_name = "PATH")]
     pub config: Option<String>,
 
+    /// Output in JSON format instead of human-readable tables.
+    ///
+    /// Query commands print the raw response from the app.
+    #[arg(long, short = 'j', global = true)]
+    pub json: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -68,10 +99,73 @@ pub enum Commands {
 
     /// Audio device management commands.
     ///
-    /// List and inspect audio devices on the system.
+    /// List and inspect audio devices on the system, and change the volume
+    /// of the default output device.
     #[command(subcommand)]
     Audio(AudioCommands),
 
+    /// Media playback commands.
+    ///
+    /// Control playback of the active media app.
+    #[command(subcommand)]
+    Media(MediaCommand),
+
+    /// Keep the system awake for a limited time.
+    ///
+    /// Keeps the system awake for the given duration, then lets it sleep
+    /// again. Running it again replaces the remaining time.
+    ///
+    /// Usage:
+    ///   stache keepawake --for 90m
+    Keepawake(KeepAwakeArgs),
+
+    /// Focus mode commands.
+    ///
+    /// A do-not-disturb style mode that hides the bar, switches the focused
+    /// workspace to monocle and keeps the system awake. Each action can be
+    /// turned off in the `focusMode` config.
+    ///
+    /// Usage:
+    ///   stache focus-mode on
+    ///   stache focus-mode off
+    #[command(subcommand)]
+    FocusMode(FocusModeCommands),
+
+    /// Status bar commands.
+    ///
+    /// Show or hide the status bar at runtime. A hidden bar stays hidden
+    /// until it is shown again.
+    ///
+    /// Usage:
+    ///   stache bar toggle
+    #[command(subcommand)]
+    Bar(BarCommands),
+
+    /// Screen capture commands.
+    ///
+    /// Save the focused window or workspace as a PNG file in
+    /// `capture.outputDir` and print its path. Requires the Screen Recording
+    /// permission.
+    #[command(subcommand)]
+    Capture(CaptureCommands),
+
+    /// User idle time commands.
+    ///
+    /// Read how long it has been since the last keyboard or mouse input, for
+    /// scripts that react to idle time. See also the `idle` config section.
+    #[command(subcommand)]
+    Idle(IdleCommands),
+
+    /// Installed application commands.
+    ///
+    /// List the applications in /Applications and ~/Applications with their
+    /// bundle ids, for use in rules and with the bar's app launcher.
+    ///
+    /// Usage:
+    ///   stache apps list
+    #[command(subcommand)]
+    Apps(AppsCommands),
+
     /// Tiling window manager commands.
     ///
     /// Manage windows, workspaces, and query tiling state.
@@ -84,10 +178,18 @@ pub enum Commands {
     #[command(subcommand)]
     Config(ConfigCommands),
 
+    /// Show the app version and which subsystems are running.
+    ///
+    /// Reports whether the accessibility permission is granted and whether
+    /// tiling, the audio watcher, noTunes and the wallpaper manager are
+    /// running. Useful to diagnose a broken install.
+    Status,
+
     /// Reload Stache configuration.
     ///
     /// Reloads the configuration file and applies changes without restarting
-    /// the application.
+    /// the application. Fails without reloading if the configuration file
+    /// can't be loaded, e.g. when strict mode finds unknown keys.
     Reload,
 
     /// Output Stache configuration JSON Schema.
@@ -102,14 +204,31 @@ pub enum Commands {
     /// Outputs shell completion script to stdout for the specified shell.
     /// Can be used with eval or redirected to a file.
     ///
+    /// Completions are generated from this command tree, so they always cover
+    /// every subcommand and flag.
+    ///
     /// Usage:
-    ///   eval "$(stache completions --shell zsh)"
-    ///   stache completions --shell bash > ~/.local/share/bash-completion/completions/stache
-    ///   stache completions --shell fish > ~/.config/fish/completions/stache.fish
+    ///   eval "$(stache completions zsh)"
+    ///   stache completions bash > ~/.local/share/bash-completion/completions/stache
+    ///   stache completions fish > ~/.config/fish/completions/stache.fish
     Completions {
         /// The shell to generate completions for.
-        #[arg(long, short, value_enum)]
-        shell: Shell,
+        #[arg(
+            value_enum,
+            required_unless_present = "shell_flag",
+            conflicts_with = "shell_flag"
+        )]
+        shell: Option<Shell>,
+
+        /// The shell to generate completions for (same as the positional argument).
+        #[arg(
+            long = "shell",
+            short = 's',
+            id = "shell_flag",
+            value_name = "SHELL",
+            value_enum
+        )]
+        shell_flag: Option<Shell>,
     },
 
     /// Launch the desktop application.
@@ -147,11 +266,25 @@ impl Cli {
         match &self.command {
             Commands::Wallpaper(cmd) => wallpaper::execute(cmd),
             Commands::Cache(cmd) => cache::execute(cmd),
-            Commands::Audio(cmd) => audio::execute(cmd),
-            Commands::Tiling(cmd) => tiling::execute(cmd),
+            Commands::Audio(cmd) => audio::execute(cmd, self.json),
+            Commands::Media(cmd) => media::execute(*cmd),
+            Commands::Keepawake(args) => keepawake::execute(args),
+            Commands::FocusMode(cmd) => focus_mode::execute(*cmd, self.json),
+            Commands::Bar(cmd) => bar::execute(*cmd, self.json),
+            Commands::Capture(cmd) => capture::execute(*cmd, self.json),
+            Commands::Idle(cmd) => idle::execute(*cmd, self.json),
+            Commands::Apps(cmd) => apps::execute(*cmd, self.json),
+            Commands::Tiling(cmd) => tiling::execute(cmd, self.json),
             Commands::Config(cmd) => config_cmd::execute(cmd),
+            Commands::Status => status::execute(self.json),
 
             Commands::Reload => {
+                // The app falls back to defaults on a bad config, so report it here
+                match config::check_config_file() {
+                    Ok(_) | Err(config::ConfigError::NotFound) => {}
+                    Err(err) => return Err(StacheError::ConfigError(err.to_string())),
+                }
+
                 if !ipc::send_notification(&StacheNotification::Reload) {
                     return Err(StacheError::IpcError(
                         "Failed to send reload notification to Stache app".to_string(),
@@ -166,8 +299,11 @@ impl Cli {
                 Ok(())
             }
 
-            Commands::Completions { shell } => {
-                Self::print_completions(*shell);
+            Commands::Completions { shell, shell_flag } => {
+                // Clap guarantees exactly one of them is present
+                if let Some(shell) = shell.or(*shell_flag) {
+                    Self::print_completions(shell);
+                }
                 Ok(())
             }
 
@@ -201,6 +337,12 @@ mod tests {
         assert!(matches!(cli.command, Commands::Reload));
     }
 
+    #[test]
+    fn test_cli_parses_status() {
+        let cli = Cli::try_parse_from(["stache", "status"]).unwrap();
+        assert!(matches!(cli.command, Commands::Status));
+    }
+
     #[test]
     fn test_cli_parses_schema() {
         let cli = Cli::try_parse_from(["stache", "schema"]).unwrap();
@@ -211,7 +353,9 @@ mod tests {
     fn test_cli_parses_completions_bash() {
         let cli = Cli::try_parse_from(["stache", "completions", "--shell", "bash"]).unwrap();
         match cli.command {
-            Commands::Completions { shell } => assert_eq!(shell, Shell::Bash),
+            Commands::Completions { shell, shell_flag } => {
+                assert_eq!(shell.or(shell_flag), Some(Shell::Bash));
+            }
             _ => panic!("Expected Completions command"),
         }
     }
@@ -220,7 +364,9 @@ mod tests {
     fn test_cli_parses_completions_zsh() {
         let cli = Cli::try_parse_from(["stache", "completions", "--shell", "zsh"]).unwrap();
         match cli.command {
-            Commands::Completions { shell } => assert_eq!(shell, Shell::Zsh),
+            Commands::Completions { shell, shell_flag } => {
+                assert_eq!(shell.or(shell_flag), Some(Shell::Zsh));
+            }
             _ => panic!("Expected Completions command"),
         }
     }
@@ -229,11 +375,57 @@ mod tests {
     fn test_cli_parses_completions_fish() {
         let cli = Cli::try_parse_from(["stache", "completions", "--shell", "fish"]).unwrap();
         match cli.command {
-            Commands::Completions { shell } => assert_eq!(shell, Shell::Fish),
+            Commands::Completions { shell, shell_flag } => {
+                assert_eq!(shell.or(shell_flag), Some(Shell::Fish));
+            }
             _ => panic!("Expected Completions command"),
         }
     }
 
+    #[test]
+    fn test_cli_parses_completions_positional() {
+        let cli = Cli::try_parse_from(["stache", "completions", "zsh"]).unwrap();
+        match cli.command {
+            Commands::Completions { shell, shell_flag } => {
+                assert_eq!(shell, Some(Shell::Zsh));
+                assert!(shell_flag.is_none());
+            }
+            _ => panic!("Expected Completions command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_completions_requires_single_shell() {
+        assert!(Cli::try_parse_from(["stache", "completions"]).is_err());
+        assert!(Cli::try_parse_from(["stache", "completions", "powershell2"]).is_err());
+        assert!(Cli::try_parse_from(["stache", "completions", "zsh", "--shell", "bash"]).is_err());
+    }
+
+    #[test]
+    fn test_completions_cover_subcommands() {
+        let mut cmd = Cli::command();
+        let mut output = Vec::new();
+        generate(Shell::Zsh, &mut cmd, "stache", &mut output);
+        let script = String::from_utf8(output).unwrap();
+
+        for subcommand in [
+            "tiling",
+            "wallpaper",
+            "audio",
+            "media",
+            "keepawake",
+            "focus-mode",
+            "bar",
+            "capture",
+            "apps",
+            "cache",
+            "config",
+            "completions",
+        ] {
+            assert!(script.contains(subcommand), "missing {subcommand}");
+        }
+    }
+
     #[test]
     fn test_cli_parses_cache_clear() {
         let cli = Cli::try_parse_from(["stache", "cache", "clear"]).unwrap();
@@ -252,12 +444,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_media_play_pause() {
+        let cli = Cli::try_parse_from(["stache", "media", "play-pause"]).unwrap();
+        assert!(matches!(cli.command, Commands::Media(MediaCommand::PlayPause)));
+    }
+
+    #[test]
+    fn test_cli_parses_keepawake() {
+        let cli = Cli::try_parse_from(["stache", "keepawake", "--for", "2h"]).unwrap();
+        match cli.command {
+            Commands::Keepawake(args) => assert_eq!(args.duration, "2h"),
+            _ => panic!("Expected Keepawake command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_focus_mode() {
+        let cli = Cli::try_parse_from(["stache", "focus-mode", "on"]).unwrap();
+        assert!(matches!(cli.command, Commands::FocusMode(FocusModeCommands::On)));
+    }
+
+    #[test]
+    fn test_cli_parses_bar_toggle() {
+        let cli = Cli::try_parse_from(["stache", "bar", "toggle"]).unwrap();
+        assert!(matches!(cli.command, Commands::Bar(BarCommands::Toggle)));
+    }
+
+    #[test]
+    fn test_cli_parses_apps_list() {
+        let cli = Cli::try_parse_from(["stache", "apps", "list"]).unwrap();
+        assert!(matches!(cli.command, Commands::Apps(AppsCommands::List)));
+    }
+
     #[test]
     fn test_cli_parses_audio_list() {
         let cli = Cli::try_parse_from(["stache", "audio", "list"]).unwrap();
         match cli.command {
-            Commands::Audio(AudioCommands::List { json, input, output }) => {
-                assert!(!json);
+            Commands::Audio(AudioCommands::List { input, output }) => {
+                assert!(!cli.json);
                 assert!(!input);
                 assert!(!output);
             }
@@ -265,17 +490,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_audio_volume() {
+        let cli = Cli::try_parse_from(["stache", "audio", "volume", "up", "10"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Audio(AudioCommands::Volume {
+                action: audio::VolumeCommands::Up { step: 10 }
+            })
+        ));
+    }
+
     #[test]
     fn test_cli_parses_audio_list_json() {
         let cli = Cli::try_parse_from(["stache", "audio", "list", "--json"]).unwrap();
+        assert!(cli.json);
+        assert!(matches!(
+            cli.command,
+            Commands::Audio(AudioCommands::List { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parses_global_json_after_subcommand() {
+        let cli = Cli::try_parse_from(["stache", "tiling", "query", "--json", "screens"]).unwrap();
+        assert!(cli.json);
         match cli.command {
-            Commands::Audio(AudioCommands::List { json, .. }) => {
-                assert!(json);
+            Commands::Tiling(TilingCommands::Query { command, .. }) => {
+                assert!(matches!(command, Some(TilingQueryCommands::Screens { .. })));
             }
-            _ => panic!("Expected Audio List command"),
+            _ => panic!("Expected Tiling Query command"),
         }
     }
 
+    #[test]
+    fn test_cli_parses_global_json_before_subcommand() {
+        let cli = Cli::try_parse_from(["stache", "-j", "tiling", "query", "windows"]).unwrap();
+        assert!(cli.json);
+    }
+
+    #[test]
+    fn test_cli_json_defaults_to_false() {
+        let cli = Cli::try_parse_from(["stache", "tiling", "query", "workspaces"]).unwrap();
+        assert!(!cli.json);
+    }
+
     #[test]
     fn test_cli_parses_audio_list_input() {
         let cli = Cli::try_parse_from(["stache", "audio", "list", "--input"]).unwrap();
@@ -443,10 +702,10 @@ mod tests {
     fn test_cli_parses_tiling_query_screens() {
         let cli = Cli::try_parse_from(["stache", "tiling", "query", "screens"]).unwrap();
         match cli.command {
-            Commands::Tiling(TilingCommands::Query { json, detailed, command }) => {
-                assert!(!json);
+            Commands::Tiling(TilingCommands::Query { detailed, command }) => {
+                assert!(!cli.json);
                 assert!(!detailed);
-                assert!(matches!(command, Some(TilingQueryCommands::Screens)));
+                assert!(matches!(command, Some(TilingQueryCommands::Screens { .. })));
             }
             _ => panic!("Expected Tiling Query command"),
         }
@@ -0,0 +1,108 @@
+//! Shared output for IPC query commands.
+//!
+//! Query commands send an [`IpcQuery`](crate::utils::ipc_socket::IpcQuery) to
+//! the app and print the response either as the raw `IpcResponse` JSON
+//! (`--json`) or through a command-specific human-readable formatter.
+
+use crate::error::StacheError;
+use crate::utils::ipc_socket::{IpcError, IpcResponse};
+
+/// Prints the result of an IPC query and maps failures to errors.
+///
+/// With `json`, the response is printed as raw `IpcResponse` JSON, including
+/// error responses. Otherwise successful data is passed to `print_human`.
+///
+/// # Errors
+///
+/// Returns an error when the app is not reachable or responds with an error,
+/// so the CLI exits with a non-zero status.
+pub fn print_query_response(
+    result: Result<IpcResponse, IpcError>,
+    json: bool,
+    print_human: impl FnOnce(serde_json::Value),
+) -> Result<(), StacheError> {
+    let response = match result {
+        Ok(response) => response,
+        Err(e) => {
+            let message = e.to_string();
+            if json {
+                println!("{}", render_json(&IpcResponse::error(message.clone())));
+            }
+            return Err(StacheError::IpcError(message));
+        }
+    };
+
+    if json {
+        println!("{}", render_json(&response));
+    }
+
+    match response {
+        IpcResponse::Success { data } => {
+            if !json {
+                print_human(data);
+            }
+            Ok(())
+        }
+        IpcResponse::Error { error } => Err(StacheError::TilingError(error)),
+    }
+}
+
+/// Serializes a response as pretty-printed JSON.
+fn render_json(response: &IpcResponse) -> String {
+    serde_json::to_string_pretty(response).unwrap_or_else(|_| "{}".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_json_success() {
+        let response = IpcResponse::success(vec![1, 2]);
+        let value: serde_json::Value = serde_json::from_str(&render_json(&response)).unwrap();
+
+        assert_eq!(value, serde_json::json!({ "data": [1, 2] }));
+    }
+
+    #[test]
+    fn test_render_json_error() {
+        let response = IpcResponse::error("Workspace not found");
+        let value: serde_json::Value = serde_json::from_str(&render_json(&response)).unwrap();
+
+        assert_eq!(value, serde_json::json!({ "error": "Workspace not found" }));
+    }
+
+    #[test]
+    fn test_print_query_response_success_uses_formatter() {
+        let mut printed = None;
+        let result = print_query_response(Ok(IpcResponse::success(42)), false, |data| {
+            printed = Some(data);
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(printed, Some(serde_json::json!(42)));
+    }
+
+    #[test]
+    fn test_print_query_response_json_skips_formatter() {
+        let mut called = false;
+        let result = print_query_response(Ok(IpcResponse::success(42)), true, |_| called = true);
+
+        assert!(result.is_ok());
+        assert!(!called);
+    }
+
+    #[test]
+    fn test_print_query_response_error_fails() {
+        for json in [false, true] {
+            let result = print_query_response(Ok(IpcResponse::error("boom")), json, |_| {});
+            assert!(matches!(result, Err(StacheError::TilingError(msg)) if msg == "boom"));
+        }
+    }
+
+    #[test]
+    fn test_print_query_response_app_not_running_fails() {
+        let result = print_query_response(Err(IpcError::AppNotRunning), true, |_| {});
+        assert!(matches!(result, Err(StacheError::IpcError(_))));
+    }
+}
@@ -0,0 +1,69 @@
+//! Status CLI command.
+//!
+//! Asks the running app for its version, the accessibility permission and
+//! which subsystems are running, to diagnose a broken install.
+
+use colored::Colorize;
+
+use super::response;
+use crate::cli::output;
+use crate::error::StacheError;
+use crate::utils::ipc_socket::{self, IpcQuery};
+
+/// Subsystem flags of the `status` response, with their labels.
+const SUBSYSTEMS: [(&str, &str); 7] = [
+    ("accessibilityGranted", "Accessibility"),
+    ("tilingEnabled", "Tiling enabled"),
+    ("tilingInitialized", "Tiling running"),
+    ("tilingPaused", "Tiling paused"),
+    ("audioWatcherRunning", "Audio watcher"),
+    ("notunesRunning", "noTunes"),
+    ("wallpaperRunning", "Wallpaper"),
+];
+
+/// Execute the status command.
+pub fn execute(json: bool) -> Result<(), StacheError> {
+    response::print_query_response(ipc_socket::send_query(IpcQuery::Status), json, |data| {
+        for line in status_lines(&data) {
+            println!("{line}");
+        }
+    })
+}
+
+/// Formats the `status` response as labelled lines.
+fn status_lines(data: &serde_json::Value) -> Vec<String> {
+    let width = SUBSYSTEMS.iter().map(|(_, label)| label.len()).max().unwrap_or(0);
+
+    let mut lines = vec![format!(
+        "{} {}",
+        format!("{:<width$}", "Version").bold(),
+        data["version"].as_str().unwrap_or("?")
+    )];
+    lines.extend(SUBSYSTEMS.iter().map(|(key, label)| {
+        format!(
+            "{} {}",
+            format!("{label:<width$}").bold(),
+            output::format_bool(data[key].as_bool().unwrap_or(false))
+        )
+    }));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_lines() {
+        let lines = status_lines(&serde_json::json!({
+            "version": "1.2.3",
+            "accessibilityGranted": true,
+            "tilingEnabled": true,
+        }));
+        assert_eq!(lines.len(), 8);
+        assert!(lines[0].contains("Version") && lines[0].ends_with("1.2.3"));
+        assert!(lines[1].contains("Accessibility") && lines[1].contains('✓'));
+        // Missing flags are reported as not running
+        assert!(lines[3].contains("Tiling running") && lines[3].contains('✗'));
+    }
+}
@@ -9,7 +9,11 @@ use tabled::settings::object::Columns;
 use tabled::settings::{Alignment, Modify, Style};
 use tabled::{Table, Tabled};
 
-use super::types::{CliLayoutType, Direction};
+use super::response;
+use super::types::{
+    CliLayoutType, CycleDirection, Direction, ResizeDimension, ScrollDirection, ToggleAction,
+    WindowSort,
+};
 use crate::cli::output;
 use crate::error::StacheError;
 use crate::tiling;
@@ -20,21 +24,17 @@ use crate::utils::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
 #[derive(Subcommand, Debug)]
 #[command(next_display_order = None)]
 pub enum TilingCommands {
-    /// Query tiling state (screens, workspaces, windows, apps).
+    /// Query tiling state (screens, workspaces, windows, apps, tree).
     ///
     /// Without a subcommand, outputs all query results.
-    /// Use --json for JSON output instead of human-readable tables.
+    /// Use the global --json flag for the raw JSON response instead of tables.
     #[command(subcommand_negates_reqs = true)]
     Query {
-        /// Output in JSON format instead of table format.
-        #[arg(long, short = 'j', global = true)]
-        json: bool,
-
         /// Show detailed information (more columns/fields).
         #[arg(long, short = 'd', global = true)]
         detailed: bool,
 
-        /// Query subcommand (screens, workspaces, windows, apps).
+        /// Query subcommand (screens, workspaces, windows, apps, tree).
         #[command(subcommand)]
         command: Option<TilingQueryCommands>,
     },
@@ -48,25 +48,90 @@ pub enum TilingCommands {
     ///
     /// Use flags to specify the workspace operation to perform.
     Workspace(TilingWorkspaceArgs),
+
+    /// Scratchpad commands.
+    ///
+    /// The scratchpad is a hidden workspace for windows sent there with
+    /// `stache tiling window --to-scratchpad`.
+    Scratchpad {
+        /// Scratchpad subcommand.
+        #[command(subcommand)]
+        action: ScratchpadCommands,
+    },
+
+    /// Move windows back to where they were before tiling.
+    ///
+    /// Restores every tracked window to the frame it had when Stache first
+    /// tracked it. Closed, minimized and fullscreen windows are skipped.
+    /// The next layout change tiles the windows again.
+    Restore,
+
+    /// Switch the gaps of all workspaces to a preset from `tiling.gapPresets`.
+    ///
+    /// The preset replaces the configured gaps, including workspace gap
+    /// overrides, until Stache quits or `reset` is used. The config is not
+    /// changed. `cycle` switches to the next preset in alphabetical order.
+    #[command(after_long_help = r#"Examples:
+  stache tiling gaps dense    # Use the 'dense' preset
+  stache tiling gaps cycle    # Next preset
+  stache tiling gaps reset    # Back to the configured gaps"#)]
+    Gaps {
+        /// Preset name, `cycle` or `reset`.
+        #[arg(value_name = "PRESET")]
+        preset: String,
+    },
+
+    /// Resync tiling with the windows on screen and re-apply all layouts.
+    ///
+    /// Use this when tiling got out of sync, e.g. after a missed event. Windows
+    /// that were missed are tracked following the rules, windows that no
+    /// longer exist are dropped, and every visible workspace is laid out again.
+    Retile,
+}
+
+/// Scratchpad subcommands.
+#[derive(Subcommand, Debug, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum ScratchpadCommands {
+    /// Show or hide the scratchpad's windows.
+    ///
+    /// The windows float over the focused screen without switching the
+    /// current workspace.
+    #[command(after_long_help = r#"Examples:
+  stache tiling scratchpad toggle   # Show or hide the scratchpad"#)]
+    Toggle,
 }
 
 /// Tiling query subcommands.
 #[derive(Subcommand, Debug)]
 #[command(next_display_order = None)]
 pub enum TilingQueryCommands {
-    /// List all connected screens.
+    /// List connected screens.
     ///
-    /// Returns information about all screens including name, frame,
-    /// and whether it's the main or built-in display.
+    /// Returns information about screens including name, frame, usable
+    /// frame (`visibleFrame`: without the menu bar, Dock and reserved space),
+    /// scale factor, whether it's the main or built-in display, and whether
+    /// it has focus (`isFocused`).
+    /// With --screen or --main, JSON output is a single object instead of an array.
     #[command(after_long_help = r#"Examples:
-  stache tiling query screens         # List all screens
-  stache tiling query --json screens  # Output as JSON"#)]
-    Screens,
+  stache tiling query screens                      # List all screens
+  stache tiling query --json screens               # Output as JSON
+  stache tiling query --json screens --main        # Only the main screen
+  stache tiling query screens --screen "DELL U2720Q"  # Only a screen by name"#)]
+    Screens {
+        /// Only show the main screen.
+        #[arg(long, conflicts_with = "screen")]
+        main: bool,
+
+        /// Only show one screen (main, secondary, or screen name).
+        #[arg(long)]
+        screen: Option<String>,
+    },
 
     /// List workspaces.
     ///
     /// Returns information about workspaces including name, layout,
-    /// screen assignment, and visibility state.
+    /// screen assignment, visibility state, and split ratios.
     #[command(after_long_help = r#"Examples:
   stache tiling query workspaces                    # List all workspaces
   stache tiling query workspaces --focused-screen   # Workspaces on focused screen
@@ -89,6 +154,7 @@ pub enum TilingQueryCommands {
   stache tiling query windows                       # List all windows
   stache tiling query windows --focused-workspace   # Windows in focused workspace
   stache tiling query windows --workspace coding    # Windows in 'coding' workspace
+  stache tiling query windows --sort recent         # Most recently focused first
   stache tiling query -d windows                    # Show detailed window info"#)]
     Windows {
         /// Only show windows on the focused screen.
@@ -106,6 +172,12 @@ pub enum TilingQueryCommands {
         /// Filter by workspace name.
         #[arg(long, conflicts_with_all = ["focused_screen", "focused_workspace"])]
         workspace: Option<String>,
+
+        /// Sort windows by app, title, workspace, or recent focus.
+        ///
+        /// Without it, windows are listed in the order they were tracked.
+        #[arg(long, value_name = "KEY", value_enum)]
+        sort: Option<WindowSort>,
     },
 
     /// List all running applications.
@@ -117,20 +189,105 @@ pub enum TilingQueryCommands {
   stache tiling query --json apps     # Output as JSON
   stache tiling query -d apps         # Show detailed app info"#)]
     Apps,
+
+    /// Show the focused window with its workspace and screen.
+    ///
+    /// Returns the focused window (id, app, title, frame), the name and
+    /// layout of its workspace, and the screen it is on. Parts that are
+    /// not available are reported as null.
+    #[command(after_long_help = r#"Examples:
+  stache tiling query focused         # Show the focused window context
+  stache tiling query --json focused  # Output as JSON"#)]
+    Focused,
+
+    /// List recently focused windows, most recent first.
+    ///
+    /// Starts with the focused window, followed by the windows that had focus
+    /// before it. Windows that have closed since are left out. Useful for
+    /// building window switchers.
+    #[command(after_long_help = r#"Examples:
+  stache tiling query history            # Recently focused windows
+  stache tiling query history --limit 5  # Only the five most recent
+  stache tiling query --json history     # Output as JSON"#)]
+    History {
+        /// Maximum number of windows to list.
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+    },
+
+    /// Show the dwindle split tree of a workspace.
+    ///
+    /// Prints each split with its orientation and ratio, down to the
+    /// window IDs at the leaves. Useful for debugging dwindle layouts and
+    /// resize ratios. Workspaces using other layouts have no tree.
+    #[command(after_long_help = r#"Examples:
+  stache tiling query tree                    # Tree of the focused workspace
+  stache tiling query tree --workspace coding # Tree of the 'coding' workspace
+  stache tiling query --json tree             # Output as JSON"#)]
+    Tree {
+        /// Workspace name (defaults to the focused workspace).
+        #[arg(long)]
+        workspace: Option<String>,
+    },
+
+    /// Compute a layout for synthetic windows without moving any window.
+    ///
+    /// Prints the frame each window would get on the given frame, using the
+    /// configured gaps and master and scrolling settings. No window is
+    /// tracked or moved, so the output is deterministic and can be checked
+    /// in tests.
+    #[command(after_long_help = r#"Examples:
+  stache tiling query dry-run --layout dwindle --windows 1,2,3 --frame 0,0,1920,1080
+  stache tiling query --json dry-run --layout master --windows 1,2 --frame 0,0,2560,1440"#)]
+    DryRun {
+        /// Layout to compute.
+        #[arg(long, value_enum)]
+        layout: CliLayoutType,
+
+        /// Comma-separated window IDs, in stack order.
+        #[arg(long, value_name = "IDS", value_delimiter = ',', required = true)]
+        windows: Vec<u32>,
+
+        /// Frame to tile within, as X,Y,WIDTH,HEIGHT.
+        #[arg(long, value_name = "X,Y,WIDTH,HEIGHT", value_parser = parse_frame)]
+        frame: [f64; 4],
+    },
 }
 
 /// Tiling window command arguments.
 ///
 /// Multiple operations can be combined in a single command.
-/// Operations are executed in order: focus -> swap -> preset -> resize -> send.
+/// Operations are executed in order:
+/// restore -> focus/focus-last/focus-index -> swap -> promote -> move -> preset -> float
+/// -> sticky -> always-on-top -> center/maximize -> resize -> send -> to-scratchpad
+/// -> minimize.
 #[derive(Debug, clap::Args)]
 #[command(after_long_help = r#"Examples:
   stache tiling window --focus left                            # Focus window to the left
+  stache tiling window --focus-last                            # Focus the previous window
+  stache tiling window --focus-index 2                         # Focus the second window
+  stache tiling window --focus-index 9 --wrap                  # Wrap past the last window
   stache tiling window --swap down                             # Swap with window below
+  stache tiling window --promote                               # Make the focused window master
+  stache tiling window --move left                             # Nudge left by floating.moveStep
+  stache tiling window --move up 10                            # Nudge up by 10px
   stache tiling window --resize width 100                      # Increase width by 100px
+  stache tiling window --resize width:-40                      # Decrease width by 40px
   stache tiling window --resize width 100 --resize height 50   # Resize both dimensions
   stache tiling window --swap right --resize width 150         # Swap then resize
-  stache tiling window --send-to-screen main                   # Send to main screen"#)]
+  stache tiling window --grow width                            # Grow width by tiling.resizeStep
+  stache tiling window --shrink height                         # Shrink height by tiling.resizeStep
+  stache tiling window --send-to-screen main                   # Send to main screen
+  stache tiling window --send coding                           # Send to 'coding', stay here
+  stache tiling window --send-follow coding                    # Send to 'coding' and follow
+  stache tiling window --float toggle                          # Float or tile again
+  stache tiling window --sticky toggle                         # Show on all workspaces
+  stache tiling window --always-on-top toggle                  # Keep above tiled windows
+  stache tiling window --float on --center                     # Float and center
+  stache tiling window --maximize                              # Fill the screen (floating only)
+  stache tiling window --to-scratchpad                         # Stash in the scratchpad
+  stache tiling window --minimize                              # Minimize to the Dock
+  stache tiling window --restore                               # Restore a minimized window"#)]
 pub struct TilingWindowArgs {
     /// Focus a window in a direction or by ID.
     ///
@@ -139,51 +296,207 @@ pub struct TilingWindowArgs {
     #[arg(long, value_name = "DIRECTION|WINDOW_ID")]
     pub focus: Option<String>,
 
+    /// Focus the previously focused window.
+    ///
+    /// Switches to its workspace if needed. Repeating it toggles between
+    /// the two most recently focused windows.
+    #[arg(long = "focus-last", conflicts_with = "focus")]
+    pub focus_last: bool,
+
+    /// Focus the window at a position in the focused workspace (1-based).
+    ///
+    /// Counts the tiled windows in the workspace's window order. Positions
+    /// past the last window fail unless --wrap is given. Prints the focused
+    /// window's ID.
+    #[arg(
+        long = "focus-index",
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with_all = ["focus", "focus_last"]
+    )]
+    pub focus_index: Option<u32>,
+
+    /// Wrap --focus-index positions past the last window around to the first.
+    #[arg(long, requires = "focus_index")]
+    pub wrap: bool,
+
     /// Swap focused window with another in a direction.
     ///
     /// Direction: up, down, left, right, previous, next.
     #[arg(long, value_name = "DIRECTION", value_enum)]
     pub swap: Option<Direction>,
 
+    /// Move the focused window to the first position of its workspace.
+    ///
+    /// In the master layout it becomes the master window; in the other tiled
+    /// layouts it takes the first position. Does nothing in monocle, tabbed
+    /// and floating workspaces. Focus stays on the window.
+    #[arg(long)]
+    pub promote: bool,
+
+    /// Move the focused window in a direction.
+    ///
+    /// Direction: up, down, left, right. Floating windows are moved by
+    /// PIXELS (default: `floating.moveStep`), staying within the screen.
+    /// Tiled windows are swapped with their neighbor instead, like --swap.
+    #[arg(long = "move", value_names = ["DIRECTION", "PIXELS"], num_args = 1..=2)]
+    pub move_window: Vec<String>,
+
     /// Apply a floating preset to the focused window.
     ///
-    /// Uses a preset defined in the configuration file.
+    /// Uses a preset defined in the configuration file, or a grid preset
+    /// such as `grid-r1c2` when `floating.grid` is configured.
     #[arg(long, value_name = "PRESET_NAME")]
     pub preset: Option<String>,
 
+    /// Make the focused window floating.
+    ///
+    /// Action: toggle, on, off. Floating windows are left out of the tiled
+    /// layout and centered when `floating.defaultPosition` is `center`.
+    /// Turning floating off puts the window back into the layout.
+    #[arg(long, value_name = "ACTION", value_enum)]
+    pub float: Option<ToggleAction>,
+
+    /// Make the focused window sticky (shown on every workspace).
+    ///
+    /// Action: toggle, on, off. Sticky windows are never hidden on workspace
+    /// switch and are left out of the tiled layout, like floating windows.
+    #[arg(long, value_name = "ACTION", value_enum)]
+    pub sticky: Option<ToggleAction>,
+
+    /// Keep the focused window above tiled windows while it floats.
+    ///
+    /// Action: toggle, on, off. Overrides `tiling.floatingAlwaysOnTop` for
+    /// this window. Only floating and sticky windows are kept on top.
+    #[arg(long = "always-on-top", value_name = "ACTION", value_enum)]
+    pub always_on_top: Option<ToggleAction>,
+
+    /// Center the focused floating window on its screen.
+    ///
+    /// Keeps the window size, shrinking it if it doesn't fit. Fails for
+    /// tiled windows, whose frame is owned by the layout.
+    #[arg(long)]
+    pub center: bool,
+
+    /// Maximize the focused floating window to fill its screen.
+    ///
+    /// Fills the screen minus the bar and the outer gaps, without entering
+    /// macOS full screen. Fails for tiled windows.
+    #[arg(long, conflicts_with = "center")]
+    pub maximize: bool,
+
     /// Resize the focused window.
     ///
-    /// Specify dimension (width/height) and amount in pixels.
+    /// Specify dimension (width/height) and amount in pixels, either as two
+    /// values (`width 40`) or a single `DIMENSION:AMOUNT` value (`width:+40`).
     /// Positive values increase size, negative values decrease.
     /// Can be specified multiple times to resize both dimensions.
-    #[arg(long, value_names = ["DIMENSION", "AMOUNT"], num_args = 2, action = clap::ArgAction::Append, allow_negative_numbers = true)]
+    ///
+    /// Fails if the layout has no adjustable splits (monocle, tabbed, master, floating).
+    #[arg(long, value_names = ["DIMENSION", "AMOUNT"], num_args = 1..=2, action = clap::ArgAction::Append, allow_negative_numbers = true)]
     pub resize: Vec<String>,
 
+    /// Grow the focused window by `tiling.resizeStep` pixels.
+    ///
+    /// Dimension: width, height. Can be specified multiple times. Splits
+    /// stop at their limits, so repeating it saturates instead of failing.
+    #[arg(long, value_name = "DIMENSION", value_enum)]
+    pub grow: Vec<ResizeDimension>,
+
+    /// Shrink the focused window by `tiling.resizeStep` pixels.
+    ///
+    /// Dimension: width, height. Can be specified multiple times.
+    #[arg(long, value_name = "DIMENSION", value_enum)]
+    pub shrink: Vec<ResizeDimension>,
+
     /// Send focused window to another screen.
     ///
     /// Target: main, secondary, or screen name.
     #[arg(long = "send-to-screen", value_name = "SCREEN")]
     pub send_to_screen: Option<String>,
 
-    /// Send focused window to another workspace.
+    /// Send focused window to another workspace and stay on the current one.
     ///
     /// The window will be hidden if the target workspace is not visible.
-    #[arg(long = "send-to-workspace", value_name = "WORKSPACE")]
+    /// Prints whether the window ended up shown or hidden.
+    #[arg(
+        long = "send",
+        visible_alias = "send-to-workspace",
+        value_name = "WORKSPACE"
+    )]
     pub send_to_workspace: Option<String>,
+
+    /// Send focused window to another workspace and switch to it.
+    #[arg(
+        long = "send-follow",
+        value_name = "WORKSPACE",
+        conflicts_with = "send_to_workspace"
+    )]
+    pub send_follow: Option<String>,
+
+    /// Move the focused window to the scratchpad and hide it.
+    ///
+    /// The window floats from then on. Show it again with
+    /// `stache tiling scratchpad toggle`.
+    #[arg(
+        long = "to-scratchpad",
+        conflicts_with_all = ["send_to_screen", "send_to_workspace", "send_follow"]
+    )]
+    pub to_scratchpad: bool,
+
+    /// Minimize the focused window to the Dock.
+    ///
+    /// Minimized windows are left out of the tiled layout until restored.
+    #[arg(long)]
+    pub minimize: bool,
+
+    /// Restore a minimized window of the focused workspace and focus it.
+    ///
+    /// Restores the last minimized window in the workspace's window order
+    /// and puts it back into the layout.
+    #[arg(long, conflicts_with = "minimize")]
+    pub restore: bool,
 }
 
 /// Tiling workspace command arguments.
 ///
 /// Multiple operations can be combined in a single command.
-/// Operations are executed in order: focus -> layout -> balance -> send.
+/// Operations are executed in order:
+/// create -> rename -> focus/focus-last -> cycle -> claim/unclaim -> layout
+/// -> copy-layout-from/swap-layout -> masters -> ratio -> scroll -> balance
+/// -> cascade/tile-floating -> send -> merge.
 #[derive(Debug, clap::Args)]
 #[command(after_long_help = r#"Examples:
   stache tiling workspace --balance                    # Balance windows in focused workspace
   stache tiling workspace --focus coding               # Switch to 'coding' workspace
+  stache tiling workspace --cycle next                 # Next workspace on this screen
+  stache tiling workspace --focus-last                 # Back to the previous workspace
+  stache tiling workspace --toggle                     # Same as --focus-last
+  stache tiling workspace --create scratch --focus scratch  # Create and switch to 'scratch'
+  stache tiling workspace --rename code                # Rename the focused workspace
+  stache tiling workspace --claim-app                  # Open the focused app here from now on
   stache tiling workspace --layout dwindle                 # Use DWINDLE layout
   stache tiling workspace --layout dwindle --balance       # Change layout then balance
-  stache tiling workspace --send-to-screen main        # Move workspace to main screen"#)]
+  stache tiling workspace --layout cycle                   # Next layout in tiling.layoutCycle
+  stache tiling workspace --copy-layout-from coding    # Use the layout and ratios of 'coding'
+  stache tiling workspace --swap-layout coding         # Exchange layouts with 'coding'
+  stache tiling workspace --masters +1                 # Add a master window
+  stache tiling workspace --masters 2                  # Use two master windows
+  stache tiling workspace --ratio 0:0.3                # First split at 30%
+  stache tiling workspace --scroll right               # Scroll a scrolling workspace
+  stache tiling workspace --cascade                    # Stagger floating windows diagonally
+  stache tiling workspace --tile-floating              # Arrange floating windows in a grid
+  stache tiling workspace --send-to-screen main        # Move workspace to main screen
+  stache tiling workspace --merge-into coding          # Move all windows to 'coding'
+  stache tiling workspace --merge-follow coding        # Move all windows and follow"#)]
 pub struct TilingWorkspaceArgs {
+    /// Create a workspace on the focused screen.
+    ///
+    /// The workspace uses `tiling.defaultLayout` and lasts until Stache
+    /// quits; it is not written to the config. Names must be unique.
+    #[arg(long, value_name = "NAME")]
+    pub create: Option<String>,
+
     /// Focus a workspace by name.
     ///
     /// Switches to the specified workspace, hiding windows from
@@ -191,12 +504,70 @@ pub struct TilingWorkspaceArgs {
     #[arg(long, value_name = "WORKSPACE")]
     pub focus: Option<String>,
 
+    /// Switch to the next or previous workspace on the focused screen.
+    ///
+    /// Direction: next, prev. Wraps around and skips workspaces assigned
+    /// to other screens. Prints the name of the workspace switched to.
+    #[arg(long, value_name = "DIRECTION", value_enum, conflicts_with = "focus")]
+    pub cycle: Option<CycleDirection>,
+
+    /// Switch back to the previously visible workspace on the focused screen.
+    ///
+    /// Repeating it toggles between the two most recent workspaces.
+    /// Prints the name of the workspace switched to.
+    #[arg(
+        long = "focus-last",
+        visible_alias = "toggle",
+        conflicts_with_all = ["focus", "cycle"]
+    )]
+    pub focus_last: bool,
+
+    /// Rename the focused workspace, or the one given with --focus.
+    ///
+    /// Combined with --focus, the workspace is renamed first and then
+    /// focused by its new name. Lasts until Stache quits; the config is not
+    /// changed. Names must be unique and non-empty.
+    #[arg(long, value_name = "NAME")]
+    pub rename: Option<String>,
+
+    /// Open new windows of the focused window's app on the focused workspace.
+    ///
+    /// Works like an `appId` rule in `tiling.workspaces`, checked before the
+    /// configured rules, and moves any claim the app had on another
+    /// workspace. Lasts until Stache quits; the config is not changed.
+    /// Prints the equivalent rule so it can be added to the config.
+    #[arg(long = "claim-app")]
+    pub claim_app: bool,
+
+    /// Release the focused window's app from the focused workspace.
+    ///
+    /// Undoes --claim-app; new windows follow the configured rules again.
+    #[arg(long = "unclaim-app", conflicts_with = "claim_app")]
+    pub unclaim_app: bool,
+
     /// Change the layout of the focused workspace.
     ///
-    /// Layout: dwindle, split, split-vertical, split-horizontal, monocle, master, grid, floating.
+    /// Layout: dwindle, split, split-vertical, split-horizontal, monocle, tabbed, master, grid,
+    /// columns, scrolling, floating. Use `cycle` to advance to the next layout in
+    /// `tiling.layoutCycle`.
     #[arg(long, value_name = "LAYOUT", value_enum)]
     pub layout: Option<CliLayoutType>,
 
+    /// Copy the layout and split ratios of a workspace to the focused one.
+    ///
+    /// The named workspace is left unchanged.
+    #[arg(long = "copy-layout-from", value_name = "WORKSPACE")]
+    pub copy_layout_from: Option<String>,
+
+    /// Exchange the layout and split ratios of the focused workspace with
+    /// another workspace.
+    #[arg(
+        long = "swap-layout",
+        value_name = "WORKSPACE",
+        conflicts_with = "copy_layout_from"
+    )]
+    pub swap_layout: Option<String>,
+
     /// Balance windows in the focused workspace.
     ///
     /// Resets all window size ratios to their default values,
@@ -204,26 +575,98 @@ pub struct TilingWorkspaceArgs {
     #[arg(long)]
     pub balance: bool,
 
-    /// Send focused workspace to another screen.
+    /// Move the focused workspace and its windows to another screen.
     ///
-    /// Target: main, secondary, or screen name.
-    #[arg(long = "send-to-screen", value_name = "SCREEN")]
+    /// Target: main, secondary, or screen name. The workspace becomes the
+    /// visible one on the target screen, and another workspace takes its
+    /// place on the source screen.
+    #[arg(
+        long = "send-to-screen",
+        visible_alias = "move-to-screen",
+        value_name = "SCREEN"
+    )]
     pub send_to_screen: Option<String>,
+
+    /// Change the number of master windows in the focused workspace.
+    ///
+    /// Use +N or -N to adjust the current count, or N to set it.
+    /// Only affects the master layout and never goes below one.
+    #[arg(
+        long,
+        value_name = "COUNT",
+        allow_hyphen_values = true,
+        value_parser = parse_master_count
+    )]
+    pub masters: Option<String>,
+
+    /// Set a split ratio of the focused workspace.
+    ///
+    /// Takes `INDEX:RATIO`, with the ratio between 0.0 and 1.0 (clamped to
+    /// 0.1 - 0.9). In the master layout, index 0 sets the master ratio.
+    /// Current ratios are listed by `stache tiling query workspaces --json`.
+    #[arg(long, value_name = "INDEX:RATIO", value_parser = parse_split_ratio)]
+    pub ratio: Option<(usize, f64)>,
+
+    /// Scroll the focused workspace by one column.
+    ///
+    /// Direction: left, right. Only affects the scrolling layout.
+    /// Focus moves along when the focused window scrolls off screen.
+    #[arg(long, value_name = "DIRECTION", value_enum)]
+    pub scroll: Option<ScrollDirection>,
+
+    /// Stagger the floating windows of the focused workspace diagonally.
+    ///
+    /// Windows keep their size and stay floating. The cascade starts over
+    /// from the top-left corner when it runs out of room.
+    #[arg(long)]
+    pub cascade: bool,
+
+    /// Arrange the floating windows of the focused workspace in a grid.
+    ///
+    /// Uses the grid layout's cells within the usable area; windows stay
+    /// floating. Beyond 12 windows, cells are shared from the first one on.
+    #[arg(long = "tile-floating", conflicts_with = "cascade")]
+    pub tile_floating: bool,
+
+    /// Move every window of the focused workspace into another workspace.
+    ///
+    /// Windows on another screen move to the target's screen. The focused
+    /// workspace stays, empty. Prints how many windows were moved.
+    #[arg(long = "merge-into", value_name = "WORKSPACE")]
+    pub merge_into: Option<String>,
+
+    /// Like --merge-into, then switch to the target workspace.
+    #[arg(
+        long = "merge-follow",
+        value_name = "WORKSPACE",
+        conflicts_with = "merge_into"
+    )]
+    pub merge_follow: Option<String>,
 }
 
 /// Execute tiling subcommands.
-pub fn execute(cmd: &TilingCommands) -> Result<(), StacheError> {
+///
+/// `json` selects raw JSON output for query commands.
+pub fn execute(cmd: &TilingCommands, json: bool) -> Result<(), StacheError> {
     match cmd {
-        TilingCommands::Query { json, detailed, command } => {
-            execute_query(*json, *detailed, command.as_ref())
+        TilingCommands::Query { detailed, command } => {
+            execute_query(json, *detailed, command.as_ref())
         }
         TilingCommands::Window(args) => execute_window(args),
         TilingCommands::Workspace(args) => execute_workspace(args),
+        TilingCommands::Scratchpad {
+            action: ScratchpadCommands::Toggle,
+        } => {
+            ipc::send_notification(&StacheNotification::TilingScratchpadToggle);
+            Ok(())
+        }
+        TilingCommands::Restore => execute_restore(),
+        TilingCommands::Gaps { preset } => execute_gaps(preset),
+        TilingCommands::Retile => execute_retile(),
     }
 }
 
 /// Execute tiling query subcommands.
-#[allow(clippy::unnecessary_wraps)] // Will return errors when fully implemented
 fn execute_query(
     json: bool,
     detailed: bool,
@@ -237,40 +680,47 @@ fn execute_query(
             println!();
             Ok(())
         }
-        Some(TilingQueryCommands::Screens) => {
-            execute_query_screens(json);
-            Ok(())
+        Some(TilingQueryCommands::Screens { main, screen }) => {
+            let screen = if *main {
+                Some("main")
+            } else {
+                screen.as_deref()
+            };
+            execute_query_screens(json, screen)
         }
         Some(TilingQueryCommands::Workspaces { focused_screen, screen }) => {
-            execute_query_workspaces(json, *focused_screen, screen.as_deref());
-            Ok(())
+            execute_query_workspaces(json, *focused_screen, screen.as_deref())
         }
         Some(TilingQueryCommands::Windows {
             focused_screen,
             focused_workspace,
             screen,
             workspace,
-        }) => {
-            execute_query_windows(
-                json,
-                detailed,
-                *focused_screen,
-                *focused_workspace,
-                screen.as_deref(),
-                workspace.as_deref(),
-            );
-            Ok(())
+            sort,
+        }) => execute_query_windows(
+            json,
+            detailed,
+            *focused_screen,
+            *focused_workspace,
+            screen.as_deref(),
+            workspace.as_deref(),
+            *sort,
+        ),
+        Some(TilingQueryCommands::Apps) => execute_query_apps(json, detailed),
+        Some(TilingQueryCommands::Focused) => execute_query_focused(json),
+        Some(TilingQueryCommands::History { limit }) => execute_query_history(json, *limit),
+        Some(TilingQueryCommands::Tree { workspace }) => {
+            execute_query_tree(json, workspace.clone())
         }
-        Some(TilingQueryCommands::Apps) => {
-            execute_query_apps(json, detailed);
-            Ok(())
+        Some(TilingQueryCommands::DryRun { layout, windows, frame }) => {
+            execute_query_dry_run(json, *layout, windows, *frame)
         }
     }
 }
 
 /// Execute tiling query screens command.
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-fn execute_query_screens(json: bool) {
+fn execute_query_screens(json: bool, screen: Option<&str>) -> Result<(), StacheError> {
     #[derive(Tabled)]
     struct ScreenRow {
         #[tabled(rename = "ID")]
@@ -279,6 +729,8 @@ fn execute_query_screens(json: bool) {
         name: String,
         #[tabled(rename = "Resolution")]
         resolution: String,
+        #[tabled(rename = "Usable")]
+        usable: String,
         #[tabled(rename = "Position")]
         position: String,
         #[tabled(rename = "Scale")]
@@ -289,85 +741,67 @@ fn execute_query_screens(json: bool) {
         builtin: String,
     }
 
+    let query = IpcQuery::Screens {
+        screen: screen.map(ToString::to_string),
+    };
+
     // Send IPC query to app
-    let response = match ipc_socket::send_query(IpcQuery::Screens) {
-        Ok(r) => r,
-        Err(IpcError::AppNotRunning) => {
-            if json {
-                println!(r#"{{"error":"Stache app is not running"}}"#);
-            } else {
-                println!("{}", "Stache app is not running.".red());
-            }
-            return;
-        }
-        Err(e) => {
-            if json {
-                println!(r#"{{"error":"{e}"}}"#);
-            } else {
-                println!("{} {e}", "Error:".red());
-            }
+    response::print_query_response(ipc_socket::send_query(query), json, |data| {
+        // Parse screens from response (a single object when filtered)
+        let screens: Vec<tiling::Screen> = if data.is_object() {
+            serde_json::from_value(data).map(|screen| vec![screen]).unwrap_or_default()
+        } else {
+            serde_json::from_value(data).unwrap_or_default()
+        };
+
+        if screens.is_empty() {
+            println!("{}", "No screens detected.".dimmed());
             return;
         }
-    };
-
-    match response {
-        IpcResponse::Success { data } => {
-            if json {
-                output::print_highlighted_json(&data);
-            } else {
-                // Parse screens from response
-                let screens: Vec<tiling::Screen> = serde_json::from_value(data).unwrap_or_default();
 
-                if screens.is_empty() {
-                    println!("{}", "No screens detected.".dimmed());
-                    return;
+        let rows: Vec<ScreenRow> = screens
+            .iter()
+            .map(|s| {
+                let width = s.frame.width as u32;
+                let height = s.frame.height as u32;
+                let x = s.frame.x as i32;
+                let y = s.frame.y as i32;
+                let scale = s.scale_factor;
+                let usable_width = s.visible_frame.width as u32;
+                let usable_height = s.visible_frame.height as u32;
+                ScreenRow {
+                    id: s.id,
+                    name: s.name.clone(),
+                    resolution: format!("{width}x{height}"),
+                    usable: format!("{usable_width}x{usable_height}"),
+                    position: format!("{x}, {y}"),
+                    scale: format!("{scale}x"),
+                    main: output::format_bool(s.is_main),
+                    builtin: output::format_bool(s.is_builtin),
                 }
-
-                let rows: Vec<ScreenRow> = screens
-                    .iter()
-                    .map(|s| {
-                        let width = s.frame.width as u32;
-                        let height = s.frame.height as u32;
-                        let x = s.frame.x as i32;
-                        let y = s.frame.y as i32;
-                        let scale = s.scale_factor;
-                        ScreenRow {
-                            id: s.id,
-                            name: s.name.clone(),
-                            resolution: format!("{width}x{height}"),
-                            position: format!("{x}, {y}"),
-                            scale: format!("{scale}x"),
-                            main: output::format_bool(s.is_main),
-                            builtin: output::format_bool(s.is_builtin),
-                        }
-                    })
-                    .collect();
-
-                let table = Table::new(rows)
-                    .with(Style::rounded())
-                    .with(Modify::new(Columns::first()).with(Alignment::right()))
-                    .with(Modify::new(Columns::new(2..5)).with(Alignment::right()))
-                    .with(Modify::new(Columns::new(5..7)).with(Alignment::center()))
-                    .to_string();
-
-                let count = screens.len();
-                println!("{}", format!("Screens ({count})").bold());
-                println!("{table}");
-            }
-        }
-        IpcResponse::Error { error } => {
-            if json {
-                println!(r#"{{"error":"{error}"}}"#);
-            } else {
-                println!("{} {error}", "Error:".red());
-            }
-        }
-    }
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .with(Style::rounded())
+            .with(Modify::new(Columns::first()).with(Alignment::right()))
+            .with(Modify::new(Columns::new(2..6)).with(Alignment::right()))
+            .with(Modify::new(Columns::new(6..8)).with(Alignment::center()))
+            .to_string();
+
+        let count = screens.len();
+        println!("{}", format!("Screens ({count})").bold());
+        println!("{table}");
+    })
 }
 
 /// Execute tiling query workspaces command.
 #[allow(clippy::cast_possible_truncation)]
-fn execute_query_workspaces(json: bool, focused_screen: bool, screen: Option<&str>) {
+fn execute_query_workspaces(
+    json: bool,
+    focused_screen: bool,
+    screen: Option<&str>,
+) -> Result<(), StacheError> {
     #[derive(Tabled)]
     struct WorkspaceRow {
         #[tabled(rename = "Name")]
@@ -382,6 +816,8 @@ fn execute_query_workspaces(json: bool, focused_screen: bool, screen: Option<&st
         visible: String,
         #[tabled(rename = "Focused")]
         focused: String,
+        #[tabled(rename = "Ratios")]
+        ratios: String,
     }
 
     // Send IPC query to app
@@ -390,70 +826,54 @@ fn execute_query_workspaces(json: bool, focused_screen: bool, screen: Option<&st
         focused_screen,
     };
 
-    let response = match ipc_socket::send_query(query) {
-        Ok(r) => r,
-        Err(IpcError::AppNotRunning) => {
-            if json {
-                println!(r#"{{"error":"Stache app is not running"}}"#);
-            } else {
-                println!("{}", "Stache app is not running.".red());
-            }
-            return;
-        }
-        Err(e) => {
-            if json {
-                println!(r#"{{"error":"{e}"}}"#);
-            } else {
-                println!("{} {e}", "Error:".red());
-            }
+    response::print_query_response(ipc_socket::send_query(query), json, |data| {
+        // Parse workspaces from response
+        let workspaces: Vec<serde_json::Value> = serde_json::from_value(data).unwrap_or_default();
+
+        if workspaces.is_empty() {
+            println!("{}", "No workspaces found.".dimmed());
             return;
         }
-    };
-
-    match response {
-        IpcResponse::Success { data } => {
-            if json {
-                output::print_highlighted_json(&data);
-            } else {
-                // Parse workspaces from response
-                let workspaces: Vec<serde_json::Value> =
-                    serde_json::from_value(data).unwrap_or_default();
 
-                if workspaces.is_empty() {
-                    println!("{}", "No workspaces found.".dimmed());
-                    return;
-                }
+        let rows: Vec<WorkspaceRow> = workspaces
+            .iter()
+            .map(|ws| WorkspaceRow {
+                name: ws["name"].as_str().unwrap_or("?").to_string(),
+                screen: output::truncate(ws["screenName"].as_str().unwrap_or("?"), 15),
+                layout: ws["layout"].as_str().unwrap_or("?").to_string(),
+                windows: ws["windowCount"].as_u64().unwrap_or(0) as usize,
+                visible: output::format_bool(ws["isVisible"].as_bool().unwrap_or(false)),
+                focused: output::format_bool(ws["isFocused"].as_bool().unwrap_or(false)),
+                ratios: format_split_ratios(&ws["splitRatios"]),
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .with(Style::rounded())
+            .with(Modify::new(Columns::new(3..4)).with(Alignment::right()))
+            .with(Modify::new(Columns::new(4..6)).with(Alignment::center()))
+            .to_string();
+
+        let count = workspaces.len();
+        println!("{}", format!("Workspaces ({count})").bold());
+        println!("{table}");
+    })
+}
 
-                let rows: Vec<WorkspaceRow> = workspaces
-                    .iter()
-                    .map(|ws| WorkspaceRow {
-                        name: ws["name"].as_str().unwrap_or("?").to_string(),
-                        screen: output::truncate(ws["screenName"].as_str().unwrap_or("?"), 15),
-                        layout: ws["layout"].as_str().unwrap_or("?").to_string(),
-                        windows: ws["windowCount"].as_u64().unwrap_or(0) as usize,
-                        visible: output::format_bool(ws["isVisible"].as_bool().unwrap_or(false)),
-                        focused: output::format_bool(ws["isFocused"].as_bool().unwrap_or(false)),
-                    })
-                    .collect();
-
-                let table = Table::new(rows)
-                    .with(Style::rounded())
-                    .with(Modify::new(Columns::new(3..4)).with(Alignment::right()))
-                    .with(Modify::new(Columns::new(4..6)).with(Alignment::center()))
-                    .to_string();
-
-                let count = workspaces.len();
-                println!("{}", format!("Workspaces ({count})").bold());
-                println!("{table}");
-            }
-        }
-        IpcResponse::Error { error } => {
-            if json {
-                println!(r#"{{"error":"{error}"}}"#);
-            } else {
-                println!("{} {error}", "Error:".red());
-            }
-        }
+/// Formats a workspace's split ratios for the table, or "-" when there are none.
+fn format_split_ratios(ratios: &serde_json::Value) -> String {
+    let ratios: Vec<String> = ratios
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(serde_json::Value::as_f64)
+        .map(|ratio| format!("{ratio:.2}"))
+        .collect();
+
+    if ratios.is_empty() {
+        "-".to_string()
+    } else {
+        ratios.join(", ")
     }
 }
 
@@ -471,7 +891,8 @@ fn execute_query_windows(
     focused_workspace: bool,
     screen: Option<&str>,
     workspace: Option<&str>,
-) {
+    sort: Option<WindowSort>,
+) -> Result<(), StacheError> {
     #[derive(Tabled)]
     struct WindowRow {
         #[tabled(rename = "ID")]
@@ -519,145 +940,98 @@ fn execute_query_windows(
         focused_screen,
         focused_workspace,
         detailed,
+        sort: sort.map(|sort| sort.as_str().to_string()),
     };
 
-    let response = match ipc_socket::send_query(query) {
-        Ok(r) => r,
-        Err(IpcError::AppNotRunning) => {
-            if json {
-                println!(r#"{{"error":"Stache app is not running"}}"#);
-            } else {
-                println!("{}", "Stache app is not running.".red());
-            }
-            return;
-        }
-        Err(e) => {
-            if json {
-                println!(r#"{{"error":"{e}"}}"#);
-            } else {
-                println!("{} {e}", "Error:".red());
-            }
+    response::print_query_response(ipc_socket::send_query(query), json, |data| {
+        // Parse windows from response
+        let windows: Vec<serde_json::Value> = serde_json::from_value(data).unwrap_or_default();
+
+        if windows.is_empty() {
+            println!("{}", "No windows found.".dimmed());
             return;
         }
-    };
 
-    match response {
-        IpcResponse::Success { data } => {
-            if json {
-                output::print_highlighted_json(&data);
-            } else {
-                // Parse windows from response
-                let windows: Vec<serde_json::Value> =
-                    serde_json::from_value(data).unwrap_or_default();
+        let count = windows.len();
+        println!("{}", format!("Windows ({count})").bold());
+
+        if detailed {
+            let rows: Vec<WindowRowDetailed> = windows
+                .iter()
+                .map(|w| {
+                    let frame = &w["frame"];
+                    WindowRowDetailed {
+                        id: w["id"].as_u64().unwrap_or(0) as u32,
+                        pid: w["pid"].as_i64().unwrap_or(0) as i32,
+                        app: output::truncate(w["appName"].as_str().unwrap_or("?"), 15),
+                        bundle_id: output::truncate(w["appId"].as_str().unwrap_or("?"), 25),
+                        title: output::truncate(
+                            w["title"].as_str().filter(|s| !s.is_empty()).unwrap_or("(no title)"),
+                            25,
+                        ),
+                        workspace: w["workspace"].as_str().unwrap_or("?").to_string(),
+                        frame: format!(
+                            "{}x{} @ {}, {}",
+                            frame["width"].as_f64().unwrap_or(0.0) as u32,
+                            frame["height"].as_f64().unwrap_or(0.0) as u32,
+                            frame["x"].as_f64().unwrap_or(0.0) as i32,
+                            frame["y"].as_f64().unwrap_or(0.0) as i32
+                        ),
+                        minimized: output::format_bool(w["isMinimized"].as_bool().unwrap_or(false)),
+                        floating: output::format_bool(w["isFloating"].as_bool().unwrap_or(false)),
+                        focused: output::format_bool(w["isFocused"].as_bool().unwrap_or(false)),
+                    }
+                })
+                .collect();
+
+            let table = Table::new(rows)
+                .with(Style::rounded())
+                .with(Modify::new(Columns::one(0)).with(Alignment::right()))
+                .with(Modify::new(Columns::one(1)).with(Alignment::right()))
+                .with(Modify::new(Columns::new(7..10)).with(Alignment::center()))
+                .to_string();
+
+            println!("{table}");
+        } else {
+            let rows: Vec<WindowRow> = windows
+                .iter()
+                .map(|w| {
+                    let frame = &w["frame"];
+                    WindowRow {
+                        id: w["id"].as_u64().unwrap_or(0) as u32,
+                        app: output::truncate(w["appName"].as_str().unwrap_or("?"), 20),
+                        title: output::truncate(
+                            w["title"].as_str().filter(|s| !s.is_empty()).unwrap_or("(no title)"),
+                            35,
+                        ),
+                        workspace: w["workspace"].as_str().unwrap_or("?").to_string(),
+                        frame: format!(
+                            "{}x{} @ {}, {}",
+                            frame["width"].as_f64().unwrap_or(0.0) as u32,
+                            frame["height"].as_f64().unwrap_or(0.0) as u32,
+                            frame["x"].as_f64().unwrap_or(0.0) as i32,
+                            frame["y"].as_f64().unwrap_or(0.0) as i32
+                        ),
+                        focused: output::format_bool(w["isFocused"].as_bool().unwrap_or(false)),
+                    }
+                })
+                .collect();
 
-                if windows.is_empty() {
-                    println!("{}", "No windows found.".dimmed());
-                    return;
-                }
+            let table = Table::new(rows)
+                .with(Style::rounded())
+                .with(Modify::new(Columns::one(0)).with(Alignment::right()))
+                .with(Modify::new(Columns::one(4)).with(Alignment::right()))
+                .with(Modify::new(Columns::last()).with(Alignment::center()))
+                .to_string();
 
-                let count = windows.len();
-                println!("{}", format!("Windows ({count})").bold());
-
-                if detailed {
-                    let rows: Vec<WindowRowDetailed> = windows
-                        .iter()
-                        .map(|w| {
-                            let frame = &w["frame"];
-                            WindowRowDetailed {
-                                id: w["id"].as_u64().unwrap_or(0) as u32,
-                                pid: w["pid"].as_i64().unwrap_or(0) as i32,
-                                app: output::truncate(w["appName"].as_str().unwrap_or("?"), 15),
-                                bundle_id: output::truncate(w["appId"].as_str().unwrap_or("?"), 25),
-                                title: output::truncate(
-                                    w["title"]
-                                        .as_str()
-                                        .filter(|s| !s.is_empty())
-                                        .unwrap_or("(no title)"),
-                                    25,
-                                ),
-                                workspace: w["workspace"].as_str().unwrap_or("?").to_string(),
-                                frame: format!(
-                                    "{}x{} @ {}, {}",
-                                    frame["width"].as_f64().unwrap_or(0.0) as u32,
-                                    frame["height"].as_f64().unwrap_or(0.0) as u32,
-                                    frame["x"].as_f64().unwrap_or(0.0) as i32,
-                                    frame["y"].as_f64().unwrap_or(0.0) as i32
-                                ),
-                                minimized: output::format_bool(
-                                    w["isMinimized"].as_bool().unwrap_or(false),
-                                ),
-                                floating: output::format_bool(
-                                    w["isFloating"].as_bool().unwrap_or(false),
-                                ),
-                                focused: output::format_bool(
-                                    w["isFocused"].as_bool().unwrap_or(false),
-                                ),
-                            }
-                        })
-                        .collect();
-
-                    let table = Table::new(rows)
-                        .with(Style::rounded())
-                        .with(Modify::new(Columns::one(0)).with(Alignment::right()))
-                        .with(Modify::new(Columns::one(1)).with(Alignment::right()))
-                        .with(Modify::new(Columns::new(7..10)).with(Alignment::center()))
-                        .to_string();
-
-                    println!("{table}");
-                } else {
-                    let rows: Vec<WindowRow> = windows
-                        .iter()
-                        .map(|w| {
-                            let frame = &w["frame"];
-                            WindowRow {
-                                id: w["id"].as_u64().unwrap_or(0) as u32,
-                                app: output::truncate(w["appName"].as_str().unwrap_or("?"), 20),
-                                title: output::truncate(
-                                    w["title"]
-                                        .as_str()
-                                        .filter(|s| !s.is_empty())
-                                        .unwrap_or("(no title)"),
-                                    35,
-                                ),
-                                workspace: w["workspace"].as_str().unwrap_or("?").to_string(),
-                                frame: format!(
-                                    "{}x{} @ {}, {}",
-                                    frame["width"].as_f64().unwrap_or(0.0) as u32,
-                                    frame["height"].as_f64().unwrap_or(0.0) as u32,
-                                    frame["x"].as_f64().unwrap_or(0.0) as i32,
-                                    frame["y"].as_f64().unwrap_or(0.0) as i32
-                                ),
-                                focused: output::format_bool(
-                                    w["isFocused"].as_bool().unwrap_or(false),
-                                ),
-                            }
-                        })
-                        .collect();
-
-                    let table = Table::new(rows)
-                        .with(Style::rounded())
-                        .with(Modify::new(Columns::one(0)).with(Alignment::right()))
-                        .with(Modify::new(Columns::one(4)).with(Alignment::right()))
-                        .with(Modify::new(Columns::last()).with(Alignment::center()))
-                        .to_string();
-
-                    println!("{table}");
-                }
-            }
-        }
-        IpcResponse::Error { error } => {
-            if json {
-                println!(r#"{{"error":"{error}"}}"#);
-            } else {
-                println!("{} {error}", "Error:".red());
-            }
+            println!("{table}");
         }
-    }
+    })
 }
 
 /// Execute tiling query apps command.
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-fn execute_query_apps(json: bool, _detailed: bool) {
+fn execute_query_apps(json: bool, _detailed: bool) -> Result<(), StacheError> {
     #[derive(Tabled)]
     struct AppRow {
         #[tabled(rename = "PID")]
@@ -671,86 +1045,258 @@ fn execute_query_apps(json: bool, _detailed: bool) {
     }
 
     // Send IPC query to app
-    let response = match ipc_socket::send_query(IpcQuery::Apps) {
-        Ok(r) => r,
-        Err(IpcError::AppNotRunning) => {
-            if json {
-                println!(r#"{{"error":"Stache app is not running"}}"#);
-            } else {
-                println!("{}", "Stache app is not running.".red());
-            }
+    response::print_query_response(ipc_socket::send_query(IpcQuery::Apps), json, |data| {
+        // Parse apps from response
+        let apps: Vec<serde_json::Value> = serde_json::from_value(data).unwrap_or_default();
+
+        if apps.is_empty() {
+            println!("{}", "No running apps found.".dimmed());
             return;
         }
-        Err(e) => {
-            if json {
-                println!(r#"{{"error":"{e}"}}"#);
-            } else {
-                println!("{} {e}", "Error:".red());
-            }
+
+        let count = apps.len();
+        println!("{}", format!("Running Apps ({count})").bold());
+
+        let rows: Vec<AppRow> = apps
+            .iter()
+            .map(|a| AppRow {
+                pid: a["pid"].as_i64().unwrap_or(0) as i32,
+                name: output::truncate(a["name"].as_str().unwrap_or("?"), 25),
+                bundle_id: output::truncate(a["bundleId"].as_str().unwrap_or("?"), 35),
+                visible: output::format_bool(!a["isHidden"].as_bool().unwrap_or(false)),
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .with(Style::rounded())
+            .with(Modify::new(Columns::one(0)).with(Alignment::right()))
+            .with(Modify::new(Columns::last()).with(Alignment::center()))
+            .to_string();
+
+        println!("{table}");
+    })
+}
+
+/// Execute tiling query focused command.
+fn execute_query_focused(json: bool) -> Result<(), StacheError> {
+    response::print_query_response(ipc_socket::send_query(IpcQuery::Focused), json, |data| {
+        print_focused_context(&data);
+    })
+}
+
+/// Prints the `focused` query response as labelled lines.
+fn print_focused_context(data: &serde_json::Value) {
+    let none = "none".dimmed().to_string();
+
+    let window = &data["window"];
+    let window_line = if window.is_null() {
+        none.clone()
+    } else {
+        format!(
+            "{} - {} (#{})",
+            window["appName"].as_str().unwrap_or("?"),
+            output::truncate(window["title"].as_str().unwrap_or(""), 50),
+            window["id"].as_u64().unwrap_or(0)
+        )
+    };
+
+    let workspace = &data["workspace"];
+    let workspace_line = if workspace.is_null() {
+        none.clone()
+    } else {
+        format!(
+            "{} ({})",
+            workspace["name"].as_str().unwrap_or("?"),
+            workspace["layout"].as_str().unwrap_or("?")
+        )
+    };
+
+    let screen_line = data["screen"]["name"].as_str().map_or(none, ToString::to_string);
+
+    println!("{} {window_line}", "Window:   ".bold());
+    println!("{} {workspace_line}", "Workspace:".bold());
+    println!("{} {screen_line}", "Screen:   ".bold());
+}
+
+/// Execute tiling query history command.
+fn execute_query_history(json: bool, limit: Option<usize>) -> Result<(), StacheError> {
+    #[derive(Tabled)]
+    struct HistoryRow {
+        #[tabled(rename = "#")]
+        position: usize,
+        #[tabled(rename = "ID")]
+        id: u64,
+        #[tabled(rename = "App")]
+        app: String,
+        #[tabled(rename = "Title")]
+        title: String,
+        #[tabled(rename = "Workspace")]
+        workspace: String,
+    }
+
+    let query = IpcQuery::History { limit };
+    response::print_query_response(ipc_socket::send_query(query), json, |data| {
+        let windows: Vec<serde_json::Value> = serde_json::from_value(data).unwrap_or_default();
+
+        if windows.is_empty() {
+            println!("{}", "No focus history.".dimmed());
+            return;
+        }
+
+        let rows = windows.iter().enumerate().map(|(index, w)| HistoryRow {
+            position: index + 1,
+            id: w["id"].as_u64().unwrap_or(0),
+            app: output::truncate(w["appName"].as_str().unwrap_or("?"), 20),
+            title: output::truncate(
+                w["title"].as_str().filter(|s| !s.is_empty()).unwrap_or("(no title)"),
+                35,
+            ),
+            workspace: w["workspace"].as_str().unwrap_or("-").to_string(),
+        });
+
+        let table = Table::new(rows)
+            .with(Style::rounded())
+            .with(Modify::new(Columns::new(0..2)).with(Alignment::right()))
+            .to_string();
+
+        println!("{}", format!("Focus history ({})", windows.len()).bold());
+        println!("{table}");
+    })
+}
+
+/// Execute tiling query tree command.
+fn execute_query_tree(json: bool, workspace: Option<String>) -> Result<(), StacheError> {
+    let query = IpcQuery::Tree { workspace };
+    response::print_query_response(ipc_socket::send_query(query), json, |data| {
+        println!(
+            "{}",
+            format!(
+                "{} ({})",
+                data["workspace"].as_str().unwrap_or("?"),
+                data["layout"].as_str().unwrap_or("?")
+            )
+            .bold()
+        );
+
+        if data["tree"].is_null() {
+            println!(
+                "{}",
+                "No split tree (not a dwindle layout or no windows)".dimmed()
+            );
             return;
         }
+
+        for line in render_tree(&data["tree"]) {
+            println!("{line}");
+        }
+    })
+}
+
+/// Execute tiling query dry-run command.
+#[allow(clippy::cast_possible_truncation)]
+fn execute_query_dry_run(
+    json: bool,
+    layout: CliLayoutType,
+    windows: &[u32],
+    frame: [f64; 4],
+) -> Result<(), StacheError> {
+    if layout == CliLayoutType::Cycle {
+        return Err(StacheError::InvalidArguments(
+            "The cycle layout can't be computed. Use a layout name.".to_string(),
+        ));
+    }
+
+    let query = IpcQuery::DryRunLayout {
+        layout: layout.as_str().to_string(),
+        window_ids: windows.to_vec(),
+        frame,
     };
+    response::print_query_response(ipc_socket::send_query(query), json, |data| {
+        let frames = data["frames"].as_array().cloned().unwrap_or_default();
+        if frames.is_empty() {
+            println!("{}", "No frames (floating layout or no windows)".dimmed());
+            return;
+        }
 
-    match response {
-        IpcResponse::Success { data } => {
-            if json {
-                output::print_highlighted_json(&data);
-            } else {
-                // Parse apps from response
-                let apps: Vec<serde_json::Value> = serde_json::from_value(data).unwrap_or_default();
+        for entry in frames {
+            let frame = &entry["frame"];
+            println!(
+                "window #{}: {}x{} @ {}, {}",
+                entry["windowId"].as_u64().unwrap_or(0),
+                frame["width"].as_f64().unwrap_or(0.0) as i32,
+                frame["height"].as_f64().unwrap_or(0.0) as i32,
+                frame["x"].as_f64().unwrap_or(0.0) as i32,
+                frame["y"].as_f64().unwrap_or(0.0) as i32
+            );
+        }
+    })
+}
 
-                if apps.is_empty() {
-                    println!("{}", "No running apps found.".dimmed());
-                    return;
-                }
+/// Renders a split tree node as indented lines with box-drawing connectors.
+fn render_tree(node: &serde_json::Value) -> Vec<String> {
+    fn label(node: &serde_json::Value) -> String {
+        if node["type"] == "split" {
+            format!(
+                "{} {:.2}",
+                node["orientation"].as_str().unwrap_or("?"),
+                node["ratio"].as_f64().unwrap_or(0.5)
+            )
+        } else {
+            format!("window #{}", node["windowId"].as_u64().unwrap_or(0))
+        }
+    }
 
-                let count = apps.len();
-                println!("{}", format!("Running Apps ({count})").bold());
-
-                let rows: Vec<AppRow> = apps
-                    .iter()
-                    .map(|a| AppRow {
-                        pid: a["pid"].as_i64().unwrap_or(0) as i32,
-                        name: output::truncate(a["name"].as_str().unwrap_or("?"), 25),
-                        bundle_id: output::truncate(a["bundleId"].as_str().unwrap_or("?"), 35),
-                        visible: output::format_bool(!a["isHidden"].as_bool().unwrap_or(false)),
-                    })
-                    .collect();
-
-                let table = Table::new(rows)
-                    .with(Style::rounded())
-                    .with(Modify::new(Columns::one(0)).with(Alignment::right()))
-                    .with(Modify::new(Columns::last()).with(Alignment::center()))
-                    .to_string();
-
-                println!("{table}");
-            }
+    fn render_children(node: &serde_json::Value, prefix: &str, lines: &mut Vec<String>) {
+        if node["type"] != "split" {
+            return;
         }
-        IpcResponse::Error { error } => {
-            if json {
-                println!(r#"{{"error":"{error}"}}"#);
+
+        for (child, is_last) in [(&node["first"], false), (&node["second"], true)] {
+            let (connector, indent) = if is_last {
+                ("└── ", "    ")
             } else {
-                println!("{} {error}", "Error:".red());
-            }
+                ("├── ", "│   ")
+            };
+            lines.push(format!("{prefix}{connector}{}", label(child)));
+            render_children(child, &format!("{prefix}{indent}"), lines);
         }
     }
+
+    let mut lines = vec![label(node)];
+    render_children(node, "", &mut lines);
+    lines
 }
 
 /// Execute tiling window commands.
 ///
-/// Operations are executed in order: focus -> swap -> preset -> resize -> send.
+/// Operations are executed in order:
+/// restore -> focus/focus-last/focus-index -> swap -> promote -> move -> preset -> float
+/// -> sticky -> always-on-top -> center/maximize -> resize -> send -> to-scratchpad
+/// -> minimize.
 /// Multiple operations can be combined in a single command.
 #[allow(clippy::useless_let_if_seq)] // Clearer to track operation state this way
 fn execute_window(args: &TilingWindowArgs) -> Result<(), StacheError> {
     let mut has_operation = false;
 
-    // 1. Focus (changes which window we're operating on)
+    // 1. Restore a minimized window (it becomes the focused window)
+    if args.restore {
+        set_window_minimized(false)?;
+        has_operation = true;
+    }
+
+    // 2. Focus (changes which window we're operating on)
     if let Some(target) = &args.focus {
         ipc::send_notification(&StacheNotification::TilingWindowFocus(target.clone()));
         has_operation = true;
+    } else if args.focus_last {
+        ipc::send_notification(&StacheNotification::TilingWindowFocusLast);
+        has_operation = true;
+    } else if let Some(index) = args.focus_index {
+        println!("{}", focus_window_index(index, args.wrap)?);
+        has_operation = true;
     }
 
-    // 2. Swap position with another window
+    // 3. Swap position with another window
     if let Some(direction) = &args.swap {
         ipc::send_notification(&StacheNotification::TilingWindowSwap(
             format!("{direction:?}").to_lowercase(),
@@ -758,54 +1304,110 @@ fn execute_window(args: &TilingWindowArgs) -> Result<(), StacheError> {
         has_operation = true;
     }
 
-    // 3. Apply floating preset
+    // 4. Promote to the first position (master in the master layout)
+    if args.promote {
+        ipc::send_notification(&StacheNotification::TilingWindowPromote);
+        has_operation = true;
+    }
+
+    // 5. Move a floating window, or swap a tiled one
+    if !args.move_window.is_empty() {
+        let (direction, step) = parse_move_args(&args.move_window)?;
+        ipc::send_notification(&StacheNotification::TilingWindowMove { direction, step });
+        has_operation = true;
+    }
+
+    // 6. Apply floating preset
     if let Some(name) = &args.preset {
         ipc::send_notification(&StacheNotification::TilingWindowPreset(name.clone()));
         has_operation = true;
     }
 
-    // 4. Resize (can be multiple, collected as pairs in a flat Vec)
-    if !args.resize.is_empty() {
-        // Process resize args in pairs: [dim1, amt1, dim2, amt2, ...]
-        for pair in args.resize.chunks(2) {
-            if pair.len() == 2 {
-                let dimension = &pair[0];
-                let amount = &pair[1];
-                // Validate dimension and amount
-                if !["width", "height"].contains(&dimension.to_lowercase().as_str()) {
-                    return Err(StacheError::InvalidArguments(format!(
-                        "Invalid resize dimension '{dimension}'. Must be 'width' or 'height'."
-                    )));
-                }
-                let amount_i32: i32 = amount.parse().map_err(|_| {
-                    StacheError::InvalidArguments(format!(
-                        "Invalid resize amount '{amount}'. Must be an integer."
-                    ))
-                })?;
-                ipc::send_notification(&StacheNotification::TilingWindowResize {
-                    dimension: dimension.to_lowercase(),
-                    amount: amount_i32,
-                });
-            }
-        }
+    // 7. Floating state
+    if let Some(action) = &args.float {
+        ipc::send_notification(&StacheNotification::TilingWindowFloat(
+            format!("{action:?}").to_lowercase(),
+        ));
         has_operation = true;
     }
 
-    // 5. Send to screen
-    if let Some(screen) = &args.send_to_screen {
-        ipc::send_notification(&StacheNotification::TilingWindowSendToScreen(screen.clone()));
+    // 8. Sticky state
+    if let Some(action) = &args.sticky {
+        ipc::send_notification(&StacheNotification::TilingWindowSticky(
+            format!("{action:?}").to_lowercase(),
+        ));
         has_operation = true;
     }
 
-    // 6. Send to workspace
-    if let Some(workspace) = &args.send_to_workspace {
-        ipc::send_notification(&StacheNotification::TilingWindowSendToWorkspace(
-            workspace.clone(),
+    // 9. Always-on-top override
+    if let Some(action) = &args.always_on_top {
+        ipc::send_notification(&StacheNotification::TilingWindowAlwaysOnTop(
+            format!("{action:?}").to_lowercase(),
         ));
         has_operation = true;
     }
 
-    if has_operation {
+    // 10. Center or maximize a floating window
+    let placement = if args.center {
+        Some("center")
+    } else if args.maximize {
+        Some("maximize")
+    } else {
+        None
+    };
+    if let Some(placement) = placement {
+        place_focused_window(placement)?;
+        has_operation = true;
+    }
+
+    // 11. Resize (can be multiple, collected in a flat Vec), then grow/shrink by a step
+    if !args.resize.is_empty() {
+        for (dimension, amount) in parse_resize_args(&args.resize)? {
+            resize_focused_window(&dimension, amount)?;
+        }
+        has_operation = true;
+    }
+    for &dimension in &args.grow {
+        resize_focused_window_step(dimension, true)?;
+        has_operation = true;
+    }
+    for &dimension in &args.shrink {
+        resize_focused_window_step(dimension, false)?;
+        has_operation = true;
+    }
+
+    // 12. Send to screen
+    if let Some(screen) = &args.send_to_screen {
+        ipc::send_notification(&StacheNotification::TilingWindowSendToScreen(screen.clone()));
+        has_operation = true;
+    }
+
+    // 13. Send to workspace, optionally following the window
+    let send = args
+        .send_to_workspace
+        .as_ref()
+        .map(|workspace| (workspace, false))
+        .or_else(|| args.send_follow.as_ref().map(|workspace| (workspace, true)));
+    if let Some((workspace, follow)) = send {
+        let (workspace, visible) = send_window_to_workspace(workspace, follow)?;
+        let state = if visible { "shown" } else { "hidden" };
+        println!("Sent window to '{workspace}' ({state})");
+        has_operation = true;
+    }
+
+    // 14. Move to the scratchpad
+    if args.to_scratchpad {
+        ipc::send_notification(&StacheNotification::TilingWindowToScratchpad);
+        has_operation = true;
+    }
+
+    // 15. Minimize
+    if args.minimize {
+        set_window_minimized(true)?;
+        has_operation = true;
+    }
+
+    if has_operation {
         Ok(())
     } else {
         Err(StacheError::InvalidArguments(
@@ -814,38 +1416,587 @@ fn execute_window(args: &TilingWindowArgs) -> Result<(), StacheError> {
     }
 }
 
+/// Validates a `--masters` value: `+N`, `-N` or `N`.
+fn parse_master_count(value: &str) -> Result<String, String> {
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("invalid master count '{value}'. Use +N, -N or N."));
+    }
+    Ok(value.to_string())
+}
+
+/// Parses a `--ratio` value: `INDEX:RATIO`, with the ratio between 0.0 and 1.0.
+fn parse_split_ratio(value: &str) -> Result<(usize, f64), String> {
+    let invalid = || format!("invalid split ratio '{value}'. Use INDEX:RATIO, e.g. 0:0.3.");
+
+    let (index, ratio) = value.split_once(':').ok_or_else(invalid)?;
+    let index = index.trim().parse::<usize>().map_err(|_| invalid())?;
+    let ratio = ratio
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|ratio| (0.0..=1.0).contains(ratio))
+        .ok_or_else(invalid)?;
+
+    Ok((index, ratio))
+}
+
+/// Parses a `--frame` value: `X,Y,WIDTH,HEIGHT`, with a positive size.
+fn parse_frame(value: &str) -> Result<[f64; 4], String> {
+    let invalid = || format!("invalid frame '{value}'. Use X,Y,WIDTH,HEIGHT, e.g. 0,0,1920,1080.");
+
+    let parts = value
+        .split(',')
+        .map(|part| part.trim().parse::<f64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let frame: [f64; 4] = parts.try_into().map_err(|_| invalid())?;
+    if frame[2] <= 0.0 || frame[3] <= 0.0 {
+        return Err(invalid());
+    }
+
+    Ok(frame)
+}
+
+/// Parses `--move` values into a direction and an optional step in pixels.
+fn parse_move_args(values: &[String]) -> Result<(String, Option<u32>), StacheError> {
+    let direction = values.first().map(|d| d.trim().to_lowercase()).unwrap_or_default();
+    if !["up", "down", "left", "right"].contains(&direction.as_str()) {
+        return Err(StacheError::InvalidArguments(format!(
+            "Invalid move direction '{direction}'. Must be 'up', 'down', 'left' or 'right'."
+        )));
+    }
+
+    let step = values
+        .get(1)
+        .map(|pixels| {
+            pixels.trim().parse::<u32>().ok().filter(|&step| step > 0).ok_or_else(|| {
+                StacheError::InvalidArguments(format!(
+                    "Invalid move distance '{pixels}'. Must be a positive integer."
+                ))
+            })
+        })
+        .transpose()?;
+
+    Ok((direction, step))
+}
+
+/// Parses `--resize` values into `(dimension, amount)` pairs.
+///
+/// Accepts both the `DIMENSION AMOUNT` form and the `DIMENSION:AMOUNT` form,
+/// which may be mixed across multiple `--resize` flags.
+fn parse_resize_args(values: &[String]) -> Result<Vec<(String, i32)>, StacheError> {
+    let mut resizes = Vec::new();
+    let mut iter = values.iter();
+
+    while let Some(value) = iter.next() {
+        let (dimension, amount) = match value.split_once(':') {
+            Some((dimension, amount)) => (dimension, amount),
+            None => {
+                let amount = iter.next().ok_or_else(|| {
+                    StacheError::InvalidArguments(format!(
+                        "Missing resize amount for '{value}'. Use 'width 40' or 'width:+40'."
+                    ))
+                })?;
+                (value.as_str(), amount.as_str())
+            }
+        };
+
+        let dimension = dimension.trim().to_lowercase();
+        if !["width", "height"].contains(&dimension.as_str()) {
+            return Err(StacheError::InvalidArguments(format!(
+                "Invalid resize dimension '{dimension}'. Must be 'width' or 'height'."
+            )));
+        }
+
+        let amount_i32: i32 = amount.trim().parse().map_err(|_| {
+            StacheError::InvalidArguments(format!(
+                "Invalid resize amount '{amount}'. Must be an integer."
+            ))
+        })?;
+
+        resizes.push((dimension, amount_i32));
+    }
+
+    Ok(resizes)
+}
+
+/// Resizes the focused window over the IPC socket.
+///
+/// Errors when the app reports the resize as ignored (e.g., monocle layout)
+/// or failed, so scripts can distinguish it from a successful resize.
+fn resize_focused_window(dimension: &str, amount: i32) -> Result<(), StacheError> {
+    let query = IpcQuery::ResizeWindow {
+        dimension: dimension.to_string(),
+        delta: amount,
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { .. }) => Ok(()),
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Grows or shrinks the focused window by `tiling.resizeStep` over the IPC socket.
+///
+/// Errors like [`resize_focused_window`] when the resize is ignored or fails.
+fn resize_focused_window_step(dimension: ResizeDimension, grow: bool) -> Result<(), StacheError> {
+    let query = IpcQuery::ResizeWindowStep {
+        dimension: dimension.as_str().to_string(),
+        grow,
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { .. }) => Ok(()),
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Sets a split ratio of the focused workspace over the IPC socket.
+///
+/// Errors when the layout has no adjustable splits or the index doesn't exist.
+fn set_split_ratio(index: usize, ratio: f64) -> Result<(), StacheError> {
+    let query = IpcQuery::SetSplitRatio { index, ratio };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { .. }) => Ok(()),
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Centers or maximizes the focused floating window over the IPC socket.
+///
+/// Errors when the focused window is tiled.
+fn place_focused_window(placement: &str) -> Result<(), StacheError> {
+    let query = IpcQuery::PlaceWindow {
+        placement: placement.to_string(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { .. }) => Ok(()),
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Focuses the window at a 1-based position in the focused workspace over the
+/// IPC socket.
+///
+/// Returns the ID of the focused window.
+fn focus_window_index(index: u32, wraps: bool) -> Result<u64, StacheError> {
+    let query = IpcQuery::FocusWindowIndex { index: index as usize, wraps };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => Ok(data["windowId"].as_u64().unwrap_or_default()),
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Minimizes the focused window, or restores a minimized window of the
+/// focused workspace, over the IPC socket.
+fn set_window_minimized(minimized: bool) -> Result<(), StacheError> {
+    let query = IpcQuery::MinimizeWindow { minimized };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { .. }) => Ok(()),
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Cascades or tiles the floating windows of the focused workspace over the
+/// IPC socket.
+///
+/// Errors when the workspace has no floating windows.
+fn arrange_floating_windows(arrangement: &str) -> Result<(), StacheError> {
+    let query = IpcQuery::ArrangeFloating {
+        arrangement: arrangement.to_string(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { .. }) => Ok(()),
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Sends the focused window to a workspace over the IPC socket.
+///
+/// Returns the workspace name and whether the window is visible afterwards.
+fn send_window_to_workspace(workspace: &str, follow: bool) -> Result<(String, bool), StacheError> {
+    let query = IpcQuery::SendWindow {
+        workspace: workspace.to_string(),
+        follow,
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => Ok((
+            data["workspace"].as_str().unwrap_or(workspace).to_string(),
+            data["visible"].as_bool().unwrap_or(follow),
+        )),
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Cycles workspaces on the focused screen over the IPC socket.
+///
+/// Returns the name of the workspace switched to. Errors when the focused
+/// screen has no other workspace.
+fn cycle_workspace(direction: CycleDirection) -> Result<String, StacheError> {
+    let query = IpcQuery::CycleWorkspace {
+        direction: direction.as_str().to_string(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            Ok(data["workspace"].as_str().unwrap_or_default().to_string())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Switches back to the previous workspace on the focused screen over the IPC socket.
+///
+/// Returns the name of the workspace switched to. Errors when the focused
+/// screen has no previous workspace.
+fn focus_last_workspace() -> Result<String, StacheError> {
+    match ipc_socket::send_query(IpcQuery::FocusLastWorkspace) {
+        Ok(IpcResponse::Success { data }) => {
+            Ok(data["workspace"].as_str().unwrap_or_default().to_string())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Moves the focused workspace's windows into `target` over the IPC socket.
+fn merge_workspace(target: &str, follow: bool) -> Result<(), StacheError> {
+    let query = IpcQuery::MergeWorkspace {
+        target: target.to_string(),
+        follow,
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            let moved = data["moved"].as_u64().unwrap_or_default();
+            let source = data["source"].as_str().unwrap_or_default();
+            let target = data["target"].as_str().unwrap_or_default();
+            let state = if data["visible"].as_bool().unwrap_or_default() {
+                "shown"
+            } else {
+                "hidden"
+            };
+            println!("Moved {moved} window(s) from '{source}' to '{target}' ({state})");
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Copies the layout of a workspace to the focused one over the IPC socket,
+/// or swaps the layouts of the two.
+fn copy_layout(workspace: &str, swap: bool) -> Result<(), StacheError> {
+    let query = IpcQuery::CopyLayout {
+        workspace: workspace.to_string(),
+        swap,
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            let source = data["source"].as_str().unwrap_or_default();
+            let target = data["target"].as_str().unwrap_or_default();
+            if swap {
+                println!("Swapped layouts of '{target}' and '{source}'");
+            } else {
+                println!("Copied layout of '{source}' to '{target}'");
+            }
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Restores tracked windows to their original frames over the IPC socket.
+fn execute_restore() -> Result<(), StacheError> {
+    match ipc_socket::send_query(IpcQuery::RestoreWindows) {
+        Ok(IpcResponse::Success { data }) => {
+            let restored = data["restored"].as_u64().unwrap_or_default();
+            println!("Restored {restored} window(s) to their original frames");
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Resyncs tiling with the system's windows over the IPC socket.
+fn execute_retile() -> Result<(), StacheError> {
+    match ipc_socket::send_query(IpcQuery::Retile) {
+        Ok(IpcResponse::Success { data }) => {
+            let tracked = data["tracked"].as_u64().unwrap_or_default();
+            let untracked = data["untracked"].as_u64().unwrap_or_default();
+            println!(
+                "Retiled: tracked {tracked} new window(s), untracked {untracked} closed window(s)"
+            );
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Switches the gap preset over the IPC socket and prints the active one.
+fn execute_gaps(preset: &str) -> Result<(), StacheError> {
+    let query = IpcQuery::SetGapPreset { preset: preset.to_string() };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            match data["preset"].as_str() {
+                Some(preset) => println!("Using gap preset '{preset}'"),
+                None => println!("Using the configured gaps"),
+            }
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Creates a workspace on the focused screen over the IPC socket.
+fn create_workspace(name: &str) -> Result<(), StacheError> {
+    let query = IpcQuery::CreateWorkspace { name: name.to_string() };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            let workspace = data["workspace"].as_str().unwrap_or_default();
+            let screen = data["screen"].as_str().unwrap_or_default();
+            println!("Created workspace '{workspace}' on {screen}");
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Renames a workspace over the IPC socket.
+///
+/// Renames the focused workspace when `workspace` is `None`.
+fn rename_workspace(workspace: Option<&str>, name: &str) -> Result<(), StacheError> {
+    let query = IpcQuery::RenameWorkspace {
+        workspace: workspace.map(str::to_string),
+        name: name.to_string(),
+    };
+
+    match ipc_socket::send_query(query) {
+        Ok(IpcResponse::Success { data }) => {
+            let previous = data["previousName"].as_str().unwrap_or_default();
+            let workspace = data["workspace"].as_str().unwrap_or_default();
+            println!("Renamed workspace '{previous}' to '{workspace}'");
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
+/// Claims the focused window's app for the focused workspace over the IPC
+/// socket, or releases the claim.
+fn claim_app(claim: bool) -> Result<(), StacheError> {
+    match ipc_socket::send_query(IpcQuery::ClaimApp { claim }) {
+        Ok(IpcResponse::Success { data }) => {
+            let workspace = data["workspace"].as_str().unwrap_or_default();
+            let app_id = data["appId"].as_str().unwrap_or_default();
+            let app_name = data["appName"].as_str().unwrap_or_default();
+            if claim {
+                let rule = serde_json::json!({ "appId": app_id });
+                println!("Claimed {app_id} ({app_name}) for '{workspace}' (rule: {rule})");
+            } else {
+                println!("Released {app_id} ({app_name}) from '{workspace}'");
+            }
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::TilingError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
 /// Execute tiling workspace commands.
 ///
-/// Operations are executed in order: focus -> layout -> balance -> send.
+/// Operations are executed in order:
+/// create -> rename -> focus/focus-last -> cycle -> claim/unclaim -> layout
+/// -> copy-layout-from/swap-layout -> masters -> ratio -> scroll -> balance
+/// -> cascade/tile-floating -> send -> merge.
 /// Multiple operations can be combined in a single command.
 #[allow(clippy::useless_let_if_seq)] // Clearer to track operation state this way
 fn execute_workspace(args: &TilingWorkspaceArgs) -> Result<(), StacheError> {
     let mut has_operation = false;
 
-    // 1. Focus workspace (switch to it first)
+    // 1. Create workspace (so it can be focused right away)
+    if let Some(name) = &args.create {
+        create_workspace(name)?;
+        has_operation = true;
+    }
+
+    // 2. Rename workspace (the one to focus, if given, so focusing uses the new name)
+    if let Some(name) = &args.rename {
+        rename_workspace(args.focus.as_deref(), name)?;
+        has_operation = true;
+    }
+
+    // 3. Focus workspace, or go back to the previous one (waits for the switch)
     if let Some(workspace) = &args.focus {
-        ipc::send_notification(&StacheNotification::TilingFocusWorkspace(workspace.clone()));
+        let workspace = args.rename.as_deref().map_or(workspace.as_str(), str::trim);
+        ipc::send_notification(&StacheNotification::TilingFocusWorkspace(workspace.to_string()));
+        has_operation = true;
+    } else if args.focus_last {
+        let name = focus_last_workspace()?;
+        println!("{name}");
+        has_operation = true;
+    }
+
+    // 4. Cycle workspaces (waits for the switch before later operations)
+    if let Some(direction) = args.cycle {
+        let name = cycle_workspace(direction)?;
+        println!("{name}");
+        has_operation = true;
+    }
+
+    // 5. Claim or release the focused app for the focused workspace
+    if args.claim_app || args.unclaim_app {
+        claim_app(args.claim_app)?;
         has_operation = true;
     }
 
-    // 2. Change layout
+    // 6. Change layout
     if let Some(layout) = &args.layout {
         ipc::send_notification(&StacheNotification::TilingSetLayout(layout.as_str().to_string()));
         has_operation = true;
     }
 
-    // 3. Balance windows
+    // 7. Copy or swap the layout of another workspace
+    let copy = args
+        .copy_layout_from
+        .as_ref()
+        .map(|workspace| (workspace, false))
+        .or_else(|| args.swap_layout.as_ref().map(|workspace| (workspace, true)));
+    if let Some((workspace, swap)) = copy {
+        copy_layout(workspace, swap)?;
+        has_operation = true;
+    }
+
+    // 8. Change master count
+    if let Some(masters) = &args.masters {
+        ipc::send_notification(&StacheNotification::TilingWorkspaceMasters(masters.clone()));
+        has_operation = true;
+    }
+
+    // 9. Set a split ratio
+    if let Some((index, ratio)) = args.ratio {
+        set_split_ratio(index, ratio)?;
+        has_operation = true;
+    }
+
+    // 10. Scroll the workspace
+    if let Some(direction) = &args.scroll {
+        ipc::send_notification(&StacheNotification::TilingWorkspaceScroll(
+            format!("{direction:?}").to_lowercase(),
+        ));
+        has_operation = true;
+    }
+
+    // 11. Balance windows
     if args.balance {
         ipc::send_notification(&StacheNotification::TilingWorkspaceBalance);
         has_operation = true;
     }
 
-    // 4. Send to screen
+    // 12. Cascade or tile the floating windows
+    if args.cascade {
+        arrange_floating_windows("cascade")?;
+        has_operation = true;
+    } else if args.tile_floating {
+        arrange_floating_windows("grid")?;
+        has_operation = true;
+    }
+
+    // 13. Send to screen
     if let Some(screen) = &args.send_to_screen {
         ipc::send_notification(&StacheNotification::TilingWorkspaceSendToScreen(screen.clone()));
         has_operation = true;
     }
 
+    // 14. Merge into another workspace, optionally following the windows
+    let merge = args
+        .merge_into
+        .as_ref()
+        .map(|target| (target, false))
+        .or_else(|| args.merge_follow.as_ref().map(|target| (target, true)));
+    if let Some((target, follow)) = merge {
+        merge_workspace(target, follow)?;
+        has_operation = true;
+    }
+
     if has_operation {
         Ok(())
     } else {
@@ -868,6 +2019,31 @@ mod tests {
         command: TilingCommands,
     }
 
+    #[test]
+    fn test_tiling_restore_parse() {
+        let cli = TestCli::try_parse_from(["test", "restore"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Restore));
+
+        assert!(TestCli::try_parse_from(["test", "restore", "--all"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_retile_parse() {
+        let cli = TestCli::try_parse_from(["test", "retile"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Retile));
+    }
+
+    #[test]
+    fn test_tiling_gaps_parse() {
+        let cli = TestCli::try_parse_from(["test", "gaps", "dense"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Gaps { ref preset } if preset == "dense"));
+
+        let cli = TestCli::try_parse_from(["test", "gaps", "cycle"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Gaps { ref preset } if preset == "cycle"));
+
+        assert!(TestCli::try_parse_from(["test", "gaps"]).is_err());
+    }
+
     // ========================================================================
     // Query command parsing tests
     // ========================================================================
@@ -876,34 +2052,53 @@ mod tests {
     fn test_tiling_query_screens_parse() {
         let cli = TestCli::try_parse_from(["test", "query", "screens"]).unwrap();
         match cli.command {
-            TilingCommands::Query { json, detailed, command } => {
-                assert!(!json);
+            TilingCommands::Query { detailed, command } => {
                 assert!(!detailed);
-                assert!(matches!(command, Some(TilingQueryCommands::Screens)));
+                assert!(matches!(
+                    command,
+                    Some(TilingQueryCommands::Screens { main: false, screen: None })
+                ));
             }
             _ => panic!("Expected Query command"),
         }
     }
 
     #[test]
-    fn test_tiling_query_screens_json_parse() {
-        let cli = TestCli::try_parse_from(["test", "query", "--json", "screens"]).unwrap();
+    fn test_tiling_query_screens_filter_parse() {
+        let cli = TestCli::try_parse_from(["test", "query", "screens", "--main"]).unwrap();
         match cli.command {
-            TilingCommands::Query { json, detailed, command } => {
-                assert!(json);
-                assert!(!detailed);
-                assert!(matches!(command, Some(TilingQueryCommands::Screens)));
+            TilingCommands::Query { command, .. } => {
+                assert!(matches!(
+                    command,
+                    Some(TilingQueryCommands::Screens { main: true, screen: None })
+                ));
             }
             _ => panic!("Expected Query command"),
         }
-    }
 
-    #[test]
-    fn test_tiling_query_workspaces_parse() {
-        let cli = TestCli::try_parse_from(["test", "query", "workspaces"]).unwrap();
+        let cli =
+            TestCli::try_parse_from(["test", "query", "screens", "--screen", "secondary"]).unwrap();
         match cli.command {
-            TilingCommands::Query { json, detailed, command } => {
-                assert!(!json);
+            TilingCommands::Query { command, .. } => match command {
+                Some(TilingQueryCommands::Screens { main, screen }) => {
+                    assert!(!main);
+                    assert_eq!(screen, Some("secondary".to_string()));
+                }
+                _ => panic!("Expected Screens query"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+
+        let result =
+            TestCli::try_parse_from(["test", "query", "screens", "--main", "--screen", "built-in"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tiling_query_workspaces_parse() {
+        let cli = TestCli::try_parse_from(["test", "query", "workspaces"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { detailed, command } => {
                 assert!(!detailed);
                 match command {
                     Some(TilingQueryCommands::Workspaces { focused_screen, screen }) => {
@@ -959,11 +2154,43 @@ mod tests {
                     focused_workspace,
                     screen,
                     workspace,
+                    sort,
                 }) => {
                     assert!(!focused_screen);
                     assert!(!focused_workspace);
                     assert!(screen.is_none());
                     assert!(workspace.is_none());
+                    assert!(sort.is_none());
+                }
+                _ => panic!("Expected Windows command"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_query_windows_focused_workspace_parse() {
+        let cli =
+            TestCli::try_parse_from(["test", "query", "windows", "--focused-workspace"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { command, .. } => match command {
+                Some(TilingQueryCommands::Windows { focused_workspace, .. }) => {
+                    assert!(focused_workspace);
+                }
+                _ => panic!("Expected Windows command"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_query_windows_workspace_parse() {
+        let cli =
+            TestCli::try_parse_from(["test", "query", "windows", "--workspace", "coding"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { command, .. } => match command {
+                Some(TilingQueryCommands::Windows { workspace, .. }) => {
+                    assert_eq!(workspace, Some("coding".to_string()));
                 }
                 _ => panic!("Expected Windows command"),
             },
@@ -972,168 +2199,585 @@ mod tests {
     }
 
     #[test]
-    fn test_tiling_query_windows_focused_workspace_parse() {
-        let cli =
-            TestCli::try_parse_from(["test", "query", "windows", "--focused-workspace"]).unwrap();
+    fn test_tiling_query_windows_sort_parse() {
+        let cli =
+            TestCli::try_parse_from(["test", "query", "windows", "--sort", "recent"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { command, .. } => match command {
+                Some(TilingQueryCommands::Windows { sort, .. }) => {
+                    assert_eq!(sort, Some(WindowSort::Recent));
+                }
+                _ => panic!("Expected Windows command"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "query", "windows", "--sort", "size"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_query_apps_parse() {
+        let cli = TestCli::try_parse_from(["test", "query", "apps"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { detailed, command } => {
+                assert!(!detailed);
+                assert!(matches!(command, Some(TilingQueryCommands::Apps)));
+            }
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_query_focused_parse() {
+        let cli = TestCli::try_parse_from(["test", "query", "focused"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { command, .. } => {
+                assert!(matches!(command, Some(TilingQueryCommands::Focused)));
+            }
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_query_dry_run_parse() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "query",
+            "dry-run",
+            "--layout",
+            "split-vertical",
+            "--windows",
+            "1,2,3",
+            "--frame",
+            "0,25,1920,1055",
+        ])
+        .unwrap();
+        match cli.command {
+            TilingCommands::Query {
+                command: Some(TilingQueryCommands::DryRun { layout, windows, frame }),
+                ..
+            } => {
+                assert_eq!(layout, CliLayoutType::SplitVertical);
+                assert_eq!(windows, [1, 2, 3]);
+                assert_eq!(frame.to_vec(), [0.0, 25.0, 1920.0, 1055.0]);
+            }
+            _ => panic!("Expected Query dry-run command"),
+        }
+
+        // Windows are required
+        assert!(
+            TestCli::try_parse_from([
+                "test",
+                "query",
+                "dry-run",
+                "--layout",
+                "grid",
+                "--frame",
+                "0,0,800,600"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_frame() {
+        assert_eq!(parse_frame("0, 0, 800, 600").unwrap().to_vec(), [
+            0.0, 0.0, 800.0, 600.0
+        ]);
+        assert!(parse_frame("0,0,800").is_err());
+        assert!(parse_frame("0,0,800,0").is_err());
+        assert!(parse_frame("0,0,wide,600").is_err());
+    }
+
+    #[test]
+    fn test_tiling_query_history_parse() {
+        let cli = TestCli::try_parse_from(["test", "query", "history"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { command, .. } => {
+                assert!(matches!(
+                    command,
+                    Some(TilingQueryCommands::History { limit: None })
+                ));
+            }
+            _ => panic!("Expected Query command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "query", "history", "--limit", "5"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { command, .. } => {
+                assert!(matches!(
+                    command,
+                    Some(TilingQueryCommands::History { limit: Some(5) })
+                ));
+            }
+            _ => panic!("Expected Query command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "query", "history", "--limit", "few"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_query_tree_parse() {
+        let cli = TestCli::try_parse_from(["test", "query", "tree"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { command, .. } => {
+                assert!(matches!(
+                    command,
+                    Some(TilingQueryCommands::Tree { workspace: None })
+                ));
+            }
+            _ => panic!("Expected Query command"),
+        }
+
+        let cli =
+            TestCli::try_parse_from(["test", "query", "tree", "--workspace", "coding"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { command, .. } => match command {
+                Some(TilingQueryCommands::Tree { workspace }) => {
+                    assert_eq!(workspace, Some("coding".to_string()));
+                }
+                _ => panic!("Expected Tree command"),
+            },
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let tree = serde_json::json!({
+            "type": "split",
+            "orientation": "horizontal",
+            "ratio": 0.6,
+            "first": { "type": "leaf", "windowId": 1 },
+            "second": {
+                "type": "split",
+                "orientation": "vertical",
+                "ratio": 0.5,
+                "first": { "type": "leaf", "windowId": 2 },
+                "second": { "type": "leaf", "windowId": 3 },
+            },
+        });
+
+        assert_eq!(render_tree(&tree), vec![
+            "horizontal 0.60",
+            "├── window #1",
+            "└── vertical 0.50",
+            "    ├── window #2",
+            "    └── window #3",
+        ]);
+    }
+
+    #[test]
+    fn test_render_tree_single_window() {
+        let tree = serde_json::json!({ "type": "leaf", "windowId": 7 });
+        assert_eq!(render_tree(&tree), vec!["window #7"]);
+    }
+
+    #[test]
+    fn test_tiling_query_detailed_flag_parse() {
+        let cli = TestCli::try_parse_from(["test", "query", "-d", "windows"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { detailed, command } => {
+                assert!(detailed);
+                assert!(matches!(command, Some(TilingQueryCommands::Windows { .. })));
+            }
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_query_detailed_long_flag_parse() {
+        let cli = TestCli::try_parse_from(["test", "query", "--detailed", "apps"]).unwrap();
+        match cli.command {
+            TilingCommands::Query { detailed, command } => {
+                assert!(detailed);
+                assert!(matches!(command, Some(TilingQueryCommands::Apps)));
+            }
+            _ => panic!("Expected Query command"),
+        }
+    }
+
+    // ========================================================================
+    // Window command parsing tests
+    // ========================================================================
+
+    #[test]
+    fn test_tiling_window_focus_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--focus", "left"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.focus, Some("left".to_string()));
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_window_focus_last_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--focus-last"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert!(args.focus_last);
+                assert_eq!(args.focus, None);
+            }
+            _ => panic!("Expected Window command"),
+        }
+
+        assert!(
+            TestCli::try_parse_from(["test", "window", "--focus-last", "--focus", "left"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_tiling_window_focus_index_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--focus-index", "2"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.focus_index, Some(2));
+                assert!(!args.wrap);
+            }
+            _ => panic!("Expected Window command"),
+        }
+
+        let cli =
+            TestCli::try_parse_from(["test", "window", "--focus-index", "9", "--wrap"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.wrap),
+            _ => panic!("Expected Window command"),
+        }
+
+        // --wrap only applies to --focus-index
+        assert!(TestCli::try_parse_from(["test", "window", "--wrap"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "window", "--focus-index", "0"]).is_err());
+        let result =
+            TestCli::try_parse_from(["test", "window", "--focus-index", "1", "--focus", "left"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tiling_window_swap_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--swap", "down"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.swap, Some(Direction::Down));
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_window_promote_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--promote"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.promote),
+            _ => panic!("Expected Window command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "window", "--swap", "left"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(!args.promote),
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_window_preset_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--preset", "center"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.preset, Some("center".to_string()));
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_window_resize_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--resize", "width", "100"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.resize, vec!["width", "100"]);
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_window_resize_multiple_parse() {
+        let cli = TestCli::try_parse_from([
+            "test", "window", "--resize", "width", "100", "--resize", "height", "50",
+        ])
+        .unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.resize, vec!["width", "100", "height", "50"]);
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_window_resize_negative_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--resize", "width", "-50"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.resize, vec!["width", "-50"]);
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_window_resize_colon_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--resize", "width:+40"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.resize, vec!["width:+40"]);
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_resize_args_pairs_and_colon_form() {
+        let values: Vec<String> = ["width", "100", "height:-50", "WIDTH:+40"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let resizes = parse_resize_args(&values).unwrap();
+        assert_eq!(resizes, vec![
+            ("width".to_string(), 100),
+            ("height".to_string(), -50),
+            ("width".to_string(), 40),
+        ]);
+    }
+
+    #[test]
+    fn test_tiling_window_grow_shrink_parse() {
+        let cli = TestCli::try_parse_from([
+            "test", "window", "--grow", "width", "--grow", "height", "--shrink", "height",
+        ])
+        .unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.grow, vec![ResizeDimension::Width, ResizeDimension::Height]);
+                assert_eq!(args.shrink, vec![ResizeDimension::Height]);
+            }
+            _ => panic!("Expected Window command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--grow", "depth"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_resize_args_invalid() {
+        let to_vec = |values: &[&str]| values.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert!(parse_resize_args(&to_vec(&["depth:40"])).is_err());
+        assert!(parse_resize_args(&to_vec(&["width:abc"])).is_err());
+        assert!(parse_resize_args(&to_vec(&["width"])).is_err());
+    }
+
+    #[test]
+    fn test_tiling_window_move_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--move", "left", "20"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.move_window, vec!["left", "20"]);
+                assert_eq!(
+                    parse_move_args(&args.move_window).unwrap(),
+                    ("left".to_string(), Some(20))
+                );
+            }
+            _ => panic!("Expected Window command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "window", "--move", "Up"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(
+                    parse_move_args(&args.move_window).unwrap(),
+                    ("up".to_string(), None)
+                );
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_move_args_invalid() {
+        let to_vec = |values: &[&str]| values.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert!(parse_move_args(&to_vec(&["next"])).is_err());
+        assert!(parse_move_args(&to_vec(&["left", "0"])).is_err());
+        assert!(parse_move_args(&to_vec(&["left", "-10"])).is_err());
+        assert!(parse_move_args(&to_vec(&["left", "abc"])).is_err());
+    }
+
+    #[test]
+    fn test_tiling_window_send_to_screen_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--send-to-screen", "main"]).unwrap();
         match cli.command {
-            TilingCommands::Query { command, .. } => match command {
-                Some(TilingQueryCommands::Windows { focused_workspace, .. }) => {
-                    assert!(focused_workspace);
-                }
-                _ => panic!("Expected Windows command"),
-            },
-            _ => panic!("Expected Query command"),
+            TilingCommands::Window(args) => {
+                assert_eq!(args.send_to_screen, Some("main".to_string()));
+            }
+            _ => panic!("Expected Window command"),
         }
     }
 
     #[test]
-    fn test_tiling_query_windows_workspace_parse() {
+    fn test_tiling_window_send_to_workspace_parse() {
         let cli =
-            TestCli::try_parse_from(["test", "query", "windows", "--workspace", "coding"]).unwrap();
+            TestCli::try_parse_from(["test", "window", "--send-to-workspace", "coding"]).unwrap();
         match cli.command {
-            TilingCommands::Query { command, .. } => match command {
-                Some(TilingQueryCommands::Windows { workspace, .. }) => {
-                    assert_eq!(workspace, Some("coding".to_string()));
-                }
-                _ => panic!("Expected Windows command"),
-            },
-            _ => panic!("Expected Query command"),
+            TilingCommands::Window(args) => {
+                assert_eq!(args.send_to_workspace, Some("coding".to_string()));
+            }
+            _ => panic!("Expected Window command"),
         }
     }
 
     #[test]
-    fn test_tiling_query_apps_parse() {
-        let cli = TestCli::try_parse_from(["test", "query", "apps"]).unwrap();
+    fn test_tiling_window_send_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--send", "coding"]).unwrap();
         match cli.command {
-            TilingCommands::Query { json, detailed, command } => {
-                assert!(!json);
-                assert!(!detailed);
-                assert!(matches!(command, Some(TilingQueryCommands::Apps)));
+            TilingCommands::Window(args) => {
+                assert_eq!(args.send_to_workspace, Some("coding".to_string()));
+                assert_eq!(args.send_follow, None);
             }
-            _ => panic!("Expected Query command"),
+            _ => panic!("Expected Window command"),
         }
-    }
 
-    #[test]
-    fn test_tiling_query_detailed_flag_parse() {
-        let cli = TestCli::try_parse_from(["test", "query", "-d", "windows"]).unwrap();
+        let cli = TestCli::try_parse_from(["test", "window", "--send-follow", "coding"]).unwrap();
         match cli.command {
-            TilingCommands::Query { json, detailed, command } => {
-                assert!(!json);
-                assert!(detailed);
-                assert!(matches!(command, Some(TilingQueryCommands::Windows { .. })));
+            TilingCommands::Window(args) => {
+                assert_eq!(args.send_to_workspace, None);
+                assert_eq!(args.send_follow, Some("coding".to_string()));
             }
-            _ => panic!("Expected Query command"),
+            _ => panic!("Expected Window command"),
         }
     }
 
     #[test]
-    fn test_tiling_query_detailed_long_flag_parse() {
-        let cli = TestCli::try_parse_from(["test", "query", "--detailed", "apps"]).unwrap();
-        match cli.command {
-            TilingCommands::Query { json, detailed, command } => {
-                assert!(!json);
-                assert!(detailed);
-                assert!(matches!(command, Some(TilingQueryCommands::Apps)));
-            }
-            _ => panic!("Expected Query command"),
-        }
+    fn test_tiling_window_send_and_send_follow_conflict() {
+        let result = TestCli::try_parse_from([
+            "test",
+            "window",
+            "--send",
+            "coding",
+            "--send-follow",
+            "browser",
+        ]);
+        assert!(result.is_err());
     }
 
-    // ========================================================================
-    // Window command parsing tests
-    // ========================================================================
-
     #[test]
-    fn test_tiling_window_focus_parse() {
-        let cli = TestCli::try_parse_from(["test", "window", "--focus", "left"]).unwrap();
+    fn test_tiling_window_to_scratchpad_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--to-scratchpad"]).unwrap();
         match cli.command {
-            TilingCommands::Window(args) => {
-                assert_eq!(args.focus, Some("left".to_string()));
-            }
+            TilingCommands::Window(args) => assert!(args.to_scratchpad),
             _ => panic!("Expected Window command"),
         }
+
+        let result =
+            TestCli::try_parse_from(["test", "window", "--to-scratchpad", "--send", "coding"]);
+        assert!(result.is_err());
     }
 
     #[test]
-    fn test_tiling_window_swap_parse() {
-        let cli = TestCli::try_parse_from(["test", "window", "--swap", "down"]).unwrap();
+    fn test_tiling_window_minimize_restore_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--minimize"]).unwrap();
         match cli.command {
-            TilingCommands::Window(args) => {
-                assert_eq!(args.swap, Some(Direction::Down));
-            }
+            TilingCommands::Window(args) => assert!(args.minimize && !args.restore),
             _ => panic!("Expected Window command"),
         }
+
+        let cli = TestCli::try_parse_from(["test", "window", "--restore"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.restore && !args.minimize),
+            _ => panic!("Expected Window command"),
+        }
+
+        let result = TestCli::try_parse_from(["test", "window", "--minimize", "--restore"]);
+        assert!(result.is_err());
     }
 
     #[test]
-    fn test_tiling_window_preset_parse() {
-        let cli = TestCli::try_parse_from(["test", "window", "--preset", "center"]).unwrap();
+    fn test_tiling_scratchpad_toggle_parse() {
+        let cli = TestCli::try_parse_from(["test", "scratchpad", "toggle"]).unwrap();
         match cli.command {
-            TilingCommands::Window(args) => {
-                assert_eq!(args.preset, Some("center".to_string()));
+            TilingCommands::Scratchpad { action } => {
+                assert_eq!(action, ScratchpadCommands::Toggle);
             }
-            _ => panic!("Expected Window command"),
+            _ => panic!("Expected Scratchpad command"),
         }
+
+        assert!(TestCli::try_parse_from(["test", "scratchpad"]).is_err());
     }
 
     #[test]
-    fn test_tiling_window_resize_parse() {
-        let cli = TestCli::try_parse_from(["test", "window", "--resize", "width", "100"]).unwrap();
+    fn test_tiling_window_float_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--float", "toggle"]).unwrap();
         match cli.command {
             TilingCommands::Window(args) => {
-                assert_eq!(args.resize, vec!["width", "100"]);
+                assert_eq!(args.float, Some(ToggleAction::Toggle));
+                assert!(args.sticky.is_none());
             }
             _ => panic!("Expected Window command"),
         }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--float", "maybe"]).is_err());
     }
 
     #[test]
-    fn test_tiling_window_resize_multiple_parse() {
-        let cli = TestCli::try_parse_from([
-            "test", "window", "--resize", "width", "100", "--resize", "height", "50",
-        ])
-        .unwrap();
+    fn test_tiling_window_sticky_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--sticky", "toggle"]).unwrap();
         match cli.command {
             TilingCommands::Window(args) => {
-                assert_eq!(args.resize, vec!["width", "100", "height", "50"]);
+                assert_eq!(args.sticky, Some(ToggleAction::Toggle));
             }
             _ => panic!("Expected Window command"),
         }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--sticky", "pin"]).is_err());
     }
 
     #[test]
-    fn test_tiling_window_resize_negative_parse() {
-        let cli = TestCli::try_parse_from(["test", "window", "--resize", "width", "-50"]).unwrap();
+    fn test_tiling_window_always_on_top_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--always-on-top", "off"]).unwrap();
         match cli.command {
             TilingCommands::Window(args) => {
-                assert_eq!(args.resize, vec!["width", "-50"]);
+                assert_eq!(args.always_on_top, Some(ToggleAction::Off));
+                assert!(args.sticky.is_none());
             }
             _ => panic!("Expected Window command"),
         }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--always-on-top", "yes"]).is_err());
     }
 
     #[test]
-    fn test_tiling_window_send_to_screen_parse() {
-        let cli = TestCli::try_parse_from(["test", "window", "--send-to-screen", "main"]).unwrap();
+    fn test_tiling_window_center_maximize_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--center"]).unwrap();
         match cli.command {
             TilingCommands::Window(args) => {
-                assert_eq!(args.send_to_screen, Some("main".to_string()));
+                assert!(args.center);
+                assert!(!args.maximize);
             }
             _ => panic!("Expected Window command"),
         }
-    }
 
-    #[test]
-    fn test_tiling_window_send_to_workspace_parse() {
         let cli =
-            TestCli::try_parse_from(["test", "window", "--send-to-workspace", "coding"]).unwrap();
+            TestCli::try_parse_from(["test", "window", "--float", "on", "--maximize"]).unwrap();
         match cli.command {
             TilingCommands::Window(args) => {
-                assert_eq!(args.send_to_workspace, Some("coding".to_string()));
+                assert_eq!(args.float, Some(ToggleAction::On));
+                assert!(args.maximize);
             }
             _ => panic!("Expected Window command"),
         }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--center", "--maximize"]).is_err());
     }
 
     #[test]
@@ -1211,6 +2855,265 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tiling_workspace_merge_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--merge-into", "coding"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.merge_into, Some("coding".to_string()));
+                assert_eq!(args.merge_follow, None);
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let result = TestCli::try_parse_from([
+            "test",
+            "workspace",
+            "--merge-into",
+            "coding",
+            "--merge-follow",
+            "browser",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tiling_workspace_copy_layout_parse() {
+        let cli =
+            TestCli::try_parse_from(["test", "workspace", "--copy-layout-from", "coding"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.copy_layout_from, Some("coding".to_string()));
+                assert_eq!(args.swap_layout, None);
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let cli =
+            TestCli::try_parse_from(["test", "workspace", "--swap-layout", "coding"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.swap_layout, Some("coding".to_string()));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let result = TestCli::try_parse_from([
+            "test",
+            "workspace",
+            "--copy-layout-from",
+            "coding",
+            "--swap-layout",
+            "browser",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tiling_workspace_arrange_floating_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--cascade"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert!(args.cascade);
+                assert!(!args.tile_floating);
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "workspace", "--tile-floating"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert!(args.tile_floating);
+                assert!(!args.cascade);
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let result = TestCli::try_parse_from(["test", "workspace", "--cascade", "--tile-floating"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tiling_workspace_move_to_screen_alias_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--move-to-screen", "secondary"])
+            .unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.send_to_screen, Some("secondary".to_string()));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_workspace_masters_parse() {
+        for value in ["+1", "-1", "2"] {
+            let cli = TestCli::try_parse_from(["test", "workspace", "--masters", value]).unwrap();
+            match cli.command {
+                TilingCommands::Workspace(args) => {
+                    assert_eq!(args.masters.as_deref(), Some(value));
+                }
+                _ => panic!("Expected Workspace command"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_tiling_workspace_masters_invalid() {
+        assert!(TestCli::try_parse_from(["test", "workspace", "--masters", "two"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "workspace", "--masters", "+"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_workspace_ratio_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--ratio", "1:0.25"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                let (index, ratio) = args.ratio.unwrap();
+                assert_eq!(index, 1);
+                assert!((ratio - 0.25).abs() < f64::EPSILON);
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_workspace_ratio_invalid() {
+        for value in ["0.3", "a:0.3", "-1:0.3", "0:1.5", "0:half"] {
+            assert!(
+                TestCli::try_parse_from(["test", "workspace", "--ratio", value]).is_err(),
+                "{value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_split_ratios() {
+        assert_eq!(
+            format_split_ratios(&serde_json::json!([0.5, 0.25])),
+            "0.50, 0.25"
+        );
+        assert_eq!(format_split_ratios(&serde_json::json!([])), "-");
+        assert_eq!(format_split_ratios(&serde_json::Value::Null), "-");
+    }
+
+    #[test]
+    fn test_tiling_workspace_scroll_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--scroll", "right"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.scroll, Some(ScrollDirection::Right));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "workspace", "--scroll", "up"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_workspace_cycle_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--cycle", "next"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.cycle, Some(CycleDirection::Next));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "workspace", "--cycle", "previous"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.cycle, Some(CycleDirection::Prev));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "workspace", "--cycle", "up"]).is_err());
+        assert!(
+            TestCli::try_parse_from(["test", "workspace", "--cycle", "next", "--focus", "a"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_tiling_workspace_focus_last_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--focus-last"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => assert!(args.focus_last),
+            _ => panic!("Expected Workspace command"),
+        }
+
+        assert!(
+            TestCli::try_parse_from(["test", "workspace", "--focus-last", "--cycle", "next"])
+                .is_err()
+        );
+        assert!(
+            TestCli::try_parse_from(["test", "workspace", "--focus-last", "--focus", "a"]).is_err()
+        );
+
+        let cli = TestCli::try_parse_from(["test", "workspace", "--toggle"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => assert!(args.focus_last),
+            _ => panic!("Expected Workspace command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_workspace_create_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--create", "scratch"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.create.as_deref(), Some("scratch"));
+                assert_eq!(args.focus, None);
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_workspace_rename_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--rename", "code"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.rename.as_deref(), Some("code"));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let cli =
+            TestCli::try_parse_from(["test", "workspace", "--focus", "dev", "--rename", "code"])
+                .unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.focus.as_deref(), Some("dev"));
+                assert_eq!(args.rename.as_deref(), Some("code"));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_workspace_claim_app_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--claim-app"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert!(args.claim_app);
+                assert!(!args.unclaim_app);
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "workspace", "--unclaim-app"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => assert!(args.unclaim_app),
+            _ => panic!("Expected Workspace command"),
+        }
+
+        assert!(
+            TestCli::try_parse_from(["test", "workspace", "--claim-app", "--unclaim-app"]).is_err()
+        );
+    }
+
     #[test]
     fn test_tiling_workspace_combined_operations_parse() {
         let cli =
@@ -1280,9 +3183,13 @@ mod tests {
             ("split-vertical", CliLayoutType::SplitVertical),
             ("split-horizontal", CliLayoutType::SplitHorizontal),
             ("monocle", CliLayoutType::Monocle),
+            ("tabbed", CliLayoutType::Tabbed),
             ("master", CliLayoutType::Master),
             ("grid", CliLayoutType::Grid),
+            ("columns", CliLayoutType::Columns),
+            ("scrolling", CliLayoutType::Scrolling),
             ("floating", CliLayoutType::Floating),
+            ("cycle", CliLayoutType::Cycle),
         ];
 
         for (name, expected) in variants {
@@ -4,6 +4,8 @@
 
 use std::str::FromStr;
 
+pub use crate::modules::tiling::WindowSort;
+
 /// A 1-based screen index for targeting specific displays.
 ///
 /// This newtype provides type safety and validation for screen indices,
@@ -93,6 +95,47 @@ pub enum Direction {
     Next,
 }
 
+/// Direction for cycling through the workspaces of a screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CycleDirection {
+    /// Switch to the next workspace.
+    Next,
+    /// Switch to the previous workspace.
+    #[value(alias = "previous")]
+    Prev,
+}
+
+impl CycleDirection {
+    /// Returns the direction as sent over IPC.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Next => "next",
+            Self::Prev => "prev",
+        }
+    }
+}
+
+/// Direction for scrolling a scrolling-layout workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ScrollDirection {
+    /// Scroll one column to the left.
+    Left,
+    /// Scroll one column to the right.
+    Right,
+}
+
+/// Change to an on/off window state, such as sticky or floating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ToggleAction {
+    /// Flip the current state.
+    Toggle,
+    /// Turn the state on.
+    On,
+    /// Turn the state off.
+    Off,
+}
+
 /// Dimension for window resize operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum ResizeDimension {
@@ -102,6 +145,17 @@ pub enum ResizeDimension {
     Height,
 }
 
+impl ResizeDimension {
+    /// Returns the dimension as sent over IPC.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Width => "width",
+            Self::Height => "height",
+        }
+    }
+}
+
 /// Layout type for workspaces (CLI representation).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum CliLayoutType {
@@ -115,12 +169,20 @@ pub enum CliLayoutType {
     SplitHorizontal,
     /// Monocle layout - all windows maximized.
     Monocle,
+    /// Tabbed layout - all windows maximized, shown as tabs.
+    Tabbed,
     /// Master layout - one large window with stack.
     Master,
     /// Grid layout - windows arranged in a grid.
     Grid,
+    /// Columns layout - equal-width columns from left to right.
+    Columns,
+    /// Scrolling layout - fixed-width columns that scroll horizontally.
+    Scrolling,
     /// Floating layout - windows can be freely moved.
     Floating,
+    /// Advance to the next layout in `tiling.layoutCycle`.
+    Cycle,
 }
 
 impl CliLayoutType {
@@ -133,9 +195,13 @@ impl CliLayoutType {
             Self::SplitVertical => "split-vertical",
             Self::SplitHorizontal => "split-horizontal",
             Self::Monocle => "monocle",
+            Self::Tabbed => "tabbed",
             Self::Master => "master",
             Self::Grid => "grid",
+            Self::Columns => "columns",
+            Self::Scrolling => "scrolling",
             Self::Floating => "floating",
+            Self::Cycle => "cycle",
         }
     }
 }
@@ -363,8 +429,12 @@ mod tests {
         assert_eq!(CliLayoutType::SplitVertical.as_str(), "split-vertical");
         assert_eq!(CliLayoutType::SplitHorizontal.as_str(), "split-horizontal");
         assert_eq!(CliLayoutType::Monocle.as_str(), "monocle");
+        assert_eq!(CliLayoutType::Tabbed.as_str(), "tabbed");
         assert_eq!(CliLayoutType::Master.as_str(), "master");
         assert_eq!(CliLayoutType::Grid.as_str(), "grid");
+        assert_eq!(CliLayoutType::Columns.as_str(), "columns");
+        assert_eq!(CliLayoutType::Scrolling.as_str(), "scrolling");
         assert_eq!(CliLayoutType::Floating.as_str(), "floating");
+        assert_eq!(CliLayoutType::Cycle.as_str(), "cycle");
     }
 }
@@ -7,9 +7,11 @@ use std::io;
 use clap::Subcommand;
 
 use super::types::ScreenTarget;
+use crate::cli::output;
 use crate::config;
 use crate::error::StacheError;
 use crate::modules::wallpaper::{self, WallpaperAction, WallpaperManagerError};
+use crate::utils::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
 
 /// Wallpaper subcommands.
 #[derive(Subcommand, Debug)]
@@ -58,19 +60,31 @@ pub enum WallpaperCommands {
     /// Returns a JSON array of wallpaper paths from the configured wallpaper
     /// directory or list.
     List,
+
+    /// Show the current wallpaper and active schedule entry.
+    ///
+    /// Queries the running app and prints JSON with the last wallpaper set
+    /// and the `schedule` entry matching the current local time (or null).
+    Current,
 }
 
 /// Execute wallpaper subcommands.
 pub fn execute(cmd: &WallpaperCommands) -> Result<(), StacheError> {
-    // Initialize config and wallpaper manager for CLI commands
-    init_wallpaper_manager()?;
-
     match cmd {
         WallpaperCommands::Set { path, random, screen } => {
+            init_wallpaper_manager()?;
             execute_set(path.as_deref(), *random, screen)
         }
-        WallpaperCommands::GenerateAll => execute_generate_all(),
-        WallpaperCommands::List => execute_list(),
+        WallpaperCommands::GenerateAll => {
+            init_wallpaper_manager()?;
+            execute_generate_all()
+        }
+        WallpaperCommands::List => {
+            init_wallpaper_manager()?;
+            execute_list()
+        }
+        // Current state lives in the running app, not in a CLI-side manager
+        WallpaperCommands::Current => execute_current(),
     }
 }
 
@@ -148,6 +162,21 @@ fn execute_list() -> Result<(), StacheError> {
     Ok(())
 }
 
+/// Execute the wallpaper current command.
+fn execute_current() -> Result<(), StacheError> {
+    match ipc_socket::send_query(IpcQuery::WallpaperCurrent) {
+        Ok(IpcResponse::Success { data }) => {
+            output::print_highlighted_json(&data);
+            Ok(())
+        }
+        Ok(IpcResponse::Error { error }) => Err(StacheError::WallpaperError(error)),
+        Err(IpcError::AppNotRunning) => {
+            Err(StacheError::IpcError("Stache app is not running".to_string()))
+        }
+        Err(e) => Err(StacheError::IpcError(e.to_string())),
+    }
+}
+
 /// Execute the wallpaper generate-all command.
 fn execute_generate_all() -> Result<(), StacheError> {
     wallpaper::generate_all_streaming(io::stdout()).map_err(wallpaper_error_to_stache_error)
@@ -178,6 +207,12 @@ mod tests {
         assert!(matches!(cli.command, WallpaperCommands::List));
     }
 
+    #[test]
+    fn test_wallpaper_current_parse() {
+        let cli = TestCli::try_parse_from(["test", "current"]).unwrap();
+        assert!(matches!(cli.command, WallpaperCommands::Current));
+    }
+
     #[test]
     fn test_wallpaper_generate_all_parse() {
         let cli = TestCli::try_parse_from(["test", "generate-all"]).unwrap();
@@ -15,14 +15,20 @@ use std::path::PathBuf;
 use std::sync::OnceLock;
 
 pub use types::{
-    AnimationConfig, AudioDeviceDependency, AudioDevicePriority, BarConfig, BorderColor,
-    BorderStateConfig, BordersConfig, CommandQuitConfig, ConfigError, DimensionValue, EasingType,
-    FloatingConfig, FloatingPreset, GapValue, GapsConfig, GapsConfigValue, GradientConfig,
-    LayoutType, MasterConfig, MasterPosition, MatchStrategy, MenuAnywhereConfig,
-    MenuAnywhereModifier, MenuAnywhereMouseButton, NoTunesConfig, ProxyAudioConfig, Rgba,
-    ShortcutCommands, StacheConfig, TargetMusicApp, TilingConfig, WallpaperConfig, WallpaperMode,
-    WeatherConfig, WindowRule, WorkspaceConfig, config_paths, load_config as load_config_default,
-    load_config_from_path, parse_color, parse_hex_color, parse_rgba_color,
+    AnimationConfig, AnimationOverride, AnimationTransitions, AudioDeviceDependency,
+    AudioDevicePriority, BarCommandConfig, BarConfig, BarLevel, BorderColor, BorderStateConfig,
+    BordersConfig, CaptureConfig, CloseTo, CommandQuitConfig, ConfigError, DimensionValue,
+    DiskConfig, EasingType, FloatRule, FloatingConfig, FloatingGridCell, FloatingGridConfig,
+    FloatingPosition, FloatingPreset, FocusModeConfig, FocusNewWindows, GapValue, GapsConfig,
+    GapsConfigValue, GradientConfig, IdleConfig, LayoutType, MasterConfig, MasterPosition,
+    MatchStrategy, MenuAnywhereConfig, MenuAnywhereModifier, MenuAnywhereMouseButton,
+    NoTunesAction, NoTunesConfig, OnEmptyWorkspace, OpenFrom, ProxyAudioConfig, QuietHours,
+    ReservedConfigValue, ReservedInsets, Rgba, ScratchpadConfig, ScrollingConfig, ShortcutCommands,
+    SpringConfig, StacheConfig, TargetMusicApp, TilingConfig, TrackingMode, UnknownKey,
+    WallpaperConfig, WallpaperMode, WallpaperScheduleEntry, WeatherConfig, WindowReadyConfig,
+    WindowRule, WorkspaceConfig, WorkspaceGapsConfig, config_paths,
+    load_config as load_config_default, load_config_from_path, parse_color, parse_hex_color,
+    parse_rgba_color,
 };
 pub use watcher::watch_config_file;
 
@@ -53,10 +59,7 @@ pub fn set_custom_config_path(path: PathBuf) -> bool { CUSTOM_CONFIG_PATH.set(pa
 /// Returns the loaded configuration, or a default configuration if loading fails.
 /// If no configuration file exists, creates a template configuration file.
 fn load_or_default() -> StacheConfig {
-    // Check for custom config path first
-    let result = CUSTOM_CONFIG_PATH.get().map_or_else(load_config_default, load_config_from_path);
-
-    match result {
+    match load_from_disk() {
         Ok((config, path)) => {
             let _ = CONFIG_PATH.set(path);
             config
@@ -73,6 +76,24 @@ fn load_or_default() -> StacheConfig {
     }
 }
 
+/// Loads the configuration file, preferring the custom config path if set.
+fn load_from_disk() -> Result<(StacheConfig, PathBuf), ConfigError> {
+    CUSTOM_CONFIG_PATH.get().map_or_else(load_config_default, load_config_from_path)
+}
+
+/// Loads the configuration file again to check that it is valid.
+///
+/// The running configuration is not replaced. Used before a reload so errors,
+/// such as unknown keys in strict mode, are reported instead of the app
+/// falling back to the default configuration.
+///
+/// # Errors
+///
+/// Returns the [`ConfigError`] loading the file produces.
+pub fn check_config_file() -> Result<PathBuf, ConfigError> {
+    load_from_disk().map(|(_, path)| path)
+}
+
 /// Creates a template configuration file at the default location.
 ///
 /// This is called when no configuration file is found during startup.
@@ -194,5 +215,42 @@ mod tests {
     fn test_bar_config_default_is_disabled() {
         let config = BarConfig::default();
         assert!(!config.is_enabled());
+        assert!(config.disk.include_volumes.is_empty());
+    }
+
+    #[test]
+    fn test_disk_config_deserialization() {
+        let config: BarConfig =
+            serde_json::from_str(r#"{"disk": {"includeVolumes": ["Recovery"]}}"#).unwrap();
+        assert_eq!(config.disk.include_volumes, vec!["Recovery"]);
+        assert_eq!(DiskConfig::default().include_volumes, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_bar_commands_deserialization() {
+        let config: BarConfig = serde_json::from_str(
+            r#"{"commands": [
+                {"id": "vpn", "command": "scutil --nc list", "intervalSeconds": 5},
+                {"id": "uptime", "command": "uptime"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(config.commands.len(), 2);
+        assert_eq!(config.command("vpn").unwrap().interval_seconds, 5);
+        assert_eq!(config.command("uptime").unwrap().interval_seconds, 60);
+        assert!(config.command("missing").is_none());
+        assert_eq!(BarCommandConfig::default().interval_seconds, 60);
+    }
+
+    #[test]
+    fn test_bar_level_deserialization() {
+        let config = BarConfig::default();
+        assert_eq!(config.level, BarLevel::BelowMenu);
+        assert!(!config.join_all_spaces);
+
+        let config: BarConfig =
+            serde_json::from_str(r#"{"level": "screensaver", "joinAllSpaces": true}"#).unwrap();
+        assert_eq!(config.level, BarLevel::Screensaver);
+        assert!(config.join_all_spaces);
     }
 }
@@ -20,6 +20,9 @@ pub fn generate_config_template() -> String {
 // Documentation: https://github.com/marcosmoura/stache
 
 {
+  // Reject unknown keys, such as a misspelled "gapps", instead of ignoring them
+  // "strict": false,
+
   // ============================================================================
   // Status Bar Configuration
   // ============================================================================
@@ -33,6 +36,12 @@ pub fn generate_config_template() -> String {
   //   // Padding around the status bar in pixels
   //   "padding": 12,
   //
+  //   // Window level: "belowMenu", "normal", "floating", "status" or "screensaver"
+  //   "level": "belowMenu",
+  //
+  //   // Also show the bar on fullscreen spaces
+  //   "joinAllSpaces": false,
+  //
   //   // Weather widget configuration
   //   "weather": {
   //     // Path to .env file containing VISUAL_CROSSING_API_KEY
@@ -40,7 +49,27 @@ pub fn generate_config_template() -> String {
   //
   //     // Default location when geolocation fails (city name or coordinates)
   //     "defaultLocation": ""
-  //   }
+  //   },
+  //
+  //   // Disk usage widget configuration
+  //   "disk": {
+  //     // Hidden or read-only volumes to report anyway (name or mount point)
+  //     "includeVolumes": []
+  //   },
+  //
+  //   // Custom command widgets, shown by id (stdout is trimmed)
+  //   "commands": [
+  //     // { "id": "uptime", "command": "uptime", "intervalSeconds": 60 }
+  //   ]
+  // },
+
+  // ============================================================================
+  // Screen Capture
+  // ============================================================================
+  // Used by `stache capture window` and `stache capture workspace`
+  // "capture": {
+  //   // Directory the PNG files are written to (created if missing)
+  //   "outputDir": "~/Pictures/Stache"
   // },
 
   // ============================================================================
@@ -55,6 +84,40 @@ pub fn generate_config_template() -> String {
   //   "holdDuration": 1500
   // },
 
+  // ============================================================================
+  // Focus Mode
+  // ============================================================================
+  // What `stache focus-mode on` does; `stache focus-mode off` undoes it
+  // "focusMode": {
+  //   // Hide the status bar (default: true)
+  //   "hideBar": true,
+  //
+  //   // Switch the focused workspace to the monocle layout (default: true)
+  //   "monocle": true,
+  //
+  //   // Keep the system awake (default: true)
+  //   "keepawake": true
+  // },
+
+  // ============================================================================
+  // Idle Monitor
+  // ============================================================================
+  // Runs commands after a period without keyboard or mouse input
+  // Check the current idle time with `stache idle seconds`
+  // "idle": {
+  //   // Enable or disable the idle monitor
+  //   "enabled": false,
+  //
+  //   // Seconds without input before you are considered idle (default: 300)
+  //   "timeout": 300,
+  //
+  //   // Commands to run when you go idle (a string or an array of strings)
+  //   "onIdle": "stache wallpaper set --random",
+  //
+  //   // Commands to run when you come back
+  //   "onActive": []
+  // },
+
   // ============================================================================
   // Wallpaper Management
   // ============================================================================
@@ -119,6 +182,12 @@ pub fn generate_config_template() -> String {
   //   // Enable automatic audio device switching
   //   "enabled": false,
   //
+  //   // Milliseconds to wait for device changes to settle before switching
+  //   "debounceMs": 300,
+  //
+  //   // Never switch devices automatically during this window (local time)
+  //   // "quietHours": { "from": "23:00", "to": "07:00" },
+  //
   //   // Input device priority list (first available device is used)
   //   // AirPlay devices are always given highest priority automatically
   //   "input": [
@@ -144,6 +213,9 @@ pub fn generate_config_template() -> String {
   //   // Enable NoTunes
   //   "enabled": false,
   //
+  //   // "replace" launches the app below; "block" only quits Apple Music
+  //   "action": "replace",
+  //
   //   // App to launch instead: "spotify", "tidal", or "none"
   //   "targetApp": "spotify"
   // },
@@ -156,9 +228,41 @@ pub fn generate_config_template() -> String {
   //   "enabled": false,
   //
   //   // Default layout for workspaces: "dwindle", "split", "monocle",
-  //   // "master", "grid", or "floating"
+  //   // "tabbed", "master", "grid", "columns", "scrolling", or "floating"
   //   "defaultLayout": "dwindle",
   //
+  //   // Default layout per screen, by screen name or "main"/"secondary"
+  //   "perScreenLayout": { "Built-in Retina Display": "monocle" },
+  //
+  //   // Layouts rotated through by `stache tiling workspace --layout cycle`
+  //   // (empty cycles through all layouts)
+  //   "layoutCycle": ["dwindle", "master", "monocle"],
+  //
+  //   // Directional focus moves to the adjacent screen when no window is
+  //   // left in that direction, wrapping around at the outermost screen
+  //   "focusWraps": false,
+  //
+  //   // Focus the window under the pointer after a short hover
+  //   "focusFollowsMouse": false,
+  //
+  //   // Move the pointer to windows focused from the keyboard
+  //   "mouseFollowsFocus": false,
+  //
+  //   // Switch to a hidden workspace when a window opens on it:
+  //   // "always", "never" or "same-screen"
+  //   "focusNewWindows": "always",
+  //
+  //   // When the last window on the focused workspace closes:
+  //   // "stay", "switch-prev" or "switch-first"
+  //   "onEmptyWorkspace": "stay",
+  //
+  //   // Move windows back to where they were before tiling when Stache quits
+  //   "restoreOnExit": false,
+  //
+  //   // "hybrid" re-queries macOS when a window event is ambiguous;
+  //   // "observer-only" trusts AX events only (faster, but can drift)
+  //   "trackingMode": "hybrid",
+  //
   //   // Gap configuration
   //   "gaps": {
   //     // Gap between windows (pixels)
@@ -169,6 +273,24 @@ pub fn generate_config_template() -> String {
   //     // Or specify per-side: { "top": 8, "right": 8, "bottom": 8, "left": 8 }
   //   },
   //
+  //   // Named gaps to switch to with `stache tiling gaps <preset>`
+  //   "gapPresets": {
+  //     "dense": { "inner": 0, "outer": 0 }
+  //   },
+  //
+  //   // Drop all gaps while a workspace has a single tiled window
+  //   "smartGaps": false,
+  //
+  //   // Reset split ratios whenever a workspace gains or loses a tiled window
+  //   "autoBalance": false,
+  //
+  //   // Pixels added or removed by `stache tiling window --grow` / `--shrink`
+  //   "resizeStep": 40,
+  //
+  //   // Space kept free at screen edges for external bars or docks (pixels)
+  //   // Or per-screen: [{ "screen": "DELL U2720Q", "bottom": 40 }]
+  //   "reserved": { "top": 0, "right": 0, "bottom": 0, "left": 0 },
+  //
   //   // Master layout configuration
   //   "master": {
   //     // Master window size ratio (0-100)
@@ -178,11 +300,46 @@ pub fn generate_config_template() -> String {
   //     "position": "auto"
   //   },
   //
+  //   // Scrolling layout configuration
+  //   "scrolling": {
+  //     // Column width (pixels or percentage of the screen)
+  //     "columnWidth": "50%"
+  //   },
+  //
+  //   // Scratchpad: a hidden workspace shown over the current one on demand
+  //   "scratchpad": {
+  //     "name": "scratchpad"
+  //   },
+  //
+  //   // Wait up to timeoutMs for new windows to stop resizing before tiling them
+  //   "windowReady": {
+  //     "initialDelayMs": 0,
+  //     "timeoutMs": 0,
+  //     "pollIntervalMs": 50
+  //   },
+  //
+  //   // Keep floating and sticky windows above tiled windows
+  //   "floatingAlwaysOnTop": false,
+  //
+  //   // Opacity of unfocused tiled windows (1.0 disables dimming)
+  //   "unfocusedOpacity": 1.0,
+  //
   //   // Animation configuration
   //   "animations": {
   //     "enabled": false,
   //     "duration": 200,
-  //     "easing": "ease-out"
+  //     "easing": "ease-out",
+  //     // Where new windows animate from: "none", "spawn", "center" or "cursor"
+  //     "openFrom": "none",
+  //     // Where `tiling window --minimize` animates windows to: "none", "center" or "cursor"
+  //     "closeTo": "none",
+  //     // Per-transition overrides: "open", "close", "move", "resize", "workspaceSwitch"
+  //     "transitions": {
+  //       "workspaceSwitch": { "duration": 120, "easing": "ease-out-expo" }
+  //     },
+  //     // Physics of the "spring" easing (stiffness, damping and mass);
+  //     // a stiffness overrides the durations above
+  //     "spring": { "stiffness": 300, "damping": 25, "mass": 1 }
   //   },
   //
   //   // Window borders
@@ -201,16 +358,48 @@ pub fn generate_config_template() -> String {
   //     "presets": [
   //       // { "name": "small", "width": 800, "height": 600, "center": true },
   //       // { "name": "large", "width": "80%", "height": "80%", "center": true }
-  //     ]
+  //     ],
+  //     // Generate "grid-r1c1" .. "grid-r2c3" presets for a 3x2 grid
+  //     // "grid": { "cols": 3, "rows": 2 },
+  //
+  //     // Pixels moved by `stache tiling window --move <direction>`
+  //     "moveStep": 50
   //   },
   //
   //   // Windows to ignore (never tiled)
   //   "ignore": [
   //     // { "appName": "System Preferences" },
   //     // { "appId": "com.apple.systempreferences" },
-  //     // { "title": "Picture in Picture" }
+  //     // { "title": "Picture in Picture" },
+  //     // { "titleRegex": "^Figma - " }  // Regex patterns are also supported
   //   ],
   //
+  //   // Apps that are never tracked at all (by name or bundle ID)
+  //   "ignoreApps": [
+  //     // "Raycast",
+  //     // "com.1password.1password"
+  //   ],
+  //
+  //   // Screens that tiling leaves alone (by display name)
+  //   "ignoreScreens": [
+  //     // "LG TV"
+  //   ],
+  //
+  //   // Windows that open floating on any workspace, optionally with a preset;
+  //   // "screen" pins matching windows to a screen's visible workspace;
+  //   // "gaps" and "borderColor" override them for matching windows
+  //   "floatRules": [
+  //     // { "appName": "System Settings", "preset": "centered" },
+  //     // { "subrole": "AXDialog" },
+  //     // { "appName": "Slack", "float": false, "screen": "secondary" },
+  //     // { "appId": "com.mitchellh.ghostty", "float": false, "gaps": 0 },
+  //     // { "appName": "IntelliJ IDEA", "float": false, "useApplescriptFrames": true }
+  //   ],
+  //
+  //   // Move windows of rules with "useApplescriptFrames" through osascript when
+  //   // the accessibility API can't; needs Automation access to System Events
+  //   "applescriptFallback": false,
+  //
   //   // Workspace definitions
   //   "workspaces": [
   //     // {
@@ -226,7 +415,9 @@ pub fn generate_config_template() -> String {
   //     //   "layout": "monocle",
   //     //   "rules": [
   //     //     { "appId": "com.microsoft.VSCode" }
-  //     //   ]
+  //     //   ],
+  //     //   // Wallpaper shown when this workspace becomes visible
+  //     //   "wallpaper": "~/Pictures/code.jpg"
   //     // }
   //   ]
   // }
@@ -270,7 +461,10 @@ mod tests {
     fn test_generate_config_template_contains_all_sections() {
         let template = generate_config_template();
         assert!(template.contains("bar"));
+        assert!(template.contains("capture"));
         assert!(template.contains("commandQuit"));
+        assert!(template.contains("focusMode"));
+        assert!(template.contains("idle"));
         assert!(template.contains("wallpapers"));
         assert!(template.contains("keybindings"));
         assert!(template.contains("menuAnywhere"));
@@ -2,9 +2,14 @@
 //!
 //! Configuration for automatic audio device switching based on priority rules.
 
+use std::time::Duration;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Default window for coalescing device change events, in milliseconds.
+const DEFAULT_DEBOUNCE_MS: u64 = 300;
+
 /// Strategy for matching device names in the priority list.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -71,6 +76,19 @@ pub struct AudioDevicePriority {
     pub depends_on: Option<AudioDeviceDependency>,
 }
 
+/// A daily time window during which automatic switching is paused.
+///
+/// Times are local and use the 24-hour "HH:MM" format. A window whose end is
+/// before its start wraps around midnight, e.g. "22:00"–"07:00".
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct QuietHours {
+    /// Start time in 24-hour "HH:MM" format.
+    pub from: String,
+
+    /// End time in 24-hour "HH:MM" format.
+    pub to: String,
+}
+
 /// Proxy audio configuration for automatic device routing.
 ///
 /// This configuration enables intelligent audio device switching based on
@@ -78,9 +96,10 @@ pub struct AudioDevicePriority {
 /// switches to the highest-priority available device when devices connect
 /// or disconnect.
 ///
-/// `AirPlay` devices are always given the highest priority, even if not
-/// explicitly listed in the priority configuration.
-#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+/// When `pinAirplay` is enabled (the default), an `AirPlay` device that is
+/// currently selected is never switched away from, even if not explicitly
+/// listed in the priority configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, rename_all = "camelCase")]
 pub struct ProxyAudioConfig {
     /// Whether proxy audio functionality is enabled.
@@ -89,23 +108,63 @@ pub struct ProxyAudioConfig {
     /// Default: false
     pub enabled: bool,
 
+    /// Whether to keep the current device when it is an `AirPlay` device.
+    /// When enabled, automatic switching never moves away from an active
+    /// `AirPlay` device. Disable to let the priority list always win.
+    /// Default: true
+    pub pin_airplay: bool,
+
+    /// Window in milliseconds for coalescing device change events.
+    /// Bursts of `CoreAudio` notifications (e.g. an interface powering on)
+    /// are handled once after this long without new events.
+    /// Default: 300
+    pub debounce_ms: u64,
+
+    /// Daily time window during which devices are never switched automatically.
+    /// Whatever device is selected stays selected until the window ends; the
+    /// next device change after that is handled normally.
+    /// Default: none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<QuietHours>,
+
     /// Priority list for input device selection.
     /// Devices are checked in order; the first available device is selected.
-    /// `AirPlay` devices are always given highest priority automatically.
+    /// When empty, the built-in `MacBook` Pro microphone is used as fallback.
     #[serde(default)]
     pub input: Vec<AudioDevicePriority>,
 
     /// Priority list for output device selection.
     /// Devices are checked in order; the first available device is selected.
-    /// `AirPlay` devices are always given highest priority automatically.
+    /// When empty, the built-in `MacBook` Pro speakers are used as fallback.
     #[serde(default)]
     pub output: Vec<AudioDevicePriority>,
 }
 
+impl Default for ProxyAudioConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            pin_airplay: true,
+            debounce_ms: DEFAULT_DEBOUNCE_MS,
+            quiet_hours: None,
+            input: Vec::new(),
+            output: Vec::new(),
+        }
+    }
+}
+
 impl ProxyAudioConfig {
     /// Returns whether proxy audio functionality is enabled.
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Returns whether the current `AirPlay` device should be kept.
+    #[must_use]
+    pub const fn should_pin_airplay(&self) -> bool { self.pin_airplay }
+
+    /// Returns the window for coalescing device change events.
+    #[must_use]
+    pub const fn debounce(&self) -> Duration { Duration::from_millis(self.debounce_ms) }
 }
 
 #[cfg(test)]
@@ -141,5 +200,43 @@ mod tests {
     fn test_proxy_audio_default() {
         let config = ProxyAudioConfig::default();
         assert!(!config.is_enabled());
+        assert!(config.should_pin_airplay());
+        assert_eq!(config.debounce(), Duration::from_millis(DEFAULT_DEBOUNCE_MS));
+        assert!(config.quiet_hours.is_none());
+    }
+
+    #[test]
+    fn test_proxy_audio_quiet_hours() {
+        let config: ProxyAudioConfig = serde_json::from_str(
+            r#"{"enabled": true, "quietHours": {"from": "22:00", "to": "07:00"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.quiet_hours,
+            Some(QuietHours {
+                from: "22:00".to_string(),
+                to: "07:00".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_proxy_audio_debounce_ms() {
+        let config: ProxyAudioConfig =
+            serde_json::from_str(r#"{"enabled": true, "debounceMs": 0}"#).unwrap();
+        assert_eq!(config.debounce(), Duration::ZERO);
+
+        let config: ProxyAudioConfig = serde_json::from_str(r#"{"enabled": true}"#).unwrap();
+        assert_eq!(config.debounce_ms, DEFAULT_DEBOUNCE_MS);
+    }
+
+    #[test]
+    fn test_proxy_audio_pin_airplay_defaults_when_missing() {
+        let config: ProxyAudioConfig = serde_json::from_str(r#"{"enabled": true}"#).unwrap();
+        assert!(config.pin_airplay);
+
+        let config: ProxyAudioConfig =
+            serde_json::from_str(r#"{"enabled": true, "pinAirplay": false}"#).unwrap();
+        assert!(!config.pin_airplay);
     }
 }
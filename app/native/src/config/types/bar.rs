@@ -1,6 +1,7 @@
 //! Status bar configuration types.
 //!
-//! Configuration for the status bar UI components including weather.
+//! Configuration for the status bar UI components including weather, disk usage and
+//! custom commands.
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -39,9 +40,64 @@ impl WeatherConfig {
     pub const fn is_enabled(&self) -> bool { !self.api_keys.is_empty() }
 }
 
+/// Disk usage configuration for the status bar.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DiskConfig {
+    /// Volumes to report even though they are hidden or read-only, matched by
+    /// name (e.g. "Recovery") or mount point (e.g. "/System/Volumes/Data").
+    /// The root volume and regular volumes are always reported.
+    /// Default: []
+    pub include_volumes: Vec<String>,
+}
+
+/// A shell command whose output is shown in the status bar.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BarCommandConfig {
+    /// Identifier the bar uses to request this command's output.
+    pub id: String,
+
+    /// Command line run with `/bin/sh -c`. Its trimmed stdout is shown.
+    pub command: String,
+
+    /// Minimum number of seconds between two runs. Requests made sooner
+    /// return the previous output.
+    /// Default: 60
+    pub interval_seconds: u64,
+}
+
+impl Default for BarCommandConfig {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            command: String::new(),
+            interval_seconds: 60,
+        }
+    }
+}
+
+/// Window level of the status bar.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum BarLevel {
+    /// Below the menu bar and other windows, like the desktop.
+    #[default]
+    BelowMenu,
+    /// The level of regular application windows.
+    Normal,
+    /// The level of floating panels, above regular windows.
+    Floating,
+    /// The level of status items, above the menu bar.
+    Status,
+    /// The level of screen savers, above almost everything.
+    Screensaver,
+}
+
 /// Bar configuration for the status bar UI components.
 ///
-/// Contains settings for bar-specific features like weather and dimensions.
+/// Contains settings for bar-specific features like weather, disk usage, custom
+/// commands and dimensions.
 /// The bar dimensions are used by the tiling window manager to account for
 /// the status bar when calculating window layouts on the main screen.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -61,12 +117,36 @@ pub struct BarConfig {
     /// Default: 12
     pub padding: u16,
 
+    /// Window level of the status bar: "belowMenu", "normal", "floating",
+    /// "status" or "screensaver". Use a higher level if the bar gets covered
+    /// by other overlays.
+    /// Default: "belowMenu"
+    pub level: BarLevel,
+
+    /// Whether the status bar also shows on fullscreen spaces.
+    /// The bar is always shown on every regular space.
+    /// Default: false
+    pub join_all_spaces: bool,
+
     /// Weather status bar configuration.
     pub weather: WeatherConfig,
+
+    /// Disk usage status bar configuration.
+    pub disk: DiskConfig,
+
+    /// Shell commands whose output can be shown in the status bar.
+    /// Default: []
+    pub commands: Vec<BarCommandConfig>,
 }
 
 impl BarConfig {
     /// Returns whether the status bar is enabled.
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Returns the command with the given id.
+    #[must_use]
+    pub fn command(&self, id: &str) -> Option<&BarCommandConfig> {
+        self.commands.iter().find(|command| command.id == id)
+    }
 }
@@ -0,0 +1,79 @@
+//! Screen capture configuration types.
+//!
+//! Configuration for `stache capture`, which saves the focused window or
+//! workspace as a PNG file.
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::path::expand;
+
+/// Default directory captures are written to.
+const DEFAULT_OUTPUT_DIR: &str = "~/Pictures/Stache";
+
+/// Configuration for screen captures.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CaptureConfig {
+    /// Directory captures are written to. Created if it doesn't exist.
+    /// Supports ~ for the home directory.
+    /// Default: "~/Pictures/Stache"
+    pub output_dir: String,
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            output_dir: DEFAULT_OUTPUT_DIR.to_string(),
+        }
+    }
+}
+
+impl CaptureConfig {
+    /// Returns the output directory with `~` expanded.
+    ///
+    /// Falls back to the default directory when the configured one is empty.
+    #[must_use]
+    pub fn output_dir(&self) -> PathBuf {
+        if self.output_dir.trim().is_empty() {
+            expand(DEFAULT_OUTPUT_DIR)
+        } else {
+            expand(&self.output_dir)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_config_default() {
+        let config = CaptureConfig::default();
+        assert_eq!(config.output_dir, "~/Pictures/Stache");
+        assert!(!config.output_dir().to_string_lossy().starts_with('~'));
+    }
+
+    #[test]
+    fn test_capture_config_output_dir() {
+        let config = CaptureConfig {
+            output_dir: "/tmp/captures".to_string(),
+        };
+        assert_eq!(config.output_dir(), PathBuf::from("/tmp/captures"));
+
+        let config = CaptureConfig { output_dir: "  ".to_string() };
+        assert_eq!(config.output_dir(), CaptureConfig::default().output_dir());
+    }
+
+    #[test]
+    fn test_deserialize_from_json() {
+        let json = r#"{"outputDir": "~/Desktop"}"#;
+        let config: CaptureConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.output_dir, "~/Desktop");
+
+        let config: CaptureConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.output_dir, "~/Pictures/Stache");
+    }
+}
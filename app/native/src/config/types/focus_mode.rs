@@ -0,0 +1,58 @@
+//! Focus mode configuration types.
+//!
+//! Configuration for `stache focus-mode`, a do-not-disturb style toggle that
+//! combines several existing features.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for focus mode.
+///
+/// Each flag controls one action taken when focus mode is turned on. Turning
+/// focus mode off undoes the actions that were taken.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FocusModeConfig {
+    /// Whether to hide the status bar.
+    /// Default: true
+    pub hide_bar: bool,
+
+    /// Whether to switch the focused workspace to the monocle layout.
+    /// Default: true
+    pub monocle: bool,
+
+    /// Whether to keep the system awake.
+    /// Default: true
+    pub keepawake: bool,
+}
+
+impl Default for FocusModeConfig {
+    fn default() -> Self {
+        Self {
+            hide_bar: true,
+            monocle: true,
+            keepawake: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_mode_config_default() {
+        let config = FocusModeConfig::default();
+        assert!(config.hide_bar);
+        assert!(config.monocle);
+        assert!(config.keepawake);
+    }
+
+    #[test]
+    fn test_deserialize_from_json() {
+        let config: FocusModeConfig = serde_json::from_str(r#"{"hideBar": false}"#).unwrap();
+        assert!(!config.hide_bar);
+        assert!(config.monocle);
+        assert!(config.keepawake);
+    }
+}
@@ -1,6 +1,7 @@
 //! Gap configuration types.
 //!
-//! Configuration for inner and outer gaps in tiling layouts.
+//! Configuration for inner and outer gaps in tiling layouts, and for the
+//! space reserved at screen edges for external bars and docks.
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -125,6 +126,121 @@ impl Default for GapsConfigValue {
     fn default() -> Self { Self::Global(GapsConfig::default()) }
 }
 
+/// Per-workspace gaps override.
+///
+/// Each field is optional: an unset field falls back to the per-screen or
+/// global gaps, so a workspace can override only its inner gaps and keep
+/// the outer gaps from the screen.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WorkspaceGapsConfig {
+    /// Inner gaps between windows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner: Option<GapValue>,
+    /// Outer gaps from screen edges.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outer: Option<GapValue>,
+}
+
+/// Space reserved at the edges of a screen, in pixels.
+///
+/// Reserved space is taken off the screen's usable frame before any layout
+/// or preset is computed, so windows stay clear of external bars or docks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ReservedInsets {
+    /// Space reserved at the top edge.
+    pub top: u32,
+    /// Space reserved at the right edge.
+    pub right: u32,
+    /// Space reserved at the bottom edge.
+    pub bottom: u32,
+    /// Space reserved at the left edge.
+    pub left: u32,
+}
+
+impl ReservedInsets {
+    /// Returns whether no space is reserved on any edge.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.top == 0 && self.right == 0 && self.bottom == 0 && self.left == 0
+    }
+}
+
+/// Per-screen reserved space.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenReservedConfig {
+    /// Screen identifier: "main"/"primary", "secondary", or screen name.
+    pub screen: String,
+    /// Space reserved at the top edge.
+    #[serde(default)]
+    pub top: u32,
+    /// Space reserved at the right edge.
+    #[serde(default)]
+    pub right: u32,
+    /// Space reserved at the bottom edge.
+    #[serde(default)]
+    pub bottom: u32,
+    /// Space reserved at the left edge.
+    #[serde(default)]
+    pub left: u32,
+}
+
+impl ScreenReservedConfig {
+    /// Returns the reserved space of this entry.
+    #[must_use]
+    pub const fn insets(&self) -> ReservedInsets {
+        ReservedInsets {
+            top: self.top,
+            right: self.right,
+            bottom: self.bottom,
+            left: self.left,
+        }
+    }
+}
+
+/// Reserved space that can be global or per-screen.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ReservedConfigValue {
+    /// Same reserved space on all screens.
+    Global(ReservedInsets),
+    /// Per-screen reserved space.
+    PerScreen(Vec<ScreenReservedConfig>),
+}
+
+impl Default for ReservedConfigValue {
+    fn default() -> Self { Self::Global(ReservedInsets::default()) }
+}
+
+impl ReservedConfigValue {
+    /// Resolves the reserved space for a screen.
+    ///
+    /// Per-screen entries match the screen name (case-insensitive), with
+    /// "main"/"primary" and "secondary" matching by role. Screens without a
+    /// matching entry have no reserved space.
+    #[must_use]
+    pub fn for_screen(&self, screen_name: &str, is_main_screen: bool) -> ReservedInsets {
+        match self {
+            Self::Global(insets) => *insets,
+            Self::PerScreen(screens) => screens
+                .iter()
+                .find(|s| s.screen.eq_ignore_ascii_case(screen_name))
+                .or_else(|| {
+                    screens.iter().find(|s| {
+                        ((s.screen.eq_ignore_ascii_case("main")
+                            || s.screen.eq_ignore_ascii_case("primary"))
+                            && is_main_screen)
+                            || (s.screen.eq_ignore_ascii_case("secondary") && !is_main_screen)
+                    })
+                })
+                .map(ScreenReservedConfig::insets)
+                .unwrap_or_default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +279,47 @@ mod tests {
         };
         assert_eq!(per_side.as_outer(), (10, 20, 30, 40));
     }
+
+    #[test]
+    fn test_workspace_gaps_config_partial() {
+        let json = r#"{"inner": 0}"#;
+        let config: WorkspaceGapsConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.inner.map(|g| g.as_inner()), Some((0, 0)));
+        assert!(config.outer.is_none());
+    }
+
+    #[test]
+    fn test_reserved_config_global() {
+        let json = r#"{"top": 30, "left": 60}"#;
+        let config: ReservedConfigValue = serde_json::from_str(json).unwrap();
+
+        let expected = ReservedInsets {
+            top: 30,
+            right: 0,
+            bottom: 0,
+            left: 60,
+        };
+        assert_eq!(config.for_screen("Built-in", true), expected);
+        assert_eq!(config.for_screen("DELL U2720Q", false), expected);
+        assert!(ReservedConfigValue::default().for_screen("Built-in", true).is_empty());
+    }
+
+    #[test]
+    fn test_reserved_config_per_screen() {
+        let json = r#"[
+            { "screen": "DELL U2720Q", "bottom": 40 },
+            { "screen": "main", "top": 30 }
+        ]"#;
+        let config: ReservedConfigValue = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.for_screen("dell u2720q", false).bottom, 40);
+        assert_eq!(config.for_screen("Built-in", true).top, 30);
+
+        // A name match wins over a role match
+        assert_eq!(config.for_screen("DELL U2720Q", true).top, 0);
+
+        // Screens without an entry reserve nothing
+        assert!(config.for_screen("LG UltraFine", false).is_empty());
+    }
 }
@@ -0,0 +1,113 @@
+//! Idle monitor configuration types.
+//!
+//! Configuration for running commands when the user goes idle and when they
+//! become active again.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::root::ShortcutCommands;
+
+/// Default idle timeout in seconds (5 minutes).
+const DEFAULT_TIMEOUT_SECS: u64 = 300;
+
+/// Configuration for the idle monitor.
+///
+/// The monitor measures the time since the last keyboard or mouse input and
+/// runs `onIdle` once it passes `timeout`, then `onActive` on the next input.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct IdleConfig {
+    /// Whether the idle monitor is enabled.
+    /// Default: false
+    pub enabled: bool,
+
+    /// Time in seconds without input before the user is considered idle.
+    /// Default: 300 (5 minutes)
+    pub timeout: u64,
+
+    /// Commands to run when the user becomes idle, e.g.
+    /// "stache wallpaper set --random".
+    /// Either a single command or an array of commands run in order.
+    /// Default: none
+    pub on_idle: Option<ShortcutCommands>,
+
+    /// Commands to run when the user becomes active again after being idle.
+    /// Either a single command or an array of commands run in order.
+    /// Default: none
+    pub on_active: Option<ShortcutCommands>,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timeout: DEFAULT_TIMEOUT_SECS,
+            on_idle: None,
+            on_active: None,
+        }
+    }
+}
+
+impl IdleConfig {
+    /// Returns whether the monitor should run.
+    ///
+    /// A zero timeout or a config without any commands disables it.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled && self.timeout > 0 && (self.on_idle.is_some() || self.on_active.is_some())
+    }
+
+    /// Returns the idle timeout in seconds.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Timeouts are far below f64's exact range
+    pub const fn timeout_secs(&self) -> f64 { self.timeout as f64 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_config_default() {
+        let config = IdleConfig::default();
+        assert!(!config.enabled);
+        assert_eq!(config.timeout, 300);
+        assert!(config.on_idle.is_none());
+        assert!(config.on_active.is_none());
+        assert!(!config.is_enabled());
+    }
+
+    #[test]
+    fn test_idle_config_is_enabled() {
+        let mut config = IdleConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        assert!(!config.is_enabled());
+
+        config.on_active = Some(ShortcutCommands::Single("stache reload".to_string()));
+        assert!(config.is_enabled());
+
+        config.timeout = 0;
+        assert!(!config.is_enabled());
+    }
+
+    #[test]
+    fn test_deserialize_from_json() {
+        let json = r#"{
+            "enabled": true,
+            "timeout": 600,
+            "onIdle": "stache wallpaper set --random",
+            "onActive": ["stache reload"]
+        }"#;
+        let config: IdleConfig = serde_json::from_str(json).unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.timeout, 600);
+        assert_eq!(config.on_idle.unwrap().get_commands(), vec![
+            "stache wallpaper set --random"
+        ]);
+        assert_eq!(config.on_active.unwrap().get_commands(), vec!["stache reload"]);
+        assert!((IdleConfig::default().timeout_secs() - 300.0).abs() < f64::EPSILON);
+    }
+}
@@ -8,12 +8,16 @@
 pub mod audio;
 pub mod bar;
 pub mod borders;
+pub mod capture;
 pub mod color;
 pub mod command_quit;
+pub mod focus_mode;
 pub mod gaps;
+pub mod idle;
 pub mod menu_anywhere;
 pub mod notunes;
 pub mod root;
+pub mod strict;
 pub mod tiling;
 pub mod wallpaper;
 pub mod workspaces;
@@ -21,31 +25,46 @@ pub mod workspaces;
 // Re-export all types for backward compatibility and convenience
 
 // Audio types
-pub use audio::{AudioDeviceDependency, AudioDevicePriority, MatchStrategy, ProxyAudioConfig};
+pub use audio::{
+    AudioDeviceDependency, AudioDevicePriority, MatchStrategy, ProxyAudioConfig, QuietHours,
+};
 // Bar types
-pub use bar::{BarConfig, WeatherConfig};
+pub use bar::{BarCommandConfig, BarConfig, BarLevel, DiskConfig, WeatherConfig};
 // Border types
 pub use borders::{BorderColor, BorderStateConfig, BordersConfig, GradientConfig};
+// Capture types
+pub use capture::CaptureConfig;
 // Color types
 pub use color::{Rgba, parse_color, parse_hex_color, parse_rgba_color};
 // Command Quit types
 pub use command_quit::CommandQuitConfig;
+// Focus mode types
+pub use focus_mode::FocusModeConfig;
 // Gap types
-pub use gaps::{DimensionValue, GapValue, GapsConfig, GapsConfigValue};
+pub use gaps::{
+    DimensionValue, GapValue, GapsConfig, GapsConfigValue, ReservedConfigValue, ReservedInsets,
+    WorkspaceGapsConfig,
+};
+// Idle types
+pub use idle::IdleConfig;
 // Menu Anywhere types
 pub use menu_anywhere::{MenuAnywhereConfig, MenuAnywhereModifier, MenuAnywhereMouseButton};
 // NoTunes types
-pub use notunes::{NoTunesConfig, TargetMusicApp};
+pub use notunes::{NoTunesAction, NoTunesConfig, TargetMusicApp};
 // Root config types
 pub use root::{
     ConfigError, ShortcutCommands, StacheConfig, config_paths, load_config, load_config_from_path,
 };
+// Strict validation types
+pub use strict::UnknownKey;
 // Tiling types
 pub use tiling::{
-    AnimationConfig, EasingType, FloatingConfig, FloatingPreset, LayoutType, MasterConfig,
-    MasterPosition, TilingConfig,
+    AnimationConfig, AnimationOverride, AnimationTransitions, CloseTo, EasingType, FloatingConfig,
+    FloatingGridCell, FloatingGridConfig, FloatingPosition, FloatingPreset, FocusNewWindows,
+    LayoutType, MasterConfig, MasterPosition, OnEmptyWorkspace, OpenFrom, ScratchpadConfig,
+    ScrollingConfig, SpringConfig, TilingConfig, TrackingMode, WindowReadyConfig,
 };
 // Wallpaper types
-pub use wallpaper::{WallpaperConfig, WallpaperMode};
+pub use wallpaper::{WallpaperConfig, WallpaperMode, WallpaperScheduleEntry};
 // Workspace types
-pub use workspaces::{WindowRule, WorkspaceConfig};
+pub use workspaces::{FloatRule, WindowRule, WorkspaceConfig};
@@ -52,6 +52,17 @@ impl TargetMusicApp {
     }
 }
 
+/// What noTunes does when a blocked app is launched.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NoTunesAction {
+    /// Quit the blocked app without launching anything.
+    Block,
+    /// Quit the blocked app, then launch the replacement app.
+    #[default]
+    Replace,
+}
+
 /// Configuration for the noTunes feature.
 ///
 /// noTunes prevents Apple Music or iTunes from launching automatically
@@ -64,17 +75,45 @@ pub struct NoTunesConfig {
     /// Default: false
     pub enabled: bool,
 
+    /// What to do when a blocked app is launched.
+    /// Options: "block" (quit only), "replace" (quit, then launch the replacement)
+    /// Default: "replace"
+    pub action: NoTunesAction,
+
     /// The music app to launch when Apple Music/iTunes is blocked.
     /// Options: "tidal", "spotify", "none"
+    /// Ignored when `replacement` is set.
     /// Default: "spotify"
     pub target_app: TargetMusicApp,
+
+    /// Custom replacement app to launch when a blocked app is intercepted.
+    /// Accepts either an application path (e.g., "/Applications/Cider.app")
+    /// or a bundle identifier (e.g., "com.spotify.client").
+    /// Takes precedence over `targetApp` when set.
+    /// Default: null
+    pub replacement: Option<String>,
+
+    /// Bundle identifiers of the apps that should be blocked from launching.
+    /// Default: `["com.apple.Music", "com.apple.iTunes"]`
+    pub blocked_bundle_ids: Vec<String>,
+}
+
+/// Returns the default list of blocked bundle identifiers.
+fn default_blocked_bundle_ids() -> Vec<String> {
+    vec![
+        "com.apple.Music".to_string(),
+        "com.apple.iTunes".to_string(),
+    ]
 }
 
 impl Default for NoTunesConfig {
     fn default() -> Self {
         Self {
             enabled: false,
+            action: NoTunesAction::Replace,
             target_app: TargetMusicApp::Spotify,
+            replacement: None,
+            blocked_bundle_ids: default_blocked_bundle_ids(),
         }
     }
 }
@@ -83,6 +122,18 @@ impl NoTunesConfig {
     /// Returns whether noTunes functionality is enabled.
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Returns the custom replacement app, ignoring empty values.
+    #[must_use]
+    pub fn replacement(&self) -> Option<&str> {
+        self.replacement.as_deref().map(str::trim).filter(|s| !s.is_empty())
+    }
+
+    /// Returns whether the given bundle identifier is blocked.
+    #[must_use]
+    pub fn is_blocked(&self, bundle_id: &str) -> bool {
+        self.blocked_bundle_ids.iter().any(|id| id == bundle_id)
+    }
 }
 
 #[cfg(test)]
@@ -122,6 +173,63 @@ mod tests {
     fn test_notunes_config_default() {
         let config = NoTunesConfig::default();
         assert!(!config.enabled);
+        assert_eq!(config.action, NoTunesAction::Replace);
         assert_eq!(config.target_app, TargetMusicApp::Spotify);
+        assert!(config.replacement.is_none());
+        assert_eq!(config.blocked_bundle_ids, vec![
+            "com.apple.Music".to_string(),
+            "com.apple.iTunes".to_string()
+        ]);
+    }
+
+    #[test]
+    fn test_notunes_config_is_blocked() {
+        let config = NoTunesConfig::default();
+        assert!(config.is_blocked("com.apple.Music"));
+        assert!(config.is_blocked("com.apple.iTunes"));
+        assert!(!config.is_blocked("com.spotify.client"));
+
+        let config = NoTunesConfig {
+            blocked_bundle_ids: vec!["com.example.Player".to_string()],
+            ..Default::default()
+        };
+        assert!(config.is_blocked("com.example.Player"));
+        assert!(!config.is_blocked("com.apple.Music"));
+    }
+
+    #[test]
+    fn test_notunes_config_replacement_ignores_blank() {
+        let config = NoTunesConfig {
+            replacement: Some("   ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.replacement(), None);
+
+        let config = NoTunesConfig {
+            replacement: Some("com.spotify.client".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.replacement(), Some("com.spotify.client"));
+    }
+
+    #[test]
+    fn test_notunes_config_deserialize_partial() {
+        let config: NoTunesConfig =
+            serde_json::from_str(r#"{"enabled": true, "replacement": "/Applications/Cider.app"}"#)
+                .unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.replacement(), Some("/Applications/Cider.app"));
+        assert_eq!(config.blocked_bundle_ids.len(), 2);
+    }
+
+    #[test]
+    fn test_notunes_config_deserialize_action() {
+        let config: NoTunesConfig = serde_json::from_str(r#"{"action": "block"}"#).unwrap();
+        assert_eq!(config.action, NoTunesAction::Block);
+
+        let config: NoTunesConfig = serde_json::from_str(r#"{"action": "replace"}"#).unwrap();
+        assert_eq!(config.action, NoTunesAction::Replace);
+
+        assert!(serde_json::from_str::<NoTunesConfig>(r#"{"action": "ignore"}"#).is_err());
     }
 }
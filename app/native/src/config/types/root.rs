@@ -4,6 +4,7 @@
 
 use std::collections::HashMap;
 use std::fs;
+use std::io::Read;
 use std::path::PathBuf;
 
 use schemars::JsonSchema;
@@ -11,9 +12,13 @@ use serde::{Deserialize, Serialize};
 
 use super::audio::ProxyAudioConfig;
 use super::bar::BarConfig;
+use super::capture::CaptureConfig;
 use super::command_quit::CommandQuitConfig;
+use super::focus_mode::FocusModeConfig;
+use super::idle::IdleConfig;
 use super::menu_anywhere::MenuAnywhereConfig;
 use super::notunes::NoTunesConfig;
+use super::strict::{UnknownKey, find_unknown_keys};
 use super::tiling::TilingConfig;
 use super::wallpaper::WallpaperConfig;
 
@@ -73,9 +78,14 @@ impl ShortcutCommands {
 pub struct StacheConfig {
     /// Bar configuration for status bar UI components.
     ///
-    /// Contains settings for weather.
+    /// Contains settings for weather and disk usage.
     pub bar: BarConfig,
 
+    /// Screen capture configuration.
+    ///
+    /// Controls where `stache capture` writes its PNG files.
+    pub capture: CaptureConfig,
+
     /// Command Quit (hold ⌘Q to quit) configuration.
     ///
     /// Prevents accidental application quits by requiring users to hold
@@ -83,6 +93,19 @@ pub struct StacheConfig {
     #[serde(rename = "commandQuit")]
     pub command_quit: CommandQuitConfig,
 
+    /// Focus mode configuration.
+    ///
+    /// Chooses what `stache focus-mode on` does: hide the bar, switch the
+    /// focused workspace to monocle and keep the system awake.
+    #[serde(rename = "focusMode")]
+    pub focus_mode: FocusModeConfig,
+
+    /// Idle monitor configuration.
+    ///
+    /// Runs commands when the user goes idle and when they become active
+    /// again. Disabled by default.
+    pub idle: IdleConfig,
+
     /// Desktop wallpaper configuration.
     ///
     /// Controls dynamic wallpaper rotation, effects, and display.
@@ -103,7 +126,7 @@ pub struct StacheConfig {
     /// Proxy audio configuration for automatic device routing.
     ///
     /// Enables intelligent audio device switching based on device availability
-    /// and priority. `AirPlay` devices are always given highest priority.
+    /// and priority. The current `AirPlay` device is kept unless `pinAirplay` is off.
     #[serde(rename = "proxyAudio")]
     pub proxy_audio: ProxyAudioConfig,
 
@@ -114,6 +137,12 @@ pub struct StacheConfig {
     #[serde(rename = "notunes")]
     pub notunes: NoTunesConfig,
 
+    /// Whether unknown keys in the configuration file are errors.
+    ///
+    /// Off by default, so misspelled keys are silently ignored. When on,
+    /// loading fails with the path of each unknown key, e.g. `tiling.gapps`.
+    pub strict: bool,
+
     /// Tiling window manager configuration.
     ///
     /// Provides virtual workspace management with multiple layout modes.
@@ -126,6 +155,7 @@ impl StacheConfig {
     ///
     /// This method should be called after loading the configuration to:
     /// - Pre-compute lowercase versions of window rule strings for faster matching
+    /// - Warn about rule `screen`s that have no effect
     ///
     /// This is called automatically by [`load_config()`].
     pub fn prepare(&mut self) {
@@ -134,6 +164,11 @@ impl StacheConfig {
             rule.prepare();
         }
 
+        // Prepare float rules
+        for float_rule in &mut self.tiling.float_rules {
+            float_rule.rule.prepare();
+        }
+
         // Prepare workspace rules
         for workspace in &mut self.tiling.workspaces {
             for rule in &mut workspace.rules {
@@ -145,6 +180,44 @@ impl StacheConfig {
         for rule in &mut self.tiling.borders.ignore {
             rule.prepare();
         }
+
+        for path in self.ignored_rule_screens() {
+            tracing::warn!(
+                path = %path,
+                "window rule `screen` only applies in tiling.floatRules, ignoring it"
+            );
+        }
+    }
+
+    /// Returns the paths of rule `screen`s outside of `tiling.floatRules`,
+    /// which have no effect there.
+    fn ignored_rule_screens(&self) -> Vec<String> {
+        let tiling = &self.tiling;
+        let workspace_rules = tiling.workspaces.iter().enumerate().flat_map(|(i, workspace)| {
+            workspace
+                .rules
+                .iter()
+                .enumerate()
+                .map(move |(j, rule)| (format!("tiling.workspaces[{i}].rules[{j}]"), rule))
+        });
+        let ignore_rules = tiling
+            .ignore
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| (format!("tiling.ignore[{i}]"), rule));
+        let border_rules = tiling
+            .borders
+            .ignore
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| (format!("tiling.borders.ignore[{i}]"), rule));
+
+        workspace_rules
+            .chain(ignore_rules)
+            .chain(border_rules)
+            .filter(|(_, rule)| rule.screen.is_some())
+            .map(|(path, _)| format!("{path}.screen"))
+            .collect()
     }
 }
 
@@ -157,6 +230,8 @@ pub enum ConfigError {
     IoError(std::io::Error),
     /// The configuration file contains invalid JSON.
     ParseError(serde_json::Error),
+    /// The configuration file opts into strict mode and contains unknown keys.
+    UnknownKeys(Vec<UnknownKey>),
 }
 
 impl std::fmt::Display for ConfigError {
@@ -169,6 +244,14 @@ impl std::fmt::Display for ConfigError {
             ),
             Self::IoError(err) => write!(f, "Failed to read configuration file: {err}"),
             Self::ParseError(err) => write!(f, "Failed to parse configuration file: {err}"),
+            Self::UnknownKeys(keys) => {
+                let keys: Vec<String> = keys.iter().map(ToString::to_string).collect();
+                write!(
+                    f,
+                    "Invalid configuration file (strict mode): {}",
+                    keys.join("; ")
+                )
+            }
         }
     }
 }
@@ -178,7 +261,7 @@ impl std::error::Error for ConfigError {
         match self {
             Self::IoError(err) => Some(err),
             Self::ParseError(err) => Some(err),
-            Self::NotFound => None,
+            Self::NotFound | Self::UnknownKeys(_) => None,
         }
     }
 }
@@ -270,6 +353,8 @@ pub fn config_paths() -> Vec<PathBuf> {
 /// Returns `ConfigError::NotFound` if the configuration file does not exist.
 /// Returns `ConfigError::IoError` if the configuration file could not be read.
 /// Returns `ConfigError::ParseError` if the configuration file contains invalid JSON.
+/// Returns `ConfigError::UnknownKeys` if the configuration file sets `strict` and
+/// contains keys that aren't configuration options.
 pub fn load_config_from_path(path: &PathBuf) -> Result<(StacheConfig, PathBuf), ConfigError> {
     if !path.exists() {
         return Err(ConfigError::NotFound);
@@ -277,13 +362,26 @@ pub fn load_config_from_path(path: &PathBuf) -> Result<(StacheConfig, PathBuf),
 
     let file = fs::File::open(path)?;
     // Strip comments from JSONC before parsing
-    let reader = json_comments::StripComments::new(file);
-    let mut config: StacheConfig = serde_json::from_reader(reader)?;
+    let mut json = String::new();
+    json_comments::StripComments::new(file).read_to_string(&mut json)?;
+    let mut config = parse_config(&json)?;
     // Pre-compute cached values for faster runtime operations
     config.prepare();
     Ok((config, path.clone()))
 }
 
+/// Parses configuration JSON, rejecting unknown keys if it sets `strict`.
+fn parse_config(json: &str) -> Result<StacheConfig, ConfigError> {
+    let config: StacheConfig = serde_json::from_str(json)?;
+    if config.strict {
+        let unknown = find_unknown_keys(&serde_json::from_str(json)?);
+        if !unknown.is_empty() {
+            return Err(ConfigError::UnknownKeys(unknown));
+        }
+    }
+    Ok(config)
+}
+
 /// Loads the configuration from the first available config file.
 ///
 /// The configuration file supports JSONC format (JSON with comments).
@@ -321,6 +419,28 @@ mod tests {
         assert!(config.keybindings.is_empty());
     }
 
+    #[test]
+    fn test_ignored_rule_screens() {
+        let json = r#"{
+            "tiling": {
+                "floatRules": [{"appName": "Slack", "screen": "secondary"}],
+                "ignore": [{"appName": "Finder", "screen": "main"}],
+                "workspaces": [
+                    {
+                        "name": "dev",
+                        "rules": [{"appId": "com.a"}, {"appId": "com.b", "screen": "main"}]
+                    }
+                ]
+            }
+        }"#;
+
+        let config: StacheConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.ignored_rule_screens(), vec![
+            "tiling.workspaces[0].rules[1].screen",
+            "tiling.ignore[0].screen"
+        ]);
+    }
+
     #[test]
     fn test_config_deserializes_single_command() {
         let json = r#"{
@@ -357,6 +477,27 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_parse_config_ignores_unknown_keys_by_default() {
+        let config = parse_config(r#"{"tiling": {"gapps": {"inner": 8}}}"#).unwrap();
+        assert!(!config.strict);
+    }
+
+    #[test]
+    fn test_parse_config_strict_rejects_misspelled_key() {
+        let err =
+            parse_config(r#"{"strict": true, "tiling": {"gapps": {"inner": 8}}}"#).unwrap_err();
+        assert!(matches!(&err, ConfigError::UnknownKeys(keys) if keys.len() == 1));
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration file (strict mode): unknown key `tiling.gapps` \
+            (did you mean `gaps`?)"
+        );
+
+        let config = parse_config(r#"{"strict": true, "tiling": {"gaps": {"inner": 8}}}"#).unwrap();
+        assert!(config.strict);
+    }
+
     #[test]
     fn test_config_paths_are_not_empty() {
         let paths = config_paths();
@@ -0,0 +1,242 @@
+//! Strict configuration validation.
+//!
+//! Config structs use `#[serde(default)]`, so unknown keys are silently
+//! ignored and a typo like `gapps` just leaves the default in place. With
+//! `"strict": true` in the config file, every key is checked against the
+//! generated JSON schema and unknown keys are reported with their path.
+//!
+//! Serde's `deny_unknown_fields` can't be switched on at runtime and doesn't
+//! work with `#[serde(flatten)]` (used by `FloatRule`), so the check walks the
+//! schema instead. Values matched by an untagged enum are only checked when
+//! exactly one of its variants is an object, since otherwise there is no way
+//! to know which variant was meant.
+
+use serde_json::{Map, Value};
+
+use crate::schema::generate_schema;
+
+/// A key in the config file that no config struct knows about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKey {
+    /// Dotted path to the key, e.g. `tiling.gapps` or `tiling.workspaces[0].nmae`.
+    pub path: String,
+    /// The closest known key at the same level, if any is close enough.
+    pub suggestion: Option<String>,
+}
+
+impl std::fmt::Display for UnknownKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown key `{}`", self.path)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean `{suggestion}`?)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the keys in `config` that aren't part of the config schema.
+#[must_use]
+pub fn find_unknown_keys(config: &Value) -> Vec<UnknownKey> {
+    let schema = generate_schema();
+    let mut unknown = Vec::new();
+    check_value(config, schema.as_value(), schema.as_value(), "", &mut unknown);
+    unknown.sort_by(|a, b| a.path.cmp(&b.path));
+    unknown
+}
+
+/// Follows `$ref` pointers into the schema's `$defs`.
+fn resolve<'a>(schema: &'a Value, root: &'a Value) -> &'a Value {
+    let mut schema = schema;
+    while let Some(name) = schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix("#/$defs/"))
+    {
+        let Some(def) = root.get("$defs").and_then(|defs| defs.get(name)) else {
+            break;
+        };
+        schema = def;
+    }
+    schema
+}
+
+/// Returns the object schema to check a JSON object against.
+///
+/// For `anyOf`/`oneOf` schemas, this is the only variant with properties;
+/// `None` if there are several, as the intended one can't be known.
+fn object_schema<'a>(schema: &'a Value, root: &'a Value) -> Option<&'a Value> {
+    let schema = resolve(schema, root);
+    if schema.get("properties").is_some() || schema.get("additionalProperties").is_some() {
+        return Some(schema);
+    }
+
+    let variants = schema.get("anyOf").or_else(|| schema.get("oneOf"))?.as_array()?;
+    let mut objects = variants
+        .iter()
+        .filter_map(|variant| object_schema(variant, root))
+        .filter(|variant| variant.get("properties").is_some());
+    let object = objects.next()?;
+    objects.next().is_none().then_some(object)
+}
+
+/// Returns the item schema to check a JSON array against.
+fn array_schema<'a>(schema: &'a Value, root: &'a Value) -> Option<&'a Value> {
+    let schema = resolve(schema, root);
+    if let Some(items) = schema.get("items") {
+        return Some(items);
+    }
+
+    let variants = schema.get("anyOf").or_else(|| schema.get("oneOf"))?.as_array()?;
+    let mut arrays = variants.iter().filter_map(|variant| resolve(variant, root).get("items"));
+    let items = arrays.next()?;
+    arrays.next().is_none().then_some(items)
+}
+
+fn check_value(
+    value: &Value,
+    schema: &Value,
+    root: &Value,
+    path: &str,
+    unknown: &mut Vec<UnknownKey>,
+) {
+    match value {
+        Value::Object(map) => {
+            if let Some(schema) = object_schema(schema, root) {
+                check_object(map, schema, root, path, unknown);
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = array_schema(schema, root) {
+                for (index, item) in items.iter().enumerate() {
+                    check_value(item, item_schema, root, &format!("{path}[{index}]"), unknown);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_object(
+    map: &Map<String, Value>,
+    schema: &Value,
+    root: &Value,
+    path: &str,
+    unknown: &mut Vec<UnknownKey>,
+) {
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties").filter(|additional| additional.is_object());
+
+    for (key, value) in map {
+        let key_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+
+        if let Some(property) = properties.and_then(|properties| properties.get(key)) {
+            check_value(value, property, root, &key_path, unknown);
+        } else if let Some(additional) = additional {
+            // Maps such as `keybindings` accept any key
+            check_value(value, additional, root, &key_path, unknown);
+        } else if properties.is_some() && !(path.is_empty() && key.starts_with('$')) {
+            // `$schema` and similar editor hints are allowed at the root
+            unknown.push(UnknownKey {
+                path: key_path,
+                suggestion: properties.and_then(|properties| closest_key(key, properties.keys())),
+            });
+        }
+    }
+}
+
+/// Returns the candidate closest to `key`, if it's within a few edits.
+fn closest_key<'a>(key: &str, candidates: impl Iterator<Item = &'a String>) -> Option<String> {
+    let max_distance = (key.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| {
+            (
+                edit_distance(&key.to_lowercase(), &candidate.to_lowercase()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.clone())
+}
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_valid_config_has_no_unknown_keys() {
+        let config = json!({
+            "$schema": "https://example.com/stache.schema.json",
+            "strict": true,
+            "keybindings": {"Command+Control+R": "stache reload"},
+            "tiling": {
+                "gaps": {"inner": 8, "outer": 8},
+                "floatRules": [{"appName": "Calculator", "preset": "centered"}],
+                "workspaces": [{"name": "main", "rules": [{"appId": "com.apple.Safari"}]}],
+            },
+        });
+        assert!(find_unknown_keys(&config).is_empty());
+    }
+
+    #[test]
+    fn test_misspelled_keys_are_reported_with_path() {
+        let config = json!({
+            "strict": true,
+            "tilling": {},
+            "tiling": {
+                "gapps": {"inner": 8},
+                "workspaces": [{"name": "main", "layuot": "dwindle"}],
+                "floatRules": [{"appNmae": "Calculator"}],
+            },
+        });
+
+        let unknown = find_unknown_keys(&config);
+        let messages: Vec<String> = unknown.iter().map(ToString::to_string).collect();
+        assert_eq!(messages, vec![
+            "unknown key `tiling.floatRules[0].appNmae` (did you mean `appName`?)",
+            "unknown key `tiling.gapps` (did you mean `gaps`?)",
+            "unknown key `tiling.workspaces[0].layuot` (did you mean `layout`?)",
+            "unknown key `tilling` (did you mean `tiling`?)",
+        ]);
+    }
+
+    #[test]
+    fn test_unknown_key_without_close_match() {
+        let unknown = find_unknown_keys(&json!({"tiling": {"somethingElse": true}}));
+        assert_eq!(unknown, vec![UnknownKey {
+            path: "tiling.somethingElse".to_string(),
+            suggestion: None,
+        }]);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("gaps", "gaps"), 0);
+        assert_eq!(edit_distance("gapps", "gaps"), 1);
+        assert_eq!(edit_distance("layuot", "layout"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
@@ -3,12 +3,15 @@
 //! Core configuration types for the tiling window manager including layouts,
 //! animations, floating window settings, and master layout configuration.
 
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::borders::BordersConfig;
-use super::gaps::{DimensionValue, GapsConfigValue};
-use super::workspaces::{WindowRule, WorkspaceConfig};
+use super::gaps::{DimensionValue, GapsConfigValue, ReservedConfigValue, WorkspaceGapsConfig};
+use super::workspaces::{FloatRule, WindowRule, WorkspaceConfig};
 
 /// Layout type for workspaces.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -24,10 +27,16 @@ pub enum LayoutType {
     SplitHorizontal,
     /// Monocle layout - all windows maximized, stacked.
     Monocle,
+    /// Tabbed layout - like monocle, with the stacked windows shown as tabs.
+    Tabbed,
     /// Master layout - one master window with stack.
     Master,
     /// Grid layout - windows arranged in a grid pattern.
     Grid,
+    /// Columns layout - equal-width, full-height columns from left to right.
+    Columns,
+    /// Scrolling layout - fixed-width columns that scroll horizontally.
+    Scrolling,
     /// Floating layout - windows can be freely moved and resized.
     #[default]
     Floating,
@@ -63,6 +72,34 @@ pub enum FloatingPosition {
     Default,
 }
 
+/// Where newly opened windows animate into their layout position from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum OpenFrom {
+    /// Appear at the layout position without animating.
+    #[default]
+    None,
+    /// Move from where the app opened the window.
+    Spawn,
+    /// Grow from the center of the screen.
+    Center,
+    /// Grow from the mouse pointer.
+    Cursor,
+}
+
+/// Where windows animate out to before they are minimized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum CloseTo {
+    /// Disappear without animating.
+    #[default]
+    None,
+    /// Shrink into the center of the screen.
+    Center,
+    /// Shrink into the mouse pointer.
+    Cursor,
+}
+
 /// Animation configuration for window transitions.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, rename_all = "camelCase")]
@@ -79,6 +116,25 @@ pub struct AnimationConfig {
     /// Easing function for animations.
     /// Default: "ease-out"
     pub easing: EasingType,
+
+    /// Where newly opened windows animate from. Only used when animations
+    /// are enabled.
+    /// Default: "none"
+    pub open_from: OpenFrom,
+
+    /// Where windows animate out to when `tiling window --minimize` takes
+    /// them off screen. Windows closed by their app are already gone when
+    /// they are reported, so they can't animate out.
+    /// Default: "none"
+    pub close_to: CloseTo,
+
+    /// Per-transition overrides of `duration` and `easing`.
+    /// Transitions without an entry use the settings above.
+    pub transitions: AnimationTransitions,
+
+    /// Physics of the `spring` easing. Omitted values keep the default
+    /// spring, which settles within `duration` with barely any overshoot.
+    pub spring: SpringConfig,
 }
 
 impl Default for AnimationConfig {
@@ -87,10 +143,80 @@ impl Default for AnimationConfig {
             enabled: false,
             duration: 200,
             easing: EasingType::EaseOut,
+            open_from: OpenFrom::None,
+            close_to: CloseTo::None,
+            transitions: AnimationTransitions::default(),
+            spring: SpringConfig::default(),
         }
     }
 }
 
+/// Physics of the `spring` easing.
+///
+/// Values are clamped to a safe range. Lower damping makes animations
+/// bouncier, higher stiffness makes them faster.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SpringConfig {
+    /// Spring stiffness (1 - 5000). If omitted, derived from the animation
+    /// duration so the spring settles in time. When set, it decides how fast
+    /// spring animations run, overriding `duration` and the durations in
+    /// `transitions`. Springs too slow to settle within a second are sped up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stiffness: Option<f64>,
+
+    /// Damping coefficient (greater than 0). If omitted, the spring is
+    /// slightly underdamped (damping ratio 0.85). The resulting damping
+    /// ratio is clamped to 0.1 - 2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub damping: Option<f64>,
+
+    /// Mass of the animated window (0.1 - 10).
+    /// Default: 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mass: Option<f64>,
+}
+
+/// Duration and easing overrides for one kind of window transition.
+///
+/// Omitted fields fall back to the global animation settings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AnimationOverride {
+    /// Animation duration in milliseconds for large movements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+
+    /// Easing function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub easing: Option<EasingType>,
+}
+
+/// Animation overrides keyed by the kind of window transition.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AnimationTransitions {
+    /// Windows making room for a newly opened window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open: Option<AnimationOverride>,
+
+    /// Windows filling the space left by a closed window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<AnimationOverride>,
+
+    /// Windows moving without changing size (swaps, moves, layout changes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#move: Option<AnimationOverride>,
+
+    /// Windows changing size (resizes, ratio changes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resize: Option<AnimationOverride>,
+
+    /// Windows settling into place after switching workspaces.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_switch: Option<AnimationOverride>,
+}
+
 /// Floating window preset for quick positioning.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -115,10 +241,73 @@ pub struct FloatingPreset {
     /// If true, center the window on screen (x and y are ignored).
     #[serde(default)]
     pub center: bool,
+
+    /// Grid cell for presets generated from `floating.grid`.
+    /// When set, the frame is computed from the cell instead of the dimensions.
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub grid_cell: Option<FloatingGridCell>,
+}
+
+/// A cell of the floating preset grid (1-based row and column).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatingGridCell {
+    /// Row of the cell, starting at 1.
+    pub row: u32,
+    /// Column of the cell, starting at 1.
+    pub col: u32,
+    /// Total number of rows in the grid.
+    pub rows: u32,
+    /// Total number of columns in the grid.
+    pub cols: u32,
+}
+
+/// Grid of generated floating presets.
+///
+/// Registers one preset per cell, named `grid-r{row}c{col}` (1-based),
+/// e.g. `grid-r1c1` for the top-left cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FloatingGridConfig {
+    /// Number of columns.
+    pub cols: u32,
+
+    /// Number of rows.
+    pub rows: u32,
+}
+
+impl FloatingGridConfig {
+    /// Returns the preset name for a grid cell.
+    #[must_use]
+    pub fn preset_name(row: u32, col: u32) -> String { format!("grid-r{row}c{col}") }
+
+    /// Generates one preset per cell, row by row.
+    ///
+    /// Returns no presets if either dimension is zero.
+    #[must_use]
+    pub fn presets(&self) -> Vec<FloatingPreset> {
+        let (rows, cols) = (self.rows, self.cols);
+        if rows == 0 || cols == 0 {
+            return Vec::new();
+        }
+
+        (1..=rows)
+            .flat_map(|row| (1..=cols).map(move |col| (row, col)))
+            .map(|(row, col)| FloatingPreset {
+                name: Self::preset_name(row, col),
+                width: DimensionValue::Percentage(format!("{}%", 100.0 / f64::from(cols))),
+                height: DimensionValue::Percentage(format!("{}%", 100.0 / f64::from(rows))),
+                x: None,
+                y: None,
+                center: false,
+                grid_cell: Some(FloatingGridCell { row, col, rows, cols }),
+            })
+            .collect()
+    }
 }
 
 /// Floating windows configuration.
-#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, rename_all = "camelCase")]
 pub struct FloatingConfig {
     /// Default position for new floating windows.
@@ -127,6 +316,27 @@ pub struct FloatingConfig {
 
     /// Named presets for window positioning.
     pub presets: Vec<FloatingPreset>,
+
+    /// Grid of generated presets (`grid-r1c1` .. `grid-r{rows}c{cols}`).
+    /// Presets with the same name in `presets` take precedence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grid: Option<FloatingGridConfig>,
+
+    /// Pixels a floating window is moved by `stache tiling window --move`
+    /// when no step is given.
+    /// Default: 50
+    pub move_step: u32,
+}
+
+impl Default for FloatingConfig {
+    fn default() -> Self {
+        Self {
+            default_position: FloatingPosition::Center,
+            presets: Vec::new(),
+            grid: None,
+            move_step: 50,
+        }
+    }
 }
 
 /// Position of the master window in the master layout.
@@ -158,6 +368,10 @@ pub struct MasterConfig {
     /// Position of the master window.
     /// Default: auto (left for landscape, top for portrait)
     pub position: MasterPosition,
+    /// Number of windows placed in the master area.
+    /// Can be adjusted per workspace at runtime.
+    /// Default: 1
+    pub max_masters: u32,
 }
 
 impl Default for MasterConfig {
@@ -165,10 +379,149 @@ impl Default for MasterConfig {
         Self {
             ratio: 60,
             position: MasterPosition::Auto,
+            max_masters: 1,
+        }
+    }
+}
+
+/// Scrolling layout configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ScrollingConfig {
+    /// Width of each column: pixels (960) or percentage of the screen ("50%").
+    /// Default: "50%"
+    pub column_width: DimensionValue,
+}
+
+impl Default for ScrollingConfig {
+    fn default() -> Self {
+        Self {
+            column_width: DimensionValue::Percentage("50%".to_string()),
         }
     }
 }
 
+/// Scratchpad configuration.
+///
+/// The scratchpad is a hidden workspace whose windows are shown over the
+/// current workspace on demand (`stache tiling scratchpad toggle`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ScratchpadConfig {
+    /// Name of the scratchpad workspace. It can't be switched to like a
+    /// regular workspace, so it shouldn't match a configured workspace.
+    /// Default: "scratchpad"
+    pub name: String,
+}
+
+impl Default for ScratchpadConfig {
+    fn default() -> Self { Self { name: "scratchpad".to_string() } }
+}
+
+/// Timing for new windows before they are tiled.
+///
+/// Some apps (e.g. Electron apps) open a window and resize it shortly after.
+/// With a `timeoutMs`, a new window is only tiled once its frame stops
+/// changing, so the layout isn't applied to a frame the app then replaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WindowReadyConfig {
+    /// Milliseconds to wait after a window appears before checking its frame.
+    /// Default: 0
+    pub initial_delay_ms: u64,
+
+    /// Longest time in milliseconds to wait for a new window's frame to
+    /// settle. The window is tiled with its latest frame once it settles or
+    /// this runs out. 0 tiles new windows right away.
+    /// Default: 0
+    pub timeout_ms: u64,
+
+    /// Milliseconds between frame checks while waiting.
+    /// Default: 50
+    pub poll_interval_ms: u64,
+}
+
+impl Default for WindowReadyConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: 0,
+            timeout_ms: 0,
+            poll_interval_ms: 50,
+        }
+    }
+}
+
+impl WindowReadyConfig {
+    /// Returns whether new windows are tiled right away.
+    #[must_use]
+    pub const fn is_immediate(&self) -> bool { self.initial_delay_ms == 0 && self.timeout_ms == 0 }
+
+    /// Returns the delay before the first frame check.
+    #[must_use]
+    pub const fn initial_delay(&self) -> Duration { Duration::from_millis(self.initial_delay_ms) }
+
+    /// Returns the longest wait for a frame to settle.
+    #[must_use]
+    pub const fn timeout(&self) -> Duration { Duration::from_millis(self.timeout_ms) }
+
+    /// Returns the interval between frame checks, at least 1 ms.
+    #[must_use]
+    pub const fn poll_interval(&self) -> Duration {
+        Duration::from_millis(if self.poll_interval_ms == 0 {
+            1
+        } else {
+            self.poll_interval_ms
+        })
+    }
+}
+
+/// How the tiling window manager keeps its window list in sync with macOS.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum TrackingMode {
+    /// Trust AX observer events, and re-query macOS when an event can't be
+    /// matched to a tracked window (e.g. a destroyed element with no window ID).
+    #[default]
+    Hybrid,
+    /// Trust AX observer events and cached window state only.
+    /// Avoids enumerating running apps and their windows on the event path,
+    /// which is faster, but windows whose events are missed can drift until
+    /// the next full sync.
+    ObserverOnly,
+}
+
+impl TrackingMode {
+    /// Returns whether events may fall back to re-querying macOS.
+    #[must_use]
+    pub const fn allows_requery(self) -> bool { matches!(self, Self::Hybrid) }
+}
+
+/// Whether a window opening on a hidden workspace switches to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum FocusNewWindows {
+    /// Switch to the workspace the new window opened on.
+    #[default]
+    Always,
+    /// Track the new window on its workspace without switching to it.
+    Never,
+    /// Switch only when the workspace is on the focused screen.
+    SameScreen,
+}
+
+/// What happens when the last window on the focused workspace closes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum OnEmptyWorkspace {
+    /// Stay on the empty workspace.
+    #[default]
+    Stay,
+    /// Switch to the previously focused workspace on the same screen.
+    SwitchPrev,
+    /// Switch to the first workspace on the same screen.
+    SwitchFirst,
+}
+
 /// Tiling window manager configuration.
 ///
 /// Provides virtual workspace management with multiple layout modes,
@@ -184,6 +537,17 @@ pub struct TilingConfig {
     /// Default: "dwindle"
     pub default_layout: LayoutType,
 
+    /// Default layout per screen, keyed by screen name (case-insensitive) or
+    /// "main"/"primary"/"secondary". Used for workspaces without a configured
+    /// layout; screens without an entry use `defaultLayout`.
+    /// Default: {}
+    pub per_screen_layout: HashMap<String, LayoutType>,
+
+    /// Layouts rotated through by `stache tiling workspace --layout cycle`, in order.
+    /// Workspaces using a layout that isn't listed start from the first entry.
+    /// If empty, cycles through all layouts.
+    pub layout_cycle: Vec<LayoutType>,
+
     /// Workspace definitions.
     /// If empty and tiling is enabled, creates one default workspace per screen.
     pub workspaces: Vec<WorkspaceConfig>,
@@ -191,21 +555,138 @@ pub struct TilingConfig {
     /// Applications/windows to ignore (never managed by tiling).
     pub ignore: Vec<WindowRule>,
 
+    /// Applications that are never tracked at all, by name or bundle ID.
+    /// Unlike `ignore`, windows of these apps are not observed, assigned to
+    /// workspaces or moved. Matching is exact and case-insensitive.
+    pub ignore_apps: Vec<String>,
+
+    /// Screens tiling leaves alone, by display name.
+    /// No workspaces are created on these screens, windows are never moved
+    /// there and windows on them are not tracked. Matching is exact and
+    /// case-insensitive.
+    pub ignore_screens: Vec<String>,
+
+    /// Rules for windows that open floating, whatever workspace they land on.
+    /// The first matching rule wins and can apply a floating preset.
+    pub float_rules: Vec<FloatRule>,
+
+    /// Whether directional focus crosses screens.
+    /// When no window is found in the direction on the focused workspace,
+    /// focus moves to the nearest window on the visible workspace of the
+    /// adjacent screen in that direction, wrapping around at the outermost
+    /// screen. Default: false
+    pub focus_wraps: bool,
+
+    /// Whether hovering a window focuses it (focus follows mouse).
+    /// Focus moves once the pointer rests over a tracked window for a
+    /// moment, never while a mouse button is held or a window is dragged.
+    /// Only read at startup, so changing it needs an app restart.
+    /// Default: false
+    pub focus_follows_mouse: bool,
+
+    /// Whether the pointer moves to the center of a window focused from the
+    /// keyboard, e.g. with `stache tiling window --focus` (mouse follows
+    /// focus). The pointer stays put if it's already over the window.
+    /// Default: false
+    pub mouse_follows_focus: bool,
+
+    /// Whether a window opening on a hidden workspace (e.g. through a
+    /// workspace rule) switches to that workspace and takes focus.
+    /// "never" keeps the current workspace; "same-screen" only switches when
+    /// the workspace is on the focused screen.
+    /// Default: "always"
+    pub focus_new_windows: FocusNewWindows,
+
+    /// What happens when the last window on the focused workspace closes:
+    /// "stay", "switch-prev" (the previously focused workspace on the same
+    /// screen) or "switch-first" (the first workspace on the same screen).
+    /// A tab shown in place of a closed tab doesn't leave the workspace empty.
+    /// Default: "stay"
+    pub on_empty_workspace: OnEmptyWorkspace,
+
+    /// Whether windows are moved back to where they were before tiling when
+    /// Stache quits. `stache tiling restore` does the same on demand.
+    /// Default: false
+    pub restore_on_exit: bool,
+
+    /// How window events are reconciled with macOS.
+    /// "hybrid" re-queries macOS when an event is ambiguous; "observer-only"
+    /// trusts AX events and cached state, trading accuracy for speed.
+    /// Default: "hybrid"
+    pub tracking_mode: TrackingMode,
+
     /// Animation settings for window transitions.
     pub animations: AnimationConfig,
 
     /// Gap configuration (global or per-screen).
     pub gaps: GapsConfigValue,
 
+    /// Named gaps switched to at runtime with `stache tiling gaps <preset>`,
+    /// e.g. `{"dense": {"inner": 0, "outer": 0}}`. An active preset replaces
+    /// workspace gap overrides; unset fields fall back to `gaps`.
+    /// `stache tiling gaps cycle` goes through presets in alphabetical order.
+    /// Default: {}
+    pub gap_presets: BTreeMap<String, WorkspaceGapsConfig>,
+
+    /// Whether gaps are dropped when a workspace has a single tiled window,
+    /// so it fills the screen. Gaps come back when a second window is tiled.
+    /// Default: false
+    pub smart_gaps: bool,
+
+    /// Whether split ratios are reset to even splits whenever the number of
+    /// tiled windows in a workspace changes (a window opens, closes, is
+    /// minimized, floated or moved away). When off, resized splits are kept.
+    /// Default: false
+    pub auto_balance: bool,
+
+    /// Pixels a window grows or shrinks by with `stache tiling window --grow`
+    /// and `--shrink`. Splits stop at their limits, so repeated steps saturate.
+    /// Default: 40
+    pub resize_step: u32,
+
+    /// Space reserved at screen edges for external bars or docks (global or
+    /// per-screen). It is taken off each screen's usable frame, so layouts,
+    /// presets and floating placement all stay clear of it.
+    pub reserved: ReservedConfigValue,
+
     /// Floating window presets and settings.
     pub floating: FloatingConfig,
 
+    /// Whether floating and sticky windows are kept above tiled windows.
+    /// They are raised again after every layout, focus or workspace change.
+    /// Override per window with `stache tiling window --always-on-top`.
+    /// Default: false
+    pub floating_always_on_top: bool,
+
     /// Master layout settings.
     pub master: MasterConfig,
 
+    /// Scrolling layout settings.
+    pub scrolling: ScrollingConfig,
+
+    /// Scratchpad settings.
+    pub scratchpad: ScratchpadConfig,
+
+    /// Timing for new windows before they are tiled.
+    pub window_ready: WindowReadyConfig,
+
+    /// Whether windows matching a rule with `useApplescriptFrames` are moved
+    /// and resized through `osascript` (System Events) when the accessibility
+    /// API can't reach them. Much slower than the accessibility API and
+    /// requires the Automation permission for System Events (System Settings >
+    /// Privacy & Security > Automation).
+    /// Default: false
+    pub applescript_fallback: bool,
+
     /// Window border configuration.
     /// Borders provide visual feedback for focus state and layout mode.
     pub borders: BordersConfig,
+
+    /// Opacity of unfocused tiled windows (0.0 - 1.0).
+    /// Values below 1.0 dim tiled windows when they lose focus.
+    /// Floating and sticky windows are never dimmed.
+    /// Default: 1.0
+    pub unfocused_opacity: f64,
 }
 
 impl Default for TilingConfig {
@@ -213,13 +694,36 @@ impl Default for TilingConfig {
         Self {
             enabled: false,
             default_layout: LayoutType::Dwindle,
+            per_screen_layout: HashMap::new(),
+            layout_cycle: Vec::new(),
             workspaces: Vec::new(),
             ignore: Vec::new(),
+            ignore_apps: Vec::new(),
+            ignore_screens: Vec::new(),
+            float_rules: Vec::new(),
+            focus_wraps: false,
+            focus_follows_mouse: false,
+            mouse_follows_focus: false,
+            focus_new_windows: FocusNewWindows::Always,
+            on_empty_workspace: OnEmptyWorkspace::Stay,
+            restore_on_exit: false,
+            tracking_mode: TrackingMode::Hybrid,
             animations: AnimationConfig::default(),
             gaps: GapsConfigValue::default(),
+            gap_presets: BTreeMap::new(),
+            smart_gaps: false,
+            auto_balance: false,
+            resize_step: 40,
+            reserved: ReservedConfigValue::default(),
             floating: FloatingConfig::default(),
+            floating_always_on_top: false,
             master: MasterConfig::default(),
+            scrolling: ScrollingConfig::default(),
+            scratchpad: ScratchpadConfig::default(),
+            window_ready: WindowReadyConfig::default(),
+            applescript_fallback: false,
             borders: BordersConfig::default(),
+            unfocused_opacity: 1.0,
         }
     }
 }
@@ -228,12 +732,146 @@ impl TilingConfig {
     /// Returns whether the tiling window manager is enabled.
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Returns the unfocused window opacity, clamped to 0.0 - 1.0.
+    #[must_use]
+    pub const fn unfocused_opacity(&self) -> f64 { self.unfocused_opacity.clamp(0.0, 1.0) }
+
+    /// Returns whether unfocused tiled windows should be dimmed.
+    #[must_use]
+    pub const fn dims_unfocused_windows(&self) -> bool { self.unfocused_opacity() < 1.0 }
+
+    /// Returns the gaps override for the named workspace, if configured.
+    #[must_use]
+    pub fn workspace_gaps(&self, workspace_name: &str) -> Option<&WorkspaceGapsConfig> {
+        self.workspaces
+            .iter()
+            .find(|ws| ws.name == workspace_name)
+            .and_then(|ws| ws.gaps.as_ref())
+    }
+
+    /// Returns the gaps override for the named workspace, or the gap preset
+    /// when one is active.
+    ///
+    /// An active preset wins over the workspace's own override. Presets that
+    /// are no longer in the config are ignored.
+    #[must_use]
+    pub fn gaps_override(
+        &self,
+        workspace_name: &str,
+        gap_preset: Option<&str>,
+    ) -> Option<&WorkspaceGapsConfig> {
+        gap_preset
+            .and_then(|name| self.gap_presets.get(name))
+            .or_else(|| self.workspace_gaps(workspace_name))
+    }
+
+    /// Returns whether the screen is listed in `ignore_screens`.
+    #[must_use]
+    pub fn is_screen_ignored(&self, screen_name: &str) -> bool {
+        self.ignore_screens.iter().any(|name| name.eq_ignore_ascii_case(screen_name))
+    }
+
+    /// Returns the default layout for workspaces on the given screen.
+    ///
+    /// An entry matching the screen name wins over "main"/"primary" and
+    /// "secondary" entries, which match by role. Falls back to `default_layout`.
+    #[must_use]
+    pub fn layout_for_screen(&self, screen_name: &str, is_main_screen: bool) -> LayoutType {
+        let by_name = self
+            .per_screen_layout
+            .iter()
+            .find(|(screen, _)| screen.eq_ignore_ascii_case(screen_name));
+        let by_role = || {
+            self.per_screen_layout.iter().find(|(screen, _)| {
+                ((screen.eq_ignore_ascii_case("main") || screen.eq_ignore_ascii_case("primary"))
+                    && is_main_screen)
+                    || (screen.eq_ignore_ascii_case("secondary") && !is_main_screen)
+            })
+        };
+
+        by_name.or_else(by_role).map_or(self.default_layout, |(_, layout)| *layout)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_floating_grid_presets() {
+        let config: FloatingConfig =
+            serde_json::from_str(r#"{"grid": {"cols": 3, "rows": 2}}"#).unwrap();
+        let presets = config.grid.unwrap().presets();
+
+        let names: Vec<&str> = presets.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, [
+            "grid-r1c1",
+            "grid-r1c2",
+            "grid-r1c3",
+            "grid-r2c1",
+            "grid-r2c2",
+            "grid-r2c3"
+        ]);
+        assert_eq!(
+            presets[5].grid_cell,
+            Some(FloatingGridCell {
+                row: 2,
+                col: 3,
+                rows: 2,
+                cols: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_floating_grid_empty() {
+        assert!(FloatingGridConfig { cols: 0, rows: 2 }.presets().is_empty());
+        assert!(FloatingConfig::default().grid.is_none());
+    }
+
+    #[test]
+    fn test_floating_move_step() {
+        assert_eq!(FloatingConfig::default().move_step, 50);
+
+        let config: FloatingConfig = serde_json::from_str(r#"{"moveStep": 20}"#).unwrap();
+        assert_eq!(config.move_step, 20);
+    }
+
+    #[test]
+    fn test_floating_always_on_top() {
+        assert!(!TilingConfig::default().floating_always_on_top);
+
+        let config: TilingConfig =
+            serde_json::from_str(r#"{"floatingAlwaysOnTop": true}"#).unwrap();
+        assert!(config.floating_always_on_top);
+    }
+
+    #[test]
+    fn test_scratchpad_config() {
+        assert_eq!(TilingConfig::default().scratchpad.name, "scratchpad");
+
+        let config: TilingConfig =
+            serde_json::from_str(r#"{"scratchpad": {"name": "drawer"}}"#).unwrap();
+        assert_eq!(config.scratchpad.name, "drawer");
+    }
+
+    #[test]
+    fn test_window_ready_config() {
+        let config = TilingConfig::default().window_ready;
+        assert!(config.is_immediate());
+        assert_eq!(config.poll_interval(), Duration::from_millis(50));
+
+        let config: TilingConfig = serde_json::from_str(
+            r#"{"windowReady": {"initialDelayMs": 100, "timeoutMs": 1000, "pollIntervalMs": 0}}"#,
+        )
+        .unwrap();
+        assert!(!config.window_ready.is_immediate());
+        assert_eq!(config.window_ready.initial_delay(), Duration::from_millis(100));
+        assert_eq!(config.window_ready.timeout(), Duration::from_secs(1));
+        assert_eq!(config.window_ready.poll_interval(), Duration::from_millis(1));
+    }
+
     #[test]
     fn test_layout_type_default_is_floating() {
         assert_eq!(LayoutType::default(), LayoutType::Floating);
@@ -250,6 +888,96 @@ mod tests {
         assert!(!config.enabled);
         assert_eq!(config.duration, 200);
         assert_eq!(config.easing, EasingType::EaseOut);
+        assert_eq!(config.open_from, OpenFrom::None);
+        assert_eq!(config.close_to, CloseTo::None);
+        assert_eq!(config.transitions, AnimationTransitions::default());
+        assert_eq!(config.spring, SpringConfig::default());
+    }
+
+    #[test]
+    fn test_animation_config_spring() {
+        let json = r#"{"spring": {"stiffness": 300, "damping": 20.5}}"#;
+        let config: AnimationConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.spring, SpringConfig {
+            stiffness: Some(300.0),
+            damping: Some(20.5),
+            mass: None,
+        });
+
+        let json = serde_json::to_value(config.spring).unwrap();
+        assert_eq!(json, serde_json::json!({"stiffness": 300.0, "damping": 20.5}));
+    }
+
+    #[test]
+    fn test_animation_config_open_from() {
+        for (value, expected) in [
+            ("none", OpenFrom::None),
+            ("spawn", OpenFrom::Spawn),
+            ("center", OpenFrom::Center),
+            ("cursor", OpenFrom::Cursor),
+        ] {
+            let json = format!(r#"{{"openFrom": "{value}"}}"#);
+            let config: AnimationConfig = serde_json::from_str(&json).unwrap();
+            assert_eq!(config.open_from, expected);
+        }
+
+        assert!(serde_json::from_str::<AnimationConfig>(r#"{"openFrom": "top"}"#).is_err());
+    }
+
+    #[test]
+    fn test_animation_config_close_to() {
+        for (value, expected) in [
+            ("none", CloseTo::None),
+            ("center", CloseTo::Center),
+            ("cursor", CloseTo::Cursor),
+        ] {
+            let json = format!(r#"{{"closeTo": "{value}"}}"#);
+            let config: AnimationConfig = serde_json::from_str(&json).unwrap();
+            assert_eq!(config.close_to, expected);
+        }
+
+        assert!(serde_json::from_str::<AnimationConfig>(r#"{"closeTo": "spawn"}"#).is_err());
+    }
+
+    #[test]
+    fn test_animation_config_without_transitions() {
+        let json = r#"{"enabled": true, "duration": 250, "easing": "spring"}"#;
+        let config: AnimationConfig = serde_json::from_str(json).unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.duration, 250);
+        assert_eq!(config.easing, EasingType::Spring);
+        assert_eq!(config.transitions, AnimationTransitions::default());
+    }
+
+    #[test]
+    fn test_animation_config_transitions() {
+        let json = r#"{
+            "transitions": {
+                "workspaceSwitch": {"duration": 120, "easing": "ease-out-expo"},
+                "open": {"easing": "ease-in-out"},
+                "move": {}
+            }
+        }"#;
+        let config: AnimationConfig = serde_json::from_str(json).unwrap();
+        let transitions = config.transitions;
+
+        assert_eq!(
+            transitions.workspace_switch,
+            Some(AnimationOverride {
+                duration: Some(120),
+                easing: Some(EasingType::EaseOutExpo),
+            })
+        );
+        assert_eq!(
+            transitions.open,
+            Some(AnimationOverride {
+                duration: None,
+                easing: Some(EasingType::EaseInOut),
+            })
+        );
+        assert_eq!(transitions.r#move, Some(AnimationOverride::default()));
+        assert!(transitions.close.is_none());
+        assert!(transitions.resize.is_none());
     }
 
     #[test]
@@ -257,6 +985,34 @@ mod tests {
         let config = MasterConfig::default();
         assert_eq!(config.ratio, 60);
         assert_eq!(config.position, MasterPosition::Auto);
+        assert_eq!(config.max_masters, 1);
+    }
+
+    #[test]
+    fn test_master_config_max_masters_serialization() {
+        let json = r#"{"ratio": 55, "maxMasters": 2}"#;
+        let config: MasterConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.ratio, 55);
+        assert_eq!(config.max_masters, 2);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_scrolling_config_default() {
+        let config = ScrollingConfig::default();
+        assert_eq!(config.column_width.resolve(1000.0), 500.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_scrolling_config_column_width_serialization() {
+        let json = r#"{"scrolling": {"columnWidth": 800}}"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.scrolling.column_width.resolve(1000.0), 800.0);
+
+        let json = r#"{"scrolling": {"columnWidth": "40%"}}"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.scrolling.column_width.resolve(1000.0), 400.0);
     }
 
     #[test]
@@ -265,6 +1021,142 @@ mod tests {
         assert!(!config.is_enabled());
         assert_eq!(config.default_layout, LayoutType::Dwindle);
         assert!(config.workspaces.is_empty());
+        assert!(config.ignore_apps.is_empty());
+        assert!(config.ignore_screens.is_empty());
+        assert!(config.layout_cycle.is_empty());
+        assert!(!config.focus_wraps);
+        assert!(!config.focus_follows_mouse);
+        assert!(!config.mouse_follows_focus);
+        assert!(!config.restore_on_exit);
+        assert_eq!(config.tracking_mode, TrackingMode::Hybrid);
+        assert!(!config.smart_gaps);
+        assert!(!config.auto_balance);
+        assert_eq!(config.resize_step, 40);
+        assert!(config.gap_presets.is_empty());
+        assert!(config.reserved.for_screen("Built-in", true).is_empty());
+        assert_eq!(config.focus_new_windows, FocusNewWindows::Always);
+        assert_eq!(config.on_empty_workspace, OnEmptyWorkspace::Stay);
+        assert!(!config.applescript_fallback);
+    }
+
+    #[test]
+    fn test_on_empty_workspace_deserialization() {
+        let config: TilingConfig =
+            serde_json::from_str(r#"{"onEmptyWorkspace": "switch-prev"}"#).unwrap();
+        assert_eq!(config.on_empty_workspace, OnEmptyWorkspace::SwitchPrev);
+
+        let config: TilingConfig =
+            serde_json::from_str(r#"{"onEmptyWorkspace": "switch-first"}"#).unwrap();
+        assert_eq!(config.on_empty_workspace, OnEmptyWorkspace::SwitchFirst);
+
+        assert!(serde_json::from_str::<TilingConfig>(r#"{"onEmptyWorkspace": "close"}"#).is_err());
+    }
+
+    #[test]
+    fn test_focus_new_windows_deserialization() {
+        let config: TilingConfig =
+            serde_json::from_str(r#"{"focusNewWindows": "same-screen"}"#).unwrap();
+        assert_eq!(config.focus_new_windows, FocusNewWindows::SameScreen);
+
+        let config: TilingConfig = serde_json::from_str(r#"{"focusNewWindows": "never"}"#).unwrap();
+        assert_eq!(config.focus_new_windows, FocusNewWindows::Never);
+
+        assert!(
+            serde_json::from_str::<TilingConfig>(r#"{"focusNewWindows": "sometimes"}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_smart_gaps_deserialization() {
+        let config: TilingConfig = serde_json::from_str(r#"{"smartGaps": true}"#).unwrap();
+        assert!(config.smart_gaps);
+    }
+
+    #[test]
+    fn test_auto_balance_deserialization() {
+        let config: TilingConfig = serde_json::from_str(r#"{"autoBalance": true}"#).unwrap();
+        assert!(config.auto_balance);
+    }
+
+    #[test]
+    fn test_resize_step_deserialization() {
+        let config: TilingConfig = serde_json::from_str(r#"{"resizeStep": 25}"#).unwrap();
+        assert_eq!(config.resize_step, 25);
+    }
+
+    #[test]
+    fn test_ignore_apps_deserialization() {
+        let json = r#"{"ignoreApps": ["Raycast", "com.1password.1password"]}"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.ignore_apps, vec!["Raycast", "com.1password.1password"]);
+    }
+
+    #[test]
+    fn test_ignore_screens() {
+        let json = r#"{"ignoreScreens": ["LG TV"]}"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.ignore_screens, vec!["LG TV"]);
+        assert!(config.is_screen_ignored("lg tv"));
+        assert!(!config.is_screen_ignored("LG TV SSCR2"));
+    }
+
+    #[test]
+    fn test_float_rules_deserialization() {
+        let json = r#"{"floatRules": [{"appName": "System Settings", "preset": "centered"}]}"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.float_rules.len(), 1);
+        assert_eq!(config.float_rules[0].preset.as_deref(), Some("centered"));
+        assert!(TilingConfig::default().float_rules.is_empty());
+    }
+
+    #[test]
+    fn test_focus_wraps_deserialization() {
+        let config: TilingConfig = serde_json::from_str(r#"{"focusWraps": true}"#).unwrap();
+        assert!(config.focus_wraps);
+    }
+
+    #[test]
+    fn test_mouse_focus_deserialization() {
+        let config: TilingConfig =
+            serde_json::from_str(r#"{"focusFollowsMouse": true, "mouseFollowsFocus": true}"#)
+                .unwrap();
+        assert!(config.focus_follows_mouse);
+        assert!(config.mouse_follows_focus);
+    }
+
+    #[test]
+    fn test_restore_on_exit_deserialization() {
+        let config: TilingConfig = serde_json::from_str(r#"{"restoreOnExit": true}"#).unwrap();
+        assert!(config.restore_on_exit);
+    }
+
+    #[test]
+    fn test_tracking_mode_deserialization() {
+        let json = r#"{"trackingMode": "observer-only"}"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.tracking_mode, TrackingMode::ObserverOnly);
+        assert!(!config.tracking_mode.allows_requery());
+
+        let config: TilingConfig = serde_json::from_str(r#"{"trackingMode": "hybrid"}"#).unwrap();
+        assert!(config.tracking_mode.allows_requery());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_unfocused_opacity() {
+        let config = TilingConfig::default();
+        assert_eq!(config.unfocused_opacity(), 1.0);
+        assert!(!config.dims_unfocused_windows());
+
+        let config: TilingConfig = serde_json::from_str(r#"{"unfocusedOpacity": 0.8}"#).unwrap();
+        assert_eq!(config.unfocused_opacity(), 0.8);
+        assert!(config.dims_unfocused_windows());
+
+        let config: TilingConfig = serde_json::from_str(r#"{"unfocusedOpacity": 1.5}"#).unwrap();
+        assert_eq!(config.unfocused_opacity(), 1.0);
+
+        let config: TilingConfig = serde_json::from_str(r#"{"unfocusedOpacity": -1}"#).unwrap();
+        assert_eq!(config.unfocused_opacity(), 0.0);
     }
 
     #[test]
@@ -273,5 +1165,75 @@ mod tests {
         let config: TilingConfig = serde_json::from_str(json).unwrap();
         assert!(config.enabled);
         assert_eq!(config.default_layout, LayoutType::Master);
+
+        let config: TilingConfig = serde_json::from_str(r#"{"defaultLayout": "columns"}"#).unwrap();
+        assert_eq!(config.default_layout, LayoutType::Columns);
+    }
+
+    #[test]
+    fn test_layout_for_screen() {
+        let json = r#"{
+            "defaultLayout": "master",
+            "perScreenLayout": {"Built-in Retina Display": "monocle", "secondary": "dwindle"}
+        }"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            config.layout_for_screen("built-in retina display", true),
+            LayoutType::Monocle
+        );
+        assert_eq!(
+            config.layout_for_screen("DELL U2720Q", false),
+            LayoutType::Dwindle
+        );
+        assert_eq!(config.layout_for_screen("DELL U2720Q", true), LayoutType::Master);
+        assert!(TilingConfig::default().per_screen_layout.is_empty());
+    }
+
+    #[test]
+    fn test_layout_cycle_deserialization() {
+        let json = r#"{"layoutCycle": ["dwindle", "master", "monocle"]}"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.layout_cycle, vec![
+            LayoutType::Dwindle,
+            LayoutType::Master,
+            LayoutType::Monocle
+        ]);
+    }
+
+    #[test]
+    fn test_workspace_gaps_lookup() {
+        let json = r#"{"workspaces": [{"name": "code", "gaps": {"inner": 0}}, {"name": "web"}]}"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+
+        assert!(config.workspace_gaps("code").is_some());
+        assert!(config.workspace_gaps("web").is_none());
+        assert!(config.workspace_gaps("missing").is_none());
+    }
+
+    #[test]
+    fn test_gaps_override_prefers_active_preset() {
+        let json = r#"{
+            "workspaces": [{"name": "code", "gaps": {"inner": 4}}],
+            "gapPresets": {"dense": {"inner": 0, "outer": 0}, "airy": {"inner": 24}}
+        }"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+
+        let inner = |gaps: Option<&WorkspaceGapsConfig>| {
+            gaps.and_then(|g| g.inner.as_ref()).map(|inner| inner.as_inner())
+        };
+        assert_eq!(inner(config.gaps_override("code", None)), Some((4, 4)));
+        assert_eq!(inner(config.gaps_override("code", Some("dense"))), Some((0, 0)));
+        assert_eq!(inner(config.gaps_override("web", Some("airy"))), Some((24, 24)));
+        assert!(config.gaps_override("web", None).is_none());
+
+        // Unknown presets fall back to the workspace override
+        assert_eq!(
+            inner(config.gaps_override("code", Some("removed"))),
+            Some((4, 4))
+        );
+        assert_eq!(config.gap_presets.keys().collect::<Vec<_>>(), vec![
+            "airy", "dense"
+        ]);
     }
 }
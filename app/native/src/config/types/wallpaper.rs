@@ -16,6 +16,23 @@ pub enum WallpaperMode {
     Sequential,
 }
 
+/// A time-of-day wallpaper schedule entry.
+///
+/// The entry is active from `from` (inclusive) to `to` (exclusive), in local
+/// time. Windows that cross midnight (e.g. "22:00" to "06:00") wrap around.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WallpaperScheduleEntry {
+    /// Start time in 24-hour "HH:MM" format.
+    pub from: String,
+
+    /// End time in 24-hour "HH:MM" format.
+    pub to: String,
+
+    /// Path to the image to show while this entry is active.
+    pub image: String,
+}
+
 /// Wallpaper configuration for dynamic wallpaper management.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
@@ -45,6 +62,11 @@ pub struct WallpaperConfig {
 
     /// Blur level in pixels for Gaussian blur effect.
     pub blur: u32,
+
+    /// Time-of-day schedule entries.
+    /// The first entry matching the current local time wins. When no entry
+    /// matches, wallpapers rotate from `path`/`list` as usual.
+    pub schedule: Vec<WallpaperScheduleEntry>,
 }
 
 impl WallpaperConfig {
@@ -52,9 +74,15 @@ impl WallpaperConfig {
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
 
-    /// Returns whether there are wallpapers configured (path or list).
+    /// Returns whether there are wallpapers configured (path, list or schedule).
     #[must_use]
-    pub const fn has_wallpapers(&self) -> bool { !self.path.is_empty() || !self.list.is_empty() }
+    pub const fn has_wallpapers(&self) -> bool {
+        !self.path.is_empty() || !self.list.is_empty() || self.has_schedule()
+    }
+
+    /// Returns whether a time-of-day schedule is configured.
+    #[must_use]
+    pub const fn has_schedule(&self) -> bool { !self.schedule.is_empty() }
 }
 
 #[cfg(test)]
@@ -93,6 +121,24 @@ mod tests {
             ..Default::default()
         };
         assert!(with_list.has_wallpapers());
+
+        let with_schedule = WallpaperConfig {
+            schedule: vec![WallpaperScheduleEntry::default()],
+            ..Default::default()
+        };
+        assert!(with_schedule.has_wallpapers());
+        assert!(with_schedule.has_schedule());
+    }
+
+    #[test]
+    fn test_wallpaper_schedule_deserialization() {
+        let json = r#"{"schedule": [{"from": "06:00", "to": "18:00", "image": "~/day.jpg"}]}"#;
+        let config: WallpaperConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.schedule.len(), 1);
+        assert_eq!(config.schedule[0].from, "06:00");
+        assert_eq!(config.schedule[0].to, "18:00");
+        assert_eq!(config.schedule[0].image, "~/day.jpg");
     }
 
     #[test]
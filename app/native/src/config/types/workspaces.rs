@@ -2,9 +2,11 @@
 //!
 //! Configuration for workspace definitions and window matching rules.
 
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::gaps::WorkspaceGapsConfig;
 use super::tiling::LayoutType;
 
 /// Window matching rule for workspace assignment.
@@ -15,8 +17,8 @@ use super::tiling::LayoutType;
 /// # Performance
 ///
 /// Call [`WindowRule::prepare()`] after loading rules from config to pre-compute
-/// lowercase versions of string fields. This avoids repeated `to_lowercase()` calls
-/// during window matching.
+/// lowercase versions of string fields and compile regex patterns. This avoids
+/// repeated `to_lowercase()` calls and regex compilation during window matching.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default, rename_all = "camelCase")]
 pub struct WindowRule {
@@ -32,6 +34,56 @@ pub struct WindowRule {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub app_name: Option<String>,
 
+    /// Match by window title using a regular expression.
+    /// Patterns are case-sensitive; use `(?i)` for case-insensitive matching.
+    /// Invalid patterns never match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_regex: Option<String>,
+
+    /// Match by application name using a regular expression.
+    /// Patterns are case-sensitive; use `(?i)` for case-insensitive matching.
+    /// Invalid patterns never match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_name_regex: Option<String>,
+
+    /// Match by accessibility role (e.g., "AXWindow"), exact and case-insensitive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+
+    /// Match by accessibility subrole (e.g., "AXDialog", "AXFloatingWindow" or
+    /// "AXStandardWindow"), exact and case-insensitive. Windows without a
+    /// subrole never match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subrole: Option<String>,
+
+    /// Screen to pin matching windows to: "main"/"primary", "secondary", or
+    /// screen name. Not a matching criterion: matching windows open on the
+    /// visible workspace of that screen. A workspace's own rules always send
+    /// windows to that workspace, so this only takes effect in `floatRules`;
+    /// elsewhere it is ignored with a warning when the config is loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub screen: Option<String>,
+
+    /// Gap around matching windows in tiled layouts, in pixels, replacing the
+    /// configured gaps: the distance to the screen edges and half the distance
+    /// to neighbouring windows. Use 0 for edge-to-edge windows. Not a matching
+    /// criterion. When several matching rules set it, the first one wins
+    /// (workspace rules in order, then `floatRules`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gaps: Option<f64>,
+
+    /// Border color (hex) of matching windows while focused, replacing the
+    /// focused border color. Not a matching criterion. When several matching
+    /// rules set it, the first one wins.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_color: Option<String>,
+
+    /// Whether matching windows fall back to `osascript` (System Events) to
+    /// read and set their frame when the accessibility API can't. Only used
+    /// when `tiling.applescriptFallback` is enabled. Not a matching criterion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_applescript_frames: Option<bool>,
+
     // Cached lowercase versions for fast matching (computed by prepare())
     #[serde(skip)]
     #[schemars(skip)]
@@ -44,23 +96,84 @@ pub struct WindowRule {
     #[serde(skip)]
     #[schemars(skip)]
     pub(crate) app_name_lower: Option<String>,
+
+    // Compiled regex patterns (computed by prepare(), None if invalid)
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub(crate) title_re: Option<Regex>,
+
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub(crate) app_name_re: Option<Regex>,
 }
 
 impl WindowRule {
     /// Returns true if the rule has at least one matching criterion.
     #[must_use]
     pub const fn is_valid(&self) -> bool {
-        self.app_id.is_some() || self.title.is_some() || self.app_name.is_some()
+        self.app_id.is_some()
+            || self.title.is_some()
+            || self.app_name.is_some()
+            || self.title_regex.is_some()
+            || self.app_name_regex.is_some()
+            || self.role.is_some()
+            || self.subrole.is_some()
+    }
+
+    /// Returns true if the rule checks the window title.
+    #[must_use]
+    pub const fn matches_on_title(&self) -> bool {
+        self.title.is_some() || self.title_regex.is_some()
     }
 
     /// Pre-computes lowercase versions of string fields for faster matching.
     ///
     /// Call this after loading rules from config. The lowercase values are cached
-    /// and reused by window matching logic.
+    /// and reused by window matching logic. Regex patterns are compiled once here;
+    /// invalid patterns are logged and left uncompiled so they never match.
     pub fn prepare(&mut self) {
         self.app_id_lower = self.app_id.as_ref().map(|s| s.to_ascii_lowercase());
         self.title_lower = self.title.as_ref().map(|s| s.to_lowercase());
         self.app_name_lower = self.app_name.as_ref().map(|s| s.to_lowercase());
+        self.title_re = self.title_regex.as_deref().and_then(|p| compile_pattern("titleRegex", p));
+        self.app_name_re =
+            self.app_name_regex.as_deref().and_then(|p| compile_pattern("appNameRegex", p));
+    }
+
+    /// Returns whether the window title matches `title_regex`.
+    ///
+    /// Uses the pattern compiled by [`prepare()`](Self::prepare), compiling it
+    /// on the fly if the rule was not prepared. Returns `false` if no pattern is
+    /// set or the pattern is invalid.
+    #[must_use]
+    pub fn title_regex_matches(&self, title: &str) -> bool {
+        pattern_matches(self.title_re.as_ref(), self.title_regex.as_deref(), title)
+    }
+
+    /// Returns whether the application name matches `app_name_regex`.
+    ///
+    /// Uses the pattern compiled by [`prepare()`](Self::prepare), compiling it
+    /// on the fly if the rule was not prepared. Returns `false` if no pattern is
+    /// set or the pattern is invalid.
+    #[must_use]
+    pub fn app_name_regex_matches(&self, app_name: &str) -> bool {
+        pattern_matches(
+            self.app_name_re.as_ref(),
+            self.app_name_regex.as_deref(),
+            app_name,
+        )
+    }
+
+    /// Returns whether a window's accessibility role and subrole match the
+    /// rule's `role` and `subrole`.
+    ///
+    /// Criteria the rule doesn't set always match.
+    #[must_use]
+    pub fn role_matches(&self, role: Option<&str>, subrole: Option<&str>) -> bool {
+        let matches = |expected: Option<&String>, actual: Option<&str>| {
+            expected.is_none_or(|expected| actual.is_some_and(|a| a.eq_ignore_ascii_case(expected)))
+        };
+        matches(self.role.as_ref(), role) && matches(self.subrole.as_ref(), subrole)
     }
 
     /// Returns the cached lowercase `app_id`, or the original if not cached.
@@ -82,6 +195,65 @@ impl WindowRule {
     }
 }
 
+/// Compiles a rule pattern, logging a config warning if it is invalid.
+fn compile_pattern(field: &str, pattern: &str) -> Option<Regex> {
+    match Regex::new(pattern) {
+        Ok(re) => Some(re),
+        Err(e) => {
+            tracing::warn!(
+                error = %e,
+                field,
+                pattern,
+                "invalid window rule regex, it will never match"
+            );
+            None
+        }
+    }
+}
+
+/// Matches text against a compiled pattern, falling back to compiling the source.
+fn pattern_matches(compiled: Option<&Regex>, pattern: Option<&str>, text: &str) -> bool {
+    match (compiled, pattern) {
+        (Some(re), _) => re.is_match(text),
+        (None, Some(pattern)) => Regex::new(pattern).is_ok_and(|re| re.is_match(text)),
+        (None, None) => false,
+    }
+}
+
+/// Rule that opens matching windows floating, regardless of their workspace.
+///
+/// Takes the same matching properties as [`WindowRule`].
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FloatRule {
+    /// Window properties to match. All specified properties must match.
+    #[serde(flatten)]
+    pub rule: WindowRule,
+
+    /// Whether matching windows open floating.
+    /// Default: true
+    #[serde(default = "default_float")]
+    pub float: bool,
+
+    /// Floating preset to apply when a matching window opens.
+    /// Only applied when the window floats.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+}
+
+impl Default for FloatRule {
+    fn default() -> Self {
+        Self {
+            rule: WindowRule::default(),
+            float: true,
+            preset: None,
+        }
+    }
+}
+
+/// Helper function for default float rule value.
+const fn default_float() -> bool { true }
+
 /// Helper function for default screen value.
 fn default_screen() -> String { "main".to_string() }
 
@@ -103,6 +275,7 @@ pub struct WorkspaceConfig {
     pub screen: String,
 
     /// Rules for automatically assigning windows to this workspace.
+    /// Rules with a title criterion are checked again when a window's title changes.
     #[serde(default)]
     pub rules: Vec<WindowRule>,
 
@@ -113,12 +286,66 @@ pub struct WorkspaceConfig {
         skip_serializing_if = "Option::is_none"
     )]
     pub preset_on_open: Option<String>,
+
+    /// Gaps override for this workspace.
+    /// Unset fields fall back to the per-screen or global gaps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gaps: Option<WorkspaceGapsConfig>,
+
+    /// Wallpaper image shown on the workspace's screen when it becomes visible.
+    /// Supports `~`. Workspaces without one keep the current wallpaper.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wallpaper: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_float_rule_deserialization() {
+        let json = r#"{"appName": "System Settings", "preset": "centered"}"#;
+        let rule: FloatRule = serde_json::from_str(json).unwrap();
+        assert_eq!(rule.rule.app_name.as_deref(), Some("System Settings"));
+        assert!(rule.float);
+        assert_eq!(rule.preset.as_deref(), Some("centered"));
+
+        let json = r#"{"appId": "com.apple.finder", "float": false}"#;
+        let rule: FloatRule = serde_json::from_str(json).unwrap();
+        assert_eq!(rule.rule.app_id.as_deref(), Some("com.apple.finder"));
+        assert!(!rule.float);
+        assert!(rule.preset.is_none());
+    }
+
+    #[test]
+    fn test_window_rule_screen_is_not_a_criterion() {
+        let rule: WindowRule = serde_json::from_str(r#"{"screen": "main"}"#).unwrap();
+        assert_eq!(rule.screen.as_deref(), Some("main"));
+        assert!(!rule.is_valid());
+
+        let json = r#"{"appName": "Slack", "float": false, "screen": "Built-in"}"#;
+        let float_rule: FloatRule = serde_json::from_str(json).unwrap();
+        assert_eq!(float_rule.rule.screen.as_deref(), Some("Built-in"));
+    }
+
+    #[test]
+    fn test_window_rule_overrides_are_not_criteria() {
+        let json = r##"{"gaps": 0, "borderColor": "#ff0000"}"##;
+        let rule: WindowRule = serde_json::from_str(json).unwrap();
+        assert_eq!(rule.gaps, Some(0.0));
+        assert_eq!(rule.border_color.as_deref(), Some("#ff0000"));
+        assert!(!rule.is_valid());
+
+        let json = r#"{"appName": "Ghostty", "float": false, "gaps": 2}"#;
+        let float_rule: FloatRule = serde_json::from_str(json).unwrap();
+        assert_eq!(float_rule.rule.gaps, Some(2.0));
+
+        let json = r#"{"useApplescriptFrames": true}"#;
+        let rule: WindowRule = serde_json::from_str(json).unwrap();
+        assert_eq!(rule.use_applescript_frames, Some(true));
+        assert!(!rule.is_valid());
+    }
+
     #[test]
     fn test_window_rule_is_valid() {
         let empty = WindowRule::default();
@@ -129,6 +356,13 @@ mod tests {
             ..Default::default()
         };
         assert!(with_app_id.is_valid());
+        assert!(!with_app_id.matches_on_title());
+
+        let with_title_regex = WindowRule {
+            title_regex: Some("^Project".to_string()),
+            ..Default::default()
+        };
+        assert!(with_title_regex.matches_on_title());
     }
 
     #[test]
@@ -145,4 +379,102 @@ mod tests {
         assert_eq!(rule.title_lowercase(), Some("my window"));
         assert_eq!(rule.app_name_lowercase(), Some("example app"));
     }
+
+    #[test]
+    fn test_window_rule_regex_is_valid() {
+        let rule = WindowRule {
+            title_regex: Some("^Figma".to_string()),
+            ..Default::default()
+        };
+        assert!(rule.is_valid());
+    }
+
+    #[test]
+    fn test_window_rule_role_matches() {
+        let rule = WindowRule {
+            subrole: Some("AXDialog".to_string()),
+            ..Default::default()
+        };
+        assert!(rule.is_valid());
+        assert!(rule.role_matches(Some("AXWindow"), Some("axdialog")));
+        assert!(!rule.role_matches(Some("AXWindow"), Some("AXStandardWindow")));
+        assert!(!rule.role_matches(Some("AXWindow"), None));
+
+        let rule = WindowRule {
+            role: Some("AXWindow".to_string()),
+            subrole: Some("AXFloatingWindow".to_string()),
+            ..Default::default()
+        };
+        assert!(rule.role_matches(Some("AXWindow"), Some("AXFloatingWindow")));
+        assert!(!rule.role_matches(None, Some("AXFloatingWindow")));
+        assert!(WindowRule::default().role_matches(None, None));
+    }
+
+    #[test]
+    fn test_window_rule_prepare_compiles_regex() {
+        let mut rule = WindowRule {
+            title_regex: Some("^Figma - ".to_string()),
+            app_name_regex: Some("(?i)^code$".to_string()),
+            ..Default::default()
+        };
+        rule.prepare();
+
+        assert!(rule.title_re.is_some());
+        assert!(rule.app_name_re.is_some());
+        assert!(rule.title_regex_matches("Figma - Design"));
+        assert!(!rule.title_regex_matches("Untitled - Figma"));
+        assert!(rule.app_name_regex_matches("Code"));
+        assert!(!rule.app_name_regex_matches("Visual Studio Code"));
+    }
+
+    #[test]
+    fn test_window_rule_invalid_regex_never_matches() {
+        let mut rule = WindowRule {
+            title_regex: Some("([unclosed".to_string()),
+            ..Default::default()
+        };
+
+        // Unprepared rules compile on the fly
+        assert!(!rule.title_regex_matches("([unclosed"));
+
+        rule.prepare();
+        assert!(rule.title_re.is_none());
+        assert!(!rule.title_regex_matches("([unclosed"));
+        assert!(!rule.app_name_regex_matches("anything"));
+    }
+
+    #[test]
+    fn test_window_rule_regex_deserialization() {
+        let json = r#"{"titleRegex": "^Figma", "appNameRegex": "Code$"}"#;
+        let rule: WindowRule = serde_json::from_str(json).unwrap();
+
+        assert_eq!(rule.title_regex.as_deref(), Some("^Figma"));
+        assert_eq!(rule.app_name_regex.as_deref(), Some("Code$"));
+        assert!(rule.title.is_none());
+    }
+
+    #[test]
+    fn test_workspace_config_gaps() {
+        let json = r#"{"name": "focus", "gaps": {"outer": 80}}"#;
+        let config: WorkspaceConfig = serde_json::from_str(json).unwrap();
+
+        let gaps = config.gaps.unwrap();
+        assert!(gaps.inner.is_none());
+        assert_eq!(gaps.outer.map(|g| g.as_outer()), Some((80, 80, 80, 80)));
+    }
+
+    #[test]
+    fn test_workspace_config_without_gaps() {
+        let json = r#"{"name": "code"}"#;
+        let config: WorkspaceConfig = serde_json::from_str(json).unwrap();
+        assert!(config.gaps.is_none());
+        assert!(config.wallpaper.is_none());
+    }
+
+    #[test]
+    fn test_workspace_config_wallpaper() {
+        let json = r#"{"name": "code", "wallpaper": "~/Pictures/code.jpg"}"#;
+        let config: WorkspaceConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.wallpaper.as_deref(), Some("~/Pictures/code.jpg"));
+    }
 }
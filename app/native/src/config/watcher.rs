@@ -3,21 +3,25 @@
 //! This module provides functionality to watch the configuration file
 //! for changes and restart the application when changes are detected.
 
+use std::path::Path;
+use std::sync::mpsc::RecvTimeoutError;
 use std::time::{Duration, Instant};
 
-use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
 use tauri::AppHandle;
 
-use super::get_config_path;
+use super::{StacheConfig, get_config, get_config_path, load_config_from_path};
 
 /// Debounce duration for config file changes.
-/// Some editors trigger multiple events per save (write to temp, rename, etc.).
-const CONFIG_DEBOUNCE_MS: u64 = 200;
+/// Some editors trigger multiple events per save (write to temp, rename, etc.),
+/// so events are coalesced until the file has been quiet for this long.
+const CONFIG_DEBOUNCE_MS: u64 = 250;
 
 /// Starts watching the configuration file for changes.
 ///
 /// When the config file is modified, the app will restart to apply the new configuration.
-/// This function spawns a background thread that watches the file.
+/// Saves that leave the parsed configuration unchanged, or that can't be
+/// parsed, are ignored. This function spawns a background thread that watches the file.
 ///
 /// # Arguments
 ///
@@ -31,6 +35,12 @@ pub fn watch_config_file<R: tauri::Runtime>(app_handle: AppHandle<R>) {
 
     let config_filename =
         config_path.file_name().map(std::ffi::OsStr::to_os_string).unwrap_or_default();
+    let affects_config = move |event: &Event| {
+        event
+            .paths
+            .iter()
+            .any(|p| p.file_name().is_some_and(|name| name == config_filename))
+    };
 
     std::thread::spawn(move || {
         let (tx, rx) = std::sync::mpsc::channel();
@@ -55,60 +65,96 @@ pub fn watch_config_file<R: tauri::Runtime>(app_handle: AppHandle<R>) {
 
         tracing::debug!(path = %config_path.display(), "watching config file for changes");
 
-        // Track last event time for debouncing (None = no previous event)
-        #[allow(unused_variables, unused_mut)]
-        let mut last_event_time: Option<Instant> = None;
         let debounce_duration = Duration::from_millis(CONFIG_DEBOUNCE_MS);
 
         loop {
+            // Wait for the first event that affects our config file
             match rx.recv() {
-                Ok(Ok(event)) => {
-                    // Check if this event affects our config file by filename
-                    let affects_config = event
-                        .paths
-                        .iter()
-                        .any(|p| p.file_name().is_some_and(|name| name == config_filename));
-
-                    if !affects_config {
-                        continue;
-                    }
-
-                    // Debounce: ignore events that occur within the debounce window
-                    // This prevents multiple restarts when editors trigger several events per save
-                    let now = Instant::now();
-                    if last_event_time.is_some_and(|t| now.duration_since(t) < debounce_duration) {
-                        continue;
-                    }
-
-                    // In debug mode, just log a message since restart kills the dev server.
-                    // Update last_event_time only in debug mode for debouncing subsequent events.
-                    // In release mode, restart the app to apply the new configuration.
-                    #[cfg(debug_assertions)]
-                    {
-                        last_event_time = Some(now);
-                        tracing::info!(
-                            "config file changed - restart the app to apply new settings"
-                        );
-                    }
-
-                    #[cfg(not(debug_assertions))]
-                    {
-                        tracing::info!("config file changed, restarting application");
-                        app_handle.restart();
-                    }
-                }
+                Ok(Ok(event)) if affects_config(&event) => {}
+                Ok(Ok(_)) => continue,
                 Ok(Err(err)) => {
                     tracing::warn!(error = %err, "config watch error");
+                    continue;
                 }
                 Err(_) => {
                     // Channel closed, watcher dropped
                     break;
                 }
             }
+
+            // Coalesce the rest of the save until the file has been quiet for the debounce window
+            let mut events = 1;
+            let mut deadline = Instant::now() + debounce_duration;
+            loop {
+                match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(Ok(event)) if affects_config(&event) => {
+                        events += 1;
+                        deadline = Instant::now() + debounce_duration;
+                    }
+                    Ok(Ok(_)) => {}
+                    Ok(Err(err)) => tracing::warn!(error = %err, "config watch error"),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+
+            let Some(sections) = changed_sections_on_disk(&config_path) else {
+                continue;
+            };
+            let sections = sections.join(", ");
+
+            // In debug mode, just log a message since restart kills the dev server.
+            // In release mode, restart the app to apply the new configuration.
+            #[cfg(debug_assertions)]
+            tracing::info!(
+                events,
+                %sections,
+                "config file changed - restart the app to apply new settings"
+            );
+
+            #[cfg(not(debug_assertions))]
+            {
+                tracing::info!(events, %sections, "config file changed, restarting application");
+                app_handle.restart();
+            }
         }
     });
 }
 
+/// Reads the config file and returns the sections that differ from the running config.
+///
+/// Returns `None` if the file can't be loaded or nothing changed.
+fn changed_sections_on_disk(config_path: &Path) -> Option<Vec<String>> {
+    let new_config = match load_config_from_path(&config_path.to_path_buf()) {
+        Ok((config, _)) => config,
+        Err(err) => {
+            tracing::warn!(error = %err, "config file changed but could not be loaded, ignoring");
+            return None;
+        }
+    };
+
+    let sections = changed_sections(get_config(), &new_config);
+    if sections.is_empty() {
+        tracing::debug!("config file saved without changes");
+        return None;
+    }
+    Some(sections)
+}
+
+/// Returns the names of the top-level config sections that differ between two configs.
+fn changed_sections(old: &StacheConfig, new: &StacheConfig) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return vec!["all".to_string()];
+    };
+
+    new.iter()
+        .filter(|(section, value)| old.get(*section) != Some(*value))
+        .map(|(section, _)| section.clone())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,4 +171,15 @@ mod tests {
         let duration = Duration::from_millis(CONFIG_DEBOUNCE_MS);
         assert_eq!(duration.as_millis(), u128::from(CONFIG_DEBOUNCE_MS));
     }
+
+    #[test]
+    fn changed_sections_lists_modified_sections() {
+        let old = StacheConfig::default();
+        assert!(changed_sections(&old, &old.clone()).is_empty());
+
+        let mut new = old.clone();
+        new.tiling.enabled = true;
+        new.proxy_audio.enabled = true;
+        assert_eq!(changed_sections(&old, &new), vec!["proxyAudio", "tiling"]);
+    }
 }
@@ -38,6 +38,12 @@ pub enum StacheError {
     /// Battery operation failed.
     #[error("Battery error: {0}")]
     BatteryError(String),
+    /// Media playback control failed.
+    #[error("Media error: {0}")]
+    MediaError(String),
+    /// Screen capture failed.
+    #[error("Capture error: {0}")]
+    CaptureError(String),
     /// Tiling window manager operation failed.
     #[error("Tiling error: {0}")]
     TilingError(String),
@@ -143,6 +149,22 @@ mod tests {
         assert!(msg.contains("Battery error"));
     }
 
+    #[test]
+    fn test_media_error_display() {
+        let err = StacheError::MediaError("No active media".to_string());
+        let msg = err.to_string();
+        assert!(msg.contains("Media error"));
+        assert!(msg.contains("No active media"));
+    }
+
+    #[test]
+    fn test_capture_error_display() {
+        let err = StacheError::CaptureError("No focused window".to_string());
+        let msg = err.to_string();
+        assert!(msg.contains("Capture error"));
+        assert!(msg.contains("No focused window"));
+    }
+
     #[test]
     fn test_tiling_error_display() {
         let err = StacheError::TilingError("Workspace not found".to_string());
@@ -33,6 +33,14 @@ pub mod keepawake {
     pub const STATE_CHANGED: &str = "stache://keepawake/state-changed";
 }
 
+/// Focus mode related events.
+pub mod focus_mode {
+    /// Emitted when focus mode is turned on or off.
+    ///
+    /// Payload: `{ active: bool, hideBar: bool, monocle: bool, keepawake: bool }`
+    pub const STATE_CHANGED: &str = "stache://focus-mode/state-changed";
+}
+
 /// Media playback related events.
 pub mod media {
     /// Emitted when media playback state changes.
@@ -147,6 +155,19 @@ pub mod tiling {
     ///
     /// Payload: `{ windowId: u32, title: String }`
     pub const WINDOW_TITLE_CHANGED: &str = "stache://tiling/window-title-changed";
+
+    /// Emitted when the tabs of a workspace in tabbed layout change.
+    ///
+    /// An empty `tabs` list means the workspace left the tabbed layout.
+    ///
+    /// Payload: `{ workspace: String, tabs: Vec<{ windowId, title, appName, isFocused }> }`
+    pub const TABS_CHANGED: &str = "stache://tiling/tabs-changed";
+
+    /// Emitted when the accessibility permission is revoked or granted again
+    /// while running. Tiling is paused while the permission is missing.
+    ///
+    /// Payload: `{ granted: bool }`
+    pub const ACCESSIBILITY_CHANGED: &str = "stache://tiling/accessibility-changed";
 }
 
 #[cfg(test)]
@@ -158,6 +179,7 @@ mod tests {
         let events = [
             menubar::VISIBILITY_CHANGED,
             keepawake::STATE_CHANGED,
+            focus_mode::STATE_CHANGED,
             media::PLAYBACK_CHANGED,
             spaces::WINDOW_FOCUS_CHANGED,
             spaces::WORKSPACE_CHANGED,
@@ -174,6 +196,8 @@ mod tests {
             tiling::INITIALIZED,
             tiling::WINDOW_FOCUS_CHANGED,
             tiling::WINDOW_TITLE_CHANGED,
+            tiling::TABS_CHANGED,
+            tiling::ACCESSIBILITY_CHANGED,
         ];
 
         for event in events {
@@ -190,6 +214,7 @@ mod tests {
         let events = [
             (menubar::VISIBILITY_CHANGED, "menubar", "visibility-changed"),
             (keepawake::STATE_CHANGED, "keepawake", "state-changed"),
+            (focus_mode::STATE_CHANGED, "focus-mode", "state-changed"),
             (media::PLAYBACK_CHANGED, "media", "playback-changed"),
             (spaces::WINDOW_FOCUS_CHANGED, "spaces", "window-focus-changed"),
             (spaces::WORKSPACE_CHANGED, "spaces", "workspace-changed"),
@@ -210,6 +235,8 @@ mod tests {
             (tiling::INITIALIZED, "tiling", "initialized"),
             (tiling::WINDOW_FOCUS_CHANGED, "tiling", "window-focus-changed"),
             (tiling::WINDOW_TITLE_CHANGED, "tiling", "window-title-changed"),
+            (tiling::TABS_CHANGED, "tiling", "tabs-changed"),
+            (tiling::ACCESSIBILITY_CHANGED, "tiling", "accessibility-changed"),
         ];
 
         for (event, module, name) in events {
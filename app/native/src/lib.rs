@@ -13,6 +13,7 @@ pub mod error;
 pub mod events;
 mod logging;
 pub mod schema;
+mod status;
 mod utils;
 
 // New infrastructure (being phased in)
@@ -27,7 +28,9 @@ pub mod modules;
 use std::sync::OnceLock;
 
 pub use modules::{audio, tiling};
-use modules::{bar, cmd_q, hotkey, menu_anywhere, notunes, tray, wallpaper, widgets};
+use modules::{
+    bar, capture, cmd_q, focus_mode, hotkey, idle, menu_anywhere, notunes, tray, wallpaper, widgets,
+};
 use tauri::App;
 
 /// Cached accessibility permission status.
@@ -48,10 +51,21 @@ fn load_base_modules(app: &App) {
 
     // Start IPC socket server for CLI queries
     utils::ipc_socket::init(|query| {
-        tiling::init::handle_ipc_query(&query)
+        status::handle_ipc_query(&query)
+            .or_else(|| wallpaper::handle_ipc_query(&query))
+            .or_else(|| bar::components::media::handle_ipc_query(&query))
+            .or_else(|| bar::components::keepawake::handle_ipc_query(&query))
+            .or_else(|| focus_mode::handle_ipc_query(&query))
+            .or_else(|| bar::visibility::handle_ipc_query(&query))
+            .or_else(|| capture::handle_ipc_query(&query))
+            .or_else(|| audio::handle_ipc_query(&query))
+            .or_else(|| tiling::init::handle_ipc_query(&query))
             .unwrap_or_else(|| utils::ipc_socket::IpcResponse::error("Unknown query"))
     });
 
+    // Focus mode needs the app handle to hide the bar and keep the system awake
+    focus_mode::init(app.handle().clone());
+
     // Initialize system tray
     tracing::debug!("initializing system tray");
     tray::init(app);
@@ -87,6 +101,10 @@ fn lazy_load_modules(app: &App, config: &config::StacheConfig) {
                 tracing::debug!("initializing notunes");
                 notunes::init();
             }),
+            tokio::task::spawn_blocking(|| {
+                tracing::debug!("initializing idle monitor");
+                idle::init();
+            }),
             tokio::task::spawn_blocking({
                 let h = handle.clone();
                 move || {
@@ -104,7 +122,7 @@ fn lazy_load_modules(app: &App, config: &config::StacheConfig) {
         );
 
         // Log any panics from spawned tasks
-        let (wallpaper_r, audio_r, notunes_r, cmd_q_r, menu_r) = results;
+        let (wallpaper_r, audio_r, notunes_r, idle_r, cmd_q_r, menu_r) = results;
         if let Err(e) = wallpaper_r {
             tracing::error!("wallpaper init panicked: {e}");
         }
@@ -114,6 +132,9 @@ fn lazy_load_modules(app: &App, config: &config::StacheConfig) {
         if let Err(e) = notunes_r {
             tracing::error!("notunes init panicked: {e}");
         }
+        if let Err(e) = idle_r {
+            tracing::error!("idle init panicked: {e}");
+        }
         if let Err(e) = cmd_q_r {
             tracing::error!("cmd_q init panicked: {e}");
         }
@@ -168,10 +189,17 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             bar::components::apps::open_app,
             bar::components::battery::get_battery_info,
+            bar::components::command::run_bar_command,
             bar::components::cpu::get_cpu_info,
+            bar::components::disk::get_disk_info,
             bar::components::keepawake::is_system_awake,
             bar::components::keepawake::toggle_system_awake,
             bar::components::media::get_current_media_info,
+            bar::components::media::media_next,
+            bar::components::media::media_play_pause,
+            bar::components::media::media_previous,
+            bar::components::memory::get_memory_info,
+            bar::components::network::get_network_info,
             bar::components::tiling::focus_tiling_window,
             bar::components::tiling::focus_tiling_workspace,
             bar::components::tiling::get_tiling_current_workspace_windows,
@@ -182,6 +210,7 @@ pub fn run() {
             bar::components::tiling::is_tiling_enabled,
             bar::components::weather::get_weather_config,
             bar::window::get_bar_window_frame,
+            focus_mode::get_focus_mode,
         ])
         .setup(move |app| {
             // Make the app not appear in the dock (must be synchronous, first thing)
@@ -204,6 +233,8 @@ pub fn run() {
         .run(|_app, event| {
             if matches!(event, tauri::RunEvent::Exit) {
                 tracing::info!("application exiting, cleaning up");
+                // Stop tiling, restoring window frames if configured
+                tiling::shutdown();
                 // Clean up IPC socket on exit
                 utils::ipc_socket::stop_server();
             }
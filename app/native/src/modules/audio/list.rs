@@ -6,7 +6,7 @@ use std::fmt::Write;
 
 use coreaudio::audio_unit::Scope;
 use coreaudio::audio_unit::macos_helpers::{
-    get_audio_device_ids, get_audio_device_supports_scope, get_device_name,
+    get_audio_device_ids, get_audio_device_supports_scope, get_default_device_id, get_device_name,
 };
 use serde::Serialize;
 
@@ -14,7 +14,10 @@ use super::device::AudioDeviceType;
 
 /// Represents an audio device with its properties for CLI output.
 #[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AudioDeviceInfo {
+    /// The `CoreAudio` device ID.
+    pub id: u32,
     /// The human-readable device name.
     pub name: String,
     /// The device type (e.g., "airplay", "bluetooth", "usb", "builtin", "virtual", "other").
@@ -24,6 +27,10 @@ pub struct AudioDeviceInfo {
     pub input: bool,
     /// Whether this device supports output.
     pub output: bool,
+    /// Whether this device is the default input device.
+    pub default_input: bool,
+    /// Whether this device is the default output device.
+    pub default_output: bool,
 }
 
 /// Filter for audio device listing.
@@ -41,6 +48,8 @@ pub enum DeviceFilter {
 #[must_use]
 pub fn list_devices(filter: DeviceFilter) -> Vec<AudioDeviceInfo> {
     let device_ids = get_audio_device_ids().unwrap_or_default();
+    let default_input = get_default_device_id(true);
+    let default_output = get_default_device_id(false);
 
     let mut devices: Vec<AudioDeviceInfo> = device_ids
         .into_iter()
@@ -58,10 +67,13 @@ pub fn list_devices(filter: DeviceFilter) -> Vec<AudioDeviceInfo> {
             }
 
             Some(AudioDeviceInfo {
+                id,
                 name: name.clone(),
                 device_type: AudioDeviceType::detect(&name).as_str().to_string(),
                 input: supports_input,
                 output: supports_output,
+                default_input: default_input == Some(id),
+                default_output: default_output == Some(id),
             })
         })
         .collect();
@@ -88,27 +100,35 @@ pub fn format_devices_table(devices: &[AudioDeviceInfo]) -> String {
     // Header
     let _ = writeln!(
         output,
-        "{:<name_col_width$}  {:<10}  {:<5}  {:<6}",
-        "Name", "Type", "Input", "Output"
+        "{:<5}  {:<name_col_width$}  {:<10}  {:<5}  {:<6}  {:<7}",
+        "ID", "Name", "Type", "Input", "Output", "Default"
     );
     let _ = writeln!(
         output,
-        "{:<name_col_width$}  {:<10}  {:<5}  {:<6}",
+        "{:<5}  {:<name_col_width$}  {:<10}  {:<5}  {:<6}  {:<7}",
+        "-".repeat(5),
         "-".repeat(name_col_width),
         "-".repeat(10),
         "-".repeat(5),
-        "-".repeat(6)
+        "-".repeat(6),
+        "-".repeat(7)
     );
 
     // Rows
     for device in devices {
         let input_mark = if device.input { "Y" } else { "-" };
         let output_mark = if device.output { "Y" } else { "-" };
+        let default_mark = match (device.default_input, device.default_output) {
+            (true, true) => "in/out",
+            (true, false) => "in",
+            (false, true) => "out",
+            (false, false) => "-",
+        };
 
         let _ = writeln!(
             output,
-            "{:<name_col_width$}  {:<10}  {:<5}  {:<6}",
-            device.name, device.device_type, input_mark, output_mark
+            "{:<5}  {:<name_col_width$}  {:<10}  {:<5}  {:<6}  {:<7}",
+            device.id, device.name, device.device_type, input_mark, output_mark, default_mark
         );
     }
 
@@ -122,13 +142,19 @@ mod tests {
     #[test]
     fn test_audio_device_info_serialization() {
         let device = AudioDeviceInfo {
+            id: 42,
             name: "Test Device".to_string(),
             device_type: "usb".to_string(),
             input: true,
             output: false,
+            default_input: true,
+            default_output: false,
         };
 
         let json = serde_json::to_string(&device).unwrap();
+        assert!(json.contains("\"id\":42"));
+        assert!(json.contains("\"defaultInput\":true"));
+        assert!(json.contains("\"defaultOutput\":false"));
         assert!(json.contains("\"name\":\"Test Device\""));
         assert!(json.contains("\"type\":\"usb\""));
         assert!(json.contains("\"input\":true"));
@@ -145,14 +171,19 @@ mod tests {
     #[test]
     fn test_format_devices_table_with_devices() {
         let devices = vec![AudioDeviceInfo {
+            id: 73,
             name: "Test".to_string(),
             device_type: "usb".to_string(),
             input: true,
             output: true,
+            default_input: false,
+            default_output: true,
         }];
         let output = format_devices_table(&devices);
         assert!(output.contains("Test"));
         assert!(output.contains("usb"));
         assert!(output.contains('Y'));
+        assert!(output.lines().nth(2).unwrap().starts_with("73 "));
+        assert!(output.contains(" out"));
     }
 }
@@ -10,19 +10,34 @@
 //!   automatically switches to the highest priority available device.
 //! - **Config-based priorities**: Device priorities can be configured in the stache
 //!   config file under the `proxyAudio` section.
-//! - **`AirPlay` priority**: `AirPlay` devices are always given highest priority, even
-//!   if not explicitly listed in the configuration.
+//! - **`AirPlay` pinning**: An active `AirPlay` device is never switched away from
+//!   unless `proxyAudio.pinAirplay` is disabled.
+//! - **Volume control**: Read and change the volume and mute state of the default
+//!   output device.
+//! - **Manual switching**: Make a device the default output or input by a
+//!   substring of its name.
+//! - **Quiet hours**: Automatic switching is paused during the daily window set
+//!   in `proxyAudio.quietHours`.
 
 mod device;
 mod list;
 mod priority;
+mod quiet_hours;
+mod switch;
+mod volume;
 mod watcher;
 
 // Re-export commonly used types
 pub use device::{AudioDevice, AudioDeviceType};
 pub use list::{AudioDeviceInfo, DeviceFilter, format_devices_table, list_devices};
+pub use switch::{DefaultDevice, DeviceDirection, SwitchError, set_default_device};
+pub use volume::{
+    VolumeChange, VolumeError, VolumeState, change_volume, get_volume_state, toggle_mute,
+};
+pub use watcher::is_watcher_running;
 
 use crate::config::get_config;
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
 
 /// Initializes the audio module.
 ///\n/// Sets up device watchers and applies initial device configuration.
@@ -36,6 +51,16 @@ pub fn init() {
     }
 }
 
+/// Handles audio IPC queries (volume, mute, device switching and status).
+///
+/// Returns `None` for queries handled elsewhere.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    volume::handle_ipc_query(query)
+        .or_else(|| switch::handle_ipc_query(query))
+        .or_else(|| quiet_hours::handle_ipc_query(query))
+}
+
 #[cfg(test)]
 mod tests {
     use super::device::*;
@@ -9,7 +9,7 @@ use crate::config::ProxyAudioConfig;
 /// Determines the target output device based on priority rules from config.
 ///
 /// Priority order:
-/// 1. Keep current `AirPlay` device (don't switch away from `AirPlay`)
+/// 1. Keep current `AirPlay` device when `pinAirplay` is enabled
 /// 2. Devices in the config priority list (in order)
 /// 3. Fallback to `MacBook` Pro speakers
 ///
@@ -33,7 +33,7 @@ pub fn get_target_output_device<'a>(
     config: &ProxyAudioConfig,
 ) -> Option<&'a AudioDevice> {
     // 1. Don't switch away from AirPlay - keep it if it's the current device
-    if current.is_airplay() {
+    if config.should_pin_airplay() && current.is_airplay() {
         return devices.iter().find(|d| d.id == current.id);
     }
 
@@ -51,7 +51,7 @@ pub fn get_target_output_device<'a>(
 /// Determines the target input device based on priority rules from config.
 ///
 /// Priority order:
-/// 1. Keep current `AirPlay` device (don't switch away from `AirPlay`)
+/// 1. Keep current `AirPlay` device when `pinAirplay` is enabled
 /// 2. Devices in the config priority list (in order)
 /// 3. Fallback to `MacBook` Pro microphone
 ///
@@ -75,7 +75,7 @@ pub fn get_target_input_device<'a>(
     config: &ProxyAudioConfig,
 ) -> Option<&'a AudioDevice> {
     // 1. Don't switch away from AirPlay - keep it if it's the current device
-    if current.is_airplay() {
+    if config.should_pin_airplay() && current.is_airplay() {
         return devices.iter().find(|d| d.id == current.id);
     }
 
@@ -137,6 +137,7 @@ mod tests {
                     depends_on: None,
                 },
             ],
+            ..Default::default()
         }
     }
 
@@ -228,6 +229,65 @@ mod tests {
         assert_eq!(target.unwrap().id, 3);
     }
 
+    #[test]
+    fn output_switches_away_from_airplay_when_not_pinned() {
+        let config = ProxyAudioConfig {
+            pin_airplay: false,
+            ..create_test_config()
+        };
+        let current = AudioDevice {
+            id: 3,
+            name: "Kitchen AirPlay".to_string(),
+        };
+
+        let devices = vec![
+            AudioDevice {
+                id: 1,
+                name: "MacBook Pro Speakers".to_string(),
+            },
+            AudioDevice {
+                id: 2,
+                name: "AirPods Pro".to_string(),
+            },
+            AudioDevice {
+                id: 3,
+                name: "Kitchen AirPlay".to_string(),
+            },
+        ];
+
+        let target = get_target_output_device(&current, &devices, &config);
+        assert!(target.is_some());
+        // Priority list wins over the current AirPlay device
+        assert_eq!(target.unwrap().id, 2);
+    }
+
+    #[test]
+    fn output_falls_back_to_macbook_when_priority_list_empty() {
+        let config = ProxyAudioConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let current = AudioDevice {
+            id: 2,
+            name: "AirPods Pro".to_string(),
+        };
+
+        let devices = vec![
+            AudioDevice {
+                id: 1,
+                name: "MacBook Pro Speakers".to_string(),
+            },
+            AudioDevice {
+                id: 2,
+                name: "AirPods Pro".to_string(),
+            },
+        ];
+
+        let target = get_target_output_device(&current, &devices, &config);
+        assert!(target.is_some());
+        assert_eq!(target.unwrap().id, 1);
+    }
+
     #[test]
     fn input_uses_config_priority() {
         let config = create_test_config();
@@ -275,6 +335,7 @@ mod tests {
                     depends_on: None,
                 },
             ],
+            ..Default::default()
         };
 
         let current = AudioDevice {
@@ -325,6 +386,7 @@ mod tests {
                     depends_on: None,
                 },
             ],
+            ..Default::default()
         };
 
         let current = AudioDevice {
@@ -368,6 +430,7 @@ mod tests {
                     strategy: MatchStrategy::StartsWith,
                 }),
             }],
+            ..Default::default()
         };
 
         let current = AudioDevice {
@@ -0,0 +1,210 @@
+//! Quiet hours for automatic device switching.
+//!
+//! While `proxyAudio.quietHours` is active, device changes are ignored and
+//! whatever device is selected stays selected. The device listeners stay
+//! registered, and the watcher re-checks the devices once the window ends, so
+//! a device connected during quiet hours is picked up without another change.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::watcher::is_watcher_running;
+use crate::config::{ProxyAudioConfig, QuietHours, get_config};
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
+use crate::utils::time_of_day::{
+    MINUTES_PER_DAY, local_minute_of_day, parse_time_of_day, window_contains,
+};
+
+/// Automatic switching state reported over IPC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStatus {
+    /// Whether automatic device switching is running.
+    pub auto_switching: bool,
+    /// The configured quiet hours, if any.
+    pub quiet_hours: Option<QuietHours>,
+    /// Whether quiet hours are currently suppressing automatic switching.
+    pub quiet_hours_active: bool,
+}
+
+/// Returns the quiet hours window in minutes since midnight.
+///
+/// Returns `None` if a time is invalid.
+fn window(quiet_hours: &QuietHours) -> Option<(u32, u32)> {
+    Some((
+        parse_time_of_day(&quiet_hours.from)?,
+        parse_time_of_day(&quiet_hours.to)?,
+    ))
+}
+
+/// Returns whether the configured quiet hours cover the given minute of the day.
+///
+/// Quiet hours with an invalid time are never active.
+fn is_active_at(config: &ProxyAudioConfig, minute: u32) -> bool {
+    config
+        .quiet_hours
+        .as_ref()
+        .and_then(window)
+        .is_some_and(|(from, to)| window_contains(from, to, minute))
+}
+
+/// Returns whether quiet hours are active right now.
+#[must_use]
+pub fn is_active(config: &ProxyAudioConfig) -> bool {
+    config.quiet_hours.is_some() && is_active_at(config, local_minute_of_day())
+}
+
+/// Returns how many minutes after `minute` the configured quiet hours end.
+///
+/// Returns `None` when they aren't active at `minute`, or cover the whole day
+/// and never end.
+fn minutes_until_end_at(config: &ProxyAudioConfig, minute: u32) -> Option<u32> {
+    let (from, to) = config.quiet_hours.as_ref().and_then(window)?;
+    if from == to || !window_contains(from, to, minute) {
+        return None;
+    }
+
+    Some(if to > minute {
+        to - minute
+    } else {
+        to + MINUTES_PER_DAY - minute
+    })
+}
+
+/// Returns how long until the active quiet hours end.
+///
+/// Returns `None` when quiet hours aren't active or never end. The result is
+/// rounded to whole minutes, so callers should check [`is_active`] again when
+/// it elapses.
+#[must_use]
+pub fn time_until_end(config: &ProxyAudioConfig) -> Option<Duration> {
+    if config.quiet_hours.is_none() {
+        return None;
+    }
+
+    minutes_until_end_at(config, local_minute_of_day())
+        .map(|minutes| Duration::from_secs(u64::from(minutes) * 60))
+}
+
+/// Logs a warning if the configured quiet hours can't be parsed.
+pub fn validate(config: &ProxyAudioConfig) {
+    if let Some(quiet_hours) = &config.quiet_hours
+        && window(quiet_hours).is_none()
+    {
+        tracing::warn!(
+            from = %quiet_hours.from,
+            to = %quiet_hours.to,
+            "ignoring audio quiet hours with invalid time (expected HH:MM)"
+        );
+    }
+}
+
+/// Returns the current automatic switching state.
+#[must_use]
+pub fn status() -> AudioStatus {
+    let config = &get_config().proxy_audio;
+    let auto_switching = is_watcher_running();
+
+    AudioStatus {
+        auto_switching,
+        quiet_hours: config.quiet_hours.clone(),
+        quiet_hours_active: auto_switching && is_active(config),
+    }
+}
+
+/// Handles the audio status IPC query.
+///
+/// Returns `None` for queries handled elsewhere.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    matches!(query, IpcQuery::AudioStatus).then(|| IpcResponse::success(status()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(from: &str, to: &str) -> ProxyAudioConfig {
+        ProxyAudioConfig {
+            quiet_hours: Some(QuietHours {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn at(time: &str) -> u32 { parse_time_of_day(time).unwrap() }
+
+    #[test]
+    fn test_quiet_hours_wrap_around_midnight() {
+        let config = config("22:00", "07:00");
+
+        assert!(is_active_at(&config, at("22:00")));
+        assert!(is_active_at(&config, at("03:00")));
+        assert!(!is_active_at(&config, at("07:00")));
+        assert!(!is_active_at(&config, at("12:00")));
+    }
+
+    #[test]
+    fn test_quiet_hours_same_day() {
+        let config = config("13:00", "14:30");
+
+        assert!(is_active_at(&config, at("13:45")));
+        assert!(!is_active_at(&config, at("14:30")));
+        assert!(!is_active_at(&config, at("23:00")));
+    }
+
+    #[test]
+    fn test_minutes_until_end() {
+        let night = config("22:00", "07:00");
+        assert_eq!(minutes_until_end_at(&night, at("22:00")), Some(9 * 60));
+        assert_eq!(minutes_until_end_at(&night, at("06:59")), Some(1));
+        assert_eq!(minutes_until_end_at(&night, at("07:00")), None);
+
+        let lunch = config("13:00", "14:30");
+        assert_eq!(minutes_until_end_at(&lunch, at("13:45")), Some(45));
+
+        assert_eq!(
+            minutes_until_end_at(&config("09:00", "09:00"), at("12:00")),
+            None
+        );
+        assert_eq!(
+            minutes_until_end_at(&ProxyAudioConfig::default(), at("03:00")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_quiet_hours_inactive_when_missing_or_invalid() {
+        assert!(!is_active_at(&ProxyAudioConfig::default(), at("03:00")));
+        assert!(!is_active_at(&config("late", "07:00"), at("03:00")));
+        assert!(!is_active(&ProxyAudioConfig::default()));
+    }
+
+    #[test]
+    fn test_audio_status_serialization() {
+        let status = AudioStatus {
+            auto_switching: true,
+            quiet_hours: Some(QuietHours {
+                from: "22:00".to_string(),
+                to: "07:00".to_string(),
+            }),
+            quiet_hours_active: false,
+        };
+        assert_eq!(
+            serde_json::to_value(status).unwrap(),
+            serde_json::json!({
+                "autoSwitching": true,
+                "quietHours": { "from": "22:00", "to": "07:00" },
+                "quietHoursActive": false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+}
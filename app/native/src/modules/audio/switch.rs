@@ -0,0 +1,258 @@
+//! Manual switching of the default audio devices.
+//!
+//! Devices are picked by a case-insensitive substring of their name, the same
+//! matching used for device lookups elsewhere. A query matching several
+//! devices is rejected unless exactly one of them has that exact name.
+
+use serde::Serialize;
+
+use super::device::{AudioDevice, get_input_devices, get_output_devices};
+use super::list::{DeviceFilter, list_devices};
+use super::watcher::{set_default_input_device, set_default_output_device};
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
+
+/// Direction of an audio device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeviceDirection {
+    /// Output device (speakers, headphones).
+    Output,
+    /// Input device (microphones).
+    Input,
+}
+
+impl DeviceDirection {
+    /// Parses a direction from its name ("output" or "input").
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "output" => Some(Self::Output),
+            "input" => Some(Self::Input),
+            _ => None,
+        }
+    }
+
+    /// Returns the direction name.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Output => "output",
+            Self::Input => "input",
+        }
+    }
+}
+
+/// The default device after a manual switch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultDevice {
+    /// The `CoreAudio` device ID.
+    pub id: u32,
+    /// The human-readable device name.
+    pub name: String,
+    /// Whether the device became the default output or input.
+    pub direction: DeviceDirection,
+}
+
+/// Errors that can occur while switching the default device.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum SwitchError {
+    /// No device name contains the query.
+    #[error("No {direction} device matches '{query}'. Available: {}", candidates.join(", "))]
+    NoMatch {
+        direction: &'static str,
+        query: String,
+        candidates: Vec<String>,
+    },
+    /// Several device names contain the query.
+    #[error("'{query}' matches several {direction} devices: {}", candidates.join(", "))]
+    Ambiguous {
+        direction: &'static str,
+        query: String,
+        candidates: Vec<String>,
+    },
+    /// `CoreAudio` rejected the change.
+    #[error("Failed to make '{device}' the default {direction} device")]
+    Failed {
+        direction: &'static str,
+        device: String,
+    },
+}
+
+/// Picks the device whose name contains `query` (case-insensitive).
+///
+/// When several names contain the query, a single exact (case-insensitive)
+/// match still wins, so "AirPods" can be told apart from "AirPods Pro".
+///
+/// # Errors
+///
+/// Returns [`SwitchError::NoMatch`] or [`SwitchError::Ambiguous`], listing
+/// the device names the query could have meant.
+pub fn match_device<'a>(
+    devices: &'a [AudioDevice],
+    query: &str,
+    direction: DeviceDirection,
+) -> Result<&'a AudioDevice, SwitchError> {
+    let matches: Vec<&AudioDevice> =
+        devices.iter().filter(|device| device.name_contains(query)).collect();
+
+    match matches.as_slice() {
+        [device] => Ok(device),
+        [] => Err(SwitchError::NoMatch {
+            direction: direction.as_str(),
+            query: query.to_string(),
+            candidates: devices.iter().map(|device| device.name.clone()).collect(),
+        }),
+        _ => {
+            let query_lower = query.to_lowercase();
+            let mut exact =
+                matches.iter().filter(|device| device.name.to_lowercase() == query_lower);
+            match (exact.next(), exact.next()) {
+                (Some(device), None) => Ok(device),
+                _ => Err(SwitchError::Ambiguous {
+                    direction: direction.as_str(),
+                    query: query.to_string(),
+                    candidates: matches.iter().map(|device| device.name.clone()).collect(),
+                }),
+            }
+        }
+    }
+}
+
+/// Makes the device matching `query` the default output or input device.
+///
+/// # Errors
+///
+/// Returns an error if no single device matches or `CoreAudio` rejects the change.
+pub fn set_default_device(
+    direction: DeviceDirection,
+    query: &str,
+) -> Result<DefaultDevice, SwitchError> {
+    let devices = match direction {
+        DeviceDirection::Output => get_output_devices(),
+        DeviceDirection::Input => get_input_devices(),
+    };
+    let device = match_device(&devices, query, direction)?;
+
+    let switched = match direction {
+        DeviceDirection::Output => set_default_output_device(device.id),
+        DeviceDirection::Input => set_default_input_device(device.id),
+    };
+    if !switched {
+        return Err(SwitchError::Failed {
+            direction: direction.as_str(),
+            device: device.name.clone(),
+        });
+    }
+
+    tracing::info!(device = %device.name, direction = direction.as_str(), "switched default device");
+    Ok(DefaultDevice {
+        id: device.id,
+        name: device.name.clone(),
+        direction,
+    })
+}
+
+/// Handles audio device listing and switching IPC queries.
+///
+/// Returns `None` for queries handled elsewhere.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    match query {
+        IpcQuery::AudioDevices => Some(IpcResponse::success(list_devices(DeviceFilter::All))),
+        IpcQuery::AudioSetDevice { direction, name } => {
+            let Some(direction) = DeviceDirection::parse(direction) else {
+                return Some(IpcResponse::error(format!(
+                    "Invalid direction '{direction}'. Must be 'output' or 'input'."
+                )));
+            };
+            Some(match set_default_device(direction, name) {
+                Ok(device) => IpcResponse::success(device),
+                Err(err) => IpcResponse::error(err.to_string()),
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn devices() -> Vec<AudioDevice> {
+        [
+            "MacBook Pro Speakers",
+            "AirPods",
+            "AirPods Pro",
+            "External Speakers",
+        ]
+        .into_iter()
+        .zip(1..)
+        .map(|(name, id)| AudioDevice { id, name: name.to_string() })
+        .collect()
+    }
+
+    #[test]
+    fn test_match_device_by_substring() {
+        let devices = devices();
+        let device = match_device(&devices, "macbook", DeviceDirection::Output).unwrap();
+        assert_eq!(device.id, 1);
+    }
+
+    #[test]
+    fn test_match_device_prefers_exact_name() {
+        let devices = devices();
+        let device = match_device(&devices, "airpods", DeviceDirection::Output).unwrap();
+        assert_eq!(device.name, "AirPods");
+    }
+
+    #[test]
+    fn test_match_device_ambiguous() {
+        let devices = devices();
+        let err = match_device(&devices, "speakers", DeviceDirection::Output).unwrap_err();
+        assert_eq!(err, SwitchError::Ambiguous {
+            direction: "output",
+            query: "speakers".to_string(),
+            candidates: vec![
+                "MacBook Pro Speakers".to_string(),
+                "External Speakers".to_string()
+            ],
+        });
+        assert_eq!(
+            err.to_string(),
+            "'speakers' matches several output devices: MacBook Pro Speakers, External Speakers"
+        );
+    }
+
+    #[test]
+    fn test_match_device_no_match() {
+        let devices = devices();
+        let err = match_device(&devices, "minifuse", DeviceDirection::Input).unwrap_err();
+        assert!(matches!(&err, SwitchError::NoMatch { candidates, .. } if candidates.len() == 4));
+        assert!(err.to_string().starts_with("No input device matches 'minifuse'. Available: "));
+    }
+
+    #[test]
+    fn test_device_direction_parse() {
+        assert_eq!(DeviceDirection::parse("output"), Some(DeviceDirection::Output));
+        assert_eq!(DeviceDirection::parse("input"), Some(DeviceDirection::Input));
+        assert_eq!(DeviceDirection::parse("both"), None);
+    }
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+
+    #[test]
+    fn test_handle_ipc_query_rejects_invalid_direction() {
+        let query = IpcQuery::AudioSetDevice {
+            direction: "both".to_string(),
+            name: "AirPods".to_string(),
+        };
+        assert!(matches!(
+            handle_ipc_query(&query),
+            Some(IpcResponse::Error { error }) if error.contains("'both'")
+        ));
+    }
+}
@@ -0,0 +1,411 @@
+//! Volume and mute control for the default output device.
+//!
+//! Reads and writes `kAudioDevicePropertyVolumeScalar` and
+//! `kAudioDevicePropertyMute` on the output scope. Devices without a main
+//! volume control are driven through their first two channels instead, and
+//! devices with neither (e.g. some aggregate devices) report a descriptive
+//! error.
+
+use std::ffi::c_void;
+use std::ptr::{NonNull, null};
+
+use objc2_core_audio::{
+    AudioObjectGetPropertyData, AudioObjectHasProperty, AudioObjectIsPropertySettable,
+    AudioObjectPropertyAddress, AudioObjectPropertySelector, AudioObjectSetPropertyData,
+    kAudioDevicePropertyMute, kAudioDevicePropertyVolumeScalar, kAudioHardwareNoError,
+    kAudioObjectPropertyElementMain, kAudioObjectPropertyScopeOutput,
+};
+use serde::Serialize;
+
+use super::device::{AudioDevice, get_default_output_device};
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
+
+/// Channels used when a device has no main volume control (left and right).
+const STEREO_CHANNELS: [u32; 2] = [1, 2];
+
+/// Volume and mute state of the default output device.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeState {
+    /// Name of the default output device.
+    pub device: String,
+    /// Volume as a percentage (0 - 100).
+    pub volume: u8,
+    /// Whether the device is muted.
+    pub muted: bool,
+}
+
+/// A change to the output volume, in percent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeChange {
+    /// Set the volume to an absolute value.
+    Set(i32),
+    /// Raise (positive) or lower (negative) the volume by a step.
+    Adjust(i32),
+}
+
+impl VolumeChange {
+    /// Applies the change to a volume percentage, clamped to 0 - 100.
+    #[must_use]
+    pub fn apply(self, current: u8) -> u8 {
+        let target = match self {
+            Self::Set(value) => value,
+            Self::Adjust(step) => i32::from(current).saturating_add(step),
+        };
+
+        // Clamped to 0 - 100, so the cast can't truncate
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let target = target.clamp(0, 100) as u8;
+        target
+    }
+}
+
+/// Errors that can occur while reading or changing the volume.
+#[derive(Debug, thiserror::Error)]
+pub enum VolumeError {
+    /// There is no default output device.
+    #[error("No default output device")]
+    NoOutputDevice,
+    /// The device doesn't expose the property (or doesn't allow changing it).
+    #[error("'{device}' does not support changing the {control}")]
+    Unsupported {
+        device: String,
+        control: &'static str,
+    },
+    /// `CoreAudio` returned an error status.
+    #[error("Failed to {action} on '{device}' (status {status})")]
+    Failed {
+        device: String,
+        action: &'static str,
+        status: i32,
+    },
+}
+
+/// Returns the volume and mute state of the default output device.
+///
+/// # Errors
+///
+/// Returns an error if there is no default output device or it has no volume control.
+pub fn get_volume_state() -> Result<VolumeState, VolumeError> {
+    let device = get_default_output_device().ok_or(VolumeError::NoOutputDevice)?;
+    read_state(&device)
+}
+
+/// Changes the volume of the default output device and returns the new state.
+///
+/// # Errors
+///
+/// Returns an error if there is no default output device, it doesn't support
+/// changing the volume, or `CoreAudio` rejects the change.
+pub fn change_volume(change: VolumeChange) -> Result<VolumeState, VolumeError> {
+    let device = get_default_output_device().ok_or(VolumeError::NoOutputDevice)?;
+    let current = read_volume(&device)?;
+    let target = change.apply(percent_from_scalar(current));
+
+    let scalar = f32::from(target) / 100.0;
+    let elements = volume_elements(&device, true)?;
+    for element in elements {
+        let address = output_address(kAudioDevicePropertyVolumeScalar, element);
+        set_property(&device, &address, &scalar, "set the volume")?;
+    }
+
+    tracing::debug!(device = %device.name, volume = target, "changed output volume");
+    read_state(&device)
+}
+
+/// Toggles mute on the default output device and returns the new state.
+///
+/// # Errors
+///
+/// Returns an error if there is no default output device, it has no mute
+/// control, or `CoreAudio` rejects the change.
+pub fn toggle_mute() -> Result<VolumeState, VolumeError> {
+    let device = get_default_output_device().ok_or(VolumeError::NoOutputDevice)?;
+    let address = output_address(kAudioDevicePropertyMute, kAudioObjectPropertyElementMain);
+
+    if !has_property(&device, &address) || !is_settable(&device, &address) {
+        return Err(VolumeError::Unsupported {
+            device: device.name,
+            control: "mute state",
+        });
+    }
+
+    let muted: u32 = get_property(&device, &address, "read the mute state")?;
+    let toggled = u32::from(muted == 0);
+    set_property(&device, &address, &toggled, "change the mute state")?;
+
+    tracing::debug!(device = %device.name, muted = toggled != 0, "toggled output mute");
+    read_state(&device)
+}
+
+/// Handles volume and mute IPC queries.
+///
+/// Returns `None` for queries handled elsewhere.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    let result = match query {
+        IpcQuery::AudioVolume { action, value } => {
+            let change = match action.as_str() {
+                "set" => VolumeChange::Set(*value),
+                "up" => VolumeChange::Adjust(*value),
+                "down" => VolumeChange::Adjust(value.saturating_neg()),
+                _ => {
+                    return Some(IpcResponse::error(format!(
+                        "Invalid volume action '{action}'. Must be 'set', 'up' or 'down'."
+                    )));
+                }
+            };
+            change_volume(change)
+        }
+        IpcQuery::AudioMute { action } => {
+            if action != "toggle" {
+                return Some(IpcResponse::error(format!(
+                    "Invalid mute action '{action}'. Must be 'toggle'."
+                )));
+            }
+            toggle_mute()
+        }
+        _ => return None,
+    };
+
+    Some(match result {
+        Ok(state) => IpcResponse::success(state),
+        Err(err) => IpcResponse::error(err.to_string()),
+    })
+}
+
+/// Reads the full state of a device.
+fn read_state(device: &AudioDevice) -> Result<VolumeState, VolumeError> {
+    let volume = percent_from_scalar(read_volume(device)?);
+
+    // Devices without a mute control are reported as unmuted
+    let address = output_address(kAudioDevicePropertyMute, kAudioObjectPropertyElementMain);
+    let muted = has_property(device, &address)
+        && get_property::<u32>(device, &address, "read the mute state")? != 0;
+
+    Ok(VolumeState {
+        device: device.name.clone(),
+        volume,
+        muted,
+    })
+}
+
+/// Reads the volume scalar (0.0 - 1.0), averaging channels when needed.
+#[allow(clippy::cast_precision_loss)] // At most two channels
+fn read_volume(device: &AudioDevice) -> Result<f32, VolumeError> {
+    let elements = volume_elements(device, false)?;
+    let mut total = 0.0;
+    for &element in &elements {
+        let address = output_address(kAudioDevicePropertyVolumeScalar, element);
+        total += get_property::<f32>(device, &address, "read the volume")?;
+    }
+
+    Ok(total / elements.len() as f32)
+}
+
+/// Returns the elements that carry the output volume of a device.
+///
+/// Prefers the main element and falls back to the stereo channels. With
+/// `settable`, only elements whose volume can be changed are returned.
+fn volume_elements(device: &AudioDevice, settable: bool) -> Result<Vec<u32>, VolumeError> {
+    let usable = |element: u32| {
+        let address = output_address(kAudioDevicePropertyVolumeScalar, element);
+        has_property(device, &address) && (!settable || is_settable(device, &address))
+    };
+
+    if usable(kAudioObjectPropertyElementMain) {
+        return Ok(vec![kAudioObjectPropertyElementMain]);
+    }
+
+    let channels: Vec<u32> = STEREO_CHANNELS.into_iter().filter(|&ch| usable(ch)).collect();
+    if channels.is_empty() {
+        return Err(VolumeError::Unsupported {
+            device: device.name.clone(),
+            control: "volume",
+        });
+    }
+
+    Ok(channels)
+}
+
+/// Converts a volume scalar to a rounded percentage.
+fn percent_from_scalar(scalar: f32) -> u8 {
+    // Clamped to 0 - 100, so the cast can't truncate
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let percent = (scalar.clamp(0.0, 1.0) * 100.0).round() as u8;
+    percent
+}
+
+/// Builds an output-scope property address.
+const fn output_address(
+    selector: AudioObjectPropertySelector,
+    element: u32,
+) -> AudioObjectPropertyAddress {
+    AudioObjectPropertyAddress {
+        mSelector: selector,
+        mScope: kAudioObjectPropertyScopeOutput,
+        mElement: element,
+    }
+}
+
+/// Returns whether the device has the property.
+fn has_property(device: &AudioDevice, address: &AudioObjectPropertyAddress) -> bool {
+    unsafe { AudioObjectHasProperty(device.id, NonNull::from(address)) }
+}
+
+/// Returns whether the property can be changed.
+fn is_settable(device: &AudioDevice, address: &AudioObjectPropertyAddress) -> bool {
+    let mut settable: u8 = 0;
+    let status = unsafe {
+        AudioObjectIsPropertySettable(
+            device.id,
+            NonNull::from(address),
+            NonNull::from(&mut settable).cast(),
+        )
+    };
+
+    status == kAudioHardwareNoError && settable != 0
+}
+
+/// Reads a fixed-size property value.
+fn get_property<T: Copy + Default>(
+    device: &AudioDevice,
+    address: &AudioObjectPropertyAddress,
+    action: &'static str,
+) -> Result<T, VolumeError> {
+    let mut value = T::default();
+    let mut size = property_size::<T>();
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            device.id,
+            NonNull::from(address),
+            0,
+            null(),
+            NonNull::from(&mut size),
+            NonNull::from(&mut value).cast::<c_void>(),
+        )
+    };
+
+    if status == kAudioHardwareNoError {
+        Ok(value)
+    } else {
+        Err(failed(device, action, status))
+    }
+}
+
+/// Writes a fixed-size property value.
+fn set_property<T>(
+    device: &AudioDevice,
+    address: &AudioObjectPropertyAddress,
+    value: &T,
+    action: &'static str,
+) -> Result<(), VolumeError> {
+    let status = unsafe {
+        AudioObjectSetPropertyData(
+            device.id,
+            NonNull::from(address),
+            0,
+            null(),
+            property_size::<T>(),
+            NonNull::from(value).cast(),
+        )
+    };
+
+    if status == kAudioHardwareNoError {
+        Ok(())
+    } else {
+        Err(failed(device, action, status))
+    }
+}
+
+/// Size of a property value in bytes.
+#[allow(clippy::cast_possible_truncation)] // Property values are a few bytes
+const fn property_size<T>() -> u32 { std::mem::size_of::<T>() as u32 }
+
+/// Builds a `Failed` error for a device.
+fn failed(device: &AudioDevice, action: &'static str, status: i32) -> VolumeError {
+    VolumeError::Failed {
+        device: device.name.clone(),
+        action,
+        status,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volume_change_set_is_clamped() {
+        assert_eq!(VolumeChange::Set(40).apply(80), 40);
+        assert_eq!(VolumeChange::Set(150).apply(80), 100);
+        assert_eq!(VolumeChange::Set(-5).apply(80), 0);
+    }
+
+    #[test]
+    fn test_volume_change_adjust_is_clamped() {
+        assert_eq!(VolumeChange::Adjust(5).apply(50), 55);
+        assert_eq!(VolumeChange::Adjust(-10).apply(50), 40);
+        assert_eq!(VolumeChange::Adjust(10).apply(95), 100);
+        assert_eq!(VolumeChange::Adjust(-10).apply(3), 0);
+        assert_eq!(VolumeChange::Adjust(i32::MAX).apply(50), 100);
+    }
+
+    #[test]
+    fn test_percent_from_scalar() {
+        assert_eq!(percent_from_scalar(0.0), 0);
+        assert_eq!(percent_from_scalar(0.504), 50);
+        assert_eq!(percent_from_scalar(0.996), 100);
+        assert_eq!(percent_from_scalar(1.5), 100);
+        assert_eq!(percent_from_scalar(-0.5), 0);
+    }
+
+    #[test]
+    fn test_volume_state_serialization() {
+        let state = VolumeState {
+            device: "MacBook Pro Speakers".to_string(),
+            volume: 45,
+            muted: false,
+        };
+        let json = serde_json::to_value(&state).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "device": "MacBook Pro Speakers", "volume": 45, "muted": false })
+        );
+    }
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+
+    #[test]
+    fn test_handle_ipc_query_rejects_invalid_actions() {
+        let query = IpcQuery::AudioVolume {
+            action: "louder".to_string(),
+            value: 5,
+        };
+        assert!(matches!(
+            handle_ipc_query(&query),
+            Some(IpcResponse::Error { error }) if error.contains("'louder'")
+        ));
+
+        let query = IpcQuery::AudioMute { action: "on".to_string() };
+        assert!(matches!(
+            handle_ipc_query(&query),
+            Some(IpcResponse::Error { error }) if error.contains("'on'")
+        ));
+    }
+
+    #[test]
+    fn test_volume_error_messages() {
+        let err = VolumeError::Unsupported {
+            device: "Aggregate Device".to_string(),
+            control: "volume",
+        };
+        assert_eq!(
+            err.to_string(),
+            "'Aggregate Device' does not support changing the volume"
+        );
+    }
+}
@@ -2,11 +2,16 @@
 //!
 //! This module handles monitoring for audio device changes and
 //! automatically applying priority-based device switching.
+//!
+//! Device change notifications are debounced: bursts of events (e.g. an
+//! audio interface enumerating its devices while powering on) are handled
+//! once, after the configured quiet window.
 
 use std::ffi::c_void;
 use std::ptr::{NonNull, null};
 use std::sync::OnceLock;
-use std::sync::mpsc::{Sender, channel};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender, channel};
+use std::time::Duration;
 
 use objc2_core_audio::{
     AudioDeviceID, AudioObjectAddPropertyListener, AudioObjectID, AudioObjectPropertyAddress,
@@ -16,9 +21,10 @@ use objc2_core_audio::{
 };
 
 use super::device::{
-    get_default_input_device, get_default_output_device, get_input_devices, get_output_devices,
+    AudioDevice, get_default_input_device, get_default_output_device, get_input_devices,
+    get_output_devices,
 };
-use super::priority;
+use super::{priority, quiet_hours};
 use crate::config::ProxyAudioConfig;
 use crate::utils::thread::spawn_named_thread;
 
@@ -38,7 +44,7 @@ const AUDIO_DEVICE_ID_SIZE: u32 = std::mem::size_of::<AudioDeviceID>() as u32;
 /// Sets the default output device.
 ///
 /// Returns `true` if the device was set successfully.
-fn set_default_output_device(device_id: AudioDeviceID) -> bool {
+pub(super) fn set_default_output_device(device_id: AudioDeviceID) -> bool {
     let property_address = AudioObjectPropertyAddress {
         mSelector: kAudioHardwarePropertyDefaultOutputDevice,
         mScope: kAudioObjectPropertyScopeGlobal,
@@ -62,7 +68,7 @@ fn set_default_output_device(device_id: AudioDeviceID) -> bool {
 /// Sets the default input device.
 ///
 /// Returns `true` if the device was set successfully.
-fn set_default_input_device(device_id: AudioDeviceID) -> bool {
+pub(super) fn set_default_input_device(device_id: AudioDeviceID) -> bool {
     let property_address = AudioObjectPropertyAddress {
         mSelector: kAudioHardwarePropertyDefaultInputDevice,
         mScope: kAudioObjectPropertyScopeGlobal,
@@ -83,13 +89,73 @@ fn set_default_input_device(device_id: AudioDeviceID) -> bool {
     status == kAudioHardwareNoError
 }
 
+/// Guards against acting on a transiently incomplete device list.
+///
+/// While devices (re)enumerate, `CoreAudio` may briefly report an empty list
+/// or one missing a device that is about to come back. Acting on it would
+/// switch to a fallback device and then immediately back.
+#[derive(Debug, Default)]
+struct DeviceListGuard {
+    /// Device IDs from the last list that was acted on.
+    known: Vec<AudioDeviceID>,
+    /// Whether a shrunk list was seen and is waiting to be confirmed.
+    pending_shrink: bool,
+}
+
+impl DeviceListGuard {
+    /// Returns whether the device list can be acted on.
+    ///
+    /// Empty lists are never acted on. A list missing previously known
+    /// devices is deferred once and accepted if it is still the same on the
+    /// next check.
+    fn accept(&mut self, devices: &[AudioDevice]) -> bool {
+        if devices.is_empty() {
+            return false;
+        }
+
+        let ids: Vec<AudioDeviceID> = devices.iter().map(|d| d.id).collect();
+        let shrank = self.known.iter().any(|id| !ids.contains(id));
+        if shrank && !self.pending_shrink {
+            self.pending_shrink = true;
+            return false;
+        }
+
+        self.pending_shrink = false;
+        self.known = ids;
+        true
+    }
+
+    /// Returns whether a shrunk list is waiting to be confirmed.
+    const fn is_pending(&self) -> bool { self.pending_shrink }
+}
+
+/// Per-direction device list guards.
+#[derive(Debug, Default)]
+struct DeviceGuards {
+    output: DeviceListGuard,
+    input: DeviceListGuard,
+}
+
+impl DeviceGuards {
+    /// Returns whether any guard is waiting to confirm a shrunk list.
+    const fn is_pending(&self) -> bool { self.output.is_pending() || self.input.is_pending() }
+}
+
 /// Handles output device changes by applying priority rules from config.
-fn handle_output_device_change(config: &ProxyAudioConfig) {
+fn handle_output_device_change(config: &ProxyAudioConfig, guard: &mut DeviceListGuard) {
+    let devices = get_output_devices();
+    if !guard.accept(&devices) {
+        tracing::debug!(
+            count = devices.len(),
+            "output device list not settled, deferring"
+        );
+        return;
+    }
+
     let Some(current) = get_default_output_device() else {
         return;
     };
 
-    let devices = get_output_devices();
     let target = priority::get_target_output_device(&current, &devices, config);
 
     let Some(target) = target else {
@@ -109,12 +175,17 @@ fn handle_output_device_change(config: &ProxyAudioConfig) {
 }
 
 /// Handles input device changes by applying priority rules from config.
-fn handle_input_device_change(config: &ProxyAudioConfig) {
+fn handle_input_device_change(config: &ProxyAudioConfig, guard: &mut DeviceListGuard) {
+    let devices = get_input_devices();
+    if !guard.accept(&devices) {
+        tracing::debug!(count = devices.len(), "input device list not settled, deferring");
+        return;
+    }
+
     let Some(current) = get_default_input_device() else {
         return;
     };
 
-    let devices = get_input_devices();
     let target = priority::get_target_input_device(&current, &devices, config);
 
     let Some(target) = target else {
@@ -137,9 +208,41 @@ fn handle_input_device_change(config: &ProxyAudioConfig) {
 ///
 /// This is called whenever an audio device is connected, disconnected,
 /// or when the default device changes. Requires config to be present.
-fn on_audio_device_change(config: &ProxyAudioConfig) {
-    handle_output_device_change(config);
-    handle_input_device_change(config);
+/// Does nothing during quiet hours, leaving the current devices selected. The
+/// watcher calls it again when they end.
+fn on_audio_device_change(config: &ProxyAudioConfig, guards: &mut DeviceGuards) {
+    if quiet_hours::is_active(config) {
+        tracing::debug!("audio quiet hours active, not switching devices");
+        // Don't keep re-checking a shrunk list until the window ends
+        *guards = DeviceGuards::default();
+        return;
+    }
+
+    handle_output_device_change(config, &mut guards.output);
+    handle_input_device_change(config, &mut guards.input);
+}
+
+/// Waits for the next event, or until `timeout` elapses.
+///
+/// Returns `false` if the channel was disconnected.
+fn wait_for_event(rx: &Receiver<()>, timeout: Option<Duration>) -> bool {
+    timeout.map_or_else(
+        || rx.recv().is_ok(),
+        |timeout| !matches!(rx.recv_timeout(timeout), Err(RecvTimeoutError::Disconnected)),
+    )
+}
+
+/// Waits until no new events arrive for `window`, coalescing the burst.
+///
+/// Returns `false` if the channel was disconnected.
+fn wait_for_quiet(rx: &Receiver<()>, window: Duration) -> bool {
+    loop {
+        match rx.recv_timeout(window) {
+            Ok(()) => {}
+            Err(RecvTimeoutError::Timeout) => return true,
+            Err(RecvTimeoutError::Disconnected) => return false,
+        }
+    }
 }
 
 /// Property listener callback for audio device changes.
@@ -226,6 +329,8 @@ fn register_audio_listeners(tx: Sender<()>) {
 ///
 /// This function spawns a background thread that monitors for audio device
 /// changes and automatically switches devices based on priority rules.
+/// Events are coalesced over `config.debounce_ms` before being handled, and
+/// a device list that shrank is re-checked after another quiet window.
 ///
 /// # Arguments
 ///
@@ -237,13 +342,29 @@ pub fn init_audio_device_watcher(config: ProxyAudioConfig) {
         // Register all audio device listeners
         register_audio_listeners(tx);
 
-        // Wait for device change events
-        while rx.recv().is_ok() {
-            on_audio_device_change(&config);
+        let debounce = config.debounce();
+        let mut guards = DeviceGuards::default();
+
+        loop {
+            // Wait for the next event, unless a shrunk device list needs a re-check.
+            // During quiet hours, also wake up when they end to re-check the devices.
+            if !guards.is_pending() && !wait_for_event(&rx, quiet_hours::time_until_end(&config)) {
+                break;
+            }
+
+            if !wait_for_quiet(&rx, debounce) {
+                break;
+            }
+
+            on_audio_device_change(&config, &mut guards);
         }
     });
 }
 
+/// Returns whether the audio device watcher has been started.
+#[must_use]
+pub fn is_watcher_running() -> bool { AUDIO_WATCHER_ONCE.get().is_some() }
+
 /// Starts the audio device watcher.
 ///
 /// This is idempotent - calling it multiple times has no effect.
@@ -256,9 +377,118 @@ pub fn start(config: ProxyAudioConfig) {
         return;
     }
 
+    quiet_hours::validate(&config);
+
     // Apply initial device configuration
-    on_audio_device_change(&config);
+    on_audio_device_change(&config, &mut DeviceGuards::default());
 
     // Start watching for device changes
     init_audio_device_watcher(config);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    fn devices(ids: &[AudioDeviceID]) -> Vec<AudioDevice> {
+        ids.iter()
+            .map(|&id| AudioDevice {
+                id,
+                name: format!("Device {id}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_guard_accepts_first_list() {
+        let mut guard = DeviceListGuard::default();
+        assert!(guard.accept(&devices(&[1, 2])));
+        assert!(!guard.is_pending());
+    }
+
+    #[test]
+    fn test_guard_rejects_empty_list() {
+        let mut guard = DeviceListGuard::default();
+        assert!(guard.accept(&devices(&[1])));
+        assert!(!guard.accept(&[]));
+        assert!(!guard.is_pending());
+    }
+
+    #[test]
+    fn test_guard_accepts_growing_list() {
+        let mut guard = DeviceListGuard::default();
+        assert!(guard.accept(&devices(&[1])));
+        assert!(guard.accept(&devices(&[1, 2])));
+    }
+
+    #[test]
+    fn test_guard_defers_shrunk_list_once() {
+        let mut guard = DeviceListGuard::default();
+        assert!(guard.accept(&devices(&[1, 2, 3])));
+
+        // Device 3 disappears: deferred until confirmed
+        assert!(!guard.accept(&devices(&[1, 2])));
+        assert!(guard.is_pending());
+
+        // Still missing on the re-check: accepted
+        assert!(guard.accept(&devices(&[1, 2])));
+        assert!(!guard.is_pending());
+    }
+
+    #[test]
+    fn test_guard_transient_shrink_recovers() {
+        let mut guard = DeviceListGuard::default();
+        assert!(guard.accept(&devices(&[1, 2])));
+        assert!(!guard.accept(&devices(&[1])));
+
+        // Device 2 is back, nothing was switched in between
+        assert!(guard.accept(&devices(&[1, 2])));
+        assert!(!guard.is_pending());
+    }
+
+    #[test]
+    fn test_wait_for_quiet_coalesces_burst() {
+        let (tx, rx) = channel();
+        for _ in 0..5 {
+            tx.send(()).unwrap();
+        }
+
+        assert!(wait_for_quiet(&rx, Duration::from_millis(10)));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_wait_for_quiet_waits_for_window() {
+        let (_tx, rx) = channel::<()>();
+        let start = Instant::now();
+
+        assert!(wait_for_quiet(&rx, Duration::from_millis(20)));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_wait_for_quiet_disconnected() {
+        let (tx, rx) = channel::<()>();
+        drop(tx);
+
+        assert!(!wait_for_quiet(&rx, Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn test_wait_for_event_times_out() {
+        let (tx, rx) = channel::<()>();
+        let start = Instant::now();
+
+        assert!(wait_for_event(&rx, Some(Duration::from_millis(20))));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        tx.send(()).unwrap();
+        assert!(wait_for_event(&rx, None));
+
+        drop(tx);
+        assert!(!wait_for_event(&rx, Some(Duration::from_millis(10))));
+        assert!(!wait_for_event(&rx, None));
+    }
+}
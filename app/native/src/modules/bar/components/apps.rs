@@ -1,13 +1,22 @@
 //! Application launcher component.
 //!
 //! Manages opening whitelisted macOS applications and URLs via the Tauri command interface.
+//! Applications can also be opened by bundle id or by the path of their `.app` bundle, and
+//! the applications installed in `/Applications` and `~/Applications` can be listed.
 
 #![allow(unexpected_cfgs)]
 
+use std::path::{Path, PathBuf};
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use serde::Serialize;
 use tauri::AppHandle;
 use tauri_plugin_shell::ShellExt;
 
 use crate::error::StacheError;
+use crate::utils::objc::{nsstring, nsstring_to_string};
+use crate::utils::path::expand;
 
 #[derive(Clone, Copy)]
 enum LaunchTarget {
@@ -92,26 +101,185 @@ fn launch_url(app: &AppHandle, url: &str) -> Result<(), StacheError> {
     run_open_command(app, [url], url)
 }
 
-/// Opens a whitelisted macOS application by its display name.
+/// Returns whether `value` looks like a bundle id such as `com.spotify.client`.
+///
+/// Bundle ids are reverse-DNS names with at least three labels, which keeps
+/// app names like `zoom.us` out.
+fn is_bundle_id(value: &str) -> bool {
+    let labels: Vec<&str> = value.split('.').collect();
+    labels.len() >= 3
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+}
+
+/// Returns whether `value` is the path of an `.app` bundle, e.g. `/Applications/Safari.app`.
+fn is_app_path(value: &str) -> bool {
+    (value.starts_with('/') || value.starts_with('~'))
+        && Path::new(value)
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("app"))
+}
+
+/// Returns the path of the installed application with the given bundle id.
+fn application_path_for_bundle_id(bundle_id: &str) -> Option<String> {
+    unsafe {
+        let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
+        if workspace.is_null() {
+            return None;
+        }
+
+        let url: *mut Object =
+            msg_send![workspace, URLForApplicationWithBundleIdentifier: nsstring(bundle_id)];
+        if url.is_null() {
+            return None;
+        }
+
+        let path: *mut Object = msg_send![url, path];
+        Some(nsstring_to_string(path)).filter(|path| !path.is_empty())
+    }
+}
+
+/// Opens the application bundle at `path` with `NSWorkspace`.
+///
+/// The launch is asynchronous, so only a missing bundle is reported as an error.
+fn open_application_at_path(path: &Path) -> Result<(), StacheError> {
+    if !path.is_dir() {
+        return Err(StacheError::InvalidArguments(format!(
+            "Application '{}' does not exist.",
+            path.display()
+        )));
+    }
+
+    unsafe {
+        let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
+        if workspace.is_null() {
+            return Err(StacheError::CommandError(
+                "NSWorkspace is not available".to_string(),
+            ));
+        }
+
+        let url: *mut Object =
+            msg_send![class!(NSURL), fileURLWithPath: nsstring(&path.to_string_lossy())];
+        let configuration: *mut Object =
+            msg_send![class!(NSWorkspaceOpenConfiguration), configuration];
+        let completion_handler: *mut Object = std::ptr::null_mut();
+        let _: () = msg_send![
+            workspace,
+            openApplicationAtURL: url
+            configuration: configuration
+            completionHandler: completion_handler
+        ];
+    }
+
+    Ok(())
+}
+
+/// Opens a macOS application by its display name, bundle id or bundle path.
+///
+/// Display names must be whitelisted. Bundle ids, such as `com.spotify.client`,
+/// are resolved with `NSWorkspace`, and paths must point to an `.app` bundle.
 ///
 /// # Errors
 ///
-/// Returns an error if the application name is not whitelisted or if launching the
+/// Returns an error if the application name is not whitelisted, if the bundle id
+/// or path can't be resolved to an installed application, or if launching the
 /// application fails.
 #[tauri::command]
 #[allow(clippy::needless_pass_by_value)]
 pub fn open_app(app: tauri::AppHandle, name: &str) -> Result<(), StacheError> {
-    resolve_allowed_app(name).map_or_else(
-        || {
-            Err(StacheError::InvalidArguments(format!(
-                "Application '{name}' is not allowed."
-            )))
-        },
-        |entry| match entry.target {
+    if let Some(entry) = resolve_allowed_app(name) {
+        return match entry.target {
             LaunchTarget::Application(app_name) => launch_application(&app, app_name),
             LaunchTarget::Url(url) => launch_url(&app, url),
-        },
-    )
+        };
+    }
+
+    let name = name.trim();
+    if is_app_path(name) {
+        return open_application_at_path(&expand(name));
+    }
+
+    if is_bundle_id(name) {
+        let Some(path) = application_path_for_bundle_id(name) else {
+            return Err(StacheError::InvalidArguments(format!(
+                "No application with bundle id '{name}' is installed."
+            )));
+        };
+        return open_application_at_path(Path::new(&path));
+    }
+
+    Err(StacheError::InvalidArguments(format!(
+        "Application '{name}' is not allowed."
+    )))
+}
+
+/// An application installed in one of the application folders.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledApp {
+    /// The application name, from its bundle file name.
+    pub name: String,
+    /// The bundle id, if the bundle declares one.
+    pub bundle_id: Option<String>,
+    /// The path of the `.app` bundle.
+    pub path: String,
+}
+
+/// Returns the folders applications are installed in.
+fn application_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![PathBuf::from("/Applications")];
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join("Applications"));
+    }
+    dirs
+}
+
+/// Returns the `.app` bundles directly inside `dir`.
+fn app_bundles_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| is_app_path(&path.to_string_lossy()) && path.is_dir())
+        .collect()
+}
+
+/// Reads the bundle id of the `.app` bundle at `path`.
+fn bundle_id_at_path(path: &Path) -> Option<String> {
+    unsafe {
+        let bundle: *mut Object =
+            msg_send![class!(NSBundle), bundleWithPath: nsstring(&path.to_string_lossy())];
+        if bundle.is_null() {
+            return None;
+        }
+
+        let bundle_id: *mut Object = msg_send![bundle, bundleIdentifier];
+        Some(nsstring_to_string(bundle_id)).filter(|id| !id.is_empty())
+    }
+}
+
+/// Lists the applications in `/Applications` and `~/Applications`, sorted by name.
+#[must_use]
+pub fn list_installed_apps() -> Vec<InstalledApp> {
+    let mut apps: Vec<InstalledApp> = application_dirs()
+        .iter()
+        .flat_map(|dir| app_bundles_in(dir))
+        .map(|path| InstalledApp {
+            name: path
+                .file_stem()
+                .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned()),
+            bundle_id: bundle_id_at_path(&path),
+            path: path.to_string_lossy().into_owned(),
+        })
+        .collect();
+
+    apps.sort_by(|a, b| natord::compare_ignore_case(&a.name, &b.name).then(a.path.cmp(&b.path)));
+    apps
 }
 
 #[cfg(test)]
@@ -147,4 +315,41 @@ mod tests {
         assert!(resolve_allowed_app("   ").is_none());
         assert!(resolve_allowed_app("Nonexistent App").is_none());
     }
+
+    #[test]
+    fn is_bundle_id_accepts_reverse_dns_names() {
+        assert!(is_bundle_id("com.spotify.client"));
+        assert!(is_bundle_id("com.microsoft.edgemac.Dev"));
+        assert!(is_bundle_id("us.zoom.xos"));
+        assert!(!is_bundle_id("zoom.us"));
+        assert!(!is_bundle_id("Activity Monitor"));
+        assert!(!is_bundle_id("com..client"));
+        assert!(!is_bundle_id("/Applications/com.example.app"));
+    }
+
+    #[test]
+    fn is_app_path_requires_absolute_app_bundle() {
+        assert!(is_app_path("/Applications/Safari.app"));
+        assert!(is_app_path("~/Applications/Tidal.APP"));
+        assert!(!is_app_path("Safari.app"));
+        assert!(!is_app_path("/Applications/Safari"));
+        assert!(!is_app_path("com.apple.Safari"));
+    }
+
+    #[test]
+    fn installed_app_serializes_camel_case() {
+        let app = InstalledApp {
+            name: "Safari".to_string(),
+            bundle_id: Some("com.apple.Safari".to_string()),
+            path: "/Applications/Safari.app".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&app).unwrap(),
+            serde_json::json!({
+                "name": "Safari",
+                "bundleId": "com.apple.Safari",
+                "path": "/Applications/Safari.app",
+            })
+        );
+    }
 }
@@ -37,6 +37,12 @@ pub struct BatteryInfo {
     pub time_to_full: Option<u64>,
     /// Time until empty in seconds (if discharging)
     pub time_to_empty: Option<u64>,
+    /// Estimated minutes until fully charged (`None` while the OS is still estimating)
+    pub time_to_full_minutes: Option<u32>,
+    /// Estimated minutes until empty (`None` while the OS is still estimating)
+    pub time_to_empty_minutes: Option<u32>,
+    /// Whether the battery is currently charging
+    pub is_charging: bool,
     /// Battery vendor (if available)
     pub vendor: Option<String>,
     /// Battery model (if available)
@@ -100,6 +106,9 @@ impl From<Technology> for BatteryTechnology {
 impl From<Battery> for BatteryInfo {
     #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn from(battery: Battery) -> Self {
+        let time_to_full = battery.time_to_full().map(|t| t.get::<second>());
+        let time_to_empty = battery.time_to_empty().map(|t| t.get::<second>());
+
         Self {
             percentage: percentage_from_ratio(battery.state_of_charge().get::<percent>()),
             state: battery.state().into(),
@@ -112,8 +121,11 @@ impl From<Battery> for BatteryInfo {
             voltage: battery.voltage().get::<volt>(),
             temperature: battery.temperature().map(|t| t.get::<degree_celsius>()),
             cycle_count: battery.cycle_count(),
-            time_to_full: battery.time_to_full().map(|t| t.get::<second>() as u64),
-            time_to_empty: battery.time_to_empty().map(|t| t.get::<second>() as u64),
+            time_to_full: time_to_full.map(|t| t as u64),
+            time_to_empty: time_to_empty.map(|t| t as u64),
+            time_to_full_minutes: time_to_full.and_then(minutes_from_seconds),
+            time_to_empty_minutes: time_to_empty.and_then(minutes_from_seconds),
+            is_charging: matches!(battery.state(), State::Charging),
             vendor: battery.vendor().map(String::from),
             model: battery.model().map(String::from),
             serial_number: battery.serial_number().map(String::from),
@@ -149,6 +161,21 @@ pub fn get_battery_info(app_handle: tauri::AppHandle) -> Result<BatteryInfo, Sta
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
 const fn percentage_from_ratio(value: f32) -> u8 { value.round().clamp(0.0, 100.0) as u8 }
 
+/// IOKit reports this many minutes while the time estimate is still being calculated.
+const ESTIMATING_MINUTES: f32 = 65_535.0;
+
+/// Converts a time estimate in seconds into whole minutes.
+///
+/// Returns `None` for zero and for the IOKit "still calculating" sentinel.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn minutes_from_seconds(seconds: f32) -> Option<u32> {
+    let minutes = (seconds / 60.0).round();
+    if !minutes.is_finite() || minutes <= 0.0 || minutes >= ESTIMATING_MINUTES {
+        return None;
+    }
+    Some(minutes as u32)
+}
+
 #[cfg(test)]
 #[allow(clippy::float_cmp)]
 mod tests {
@@ -165,6 +192,22 @@ mod tests {
         assert_eq!(percentage_from_ratio(150.0), 100);
     }
 
+    #[test]
+    fn minutes_from_seconds_rounds_to_minutes() {
+        assert_eq!(minutes_from_seconds(60.0), Some(1));
+        assert_eq!(minutes_from_seconds(5_400.0), Some(90));
+        assert_eq!(minutes_from_seconds(89.0), Some(1));
+        assert_eq!(minutes_from_seconds(91.0), Some(2));
+    }
+
+    #[test]
+    fn minutes_from_seconds_ignores_pending_estimates() {
+        assert_eq!(minutes_from_seconds(0.0), None);
+        assert_eq!(minutes_from_seconds(-60.0), None);
+        assert_eq!(minutes_from_seconds(f32::INFINITY), None);
+        assert_eq!(minutes_from_seconds(65_535.0 * 60.0), None);
+    }
+
     #[test]
     fn battery_state_from_state_matches_variants() {
         assert!(matches!(
@@ -252,6 +295,9 @@ mod tests {
         assert!(info.cycle_count.is_none());
         assert!(info.time_to_full.is_none());
         assert!(info.time_to_empty.is_none());
+        assert!(info.time_to_full_minutes.is_none());
+        assert!(info.time_to_empty_minutes.is_none());
+        assert!(!info.is_charging);
         assert!(info.vendor.is_none());
         assert!(info.model.is_none());
         assert!(info.serial_number.is_none());
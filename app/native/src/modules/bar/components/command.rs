@@ -0,0 +1,196 @@
+//! Custom command component.
+//!
+//! Runs the shell commands configured in `bar.commands` and returns their
+//! trimmed stdout. Results are cached per command id, so the bar can poll as
+//! often as it likes without running a command more than once per interval.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use tauri::AppHandle;
+use tauri_plugin_shell::ShellExt;
+use tauri_plugin_shell::process::CommandEvent;
+
+use crate::config::get_config;
+use crate::error::StacheError;
+
+/// Maximum time a command may run before it is killed.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Output of a bar command.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandOutput {
+    /// The command id from the config.
+    pub id: String,
+    /// The command's stdout, trimmed.
+    pub output: String,
+    /// When the command ran, in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// A command output and the instant the command ran.
+struct CachedOutput {
+    ran_at: Instant,
+    output: CommandOutput,
+}
+
+static OUTPUT_CACHE: LazyLock<Mutex<HashMap<String, CachedOutput>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Returns whether output from a run at `ran_at` can still be reused at `now`.
+fn is_fresh(ran_at: Instant, interval: Duration, now: Instant) -> bool {
+    now.saturating_duration_since(ran_at) < interval
+}
+
+/// Appends a line of process output, keeping lines separated whether or not
+/// the event included its newline.
+fn push_line(buffer: &mut Vec<u8>, line: &[u8]) {
+    buffer.extend_from_slice(line);
+    if !line.ends_with(b"\n") {
+        buffer.push(b'\n');
+    }
+}
+
+fn cached_output(id: &str, interval: Duration) -> Option<CommandOutput> {
+    let cache = OUTPUT_CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    cache
+        .get(id)
+        .filter(|cached| is_fresh(cached.ran_at, interval, Instant::now()))
+        .map(|cached| cached.output.clone())
+}
+
+/// Runs `command` with `/bin/sh -c` and returns its trimmed stdout.
+///
+/// The command is killed if it runs longer than [`COMMAND_TIMEOUT`].
+async fn execute(app: &AppHandle, command: &str) -> Result<String, StacheError> {
+    let (mut rx, child) = app
+        .shell()
+        .command("/bin/sh")
+        .args(["-c", command])
+        .spawn()
+        .map_err(|e| StacheError::ShellError(format!("Failed to run '{command}': {e}")))?;
+
+    let collect = async {
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line) => push_line(&mut stdout, &line),
+                CommandEvent::Stderr(line) => push_line(&mut stderr, &line),
+                CommandEvent::Error(err) => return Err(err),
+                CommandEvent::Terminated(payload) => {
+                    return Ok((stdout, stderr, payload.code));
+                }
+                _ => {}
+            }
+        }
+        Ok((stdout, stderr, None))
+    };
+
+    let Ok(result) = tokio::time::timeout(COMMAND_TIMEOUT, collect).await else {
+        let _ = child.kill();
+        return Err(StacheError::ShellError(format!(
+            "'{command}' timed out after {}s",
+            COMMAND_TIMEOUT.as_secs()
+        )));
+    };
+
+    let (stdout, stderr, code) =
+        result.map_err(|e| StacheError::ShellError(format!("'{command}' failed: {e}")))?;
+    if let Some(code) = code.filter(|&code| code != 0) {
+        let stderr = String::from_utf8_lossy(&stderr);
+        return Err(StacheError::ShellError(format!(
+            "'{command}' exited with status {code}: {}",
+            stderr.trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+}
+
+/// Runs the bar command with the given id and returns its output.
+///
+/// If the command ran less than its configured interval ago, the previous
+/// output is returned without running it again.
+///
+/// # Errors
+///
+/// Returns an error if no command has this id, or if the command fails, exits
+/// with a non-zero status or times out.
+#[tauri::command]
+pub async fn run_bar_command(app: AppHandle, id: String) -> Result<CommandOutput, StacheError> {
+    let Some(config) = get_config().bar.command(&id).cloned() else {
+        return Err(StacheError::InvalidArguments(format!(
+            "Unknown bar command '{id}'"
+        )));
+    };
+
+    let interval = Duration::from_secs(config.interval_seconds);
+    if let Some(output) = cached_output(&id, interval) {
+        return Ok(output);
+    }
+
+    let output = CommandOutput {
+        output: execute(&app, &config.command).await?,
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX)),
+        id,
+    };
+
+    OUTPUT_CACHE.lock().unwrap_or_else(PoisonError::into_inner).insert(
+        output.id.clone(),
+        CachedOutput {
+            ran_at: Instant::now(),
+            output: output.clone(),
+        },
+    );
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_fresh() {
+        let ran_at = Instant::now();
+        let interval = Duration::from_secs(60);
+        assert!(is_fresh(ran_at, interval, ran_at));
+        assert!(is_fresh(ran_at, interval, ran_at + Duration::from_secs(59)));
+        assert!(!is_fresh(ran_at, interval, ran_at + interval));
+        assert!(!is_fresh(ran_at, Duration::ZERO, ran_at));
+    }
+
+    #[test]
+    fn test_push_line_separates_lines() {
+        let mut buffer = Vec::new();
+        push_line(&mut buffer, b"first\n");
+        push_line(&mut buffer, b"second");
+        assert_eq!(buffer, b"first\nsecond\n");
+    }
+
+    #[test]
+    fn test_cached_output_respects_interval() {
+        OUTPUT_CACHE.lock().unwrap_or_else(PoisonError::into_inner).insert(
+            "test-cached".to_string(),
+            CachedOutput {
+                ran_at: Instant::now(),
+                output: CommandOutput {
+                    id: "test-cached".to_string(),
+                    output: "42".to_string(),
+                    timestamp: 0,
+                },
+            },
+        );
+
+        let output = cached_output("test-cached", Duration::from_secs(60)).unwrap();
+        assert_eq!(output.output, "42");
+        assert!(cached_output("test-cached", Duration::ZERO).is_none());
+        assert!(cached_output("test-missing", Duration::from_secs(60)).is_none());
+    }
+}
@@ -1,7 +1,8 @@
 //! CPU monitoring component.
 //!
-//! Provides synchronous helpers that read CPU metrics on demand using sysinfo
-//! and direct SMC access for accurate temperature readings.
+//! Provides synchronous helpers that read CPU metrics on demand using sysinfo,
+//! Mach per-core tick counters, and direct SMC access for accurate temperature
+//! readings.
 
 use std::sync::{LazyLock, Mutex, PoisonError};
 
@@ -14,26 +15,73 @@ const TEMP_MIN: f64 = 0.0;
 /// Maximum valid temperature in Celsius (sanity check).
 const TEMP_MAX: f64 = 150.0;
 
+// ============================================================================
+// FFI Declarations
+// ============================================================================
+
+/// `host_processor_info` flavor returning per-core tick counters.
+const PROCESSOR_CPU_LOAD_INFO: i32 = 2;
+/// Number of tick counters per core (user, system, idle, nice).
+const CPU_STATE_MAX: usize = 4;
+/// Index of the idle counter in a core's ticks.
+const CPU_STATE_IDLE: usize = 2;
+const KERN_SUCCESS: i32 = 0;
+
+unsafe extern "C" {
+    static mach_task_self_: u32;
+    fn mach_host_self() -> u32;
+    fn host_processor_info(
+        host: u32,
+        flavor: i32,
+        out_processor_count: *mut u32,
+        out_processor_info: *mut *mut i32,
+        out_processor_info_count: *mut u32,
+    ) -> i32;
+    fn vm_deallocate(target_task: u32, address: usize, size: usize) -> i32;
+}
+
 /// CPU metrics payload.
 #[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CpuInfo {
     /// CPU usage percentage (0-100).
     usage: f32,
     /// CPU temperature in Celsius (None if unavailable).
     temperature: Option<f32>,
+    /// Usage percentage of each core (0-100), all zeros on the first call.
+    per_core: Vec<f32>,
+    /// System load averages over 1, 5 and 15 minutes.
+    load_avg: [f64; 3],
 }
 
+/// Cumulative tick counters of a single core.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct CoreTicks([u32; CPU_STATE_MAX]);
+
 /// Global sysinfo instance to track CPU usage over time.
 static SYS: LazyLock<Mutex<System>> = LazyLock::new(|| Mutex::new(System::new_all()));
 
-/// Fetch current CPU metrics (usage and temperature) on demand.
+/// Per-core ticks from the previous call, used to compute usage deltas.
+static PREV_CORE_TICKS: Mutex<Vec<CoreTicks>> = Mutex::new(Vec::new());
+
+/// Mach host port, fetched once to avoid leaking a port right per call.
+static HOST_PORT: LazyLock<u32> = LazyLock::new(|| unsafe { mach_host_self() });
+
+/// Fetch current CPU metrics (usage, per-core usage, load and temperature) on demand.
 #[tauri::command]
 #[allow(clippy::needless_pass_by_value)]
 pub fn get_cpu_info(app: tauri::AppHandle) -> CpuInfo {
     let usage = get_cpu_usage().round();
+    let per_core = get_per_core_usage();
+    let load_avg = get_load_average();
     let temperature = get_cpu_temperature(&app).map(f32::round);
 
-    CpuInfo { usage, temperature }
+    CpuInfo {
+        usage,
+        temperature,
+        per_core,
+        load_avg,
+    }
 }
 
 /// Get current CPU usage percentage.
@@ -47,6 +95,96 @@ fn get_cpu_usage() -> f32 {
     sys.global_cpu_usage()
 }
 
+/// Get the usage of each core since the previous call.
+///
+/// The first call has nothing to compare against and reports zeros.
+fn get_per_core_usage() -> Vec<f32> {
+    let Some(current) = read_core_ticks() else {
+        return Vec::new();
+    };
+
+    let mut prev = PREV_CORE_TICKS.lock().unwrap_or_else(PoisonError::into_inner);
+    let usage = core_usage(&prev, &current);
+    *prev = current;
+
+    usage
+}
+
+/// Compute per-core usage percentages from two tick samples.
+///
+/// Returns zeros when the samples don't line up (e.g. the first sample).
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn core_usage(prev: &[CoreTicks], current: &[CoreTicks]) -> Vec<f32> {
+    if prev.len() != current.len() {
+        return vec![0.0; current.len()];
+    }
+
+    prev.iter()
+        .zip(current)
+        .map(|(prev, current)| {
+            // Counters are 32-bit and may wrap around
+            let deltas: [u64; CPU_STATE_MAX] =
+                std::array::from_fn(|i| u64::from(current.0[i].wrapping_sub(prev.0[i])));
+            let total: u64 = deltas.iter().sum();
+            let idle = deltas[CPU_STATE_IDLE];
+
+            if total == 0 {
+                return 0.0;
+            }
+
+            ((total - idle) as f64 / total as f64 * 100.0).round() as f32
+        })
+        .collect()
+}
+
+/// Read the cumulative tick counters of every core with `host_processor_info`.
+#[allow(clippy::cast_sign_loss)]
+fn read_core_ticks() -> Option<Vec<CoreTicks>> {
+    let mut cpu_count: u32 = 0;
+    let mut info: *mut i32 = std::ptr::null_mut();
+    let mut info_count: u32 = 0;
+
+    let result = unsafe {
+        host_processor_info(
+            *HOST_PORT,
+            PROCESSOR_CPU_LOAD_INFO,
+            &raw mut cpu_count,
+            &raw mut info,
+            &raw mut info_count,
+        )
+    };
+
+    if result != KERN_SUCCESS || info.is_null() {
+        return None;
+    }
+
+    let ticks = {
+        let values = unsafe { std::slice::from_raw_parts(info, info_count as usize) };
+        values
+            .chunks_exact(CPU_STATE_MAX)
+            .take(cpu_count as usize)
+            .map(|chunk| CoreTicks(std::array::from_fn(|i| chunk[i] as u32)))
+            .collect()
+    };
+
+    // The kernel allocates the info array in our address space
+    unsafe {
+        vm_deallocate(
+            mach_task_self_,
+            info as usize,
+            info_count as usize * std::mem::size_of::<i32>(),
+        );
+    }
+
+    Some(ticks)
+}
+
+/// Get the 1, 5 and 15 minute load averages (sysinfo reads them with `getloadavg`).
+fn get_load_average() -> [f64; 3] {
+    let load = System::load_average();
+    [load.one, load.five, load.fifteen]
+}
+
 /// Get CPU temperature using multiple methods in order of preference:
 /// 1. Direct SMC access via smc crate (most accurate, requires proper entitlements)
 /// 2. External tools (ismc or smctemp) if installed via Homebrew
@@ -188,6 +326,7 @@ mod tests {
         let info = CpuInfo {
             usage: 45.5,
             temperature: Some(65.2),
+            ..Default::default()
         };
 
         assert!((info.usage - 45.5).abs() < f32::EPSILON);
@@ -199,6 +338,7 @@ mod tests {
         let info = CpuInfo {
             usage: 45.5,
             temperature: Some(65.2),
+            ..Default::default()
         };
         let cloned = info.clone();
 
@@ -208,7 +348,11 @@ mod tests {
 
     #[test]
     fn test_cpu_info_with_no_temperature() {
-        let info = CpuInfo { usage: 45.5, temperature: None };
+        let info = CpuInfo {
+            usage: 45.5,
+            temperature: None,
+            ..Default::default()
+        };
 
         assert!((info.usage - 45.5).abs() < f32::EPSILON);
         assert!(info.temperature.is_none());
@@ -367,6 +511,7 @@ mod tests {
         let info = CpuInfo {
             usage: 50.0,
             temperature: Some(70.0),
+            ..Default::default()
         };
         let debug_str = format!("{info:?}");
         assert!(debug_str.contains("CpuInfo"));
@@ -379,6 +524,7 @@ mod tests {
         let info = CpuInfo {
             usage: 45.5,
             temperature: Some(65.2),
+            ..Default::default()
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains("usage"));
@@ -389,7 +535,11 @@ mod tests {
 
     #[test]
     fn test_cpu_info_serialization_no_temperature() {
-        let info = CpuInfo { usage: 45.5, temperature: None };
+        let info = CpuInfo {
+            usage: 45.5,
+            temperature: None,
+            ..Default::default()
+        };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains("null") || json.contains("temperature"));
     }
@@ -399,4 +549,48 @@ mod tests {
         assert!((TEMP_MIN - 0.0).abs() < f64::EPSILON);
         assert!((TEMP_MAX - 150.0).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_cpu_info_serialization_per_core_and_load() {
+        let info = CpuInfo {
+            usage: 30.0,
+            temperature: None,
+            per_core: vec![10.0, 50.0],
+            load_avg: [1.5, 1.25, 1.0],
+        };
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["perCore"], serde_json::json!([10.0, 50.0]));
+        assert_eq!(json["loadAvg"], serde_json::json!([1.5, 1.25, 1.0]));
+    }
+
+    #[test]
+    fn test_core_usage_first_sample_is_zero() {
+        let current = [CoreTicks([100, 50, 850, 0]), CoreTicks([10, 10, 980, 0])];
+        assert_eq!(core_usage(&[], &current), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_core_usage_from_deltas() {
+        let prev = [CoreTicks([100, 50, 850, 0]), CoreTicks([10, 10, 980, 0])];
+        // Core 0: 30 busy out of 100 ticks, core 1: idle the whole time
+        let current = [CoreTicks([120, 60, 920, 0]), CoreTicks([10, 10, 1080, 0])];
+        assert_eq!(core_usage(&prev, &current), vec![30.0, 0.0]);
+
+        // No ticks elapsed
+        assert_eq!(core_usage(&prev, &prev), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_core_usage_handles_counter_wraparound() {
+        let prev = [CoreTicks([u32::MAX - 9, 0, 0, 0])];
+        let current = [CoreTicks([10, 0, 20, 0])];
+        // 20 busy ticks across the wrap, 20 idle ticks
+        assert_eq!(core_usage(&prev, &current), vec![50.0]);
+    }
+
+    #[test]
+    fn test_get_load_average() {
+        let load = get_load_average();
+        assert!(load.iter().all(|&value| value >= 0.0));
+    }
 }
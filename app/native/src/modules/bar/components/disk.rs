@@ -0,0 +1,228 @@
+//! Disk usage component.
+//!
+//! Lists mounted volumes with `getmntinfo` and reports the free and total
+//! space of each one. Hidden and read-only system volumes are skipped unless
+//! listed in `bar.disk.includeVolumes`.
+
+use std::ffi::{CStr, c_char};
+
+use serde::Serialize;
+
+use crate::config::get_config;
+
+// ============================================================================
+// FFI Declarations
+// ============================================================================
+
+/// Return cached statistics without asking each file system, so a volume
+/// that stopped responding (e.g. a dropped network share) can't block.
+const MNT_NOWAIT: i32 = 2;
+/// Volume is mounted read-only.
+const MNT_RDONLY: u32 = 0x0000_0001;
+/// Volume is hidden from the user (system, VM and snapshot volumes).
+const MNT_DONTBROWSE: u32 = 0x0010_0000;
+/// Maximum length of a mount path (`MAXPATHLEN`).
+const MAX_PATH_LEN: usize = 1024;
+/// Maximum length of a file system type name (`MFSTYPENAMELEN`).
+const FS_TYPE_NAME_LEN: usize = 16;
+
+/// File system statistics (64-bit inode `struct statfs`).
+#[repr(C)]
+struct Statfs {
+    f_bsize: u32,
+    _f_iosize: i32,
+    f_blocks: u64,
+    _f_bfree: u64,
+    f_bavail: u64,
+    _f_files: u64,
+    _f_ffree: u64,
+    _f_fsid: [i32; 2],
+    _f_owner: u32,
+    _f_type: u32,
+    f_flags: u32,
+    _f_fssubtype: u32,
+    _f_fstypename: [c_char; FS_TYPE_NAME_LEN],
+    f_mntonname: [c_char; MAX_PATH_LEN],
+    _f_mntfromname: [c_char; MAX_PATH_LEN],
+    _f_flags_ext: u32,
+    _f_reserved: [u32; 7],
+}
+
+unsafe extern "C" {
+    #[cfg_attr(target_arch = "x86_64", link_name = "getmntinfo$INODE64")]
+    fn getmntinfo(mntbufp: *mut *mut Statfs, flags: i32) -> i32;
+}
+
+/// Disk usage of a mounted volume.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskVolume {
+    /// Path the volume is mounted on (e.g. `/` or `/Volumes/Backup`).
+    mount_point: String,
+    /// Display name, the last component of the mount point.
+    name: String,
+    /// Space available to the user, in bytes.
+    free_bytes: u64,
+    /// Total size of the volume, in bytes.
+    total_bytes: u64,
+}
+
+/// Statistics of a single mount, as read from `getmntinfo`.
+#[derive(Debug, Clone)]
+struct MountStats {
+    mount_point: String,
+    flags: u32,
+    block_size: u64,
+    blocks: u64,
+    available_blocks: u64,
+}
+
+/// Fetch the free and total space of the mounted volumes on demand.
+#[tauri::command]
+pub fn get_disk_info() -> Vec<DiskVolume> {
+    let include = &get_config().bar.disk.include_volumes;
+    disk_volumes(read_mounts(), include)
+}
+
+/// Build the volume list from mount statistics.
+///
+/// The root volume is always listed. Other hidden or read-only volumes, and
+/// pseudo file systems without any blocks, are skipped unless their name or
+/// mount point is in `include`.
+fn disk_volumes(mounts: Vec<MountStats>, include: &[String]) -> Vec<DiskVolume> {
+    mounts
+        .into_iter()
+        .filter_map(|mount| {
+            let name = volume_name(&mount.mount_point);
+            let is_included = include.iter().any(|v| *v == name || *v == mount.mount_point);
+            let is_system = mount.flags & (MNT_DONTBROWSE | MNT_RDONLY) != 0;
+
+            let is_listed =
+                mount.blocks > 0 && (mount.mount_point == "/" || !is_system || is_included);
+            is_listed.then(|| DiskVolume {
+                free_bytes: mount.available_blocks * mount.block_size,
+                total_bytes: mount.blocks * mount.block_size,
+                mount_point: mount.mount_point,
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Returns the display name of a volume from its mount point.
+fn volume_name(mount_point: &str) -> String {
+    mount_point
+        .rsplit('/')
+        .find(|component| !component.is_empty())
+        .unwrap_or(mount_point)
+        .to_string()
+}
+
+/// Read the statistics of every mounted file system.
+fn read_mounts() -> Vec<MountStats> {
+    let mut buffer: *mut Statfs = std::ptr::null_mut();
+
+    // The buffer is owned by libc and reused across calls, so it is not freed
+    let count = unsafe { getmntinfo(&raw mut buffer, MNT_NOWAIT) };
+    let Ok(count) = usize::try_from(count) else {
+        return Vec::new();
+    };
+    if buffer.is_null() {
+        return Vec::new();
+    }
+
+    let entries = unsafe { std::slice::from_raw_parts(buffer, count) };
+    entries
+        .iter()
+        .map(|entry| MountStats {
+            mount_point: unsafe { CStr::from_ptr(entry.f_mntonname.as_ptr()) }
+                .to_string_lossy()
+                .into_owned(),
+            flags: entry.f_flags,
+            block_size: u64::from(entry.f_bsize),
+            blocks: entry.f_blocks,
+            available_blocks: entry.f_bavail,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mount(mount_point: &str, flags: u32) -> MountStats {
+        MountStats {
+            mount_point: mount_point.to_string(),
+            flags,
+            block_size: 4096,
+            blocks: 1_000,
+            available_blocks: 250,
+        }
+    }
+
+    #[test]
+    fn test_statfs_layout() {
+        assert_eq!(std::mem::size_of::<Statfs>(), 2168);
+    }
+
+    #[test]
+    fn test_disk_volumes_skips_system_volumes() {
+        let mounts = vec![
+            mount("/", MNT_RDONLY),
+            mount("/System/Volumes/Data", MNT_DONTBROWSE),
+            mount("/Volumes/Backup", 0),
+            mount("/Volumes/Installer", MNT_RDONLY),
+        ];
+
+        let volumes = disk_volumes(mounts, &[]);
+        let names: Vec<&str> = volumes.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["/", "Backup"]);
+        assert_eq!(volumes[1].free_bytes, 250 * 4096);
+        assert_eq!(volumes[1].total_bytes, 1_000 * 4096);
+    }
+
+    #[test]
+    fn test_disk_volumes_include_override() {
+        let mounts = vec![
+            mount("/System/Volumes/Data", MNT_DONTBROWSE),
+            mount("/Volumes/Installer", MNT_RDONLY),
+        ];
+
+        let include = ["Installer".to_string(), "/System/Volumes/Data".to_string()];
+        assert_eq!(disk_volumes(mounts, &include).len(), 2);
+    }
+
+    #[test]
+    fn test_disk_volumes_skips_empty_file_systems() {
+        let devfs = MountStats { blocks: 0, ..mount("/dev", 0) };
+        assert!(disk_volumes(vec![devfs], &["/dev".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn test_volume_name() {
+        assert_eq!(volume_name("/"), "/");
+        assert_eq!(volume_name("/Volumes/Backup"), "Backup");
+        assert_eq!(volume_name("/Volumes/My Disk/"), "My Disk");
+    }
+
+    #[test]
+    fn test_disk_volume_serialization() {
+        let volume = DiskVolume {
+            mount_point: "/Volumes/Backup".to_string(),
+            name: "Backup".to_string(),
+            free_bytes: 1_024,
+            total_bytes: 4_096,
+        };
+
+        let json = serde_json::to_value(&volume).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "mountPoint": "/Volumes/Backup",
+                "name": "Backup",
+                "freeBytes": 1024,
+                "totalBytes": 4096
+            })
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::ffi::c_void;
-use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::sync::{Condvar, Mutex, MutexGuard, OnceLock};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use core_foundation::base::TCFType;
 use core_foundation::string::CFString;
@@ -13,6 +13,8 @@ use serde::Serialize;
 use tauri::{Emitter, Manager};
 
 use crate::error::StacheError;
+use crate::utils::duration::parse_duration;
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
 use crate::utils::thread::spawn_named_thread;
 use crate::{constants, events};
 
@@ -37,11 +39,17 @@ fn emit_keep_awake_changed(
 struct KeepAwakeState {
     desired_awake: bool,
     handle: Option<KeepAwake>,
+    /// When a timed keep-awake releases. `None` keeps the system awake indefinitely.
+    expires_at: Option<Instant>,
+    /// Whether the expiry timer thread is running.
+    timer_running: bool,
 }
 
 #[derive(Default)]
 pub struct KeepAwakeController {
     state: Mutex<KeepAwakeState>,
+    /// Wakes the expiry timer when the expiry changes.
+    expiry_changed: Condvar,
 }
 
 impl KeepAwakeController {
@@ -75,8 +83,60 @@ impl KeepAwakeController {
         })
     }
 
+    /// Keeps the system awake until `expires_at`, replacing any previous expiry.
+    ///
+    /// Returns `true` when no expiry timer is running yet and one must be started.
+    fn enable_until(&self, expires_at: Instant) -> Result<bool, String> {
+        let start_timer = self.lock_state().and_then(|mut state| {
+            state.desired_awake = true;
+            state.expires_at = Some(expires_at);
+            Self::ensure_awake_handle(&mut state)?;
+            Ok(!std::mem::replace(&mut state.timer_running, true))
+        })?;
+
+        self.expiry_changed.notify_all();
+        Ok(start_timer)
+    }
+
+    /// Blocks until the current expiry elapses or is cleared.
+    ///
+    /// Releases the wake lock and returns the new state when the expiry
+    /// elapses, or `None` when it was cleared (e.g. by toggling).
+    fn wait_for_expiry(&self) -> Result<Option<KeepAwakeChangedPayload>, String> {
+        let mut state = self.lock_state()?;
+
+        loop {
+            let Some(expires_at) = state.expires_at else {
+                state.timer_running = false;
+                return Ok(None);
+            };
+
+            let now = Instant::now();
+            if expires_at <= now {
+                state.expires_at = None;
+                state.desired_awake = false;
+                state.handle = None;
+                state.timer_running = false;
+
+                return Ok(Some(KeepAwakeChangedPayload {
+                    locked: false,
+                    desired_awake: false,
+                }));
+            }
+
+            state = self
+                .expiry_changed
+                .wait_timeout(state, expires_at - now)
+                .map_err(|err| err.to_string())?
+                .0;
+        }
+    }
+
     fn toggle_impl(&self) -> Result<bool, String> {
-        self.lock_state().and_then(|mut state| {
+        let result = self.lock_state().and_then(|mut state| {
+            // Toggling always ends a timed keep-awake
+            state.expires_at = None;
+
             if state.desired_awake {
                 state.desired_awake = false;
                 state.handle = None;
@@ -86,7 +146,30 @@ impl KeepAwakeController {
                 Self::ensure_awake_handle(&mut state)?;
                 Ok(true)
             }
-        })
+        });
+
+        self.expiry_changed.notify_all();
+        result
+    }
+
+    /// Keeps the system awake or lets it sleep, ending any timed keep-awake.
+    ///
+    /// Returns whether the system was meant to be kept awake before.
+    fn set_impl(&self, awake: bool) -> Result<bool, String> {
+        let result = self.lock_state().and_then(|mut state| {
+            state.expires_at = None;
+
+            let was_awake = std::mem::replace(&mut state.desired_awake, awake);
+            if awake {
+                Self::ensure_awake_handle(&mut state)?;
+            } else {
+                state.handle = None;
+            }
+            Ok(was_awake)
+        });
+
+        self.expiry_changed.notify_all();
+        result
     }
 
     fn is_awake(&self) -> Result<bool, String> {
@@ -139,10 +222,104 @@ pub fn is_system_awake(state: tauri::State<KeepAwakeController>) -> Result<bool,
     state.is_awake().map_err(StacheError::CommandError)
 }
 
+/// Keeps the system awake for `duration`, then releases the wake lock.
+///
+/// Calling it again while a timed keep-awake is active moves the expiry
+/// instead of taking another wake lock.
+fn keep_awake_for(app_handle: &tauri::AppHandle, duration: Duration) -> Result<(), String> {
+    let expires_at = Instant::now()
+        .checked_add(duration)
+        .ok_or_else(|| "Duration is too long".to_string())?;
+    let start_timer = app_handle.state::<KeepAwakeController>().enable_until(expires_at)?;
+
+    let payload = KeepAwakeChangedPayload {
+        locked: is_session_locked().unwrap_or(false),
+        desired_awake: true,
+    };
+    if let Err(err) = emit_keep_awake_changed(app_handle, payload) {
+        tracing::warn!(error = %err, "failed to emit keep_awake_changed event");
+    }
+
+    if start_timer {
+        let app_handle = app_handle.clone();
+        spawn_named_thread("keepawake-timer", move || run_expiry_timer(&app_handle));
+    }
+
+    Ok(())
+}
+
+/// Keeps the system awake or lets it sleep, for features that drive
+/// keep-awake themselves.
+///
+/// Returns whether the system was meant to be kept awake before, so the
+/// caller can restore it.
+///
+/// # Errors
+///
+/// Returns an error if the wake lock cannot be acquired.
+pub fn set_system_awake(app_handle: &tauri::AppHandle, awake: bool) -> Result<bool, String> {
+    let was_awake = app_handle.state::<KeepAwakeController>().set_impl(awake)?;
+
+    let payload = KeepAwakeChangedPayload {
+        locked: is_session_locked().unwrap_or(false),
+        desired_awake: awake,
+    };
+    if let Err(err) = emit_keep_awake_changed(app_handle, payload) {
+        tracing::warn!(error = %err, "failed to emit keep_awake_changed event");
+    }
+
+    Ok(was_awake)
+}
+
+fn run_expiry_timer(app_handle: &tauri::AppHandle) {
+    match app_handle.state::<KeepAwakeController>().wait_for_expiry() {
+        Ok(Some(payload)) => {
+            tracing::debug!("timed keep awake expired, releasing wake lock");
+            if let Err(err) = emit_keep_awake_changed(app_handle, payload) {
+                tracing::warn!(error = %err, "failed to emit keep_awake_changed event");
+            }
+        }
+        Ok(None) => {}
+        Err(err) => tracing::warn!(error = %err, "keep awake timer failed"),
+    }
+}
+
+/// Handles keep-awake IPC queries from the CLI.
+///
+/// Returns `None` for queries handled elsewhere.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    let IpcQuery::KeepAwake { duration } = query else {
+        return None;
+    };
+
+    let Some(parsed) = parse_duration(duration) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid duration '{duration}'. Use e.g. '90m', '2h' or '1h30m'."
+        )));
+    };
+
+    let Some(app_handle) = APP_HANDLE.get() else {
+        return Some(IpcResponse::error("Keep awake is not initialized"));
+    };
+
+    Some(match keep_awake_for(app_handle, parsed) {
+        Ok(()) => IpcResponse::success(serde_json::json!({
+            "awake": true,
+            "expiresInSecs": parsed.as_secs(),
+        })),
+        Err(err) => IpcResponse::error(err),
+    })
+}
+
 static LOCK_WATCHER_ONCE: OnceLock<()> = OnceLock::new();
 
+/// App handle used by IPC queries, set once the bar window is initialized.
+static APP_HANDLE: OnceLock<tauri::AppHandle> = OnceLock::new();
+
 pub fn init(window: &tauri::WebviewWindow) {
     let app_handle = window.app_handle();
+    let _ = APP_HANDLE.set(app_handle.clone());
 
     if let Err(err) = app_handle.state::<KeepAwakeController>().enable_awake() {
         tracing::warn!(error = %err, "failed to acquire keep awake handle on startup");
@@ -259,6 +436,8 @@ mod tests {
 
         assert!(!state.desired_awake);
         assert!(state.handle.is_none());
+        assert!(state.expires_at.is_none());
+        assert!(!state.timer_running);
     }
 
     #[test]
@@ -456,6 +635,80 @@ mod tests {
         assert!(state.handle.is_none());
     }
 
+    #[test]
+    fn test_keep_awake_controller_enable_until_extends_expiry() {
+        let controller = KeepAwakeController::default();
+        let first = Instant::now() + Duration::from_secs(60);
+        let second = first + Duration::from_secs(60);
+
+        // Only the first call needs to start the timer
+        assert_eq!(controller.enable_until(first), Ok(true));
+        assert_eq!(controller.enable_until(second), Ok(false));
+
+        let state = controller.lock_state().unwrap();
+        assert!(state.desired_awake);
+        assert_eq!(state.expires_at, Some(second));
+        drop(state);
+    }
+
+    #[test]
+    #[allow(clippy::significant_drop_tightening)]
+    fn test_keep_awake_controller_wait_for_expiry_releases() {
+        let controller = KeepAwakeController::default();
+        let _ = controller.enable_until(Instant::now());
+
+        let payload = controller.wait_for_expiry().unwrap().unwrap();
+        assert!(!payload.desired_awake);
+        assert!(!controller.is_awake().unwrap());
+
+        let state = controller.lock_state().unwrap();
+        assert!(!state.desired_awake);
+        assert!(state.expires_at.is_none());
+        assert!(!state.timer_running);
+    }
+
+    #[test]
+    #[allow(clippy::significant_drop_tightening)]
+    fn test_keep_awake_controller_toggle_cancels_expiry() {
+        let controller = KeepAwakeController::default();
+        let _ = controller.enable_until(Instant::now() + Duration::from_secs(60));
+
+        // Toggling off clears the expiry, so the timer stops without releasing again
+        assert_eq!(controller.toggle_impl(), Ok(false));
+        assert!(controller.wait_for_expiry().unwrap().is_none());
+
+        let state = controller.lock_state().unwrap();
+        assert!(state.expires_at.is_none());
+        assert!(!state.timer_running);
+    }
+
+    #[test]
+    fn test_keep_awake_controller_set_returns_previous_state() {
+        let controller = KeepAwakeController::default();
+        let _ = controller.enable_until(Instant::now() + Duration::from_secs(60));
+
+        // Turning it off ends the timed keep-awake and reports it was on
+        assert_eq!(controller.set_impl(false), Ok(true));
+        assert_eq!(controller.set_impl(false), Ok(false));
+        assert!(controller.wait_for_expiry().unwrap().is_none());
+        assert!(!controller.lock_state().unwrap().desired_awake);
+    }
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+
+    #[test]
+    fn test_handle_ipc_query_rejects_invalid_duration() {
+        let query = IpcQuery::KeepAwake { duration: "soon".to_string() };
+
+        assert!(matches!(
+            handle_ipc_query(&query),
+            Some(IpcResponse::Error { error }) if error.contains("Invalid duration")
+        ));
+    }
+
     #[test]
     fn test_keep_awake_controller_handle_system_unlocked_without_desired() {
         let controller = KeepAwakeController::default();
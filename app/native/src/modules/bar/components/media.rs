@@ -3,6 +3,7 @@
 //! Monitors currently playing media using the bundled `media-control` sidecar.
 //! Streams media metadata changes and processes artwork for display in the frontend.
 //! Artwork is resized to 128x128, cached to disk, and sent as base64-encoded PNG data.
+//! Playback can be controlled (play/pause, next, previous) through the same sidecar.
 
 #![allow(unexpected_cfgs)]
 
@@ -22,7 +23,9 @@ use tauri_plugin_shell::ShellExt;
 use tauri_plugin_shell::process::CommandEvent;
 
 use crate::cache::get_cache_subdir_str;
+use crate::error::StacheError;
 use crate::events;
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
 use crate::utils::thread::spawn_named_thread;
 
 /// Resize the provided image to 128x128 and encode it as PNG.
@@ -88,9 +91,15 @@ fn cleanup_string_for_filename(s: &str) -> String {
 
 static UNKNOWN: &str = "unknown";
 
+/// Error message returned when a playback command has no media app to target.
+const NO_ACTIVE_MEDIA: &str = "No active media";
+
 static LAST_MEDIA_PAYLOAD: OnceLock<Mutex<Value>> = OnceLock::new();
 static LAST_STATE_HASH: AtomicU64 = AtomicU64::new(0);
 
+/// The bar window media updates are emitted to, set on init.
+static MEDIA_WINDOW: OnceLock<WebviewWindow> = OnceLock::new();
+
 fn get_cache_path(state: &Map<String, Value>, extension: &str) -> String {
     let cache_dir = get_cache_dir();
 
@@ -224,6 +233,192 @@ fn get_last_media_payload() -> Option<Value> {
 #[must_use]
 pub fn get_current_media_info() -> Option<Value> { get_last_media_payload() }
 
+/// Playback commands sent to the active media app.
+///
+/// Also the `stache media` subcommands.
+#[derive(clap::Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+#[command(next_display_order = None)]
+pub enum MediaCommand {
+    /// Toggle play/pause on the active media app.
+    PlayPause,
+
+    /// Skip to the next track.
+    Next,
+
+    /// Go back to the previous track.
+    Previous,
+}
+
+impl MediaCommand {
+    /// Parses a command name as used by the CLI ("play-pause", "next", "previous").
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "play-pause" => Some(Self::PlayPause),
+            "next" => Some(Self::Next),
+            "previous" | "prev" => Some(Self::Previous),
+            _ => None,
+        }
+    }
+
+    /// Returns the command name as used by the CLI and sent over IPC.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PlayPause => "play-pause",
+            Self::Next => "next",
+            Self::Previous => "previous",
+        }
+    }
+
+    /// Returns the `media-control` subcommand for this command.
+    const fn sidecar_arg(self) -> &'static str {
+        match self {
+            Self::PlayPause => "toggle-play-pause",
+            Self::Next => "next-track",
+            Self::Previous => "previous-track",
+        }
+    }
+}
+
+/// Runs the `media-control` sidecar to completion and returns its stdout.
+async fn run_sidecar(app: &AppHandle, args: &[&str]) -> Result<String, StacheError> {
+    let command = app
+        .shell()
+        .sidecar("media-control")
+        .map_err(|err| StacheError::MediaError(format!("Failed to create sidecar: {err}")))?
+        .args(args);
+
+    let output = command
+        .output()
+        .await
+        .map_err(|err| StacheError::MediaError(format!("Failed to run media-control: {err}")))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(StacheError::MediaError(format!(
+            "media-control {} failed: {}",
+            args.join(" "),
+            stderr.trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Extracts the now-playing state from `media-control get` output.
+///
+/// Returns `None` when nothing is playing (`null` or an empty object).
+fn parse_get_output(output: &str) -> Option<Map<String, Value>> {
+    let parsed = parse_output(output.trim())?;
+    let state = parsed
+        .get("payload")
+        .and_then(Value::as_object)
+        .or_else(|| parsed.as_object())?;
+
+    if state.is_empty() {
+        None
+    } else {
+        Some(state.clone())
+    }
+}
+
+/// Fetches the current now-playing state and emits it to the bar.
+async fn refresh_media_info(window: &WebviewWindow) {
+    let state = match run_sidecar(window.app_handle(), &["get"]).await {
+        Ok(output) => parse_get_output(&output),
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to refresh media info");
+            return;
+        }
+    };
+
+    let Some(mut state) = state else {
+        set_last_media_payload(None);
+        return;
+    };
+
+    if let Err(err) = save_artwork_and_emit(&mut state, window, true) {
+        tracing::warn!(error = %err, "failed to emit media update");
+    }
+}
+
+/// Sends a playback command to the active media app.
+///
+/// Returns the updated media info, which is also emitted to the bar.
+///
+/// # Errors
+///
+/// Returns an error if no media app is active or the command fails.
+pub async fn send_media_command(command: MediaCommand) -> Result<Value, StacheError> {
+    if get_last_media_payload().is_none() {
+        return Err(StacheError::MediaError(NO_ACTIVE_MEDIA.to_string()));
+    }
+
+    let window = MEDIA_WINDOW
+        .get()
+        .ok_or_else(|| StacheError::MediaError("Media component is not initialized".to_string()))?;
+
+    run_sidecar(window.app_handle(), &[command.sidecar_arg()]).await?;
+    refresh_media_info(window).await;
+
+    get_last_media_payload().ok_or_else(|| StacheError::MediaError(NO_ACTIVE_MEDIA.to_string()))
+}
+
+/// Toggles play/pause on the active media app.
+///
+/// # Errors
+///
+/// Returns an error if no media app is active or the command fails.
+#[tauri::command]
+pub async fn media_play_pause() -> Result<Value, StacheError> {
+    send_media_command(MediaCommand::PlayPause).await
+}
+
+/// Skips to the next track on the active media app.
+///
+/// # Errors
+///
+/// Returns an error if no media app is active or the command fails.
+#[tauri::command]
+pub async fn media_next() -> Result<Value, StacheError> {
+    send_media_command(MediaCommand::Next).await
+}
+
+/// Goes back to the previous track on the active media app.
+///
+/// # Errors
+///
+/// Returns an error if no media app is active or the command fails.
+#[tauri::command]
+pub async fn media_previous() -> Result<Value, StacheError> {
+    send_media_command(MediaCommand::Previous).await
+}
+
+/// Handles media IPC queries.
+///
+/// Returns `None` for queries that are not media-related.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    let IpcQuery::MediaControl { command } = query else {
+        return None;
+    };
+
+    let Some(command) = MediaCommand::parse(command) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid media command '{command}'. Must be 'play-pause', 'next' or 'previous'."
+        )));
+    };
+
+    Some(
+        match tauri::async_runtime::block_on(send_media_command(command)) {
+            Ok(info) => IpcResponse::success(info),
+            Err(StacheError::MediaError(message)) => IpcResponse::error(message),
+            Err(err) => IpcResponse::error(err.to_string()),
+        },
+    )
+}
+
 fn save_artwork_and_emit(
     state: &mut Map<String, Value>,
     window: &WebviewWindow,
@@ -290,9 +485,10 @@ fn process_stream_output(line: &str, state: &mut Map<String, Value>, window: &We
     state.clear();
     state.extend(payload_obj.clone());
 
-    if !state.is_empty()
-        && let Err(err) = save_artwork_and_emit(state, window, false)
-    {
+    // An empty payload means no media app is active anymore
+    if state.is_empty() {
+        set_last_media_payload(None);
+    } else if let Err(err) = save_artwork_and_emit(state, window, false) {
         tracing::warn!(error = %err, "failed to emit media update");
     }
 }
@@ -354,6 +550,8 @@ fn start_streaming(app: AppHandle, window: WebviewWindow) {
 /// Spawns a background thread that streams media control events and processes
 /// artwork for efficient frontend display.
 pub fn init(window: &WebviewWindow) {
+    let _ = MEDIA_WINDOW.set(window.clone());
+
     let w = window.clone();
     let app = window.app_handle().clone();
     spawn_named_thread("media", move || start_streaming(app, w));
@@ -365,10 +563,11 @@ mod tests {
     use serde_json::{Map, Value, json};
 
     use super::{
-        UNKNOWN, calculate_state_hash, cleanup_string_for_filename, get_cache_dir, get_cache_path,
-        get_current_media_info, image_format_from_mime, parse_json, parse_output,
-        set_last_media_payload,
+        MediaCommand, UNKNOWN, calculate_state_hash, cleanup_string_for_filename, get_cache_dir,
+        get_cache_path, get_current_media_info, handle_ipc_query, image_format_from_mime,
+        parse_get_output, parse_json, parse_output, set_last_media_payload,
     };
+    use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
 
     #[test]
     fn test_cleanup_string_for_filename() {
@@ -552,6 +751,61 @@ mod tests {
         assert!(parse_output("   ").is_none());
     }
 
+    #[test]
+    fn test_media_command_parse() {
+        assert_eq!(MediaCommand::parse("play-pause"), Some(MediaCommand::PlayPause));
+        assert_eq!(MediaCommand::parse("NEXT"), Some(MediaCommand::Next));
+        assert_eq!(MediaCommand::parse("previous"), Some(MediaCommand::Previous));
+        assert_eq!(MediaCommand::parse("prev"), Some(MediaCommand::Previous));
+        assert_eq!(MediaCommand::parse("stop"), None);
+    }
+
+    #[test]
+    fn test_media_command_round_trip() {
+        for command in [
+            MediaCommand::PlayPause,
+            MediaCommand::Next,
+            MediaCommand::Previous,
+        ] {
+            assert_eq!(MediaCommand::parse(command.as_str()), Some(command));
+        }
+    }
+
+    #[test]
+    fn test_media_command_sidecar_arg() {
+        assert_eq!(MediaCommand::PlayPause.sidecar_arg(), "toggle-play-pause");
+        assert_eq!(MediaCommand::Next.sidecar_arg(), "next-track");
+        assert_eq!(MediaCommand::Previous.sidecar_arg(), "previous-track");
+    }
+
+    #[test]
+    fn test_parse_get_output() {
+        let state = parse_get_output(r#"{"title": "Song", "playing": true}"#).unwrap();
+        assert_eq!(state.get("title"), Some(&json!("Song")));
+
+        let state = parse_get_output(r#"{"payload": {"title": "Song"}}"#).unwrap();
+        assert_eq!(state.get("title"), Some(&json!("Song")));
+    }
+
+    #[test]
+    fn test_parse_get_output_nothing_playing() {
+        assert!(parse_get_output("null").is_none());
+        assert!(parse_get_output("{}").is_none());
+        assert!(parse_get_output("").is_none());
+    }
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+
+    #[test]
+    fn test_handle_ipc_query_invalid_command() {
+        let query = IpcQuery::MediaControl { command: "stop".to_string() };
+        let response = handle_ipc_query(&query).unwrap();
+        assert!(matches!(response, IpcResponse::Error { error } if error.contains("stop")));
+    }
+
     #[test]
     fn test_get_current_media_info_none_when_unset() {
         set_last_media_payload(None);
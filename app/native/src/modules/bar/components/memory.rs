@@ -0,0 +1,251 @@
+//! Memory monitoring component.
+//!
+//! Reads the VM page counters with a single `host_statistics64` call and
+//! reports used and total physical memory along with a memory pressure level.
+//! Cheap enough to be polled frequently by the bar.
+
+use std::ffi::{c_char, c_void};
+use std::sync::LazyLock;
+
+use serde::Serialize;
+
+// ============================================================================
+// FFI Declarations
+// ============================================================================
+
+/// `host_statistics64` flavor returning `vm_statistics64`.
+const HOST_VM_INFO64: i32 = 4;
+/// Size of `vm_statistics64` in `integer_t` units.
+#[allow(clippy::cast_possible_truncation)] // The struct is 152 bytes
+const HOST_VM_INFO64_COUNT: u32 =
+    (std::mem::size_of::<VmStatistics64>() / std::mem::size_of::<i32>()) as u32;
+const KERN_SUCCESS: i32 = 0;
+
+/// Share of memory that must be readily available to stay at normal pressure.
+const WARNING_AVAILABLE_RATIO: f64 = 0.2;
+/// Share of memory below which pressure is critical.
+const CRITICAL_AVAILABLE_RATIO: f64 = 0.1;
+
+/// VM page counters (`struct vm_statistics64`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+struct VmStatistics64 {
+    free_count: u32,
+    _active_count: u32,
+    inactive_count: u32,
+    wire_count: u32,
+    /// Zero fill, reactivation, page in/out, fault, COW fault, lookup, hit
+    /// and purge counters.
+    _events: [u64; 9],
+    purgeable_count: u32,
+    speculative_count: u32,
+    /// Decompression, compression and swap in/out counters.
+    _compressor_events: [u64; 4],
+    compressor_page_count: u32,
+    _throttled_count: u32,
+    _external_page_count: u32,
+    internal_page_count: u32,
+    _total_uncompressed_pages_in_compressor: u64,
+}
+
+unsafe extern "C" {
+    static vm_kernel_page_size: usize;
+    fn mach_host_self() -> u32;
+    fn host_statistics64(
+        host: u32,
+        flavor: i32,
+        host_info_out: *mut VmStatistics64,
+        host_info_out_count: *mut u32,
+    ) -> i32;
+    fn sysctlbyname(
+        name: *const c_char,
+        oldp: *mut c_void,
+        oldlenp: *mut usize,
+        newp: *mut c_void,
+        newlen: usize,
+    ) -> i32;
+}
+
+/// Memory pressure level.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryPressure {
+    /// Plenty of memory is readily available.
+    #[default]
+    Normal,
+    /// Available memory is running low.
+    Warning,
+    /// The system is close to running out of memory.
+    Critical,
+}
+
+/// Memory metrics payload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryInfo {
+    /// Physical memory in use (app, wired and compressed memory), in bytes.
+    used_bytes: u64,
+    /// Total physical memory, in bytes.
+    total_bytes: u64,
+    /// Memory pressure level.
+    pressure: MemoryPressure,
+}
+
+/// Mach host port, fetched once to avoid leaking a port right per call.
+static HOST_PORT: LazyLock<u32> = LazyLock::new(|| unsafe { mach_host_self() });
+
+/// Total physical memory in bytes, which doesn't change while running.
+static TOTAL_BYTES: LazyLock<u64> = LazyLock::new(read_total_bytes);
+
+/// Fetch the current memory usage and pressure on demand.
+#[tauri::command]
+pub fn get_memory_info() -> MemoryInfo {
+    let Some(stats) = read_vm_statistics() else {
+        return MemoryInfo::default();
+    };
+
+    let page_size = unsafe { vm_kernel_page_size } as u64;
+    memory_info(&stats, page_size, *TOTAL_BYTES)
+}
+
+/// Compute memory usage and pressure from VM page counters.
+///
+/// Used memory matches Activity Monitor: app memory (anonymous pages that
+/// can't be purged), wired memory and pages held by the compressor. Pressure
+/// is derived from the share of memory that is readily available (free,
+/// inactive, speculative and purgeable pages).
+#[allow(clippy::cast_precision_loss)]
+fn memory_info(stats: &VmStatistics64, page_size: u64, total_bytes: u64) -> MemoryInfo {
+    let pages = |count: u32| u64::from(count) * page_size;
+
+    let app = pages(stats.internal_page_count).saturating_sub(pages(stats.purgeable_count));
+    let used_bytes =
+        (app + pages(stats.wire_count) + pages(stats.compressor_page_count)).min(total_bytes);
+
+    let available = pages(stats.free_count)
+        + pages(stats.inactive_count)
+        + pages(stats.speculative_count)
+        + pages(stats.purgeable_count);
+
+    let pressure = if total_bytes == 0 {
+        MemoryPressure::Normal
+    } else {
+        let available_ratio = available as f64 / total_bytes as f64;
+        if available_ratio < CRITICAL_AVAILABLE_RATIO {
+            MemoryPressure::Critical
+        } else if available_ratio < WARNING_AVAILABLE_RATIO {
+            MemoryPressure::Warning
+        } else {
+            MemoryPressure::Normal
+        }
+    };
+
+    MemoryInfo {
+        used_bytes,
+        total_bytes,
+        pressure,
+    }
+}
+
+/// Read the VM page counters with `host_statistics64`.
+fn read_vm_statistics() -> Option<VmStatistics64> {
+    let mut stats = VmStatistics64::default();
+    let mut count = HOST_VM_INFO64_COUNT;
+
+    let result =
+        unsafe { host_statistics64(*HOST_PORT, HOST_VM_INFO64, &raw mut stats, &raw mut count) };
+
+    (result == KERN_SUCCESS).then_some(stats)
+}
+
+/// Read the total physical memory with the `hw.memsize` sysctl.
+fn read_total_bytes() -> u64 {
+    let mut total: u64 = 0;
+    let mut size = std::mem::size_of::<u64>();
+
+    let result = unsafe {
+        sysctlbyname(
+            c"hw.memsize".as_ptr(),
+            (&raw mut total).cast(),
+            &raw mut size,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+
+    if result == 0 { total } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE_SIZE: u64 = 16_384;
+    /// 16 GiB in 16 KiB pages.
+    const TOTAL_PAGES: u32 = 1_048_576;
+
+    fn stats(available_pages: u32) -> VmStatistics64 {
+        VmStatistics64 {
+            free_count: available_pages / 2,
+            inactive_count: available_pages / 2,
+            wire_count: 100_000,
+            internal_page_count: 400_000,
+            purgeable_count: 10_000,
+            compressor_page_count: 50_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_vm_statistics_layout() {
+        assert_eq!(std::mem::size_of::<VmStatistics64>(), 152);
+        assert_eq!(HOST_VM_INFO64_COUNT, 38);
+    }
+
+    #[test]
+    fn test_memory_info_used_bytes() {
+        let total = u64::from(TOTAL_PAGES) * PAGE_SIZE;
+        let info = memory_info(&stats(400_000), PAGE_SIZE, total);
+
+        // (400k app - 10k purgeable + 100k wired + 50k compressed) pages
+        assert_eq!(info.used_bytes, 540_000 * PAGE_SIZE);
+        assert_eq!(info.total_bytes, total);
+    }
+
+    #[test]
+    fn test_memory_info_pressure_levels() {
+        let total = u64::from(TOTAL_PAGES) * PAGE_SIZE;
+
+        // Purgeable pages count as available too
+        let pressure = |available: u32| memory_info(&stats(available), PAGE_SIZE, total).pressure;
+        assert_eq!(pressure(400_000), MemoryPressure::Normal);
+        assert_eq!(pressure(150_000), MemoryPressure::Warning);
+        assert_eq!(pressure(50_000), MemoryPressure::Critical);
+    }
+
+    #[test]
+    fn test_memory_info_unknown_total() {
+        let info = memory_info(&stats(400_000), PAGE_SIZE, 0);
+        assert_eq!(info.used_bytes, 0);
+        assert_eq!(info.pressure, MemoryPressure::Normal);
+    }
+
+    #[test]
+    fn test_memory_info_serialization() {
+        let info = MemoryInfo {
+            used_bytes: 8_589_934_592,
+            total_bytes: 17_179_869_184,
+            pressure: MemoryPressure::Warning,
+        };
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "usedBytes": 8_589_934_592_u64,
+                "totalBytes": 17_179_869_184_u64,
+                "pressure": "warning"
+            })
+        );
+    }
+}
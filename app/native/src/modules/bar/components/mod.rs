@@ -2,9 +2,13 @@ use tauri::WebviewWindow;
 
 pub mod apps;
 pub mod battery;
+pub mod command;
 pub mod cpu;
+pub mod disk;
 pub mod keepawake;
 pub mod media;
+pub mod memory;
+pub mod network;
 pub mod tiling;
 pub mod weather;
 
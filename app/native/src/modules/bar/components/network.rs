@@ -0,0 +1,288 @@
+//! Network throughput component.
+//!
+//! Samples the cumulative byte counters of every network interface with
+//! `getifaddrs` and reports the throughput of the busiest one since the
+//! previous call.
+
+use std::ffi::{CStr, c_char, c_void};
+use std::sync::{Mutex, PoisonError};
+use std::time::Instant;
+
+use serde::Serialize;
+
+// ============================================================================
+// FFI Declarations
+// ============================================================================
+
+/// Link-level address family, whose entries carry the interface statistics.
+const AF_LINK: u8 = 18;
+/// Interface is administratively up.
+const IFF_UP: u32 = 0x1;
+/// Interface is the loopback.
+const IFF_LOOPBACK: u32 = 0x8;
+
+/// Interface address entry (`struct ifaddrs`).
+#[repr(C)]
+struct IfAddrs {
+    ifa_next: *mut Self,
+    ifa_name: *const c_char,
+    ifa_flags: u32,
+    ifa_addr: *const SockAddr,
+    _ifa_netmask: *const SockAddr,
+    _ifa_dstaddr: *const SockAddr,
+    ifa_data: *const c_void,
+}
+
+/// Leading fields of a socket address (`struct sockaddr`).
+#[repr(C)]
+struct SockAddr {
+    _sa_len: u8,
+    sa_family: u8,
+}
+
+/// Leading fields of the interface statistics attached to `AF_LINK` entries
+/// (`struct if_data`), up to the byte counters.
+#[repr(C)]
+struct IfData {
+    /// Type, lengths and quotas (eight `u_char` fields).
+    _header: [u8; 8],
+    /// MTU, metric, baudrate, packet, error and collision counters.
+    _counters: [u32; 8],
+    ifi_ibytes: u32,
+    ifi_obytes: u32,
+}
+
+unsafe extern "C" {
+    fn getifaddrs(ifap: *mut *mut IfAddrs) -> i32;
+    fn freeifaddrs(ifp: *mut IfAddrs);
+}
+
+/// Network throughput payload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkInfo {
+    /// Name of the active interface (e.g. `en0`), None if no interface is up.
+    interface: Option<String>,
+    /// Bytes received per second, zero on the first call.
+    rx_bytes_per_sec: u64,
+    /// Bytes sent per second, zero on the first call.
+    tx_bytes_per_sec: u64,
+}
+
+/// Cumulative byte counters of a single interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InterfaceCounters {
+    name: String,
+    rx_bytes: u32,
+    tx_bytes: u32,
+}
+
+/// Byte counters of every interface at a point in time.
+#[derive(Debug, Clone)]
+struct Sample {
+    taken_at: Instant,
+    interfaces: Vec<InterfaceCounters>,
+}
+
+/// Sample from the previous call, used to compute throughput deltas.
+static PREV_SAMPLE: Mutex<Option<Sample>> = Mutex::new(None);
+
+/// Fetch the current network throughput on demand.
+#[tauri::command]
+pub fn get_network_info() -> NetworkInfo {
+    let Some(interfaces) = read_interface_counters() else {
+        return NetworkInfo::default();
+    };
+
+    let current = Sample {
+        taken_at: Instant::now(),
+        interfaces,
+    };
+
+    let mut prev = PREV_SAMPLE.lock().unwrap_or_else(PoisonError::into_inner);
+    let info = network_rates(prev.as_ref(), &current);
+    *prev = Some(current);
+
+    info
+}
+
+/// Compute the throughput of the active interface between two samples.
+///
+/// The active interface is the one that moved the most bytes since the
+/// previous sample, or the one with the most traffic overall when there is
+/// nothing to compare against. Rates are zero on the first sample.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn network_rates(prev: Option<&Sample>, current: &Sample) -> NetworkInfo {
+    let elapsed = prev.map_or(0.0, |prev| {
+        current.taken_at.saturating_duration_since(prev.taken_at).as_secs_f64()
+    });
+
+    let deltas = current.interfaces.iter().map(|iface| {
+        let prev = prev
+            .filter(|_| elapsed > 0.0)
+            .and_then(|prev| prev.interfaces.iter().find(|p| p.name == iface.name));
+
+        // Counters are 32-bit and may wrap around
+        let (rx, tx) = prev.map_or((0, 0), |prev| {
+            (
+                u64::from(iface.rx_bytes.wrapping_sub(prev.rx_bytes)),
+                u64::from(iface.tx_bytes.wrapping_sub(prev.tx_bytes)),
+            )
+        });
+
+        (iface, rx, tx)
+    });
+
+    let Some((iface, rx, tx)) = deltas.max_by_key(|(iface, rx, tx)| {
+        (rx + tx, u64::from(iface.rx_bytes) + u64::from(iface.tx_bytes))
+    }) else {
+        return NetworkInfo::default();
+    };
+
+    let per_sec = |bytes: u64| {
+        if elapsed > 0.0 {
+            (bytes as f64 / elapsed).round() as u64
+        } else {
+            0
+        }
+    };
+
+    NetworkInfo {
+        interface: Some(iface.name.clone()),
+        rx_bytes_per_sec: per_sec(rx),
+        tx_bytes_per_sec: per_sec(tx),
+    }
+}
+
+/// Read the byte counters of every interface that is up, skipping the loopback.
+fn read_interface_counters() -> Option<Vec<InterfaceCounters>> {
+    let mut addrs: *mut IfAddrs = std::ptr::null_mut();
+    if unsafe { getifaddrs(&raw mut addrs) } != 0 {
+        return None;
+    }
+
+    let mut interfaces = Vec::new();
+    let mut cursor = addrs;
+
+    while let Some(entry) = unsafe { cursor.as_ref() } {
+        cursor = entry.ifa_next;
+
+        if entry.ifa_flags & IFF_UP == 0 || entry.ifa_flags & IFF_LOOPBACK != 0 {
+            continue;
+        }
+
+        // Only link-level entries carry statistics, and there is one per interface
+        let is_link = unsafe { entry.ifa_addr.as_ref() }.is_some_and(|a| a.sa_family == AF_LINK);
+        if !is_link || entry.ifa_name.is_null() {
+            continue;
+        }
+        let Some(data) = (unsafe { entry.ifa_data.cast::<IfData>().as_ref() }) else {
+            continue;
+        };
+
+        let name = unsafe { CStr::from_ptr(entry.ifa_name) }.to_string_lossy().into_owned();
+        interfaces.push(InterfaceCounters {
+            name,
+            rx_bytes: data.ifi_ibytes,
+            tx_bytes: data.ifi_obytes,
+        });
+    }
+
+    unsafe { freeifaddrs(addrs) };
+
+    Some(interfaces)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn counters(name: &str, rx_bytes: u32, tx_bytes: u32) -> InterfaceCounters {
+        InterfaceCounters {
+            name: name.to_string(),
+            rx_bytes,
+            tx_bytes,
+        }
+    }
+
+    #[test]
+    fn test_network_rates_first_sample_reports_zero() {
+        let current = Sample {
+            taken_at: Instant::now(),
+            interfaces: vec![counters("en0", 5_000, 1_000), counters("en1", 10, 10)],
+        };
+
+        let info = network_rates(None, &current);
+        assert_eq!(info.interface.as_deref(), Some("en0"));
+        assert_eq!(info.rx_bytes_per_sec, 0);
+        assert_eq!(info.tx_bytes_per_sec, 0);
+    }
+
+    #[test]
+    fn test_network_rates_uses_busiest_interface() {
+        let start = Instant::now();
+        let prev = Sample {
+            taken_at: start,
+            interfaces: vec![counters("en0", 50_000, 50_000), counters("utun0", 0, 0)],
+        };
+        let current = Sample {
+            taken_at: start + Duration::from_secs(2),
+            interfaces: vec![
+                counters("en0", 50_100, 50_100),
+                counters("utun0", 4_000, 2_000),
+            ],
+        };
+
+        let info = network_rates(Some(&prev), &current);
+        assert_eq!(info.interface.as_deref(), Some("utun0"));
+        assert_eq!(info.rx_bytes_per_sec, 2_000);
+        assert_eq!(info.tx_bytes_per_sec, 1_000);
+    }
+
+    #[test]
+    fn test_network_rates_handles_counter_wraparound() {
+        let start = Instant::now();
+        let prev = Sample {
+            taken_at: start,
+            interfaces: vec![counters("en0", u32::MAX - 99, 0)],
+        };
+        let current = Sample {
+            taken_at: start + Duration::from_secs(1),
+            interfaces: vec![counters("en0", 100, 0)],
+        };
+
+        let info = network_rates(Some(&prev), &current);
+        assert_eq!(info.rx_bytes_per_sec, 200);
+    }
+
+    #[test]
+    fn test_network_rates_no_interfaces() {
+        let current = Sample {
+            taken_at: Instant::now(),
+            interfaces: Vec::new(),
+        };
+
+        assert_eq!(network_rates(None, &current), NetworkInfo::default());
+    }
+
+    #[test]
+    fn test_network_info_serialization() {
+        let info = NetworkInfo {
+            interface: Some("en0".to_string()),
+            rx_bytes_per_sec: 1_024,
+            tx_bytes_per_sec: 512,
+        };
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "interface": "en0", "rxBytesPerSec": 1024, "txBytesPerSec": 512 })
+        );
+    }
+}
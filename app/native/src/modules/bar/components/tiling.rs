@@ -63,6 +63,7 @@ pub struct WindowInfo {
 /// Gets all workspaces from the tiling manager.
 ///
 /// Returns workspaces for all screens, or for a specific screen if specified.
+/// The scratchpad is left out, as it is never shown as a workspace of its own.
 ///
 /// # Errors
 ///
@@ -100,6 +101,7 @@ pub async fn get_tiling_workspaces(
     // Convert to WorkspaceInfo format
     let infos: Vec<WorkspaceInfo> = workspaces
         .into_iter()
+        .filter(|ws| !ws.is_scratchpad)
         .map(|ws| {
             let screen_name = screens
                 .iter()
@@ -102,38 +102,40 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
                     return;
                 }
 
-                // Parse the layout string into LayoutType
-                let layout_type: Result<tiling::state::LayoutType, _> =
-                    serde_json::from_value(serde_json::json!(layout));
-
-                match layout_type {
-                    Ok(layout_type) => {
-                        let Some(handle) = tiling::init::get_handle() else {
-                            return;
-                        };
-                        // Get focused workspace ID
-                        let rt = tokio::runtime::Builder::new_current_thread()
-                            .enable_all()
-                            .build()
-                            .unwrap();
-                        let Ok(result) = rt.block_on(handle.get_focused_workspace()) else {
-                            return;
-                        };
-                        let Some(Some(ws)) = result.into_workspace() else {
+                // "cycle" advances through `tiling.layoutCycle`, anything else is a LayoutType
+                let layout_type: Option<tiling::state::LayoutType> = if layout == "cycle" {
+                    None
+                } else {
+                    match serde_json::from_value(serde_json::json!(layout)) {
+                        Ok(layout_type) => Some(layout_type),
+                        Err(e) => {
+                            tracing::warn!("tiling: invalid layout '{layout}': {e}");
                             return;
-                        };
-                        if let Err(e) = handle.set_layout(ws.id, layout_type) {
-                            tracing::warn!("tiling: failed to set layout: {e}");
-                        } else {
-                            tracing::debug!(
-                                "tiling: set layout to {layout_type:?} for workspace '{}'",
-                                ws.name
-                            );
                         }
                     }
-                    Err(e) => {
-                        tracing::warn!("tiling: invalid layout '{layout}': {e}");
-                    }
+                };
+
+                let Some(handle) = tiling::init::get_handle() else {
+                    return;
+                };
+                // Get focused workspace ID
+                let rt =
+                    tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+                let Ok(result) = rt.block_on(handle.get_focused_workspace()) else {
+                    return;
+                };
+                let Some(Some(ws)) = result.into_workspace() else {
+                    return;
+                };
+
+                let result = match layout_type {
+                    Some(layout_type) => handle.set_layout(ws.id, layout_type),
+                    None => handle.cycle_layout(ws.id),
+                };
+                if let Err(e) = result {
+                    tracing::warn!("tiling: failed to set layout: {e}");
+                } else {
+                    tracing::debug!("tiling: set layout to {layout} for workspace '{}'", ws.name);
                 }
             });
         }
@@ -167,6 +169,23 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             });
         }
 
+        StacheNotification::TilingWindowFocusLast => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.focus_last_window() {
+                        tracing::warn!("tiling: failed to focus last window: {e}");
+                    } else {
+                        tracing::debug!("tiling: focused last window");
+                    }
+                }
+            });
+        }
+
         StacheNotification::TilingWindowSwap(direction) => {
             std::thread::spawn(move || {
                 if !tiling::init::is_initialized() {
@@ -189,6 +208,28 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             });
         }
 
+        StacheNotification::TilingWindowMove { direction, step } => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    // Parse direction
+                    if let Some(dir) = tiling::actor::FocusDirection::parse(&direction) {
+                        if let Err(e) = handle.move_window_in_direction(dir, step) {
+                            tracing::warn!("tiling: failed to move window: {e}");
+                        } else {
+                            tracing::debug!("tiling: moved window {direction}");
+                        }
+                    } else {
+                        tracing::warn!("tiling: invalid move direction: {direction}");
+                    }
+                }
+            });
+        }
+
         StacheNotification::TilingWindowResize { dimension, amount } => {
             std::thread::spawn(move || {
                 if !tiling::init::is_initialized() {
@@ -206,6 +247,108 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             });
         }
 
+        StacheNotification::TilingWindowSticky(action) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.set_sticky(&action) {
+                        tracing::warn!("tiling: failed to set sticky: {e}");
+                    } else {
+                        tracing::debug!("tiling: set sticky '{action}'");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingWindowAlwaysOnTop(action) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.set_always_on_top(&action) {
+                        tracing::warn!("tiling: failed to set always-on-top: {e}");
+                    } else {
+                        tracing::debug!("tiling: set always-on-top '{action}'");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingWindowFloat(action) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.set_floating(&action) {
+                        tracing::warn!("tiling: failed to set floating: {e}");
+                    } else {
+                        tracing::debug!("tiling: set floating '{action}'");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingWindowPromote => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.promote_window() {
+                        tracing::warn!("tiling: failed to promote window: {e}");
+                    } else {
+                        tracing::debug!("tiling: promoted window");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingWindowToScratchpad => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.move_window_to_scratchpad() {
+                        tracing::warn!("tiling: failed to move window to scratchpad: {e}");
+                    } else {
+                        tracing::debug!("tiling: moved window to scratchpad");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingScratchpadToggle => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.toggle_scratchpad() {
+                        tracing::warn!("tiling: failed to toggle scratchpad: {e}");
+                    } else {
+                        tracing::debug!("tiling: toggled scratchpad");
+                    }
+                }
+            });
+        }
+
         StacheNotification::TilingWindowPreset(preset) => {
             std::thread::spawn(move || {
                 if !tiling::init::is_initialized() {
@@ -337,6 +480,43 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
                 }
             });
         }
+
+        StacheNotification::TilingWorkspaceMasters(masters) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.adjust_master_count(&masters) {
+                        tracing::warn!("tiling: failed to change master count: {e}");
+                    } else {
+                        tracing::debug!("tiling: changed master count by {masters}");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingWorkspaceScroll(direction) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.scroll_workspace(&direction) {
+                        tracing::warn!("tiling: failed to scroll workspace: {e}");
+                    } else {
+                        tracing::debug!("tiling: scrolled workspace {direction}");
+                    }
+                }
+            });
+        }
+
+        // Posted by the app itself for external listeners such as tests
+        StacheNotification::TilingLayoutApplied { .. } => {}
     }
 }
 
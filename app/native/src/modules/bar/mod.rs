@@ -2,11 +2,12 @@ pub mod components;
 mod ipc_listener;
 mod menubar;
 mod screen;
+pub mod visibility;
 pub mod window;
 
 use tauri::{App, Manager};
 
-use crate::utils::window::{set_window_below_menu, set_window_sticky};
+use crate::utils::window::set_window_sticky;
 
 pub fn init(app: &App) {
     let config = crate::config::get_config();
@@ -23,17 +24,37 @@ pub fn init(app: &App) {
     };
 
     set_window_sticky(&webview_window);
-    set_window_below_menu(&webview_window);
+    window::apply_window_level(&webview_window);
     window::set_window_position(&webview_window);
 
     let webview_watcher_clone = webview_window.clone();
-    screen::init_screen_watcher(move || window::set_window_position(&webview_watcher_clone));
+    screen::init_screen_watcher(move || {
+        window::set_window_position(&webview_watcher_clone);
+
+        // Keep the bar hidden if it was hidden on request
+        if visibility::is_hidden()
+            && let Err(e) = webview_watcher_clone.hide()
+        {
+            tracing::warn!(error = %e, "failed to keep bar window hidden");
+        }
+
+        // Screen changes can reset the level, so apply it again on the main thread
+        let bar_window = webview_watcher_clone.clone();
+        if let Err(e) = webview_watcher_clone
+            .run_on_main_thread(move || window::apply_window_level(&bar_window))
+        {
+            tracing::warn!(error = %e, "failed to reapply bar window level");
+        }
+    });
 
     menubar::start_menu_bar_visibility_watcher(&webview_window);
 
     // Initialize components
     components::init(&webview_window);
 
+    // Allow showing and hiding the bar from the CLI
+    visibility::init(app_handle.clone());
+
     // Initialize IPC listener for CLI notifications
     ipc_listener::init(app_handle);
 
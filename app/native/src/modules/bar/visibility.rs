@@ -0,0 +1,159 @@
+//! Status bar visibility.
+//!
+//! `stache bar show|hide|toggle` shows or hides the bar window at runtime.
+//! The requested state is remembered, so repositioning the bar after a screen
+//! change doesn't bring back a bar the user hid.
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager, WebviewWindow};
+
+use crate::error::StacheError;
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
+
+/// Label of the bar webview window.
+const BAR_WINDOW_LABEL: &str = "bar";
+
+/// Whether the bar was hidden on request and must stay hidden.
+static HIDDEN: AtomicBool = AtomicBool::new(false);
+
+/// App handle used by IPC queries, set when the bar is enabled.
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+/// Bar visibility reported over IPC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BarVisibility {
+    /// Whether the bar window is visible.
+    pub visible: bool,
+}
+
+/// A bar visibility request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BarAction {
+    Show,
+    Hide,
+    Toggle,
+}
+
+impl BarAction {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "show" => Some(Self::Show),
+            "hide" => Some(Self::Hide),
+            "toggle" => Some(Self::Toggle),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the bar should be visible after this action.
+    const fn target(self, visible: bool) -> bool {
+        match self {
+            Self::Show => true,
+            Self::Hide => false,
+            Self::Toggle => !visible,
+        }
+    }
+}
+
+fn bar_window(app_handle: &AppHandle) -> Result<WebviewWindow, StacheError> {
+    app_handle
+        .get_webview_window(BAR_WINDOW_LABEL)
+        .ok_or_else(|| StacheError::CommandError("Failed to get bar window".to_string()))
+}
+
+/// Returns whether the bar was hidden on request.
+#[must_use]
+pub fn is_hidden() -> bool { HIDDEN.load(Ordering::Acquire) }
+
+/// Shows or hides the bar window and returns whether it was visible.
+///
+/// # Errors
+///
+/// Returns an error if the bar window doesn't exist or can't be shown or hidden.
+pub fn set_bar_visible(app_handle: &AppHandle, visible: bool) -> Result<bool, StacheError> {
+    let window = bar_window(app_handle)?;
+    let was_visible = window.is_visible().unwrap_or(true);
+
+    let result = if visible {
+        window.show()
+    } else {
+        window.hide()
+    };
+    result.map_err(|e| StacheError::CommandError(format!("Failed to update the bar: {e}")))?;
+
+    HIDDEN.store(!visible, Ordering::Release);
+    Ok(was_visible)
+}
+
+fn apply_action(app_handle: &AppHandle, action: BarAction) -> Result<BarVisibility, StacheError> {
+    let visible = bar_window(app_handle)?.is_visible().unwrap_or(true);
+    let visible = action.target(visible);
+    set_bar_visible(app_handle, visible)?;
+    Ok(BarVisibility { visible })
+}
+
+/// Handles bar visibility IPC queries from the CLI.
+///
+/// Returns `None` for queries handled elsewhere.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    let IpcQuery::Bar { action } = query else {
+        return None;
+    };
+
+    let Some(action) = BarAction::parse(action) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid bar action '{action}'. Must be 'show', 'hide' or 'toggle'."
+        )));
+    };
+
+    let Some(app_handle) = APP_HANDLE.get() else {
+        return Some(IpcResponse::error("The status bar is not enabled"));
+    };
+
+    Some(match apply_action(app_handle, action) {
+        Ok(visibility) => IpcResponse::success(visibility),
+        Err(err) => IpcResponse::error(err.to_string()),
+    })
+}
+
+/// Initializes bar visibility control with the app handle it needs.
+pub fn init(app_handle: AppHandle) { let _ = APP_HANDLE.set(app_handle); }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_action_parse() {
+        assert_eq!(BarAction::parse("show"), Some(BarAction::Show));
+        assert_eq!(BarAction::parse("hide"), Some(BarAction::Hide));
+        assert_eq!(BarAction::parse("toggle"), Some(BarAction::Toggle));
+        assert_eq!(BarAction::parse("status"), None);
+    }
+
+    #[test]
+    fn test_bar_action_target() {
+        assert!(BarAction::Show.target(false));
+        assert!(!BarAction::Hide.target(true));
+        assert!(BarAction::Toggle.target(false));
+        assert!(!BarAction::Toggle.target(true));
+    }
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+
+    #[test]
+    fn test_handle_ipc_query_rejects_invalid_action() {
+        let query = IpcQuery::Bar { action: "flip".to_string() };
+        assert!(matches!(
+            handle_ipc_query(&query),
+            Some(IpcResponse::Error { error }) if error.contains("'flip'")
+        ));
+    }
+}
@@ -1,9 +1,12 @@
 use serde::Serialize;
 use tauri::Manager;
 
-use crate::config::get_config;
+use crate::config::{BarLevel, get_config};
 use crate::error::StacheError;
-use crate::utils::window::{get_screen_size, set_position};
+use crate::utils::window::{
+    get_screen_size, set_position, set_window_below_menu, set_window_fullscreen_spaces,
+    set_window_level,
+};
 
 #[derive(Debug, Clone, Copy, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -29,6 +32,33 @@ pub fn set_window_position(webview_window: &tauri::WebviewWindow) {
     set_position(webview_window, x, y, width, height);
 }
 
+/// Returns the Core Graphics window level key for a bar level, or `None` for
+/// the default level below the menu bar.
+const fn window_level_key(level: BarLevel) -> Option<i32> {
+    match level {
+        BarLevel::BelowMenu => None,
+        BarLevel::Normal => Some(4),
+        BarLevel::Floating => Some(5),
+        BarLevel::Status => Some(9),
+        BarLevel::Screensaver => Some(13),
+    }
+}
+
+/// Applies the configured window level and fullscreen space behavior.
+///
+/// Must run on the main thread.
+pub fn apply_window_level(webview_window: &tauri::WebviewWindow) {
+    let config = &get_config().bar;
+    match window_level_key(config.level) {
+        None => set_window_below_menu(webview_window),
+        Some(key) => {
+            let _ = webview_window.set_always_on_bottom(false);
+            set_window_level(webview_window, key);
+        }
+    }
+    set_window_fullscreen_spaces(webview_window, config.join_all_spaces);
+}
+
 const fn calculate_window_frame(
     logical_width: f64,
     bar_height: f64,
@@ -71,6 +101,15 @@ mod tests {
     const TEST_BAR_HEIGHT: f64 = 28.0;
     const TEST_PADDING: f64 = 12.0;
 
+    #[test]
+    fn window_level_key_maps_bar_levels() {
+        assert_eq!(window_level_key(BarLevel::BelowMenu), None);
+        assert_eq!(window_level_key(BarLevel::Normal), Some(4));
+        assert_eq!(window_level_key(BarLevel::Floating), Some(5));
+        assert_eq!(window_level_key(BarLevel::Status), Some(9));
+        assert_eq!(window_level_key(BarLevel::Screensaver), Some(13));
+    }
+
     #[test]
     fn calculate_window_frame_returns_correct_dimensions() {
         let logical_width = 1920.0;
@@ -0,0 +1,393 @@
+//! Screen capture for the focused window or workspace.
+//!
+//! Captures the focused tiled window (`CGWindowListCreateImage`) or the whole
+//! screen of the focused workspace (`CGDisplayCreateImage`) and writes it as a
+//! PNG file to `capture.outputDir` with a timestamped file name. Both need the
+//! Screen Recording permission; without it the capture fails with a clear
+//! error instead of writing a blank image.
+
+use std::path::{Path, PathBuf};
+
+use core_graphics::display::CGDisplay;
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+use core_graphics::image::CGImage;
+
+use crate::config::get_config;
+use crate::modules::tiling;
+use crate::modules::tiling::actor::QueryResult;
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
+use crate::utils::time_of_day::local_time_secs;
+
+/// Only capture the window passed to `CGWindowListCreateImage`.
+#[allow(non_upper_case_globals)]
+const kCGWindowListOptionIncludingWindow: u32 = 1 << 3;
+
+/// Leave the window shadow out of the captured bounds.
+#[allow(non_upper_case_globals)]
+const kCGWindowImageBoundsIgnoreFraming: u32 = 1 << 0;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGPreflightScreenCaptureAccess() -> bool;
+    fn CGRequestScreenCaptureAccess() -> bool;
+}
+
+/// What to capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureTarget {
+    /// The focused window's frame.
+    Window,
+    /// The full screen of the focused workspace.
+    Workspace,
+}
+
+impl CaptureTarget {
+    /// Parses a target name ("window" or "workspace").
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "window" => Some(Self::Window),
+            "workspace" => Some(Self::Workspace),
+            _ => None,
+        }
+    }
+
+    /// Returns the target name as used over IPC and in file names.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Window => "window",
+            Self::Workspace => "workspace",
+        }
+    }
+}
+
+/// Errors that can occur while capturing.
+#[derive(Debug, thiserror::Error)]
+pub enum CaptureError {
+    /// Screen Recording permission has not been granted.
+    #[error(
+        "Screen Recording permission not granted. Allow Stache in System Settings > Privacy & \
+         Security > Screen Recording, then restart it."
+    )]
+    PermissionDenied,
+    /// The tiling manager isn't running, so there is no focused window or workspace.
+    #[error("Tiling is not enabled")]
+    TilingUnavailable,
+    /// There is no focused window to capture.
+    #[error("No focused window")]
+    NoFocusedWindow,
+    /// There is no focused workspace (or its screen is gone).
+    #[error("No focused workspace")]
+    NoFocusedWorkspace,
+    /// Core Graphics returned no image.
+    #[error("Failed to capture {0}")]
+    ImageUnavailable(&'static str),
+    /// Writing the PNG file failed.
+    #[error("Failed to write {path}: {reason}")]
+    WriteFailed { path: String, reason: String },
+}
+
+/// Captures the target and writes it to the configured output directory.
+///
+/// Returns the path of the written PNG file.
+///
+/// # Errors
+///
+/// Returns an error if Screen Recording permission is missing, nothing is
+/// focused, or the image can't be captured or written.
+pub fn capture(target: CaptureTarget) -> Result<PathBuf, CaptureError> {
+    ensure_permission()?;
+
+    let image = match target {
+        CaptureTarget::Window => capture_focused_window()?,
+        CaptureTarget::Workspace => capture_focused_screen()?,
+    };
+
+    let dir = get_config().capture.output_dir();
+    std::fs::create_dir_all(&dir).map_err(|err| CaptureError::WriteFailed {
+        path: dir.display().to_string(),
+        reason: err.to_string(),
+    })?;
+
+    let path = unique_path(&dir, &file_stem(target, local_time_secs()));
+    write_png(&image, &path)?;
+
+    tracing::info!(path = %path.display(), "captured {}", target.as_str());
+    Ok(path)
+}
+
+/// Checks Screen Recording permission, asking for it the first time.
+fn ensure_permission() -> Result<(), CaptureError> {
+    // SAFETY: Both functions take no arguments and only read/request TCC state
+    unsafe {
+        if CGPreflightScreenCaptureAccess() {
+            return Ok(());
+        }
+
+        // Shows the system prompt once; the grant only applies after a restart
+        CGRequestScreenCaptureAccess();
+    }
+
+    Err(CaptureError::PermissionDenied)
+}
+
+/// Captures the focused window within its tiled frame.
+fn capture_focused_window() -> Result<CGImage, CaptureError> {
+    let window = query_tiling(|handle| async move {
+        handle
+            .get_focused_window()
+            .await
+            .ok()
+            .and_then(QueryResult::into_window)
+            .flatten()
+    })?
+    .ok_or(CaptureError::NoFocusedWindow)?;
+
+    let frame = window.frame;
+    let bounds = CGRect::new(
+        &CGPoint::new(frame.x, frame.y),
+        &CGSize::new(frame.width, frame.height),
+    );
+
+    CGDisplay::screenshot(
+        bounds,
+        kCGWindowListOptionIncludingWindow,
+        window.id,
+        kCGWindowImageBoundsIgnoreFraming,
+    )
+    .ok_or(CaptureError::ImageUnavailable("window"))
+}
+
+/// Captures the full screen of the focused workspace.
+fn capture_focused_screen() -> Result<CGImage, CaptureError> {
+    let screen = query_tiling(|handle| async move {
+        let workspace = handle
+            .get_focused_workspace()
+            .await
+            .ok()
+            .and_then(QueryResult::into_workspace)??;
+        handle
+            .get_screen(workspace.screen_id)
+            .await
+            .ok()
+            .and_then(QueryResult::into_screen)?
+    })?
+    .ok_or(CaptureError::NoFocusedWorkspace)?;
+
+    CGDisplay::new(screen.id)
+        .image()
+        .ok_or(CaptureError::ImageUnavailable("screen"))
+}
+
+/// Runs a query against the tiling actor and waits for the result.
+fn query_tiling<T, F, Fut>(query: F) -> Result<T, CaptureError>
+where
+    F: FnOnce(&'static tiling::StateActorHandle) -> Fut,
+    Fut: Future<Output = T>, {
+    if !tiling::init::is_initialized() {
+        return Err(CaptureError::TilingUnavailable);
+    }
+
+    let handle = tiling::init::get_handle().ok_or(CaptureError::TilingUnavailable)?;
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|_| CaptureError::TilingUnavailable)?;
+
+    Ok(rt.block_on(query(handle)))
+}
+
+/// Encodes a Core Graphics image as PNG.
+fn write_png(captured: &CGImage, path: &Path) -> Result<(), CaptureError> {
+    let width = captured.width();
+    let height = captured.height();
+    let data = captured.data();
+    let rgba = bgra_to_rgba(data.bytes(), width, height, captured.bytes_per_row());
+
+    let write_failed = |reason: String| CaptureError::WriteFailed {
+        path: path.display().to_string(),
+        reason,
+    };
+
+    let width = u32::try_from(width).map_err(|err| write_failed(err.to_string()))?;
+    let height = u32::try_from(height).map_err(|err| write_failed(err.to_string()))?;
+
+    image::save_buffer_with_format(
+        path,
+        &rgba,
+        width,
+        height,
+        image::ExtendedColorType::Rgba8,
+        image::ImageFormat::Png,
+    )
+    .map_err(|err| write_failed(err.to_string()))
+}
+
+/// Converts 32-bit BGRA rows (as returned by Core Graphics) to tightly packed RGBA.
+///
+/// Rows may be padded, so each row is read `bytes_per_row` apart.
+fn bgra_to_rgba(data: &[u8], width: usize, height: usize, bytes_per_row: usize) -> Vec<u8> {
+    let mut rgba = Vec::with_capacity(width * height * 4);
+
+    for row in data.chunks(bytes_per_row).take(height) {
+        for pixel in row[..width * 4].chunks_exact(4) {
+            rgba.extend_from_slice(&[pixel[2], pixel[1], pixel[0], pixel[3]]);
+        }
+    }
+
+    rgba
+}
+
+/// Builds a file name stem like `stache-window-2026-10-16-143005`.
+fn file_stem(target: CaptureTarget, local_secs: i64) -> String {
+    let days = local_secs.div_euclid(86_400);
+    let secs = local_secs.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "stache-{}-{year:04}-{month:02}-{day:02}-{:02}{:02}{:02}",
+        target.as_str(),
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Converts days since the Unix epoch to a (year, month, day) date.
+///
+/// Based on Howard Hinnant's `civil_from_days` algorithm.
+#[allow(clippy::similar_names)] // Names follow the original algorithm
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400;
+
+    if month <= 2 {
+        (year + 1, month, day)
+    } else {
+        (year, month, day)
+    }
+}
+
+/// Returns `<dir>/<stem>.png`, adding a counter if the file already exists.
+fn unique_path(dir: &Path, stem: &str) -> PathBuf {
+    let path = dir.join(format!("{stem}.png"));
+    if !path.exists() {
+        return path;
+    }
+
+    (2..)
+        .map(|n| dir.join(format!("{stem}-{n}.png")))
+        .find(|path| !path.exists())
+        .unwrap_or(path)
+}
+
+/// Handles capture IPC queries from the CLI.
+///
+/// Returns `None` for queries handled elsewhere.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    let IpcQuery::Capture { target } = query else {
+        return None;
+    };
+
+    let Some(target) = CaptureTarget::parse(target) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid capture target '{target}'. Must be 'window' or 'workspace'."
+        )));
+    };
+
+    Some(match capture(target) {
+        Ok(path) => IpcResponse::success(serde_json::json!({
+            "target": target.as_str(),
+            "path": path.display().to_string(),
+        })),
+        Err(err) => IpcResponse::error(err.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_target_parse() {
+        assert_eq!(CaptureTarget::parse("window"), Some(CaptureTarget::Window));
+        assert_eq!(
+            CaptureTarget::parse(" Workspace "),
+            Some(CaptureTarget::Workspace)
+        );
+        assert_eq!(CaptureTarget::parse("screen"), None);
+    }
+
+    #[test]
+    fn test_file_stem() {
+        // 2026-10-16 14:30:05
+        let secs = 1_792_161_005;
+        assert_eq!(
+            file_stem(CaptureTarget::Window, secs),
+            "stache-window-2026-10-16-143005"
+        );
+        assert_eq!(
+            file_stem(CaptureTarget::Workspace, 0),
+            "stache-workspace-1970-01-01-000000"
+        );
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        // Leap day
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_bgra_to_rgba_skips_row_padding() {
+        // 2x2 image with 4 bytes of padding per row
+        let data = [
+            1, 2, 3, 255, 4, 5, 6, 255, 0, 0, 0, 0, //
+            7, 8, 9, 255, 10, 11, 12, 128, 0, 0, 0, 0,
+        ];
+
+        assert_eq!(bgra_to_rgba(&data, 2, 2, 12), vec![
+            3, 2, 1, 255, 6, 5, 4, 255, //
+            9, 8, 7, 255, 12, 11, 10, 128,
+        ]);
+    }
+
+    #[test]
+    fn test_unique_path_adds_counter() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let first = unique_path(dir.path(), "stache-window");
+        assert_eq!(first, dir.path().join("stache-window.png"));
+
+        std::fs::write(&first, b"").unwrap();
+        assert_eq!(
+            unique_path(dir.path(), "stache-window"),
+            dir.path().join("stache-window-2.png")
+        );
+    }
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+
+    #[test]
+    fn test_handle_ipc_query_rejects_invalid_target() {
+        let query = IpcQuery::Capture { target: "screen".to_string() };
+        assert!(matches!(
+            handle_ipc_query(&query),
+            Some(IpcResponse::Error { error }) if error.contains("Invalid capture target")
+        ));
+    }
+}
@@ -0,0 +1,319 @@
+//! Focus Mode Module for Stache.
+//!
+//! `stache focus-mode on` is a do-not-disturb style toggle built from existing
+//! features: it hides the bar, switches the focused workspace to the monocle
+//! layout and keeps the system awake. Each action can be turned off in the
+//! `focusMode` config. The state each action replaced is remembered, so
+//! `stache focus-mode off` puts things back the way they were.
+//!
+//! The current state is reported over IPC and emitted to the bar with
+//! [`events::focus_mode::STATE_CHANGED`].
+
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+use crate::config::get_config;
+use crate::events;
+use crate::modules::bar::components::keepawake::set_system_awake;
+use crate::modules::bar::visibility::set_bar_visible;
+use crate::modules::tiling;
+use crate::modules::tiling::actor::QueryResult;
+use crate::modules::tiling::state::LayoutType;
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
+
+/// Focus mode state reported over IPC and to the bar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusModeStatus {
+    /// Whether focus mode is on.
+    pub active: bool,
+    /// Whether focus mode hid the bar.
+    pub hide_bar: bool,
+    /// Whether focus mode switched the focused workspace to monocle.
+    pub monocle: bool,
+    /// Whether focus mode keeps the system awake.
+    pub keepawake: bool,
+}
+
+/// What focus mode replaced when it was turned on.
+///
+/// Each field is `None` when the matching action is disabled or failed.
+#[derive(Debug, Default)]
+struct PriorState {
+    /// Whether the bar was visible.
+    bar_visible: Option<bool>,
+    /// The workspace switched to monocle and its previous layout.
+    workspace_layout: Option<(Uuid, LayoutType)>,
+    /// Whether the system was already kept awake.
+    keepawake: Option<bool>,
+}
+
+impl PriorState {
+    const fn status(&self) -> FocusModeStatus {
+        FocusModeStatus {
+            active: true,
+            hide_bar: self.bar_visible.is_some(),
+            monocle: self.workspace_layout.is_some(),
+            keepawake: self.keepawake.is_some(),
+        }
+    }
+}
+
+/// A focus mode request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FocusModeAction {
+    On,
+    Off,
+    Status,
+}
+
+impl FocusModeAction {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "on" => Some(Self::On),
+            "off" => Some(Self::Off),
+            "status" => Some(Self::Status),
+            _ => None,
+        }
+    }
+}
+
+/// State replaced by focus mode, `Some` while focus mode is on.
+static PRIOR_STATE: Mutex<Option<PriorState>> = Mutex::new(None);
+
+/// App handle used by IPC queries.
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+/// Runs a query against the tiling actor and waits for the result.
+///
+/// Returns `None` when tiling isn't running.
+fn query_tiling<T, F, Fut>(query: F) -> Option<T>
+where
+    F: FnOnce(&'static tiling::StateActorHandle) -> Fut,
+    Fut: Future<Output = Option<T>>, {
+    if !tiling::init::is_initialized() {
+        return None;
+    }
+
+    let handle = tiling::init::get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+    rt.block_on(query(handle))
+}
+
+/// Hides the bar window and returns whether it was visible.
+fn hide_bar(app_handle: &AppHandle) -> Option<bool> {
+    set_bar_visible(app_handle, false)
+        .inspect_err(|err| tracing::warn!(error = %err, "focus mode: failed to hide the bar"))
+        .ok()
+}
+
+fn show_bar(app_handle: &AppHandle) {
+    if let Err(err) = set_bar_visible(app_handle, true) {
+        tracing::warn!(error = %err, "focus mode: failed to show the bar");
+    }
+}
+
+/// Switches the focused workspace to monocle and returns its previous layout.
+///
+/// Returns `None` when tiling isn't running or the workspace already uses monocle.
+fn switch_to_monocle() -> Option<(Uuid, LayoutType)> {
+    let workspace = query_tiling(|handle| async move {
+        handle
+            .get_focused_workspace()
+            .await
+            .ok()
+            .and_then(QueryResult::into_workspace)?
+    })?;
+    if workspace.layout == LayoutType::Monocle {
+        return None;
+    }
+
+    tiling::init::get_handle()?.set_layout(workspace.id, LayoutType::Monocle).ok()?;
+    Some((workspace.id, workspace.layout))
+}
+
+/// Puts back a workspace's layout, unless it was changed since focus mode
+/// switched it to monocle.
+fn restore_layout(workspace_id: Uuid, layout: LayoutType) {
+    let still_monocle = query_tiling(|handle| async move {
+        let workspace = handle
+            .get_workspace(workspace_id)
+            .await
+            .ok()
+            .and_then(QueryResult::into_workspace)?;
+        workspace.map(|ws| ws.layout == LayoutType::Monocle)
+    });
+    if still_monocle != Some(true) {
+        return;
+    }
+
+    if let Some(handle) = tiling::init::get_handle() {
+        let _ = handle.set_layout(workspace_id, layout);
+    }
+}
+
+fn emit_state_changed(app_handle: &AppHandle, status: FocusModeStatus) {
+    if let Err(err) = app_handle.emit(events::focus_mode::STATE_CHANGED, status) {
+        tracing::warn!(error = %err, "failed to emit focus mode state");
+    }
+}
+
+/// Returns the current focus mode state.
+#[must_use]
+pub fn status() -> FocusModeStatus {
+    PRIOR_STATE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .map_or_else(FocusModeStatus::default, PriorState::status)
+}
+
+/// Turns focus mode on, running the actions enabled in the config.
+///
+/// Does nothing if focus mode is already on.
+pub fn enable(app_handle: &AppHandle) -> FocusModeStatus {
+    let mut prior_state = PRIOR_STATE.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(prior) = prior_state.as_ref() {
+        return prior.status();
+    }
+
+    let config = &get_config().focus_mode;
+    let mut prior = PriorState::default();
+
+    if config.hide_bar {
+        prior.bar_visible = hide_bar(app_handle);
+    }
+    if config.monocle {
+        prior.workspace_layout = switch_to_monocle();
+    }
+    if config.keepawake {
+        match set_system_awake(app_handle, true) {
+            Ok(was_awake) => prior.keepawake = Some(was_awake),
+            Err(err) => tracing::warn!(error = %err, "focus mode: failed to keep the system awake"),
+        }
+    }
+
+    let status = prior.status();
+    *prior_state = Some(prior);
+    drop(prior_state);
+
+    tracing::info!(?status, "focus mode on");
+    emit_state_changed(app_handle, status);
+    status
+}
+
+/// Turns focus mode off, restoring what it changed.
+///
+/// Does nothing if focus mode is off.
+pub fn disable(app_handle: &AppHandle) -> FocusModeStatus {
+    let Some(prior) = PRIOR_STATE.lock().unwrap_or_else(PoisonError::into_inner).take() else {
+        return FocusModeStatus::default();
+    };
+
+    if prior.bar_visible == Some(true) {
+        show_bar(app_handle);
+    }
+    if let Some((workspace_id, layout)) = prior.workspace_layout {
+        restore_layout(workspace_id, layout);
+    }
+    if prior.keepawake == Some(false)
+        && let Err(err) = set_system_awake(app_handle, false)
+    {
+        tracing::warn!(error = %err, "focus mode: failed to release the wake lock");
+    }
+
+    let status = FocusModeStatus::default();
+    tracing::info!("focus mode off");
+    emit_state_changed(app_handle, status);
+    status
+}
+
+/// Gets the current focus mode state.
+#[tauri::command]
+#[must_use]
+pub fn get_focus_mode() -> FocusModeStatus { status() }
+
+/// Handles focus mode IPC queries from the CLI.
+///
+/// Returns `None` for queries handled elsewhere.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    let IpcQuery::FocusMode { action } = query else {
+        return None;
+    };
+
+    let Some(action) = FocusModeAction::parse(action) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid focus mode action '{action}'. Must be 'on', 'off' or 'status'."
+        )));
+    };
+
+    Some(match (action, APP_HANDLE.get()) {
+        (FocusModeAction::Status, _) => IpcResponse::success(status()),
+        (_, None) => IpcResponse::error("Focus mode is not initialized"),
+        (FocusModeAction::On, Some(app_handle)) => IpcResponse::success(enable(app_handle)),
+        (FocusModeAction::Off, Some(app_handle)) => IpcResponse::success(disable(app_handle)),
+    })
+}
+
+/// Initializes focus mode with the app handle its actions need.
+pub fn init(app_handle: AppHandle) { let _ = APP_HANDLE.set(app_handle); }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_mode_action_parse() {
+        assert_eq!(FocusModeAction::parse("on"), Some(FocusModeAction::On));
+        assert_eq!(FocusModeAction::parse("off"), Some(FocusModeAction::Off));
+        assert_eq!(FocusModeAction::parse("status"), Some(FocusModeAction::Status));
+        assert_eq!(FocusModeAction::parse("toggle"), None);
+    }
+
+    #[test]
+    fn test_prior_state_status() {
+        let prior = PriorState {
+            bar_visible: Some(true),
+            workspace_layout: None,
+            keepawake: Some(false),
+        };
+        assert_eq!(prior.status(), FocusModeStatus {
+            active: true,
+            hide_bar: true,
+            monocle: false,
+            keepawake: true,
+        });
+    }
+
+    #[test]
+    fn test_focus_mode_status_serialization() {
+        let json = serde_json::to_value(FocusModeStatus::default()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "active": false,
+                "hideBar": false,
+                "monocle": false,
+                "keepawake": false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+
+    #[test]
+    fn test_handle_ipc_query_rejects_invalid_action() {
+        let query = IpcQuery::FocusMode { action: "toggle".to_string() };
+        assert!(matches!(
+            handle_ipc_query(&query),
+            Some(IpcResponse::Error { error }) if error.contains("'toggle'")
+        ));
+    }
+}
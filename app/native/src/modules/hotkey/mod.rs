@@ -40,30 +40,14 @@ pub fn create_hotkey_plugin<R: Runtime>() -> tauri::plugin::TauriPlugin<R> {
     }
 
     // Build the shortcut-to-command mapping
-    let mut shortcut_map: HashMap<Shortcut, ShortcutCommands> = HashMap::new();
-    let mut valid_shortcuts: Vec<Shortcut> = Vec::new();
-
-    for (shortcut_key, commands) in keybindings {
-        // Normalize the shortcut string for consistency
-        let shortcut_str = normalize_shortcut(shortcut_key);
+    let shortcut_map = parse_keybindings(keybindings);
 
-        // Try to parse the shortcut to validate it
-        match shortcut_str.parse::<Shortcut>() {
-            Ok(shortcut) => {
-                shortcut_map.insert(shortcut, commands.clone());
-                valid_shortcuts.push(shortcut);
-                tracing::debug!(shortcut = %shortcut_key, "registered shortcut");
-            }
-            Err(err) => {
-                tracing::warn!(shortcut = %shortcut_key, error = %err, "invalid shortcut");
-            }
-        }
-    }
-
-    if valid_shortcuts.is_empty() {
+    if shortcut_map.is_empty() {
         return Builder::<R>::new().build();
     }
 
+    let valid_shortcuts: Vec<Shortcut> = shortcut_map.keys().copied().collect();
+
     let shortcut_map: ShortcutCommandMap = Arc::new(shortcut_map);
     let shortcut_map_handler = Arc::clone(&shortcut_map);
 
@@ -92,10 +76,52 @@ pub fn create_hotkey_plugin<R: Runtime>() -> tauri::plugin::TauriPlugin<R> {
         .build()
 }
 
+/// Parses configured keybindings into a shortcut-to-command map.
+///
+/// Chords that cannot be parsed are logged and skipped. When several chords
+/// resolve to the same shortcut (e.g. "cmd+alt+h" and "Command+Option+H"),
+/// the first one in sorted order wins and the others are logged.
+fn parse_keybindings(
+    keybindings: &HashMap<String, ShortcutCommands>,
+) -> HashMap<Shortcut, ShortcutCommands> {
+    let mut shortcut_map: HashMap<Shortcut, ShortcutCommands> = HashMap::new();
+
+    // Sort for deterministic duplicate handling (config maps are unordered)
+    let mut entries: Vec<_> = keybindings.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    for (shortcut_key, commands) in entries {
+        // Normalize the shortcut string for consistency
+        let shortcut_str = normalize_shortcut(shortcut_key);
+
+        // Try to parse the shortcut to validate it
+        let shortcut = match shortcut_str.parse::<Shortcut>() {
+            Ok(shortcut) => shortcut,
+            Err(err) => {
+                tracing::warn!(shortcut = %shortcut_key, error = %err, "invalid shortcut");
+                continue;
+            }
+        };
+
+        if shortcut_map.contains_key(&shortcut) {
+            tracing::warn!(
+                shortcut = %shortcut_key,
+                "duplicate shortcut, another keybinding already uses this chord"
+            );
+            continue;
+        }
+
+        shortcut_map.insert(shortcut, commands.clone());
+        tracing::debug!(shortcut = %shortcut_key, "registered shortcut");
+    }
+
+    shortcut_map
+}
+
 /// Normalizes a shortcut string to a consistent format for macOS.
 ///
 /// This function handles common variations in shortcut notation using a single-pass
-/// approach for efficiency:
+/// approach for efficiency. Modifier names are matched case-insensitively:
 /// - "Ctrl" is normalized to "Control"
 /// - "Cmd" is normalized to "Command" (macOS Command key)
 /// - "Alt" and "Opt" are normalized to "Option" (macOS Option key)
@@ -109,12 +135,13 @@ fn normalize_shortcut(shortcut: &str) -> String {
             result.push('+');
         }
 
-        let normalized = match part {
-            "Ctrl" => "Control",
-            "Cmd" | "Super" | "Meta" => "Command",
-            "Alt" | "Opt" => "Option",
+        let normalized = match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => "Control",
+            "cmd" | "command" | "super" | "meta" => "Command",
+            "alt" | "opt" | "option" => "Option",
+            "shift" => "Shift",
             "`" => "Backquote",
-            other => other,
+            _ => part,
         };
 
         result.push_str(normalized);
@@ -132,7 +159,7 @@ fn normalize_shortcut(shortcut: &str) -> String {
 /// If no commands are configured (empty string or empty array), the function
 /// returns immediately without executing anything. This is useful for capturing
 /// shortcuts to disable global OS shortcuts.
-fn execute_shortcut_commands(shortcut_commands: &ShortcutCommands) {
+pub fn execute_shortcut_commands(shortcut_commands: &ShortcutCommands) {
     let commands = shortcut_commands.get_commands();
 
     // No commands configured - this shortcut is just for capturing/blocking
@@ -268,6 +295,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_normalize_shortcut_lowercase_modifiers() {
+        assert_eq!(normalize_shortcut("cmd+alt+h"), "Command+Option+h");
+        assert_eq!(normalize_shortcut("ctrl+shift+s"), "Control+Shift+s");
+        assert_eq!(normalize_shortcut("CMD+OPT+K"), "Command+Option+K");
+    }
+
     #[test]
     fn test_normalize_shortcut_passthrough() {
         // Keys that should pass through unchanged
@@ -469,6 +503,76 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // parse_keybindings tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_keybindings_valid() {
+        let keybindings = HashMap::from([
+            (
+                "cmd+alt+h".to_string(),
+                ShortcutCommands::Single("stache tiling window --focus left".to_string()),
+            ),
+            (
+                "Command+Option+L".to_string(),
+                ShortcutCommands::Single("stache tiling window --focus right".to_string()),
+            ),
+        ]);
+
+        let map = parse_keybindings(&keybindings);
+        assert_eq!(map.len(), 2);
+
+        let shortcut: Shortcut = "Command+Option+H".parse().unwrap();
+        assert_eq!(map.get(&shortcut).unwrap().get_commands(), vec![
+            "stache tiling window --focus left"
+        ]);
+    }
+
+    #[test]
+    fn test_parse_keybindings_skips_invalid() {
+        let keybindings = HashMap::from([
+            (
+                "Cmd+NotAKey".to_string(),
+                ShortcutCommands::Single("echo bad".to_string()),
+            ),
+            (
+                "Cmd+K".to_string(),
+                ShortcutCommands::Single("echo good".to_string()),
+            ),
+        ]);
+
+        let map = parse_keybindings(&keybindings);
+        assert_eq!(map.len(), 1);
+        assert!(map.contains_key(&"Command+K".parse::<Shortcut>().unwrap()));
+    }
+
+    #[test]
+    fn test_parse_keybindings_skips_duplicates() {
+        let keybindings = HashMap::from([
+            (
+                "Command+Option+H".to_string(),
+                ShortcutCommands::Single("echo first".to_string()),
+            ),
+            (
+                "cmd+alt+h".to_string(),
+                ShortcutCommands::Single("echo second".to_string()),
+            ),
+        ]);
+
+        let map = parse_keybindings(&keybindings);
+        assert_eq!(map.len(), 1);
+
+        // "Command+Option+H" sorts before "cmd+alt+h"
+        let commands = map.values().next().unwrap();
+        assert_eq!(commands.get_commands(), vec!["echo first"]);
+    }
+
+    #[test]
+    fn test_parse_keybindings_empty() {
+        assert!(parse_keybindings(&HashMap::new()).is_empty());
+    }
+
     #[test]
     fn test_shortcut_commands_get_commands_single() {
         let commands = ShortcutCommands::Single("echo hello".to_string());
@@ -0,0 +1,185 @@
+//! Idle Monitor Module for Stache.
+//!
+//! This module tracks how long the user has been away from the keyboard and
+//! mouse, using `CGEventSourceSecondsSinceLastEventType`. When `idle.enabled`
+//! is set, a background thread polls the idle time and runs `idle.onIdle`
+//! once it passes `idle.timeout`, then `idle.onActive` when the user comes
+//! back. Transitions are debounced so a single stray input doesn't count as
+//! being back.
+//!
+//! The current idle time is also available from the CLI with
+//! `stache idle seconds`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crate::config;
+use crate::modules::hotkey::execute_shortcut_commands;
+use crate::utils::thread::spawn_named_thread;
+
+/// How often the idle time is sampled.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Consecutive polls a new state must be seen on before it takes effect.
+const DEBOUNCE_POLLS: u32 = 2;
+
+/// `kCGEventSourceStateCombinedSessionState`.
+const COMBINED_SESSION_STATE: i32 = 0;
+
+/// `kCGAnyInputEventType`.
+const ANY_INPUT_EVENT_TYPE: u32 = u32::MAX;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGEventSourceSecondsSinceLastEventType(state_id: i32, event_type: u32) -> f64;
+}
+
+/// Flag indicating if the monitor is running.
+static IS_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Returns the number of seconds since the last keyboard or mouse input.
+#[must_use]
+pub fn idle_seconds() -> f64 {
+    unsafe { CGEventSourceSecondsSinceLastEventType(COMBINED_SESSION_STATE, ANY_INPUT_EVENT_TYPE) }
+}
+
+/// A change between the idle and active states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IdleTransition {
+    /// The user went idle.
+    Idle,
+    /// The user is active again.
+    Active,
+}
+
+/// Debounced idle state, fed with the idle time on every poll.
+#[derive(Debug)]
+struct IdleTracker {
+    /// Seconds without input before the user counts as idle.
+    timeout: f64,
+    /// Whether the user is currently considered idle.
+    is_idle: bool,
+    /// Consecutive polls on which the opposite state was seen.
+    streak: u32,
+}
+
+impl IdleTracker {
+    const fn new(timeout: f64) -> Self {
+        Self {
+            timeout,
+            is_idle: false,
+            streak: 0,
+        }
+    }
+
+    /// Records an idle time sample and returns the transition it completes, if any.
+    ///
+    /// The user counts as idle once the idle time passes the timeout, and as
+    /// active when there was input since the previous poll. Samples in between
+    /// keep the current state.
+    fn update(&mut self, idle_seconds: f64) -> Option<IdleTransition> {
+        let seen_idle = if idle_seconds >= self.timeout {
+            Some(true)
+        } else if idle_seconds < POLL_INTERVAL.as_secs_f64() {
+            Some(false)
+        } else {
+            None
+        };
+
+        if seen_idle.is_none_or(|idle| idle == self.is_idle) {
+            self.streak = 0;
+            return None;
+        }
+
+        self.streak += 1;
+        if self.streak < DEBOUNCE_POLLS {
+            return None;
+        }
+
+        self.streak = 0;
+        self.is_idle = !self.is_idle;
+        Some(if self.is_idle {
+            IdleTransition::Idle
+        } else {
+            IdleTransition::Active
+        })
+    }
+}
+
+/// Initializes the idle monitor.
+///
+/// Does nothing unless `idle.enabled` is set and at least one of `idle.onIdle`
+/// or `idle.onActive` is configured.
+pub fn init() {
+    let config = config::get_config().idle.clone();
+
+    if !config.is_enabled() {
+        return;
+    }
+
+    if IS_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    spawn_named_thread("idle-monitor", move || {
+        let mut tracker = IdleTracker::new(config.timeout_secs());
+
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let commands = match tracker.update(idle_seconds()) {
+                Some(IdleTransition::Idle) => config.on_idle.as_ref(),
+                Some(IdleTransition::Active) => config.on_active.as_ref(),
+                None => continue,
+            };
+
+            tracing::debug!(idle = tracker.is_idle, "idle state changed");
+            if let Some(commands) = commands {
+                execute_shortcut_commands(commands);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker_goes_idle_after_timeout() {
+        let mut tracker = IdleTracker::new(60.0);
+
+        assert_eq!(tracker.update(30.0), None);
+        assert_eq!(tracker.update(60.0), None);
+        assert_eq!(tracker.update(62.0), Some(IdleTransition::Idle));
+        assert_eq!(tracker.update(64.0), None);
+    }
+
+    #[test]
+    fn test_tracker_debounces_single_input() {
+        let mut tracker = IdleTracker::new(60.0);
+        tracker.update(60.0);
+        tracker.update(62.0);
+
+        // A single input followed by no further activity keeps the idle state
+        assert_eq!(tracker.update(0.5), None);
+        assert_eq!(tracker.update(2.5), None);
+        assert_eq!(tracker.update(4.5), None);
+        assert!(tracker.is_idle);
+
+        // Sustained input makes the user active again
+        assert_eq!(tracker.update(0.5), None);
+        assert_eq!(tracker.update(1.0), Some(IdleTransition::Active));
+        assert!(!tracker.is_idle);
+    }
+
+    #[test]
+    fn test_tracker_stays_active_without_timeout() {
+        let mut tracker = IdleTracker::new(60.0);
+
+        for seconds in [0.0, 10.0, 30.0, 59.0, 0.5, 1.0] {
+            assert_eq!(tracker.update(seconds), None);
+        }
+        assert!(!tracker.is_idle);
+    }
+}
@@ -5,8 +5,11 @@
 //!
 //! - [`audio`] - Audio device management and automatic switching
 //! - [`bar`] - Status bar UI and components
+//! - [`capture`] - Screen capture of the focused window or workspace
 //! - [`cmd_q`] - Hold-to-quit (⌘Q) handler
+//! - [`focus_mode`] - Do-not-disturb style focus mode
 //! - [`hotkey`] - Global keyboard shortcut handling
+//! - [`idle`] - User idle time monitoring
 //! - [`menu_anywhere`] - Summon app menus at cursor position
 //! - [`notunes`] - Prevent Apple Music from auto-launching
 //! - [`tiling`] - Tiling window manager (reactive architecture)
@@ -16,8 +19,11 @@
 
 pub mod audio;
 pub mod bar;
+pub mod capture;
 pub mod cmd_q;
+pub mod focus_mode;
 pub mod hotkey;
+pub mod idle;
 pub mod menu_anywhere;
 pub mod notunes;
 pub mod tiling;
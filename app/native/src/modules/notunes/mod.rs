@@ -5,7 +5,10 @@
 //! launch Apple Music - this module intercepts those launches and optionally opens
 //! a preferred music player instead.
 //!
-//! The target music player is configurable via the `notunes.target_app` config option.
+//! The replacement player is configurable via `notunes.replacement` (an app path or
+//! bundle identifier) or the `notunes.target_app` preset, and the set of blocked apps
+//! via `notunes.blocked_bundle_ids`. With `notunes.action` set to `block`, blocked
+//! apps are only quit and no replacement is launched.
 //!
 //! Inspired by <https://github.com/tombonez/noTunes> (MIT License, Tom Taylor 2017).
 
@@ -17,7 +20,7 @@ use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::config::{self, TargetMusicApp};
+use crate::config::{self, NoTunesAction, NoTunesConfig};
 use crate::utils::objc::{get_app_bundle_id, nsstring};
 use crate::utils::thread::spawn_named_thread;
 
@@ -30,15 +33,123 @@ const ITUNES_BUNDLE_ID: &str = "com.apple.iTunes";
 /// Flag indicating if the module is running.
 static IS_RUNNING: AtomicBool = AtomicBool::new(false);
 
-/// Configured target music app (cached from config at init time).
-static TARGET_APP: OnceLock<TargetMusicApp> = OnceLock::new();
+/// Resolved noTunes settings (cached from config at init time).
+static SETTINGS: OnceLock<NoTunesSettings> = OnceLock::new();
 
-/// Checks if a bundle identifier belongs to Apple Music or iTunes.
+/// How the replacement app was specified in the config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ReplacementSpec {
+    /// Path to an application bundle.
+    Path(String),
+    /// Bundle identifier of an installed application.
+    BundleId(String),
+}
+
+impl ReplacementSpec {
+    /// Parses a replacement value, treating anything path-like as an app path.
+    fn parse(value: &str) -> Self {
+        if value.contains('/') || value.to_ascii_lowercase().ends_with(".app") {
+            Self::Path(value.to_string())
+        } else {
+            Self::BundleId(value.to_string())
+        }
+    }
+}
+
+/// The app launched in place of a blocked music app.
+#[derive(Debug, Clone)]
+struct ReplacementApp {
+    /// Application path, if known.
+    app_path: Option<String>,
+    /// Bundle identifier used for the "already running" check, if known.
+    bundle_id: Option<String>,
+    /// Name used in log messages.
+    display_name: String,
+}
+
+/// noTunes settings resolved from the config.
+#[derive(Debug, Clone)]
+struct NoTunesSettings {
+    /// The replacement app, or `None` to only block.
+    ///
+    /// Always `None` when the configured action is [`NoTunesAction::Block`].
+    replacement: Option<ReplacementApp>,
+    /// The config the settings were resolved from, for the blocklist.
+    config: NoTunesConfig,
+}
+
+impl NoTunesSettings {
+    /// Resolves the settings from the noTunes config.
+    fn from_config(config: &NoTunesConfig) -> Self {
+        if config.action == NoTunesAction::Block {
+            return Self {
+                replacement: None,
+                config: config.clone(),
+            };
+        }
+
+        let replacement = config.replacement().map_or_else(
+            || {
+                let target = &config.target_app;
+                target.app_path().map(|path| ReplacementApp {
+                    app_path: Some(path.to_string()),
+                    bundle_id: target.bundle_id().map(str::to_string),
+                    display_name: target.display_name().to_string(),
+                })
+            },
+            |value| {
+                Some(match ReplacementSpec::parse(value) {
+                    ReplacementSpec::Path(path) => ReplacementApp {
+                        bundle_id: bundle_id_for_path(&path),
+                        display_name: path.clone(),
+                        app_path: Some(path),
+                    },
+                    ReplacementSpec::BundleId(bundle_id) => ReplacementApp {
+                        app_path: None,
+                        display_name: bundle_id.clone(),
+                        bundle_id: Some(bundle_id),
+                    },
+                })
+            },
+        );
+
+        Self {
+            replacement,
+            config: config.clone(),
+        }
+    }
+}
+
+/// Reads the bundle identifier of the application bundle at `path`.
+fn bundle_id_for_path(path: &str) -> Option<String> {
+    if !std::path::Path::new(path).exists() {
+        return None;
+    }
+
+    // SAFETY: NSBundle is queried with a valid NSString path; the returned
+    // bundle is checked for null inside `get_app_bundle_id`.
+    unsafe {
+        let bundle: *mut Object = msg_send![class!(NSBundle), bundleWithPath: nsstring(path)];
+        get_app_bundle_id(bundle)
+    }
+}
+
+/// Checks if a bundle identifier belongs to a blocked music app.
+///
+/// Uses the configured blocklist, falling back to Apple Music and iTunes
+/// when the module has not been initialized.
 #[inline]
 fn is_music_app(bundle_id: &str) -> bool {
-    bundle_id == APPLE_MUSIC_BUNDLE_ID || bundle_id == ITUNES_BUNDLE_ID
+    SETTINGS.get().map_or_else(
+        || bundle_id == APPLE_MUSIC_BUNDLE_ID || bundle_id == ITUNES_BUNDLE_ID,
+        |settings| settings.config.is_blocked(bundle_id),
+    )
 }
 
+/// Returns whether the noTunes observer is running.
+#[must_use]
+pub fn is_running() -> bool { IS_RUNNING.load(Ordering::SeqCst) }
+
 /// Initializes the noTunes module.
 ///
 /// This sets up an observer for `NSWorkspace.willLaunchApplicationNotification`
@@ -56,8 +167,8 @@ pub fn init() {
         return;
     }
 
-    // Cache the target app setting
-    let _ = TARGET_APP.set(config.notunes.target_app.clone());
+    // Cache the resolved settings
+    let _ = SETTINGS.set(NoTunesSettings::from_config(&config.notunes));
 
     spawn_named_thread("notunes-init", move || {
         // SAFETY: These functions interact with NSWorkspace and NSNotificationCenter APIs:
@@ -72,9 +183,9 @@ pub fn init() {
     });
 }
 
-/// Returns the configured target music app.
-fn get_target_app() -> &'static TargetMusicApp {
-    TARGET_APP.get().unwrap_or(&TargetMusicApp::Tidal)
+/// Returns the configured replacement app, if any.
+fn get_replacement_app() -> Option<&'static ReplacementApp> {
+    SETTINGS.get().and_then(|settings| settings.replacement.as_ref())
 }
 
 /// Terminates any currently running Apple Music or iTunes instances.
@@ -200,26 +311,23 @@ extern "C" fn handle_app_launch(_self: &Object, _cmd: Sel, notification: *mut Ob
     }
 }
 
-/// Launches the configured target music app as replacement.
+/// Launches the configured replacement music app.
 fn launch_target_app() {
-    let target = get_target_app();
-
-    // Get app path and bundle ID, or return if target is None
-    let Some(app_path) = target.app_path() else {
+    let Some(target) = get_replacement_app() else {
         return;
     };
-    let bundle_id = target.bundle_id();
-    let display_name = target.display_name();
+    let display_name = &target.display_name;
 
     // Check if the app is installed
-    let path = std::path::Path::new(app_path);
-    if !path.exists() {
+    if let Some(app_path) = &target.app_path
+        && !std::path::Path::new(app_path).exists()
+    {
         tracing::warn!(app = %display_name, path = %app_path, "notunes: target app not found");
         return;
     }
 
     // Check if the app is already running
-    if let Some(bundle_id) = bundle_id {
+    if let Some(bundle_id) = &target.bundle_id {
         unsafe {
             let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
             let running_apps: *mut Object = msg_send![workspace, runningApplications];
@@ -229,7 +337,7 @@ fn launch_target_app() {
                 let app: *mut Object = msg_send![running_apps, objectAtIndex: i];
 
                 if let Some(bundle_id_str) = get_app_bundle_id(app)
-                    && bundle_id_str == bundle_id
+                    && bundle_id_str == *bundle_id
                 {
                     // App is already running, no need to launch
                     return;
@@ -239,7 +347,14 @@ fn launch_target_app() {
     }
 
     // Launch the app using /usr/bin/open
-    match std::process::Command::new("/usr/bin/open").arg(app_path).spawn() {
+    let mut command = std::process::Command::new("/usr/bin/open");
+    match (&target.app_path, &target.bundle_id) {
+        (Some(app_path), _) => command.arg(app_path),
+        (None, Some(bundle_id)) => command.arg("-b").arg(bundle_id),
+        (None, None) => return,
+    };
+
+    match command.spawn() {
         Ok(_) => tracing::info!(app = %display_name, "notunes: launched replacement app"),
         Err(e) => {
             tracing::error!(app = %display_name, error = %e, "notunes: failed to launch replacement app");
@@ -250,6 +365,7 @@ fn launch_target_app() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::TargetMusicApp;
 
     #[test]
     fn test_is_music_app_apple_music() {
@@ -324,4 +440,89 @@ mod tests {
         let app = TargetMusicApp::default();
         assert_eq!(app, TargetMusicApp::Tidal);
     }
+
+    #[test]
+    fn test_replacement_spec_parse_path() {
+        assert_eq!(
+            ReplacementSpec::parse("/Applications/Cider.app"),
+            ReplacementSpec::Path("/Applications/Cider.app".to_string())
+        );
+        assert_eq!(
+            ReplacementSpec::parse("Cider.app"),
+            ReplacementSpec::Path("Cider.app".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replacement_spec_parse_bundle_id() {
+        assert_eq!(
+            ReplacementSpec::parse("com.spotify.client"),
+            ReplacementSpec::BundleId("com.spotify.client".to_string())
+        );
+    }
+
+    #[test]
+    fn test_settings_use_target_app_without_replacement() {
+        let config = NoTunesConfig {
+            target_app: TargetMusicApp::Spotify,
+            ..Default::default()
+        };
+        let settings = NoTunesSettings::from_config(&config);
+        let replacement = settings.replacement.unwrap();
+        assert_eq!(
+            replacement.app_path.as_deref(),
+            Some("/Applications/Spotify.app")
+        );
+        assert_eq!(replacement.bundle_id.as_deref(), Some("com.spotify.client"));
+    }
+
+    #[test]
+    fn test_settings_without_any_replacement() {
+        let config = NoTunesConfig {
+            target_app: TargetMusicApp::None,
+            ..Default::default()
+        };
+        let settings = NoTunesSettings::from_config(&config);
+        assert!(settings.replacement.is_none());
+    }
+
+    #[test]
+    fn test_settings_replacement_bundle_id_overrides_target_app() {
+        let config = NoTunesConfig {
+            target_app: TargetMusicApp::Tidal,
+            replacement: Some("com.example.Player".to_string()),
+            ..Default::default()
+        };
+        let settings = NoTunesSettings::from_config(&config);
+        let replacement = settings.replacement.unwrap();
+        assert_eq!(replacement.app_path, None);
+        assert_eq!(replacement.bundle_id.as_deref(), Some("com.example.Player"));
+    }
+
+    #[test]
+    fn test_settings_block_action_skips_replacement() {
+        let config = NoTunesConfig {
+            action: NoTunesAction::Block,
+            target_app: TargetMusicApp::Spotify,
+            replacement: Some("com.example.Player".to_string()),
+            ..Default::default()
+        };
+        let settings = NoTunesSettings::from_config(&config);
+        assert!(settings.replacement.is_none());
+        assert_eq!(settings.config.blocked_bundle_ids.len(), 2);
+    }
+
+    #[test]
+    fn test_settings_copy_blocked_bundle_ids() {
+        let config = NoTunesConfig {
+            blocked_bundle_ids: vec!["com.example.Blocked".to_string()],
+            ..Default::default()
+        };
+        let settings = NoTunesSettings::from_config(&config);
+        assert_eq!(settings.config.blocked_bundle_ids, vec![
+            "com.example.Blocked".to_string()
+        ]);
+        assert!(settings.config.is_blocked("com.example.Blocked"));
+        assert!(!settings.config.is_blocked(APPLE_MUSIC_BUNDLE_ID));
+    }
 }
@@ -7,7 +7,13 @@ use std::time::Duration;
 
 use tokio::sync::{mpsc, oneshot};
 
-use super::messages::{QueryResult, ResizeDimension, StateMessage, StateQuery, TargetScreen};
+use super::messages::{
+    ArrangeFloatingOutcome, ClaimAppOutcome, CommandOutcome, CopyLayoutOutcome, CycleDirection,
+    FloatingArrangement, FloatingPlacement, FocusIndexOutcome, GapPresetChange, GapPresetOutcome,
+    MasterCountChange, MergeWorkspaceOutcome, MinimizeOutcome, PlaceWindowOutcome, QueryResult,
+    ResizeDimension, ResizeOutcome, RetileOutcome, ScrollDirection, SendWindowOutcome,
+    StateMessage, StateQuery, TargetScreen, ToggleAction, WindowCreatedInfo, WorkspaceEditOutcome,
+};
 
 /// Error types for actor communication.
 #[derive(Debug, thiserror::Error)]
@@ -63,6 +69,23 @@ impl StateActorHandle {
         self.sender.send(msg).await.map_err(|_| ActorError::SendFailed)
     }
 
+    /// Send a message built around a response channel and wait for the reply.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed, or
+    /// [`ActorError::ReceiveFailed`] if the response channel is closed.
+    async fn request<T>(
+        &self,
+        message: impl FnOnce(oneshot::Sender<T>) -> StateMessage,
+    ) -> Result<T, ActorError> {
+        let (tx, rx) = oneshot::channel();
+
+        self.sender.send(message(tx)).await.map_err(|_| ActorError::SendFailed)?;
+
+        rx.await.map_err(|_| ActorError::ReceiveFailed)
+    }
+
     // ========================================================================
     // Query methods
     // ========================================================================
@@ -74,14 +97,7 @@ impl StateActorHandle {
     /// Returns [`ActorError::SendFailed`] if the channel is closed, or
     /// [`ActorError::ReceiveFailed`] if the response channel is closed.
     pub async fn query(&self, query: StateQuery) -> Result<QueryResult, ActorError> {
-        let (tx, rx) = oneshot::channel();
-
-        self.sender
-            .send(StateMessage::Query { query, respond_to: tx })
-            .await
-            .map_err(|_| ActorError::SendFailed)?;
-
-        rx.await.map_err(|_| ActorError::ReceiveFailed)
+        self.request(|tx| StateMessage::Query { query, respond_to: tx }).await
     }
 
     /// Execute a query with a timeout.
@@ -203,6 +219,15 @@ impl StateActorHandle {
         self.query(StateQuery::GetFocusedWindow).await
     }
 
+    /// Get the frames windows had when first tracked, for windows that can be restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication with the actor fails.
+    pub async fn get_original_frames(&self) -> Result<QueryResult, ActorError> {
+        self.query(StateQuery::GetOriginalFrames).await
+    }
+
     // ========================================================================
     // Convenience command methods
     // ========================================================================
@@ -216,6 +241,112 @@ impl StateActorHandle {
         self.send(StateMessage::SwitchWorkspace { name: name.to_string() })
     }
 
+    /// Switch to the next/previous workspace on the focused screen and wait
+    /// for the result.
+    ///
+    /// Returns the name of the workspace switched to, or `None` if there was
+    /// nothing to cycle to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn cycle_workspace(
+        &self,
+        direction: CycleDirection,
+    ) -> Result<Option<String>, ActorError> {
+        self.request(|tx| StateMessage::CycleWorkspace {
+            direction,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
+    /// Switch to the previously visible workspace on the focused screen and
+    /// wait for the result.
+    ///
+    /// Returns the name of the workspace switched to, or `None` if there was
+    /// no previous workspace on the screen.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn focus_last_workspace(&self) -> Result<Option<String>, ActorError> {
+        self.request(|tx| StateMessage::FocusLastWorkspace { respond_to: Some(tx) })
+            .await
+    }
+
+    /// Create a workspace on the focused screen and wait for the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn create_workspace(
+        &self,
+        name: &str,
+    ) -> Result<CommandOutcome<WorkspaceEditOutcome>, ActorError> {
+        self.request(|tx| StateMessage::CreateWorkspace {
+            name: name.to_string(),
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
+    /// Rename a workspace and wait for the outcome.
+    ///
+    /// Renames the focused workspace when `workspace` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn rename_workspace(
+        &self,
+        workspace: Option<&str>,
+        name: &str,
+    ) -> Result<CommandOutcome<WorkspaceEditOutcome>, ActorError> {
+        self.request(|tx| StateMessage::RenameWorkspace {
+            workspace: workspace.map(str::to_string),
+            name: name.to_string(),
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
+    /// Move every window of the focused workspace into `target` and wait for
+    /// the outcome.
+    ///
+    /// With `follow`, the target workspace is switched to afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn merge_workspace(
+        &self,
+        target: &str,
+        follow: bool,
+    ) -> Result<CommandOutcome<MergeWorkspaceOutcome>, ActorError> {
+        self.request(|tx| StateMessage::MergeWorkspace {
+            target: target.to_string(),
+            follow,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
+    /// Claim the focused window's app for the focused workspace and wait for
+    /// the outcome.
+    ///
+    /// Releases the claim instead when `claim` is false.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn claim_app(
+        &self,
+        claim: bool,
+    ) -> Result<CommandOutcome<ClaimAppOutcome>, ActorError> {
+        self.request(|tx| StateMessage::ClaimApp { claim, respond_to: Some(tx) }).await
+    }
+
     /// Set the layout for a workspace.
     ///
     /// # Errors
@@ -238,6 +369,89 @@ impl StateActorHandle {
         self.send(StateMessage::ToggleFloating { window_id })
     }
 
+    /// Change the sticky state of the focused window.
+    ///
+    /// Accepts "toggle", "on" or "off".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    /// Returns `Ok(())` but logs a warning if the action is invalid.
+    pub fn set_sticky(&self, action: &str) -> Result<(), ActorError> {
+        let Some(action) = ToggleAction::parse(action) else {
+            tracing::warn!("set_sticky: invalid action '{action}'");
+            return Ok(());
+        };
+        self.send(StateMessage::SetSticky { action })
+    }
+
+    /// Change whether the focused window is kept above tiled windows.
+    ///
+    /// Accepts "toggle", "on" or "off".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    /// Returns `Ok(())` but logs a warning if the action is invalid.
+    pub fn set_always_on_top(&self, action: &str) -> Result<(), ActorError> {
+        let Some(action) = ToggleAction::parse(action) else {
+            tracing::warn!("set_always_on_top: invalid action '{action}'");
+            return Ok(());
+        };
+        self.send(StateMessage::SetAlwaysOnTop { action })
+    }
+
+    /// Move the focused window to the scratchpad.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn move_window_to_scratchpad(&self) -> Result<(), ActorError> {
+        self.send(StateMessage::MoveWindowToScratchpad)
+    }
+
+    /// Show or hide the scratchpad's windows over the focused screen.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn toggle_scratchpad(&self) -> Result<(), ActorError> {
+        self.send(StateMessage::ToggleScratchpad)
+    }
+
+    /// Change the floating state of the focused window.
+    ///
+    /// Accepts "toggle", "on" or "off".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    /// Returns `Ok(())` but logs a warning if the action is invalid.
+    pub fn set_floating(&self, action: &str) -> Result<(), ActorError> {
+        let Some(action) = ToggleAction::parse(action) else {
+            tracing::warn!("set_floating: invalid action '{action}'");
+            return Ok(());
+        };
+        self.send(StateMessage::SetFloating { action })
+    }
+
+    /// Minimize the focused window, or restore a minimized window of the
+    /// focused workspace, and wait for the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn set_minimized(
+        &self,
+        minimized: bool,
+    ) -> Result<CommandOutcome<MinimizeOutcome>, ActorError> {
+        self.request(|tx| StateMessage::SetMinimized {
+            minimized,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
     /// Enable or disable tiling.
     ///
     /// # Errors
@@ -258,6 +472,35 @@ impl StateActorHandle {
         self.send(StateMessage::FocusWindow { direction })
     }
 
+    /// Focus the previously focused window.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn focus_last_window(&self) -> Result<(), ActorError> {
+        self.send(StateMessage::FocusLastWindow)
+    }
+
+    /// Focus the window at a 1-based position in the focused workspace and
+    /// wait for the outcome. With `wraps`, positions past the last window
+    /// wrap around instead of failing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn focus_window_at(
+        &self,
+        index: usize,
+        wraps: bool,
+    ) -> Result<CommandOutcome<FocusIndexOutcome>, ActorError> {
+        self.request(|tx| StateMessage::FocusWindowAt {
+            index,
+            wraps,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
     /// Swap focused window with another in a direction.
     ///
     /// Supports spatial directions (up/down/left/right) and cycling (next/previous).
@@ -272,6 +515,33 @@ impl StateActorHandle {
         self.send(StateMessage::SwapWindowInDirection { direction })
     }
 
+    /// Move the focused window to the first position of its workspace.
+    ///
+    /// In the master layout this makes it the master window.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn promote_window(&self) -> Result<(), ActorError> {
+        self.send(StateMessage::PromoteWindow)
+    }
+
+    /// Move the focused window in a direction.
+    ///
+    /// Floating windows are moved by `step` pixels (or `floating.moveStep`),
+    /// staying within the screen. Tiled windows are swapped instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn move_window_in_direction(
+        &self,
+        direction: super::FocusDirection,
+        step: Option<u32>,
+    ) -> Result<(), ActorError> {
+        self.send(StateMessage::MoveWindowInDirection { direction, step })
+    }
+
     /// Balance split ratios in the focused workspace.
     ///
     /// # Errors
@@ -290,6 +560,27 @@ impl StateActorHandle {
         self.send(StateMessage::CycleLayout { workspace_id })
     }
 
+    /// Copy the layout and split ratios of a workspace by name to the focused
+    /// workspace and wait for the outcome.
+    ///
+    /// With `swap`, the two workspaces exchange their layouts instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn copy_layout(
+        &self,
+        source: &str,
+        swap: bool,
+    ) -> Result<CommandOutcome<CopyLayoutOutcome>, ActorError> {
+        self.request(|tx| StateMessage::CopyLayout {
+            source: source.to_string(),
+            swap,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
     /// Send focused window to another screen.
     ///
     /// Supports "main"/"primary", "secondary", or display name.
@@ -303,6 +594,26 @@ impl StateActorHandle {
         })
     }
 
+    /// Send the focused window to a workspace by name and wait for the outcome.
+    ///
+    /// With `follow`, the workspace is switched to afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn send_window_to_workspace(
+        &self,
+        workspace: &str,
+        follow: bool,
+    ) -> Result<CommandOutcome<SendWindowOutcome>, ActorError> {
+        self.request(|tx| StateMessage::SendWindowToWorkspace {
+            workspace: workspace.to_string(),
+            follow,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
     /// Send focused workspace to another screen.
     ///
     /// Supports "main"/"primary", "secondary", or display name.
@@ -334,7 +645,53 @@ impl StateActorHandle {
             tracing::warn!("resize_focused_window: invalid dimension '{dimension}'");
             return Ok(());
         };
-        self.send(StateMessage::ResizeFocusedWindow { dimension: dim, amount })
+        self.send(StateMessage::ResizeFocusedWindow {
+            dimension: dim,
+            amount,
+            respond_to: None,
+        })
+    }
+
+    /// Resize the focused window and wait for the outcome.
+    ///
+    /// Unlike [`Self::resize_focused_window`], this reports whether the split
+    /// was adjusted, ignored by the layout, or failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn resize_focused_window_with_outcome(
+        &self,
+        dimension: ResizeDimension,
+        amount: i32,
+    ) -> Result<CommandOutcome<ResizeOutcome>, ActorError> {
+        self.request(|tx| StateMessage::ResizeFocusedWindow {
+            dimension,
+            amount,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
+    /// Set a split ratio of the focused workspace and wait for the outcome.
+    ///
+    /// The ratio is clamped to 0.1 - 0.9. In the master layout, index 0 sets
+    /// the master ratio.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn set_split_ratio(
+        &self,
+        index: usize,
+        ratio: f64,
+    ) -> Result<CommandOutcome<ResizeOutcome>, ActorError> {
+        self.request(|tx| StateMessage::SetSplitRatio {
+            index,
+            ratio,
+            respond_to: Some(tx),
+        })
+        .await
     }
 
     /// Apply a floating preset to the focused window.
@@ -351,6 +708,114 @@ impl StateActorHandle {
         })
     }
 
+    /// Center or maximize the focused floating window and wait for the outcome.
+    ///
+    /// Tiled windows are left alone and produce a failed outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn place_floating_window(
+        &self,
+        placement: FloatingPlacement,
+    ) -> Result<CommandOutcome<PlaceWindowOutcome>, ActorError> {
+        self.request(|tx| StateMessage::PlaceFloatingWindow {
+            placement,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
+    /// Cascade or tile the floating windows of the focused workspace and wait
+    /// for the outcome.
+    ///
+    /// The windows stay floating; only their frames change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn arrange_floating_windows(
+        &self,
+        arrangement: FloatingArrangement,
+    ) -> Result<CommandOutcome<ArrangeFloatingOutcome>, ActorError> {
+        self.request(|tx| StateMessage::ArrangeFloatingWindows {
+            arrangement,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
+    /// Change the number of master windows in the focused workspace.
+    ///
+    /// Accepts "+N"/"-N" to adjust the current count, or "N" to set it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    /// Returns `Ok(())` but logs a warning if the change is invalid.
+    pub fn adjust_master_count(&self, change: &str) -> Result<(), ActorError> {
+        let Some(change) = MasterCountChange::parse(change) else {
+            tracing::warn!("adjust_master_count: invalid master count '{change}'");
+            return Ok(());
+        };
+        self.send(StateMessage::AdjustMasterCount { change })
+    }
+
+    /// Scroll the focused scrolling workspace by one column.
+    ///
+    /// Accepts "left" or "right".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    /// Returns `Ok(())` but logs a warning if the direction is invalid.
+    pub fn scroll_workspace(&self, direction: &str) -> Result<(), ActorError> {
+        let Some(direction) = ScrollDirection::parse(direction) else {
+            tracing::warn!("scroll_workspace: invalid direction '{direction}'");
+            return Ok(());
+        };
+        self.send(StateMessage::ScrollWorkspace { direction })
+    }
+
+    /// Switch the gaps of all workspaces to a preset and wait for the outcome.
+    ///
+    /// Accepts a `tiling.gapPresets` name, "cycle" or "reset".
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn set_gap_preset(
+        &self,
+        preset: &str,
+    ) -> Result<CommandOutcome<GapPresetOutcome>, ActorError> {
+        self.request(|tx| StateMessage::SetGapPreset {
+            change: GapPresetChange::parse(preset),
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
+    /// Reconcile tracked windows with the system's windows and re-apply all layouts.
+    ///
+    /// `windows` are the trackable windows and `window_ids` every window that
+    /// currently exists; see [`StateMessage::Retile`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ActorError`] if the actor has stopped.
+    pub async fn retile(
+        &self,
+        windows: Vec<WindowCreatedInfo>,
+        window_ids: Vec<u32>,
+    ) -> Result<RetileOutcome, ActorError> {
+        self.request(|tx| StateMessage::Retile {
+            windows,
+            window_ids,
+            respond_to: Some(tx),
+        })
+        .await
+    }
+
     /// Request shutdown of the actor.
     ///
     /// # Errors
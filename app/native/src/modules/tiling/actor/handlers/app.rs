@@ -11,7 +11,9 @@ use std::collections::HashSet;
 
 use uuid::Uuid;
 
-use crate::modules::tiling::effects::get_window_cache;
+use super::window::leave_empty_workspace;
+use crate::config::get_config;
+use crate::modules::tiling::effects::{applescript, get_window_cache};
 use crate::modules::tiling::init::get_subscriber_handle;
 use crate::modules::tiling::state::TilingState;
 
@@ -29,7 +31,9 @@ pub fn on_app_launched(state: &mut TilingState, pid: i32, bundle_id: &str, name:
 
 /// Handles an app terminated event.
 ///
-/// Removes all windows belonging to this application from tracking.
+/// Removes all windows belonging to this application from tracking. When the
+/// focused workspace is left empty, `tiling.onEmptyWorkspace` decides whether
+/// to switch away from it.
 /// Returns the set of affected workspace IDs (for layout recomputation).
 pub fn on_app_terminated(state: &mut TilingState, pid: i32) -> HashSet<Uuid> {
     tracing::debug!("Handling app terminated: pid={pid}");
@@ -58,6 +62,7 @@ pub fn on_app_terminated(state: &mut TilingState, pid: i32) -> HashSet<Uuid> {
 
         // Remove from state
         state.remove_window(*window_id);
+        applescript::unregister(*window_id);
 
         // Remove from workspace's window list
         if let Some(ws_id) = workspace_id {
@@ -80,6 +85,7 @@ pub fn on_app_terminated(state: &mut TilingState, pid: i32) -> HashSet<Uuid> {
 
     // Clear focus if any removed window was focused
     let focus = eyeball::Observable::get(&state.focus);
+    let focused_workspace_id = focus.focused_workspace_id;
     if focus
         .focused_window_id
         .is_some_and(|focused_id| window_ids.contains(&focused_id))
@@ -87,6 +93,11 @@ pub fn on_app_terminated(state: &mut TilingState, pid: i32) -> HashSet<Uuid> {
         state.clear_focus();
     }
 
+    // Only the focused workspace is left, so focus isn't pulled to another screen
+    if let Some(ws_id) = focused_workspace_id.filter(|id| affected_workspaces.contains(id)) {
+        leave_empty_workspace(state, ws_id, get_config().tiling.on_empty_workspace);
+    }
+
     // Notify subscriber to recompute layouts for affected workspaces
     if let Some(handle) = get_subscriber_handle() {
         for ws_id in &affected_workspaces {
@@ -164,7 +175,11 @@ mod tests {
             window_ids: WindowIdList::new(),
             focused_window_index: None,
             split_ratios: Vec::new(),
+            master_count: None,
+            scroll_offset: 0,
             configured_screen: None,
+            claimed_app_ids: Vec::new(),
+            is_scratchpad: false,
         };
         let ws_id = ws.id;
         state.upsert_workspace(ws);
@@ -179,6 +194,8 @@ mod tests {
             app_id: format!("com.test.app{pid}"),
             app_name: format!("App {pid}"),
             title: format!("Window {id}"),
+            role: None,
+            subrole: None,
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             minimum_size: None,
             inferred_minimum_size: None,
@@ -188,6 +205,8 @@ mod tests {
             is_fullscreen: false,
             is_hidden: false,
             is_floating: false,
+            is_sticky: false,
+            always_on_top: None,
             tab_group_id: None,
             is_active_tab: true,
             matched_rule: None,
@@ -230,6 +249,31 @@ mod tests {
         assert!(affected.contains(&ws_id));
     }
 
+    #[test]
+    fn test_app_terminated_keeps_empty_workspace_with_stay() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        let mut other = Workspace::new("other");
+        other.screen_id = 1;
+        let other_id = other.id;
+        state.upsert_workspace(other);
+        state.update_workspace(other_id, |ws| ws.is_visible = false);
+
+        state.upsert_window(make_window(100, 1000, ws_id));
+        state.update_workspace(ws_id, |ws| ws.window_ids = smallvec![100]);
+        state.set_focused_workspace(Some(other_id));
+        state.set_focused_workspace(Some(ws_id));
+        state.set_focused_window(Some(100));
+
+        // `tiling.onEmptyWorkspace` defaults to "stay"
+        on_app_terminated(&mut state, 1000);
+
+        let ws = state.get_workspace(ws_id).unwrap();
+        assert!(ws.window_ids.is_empty());
+        assert!(ws.is_visible);
+        assert!(!state.get_workspace(other_id).unwrap().is_visible);
+        assert!(state.get_focus_state().focused_window_id.is_none());
+    }
+
     #[test]
     fn test_app_hidden_marks_windows_hidden() {
         let (mut state, ws_id) = make_state_with_workspace();
@@ -1,12 +1,56 @@
 //! Focus command handlers.
 //!
-//! These handlers manage focus cycling, directional focus, and swapping
-//! windows in a direction.
-
-use crate::modules::tiling::actor::{CycleDirection, FocusDirection};
+//! These handlers manage focus cycling, directional focus, focusing a window by
+//! position, jumping back to the previously focused window, and swapping
+//! windows in a direction. Directional focus can optionally cross screens
+//! (`tiling.focusWraps`), and the pointer can follow the focused window
+//! (`tiling.mouseFollowsFocus`).
+
+use crate::config::get_config;
+use crate::modules::tiling::actor::{
+    CommandFailed, CommandOutcome, CycleDirection, FocusDirection, FocusIndexOutcome,
+};
+use crate::modules::tiling::effects::window_ops;
+use crate::modules::tiling::events::{drag_state, mouse_monitor};
 use crate::modules::tiling::init::get_subscriber_handle;
 use crate::modules::tiling::state::{Rect, TilingState};
 
+// ============================================================================
+// Applying Focus
+// ============================================================================
+
+/// Focuses a window via the AX API.
+///
+/// With `tiling.mouseFollowsFocus`, the pointer also moves to the window's
+/// center, unless a drag is in progress.
+fn apply_focus(state: &TilingState, window_id: u32) {
+    let _ = window_ops::focus_window(window_id);
+
+    if !get_config().tiling.mouse_follows_focus
+        || mouse_monitor::is_mouse_down()
+        || drag_state::is_operation_in_progress()
+    {
+        return;
+    }
+
+    let Some(window) = state.get_window(window_id) else {
+        return;
+    };
+    let frame = window.expected_frame.unwrap_or(window.frame);
+    if let Some((x, y)) = pointer_target(&frame, mouse_monitor::cursor_position()) {
+        mouse_monitor::warp_cursor(x, y);
+    }
+}
+
+/// Returns where the pointer should move to follow focus to a window,
+/// or `None` when it's already over the window.
+fn pointer_target(frame: &Rect, cursor: Option<(f64, f64)>) -> Option<(f64, f64)> {
+    if cursor.is_some_and(|(x, y)| frame.contains_point(x, y)) {
+        return None;
+    }
+    Some(frame.center())
+}
+
 // ============================================================================
 // Focus Cycling
 // ============================================================================
@@ -77,7 +121,7 @@ pub fn on_cycle_focus(state: &mut TilingState, direction: CycleDirection) {
     }
 
     // Actually focus the window via AX API
-    let _ = crate::modules::tiling::effects::window_ops::focus_window(next_window_id);
+    apply_focus(state, next_window_id);
 }
 
 // ============================================================================
@@ -87,7 +131,10 @@ pub fn on_cycle_focus(state: &mut TilingState, direction: CycleDirection) {
 /// Focus a window in a direction.
 ///
 /// Supports both spatial directions (up/down/left/right) and cycling (next/previous).
-pub fn on_focus_window(state: &mut TilingState, direction: FocusDirection) {
+/// With `wraps`, spatial focus that finds no window on the focused workspace
+/// moves to the visible workspace of the adjacent screen in that direction,
+/// wrapping around to the screen at the opposite edge.
+pub fn on_focus_window(state: &mut TilingState, direction: FocusDirection, wraps: bool) {
     tracing::debug!("on_focus_window called with direction={direction:?}");
 
     // For next/previous, delegate to cycle_focus
@@ -108,37 +155,23 @@ pub fn on_focus_window(state: &mut TilingState, direction: FocusDirection) {
         return;
     };
 
-    let Some(focused_window_id) = focus.focused_window_id else {
-        tracing::debug!("focus_window: no focused window in state");
-        return;
-    };
-
     let Some(workspace) = state.get_workspace(workspace_id) else {
         tracing::debug!("focus_window: workspace {workspace_id} not found");
         return;
     };
 
     // Get layoutable windows only
-    let layoutable: Vec<u32> = workspace
-        .window_ids
-        .iter()
-        .filter(|&&id| state.get_window(id).is_some_and(|w| w.is_layoutable()))
-        .copied()
-        .collect();
-
-    if layoutable.len() < 2 {
-        tracing::debug!("focus_window: need at least 2 windows for spatial focus");
-        return;
-    }
+    let layoutable = layoutable_window_ids(state, &workspace.window_ids);
 
     // Get current window's frame
-    let Some(from_window) = state.get_window(focused_window_id) else {
-        tracing::debug!("focus_window: focused window {focused_window_id} not found in state");
-        return;
-    };
+    let from_frame = focus
+        .focused_window_id
+        .and_then(|id| state.get_window(id))
+        .map(|window| window.frame);
 
     // Find best candidate in the direction
-    let candidate = find_window_in_direction(state, &from_window.frame, direction, &layoutable);
+    let candidate = from_frame
+        .and_then(|frame| find_window_in_direction(state, &frame, direction, &layoutable));
 
     if let Some(target_window_id) = candidate {
         // Update focus state
@@ -161,12 +194,113 @@ pub fn on_focus_window(state: &mut TilingState, direction: FocusDirection) {
         }
 
         // Actually focus the window via AX API
-        let _ = crate::modules::tiling::effects::window_ops::focus_window(target_window_id);
-    } else {
+        apply_focus(state, target_window_id);
+        return;
+    }
+
+    if !wraps {
         tracing::debug!("focus_window: no window found in direction {direction:?}");
+        return;
+    }
+
+    // Without a focused window, search from the middle of the screen
+    let from_frame =
+        from_frame.or_else(|| state.get_screen(workspace.screen_id).map(|screen| screen.frame));
+    let target = from_frame.and_then(|frame| {
+        find_window_across_screens(state, workspace.screen_id, &frame, direction)
+    });
+
+    let Some(target_window_id) = target else {
+        tracing::debug!("focus_window: no window found on any screen {direction:?}");
+        return;
+    };
+
+    if focus.focused_window_id == Some(target_window_id) {
+        return;
+    }
+
+    tracing::debug!("Focused window {target_window_id} across screens ({direction:?})");
+
+    // Moves focus (and the focused workspace) to the target's screen
+    super::window::on_window_focused(state, target_window_id);
+
+    // Actually focus the window via AX API
+    apply_focus(state, target_window_id);
+}
+
+// ============================================================================
+// Focus Last
+// ============================================================================
+
+/// Focus the previously focused window.
+///
+/// Switches to the window's workspace when it is hidden. The window losing
+/// focus becomes the previous one, so repeating this toggles between two windows.
+pub fn on_focus_last_window(state: &mut TilingState) {
+    let Some(window_id) = state.get_previous_window() else {
+        tracing::debug!("focus_last_window: no previously focused window");
+        return;
+    };
+
+    tracing::debug!("Focusing previously focused window {window_id}");
+
+    // Moves focus (and the focused workspace) to the window, revealing its workspace
+    super::window::on_window_focused(state, window_id);
+
+    // Actually focus the window via AX API
+    apply_focus(state, window_id);
+}
+
+// ============================================================================
+// Focus by Position
+// ============================================================================
+
+/// Returns the 0-based position for a 1-based `index` among `count` windows.
+///
+/// Out of range indexes wrap around when `wraps` is set.
+const fn window_position(index: usize, count: usize, wraps: bool) -> Option<usize> {
+    if index == 0 || count == 0 {
+        return None;
+    }
+    if index <= count {
+        Some(index - 1)
+    } else if wraps {
+        Some((index - 1) % count)
+    } else {
+        None
     }
 }
 
+/// Focus the window at a 1-based position in the focused workspace.
+///
+/// Positions follow the workspace's window order, counting only windows in
+/// the layout, like focus cycling.
+pub fn on_focus_window_at(
+    state: &mut TilingState,
+    index: usize,
+    wraps: bool,
+) -> CommandOutcome<FocusIndexOutcome> {
+    let Some(workspace) = state.get_focused_workspace() else {
+        return Err(CommandFailed::new("No focused workspace"));
+    };
+
+    let layoutable = layoutable_window_ids(state, &workspace.window_ids);
+    let Some(position) = window_position(index, layoutable.len(), wraps) else {
+        return Err(CommandFailed::new(format!(
+            "No window at position {index} (the workspace has {})",
+            layoutable.len()
+        )));
+    };
+
+    let window_id = layoutable[position];
+    tracing::debug!("Focusing window {window_id} at position {index}");
+
+    super::window::on_window_focused(state, window_id);
+    apply_focus(state, window_id);
+
+    Ok(FocusIndexOutcome::Focused { window_id, index: position + 1 })
+}
+
 // ============================================================================
 // Swap in Direction
 // ============================================================================
@@ -265,9 +399,126 @@ pub fn on_swap_window_in_direction(state: &mut TilingState, direction: FocusDire
 }
 
 // ============================================================================
-// Direction Helper
+// Direction Helpers
 // ============================================================================
 
+/// Returns the layoutable windows among `window_ids`, keeping their order.
+fn layoutable_window_ids(state: &TilingState, window_ids: &[u32]) -> Vec<u32> {
+    window_ids
+        .iter()
+        .filter(|&&id| state.get_window(id).is_some_and(|w| w.is_layoutable()))
+        .copied()
+        .collect()
+}
+
+/// Returns whether `to` lies in `direction` from `from` (both are centers).
+fn is_in_direction(from: (f64, f64), to: (f64, f64), direction: FocusDirection) -> bool {
+    match direction {
+        FocusDirection::Up => to.1 < from.1,
+        FocusDirection::Down => to.1 > from.1,
+        FocusDirection::Left => to.0 < from.0,
+        FocusDirection::Right => to.0 > from.0,
+        _ => false,
+    }
+}
+
+/// Returns the squared distance between two centers, penalizing misalignment
+/// with the direction.
+fn weighted_distance(from: (f64, f64), to: (f64, f64), direction: FocusDirection) -> f64 {
+    let dx = to.0 - from.0;
+    let dy = to.1 - from.1;
+    let distance = dx * dx + dy * dy;
+
+    match direction {
+        FocusDirection::Up | FocusDirection::Down => {
+            // Prefer vertically aligned windows
+            let alignment_penalty = dx.abs() * 2.0;
+            distance + alignment_penalty * alignment_penalty
+        }
+        FocusDirection::Left | FocusDirection::Right => {
+            // Prefer horizontally aligned windows
+            let alignment_penalty = dy.abs() * 2.0;
+            distance + alignment_penalty * alignment_penalty
+        }
+        _ => distance,
+    }
+}
+
+/// Find the window to focus on the next screen in a direction.
+///
+/// Picks the nearest screen whose center lies in `direction`, or wraps around
+/// to the screen furthest in the opposite direction (which may be the current
+/// one). On that screen's visible workspace, returns the window closest to
+/// the edge focus enters from, preferring windows aligned with `from_frame`.
+#[must_use]
+pub fn find_window_across_screens(
+    state: &TilingState,
+    from_screen_id: u32,
+    from_frame: &Rect,
+    direction: FocusDirection,
+) -> Option<u32> {
+    let from_screen = state.get_screen(from_screen_id)?;
+    let from_center = from_screen.frame.center();
+
+    let adjacent = state
+        .screens
+        .iter()
+        .filter(|screen| screen.id != from_screen_id)
+        .filter(|screen| is_in_direction(from_center, screen.frame.center(), direction))
+        .min_by(|a, b| {
+            let a = weighted_distance(from_center, a.frame.center(), direction);
+            let b = weighted_distance(from_center, b.frame.center(), direction);
+            a.total_cmp(&b)
+        });
+
+    // Wrap around: the screen furthest against the direction
+    let target_screen = adjacent.or_else(|| {
+        state.screens.iter().max_by(|a, b| {
+            let a = entry_position(&a.frame, direction);
+            let b = entry_position(&b.frame, direction);
+            a.total_cmp(&b)
+        })
+    })?;
+
+    let workspace = state
+        .get_visible_workspaces()
+        .into_iter()
+        .find(|ws| ws.screen_id == target_screen.id)?;
+
+    let (from_x, from_y) = from_frame.center();
+    layoutable_window_ids(state, &workspace.window_ids)
+        .into_iter()
+        .filter_map(|id| state.get_window(id).map(|window| (id, window.frame)))
+        .min_by(|(_, a), (_, b)| {
+            let offset = |frame: &Rect| {
+                let (x, y) = frame.center();
+                match direction {
+                    FocusDirection::Up | FocusDirection::Down => (x - from_x).abs(),
+                    _ => (y - from_y).abs(),
+                }
+            };
+            // Furthest toward the entry edge first, then best aligned
+            entry_position(b, direction)
+                .total_cmp(&entry_position(a, direction))
+                .then_with(|| offset(a).total_cmp(&offset(b)))
+        })
+        .map(|(id, _)| id)
+}
+
+/// Returns how far a frame's near edge sits against `direction`.
+///
+/// Larger values are closer to the edge that focus enters from when moving
+/// in `direction` (e.g. the left edge when moving right).
+fn entry_position(frame: &Rect, direction: FocusDirection) -> f64 {
+    match direction {
+        FocusDirection::Right => -frame.x,
+        FocusDirection::Left => frame.x + frame.width,
+        FocusDirection::Down => -frame.y,
+        FocusDirection::Up => frame.y + frame.height,
+        _ => 0.0,
+    }
+}
+
 /// Find the nearest window in a spatial direction.
 ///
 /// Uses weighted distance to prefer windows that are more aligned with the direction.
@@ -281,8 +532,7 @@ pub fn find_window_in_direction(
     direction: FocusDirection,
     window_ids: &[u32],
 ) -> Option<u32> {
-    let from_center_x = from_frame.x + from_frame.width / 2.0;
-    let from_center_y = from_frame.y + from_frame.height / 2.0;
+    let from_center = from_frame.center();
 
     let mut best_candidate: Option<(u32, f64)> = None;
 
@@ -300,41 +550,15 @@ pub fn find_window_in_direction(
             continue;
         }
 
-        let center_x = window.frame.x + window.frame.width / 2.0;
-        let center_y = window.frame.y + window.frame.height / 2.0;
+        let center = window.frame.center();
 
         // Check if this window is in the right direction
-        let is_valid = match direction {
-            FocusDirection::Up => center_y < from_center_y,
-            FocusDirection::Down => center_y > from_center_y,
-            FocusDirection::Left => center_x < from_center_x,
-            FocusDirection::Right => center_x > from_center_x,
-            _ => false,
-        };
-
-        if !is_valid {
+        if !is_in_direction(from_center, center, direction) {
             continue;
         }
 
-        // Calculate distance
-        let dx = center_x - from_center_x;
-        let dy = center_y - from_center_y;
-        let distance = dx * dx + dy * dy;
-
-        // Apply alignment penalty (prefer windows aligned with direction)
-        let weighted_distance = match direction {
-            FocusDirection::Up | FocusDirection::Down => {
-                // Prefer vertically aligned windows
-                let alignment_penalty = dx.abs() * 2.0;
-                distance + alignment_penalty * alignment_penalty
-            }
-            FocusDirection::Left | FocusDirection::Right => {
-                // Prefer horizontally aligned windows
-                let alignment_penalty = dy.abs() * 2.0;
-                distance + alignment_penalty * alignment_penalty
-            }
-            _ => distance,
-        };
+        // Distance with an alignment penalty (prefer windows aligned with direction)
+        let weighted_distance = weighted_distance(from_center, center, direction);
 
         if best_candidate.is_none() || weighted_distance < best_candidate.unwrap().1 {
             best_candidate = Some((window_id, weighted_distance));
@@ -423,6 +647,53 @@ mod tests {
         assert_eq!(state.get_focus_state().focused_window_id, Some(300));
     }
 
+    #[test]
+    fn test_pointer_target() {
+        let frame = Rect::new(100.0, 100.0, 800.0, 600.0);
+
+        assert_eq!(pointer_target(&frame, Some((10.0, 10.0))), Some((500.0, 400.0)));
+        assert_eq!(pointer_target(&frame, None), Some((500.0, 400.0)));
+        assert_eq!(pointer_target(&frame, Some((300.0, 200.0))), None);
+    }
+
+    #[test]
+    fn test_window_position() {
+        assert_eq!(window_position(1, 3, false), Some(0));
+        assert_eq!(window_position(3, 3, false), Some(2));
+        assert_eq!(window_position(4, 3, false), None);
+        assert_eq!(window_position(4, 3, true), Some(0));
+        assert_eq!(window_position(8, 3, true), Some(1));
+        assert_eq!(window_position(0, 3, true), None);
+        assert_eq!(window_position(1, 0, true), None);
+    }
+
+    #[test]
+    fn test_focus_window_at() {
+        let mut state = create_test_state();
+        add_window(&mut state, 100, 0.0, 0.0, 400.0, 300.0);
+        add_window(&mut state, 200, 400.0, 0.0, 400.0, 300.0);
+        add_window(&mut state, 300, 0.0, 300.0, 800.0, 300.0);
+
+        let outcome = on_focus_window_at(&mut state, 2, false);
+        assert_eq!(
+            outcome,
+            Ok(FocusIndexOutcome::Focused { window_id: 200, index: 2 })
+        );
+        assert_eq!(state.get_focus_state().focused_window_id, Some(200));
+
+        assert!(matches!(
+            on_focus_window_at(&mut state, 5, false),
+            Err(CommandFailed { .. })
+        ));
+        assert_eq!(state.get_focus_state().focused_window_id, Some(200));
+
+        let outcome = on_focus_window_at(&mut state, 4, true);
+        assert_eq!(
+            outcome,
+            Ok(FocusIndexOutcome::Focused { window_id: 100, index: 1 })
+        );
+    }
+
     #[test]
     fn test_find_window_in_direction() {
         let mut state = create_test_state();
@@ -456,4 +727,113 @@ mod tests {
         let result = find_window_in_direction(&state, &from_frame, FocusDirection::Up, &window_ids);
         assert_eq!(result, Some(2));
     }
+
+    /// Two side-by-side 1920x1080 screens, each showing a workspace with two
+    /// columns: windows 100 and 200 on the left screen, 300 and 400 on the right.
+    fn create_two_screen_state() -> (TilingState, [uuid::Uuid; 2]) {
+        let mut state = TilingState::new();
+        let mut workspace_ids = [uuid::Uuid::nil(); 2];
+
+        for (index, screen_id) in [1_u32, 2].into_iter().enumerate() {
+            let x = f64::from(screen_id - 1) * 1920.0;
+            state.upsert_screen(Screen {
+                id: screen_id,
+                name: format!("Screen {screen_id}"),
+                frame: Rect::new(x, 0.0, 1920.0, 1080.0),
+                is_main: screen_id == 1,
+                ..Default::default()
+            });
+
+            let mut ws = Workspace::new(format!("workspace{screen_id}"));
+            ws.screen_id = screen_id;
+            ws.is_visible = true;
+            ws.is_focused = screen_id == 1;
+            workspace_ids[index] = ws.id;
+            state.upsert_workspace(ws);
+
+            for column in 0..2_u32 {
+                let window_id = (screen_id - 1) * 200 + (column + 1) * 100;
+                state.upsert_window(Window {
+                    id: window_id,
+                    workspace_id: workspace_ids[index],
+                    frame: Rect::new(x + f64::from(column) * 960.0, 0.0, 960.0, 1080.0),
+                    ..Default::default()
+                });
+                state.update_workspace(workspace_ids[index], |ws| ws.window_ids.push(window_id));
+            }
+        }
+
+        state.update_focus(|focus| {
+            focus.focused_workspace_id = Some(workspace_ids[0]);
+            focus.focused_screen_id = Some(1);
+            focus.focused_window_id = Some(200);
+        });
+
+        (state, workspace_ids)
+    }
+
+    #[test]
+    fn test_focus_window_without_wraps_stays_on_screen() {
+        let (mut state, [ws1_id, _]) = create_two_screen_state();
+
+        on_focus_window(&mut state, FocusDirection::Right, false);
+
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_window_id, Some(200));
+        assert_eq!(focus.focused_workspace_id, Some(ws1_id));
+    }
+
+    #[test]
+    fn test_focus_window_wraps_to_adjacent_screen() {
+        let (mut state, [ws1_id, ws2_id]) = create_two_screen_state();
+
+        // Rightmost window on the left screen -> leftmost window on the right screen
+        on_focus_window(&mut state, FocusDirection::Right, true);
+
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_window_id, Some(300));
+        assert_eq!(focus.focused_workspace_id, Some(ws2_id));
+        assert_eq!(focus.focused_screen_id, Some(2));
+        assert!(state.get_workspace(ws2_id).unwrap().is_focused);
+        assert!(!state.get_workspace(ws1_id).unwrap().is_focused);
+        // Both workspaces stay visible on their own screens
+        assert!(state.get_workspace(ws1_id).unwrap().is_visible);
+
+        // And back again, entering from the right edge
+        on_focus_window(&mut state, FocusDirection::Left, true);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(200));
+    }
+
+    #[test]
+    fn test_focus_window_wraps_around_outermost_screen() {
+        let (mut state, [ws1_id, _]) = create_two_screen_state();
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        // Nothing is left of the left screen, so focus wraps to the right edge
+        on_focus_window(&mut state, FocusDirection::Left, true);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(400));
+
+        on_focus_window(&mut state, FocusDirection::Right, true);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(100));
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(ws1_id));
+    }
+
+    #[test]
+    fn test_find_window_across_screens_prefers_aligned_window() {
+        let (mut state, [_, ws2_id]) = create_two_screen_state();
+
+        // Split the right screen's left column into a top and bottom window
+        state.update_window(300, |w| w.frame = Rect::new(1920.0, 0.0, 960.0, 540.0));
+        state.upsert_window(Window {
+            id: 500,
+            workspace_id: ws2_id,
+            frame: Rect::new(1920.0, 540.0, 960.0, 540.0),
+            ..Default::default()
+        });
+        state.update_workspace(ws2_id, |ws| ws.window_ids.push(500));
+
+        let bottom = Rect::new(960.0, 600.0, 960.0, 480.0);
+        let result = find_window_across_screens(&state, 1, &bottom, FocusDirection::Right);
+        assert_eq!(result, Some(500));
+    }
 }
@@ -1,9 +1,14 @@
 //! Layout command handlers.
 //!
-//! These handlers manage layout switching and cycling.
+//! These handlers manage layout switching and cycling, copying layouts between
+//! workspaces, and switching the gaps of all workspaces to a preset.
 
 use uuid::Uuid;
 
+use crate::modules::tiling::actor::{
+    CommandFailed, CommandOutcome, CopyLayoutOutcome, GapPresetChange, GapPresetOutcome,
+    MasterCountChange,
+};
 use crate::modules::tiling::init::get_subscriber_handle;
 use crate::modules::tiling::state::{LayoutType, TilingState};
 
@@ -29,27 +34,191 @@ pub fn on_set_layout(state: &mut TilingState, workspace_id: Uuid, layout: Layout
 }
 
 /// Cycle through layouts for a workspace.
-pub fn on_cycle_layout(state: &mut TilingState, workspace_id: Uuid) {
+///
+/// Advances to the entry after the current layout in `cycle`, wrapping
+/// around. Layouts that aren't in `cycle` start from its first entry. An
+/// empty `cycle` goes through all layouts.
+pub fn on_cycle_layout(state: &mut TilingState, workspace_id: Uuid, cycle: &[LayoutType]) {
     let Some(workspace) = state.get_workspace(workspace_id) else {
         tracing::warn!("cycle_layout: workspace {workspace_id} not found");
         return;
     };
 
+    if !cycle.is_empty() {
+        let next_layout = cycle
+            .iter()
+            .position(|&layout| layout == workspace.layout)
+            .map_or(cycle[0], |index| cycle[(index + 1) % cycle.len()]);
+
+        on_set_layout(state, workspace_id, next_layout);
+        tracing::debug!("Cycled workspace {workspace_id} layout to {next_layout:?}");
+        return;
+    }
+
     let next_layout = match workspace.layout {
         LayoutType::Floating => LayoutType::Dwindle,
         LayoutType::Dwindle => LayoutType::Monocle,
-        LayoutType::Monocle => LayoutType::Master,
+        LayoutType::Monocle => LayoutType::Tabbed,
+        LayoutType::Tabbed => LayoutType::Master,
         LayoutType::Master => LayoutType::Split,
         LayoutType::Split => LayoutType::SplitVertical,
         LayoutType::SplitVertical => LayoutType::SplitHorizontal,
         LayoutType::SplitHorizontal => LayoutType::Grid,
-        LayoutType::Grid => LayoutType::Floating,
+        LayoutType::Grid => LayoutType::Columns,
+        LayoutType::Columns => LayoutType::Scrolling,
+        LayoutType::Scrolling => LayoutType::Floating,
     };
 
     on_set_layout(state, workspace_id, next_layout);
     tracing::debug!("Cycled workspace {workspace_id} layout to {next_layout:?}");
 }
 
+/// Copy the layout and split ratios of the workspace named `source` to the
+/// focused workspace.
+///
+/// With `swap`, `source` takes the focused workspace's layout and ratios in
+/// return. Both workspaces are re-laid out.
+pub fn on_copy_layout(
+    state: &mut TilingState,
+    source: &str,
+    swap: bool,
+) -> CommandOutcome<CopyLayoutOutcome> {
+    let Some(target) = state.get_focused_workspace() else {
+        return Err(CommandFailed::new("No focused workspace"));
+    };
+
+    let Some(source_ws) = state.get_workspace_by_name(source) else {
+        return Err(CommandFailed::new(format!("Workspace '{source}' not found")));
+    };
+
+    if source_ws.id == target.id {
+        return Err(CommandFailed::new(format!(
+            "Workspace '{source}' is the focused workspace"
+        )));
+    }
+
+    state.update_workspace(target.id, |ws| {
+        ws.layout = source_ws.layout;
+        ws.split_ratios.clone_from(&source_ws.split_ratios);
+    });
+    let mut changed = vec![(target.id, source_ws.layout)];
+
+    if swap {
+        state.update_workspace(source_ws.id, |ws| {
+            ws.layout = target.layout;
+            ws.split_ratios.clone_from(&target.split_ratios);
+        });
+        changed.push((source_ws.id, target.layout));
+    }
+
+    tracing::debug!(
+        "Copied layout {:?} from '{}' to '{}' (swap: {swap})",
+        source_ws.layout,
+        source_ws.name,
+        target.name
+    );
+
+    if let Some(handle) = get_subscriber_handle() {
+        for (workspace_id, layout) in changed {
+            handle.notify_workspace_layout_changed(workspace_id, layout);
+            handle.notify_layout_changed(workspace_id, true);
+        }
+    }
+
+    Ok(CopyLayoutOutcome::Copied {
+        source: source_ws.name,
+        target: target.name,
+        layout: source_ws.layout,
+        swapped: swap,
+    })
+}
+
+/// Change the number of master windows in the focused workspace.
+///
+/// The new count is stored on the workspace and overrides `default_count`
+/// (the configured `maxMasters`) until the app restarts.
+pub fn on_adjust_master_count(
+    state: &mut TilingState,
+    change: MasterCountChange,
+    default_count: usize,
+) {
+    let Some(workspace_id) = state.get_focus_state().focused_workspace_id else {
+        tracing::debug!("adjust_master_count: no focused workspace");
+        return;
+    };
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::warn!("adjust_master_count: workspace {workspace_id} not found");
+        return;
+    };
+
+    let current = workspace.master_count.unwrap_or(default_count);
+    let master_count = change.apply(current);
+    state.update_workspace(workspace_id, |ws| {
+        ws.master_count = Some(master_count);
+    });
+
+    tracing::debug!("Set workspace {workspace_id} master count to {master_count}");
+
+    // Only master layouts need to be re-applied
+    if workspace.layout == LayoutType::Master
+        && let Some(handle) = get_subscriber_handle()
+    {
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
+/// Switch the gaps of all workspaces to a preset, or back to the configured gaps.
+///
+/// `presets` are the names of the `tiling.gapPresets` entries, in the order
+/// cycled through. Layouts of visible workspaces are re-applied with the new
+/// gaps; hidden workspaces pick them up when shown.
+pub fn on_set_gap_preset(
+    state: &mut TilingState,
+    change: GapPresetChange,
+    presets: &[String],
+) -> CommandOutcome<GapPresetOutcome> {
+    let preset = match change {
+        GapPresetChange::Reset => None,
+        GapPresetChange::Cycle => {
+            if presets.is_empty() {
+                return Err(CommandFailed::new("No gap presets in tiling.gapPresets"));
+            }
+            let next = state
+                .gap_preset()
+                .and_then(|current| presets.iter().position(|name| name == current))
+                .map_or(0, |index| (index + 1) % presets.len());
+            Some(presets[next].clone())
+        }
+        GapPresetChange::Set(name) => {
+            if !presets.contains(&name) {
+                let available = if presets.is_empty() {
+                    "none".to_string()
+                } else {
+                    presets.join(", ")
+                };
+                return Err(CommandFailed::new(format!(
+                    "Unknown gap preset '{name}'. Available: {available}"
+                )));
+            }
+            Some(name)
+        }
+    };
+
+    state.set_gap_preset(preset.clone());
+    tracing::debug!("Set gap preset to {preset:?}");
+
+    if let Some(handle) = get_subscriber_handle() {
+        let visible: Vec<Uuid> =
+            state.workspaces.iter().filter(|ws| ws.is_visible).map(|ws| ws.id).collect();
+        for workspace_id in visible {
+            handle.notify_layout_changed(workspace_id, true);
+        }
+    }
+
+    Ok(GapPresetOutcome::Applied { preset })
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -98,6 +267,69 @@ mod tests {
         assert_eq!(ws.layout, LayoutType::Master);
     }
 
+    fn add_workspace(state: &mut TilingState, name: &str, layout: LayoutType) -> Uuid {
+        let mut ws = Workspace::new(name);
+        ws.screen_id = 1;
+        ws.layout = layout;
+        ws.split_ratios = vec![0.3];
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+        ws_id
+    }
+
+    #[test]
+    fn test_copy_layout() {
+        let (mut state, ws_id) = create_test_state();
+        on_set_layout(&mut state, ws_id, LayoutType::Dwindle);
+        let other_id = add_workspace(&mut state, "other", LayoutType::Master);
+
+        let outcome = on_copy_layout(&mut state, "other", false);
+        assert_eq!(
+            outcome,
+            Ok(CopyLayoutOutcome::Copied {
+                source: "other".to_string(),
+                target: "workspace1".to_string(),
+                layout: LayoutType::Master,
+                swapped: false,
+            })
+        );
+
+        let ws = state.get_workspace(ws_id).unwrap();
+        assert_eq!(ws.layout, LayoutType::Master);
+        assert_eq!(ws.split_ratios, vec![0.3]);
+        assert_eq!(state.get_workspace(other_id).unwrap().layout, LayoutType::Master);
+    }
+
+    #[test]
+    fn test_swap_layout() {
+        let (mut state, ws_id) = create_test_state();
+        on_set_layout(&mut state, ws_id, LayoutType::Dwindle);
+        let other_id = add_workspace(&mut state, "other", LayoutType::Master);
+
+        on_copy_layout(&mut state, "other", true).unwrap();
+
+        let ws = state.get_workspace(ws_id).unwrap();
+        assert_eq!(ws.layout, LayoutType::Master);
+        assert_eq!(ws.split_ratios, vec![0.3]);
+        let other = state.get_workspace(other_id).unwrap();
+        assert_eq!(other.layout, LayoutType::Dwindle);
+        assert!(other.split_ratios.is_empty());
+    }
+
+    #[test]
+    fn test_copy_layout_requires_another_workspace() {
+        let (mut state, _) = create_test_state();
+
+        assert_eq!(
+            on_copy_layout(&mut state, "missing", false),
+            Err(CommandFailed::new("Workspace 'missing' not found"))
+        );
+        assert!(matches!(
+            on_copy_layout(&mut state, "workspace1", true),
+            Err(CommandFailed { .. })
+        ));
+    }
+
     #[test]
     fn test_cycle_layout() {
         let (mut state, ws_id) = create_test_state();
@@ -106,13 +338,131 @@ mod tests {
         on_set_layout(&mut state, ws_id, LayoutType::Floating);
 
         // Cycle through
-        on_cycle_layout(&mut state, ws_id);
+        on_cycle_layout(&mut state, ws_id, &[]);
         assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Dwindle);
 
-        on_cycle_layout(&mut state, ws_id);
+        on_cycle_layout(&mut state, ws_id, &[]);
         assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Monocle);
 
-        on_cycle_layout(&mut state, ws_id);
+        on_cycle_layout(&mut state, ws_id, &[]);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Tabbed);
+
+        on_cycle_layout(&mut state, ws_id, &[]);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Master);
+    }
+
+    #[test]
+    fn test_cycle_layout_custom_order() {
+        let (mut state, ws_id) = create_test_state();
+        let cycle = [LayoutType::Dwindle, LayoutType::Master, LayoutType::Monocle];
+
+        // Floating isn't in the cycle, so it starts from the first entry
+        on_set_layout(&mut state, ws_id, LayoutType::Floating);
+        on_cycle_layout(&mut state, ws_id, &cycle);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Dwindle);
+
+        on_cycle_layout(&mut state, ws_id, &cycle);
         assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Master);
+
+        on_cycle_layout(&mut state, ws_id, &cycle);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Monocle);
+
+        // Wraps around
+        on_cycle_layout(&mut state, ws_id, &cycle);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Dwindle);
+    }
+
+    #[test]
+    fn test_adjust_master_count_from_default() {
+        let (mut state, ws_id) = create_test_state();
+
+        on_adjust_master_count(&mut state, MasterCountChange::Adjust(1), 1);
+        assert_eq!(state.get_workspace(ws_id).unwrap().master_count, Some(2));
+
+        on_adjust_master_count(&mut state, MasterCountChange::Adjust(1), 1);
+        assert_eq!(state.get_workspace(ws_id).unwrap().master_count, Some(3));
+    }
+
+    #[test]
+    fn test_adjust_master_count_never_below_one() {
+        let (mut state, ws_id) = create_test_state();
+
+        on_adjust_master_count(&mut state, MasterCountChange::Adjust(-3), 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().master_count, Some(1));
+    }
+
+    #[test]
+    fn test_set_master_count() {
+        let (mut state, ws_id) = create_test_state();
+
+        on_adjust_master_count(&mut state, MasterCountChange::Set(4), 1);
+        assert_eq!(state.get_workspace(ws_id).unwrap().master_count, Some(4));
+    }
+
+    #[test]
+    fn test_master_count_survives_layout_change() {
+        let (mut state, ws_id) = create_test_state();
+
+        on_adjust_master_count(&mut state, MasterCountChange::Set(2), 1);
+        on_set_layout(&mut state, ws_id, LayoutType::Dwindle);
+        on_set_layout(&mut state, ws_id, LayoutType::Master);
+
+        assert_eq!(state.get_workspace(ws_id).unwrap().master_count, Some(2));
+    }
+
+    #[test]
+    fn test_set_gap_preset() {
+        let (mut state, _) = create_test_state();
+        let presets = ["airy".to_string(), "dense".to_string()];
+
+        let outcome = on_set_gap_preset(&mut state, GapPresetChange::Set("dense".into()), &presets);
+        assert_eq!(
+            outcome,
+            Ok(GapPresetOutcome::Applied {
+                preset: Some("dense".to_string())
+            })
+        );
+        assert_eq!(state.gap_preset(), Some("dense"));
+
+        let outcome = on_set_gap_preset(&mut state, GapPresetChange::Reset, &presets);
+        assert_eq!(outcome, Ok(GapPresetOutcome::Applied { preset: None }));
+        assert!(state.gap_preset().is_none());
+    }
+
+    #[test]
+    fn test_set_gap_preset_unknown_keeps_current() {
+        let (mut state, _) = create_test_state();
+        let presets = ["dense".to_string()];
+        on_set_gap_preset(&mut state, GapPresetChange::Set("dense".into()), &presets).unwrap();
+
+        let outcome = on_set_gap_preset(&mut state, GapPresetChange::Set("roomy".into()), &presets);
+        assert_eq!(
+            outcome,
+            Err(CommandFailed::new(
+                "Unknown gap preset 'roomy'. Available: dense"
+            ))
+        );
+        assert_eq!(state.gap_preset(), Some("dense"));
+    }
+
+    #[test]
+    fn test_cycle_gap_preset() {
+        let (mut state, _) = create_test_state();
+        let presets = ["airy".to_string(), "dense".to_string()];
+
+        on_set_gap_preset(&mut state, GapPresetChange::Cycle, &presets).unwrap();
+        assert_eq!(state.gap_preset(), Some("airy"));
+
+        on_set_gap_preset(&mut state, GapPresetChange::Cycle, &presets).unwrap();
+        assert_eq!(state.gap_preset(), Some("dense"));
+
+        // Wraps around
+        on_set_gap_preset(&mut state, GapPresetChange::Cycle, &presets).unwrap();
+        assert_eq!(state.gap_preset(), Some("airy"));
+
+        assert_eq!(
+            on_set_gap_preset(&mut state, GapPresetChange::Cycle, &[]),
+            Err(CommandFailed::new("No gap presets in tiling.gapPresets"))
+        );
     }
 }
@@ -8,35 +8,59 @@
 //! - `workspace` - Workspace switching, cycling, balancing
 //! - `layout` - Layout switching and cycling
 //! - `focus` - Focus cycling and directional focus
-//! - `window_move` - Moving windows between workspaces
+//! - `window_move` - Moving windows between workspaces, floating and sticky state
 //! - `preset` - Floating preset application
 //! - `resize` - Split ratio manipulation and window resizing
+//! - `scroll` - Scrolling layout viewport and focus-follows-scroll
+//! - `scratchpad` - Moving windows to the scratchpad and toggling it
 
 pub mod app;
 pub mod focus;
 pub mod layout;
 pub mod preset;
 pub mod resize;
+pub mod scratchpad;
 pub mod screen;
+pub mod scroll;
 pub mod window;
 pub mod window_move;
 pub mod workspace;
 
 // Re-export handler functions for convenience
 pub use app::{on_app_activated, on_app_hidden, on_app_launched, on_app_shown, on_app_terminated};
-pub use focus::{on_cycle_focus, on_focus_window, on_swap_window_in_direction};
-pub use layout::{on_cycle_layout, on_set_layout};
-pub use preset::on_apply_preset;
-pub use resize::{on_resize_focused_window, on_resize_split, on_user_resize_completed};
-pub use screen::{get_screens_from_macos, on_screens_changed, on_set_screens};
+pub use focus::{
+    on_cycle_focus, on_focus_last_window, on_focus_window, on_focus_window_at,
+    on_swap_window_in_direction,
+};
+pub use layout::{
+    on_adjust_master_count, on_copy_layout, on_cycle_layout, on_set_gap_preset, on_set_layout,
+};
+pub use preset::{
+    apply_preset_to_window, on_apply_preset, on_arrange_floating_windows,
+    on_move_window_in_direction, on_place_floating_window,
+};
+pub use resize::{
+    on_resize_focused_window, on_resize_split, on_set_split_ratio, on_user_resize_completed,
+};
+pub use scratchpad::{on_move_window_to_scratchpad, on_toggle_scratchpad};
+pub use screen::{
+    convert_layout_type, find_screen_by_name, get_screens_from_macos, on_screens_changed,
+    on_set_screens,
+};
+pub use scroll::{on_reveal_focused_column, on_scroll_workspace};
 pub use window::{
-    on_batched_geometry_updates, on_window_created, on_window_created_silent, on_window_destroyed,
-    on_window_focused, on_window_fullscreen_changed, on_window_minimized, on_window_moved,
-    on_window_resized, on_window_title_changed, on_window_unfocused,
+    on_batched_geometry_updates, on_set_minimized, on_window_created, on_window_created_silent,
+    on_window_destroyed, on_window_focused, on_window_fullscreen_changed, on_window_minimized,
+    on_window_moved, on_window_resized, on_window_title_changed, on_window_title_settled,
+    on_window_unfocused, release_windows_on_ignored_screens, untrack_window,
 };
 pub use window_move::{
-    on_move_window_to_workspace, on_send_window_to_screen, on_swap_windows, on_toggle_floating,
+    on_move_window_to_workspace, on_promote_window, on_send_window_to_screen,
+    on_send_window_to_workspace, on_set_always_on_top, on_set_floating, on_set_sticky,
+    on_swap_windows, on_toggle_floating,
 };
 pub use workspace::{
-    on_balance_workspace, on_cycle_workspace, on_send_workspace_to_screen, on_switch_workspace,
+    on_balance_workspace, on_claim_app, on_create_workspace, on_cycle_workspace,
+    on_focus_last_workspace, on_merge_workspace, on_rename_workspace, on_send_workspace_to_screen,
+    on_switch_workspace,
 };
@@ -1,8 +1,20 @@
 //! Floating preset command handlers.
 //!
-//! These handlers manage applying floating presets to windows.
-
-use crate::modules::tiling::state::{LayoutType, TilingState};
+//! These handlers manage applying floating presets to windows, and centering,
+//! maximizing, moving or arranging floating windows within the usable area of
+//! their screen.
+
+use super::focus::on_swap_window_in_direction;
+use crate::config::get_config;
+use crate::modules::tiling::actor::messages::{
+    ArrangeFloatingOutcome, CommandFailed, CommandOutcome, FloatingArrangement, FloatingPlacement,
+    FocusDirection, PlaceWindowOutcome,
+};
+use crate::modules::tiling::effects::{AnimationSystem, WindowTransition};
+use crate::modules::tiling::layout::{
+    Gaps, MAX_GRID_WINDOWS, calculate_layout_with_gaps, center_frame,
+};
+use crate::modules::tiling::state::{LayoutType, Rect, Screen, TilingState, Window, Workspace};
 
 // ============================================================================
 // Floating Preset Commands
@@ -17,17 +29,7 @@ use crate::modules::tiling::state::{LayoutType, TilingState};
 ///
 /// * `state` - The tiling state
 /// * `preset_name` - Name of the preset to apply (case-insensitive)
-#[allow(clippy::cast_possible_truncation)]
 pub fn on_apply_preset(state: &mut TilingState, preset_name: &str) {
-    use crate::config::get_config;
-    use crate::modules::tiling::layout::{Gaps, calculate_preset_frame, find_preset};
-
-    // Find the preset
-    let Some(preset) = find_preset(preset_name) else {
-        tracing::warn!("apply_preset: preset '{preset_name}' not found");
-        return;
-    };
-
     let focus = state.get_focus_state();
     let Some(workspace_id) = focus.focused_workspace_id else {
         tracing::debug!("apply_preset: no focused workspace");
@@ -55,43 +57,49 @@ pub fn on_apply_preset(state: &mut TilingState, preset_name: &str) {
         return;
     };
 
-    let Some(screen) = state.get_screen(workspace.screen_id) else {
-        tracing::debug!("apply_preset: screen not found");
-        return;
-    };
+    apply_preset_to_window(state, window_id, preset_name);
+}
 
-    // Get gaps from config
-    let config = get_config();
-    let bar_offset = if config.bar.is_enabled() {
-        f64::from(config.bar.height) + f64::from(config.bar.padding)
-    } else {
-        0.0
+/// Apply a floating preset to a window.
+///
+/// The frame is computed on the screen of the window's workspace, honoring
+/// the workspace gaps. Returns whether the preset was applied.
+///
+/// # Arguments
+///
+/// * `state` - The tiling state
+/// * `window_id` - The window to move
+/// * `preset_name` - Name of the preset to apply (case-insensitive)
+#[allow(clippy::cast_possible_truncation)]
+pub fn apply_preset_to_window(state: &mut TilingState, window_id: u32, preset_name: &str) -> bool {
+    use crate::modules::tiling::layout::{calculate_preset_frame, find_preset};
+
+    // Find the preset
+    let Some(preset) = find_preset(preset_name) else {
+        tracing::warn!("apply_preset: preset '{preset_name}' not found");
+        return false;
     };
-    let gaps = Gaps::from_config(&config.tiling.gaps, &screen.name, screen.is_main, bar_offset);
 
-    // Calculate the target frame
-    let target_frame = calculate_preset_frame(&preset, &screen.visible_frame, &gaps);
+    let Some(window) = state.get_window(window_id) else {
+        tracing::debug!("apply_preset: window {window_id} not found");
+        return false;
+    };
 
-    // Get current frame for animation
-    let current_frame = state.get_window(window_id).map(|w| w.frame);
+    let Some(workspace) = state.get_workspace(window.workspace_id) else {
+        tracing::debug!("apply_preset: workspace not found");
+        return false;
+    };
 
-    // Update window frame in state
-    state.update_window(window_id, |w| {
-        w.frame = target_frame;
-    });
+    let Some(screen) = state.get_screen(workspace.screen_id) else {
+        tracing::debug!("apply_preset: screen not found");
+        return false;
+    };
 
-    // Apply the frame with animation
-    if let Some(from_frame) = current_frame {
-        use crate::modules::tiling::effects::{AnimationSystem, WindowTransition};
+    // Calculate the target frame
+    let gaps = floating_gaps(state, &workspace, &screen);
+    let target_frame = calculate_preset_frame(&preset, &screen.visible_frame, &gaps);
 
-        let animation = AnimationSystem::from_config();
-        let transition = WindowTransition::new(window_id, from_frame, target_frame);
-        let _ = animation.animate(vec![transition]);
-    } else {
-        // Fallback: no current frame, just set directly
-        let _ =
-            crate::modules::tiling::effects::window_ops::set_window_frame(window_id, &target_frame);
-    }
+    move_floating_window(state, window_id, window.frame, target_frame);
 
     tracing::debug!(
         "Applied preset '{}' to window {window_id}: ({}, {}, {}, {})",
@@ -101,6 +109,285 @@ pub fn on_apply_preset(state: &mut TilingState, preset_name: &str) {
         target_frame.width as i32,
         target_frame.height as i32
     );
+
+    true
+}
+
+/// Center or maximize the focused floating window.
+///
+/// The window is placed within the usable area of its screen (the visible
+/// frame minus the workspace's outer gaps). Tiled windows are left alone and
+/// produce a failed outcome, since their frame belongs to the layout.
+///
+/// # Arguments
+///
+/// * `state` - The tiling state
+/// * `placement` - Whether to center or maximize the window
+pub fn on_place_floating_window(
+    state: &mut TilingState,
+    placement: FloatingPlacement,
+) -> CommandOutcome<PlaceWindowOutcome> {
+    let Some(window) = state.get_focused_window() else {
+        return Err(CommandFailed::new("No focused window"));
+    };
+
+    let Some(workspace) = state.get_workspace(window.workspace_id) else {
+        return Err(CommandFailed::new("Workspace not found"));
+    };
+
+    let Some(screen) = state.get_screen(workspace.screen_id) else {
+        return Err(CommandFailed::new("Screen not found"));
+    };
+
+    if !window.is_effectively_floating() && workspace.layout != LayoutType::Floating {
+        return Err(CommandFailed::new(format!(
+            "Window {} is tiled. Only floating windows can be {}d.",
+            window.id,
+            placement.as_str()
+        )));
+    }
+
+    let usable = floating_gaps(state, &workspace, &screen).apply_outer(&screen.visible_frame);
+    let target_frame = placement_frame(placement, &window.frame, &usable);
+
+    move_floating_window(state, window.id, window.frame, target_frame);
+
+    tracing::debug!(
+        "Placed window {} ({}) at {target_frame:?}",
+        window.id,
+        placement.as_str()
+    );
+
+    Ok(PlaceWindowOutcome::Placed {
+        window_id: window.id,
+        frame: target_frame,
+    })
+}
+
+/// Move the focused window in a direction.
+///
+/// Floating windows are shifted by `step` pixels (`floating.moveStep` when
+/// None), clamped to the usable area of their screen. Tiled windows are
+/// swapped with their neighbor in that direction instead.
+///
+/// # Arguments
+///
+/// * `state` - The tiling state
+/// * `direction` - Direction to move the window in (up/down/left/right)
+/// * `step` - Pixels to move a floating window by
+pub fn on_move_window_in_direction(
+    state: &mut TilingState,
+    direction: FocusDirection,
+    step: Option<u32>,
+) {
+    let Some(window) = state.get_focused_window() else {
+        tracing::debug!("move_in_direction: no focused window");
+        return;
+    };
+
+    let Some(workspace) = state.get_workspace(window.workspace_id) else {
+        return;
+    };
+
+    if !window.is_effectively_floating() && workspace.layout != LayoutType::Floating {
+        on_swap_window_in_direction(state, direction);
+        return;
+    }
+
+    let Some(screen) = state.get_screen(workspace.screen_id) else {
+        return;
+    };
+
+    let step = f64::from(step.unwrap_or(get_config().tiling.floating.move_step));
+    let usable = floating_gaps(state, &workspace, &screen).apply_outer(&screen.visible_frame);
+    let Some(target_frame) = moved_frame(&window.frame, direction, step, &usable) else {
+        tracing::debug!("move_in_direction: {direction:?} has no effect on floating windows");
+        return;
+    };
+
+    if target_frame == window.frame {
+        return;
+    }
+
+    move_floating_window(state, window.id, window.frame, target_frame);
+
+    tracing::debug!("Moved window {} {direction:?} to {target_frame:?}", window.id);
+}
+
+/// Cascade or tile the floating windows of the focused workspace.
+///
+/// Windows keep their floating state; only their frames change, within the
+/// usable area of the workspace's screen. Minimized and hidden windows, and
+/// tabs in the background of a tab group, are left alone.
+///
+/// # Arguments
+///
+/// * `state` - The tiling state
+/// * `arrangement` - Whether to cascade the windows or tile them in a grid
+pub fn on_arrange_floating_windows(
+    state: &mut TilingState,
+    arrangement: FloatingArrangement,
+) -> CommandOutcome<ArrangeFloatingOutcome> {
+    let Some(workspace) = state.get_focused_workspace() else {
+        return Err(CommandFailed::new("No focused workspace"));
+    };
+
+    let Some(screen) = state.get_screen(workspace.screen_id) else {
+        return Err(CommandFailed::new("Screen not found"));
+    };
+
+    let floating_layout = workspace.layout == LayoutType::Floating;
+    let windows: Vec<Window> = workspace
+        .window_ids
+        .iter()
+        .filter_map(|&id| state.get_window(id))
+        .filter(|w| {
+            (floating_layout || w.is_effectively_floating())
+                && !w.is_minimized
+                && !w.is_hidden
+                && (w.tab_group_id.is_none() || w.is_active_tab)
+        })
+        .collect();
+
+    if windows.is_empty() {
+        return Err(CommandFailed::new(format!(
+            "Workspace '{}' has no floating windows",
+            workspace.name
+        )));
+    }
+
+    let gaps = floating_gaps(state, &workspace, &screen);
+    let target_frames = match arrangement {
+        FloatingArrangement::Cascade => {
+            let frames: Vec<Rect> = windows.iter().map(|w| w.frame).collect();
+            cascade_frames(&frames, &gaps.apply_outer(&screen.visible_frame))
+        }
+        FloatingArrangement::Grid => grid_frames(windows.len(), &screen.visible_frame, &gaps),
+    };
+
+    let mut transitions = Vec::with_capacity(windows.len());
+    for (window, &target_frame) in windows.iter().zip(&target_frames) {
+        if target_frame == window.frame {
+            continue;
+        }
+        state.update_window(window.id, |w| {
+            w.frame = target_frame;
+        });
+        transitions.push(WindowTransition::new(window.id, window.frame, target_frame));
+    }
+
+    let animation = AnimationSystem::from_config();
+    let _ = animation.animate(transitions);
+
+    tracing::debug!(
+        "Arranged {} floating windows on '{}' ({})",
+        windows.len(),
+        workspace.name,
+        arrangement.as_str()
+    );
+
+    Ok(ArrangeFloatingOutcome::Arranged { count: windows.len() })
+}
+
+/// Shifts a frame by `step` pixels in a direction, keeping it inside the usable area.
+///
+/// Frames larger than the usable area are aligned to its top-left corner.
+/// Returns None for directions without a spatial meaning (next/previous).
+fn moved_frame(frame: &Rect, direction: FocusDirection, step: f64, usable: &Rect) -> Option<Rect> {
+    let (dx, dy) = match direction {
+        FocusDirection::Left => (-step, 0.0),
+        FocusDirection::Right => (step, 0.0),
+        FocusDirection::Up => (0.0, -step),
+        FocusDirection::Down => (0.0, step),
+        FocusDirection::Next | FocusDirection::Previous => return None,
+    };
+
+    let clamp = |value: f64, min: f64, max: f64| value.min(max).max(min);
+
+    Some(Rect::new(
+        clamp(frame.x + dx, usable.x, usable.x + usable.width - frame.width),
+        clamp(frame.y + dy, usable.y, usable.y + usable.height - frame.height),
+        frame.width,
+        frame.height,
+    ))
+}
+
+/// Computes the frame of a placed window within the usable area.
+fn placement_frame(placement: FloatingPlacement, frame: &Rect, usable: &Rect) -> Rect {
+    match placement {
+        FloatingPlacement::Center => center_frame(frame, usable),
+        FloatingPlacement::Maximize => *usable,
+    }
+}
+
+/// Offset between cascaded windows, in pixels.
+const CASCADE_STEP: f64 = 32.0;
+
+/// Cascades frames from the top-left corner of the usable area.
+///
+/// Each window keeps its size (shrunk to fit the usable area) and is offset
+/// by [`CASCADE_STEP`] from the previous one. When the next window would
+/// overflow the usable area, the cascade starts over from the corner.
+fn cascade_frames(frames: &[Rect], usable: &Rect) -> Vec<Rect> {
+    let mut offset = 0.0;
+    frames
+        .iter()
+        .map(|frame| {
+            let width = frame.width.min(usable.width);
+            let height = frame.height.min(usable.height);
+            if offset + width > usable.width || offset + height > usable.height {
+                offset = 0.0;
+            }
+
+            let cascaded = Rect::new(usable.x + offset, usable.y + offset, width, height);
+            offset += CASCADE_STEP;
+            cascaded
+        })
+        .collect()
+}
+
+/// Computes grid cells for `count` floating windows.
+///
+/// The grid layout has at most [`MAX_GRID_WINDOWS`] cells, so further
+/// windows wrap around and share cells with the first ones.
+fn grid_frames(count: usize, screen_frame: &Rect, gaps: &Gaps) -> Vec<Rect> {
+    let cell_ids: Vec<u32> = (0..).take(count.min(MAX_GRID_WINDOWS)).collect();
+    let mut cells =
+        calculate_layout_with_gaps(LayoutType::Grid, &cell_ids, screen_frame, 0.5, gaps);
+    cells.sort_by_key(|&(id, _)| id);
+    if cells.is_empty() {
+        return Vec::new();
+    }
+
+    (0..count).map(|i| cells[i % cells.len()].1).collect()
+}
+
+/// Gaps for floating windows on a workspace, including the bar offset.
+fn floating_gaps(state: &TilingState, workspace: &Workspace, screen: &Screen) -> Gaps {
+    let config = get_config();
+    let bar_offset = if config.bar.is_enabled() {
+        f64::from(config.bar.height) + f64::from(config.bar.padding)
+    } else {
+        0.0
+    };
+
+    Gaps::from_config_for_workspace(
+        &config.tiling.gaps,
+        config.tiling.gaps_override(&workspace.name, state.gap_preset()),
+        &screen.name,
+        screen.is_main,
+        bar_offset,
+    )
+}
+
+/// Stores a floating window's new frame and animates it there.
+fn move_floating_window(state: &mut TilingState, window_id: u32, from: Rect, to: Rect) {
+    state.update_window(window_id, |w| {
+        w.frame = to;
+    });
+
+    let animation = AnimationSystem::from_config();
+    let _ = animation.animate(vec![WindowTransition::new(window_id, from, to)]);
 }
 
 // ============================================================================
@@ -206,4 +493,216 @@ mod tests {
         // Try to apply invalid preset (should not panic)
         on_apply_preset(&mut state, "nonexistent_preset_xyz");
     }
+
+    #[test]
+    fn test_place_floating_window_rejects_tiled_window() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_workspace(ws_id, |ws| {
+            ws.layout = LayoutType::Dwindle;
+        });
+
+        let window = Window {
+            id: 100,
+            workspace_id: ws_id,
+            frame: Rect::new(100.0, 100.0, 400.0, 300.0),
+            ..Default::default()
+        };
+        state.upsert_window(window);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        let outcome = on_place_floating_window(&mut state, FloatingPlacement::Center);
+        assert!(matches!(
+            outcome,
+            Err(CommandFailed { ref reason }) if reason.contains("tiled")
+        ));
+
+        // The tiled window keeps its frame
+        assert_eq!(
+            state.get_window(100).unwrap().frame,
+            Rect::new(100.0, 100.0, 400.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn test_place_floating_window_no_window() {
+        let mut state = create_test_state();
+
+        let outcome = on_place_floating_window(&mut state, FloatingPlacement::Maximize);
+        assert_eq!(outcome, Err(CommandFailed::new("No focused window")));
+    }
+
+    #[test]
+    fn test_placement_frame() {
+        let usable = Rect::new(10.0, 40.0, 1900.0, 1030.0);
+        let frame = Rect::new(0.0, 0.0, 400.0, 300.0);
+
+        assert_eq!(
+            placement_frame(FloatingPlacement::Center, &frame, &usable),
+            Rect::new(760.0, 405.0, 400.0, 300.0)
+        );
+        assert_eq!(
+            placement_frame(FloatingPlacement::Maximize, &frame, &usable),
+            usable
+        );
+
+        // Windows larger than the usable area are shrunk to fit when centered
+        let large = Rect::new(0.0, 0.0, 2500.0, 300.0);
+        assert_eq!(
+            placement_frame(FloatingPlacement::Center, &large, &usable),
+            Rect::new(10.0, 405.0, 1900.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn test_move_window_in_direction_no_window() {
+        let mut state = create_test_state();
+
+        // Should not panic without a focused window
+        on_move_window_in_direction(&mut state, FocusDirection::Left, Some(20));
+    }
+
+    #[test]
+    fn test_move_window_in_direction_tiled_window_is_not_shifted() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_workspace(ws_id, |ws| {
+            ws.layout = LayoutType::Dwindle;
+        });
+
+        let window = Window {
+            id: 100,
+            workspace_id: ws_id,
+            frame: Rect::new(100.0, 100.0, 400.0, 300.0),
+            ..Default::default()
+        };
+        state.upsert_window(window);
+        state.update_workspace(ws_id, |ws| {
+            ws.window_ids.push(100);
+            ws.focused_window_index = Some(0);
+        });
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        // Tiled windows are swapped instead, and there is no neighbor here
+        on_move_window_in_direction(&mut state, FocusDirection::Left, Some(20));
+        assert_eq!(
+            state.get_window(100).unwrap().frame,
+            Rect::new(100.0, 100.0, 400.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn test_moved_frame() {
+        let usable = Rect::new(10.0, 40.0, 1900.0, 1030.0);
+        let frame = Rect::new(500.0, 400.0, 400.0, 300.0);
+
+        assert_eq!(
+            moved_frame(&frame, FocusDirection::Left, 50.0, &usable),
+            Some(Rect::new(450.0, 400.0, 400.0, 300.0))
+        );
+        assert_eq!(
+            moved_frame(&frame, FocusDirection::Down, 50.0, &usable),
+            Some(Rect::new(500.0, 450.0, 400.0, 300.0))
+        );
+        assert_eq!(moved_frame(&frame, FocusDirection::Next, 50.0, &usable), None);
+
+        // Moves stop at the edges of the usable area
+        assert_eq!(
+            moved_frame(&frame, FocusDirection::Up, 1000.0, &usable),
+            Some(Rect::new(500.0, 40.0, 400.0, 300.0))
+        );
+        assert_eq!(
+            moved_frame(&frame, FocusDirection::Right, 5000.0, &usable),
+            Some(Rect::new(1510.0, 400.0, 400.0, 300.0))
+        );
+
+        // Windows larger than the usable area stick to its top-left corner
+        let large = Rect::new(0.0, 0.0, 2500.0, 300.0);
+        assert_eq!(
+            moved_frame(&large, FocusDirection::Right, 50.0, &usable),
+            Some(Rect::new(10.0, 40.0, 2500.0, 300.0))
+        );
+    }
+
+    #[test]
+    fn test_arrange_floating_windows_without_floating_windows() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_workspace(ws_id, |ws| {
+            ws.layout = LayoutType::Dwindle;
+        });
+
+        let window = Window {
+            id: 100,
+            workspace_id: ws_id,
+            frame: Rect::new(100.0, 100.0, 400.0, 300.0),
+            ..Default::default()
+        };
+        state.upsert_window(window);
+        state.update_workspace(ws_id, |ws| ws.window_ids.push(100));
+
+        let outcome = on_arrange_floating_windows(&mut state, FloatingArrangement::Cascade);
+        assert_eq!(
+            outcome,
+            Err(CommandFailed::new(
+                "Workspace 'workspace1' has no floating windows"
+            ))
+        );
+        assert_eq!(
+            state.get_window(100).unwrap().frame,
+            Rect::new(100.0, 100.0, 400.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn test_cascade_frames() {
+        let usable = Rect::new(10.0, 40.0, 500.0, 400.0);
+        let frames = [
+            Rect::new(300.0, 200.0, 400.0, 300.0),
+            Rect::new(0.0, 0.0, 400.0, 300.0),
+            Rect::new(50.0, 50.0, 800.0, 200.0),
+        ];
+
+        assert_eq!(cascade_frames(&frames, &usable), vec![
+            Rect::new(10.0, 40.0, 400.0, 300.0),
+            Rect::new(42.0, 72.0, 400.0, 300.0),
+            // Too wide for the usable area: shrunk and restarted at the corner
+            Rect::new(10.0, 40.0, 500.0, 200.0),
+        ]);
+    }
+
+    #[test]
+    fn test_cascade_frames_wraps_when_out_of_room() {
+        let usable = Rect::new(0.0, 0.0, 500.0, 400.0);
+        let frames = [Rect::new(0.0, 0.0, 400.0, 300.0); 5];
+
+        let cascaded = cascade_frames(&frames, &usable);
+        let offsets: Vec<f64> = cascaded.iter().map(|frame| frame.x).collect();
+        assert_eq!(offsets, vec![0.0, 32.0, 64.0, 96.0, 0.0]);
+        assert!(cascaded.iter().all(|frame| frame.y + frame.height <= usable.height));
+    }
+
+    #[test]
+    fn test_grid_frames() {
+        let screen_frame = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        let frames = grid_frames(4, &screen_frame, &Gaps::zero());
+
+        assert_eq!(frames, vec![
+            Rect::new(0.0, 0.0, 960.0, 540.0),
+            Rect::new(960.0, 0.0, 960.0, 540.0),
+            Rect::new(0.0, 540.0, 960.0, 540.0),
+            Rect::new(960.0, 540.0, 960.0, 540.0),
+        ]);
+    }
+
+    #[test]
+    fn test_grid_frames_wraps_beyond_max_cells() {
+        let screen_frame = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        let frames = grid_frames(MAX_GRID_WINDOWS + 2, &screen_frame, &Gaps::zero());
+
+        assert_eq!(frames.len(), MAX_GRID_WINDOWS + 2);
+        assert_eq!(frames[MAX_GRID_WINDOWS], frames[0]);
+        assert_eq!(frames[MAX_GRID_WINDOWS + 1], frames[1]);
+        assert!(grid_frames(0, &screen_frame, &Gaps::zero()).is_empty());
+    }
 }
@@ -1,11 +1,13 @@
 //! Resize command handlers.
 //!
-//! These handlers manage split ratio manipulation, window resizing,
-//! and user-initiated resize completion.
+//! These handlers manage split ratio manipulation, setting split ratios
+//! directly, window resizing, and user-initiated resize completion.
 
 use uuid::Uuid;
 
-use crate::modules::tiling::actor::messages::ResizeDimension;
+use crate::modules::tiling::actor::messages::{
+    CommandFailed, CommandOutcome, ResizeDimension, ResizeOutcome,
+};
 use crate::modules::tiling::init::get_subscriber_handle;
 use crate::modules::tiling::state::{LayoutType, Rect, TilingState};
 
@@ -16,14 +18,17 @@ use crate::modules::tiling::state::{LayoutType, Rect, TilingState};
 /// Initialize default ratios based on layout type and window count.
 ///
 /// Different layouts interpret ratios differently:
-/// - Split layouts: cumulative ratios (e.g., `[0.33, 0.66]` for 3 windows)
+/// - Split and columns layouts: cumulative ratios (e.g., `[0.33, 0.66]` for 3 windows)
 /// - Dwindle: direct ratios per split level (e.g., `[0.5, 0.5]` for 3 windows)
 /// - Grid: layout-specific (first ratio often controls master/primary split)
 #[allow(clippy::cast_precision_loss)]
 fn initialize_default_ratios(layout: LayoutType, window_count: usize) -> Vec<f64> {
     match layout {
         // Split layouts use cumulative ratios
-        LayoutType::Split | LayoutType::SplitHorizontal | LayoutType::SplitVertical => {
+        LayoutType::Split
+        | LayoutType::SplitHorizontal
+        | LayoutType::SplitVertical
+        | LayoutType::Columns => {
             (1..window_count).map(|i| i as f64 / window_count as f64).collect()
         }
         // Dwindle uses direct ratios (0.5 for each split by default)
@@ -40,8 +45,13 @@ fn initialize_default_ratios(layout: LayoutType, window_count: usize) -> Vec<f64
                 _ => vec![0.5],           // Default
             }
         }
-        // Master layout uses master_ratio from workspace config, not split_ratios
-        LayoutType::Master | LayoutType::Floating | LayoutType::Monocle => Vec::new(),
+        // Master layout uses the configured master ratio unless one is set
+        // directly (see `on_set_split_ratio`)
+        LayoutType::Master
+        | LayoutType::Floating
+        | LayoutType::Monocle
+        | LayoutType::Tabbed
+        | LayoutType::Scrolling => Vec::new(),
     }
 }
 
@@ -87,7 +97,11 @@ pub fn on_resize_split(
     // Skip layouts that don't support split ratios
     if matches!(
         layout,
-        LayoutType::Floating | LayoutType::Monocle | LayoutType::Master
+        LayoutType::Floating
+            | LayoutType::Monocle
+            | LayoutType::Tabbed
+            | LayoutType::Master
+            | LayoutType::Scrolling
     ) {
         tracing::debug!("resize_split: layout {layout:?} doesn't use split ratios");
         return;
@@ -131,9 +145,11 @@ pub fn on_resize_split(
     };
 
     // Determine which dimension we're resizing (for split layouts)
-    let is_horizontal = matches!(layout, LayoutType::Split | LayoutType::SplitHorizontal)
-        || (layout == LayoutType::Split
-            && screen.visible_frame.width >= screen.visible_frame.height);
+    let is_horizontal = matches!(
+        layout,
+        LayoutType::Split | LayoutType::SplitHorizontal | LayoutType::Columns
+    ) || (layout == LayoutType::Split
+        && screen.visible_frame.width >= screen.visible_frame.height);
     let total_size = if is_horizontal {
         screen.visible_frame.width
     } else {
@@ -167,6 +183,7 @@ pub fn on_resize_split(
                 &screen.visible_frame,
                 &screen.name,
                 screen.is_main,
+                &workspace.name,
             );
         }
         LayoutType::Grid => {
@@ -180,6 +197,7 @@ pub fn on_resize_split(
                 &screen.visible_frame,
                 &screen.name,
                 screen.is_main,
+                &workspace.name,
             );
         }
         _ => {
@@ -313,6 +331,7 @@ fn apply_dwindle_resize_with_minimums(
     screen_frame: &Rect,
     screen_name: &str,
     is_main_screen: bool,
+    workspace_name: &str,
 ) {
     use crate::config::get_config;
     use crate::modules::tiling::layout::{Gaps, MasterPosition, calculate_layout_full};
@@ -328,7 +347,13 @@ fn apply_dwindle_resize_with_minimums(
     } else {
         0.0
     };
-    let gaps = Gaps::from_config(&config.tiling.gaps, screen_name, is_main_screen, bar_offset);
+    let gaps = Gaps::from_config_for_workspace(
+        &config.tiling.gaps,
+        config.tiling.gaps_override(workspace_name, state.gap_preset()),
+        screen_name,
+        is_main_screen,
+        bar_offset,
+    );
 
     // Calculate proposed new ratio
     let current_ratio = ratios[index];
@@ -347,6 +372,7 @@ fn apply_dwindle_resize_with_minimums(
         &gaps,
         &proposed_ratios,
         MasterPosition::Auto,
+        1,
     );
 
     // Check if any window would violate its minimum size
@@ -384,6 +410,7 @@ fn apply_grid_resize_with_minimums(
     screen_frame: &Rect,
     screen_name: &str,
     is_main_screen: bool,
+    workspace_name: &str,
 ) {
     use crate::config::get_config;
     use crate::modules::tiling::layout::{Gaps, MasterPosition, calculate_layout_full};
@@ -399,7 +426,13 @@ fn apply_grid_resize_with_minimums(
     } else {
         0.0
     };
-    let gaps = Gaps::from_config(&config.tiling.gaps, screen_name, is_main_screen, bar_offset);
+    let gaps = Gaps::from_config_for_workspace(
+        &config.tiling.gaps,
+        config.tiling.gaps_override(workspace_name, state.gap_preset()),
+        screen_name,
+        is_main_screen,
+        bar_offset,
+    );
 
     // Calculate proposed new ratio
     let current_ratio = ratios[index];
@@ -418,6 +451,7 @@ fn apply_grid_resize_with_minimums(
         &gaps,
         &proposed_ratios,
         MasterPosition::Auto,
+        1,
     );
 
     // Check if any window would violate its minimum size
@@ -451,51 +485,84 @@ fn apply_grid_resize_with_minimums(
 // Focused Window Resize
 // ============================================================================
 
+/// Returns whether the focused window can be resized in the given layout.
+///
+/// Only layouts backed by split ratios (dwindle, grid, split, columns) support resizing.
+const fn supports_split_resize(layout: LayoutType) -> bool {
+    matches!(
+        layout,
+        LayoutType::Dwindle
+            | LayoutType::Grid
+            | LayoutType::Split
+            | LayoutType::SplitHorizontal
+            | LayoutType::SplitVertical
+            | LayoutType::Columns
+    )
+}
+
 /// Resize the focused window in a dimension.
 ///
 /// Adjusts the split ratios to resize the window by the specified amount.
-/// Works with layouts that support split ratios (dwindle, grid, split).
+/// Works with layouts that support split ratios (dwindle, grid, split, columns).
 ///
 /// # Arguments
 ///
 /// * `state` - The tiling state
 /// * `dimension` - `ResizeDimension::Width` or `ResizeDimension::Height`
 /// * `amount` - Pixels to add (positive) or remove (negative)
+///
+/// # Returns
+///
+/// A [`ResizeOutcome`] describing whether the split was adjusted or ignored because
+/// the layout has no adjustable splits (monocle, tabbed, master, floating), or a
+/// [`CommandFailed`] when the resize could not be applied.
 #[allow(clippy::too_many_lines)]
-pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimension, amount: i32) {
+pub fn on_resize_focused_window(
+    state: &mut TilingState,
+    dimension: ResizeDimension,
+    amount: i32,
+) -> CommandOutcome<ResizeOutcome> {
     let focus = state.get_focus_state();
     let Some(workspace_id) = focus.focused_workspace_id else {
         tracing::debug!("resize_focused_window: no focused workspace");
-        return;
+        return Err(CommandFailed::new("no focused workspace"));
     };
 
     let Some(workspace) = state.get_workspace(workspace_id) else {
         tracing::debug!("resize_focused_window: workspace not found");
-        return;
+        return Err(CommandFailed::new("workspace not found"));
     };
 
     let layout = workspace.layout;
+
+    // Layouts without split ratios never resize; report this separately so
+    // callers can tell an ignored request from a failed one
+    if !supports_split_resize(layout) {
+        tracing::debug!("resize_focused_window: layout {layout:?} doesn't support resize");
+        return Ok(ResizeOutcome::Ignored { layout });
+    }
+
     let window_ids = workspace.window_ids.clone();
     if window_ids.len() < 2 {
         tracing::debug!("resize_focused_window: need at least 2 windows to resize");
-        return;
+        return Err(CommandFailed::new("need at least 2 windows to resize"));
     }
 
     let focused_idx = workspace.focused_window_index.unwrap_or(0);
     let Some(&focused_id) = window_ids.get(focused_idx) else {
         tracing::debug!("resize_focused_window: no window at focused index");
-        return;
+        return Err(CommandFailed::new("no window at focused index"));
     };
 
     let Some(_focused_window) = state.get_window(focused_id) else {
         tracing::debug!("resize_focused_window: focused window not in state");
-        return;
+        return Err(CommandFailed::new("focused window not in state"));
     };
 
     // Get the screen for workspace to calculate delta ratio
     let Some(screen) = state.get_screen(workspace.screen_id) else {
         tracing::debug!("resize_focused_window: screen not found");
-        return;
+        return Err(CommandFailed::new("screen not found"));
     };
 
     let is_landscape = screen.visible_frame.width >= screen.visible_frame.height;
@@ -515,7 +582,7 @@ pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimens
 
     let Some(window_index) = layoutable.iter().position(|&id| id == focused_id) else {
         tracing::debug!("resize_focused_window: focused window not layoutable");
-        return;
+        return Err(CommandFailed::new("focused window not layoutable"));
     };
 
     // Determine which ratio index to modify based on layout type
@@ -532,7 +599,7 @@ pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimens
                     tracing::debug!(
                         "resize_focused_window: cannot resize single window in dwindle"
                     );
-                    return;
+                    return Err(CommandFailed::new("cannot resize single window"));
                 }
             } else {
                 // Determine if this split is horizontal or vertical
@@ -553,7 +620,10 @@ pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimens
                     tracing::debug!(
                         "resize_focused_window: dimension {dimension:?} doesn't match split direction for window at index {window_index}"
                     );
-                    return;
+                    return Err(CommandFailed::new(format!(
+                        "{} doesn't match the split direction of the focused window",
+                        dimension.as_str()
+                    )));
                 };
 
                 (split_index, effective)
@@ -569,17 +639,28 @@ pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimens
                 tracing::debug!(
                     "resize_focused_window: height resize not fully supported for grid yet"
                 );
-                return;
+                return Err(CommandFailed::new(
+                    "height resize is not supported for grid layout",
+                ));
             }
         }
-        LayoutType::Split | LayoutType::SplitHorizontal | LayoutType::SplitVertical => {
+        LayoutType::Columns if dimension == ResizeDimension::Height => {
+            // Columns always span the full height
+            return Err(CommandFailed::new(
+                "height resize is not supported for columns layout",
+            ));
+        }
+        LayoutType::Split
+        | LayoutType::SplitHorizontal
+        | LayoutType::SplitVertical
+        | LayoutType::Columns => {
             // Split: cumulative ratios, each window (except last) has a ratio
             if window_index >= layoutable.len() - 1 {
                 if window_index > 0 {
                     (window_index - 1, -delta_ratio)
                 } else {
                     tracing::debug!("resize_focused_window: cannot resize single window");
-                    return;
+                    return Err(CommandFailed::new("cannot resize single window"));
                 }
             } else {
                 (window_index, delta_ratio)
@@ -587,7 +668,7 @@ pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimens
         }
         _ => {
             tracing::debug!("resize_focused_window: layout {layout:?} doesn't support resize");
-            return;
+            return Ok(ResizeOutcome::Ignored { layout });
         }
     };
 
@@ -596,6 +677,106 @@ pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimens
     tracing::debug!(
         "Resized window {focused_id} {dimension:?} by {amount}px (layout: {layout:?}, ratio_index: {ratio_index}, delta: {effective_delta:.4})"
     );
+
+    let ratio = state
+        .get_workspace(workspace_id)
+        .and_then(|ws| ws.split_ratios.get(ratio_index).copied())
+        .unwrap_or_default();
+
+    Ok(ResizeOutcome::Resized { ratio_index, ratio })
+}
+
+// ============================================================================
+// Set Split Ratio
+// ============================================================================
+
+/// Smallest split ratio that can be set directly, matching the layouts' clamp.
+const MIN_SPLIT_RATIO: f64 = 0.1;
+/// Largest split ratio that can be set directly, matching the layouts' clamp.
+const MAX_SPLIT_RATIO: f64 = 0.9;
+
+/// Set the split ratio at `index` in the focused workspace.
+///
+/// The ratio is clamped to 0.1 - 0.9. Missing ratios are filled with the
+/// layout's defaults, then with 0.5, so any index can be set. In the master
+/// layout only index 0 exists, and it overrides `master.ratio`.
+///
+/// # Returns
+///
+/// [`ResizeOutcome::Resized`] with the ratio that was stored, `Ignored` for
+/// layouts without splits (monocle, tabbed, scrolling, floating), or a [`CommandFailed`].
+pub fn on_set_split_ratio(
+    state: &mut TilingState,
+    index: usize,
+    ratio: f64,
+) -> CommandOutcome<ResizeOutcome> {
+    let Some(workspace_id) = state.get_focus_state().focused_workspace_id else {
+        tracing::debug!("set_split_ratio: no focused workspace");
+        return Err(CommandFailed::new("no focused workspace"));
+    };
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::debug!("set_split_ratio: workspace not found");
+        return Err(CommandFailed::new("workspace not found"));
+    };
+
+    let layout = workspace.layout;
+    if !supports_split_resize(layout) && layout != LayoutType::Master {
+        tracing::debug!("set_split_ratio: layout {layout:?} doesn't use split ratios");
+        return Ok(ResizeOutcome::Ignored { layout });
+    }
+
+    if layout == LayoutType::Master && index > 0 {
+        return Err(CommandFailed::new(
+            "master layout only has split ratio 0 (the master ratio)",
+        ));
+    }
+
+    let ratios = with_split_ratio(
+        &workspace.split_ratios,
+        layout,
+        state.get_layoutable_windows(workspace_id).len(),
+        index,
+        ratio,
+    );
+    let ratio = ratios[index];
+
+    state.update_workspace(workspace_id, |ws| {
+        ws.split_ratios = ratios;
+    });
+
+    tracing::debug!("Set split ratio {index} to {ratio} (layout: {layout:?})");
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_id, true);
+    }
+
+    Ok(ResizeOutcome::Resized { ratio_index: index, ratio })
+}
+
+/// Returns `ratios` with the entry at `index` set to the clamped `ratio`.
+///
+/// Empty ratios start from the layout's defaults for `window_count` windows,
+/// and the vector grows with 0.5 entries up to `index`.
+fn with_split_ratio(
+    ratios: &[f64],
+    layout: LayoutType,
+    window_count: usize,
+    index: usize,
+    ratio: f64,
+) -> Vec<f64> {
+    let mut ratios = if ratios.is_empty() {
+        initialize_default_ratios(layout, window_count)
+    } else {
+        ratios.to_vec()
+    };
+
+    if ratios.len() <= index {
+        ratios.resize(index + 1, 0.5);
+    }
+    ratios[index] = ratio.clamp(MIN_SPLIT_RATIO, MAX_SPLIT_RATIO);
+
+    ratios
 }
 
 // ============================================================================
@@ -641,7 +822,11 @@ pub fn on_user_resize_completed(
     // Skip layouts that don't support split ratios
     if matches!(
         layout,
-        LayoutType::Floating | LayoutType::Monocle | LayoutType::Master
+        LayoutType::Floating
+            | LayoutType::Monocle
+            | LayoutType::Tabbed
+            | LayoutType::Master
+            | LayoutType::Scrolling
     ) {
         tracing::debug!("user_resize_completed: layout {layout:?} doesn't use split ratios");
         // Just re-apply layout to snap back
@@ -729,6 +914,29 @@ pub fn on_user_resize_completed(
                 return;
             }
         }
+        LayoutType::Columns => {
+            // Columns: the dragged border is the boundary shared with the neighbor
+            let border = (resize_dimension == ResizeDimension::Width)
+                .then(|| column_border(window_index, window_count, &old_frame, &new_frame))
+                .flatten();
+            if let Some((border_index, is_right_edge)) = border {
+                // Growing past the right edge moves the border right, past the left edge left
+                let delta = if is_right_edge {
+                    ratio_delta
+                } else {
+                    -ratio_delta
+                };
+                (border_index, delta)
+            } else {
+                tracing::debug!(
+                    "user_resize_completed: no column border moved, re-applying layout"
+                );
+                if let Some(handle) = get_subscriber_handle() {
+                    handle.notify_layout_changed(workspace_id, true);
+                }
+                return;
+            }
+        }
         LayoutType::Split | LayoutType::SplitHorizontal | LayoutType::SplitVertical => {
             // Split: cumulative ratios
             // Each window (except last) has a ratio marking where it ends
@@ -757,6 +965,32 @@ pub fn on_user_resize_completed(
     on_resize_split(state, workspace_id, ratio_index, effective_delta);
 }
 
+/// Finds the column border dragged by a user resize in the columns layout.
+///
+/// Column borders are the cumulative split ratios: border `i` sits between
+/// column `i` and column `i + 1`. Returns the border index and whether it is
+/// the window's right edge, or `None` for a single column.
+fn column_border(
+    window_index: usize,
+    window_count: usize,
+    old_frame: &Rect,
+    new_frame: &Rect,
+) -> Option<(usize, bool)> {
+    let left_moved = (new_frame.x - old_frame.x).abs();
+    let right_moved = ((new_frame.x + new_frame.width) - (old_frame.x + old_frame.width)).abs();
+
+    let has_left_border = window_index > 0;
+    let has_right_border = window_index + 1 < window_count;
+
+    if has_left_border && (left_moved > right_moved || !has_right_border) {
+        Some((window_index - 1, false))
+    } else if has_right_border {
+        Some((window_index, true))
+    } else {
+        None
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -764,7 +998,7 @@ pub fn on_user_resize_completed(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::tiling::state::{Screen, Workspace};
+    use crate::modules::tiling::state::{Screen, Window, Workspace};
 
     fn create_test_state() -> (TilingState, Uuid) {
         let mut state = TilingState::new();
@@ -831,4 +1065,153 @@ mod tests {
         // Should not panic
         on_resize_split(&mut state, ws_id, 0, 0.1);
     }
+
+    #[test]
+    fn test_supports_split_resize() {
+        assert!(supports_split_resize(LayoutType::Dwindle));
+        assert!(supports_split_resize(LayoutType::Grid));
+        assert!(supports_split_resize(LayoutType::Split));
+        assert!(supports_split_resize(LayoutType::SplitHorizontal));
+        assert!(supports_split_resize(LayoutType::SplitVertical));
+        assert!(supports_split_resize(LayoutType::Columns));
+        assert!(!supports_split_resize(LayoutType::Monocle));
+        assert!(!supports_split_resize(LayoutType::Tabbed));
+        assert!(!supports_split_resize(LayoutType::Master));
+        assert!(!supports_split_resize(LayoutType::Floating));
+        assert!(!supports_split_resize(LayoutType::Scrolling));
+    }
+
+    #[test]
+    fn test_resize_focused_window_ignored_in_monocle() {
+        let (mut state, ws_id) = create_test_state();
+        state.update_workspace(ws_id, |ws| {
+            ws.layout = LayoutType::Monocle;
+        });
+
+        let outcome = on_resize_focused_window(&mut state, ResizeDimension::Width, 40);
+        assert_eq!(
+            outcome,
+            Ok(ResizeOutcome::Ignored { layout: LayoutType::Monocle })
+        );
+    }
+
+    #[test]
+    fn test_column_border() {
+        let old = Rect::new(640.0, 0.0, 640.0, 1080.0);
+
+        // Dragging the right edge of the middle column moves the border after it
+        let wider_right = Rect::new(640.0, 0.0, 740.0, 1080.0);
+        assert_eq!(column_border(1, 3, &old, &wider_right), Some((1, true)));
+
+        // Dragging its left edge moves the border before it
+        let wider_left = Rect::new(540.0, 0.0, 740.0, 1080.0);
+        assert_eq!(column_border(1, 3, &old, &wider_left), Some((0, false)));
+
+        // The outer columns only have one border
+        assert_eq!(column_border(0, 3, &old, &wider_left), Some((0, true)));
+        assert_eq!(column_border(2, 3, &old, &wider_right), Some((1, false)));
+        assert_eq!(column_border(0, 1, &old, &wider_right), None);
+    }
+
+    #[test]
+    fn test_initialize_default_ratios_columns() {
+        let ratios = initialize_default_ratios(LayoutType::Columns, 4);
+        assert_eq!(ratios, vec![0.25, 0.5, 0.75]);
+    }
+
+    #[test]
+    fn test_resize_focused_window_fails_without_windows() {
+        let (mut state, _) = create_test_state();
+
+        let outcome = on_resize_focused_window(&mut state, ResizeDimension::Width, 40);
+        assert!(matches!(outcome, Err(CommandFailed { .. })));
+    }
+
+    #[test]
+    fn test_resize_focused_window_saturates_at_clamp() {
+        let (mut state, ws_id) = create_test_state();
+        for id in [100, 101] {
+            state.upsert_window(Window {
+                id,
+                workspace_id: ws_id,
+                ..Default::default()
+            });
+        }
+        state.update_workspace(ws_id, |ws| {
+            ws.window_ids.push(100);
+            ws.window_ids.push(101);
+            ws.focused_window_index = Some(0);
+        });
+
+        // Repeated steps stop at the largest ratio instead of overshooting
+        for _ in 0..40 {
+            let outcome = on_resize_focused_window(&mut state, ResizeDimension::Width, 40);
+            assert!(matches!(
+                outcome,
+                Ok(ResizeOutcome::Resized { ratio_index: 0, .. })
+            ));
+        }
+        let ratio = state.get_workspace(ws_id).unwrap().split_ratios[0];
+        assert!((ratio - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_with_split_ratio_clamps_and_grows() {
+        // Missing entries are filled with 0.5
+        let ratios = with_split_ratio(&[0.3], LayoutType::Dwindle, 2, 2, 0.95);
+        assert_eq!(ratios, vec![0.3, 0.5, 0.9]);
+
+        let ratios = with_split_ratio(&[0.3, 0.6], LayoutType::Dwindle, 3, 0, 0.0);
+        assert_eq!(ratios, vec![0.1, 0.6]);
+
+        // Empty ratios start from the layout's defaults
+        let ratios = with_split_ratio(&[], LayoutType::Dwindle, 3, 1, 0.7);
+        assert_eq!(ratios, vec![0.5, 0.7]);
+    }
+
+    #[test]
+    fn test_set_split_ratio() {
+        let (mut state, ws_id) = create_test_state();
+
+        let outcome = on_set_split_ratio(&mut state, 1, 0.25);
+        assert_eq!(
+            outcome,
+            Ok(ResizeOutcome::Resized { ratio_index: 1, ratio: 0.25 })
+        );
+        assert_eq!(state.get_workspace(ws_id).unwrap().split_ratios, vec![0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_set_split_ratio_master_layout() {
+        let (mut state, ws_id) = create_test_state();
+        state.update_workspace(ws_id, |ws| {
+            ws.layout = LayoutType::Master;
+        });
+
+        let outcome = on_set_split_ratio(&mut state, 0, 0.7);
+        assert_eq!(
+            outcome,
+            Ok(ResizeOutcome::Resized { ratio_index: 0, ratio: 0.7 })
+        );
+        assert_eq!(state.get_workspace(ws_id).unwrap().split_ratios, vec![0.7]);
+
+        // The master layout has a single ratio
+        let outcome = on_set_split_ratio(&mut state, 1, 0.7);
+        assert!(matches!(outcome, Err(CommandFailed { .. })));
+    }
+
+    #[test]
+    fn test_set_split_ratio_ignored_in_monocle() {
+        let (mut state, ws_id) = create_test_state();
+        state.update_workspace(ws_id, |ws| {
+            ws.layout = LayoutType::Monocle;
+        });
+
+        let outcome = on_set_split_ratio(&mut state, 0, 0.7);
+        assert_eq!(
+            outcome,
+            Ok(ResizeOutcome::Ignored { layout: LayoutType::Monocle })
+        );
+        assert!(state.get_workspace(ws_id).unwrap().split_ratios.is_empty());
+    }
 }
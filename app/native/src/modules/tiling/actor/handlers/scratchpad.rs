@@ -0,0 +1,285 @@
+//! Scratchpad command handlers.
+//!
+//! The scratchpad is a reserved workspace, created on first use, whose windows
+//! stay hidden until toggled. When shown, they float over the focused screen
+//! without switching the workspace underneath.
+
+use uuid::Uuid;
+
+use super::window::sync_window_visibility_for_workspaces;
+use super::window_move::on_move_window_to_workspace;
+use crate::config::get_config;
+use crate::modules::tiling::effects::window_ops;
+use crate::modules::tiling::init::get_subscriber_handle;
+use crate::modules::tiling::layout::center_frame;
+use crate::modules::tiling::state::{LayoutType, TilingState, Workspace};
+
+/// Get the scratchpad workspace, creating it on the focused screen if needed.
+///
+/// Returns `None` if a regular workspace already uses the configured name or
+/// there is no screen to create it on.
+fn ensure_scratchpad_workspace(state: &mut TilingState) -> Option<Uuid> {
+    if let Some(workspace) = state.get_scratchpad_workspace() {
+        return Some(workspace.id);
+    }
+
+    let name = get_config().tiling.scratchpad.name.clone();
+    if state.get_workspace_by_name(&name).is_some() {
+        tracing::warn!("scratchpad: workspace '{name}' already exists and is not the scratchpad");
+        return None;
+    }
+
+    let screen_id = state
+        .get_focus_state()
+        .focused_screen_id
+        .or_else(|| state.get_main_screen().map(|s| s.id))?;
+
+    let mut workspace = Workspace::new(name);
+    workspace.screen_id = screen_id;
+    workspace.layout = LayoutType::Floating;
+    workspace.is_scratchpad = true;
+    let workspace_id = workspace.id;
+    state.upsert_workspace(workspace);
+
+    tracing::debug!("Created scratchpad workspace on screen {screen_id}");
+    Some(workspace_id)
+}
+
+/// Move the focused window to the scratchpad.
+///
+/// The window floats from then on, and its app is hidden unless the
+/// scratchpad is currently shown.
+pub fn on_move_window_to_scratchpad(state: &mut TilingState) {
+    let Some(window_id) = state.get_focus_state().focused_window_id else {
+        tracing::debug!("move_to_scratchpad: no focused window");
+        return;
+    };
+
+    if state.get_window(window_id).is_none() {
+        tracing::warn!("move_to_scratchpad: window {window_id} not found");
+        return;
+    }
+
+    let Some(scratchpad_id) = ensure_scratchpad_workspace(state) else {
+        return;
+    };
+
+    state.update_window(window_id, |w| {
+        w.is_floating = true;
+    });
+    on_move_window_to_workspace(state, window_id, scratchpad_id);
+
+    if !state.is_scratchpad_visible() {
+        sync_window_visibility_for_workspaces(state, &[], &[scratchpad_id]);
+    }
+
+    tracing::debug!("Moved window {window_id} to the scratchpad");
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_floating_changed(window_id, true);
+    }
+}
+
+/// Show or hide the scratchpad's windows.
+///
+/// Showing brings the scratchpad to the focused screen, centering windows
+/// left on another screen, and focuses its last focused window. Hiding
+/// returns focus to the focused workspace. The focused workspace never
+/// changes either way.
+pub fn on_toggle_scratchpad(state: &mut TilingState) {
+    let Some(scratchpad) = state.get_scratchpad_workspace() else {
+        tracing::debug!("toggle_scratchpad: scratchpad is empty");
+        return;
+    };
+
+    if state.is_scratchpad_visible() {
+        hide_scratchpad(state, scratchpad.id);
+    } else {
+        show_scratchpad(state, &scratchpad);
+    }
+}
+
+fn show_scratchpad(state: &mut TilingState, scratchpad: &Workspace) {
+    let focus = state.get_focus_state();
+    let screen_id = focus.focused_screen_id.unwrap_or(scratchpad.screen_id);
+
+    state.set_scratchpad_visible(true);
+    state.update_workspace(scratchpad.id, |ws| {
+        ws.screen_id = screen_id;
+    });
+
+    // Windows shown on another screen last time are brought over, centered
+    if let Some(screen) = state.get_screen(screen_id) {
+        for &window_id in &scratchpad.window_ids {
+            let Some(window) = state.get_window(window_id) else {
+                continue;
+            };
+            let (x, y) = window.frame.center();
+            if screen.visible_frame.contains_point(x, y) {
+                continue;
+            }
+            let target_frame = center_frame(&window.frame, &screen.visible_frame);
+            state.update_window(window_id, |w| {
+                w.frame = target_frame;
+            });
+            let _ = window_ops::set_window_frame(window_id, &target_frame);
+        }
+    }
+
+    sync_window_visibility_for_workspaces(state, &[scratchpad.id], &[]);
+
+    tracing::debug!("Showing scratchpad on screen {screen_id}");
+
+    let window_id = state
+        .get_focus_history(scratchpad.id)
+        .filter(|id| scratchpad.window_ids.contains(id))
+        .or_else(|| scratchpad.window_ids.last().copied());
+    if let Some(window_id) = window_id {
+        if let Some(previous_ws_id) = focus.focused_workspace_id
+            && let Some(previous_window_id) = focus.focused_window_id
+        {
+            state.record_focus_history(previous_ws_id, previous_window_id);
+        }
+        state.set_focused_window(Some(window_id));
+        let _ = window_ops::focus_window(window_id);
+    }
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_focus_changed();
+    }
+}
+
+fn hide_scratchpad(state: &mut TilingState, scratchpad_id: Uuid) {
+    // Remember the scratchpad window in use for the next time it is shown
+    if let Some(window_id) = state.get_focus_state().focused_window_id
+        && state.get_window(window_id).is_some_and(|w| w.workspace_id == scratchpad_id)
+    {
+        state.record_focus_history(scratchpad_id, window_id);
+    }
+
+    state.set_scratchpad_visible(false);
+    sync_window_visibility_for_workspaces(state, &[], &[scratchpad_id]);
+
+    tracing::debug!("Hiding scratchpad");
+
+    // Hand focus back to the workspace underneath
+    let window_id = state.get_focused_workspace().and_then(|ws| {
+        state
+            .get_focus_history(ws.id)
+            .filter(|id| ws.window_ids.contains(id))
+            .or_else(|| ws.window_ids.first().copied())
+    });
+    state.set_focused_window(window_id);
+    if let Some(window_id) = window_id {
+        let _ = window_ops::focus_window(window_id);
+    }
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_focus_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::tiling::state::{Rect, Screen, Window};
+
+    fn create_test_state() -> TilingState {
+        let mut state = TilingState::new();
+        state.upsert_screen(Screen {
+            id: 1,
+            name: "Test Screen".to_string(),
+            is_main: true,
+            frame: Rect::new(0.0, 0.0, 1920.0, 1080.0),
+            visible_frame: Rect::new(0.0, 25.0, 1920.0, 1055.0),
+            ..Default::default()
+        });
+
+        let mut ws = Workspace::new("workspace1");
+        ws.screen_id = 1;
+        ws.is_visible = true;
+        ws.is_focused = true;
+        ws.window_ids.push(100);
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+
+        state.upsert_window(Window {
+            id: 100,
+            workspace_id: ws_id,
+            frame: Rect::new(100.0, 100.0, 800.0, 600.0),
+            ..Default::default()
+        });
+        state.set_focus(Some(100), Some(ws_id), Some(1));
+
+        state
+    }
+
+    #[test]
+    fn test_move_window_to_scratchpad() {
+        let mut state = create_test_state();
+        let ws_id = state.get_focused_workspace().unwrap().id;
+
+        on_move_window_to_scratchpad(&mut state);
+
+        let scratchpad = state.get_scratchpad_workspace().unwrap();
+        assert_eq!(scratchpad.name, get_config().tiling.scratchpad.name);
+        assert_eq!(scratchpad.layout, LayoutType::Floating);
+        assert_eq!(scratchpad.screen_id, 1);
+        assert!(!scratchpad.is_visible);
+        assert!(scratchpad.window_ids.contains(&100));
+
+        let window = state.get_window(100).unwrap();
+        assert_eq!(window.workspace_id, scratchpad.id);
+        assert!(window.is_floating);
+        assert!(!state.get_workspace(ws_id).unwrap().window_ids.contains(&100));
+        assert!(!state.is_scratchpad_visible());
+    }
+
+    #[test]
+    fn test_move_window_to_scratchpad_reuses_workspace() {
+        let mut state = create_test_state();
+        let ws_id = state.get_focused_workspace().unwrap().id;
+        on_move_window_to_scratchpad(&mut state);
+
+        state.upsert_window(Window {
+            id: 101,
+            workspace_id: ws_id,
+            ..Default::default()
+        });
+        state.update_workspace(ws_id, |ws| ws.window_ids.push(101));
+        state.set_focused_window(Some(101));
+        on_move_window_to_scratchpad(&mut state);
+
+        let scratchpads = state.workspaces.iter().filter(|ws| ws.is_scratchpad).count();
+        assert_eq!(scratchpads, 1);
+        let scratchpad = state.get_scratchpad_workspace().unwrap();
+        assert_eq!(scratchpad.window_ids.as_slice(), &[100, 101]);
+    }
+
+    #[test]
+    fn test_toggle_scratchpad() {
+        let mut state = create_test_state();
+        let ws_id = state.get_focused_workspace().unwrap().id;
+        on_move_window_to_scratchpad(&mut state);
+
+        on_toggle_scratchpad(&mut state);
+        assert!(state.is_scratchpad_visible());
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_window_id, Some(100));
+        assert_eq!(focus.focused_workspace_id, Some(ws_id));
+
+        on_toggle_scratchpad(&mut state);
+        assert!(!state.is_scratchpad_visible());
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_window_id, None);
+        assert_eq!(focus.focused_workspace_id, Some(ws_id));
+    }
+
+    #[test]
+    fn test_toggle_scratchpad_without_scratchpad() {
+        let mut state = create_test_state();
+        on_toggle_scratchpad(&mut state);
+        assert!(!state.is_scratchpad_visible());
+        assert!(state.get_scratchpad_workspace().is_none());
+    }
+}
@@ -2,10 +2,13 @@
 //!
 //! These handlers process display configuration changes:
 //! - Screens changed → refresh screen list, create/reassign workspaces
+//!
+//! Screens listed in `tiling.ignoreScreens` never enter the state, so no
+//! workspace lives on them and no window is moved there.
 
 use core_graphics::display::CGDisplay;
 
-use crate::config::get_config;
+use crate::config::{ReservedInsets, TilingConfig, get_config};
 use crate::modules::tiling::init::get_subscriber_handle;
 use crate::modules::tiling::state::{
     LayoutType, Rect, Screen, TilingState, WindowIdList, Workspace,
@@ -20,7 +23,9 @@ pub fn on_screens_changed(state: &mut TilingState) {
     tracing::debug!("Handling screens changed");
 
     // Get current screens from macOS
-    let new_screens = get_screens_from_macos();
+    let (new_screens, ignored_frames) =
+        partition_ignored_screens(get_screens_from_macos(), &get_config().tiling);
+    state.set_ignored_screen_frames(ignored_frames);
 
     if new_screens.is_empty() {
         tracing::warn!("No screens detected!");
@@ -95,6 +100,9 @@ pub fn on_screens_changed(state: &mut TilingState) {
 pub fn on_set_screens(state: &mut TilingState, screens: Vec<Screen>) {
     tracing::debug!("tiling: on_set_screens called with {} screens", screens.len());
 
+    let (screens, ignored_frames) = partition_ignored_screens(screens, &get_config().tiling);
+    state.set_ignored_screen_frames(ignored_frames);
+
     if screens.is_empty() {
         tracing::warn!("tiling: no screens provided to on_set_screens");
         return;
@@ -163,6 +171,28 @@ pub fn on_set_screens(state: &mut TilingState, screens: Vec<Screen>) {
     );
 }
 
+/// Splits off the screens listed in `tiling.ignoreScreens`.
+///
+/// Returns the screens to manage and the frames of the ignored ones. If every
+/// screen is ignored, none is, as tiling needs a screen to work with.
+fn partition_ignored_screens(
+    screens: Vec<Screen>,
+    tiling_config: &TilingConfig,
+) -> (Vec<Screen>, Vec<Rect>) {
+    let (ignored, managed): (Vec<Screen>, Vec<Screen>) =
+        screens.into_iter().partition(|s| tiling_config.is_screen_ignored(&s.name));
+
+    if managed.is_empty() && !ignored.is_empty() {
+        tracing::warn!("tiling: every screen is listed in ignoreScreens, ignoring none");
+        return (ignored, Vec::new());
+    }
+
+    for screen in &ignored {
+        tracing::debug!("tiling: leaving screen '{}' alone", screen.name);
+    }
+    (managed, ignored.iter().map(|s| s.frame).collect())
+}
+
 /// Creates workspaces from configuration.
 fn create_workspaces_from_config(state: &mut TilingState) {
     let config = get_config();
@@ -190,8 +220,9 @@ fn create_workspaces_from_config(state: &mut TilingState) {
             });
 
             if let Some(screen_id) = screen_id {
-                let config_layout = ws_config.layout.unwrap_or(tiling_config.default_layout);
-                let layout = convert_layout_type(config_layout);
+                let layout = ws_config
+                    .layout
+                    .map_or_else(|| screen_default_layout(state, screen_id), convert_layout_type);
                 let workspace = Workspace {
                     id: uuid::Uuid::now_v7(),
                     name: ws_config.name.clone(),
@@ -202,7 +233,11 @@ fn create_workspaces_from_config(state: &mut TilingState) {
                     window_ids: WindowIdList::new(),
                     focused_window_index: None,
                     split_ratios: Vec::new(),
+                    master_count: None,
+                    scroll_offset: 0,
                     configured_screen: Some(ws_config.screen.clone()),
+                    claimed_app_ids: Vec::new(),
+                    is_scratchpad: false,
                 };
                 state.upsert_workspace(workspace);
                 tracing::debug!(
@@ -218,9 +253,6 @@ fn create_workspaces_from_config(state: &mut TilingState) {
 
 /// Creates a default workspace for each screen.
 fn create_default_workspaces(state: &mut TilingState) {
-    let config = get_config();
-    let default_layout = convert_layout_type(config.tiling.default_layout);
-
     let screen_info: Vec<(usize, u32)> =
         state.screens.iter().enumerate().map(|(i, s)| (i, s.id)).collect();
 
@@ -231,13 +263,17 @@ fn create_default_workspaces(state: &mut TilingState) {
             id: uuid::Uuid::now_v7(),
             name: name.clone(),
             screen_id,
-            layout: default_layout,
+            layout: screen_default_layout(state, screen_id),
             is_visible: false,
             is_focused: false,
             window_ids: WindowIdList::new(),
             focused_window_index: None,
             split_ratios: Vec::new(),
+            master_count: None,
+            scroll_offset: 0,
             configured_screen: None,
+            claimed_app_ids: Vec::new(),
+            is_scratchpad: false,
         };
         state.upsert_workspace(workspace);
         tracing::debug!("Created default workspace '{name}' on screen {screen_id}");
@@ -249,7 +285,8 @@ fn ensure_screen_workspaces(state: &mut TilingState) {
     let screen_ids: Vec<u32> = state.screens.iter().map(|s| s.id).collect();
 
     for screen_id in screen_ids {
-        let has_workspace = state.workspaces.iter().any(|w| w.screen_id == screen_id);
+        let has_workspace =
+            state.workspaces.iter().any(|w| w.screen_id == screen_id && !w.is_scratchpad);
 
         if !has_workspace {
             let name = format!("default-{screen_id}");
@@ -257,13 +294,17 @@ fn ensure_screen_workspaces(state: &mut TilingState) {
                 id: uuid::Uuid::now_v7(),
                 name: name.clone(),
                 screen_id,
-                layout: LayoutType::Dwindle,
+                layout: screen_default_layout(state, screen_id),
                 is_visible: false,
                 is_focused: false,
                 window_ids: WindowIdList::new(),
                 focused_window_index: None,
                 split_ratios: Vec::new(),
+                master_count: None,
+                scroll_offset: 0,
                 configured_screen: None,
+                claimed_app_ids: Vec::new(),
+                is_scratchpad: false,
             };
             state.upsert_workspace(workspace);
             tracing::debug!("Created fallback workspace '{name}' for screen {screen_id}");
@@ -273,28 +314,37 @@ fn ensure_screen_workspaces(state: &mut TilingState) {
 
 /// Resolves a screen name to a screen ID.
 fn resolve_screen_name(state: &TilingState, name: &str) -> Option<u32> {
+    find_screen_by_name(state.screens.iter(), name).map(|s| s.id)
+}
+
+/// Finds a screen by name.
+///
+/// Accepts "main"/"primary", "secondary" (the first non-main screen), the
+/// exact display name, or a case-insensitive part of it.
+pub fn find_screen_by_name<'a, I>(screens: I, name: &str) -> Option<&'a Screen>
+where
+    I: IntoIterator<Item = &'a Screen>,
+    I::IntoIter: Clone, {
+    let mut screens = screens.into_iter();
+
     // "main" or "primary" matches the main screen
     if name == "main" || name == "primary" {
-        return state.get_main_screen().map(|s| s.id);
+        return screens.find(|s| s.is_main);
     }
 
     // "secondary" matches the first non-main screen
     if name == "secondary" {
-        return state.screens.iter().find(|s| !s.is_main).map(|s| s.id);
+        return screens.find(|s| !s.is_main);
     }
 
     // Try to match by display name (exact)
-    if let Some(screen) = state.screens.iter().find(|s| s.name == name) {
-        return Some(screen.id);
+    if let Some(screen) = screens.clone().find(|s| s.name == name) {
+        return Some(screen);
     }
 
     // Try case-insensitive partial match
     let name_lower = name.to_lowercase();
-    state
-        .screens
-        .iter()
-        .find(|s| s.name.to_lowercase().contains(&name_lower))
-        .map(|s| s.id)
+    screens.find(|s| s.name.to_lowercase().contains(&name_lower))
 }
 
 /// Sets initial focus and visibility for workspaces.
@@ -341,16 +391,30 @@ fn set_initial_focus(state: &mut TilingState) {
     }
 }
 
+/// Returns the default layout for new workspaces on a screen.
+///
+/// Uses the screen's `perScreenLayout` entry, falling back to `defaultLayout`.
+pub fn screen_default_layout(state: &TilingState, screen_id: u32) -> LayoutType {
+    let tiling_config = &get_config().tiling;
+    let layout = state.get_screen(screen_id).map_or(tiling_config.default_layout, |screen| {
+        tiling_config.layout_for_screen(&screen.name, screen.is_main)
+    });
+    convert_layout_type(layout)
+}
+
 /// Converts config `LayoutType` to state `LayoutType`.
-const fn convert_layout_type(config_layout: crate::config::LayoutType) -> LayoutType {
+pub const fn convert_layout_type(config_layout: crate::config::LayoutType) -> LayoutType {
     match config_layout {
         crate::config::LayoutType::Dwindle => LayoutType::Dwindle,
         crate::config::LayoutType::Split => LayoutType::Split,
         crate::config::LayoutType::SplitVertical => LayoutType::SplitVertical,
         crate::config::LayoutType::SplitHorizontal => LayoutType::SplitHorizontal,
         crate::config::LayoutType::Monocle => LayoutType::Monocle,
+        crate::config::LayoutType::Tabbed => LayoutType::Tabbed,
         crate::config::LayoutType::Master => LayoutType::Master,
         crate::config::LayoutType::Grid => LayoutType::Grid,
+        crate::config::LayoutType::Columns => LayoutType::Columns,
+        crate::config::LayoutType::Scrolling => LayoutType::Scrolling,
         crate::config::LayoutType::Floating => LayoutType::Floating,
     }
 }
@@ -591,11 +655,15 @@ fn get_screen_info(display_id: u32, main_screen_height: f64) -> Option<Screen> {
             let converted_visible_frame =
                 convert_to_top_left_origin(visible_frame, main_screen_height);
 
+            // Keep windows clear of external bars and docks
+            let reserved = get_config().tiling.reserved.for_screen(&name, is_main);
+            let usable_frame = apply_reserved_insets(converted_visible_frame, &reserved);
+
             return Some(Screen {
                 id: display_id,
                 name,
                 frame: converted_frame,
-                visible_frame: converted_visible_frame,
+                visible_frame: usable_frame,
                 scale_factor: backing_scale,
                 is_main,
                 is_builtin,
@@ -630,6 +698,25 @@ fn convert_to_top_left_origin(rect: NSRect, main_screen_height: f64) -> Rect {
     Rect::new(rect.origin.x, new_y, rect.size.width, rect.size.height)
 }
 
+/// Takes the `tiling.reserved` space off a screen's visible frame.
+///
+/// Insets larger than the frame leave an empty frame rather than a negative one.
+fn apply_reserved_insets(frame: Rect, reserved: &ReservedInsets) -> Rect {
+    if reserved.is_empty() {
+        return frame;
+    }
+
+    let top = f64::from(reserved.top);
+    let left = f64::from(reserved.left);
+
+    Rect::new(
+        frame.x + left,
+        frame.y + top,
+        (frame.width - left - f64::from(reserved.right)).max(0.0),
+        (frame.height - top - f64::from(reserved.bottom)).max(0.0),
+    )
+}
+
 /// Gets the refresh rate for a display.
 fn get_display_refresh_rate(display_id: u32) -> f64 {
     let display = CGDisplay::new(display_id);
@@ -702,7 +789,11 @@ mod tests {
             window_ids: WindowIdList::new(),
             focused_window_index: None,
             split_ratios: Vec::new(),
+            master_count: None,
+            scroll_offset: 0,
             configured_screen: None,
+            claimed_app_ids: Vec::new(),
+            is_scratchpad: false,
         }
     }
 
@@ -715,6 +806,25 @@ mod tests {
         assert!(ids.contains(&CGDisplay::main().id));
     }
 
+    #[test]
+    fn test_partition_ignored_screens() {
+        let tiling_config: TilingConfig =
+            serde_json::from_str(r#"{"ignoreScreens": ["LG TV"]}"#).unwrap();
+        let mut tv = make_screen(3, "LG TV", false);
+        tv.frame = Rect::new(1920.0, 0.0, 3840.0, 2160.0);
+        let screens = vec![make_screen(1, "Main", true), tv];
+
+        let (managed, ignored) = partition_ignored_screens(screens, &tiling_config);
+        assert_eq!(managed.iter().map(|s| s.id).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(ignored, vec![Rect::new(1920.0, 0.0, 3840.0, 2160.0)]);
+
+        // A lone ignored screen is still managed
+        let (managed, ignored) =
+            partition_ignored_screens(vec![make_screen(3, "LG TV", true)], &tiling_config);
+        assert_eq!(managed.len(), 1);
+        assert!(ignored.is_empty());
+    }
+
     #[test]
     fn test_reassign_workspaces_from_removed_screens() {
         let mut state = TilingState::new();
@@ -804,6 +914,48 @@ mod tests {
         assert_eq!(state.get_workspace(ws_id).unwrap().screen_id, 2);
     }
 
+    #[test]
+    fn test_apply_reserved_insets() {
+        let frame = Rect::new(0.0, 25.0, 1920.0, 1055.0);
+
+        assert_eq!(apply_reserved_insets(frame, &ReservedInsets::default()), frame);
+
+        let reserved = ReservedInsets {
+            top: 30,
+            right: 0,
+            bottom: 50,
+            left: 70,
+        };
+        assert_eq!(
+            apply_reserved_insets(frame, &reserved),
+            Rect::new(70.0, 55.0, 1850.0, 975.0)
+        );
+
+        let oversized = ReservedInsets {
+            top: 600,
+            right: 0,
+            bottom: 600,
+            left: 0,
+        };
+        assert!(apply_reserved_insets(frame, &oversized).height.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_find_screen_by_name() {
+        let screens = [
+            make_screen(1, "Built-in Retina Display", true),
+            make_screen(2, "DELL U2720Q", false),
+        ];
+
+        let find = |name| find_screen_by_name(&screens, name).map(|s| s.id);
+        assert_eq!(find("main"), Some(1));
+        assert_eq!(find("primary"), Some(1));
+        assert_eq!(find("secondary"), Some(2));
+        assert_eq!(find("DELL U2720Q"), Some(2));
+        assert_eq!(find("retina"), Some(1));
+        assert_eq!(find("LG"), None);
+    }
+
     #[test]
     fn test_get_screen_info_main() {
         let main_id = CGDisplay::main().id;
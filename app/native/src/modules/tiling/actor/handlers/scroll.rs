@@ -0,0 +1,259 @@
+//! Scrolling layout handlers.
+//!
+//! These handlers move the viewport of scrolling workspaces. Scrolling and
+//! focus follow each other: scrolling moves focus to a column that stays on
+//! screen, and focusing a column scrolls it into view.
+
+use uuid::Uuid;
+
+use crate::modules::tiling::actor::ScrollDirection;
+use crate::modules::tiling::init::get_subscriber_handle;
+use crate::modules::tiling::layout::{clamp_offset, reveal_offset};
+use crate::modules::tiling::state::{LayoutType, TilingState};
+
+/// Returns the layoutable window IDs of a workspace, in column order.
+fn column_window_ids(state: &TilingState, workspace_id: Uuid) -> Vec<u32> {
+    state.get_workspace(workspace_id).map_or_else(Vec::new, |workspace| {
+        workspace
+            .window_ids
+            .iter()
+            .filter(|&&id| state.get_window(id).is_some_and(|w| w.is_layoutable()))
+            .copied()
+            .collect()
+    })
+}
+
+/// Scroll the focused scrolling workspace by one column.
+///
+/// If the focused window scrolls off screen, focus moves to the nearest
+/// column that is still visible.
+pub fn on_scroll_workspace(
+    state: &mut TilingState,
+    direction: ScrollDirection,
+    visible_columns: usize,
+) {
+    let focus = state.get_focus_state();
+    let Some(workspace_id) = focus.focused_workspace_id else {
+        tracing::debug!("scroll_workspace: no focused workspace");
+        return;
+    };
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::warn!("scroll_workspace: workspace {workspace_id} not found");
+        return;
+    };
+
+    if workspace.layout != LayoutType::Scrolling {
+        tracing::debug!("scroll_workspace: layout {:?} doesn't scroll", workspace.layout);
+        return;
+    }
+
+    let columns = column_window_ids(state, workspace_id);
+    let current = clamp_offset(workspace.scroll_offset, columns.len(), visible_columns);
+    let offset = clamp_offset(direction.apply(current), columns.len(), visible_columns);
+    if offset == workspace.scroll_offset {
+        return;
+    }
+
+    state.update_workspace(workspace_id, |ws| {
+        ws.scroll_offset = offset;
+    });
+
+    tracing::debug!("Scrolled workspace {workspace_id} {direction:?} to column {offset}");
+
+    // Keep focus on screen
+    let focused_column = focus
+        .focused_window_id
+        .and_then(|id| columns.iter().position(|&column_id| column_id == id));
+    if let Some(column) = focused_column {
+        let target = column.clamp(offset, offset + visible_columns.max(1) - 1);
+        if target != column {
+            let target_window_id = columns[target];
+            state.update_focus(|focus| {
+                focus.focused_window_id = Some(target_window_id);
+            });
+            if let Some(idx) = workspace.window_ids.iter().position(|&id| id == target_window_id) {
+                state.update_workspace(workspace_id, |ws| {
+                    ws.focused_window_index = Some(idx);
+                });
+            }
+
+            if let Some(handle) = get_subscriber_handle() {
+                handle.notify_focus_changed();
+            }
+
+            let _ = crate::modules::tiling::effects::window_ops::focus_window(target_window_id);
+        }
+    }
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
+/// Scroll the focused scrolling workspace so its focused window is on screen.
+///
+/// Does nothing for other layouts or when the focused column is already visible.
+pub fn on_reveal_focused_column(state: &mut TilingState, visible_columns: usize) {
+    let focus = state.get_focus_state();
+    let (Some(workspace_id), Some(window_id)) =
+        (focus.focused_workspace_id, focus.focused_window_id)
+    else {
+        return;
+    };
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        return;
+    };
+
+    if workspace.layout != LayoutType::Scrolling {
+        return;
+    }
+
+    let columns = column_window_ids(state, workspace_id);
+    let Some(column) = columns.iter().position(|&id| id == window_id) else {
+        return;
+    };
+
+    let current = clamp_offset(workspace.scroll_offset, columns.len(), visible_columns);
+    let offset = reveal_offset(column, current, visible_columns);
+    if offset == workspace.scroll_offset {
+        return;
+    }
+
+    state.update_workspace(workspace_id, |ws| {
+        ws.scroll_offset = offset;
+    });
+
+    tracing::debug!(
+        "Scrolled workspace {workspace_id} to column {offset} to reveal window {window_id}"
+    );
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::tiling::state::{Screen, Window, Workspace};
+
+    fn create_test_state(window_count: u32) -> (TilingState, Uuid) {
+        let mut state = TilingState::new();
+
+        state.upsert_screen(Screen {
+            id: 1,
+            name: "Test Screen".to_string(),
+            is_main: true,
+            ..Default::default()
+        });
+
+        let mut ws = Workspace::new("workspace1");
+        ws.screen_id = 1;
+        ws.is_visible = true;
+        ws.is_focused = true;
+        ws.layout = LayoutType::Scrolling;
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+
+        for id in 1..=window_count {
+            state.upsert_window(Window {
+                id,
+                workspace_id: ws_id,
+                ..Default::default()
+            });
+            state.update_workspace(ws_id, |ws| ws.window_ids.push(id));
+        }
+
+        state.update_focus(|focus| {
+            focus.focused_workspace_id = Some(ws_id);
+            focus.focused_screen_id = Some(1);
+            focus.focused_window_id = Some(1);
+        });
+
+        (state, ws_id)
+    }
+
+    fn focus_window(state: &mut TilingState, window_id: u32) {
+        state.update_focus(|focus| focus.focused_window_id = Some(window_id));
+    }
+
+    #[test]
+    fn test_scroll_right_and_left() {
+        let (mut state, ws_id) = create_test_state(4);
+
+        on_scroll_workspace(&mut state, ScrollDirection::Right, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 1);
+
+        on_scroll_workspace(&mut state, ScrollDirection::Left, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_scroll_is_clamped() {
+        let (mut state, ws_id) = create_test_state(3);
+
+        on_scroll_workspace(&mut state, ScrollDirection::Left, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 0);
+
+        on_scroll_workspace(&mut state, ScrollDirection::Right, 2);
+        on_scroll_workspace(&mut state, ScrollDirection::Right, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 1);
+    }
+
+    #[test]
+    fn test_scroll_moves_focus_on_screen() {
+        let (mut state, ws_id) = create_test_state(4);
+
+        // Window 1 scrolls off the left edge, focus follows to window 2
+        on_scroll_workspace(&mut state, ScrollDirection::Right, 2);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(2));
+        assert_eq!(state.get_workspace(ws_id).unwrap().focused_window_index, Some(1));
+
+        // Window 2 scrolls off as well, focus follows to window 3
+        on_scroll_workspace(&mut state, ScrollDirection::Right, 2);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(3));
+    }
+
+    #[test]
+    fn test_scroll_ignores_other_layouts() {
+        let (mut state, ws_id) = create_test_state(4);
+        state.update_workspace(ws_id, |ws| ws.layout = LayoutType::Dwindle);
+
+        on_scroll_workspace(&mut state, ScrollDirection::Right, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_reveal_focused_column_scrolls_right() {
+        let (mut state, ws_id) = create_test_state(4);
+
+        focus_window(&mut state, 3);
+        on_reveal_focused_column(&mut state, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 1);
+
+        focus_window(&mut state, 4);
+        on_reveal_focused_column(&mut state, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 2);
+    }
+
+    #[test]
+    fn test_reveal_focused_column_scrolls_left() {
+        let (mut state, ws_id) = create_test_state(4);
+        state.update_workspace(ws_id, |ws| ws.scroll_offset = 2);
+
+        // Window 3 is visible, nothing changes
+        focus_window(&mut state, 3);
+        on_reveal_focused_column(&mut state, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 2);
+
+        focus_window(&mut state, 1);
+        on_reveal_focused_column(&mut state, 2);
+        assert_eq!(state.get_workspace(ws_id).unwrap().scroll_offset, 0);
+    }
+}
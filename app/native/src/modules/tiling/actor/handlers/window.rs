@@ -9,11 +9,25 @@
 
 use uuid::Uuid;
 
+use super::preset::apply_preset_to_window;
+use super::screen::screen_default_layout;
+use super::window_move::on_move_window_to_workspace;
+use super::workspace::{
+    auto_balance_workspace, on_switch_workspace, resolve_screen, show_workspace_wallpapers,
+};
+use crate::config::{
+    FloatRule, FloatingPosition, FocusNewWindows, OnEmptyWorkspace, WorkspaceConfig, get_config,
+};
 use crate::modules::tiling::actor::messages::{
-    GeometryUpdate, GeometryUpdateType, WindowCreatedInfo,
+    CommandFailed, CommandOutcome, GeometryUpdate, GeometryUpdateType, MinimizeOutcome,
+    TargetScreen, WindowCreatedInfo,
+};
+use crate::modules::tiling::effects::{
+    applescript, get_window_cache, should_ignore_geometry_events, window_ops,
 };
-use crate::modules::tiling::effects::{get_window_cache, should_ignore_geometry_events};
 use crate::modules::tiling::init::get_subscriber_handle;
+use crate::modules::tiling::layout::center_frame;
+use crate::modules::tiling::rules::{find_float_rule, find_matching_workspace};
 use crate::modules::tiling::state::{Rect, TilingState, Window, WindowIdList, Workspace};
 use crate::modules::tiling::tabs;
 
@@ -25,10 +39,30 @@ use crate::modules::tiling::tabs;
 /// 2. The focused workspace on the window's screen
 /// 3. A default workspace
 pub fn on_window_created(state: &mut TilingState, info: WindowCreatedInfo) {
+    let window_id = info.window_id;
     let workspace_id = on_window_created_internal(state, info);
 
-    // Notify subscriber that layout needs to be recomputed for this workspace
+    // Stay on the current workspace when the window opened on a hidden one
+    // and `tiling.focusNewWindows` says not to switch
+    if let Some(ws_id) = workspace_id
+        && !switches_to_new_window(state, ws_id, get_config().tiling.focus_new_windows)
+    {
+        tracing::debug!("Window {window_id} opened in the background on workspace {ws_id}");
+        state.mark_background_window(window_id);
+        sync_window_visibility_for_workspaces(state, &[], &[ws_id]);
+    }
+
+    // A new tiled window changes the workspace's window count
+    if let Some(ws_id) = workspace_id
+        && state.get_window(window_id).is_some_and(|w| !w.is_effectively_floating())
+    {
+        auto_balance_workspace(state, ws_id);
+    }
+
+    // Notify subscriber that layout needs to be recomputed for this workspace,
+    // opening the window from `tiling.animations.openFrom`
     if let (Some(ws_id), Some(handle)) = (workspace_id, get_subscriber_handle()) {
+        handle.notify_window_opened(window_id);
         handle.notify_layout_changed(ws_id, false);
     }
 }
@@ -65,6 +99,12 @@ fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo)
         return None;
     }
 
+    // Windows on screens listed in `tiling.ignoreScreens` are left alone
+    if state.is_on_ignored_screen(&info.frame) {
+        tracing::debug!("Window {} is on an ignored screen, not tracking", info.window_id);
+        return None;
+    }
+
     // Scan and register tabs for this app to update the tab registry
     tabs::scan_and_register_tabs_for_app(info.pid);
 
@@ -91,12 +131,14 @@ fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo)
     }
 
     // Create the window (this is a real window, not a tab)
-    let window = Window {
+    let mut window = Window {
         id: info.window_id,
         pid: info.pid,
         app_id: info.app_id,
         app_name: info.app_name,
         title: info.title,
+        role: info.role,
+        subrole: info.subrole,
         frame: info.frame,
         minimum_size: info.minimum_size,
         inferred_minimum_size: None,
@@ -105,13 +147,22 @@ fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo)
         is_minimized: info.is_minimized,
         is_fullscreen: info.is_fullscreen,
         is_hidden: false,
-        is_floating: false,  // TODO: Check window rules for float
+        is_floating: false,
+        is_sticky: false,
+        always_on_top: None,
         tab_group_id: None,  // Not using old tab detection
         is_active_tab: true, // Real windows are always "active"
         matched_rule: None,  // TODO: Set from window rules
     };
 
-    // Track window in state
+    // Float rules open matching windows floating, whatever workspace they land on
+    let float_rule = find_float_rule(&get_config().tiling.float_rules, &window)
+        .filter(|float_rule| float_rule.float);
+    window.is_floating = float_rule.is_some();
+
+    // Track window in state, remembering where it was before any layout
+    applescript::register(&window);
+    state.record_original_frame(window.id, window.frame);
     state.upsert_window(window);
 
     // Get the focused window in this workspace to insert after
@@ -141,12 +192,124 @@ fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo)
         focused_window_id
     );
 
+    if let Some(float_rule) = float_rule {
+        place_rule_floating_window(state, info.window_id, float_rule.preset.as_deref());
+    }
+
     Some(workspace_id)
 }
 
+/// Returns whether a window that opened on a workspace should switch to it.
+///
+/// Windows opening on a visible workspace never need a switch, so they
+/// always pass.
+fn switches_to_new_window(
+    state: &TilingState,
+    workspace_id: Uuid,
+    policy: FocusNewWindows,
+) -> bool {
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        return true;
+    };
+
+    if workspace.is_visible {
+        return true;
+    }
+
+    match policy {
+        FocusNewWindows::Always => true,
+        FocusNewWindows::Never => false,
+        FocusNewWindows::SameScreen => {
+            state.get_focus_state().focused_screen_id == Some(workspace.screen_id)
+        }
+    }
+}
+
+/// Places a window that a float rule opened floating.
+///
+/// Applies the rule's preset when set. Otherwise the window is centered on its
+/// screen when `floating.defaultPosition` is `center`, and left where it
+/// opened if not.
+fn place_rule_floating_window(state: &mut TilingState, window_id: u32, preset: Option<&str>) {
+    tracing::debug!("Window {window_id} matched a float rule (preset: {preset:?})");
+
+    let placed = preset.is_some_and(|preset| apply_preset_to_window(state, window_id, preset));
+
+    if !placed && get_config().tiling.floating.default_position == FloatingPosition::Center {
+        let frames = state.get_window(window_id).and_then(|window| {
+            let workspace = state.get_workspace(window.workspace_id)?;
+            let screen = state.get_screen(workspace.screen_id)?;
+            Some((window.frame, screen.visible_frame))
+        });
+
+        if let Some((frame, screen_frame)) = frames {
+            let target_frame = center_frame(&frame, &screen_frame);
+            state.update_window(window_id, |w| {
+                w.frame = target_frame;
+            });
+            let _ = window_ops::set_window_frame(window_id, &target_frame);
+        }
+    }
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_floating_changed(window_id, true);
+    }
+}
+
+/// Returns the workspace to switch to when `workspace` has no windows left,
+/// following `tiling.onEmptyWorkspace`.
+fn empty_workspace_target(
+    state: &TilingState,
+    workspace: &Workspace,
+    behavior: OnEmptyWorkspace,
+) -> Option<String> {
+    match behavior {
+        OnEmptyWorkspace::Stay => None,
+        OnEmptyWorkspace::SwitchPrev => state
+            .get_previous_workspace(workspace.screen_id)
+            .filter(|ws| !ws.is_scratchpad)
+            .map(|ws| ws.name),
+        OnEmptyWorkspace::SwitchFirst => state
+            .get_workspaces_for_screen(workspace.screen_id)
+            .into_iter()
+            .find(|ws| !ws.is_scratchpad)
+            .filter(|ws| ws.id != workspace.id)
+            .map(|ws| ws.name),
+    }
+}
+
+/// Leaves a visible workspace whose last window closed, if `behavior` (from
+/// `tiling.onEmptyWorkspace`) says to.
+///
+/// Only called when windows are closed, not when they are untracked.
+pub fn leave_empty_workspace(
+    state: &mut TilingState,
+    workspace_id: Uuid,
+    behavior: OnEmptyWorkspace,
+) {
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        return;
+    };
+    if !workspace.is_visible || workspace.is_scratchpad || !workspace.window_ids.is_empty() {
+        return;
+    }
+
+    if let Some(name) = empty_workspace_target(state, &workspace, behavior) {
+        tracing::debug!(
+            "tiling: workspace '{}' is empty, switching to '{name}' ({behavior:?})",
+            workspace.name
+        );
+        on_switch_workspace(state, &name);
+    }
+}
+
 /// Handles a window destroyed event.
 ///
-/// Removes the window from tracking and from its workspace.
+/// Removes the window from tracking and from its workspace. When the window
+/// was the shown tab of a native tab group, the tab shown in its place takes
+/// over its slot in the workspace so the window order is kept. When the
+/// focused workspace is left empty, `tiling.onEmptyWorkspace` decides whether
+/// to switch away from it.
 /// Returns the workspace ID if the window was tracked AND was a real window (for layout recomputation).
 /// Tabs return None since they don't affect layout.
 pub fn on_window_destroyed(state: &mut TilingState, window_id: u32) -> Option<uuid::Uuid> {
@@ -159,25 +322,76 @@ pub fn on_window_destroyed(state: &mut TilingState, window_id: u32) -> Option<uu
     }
 
     // Get the window info before removing
-    let window_info = state.get_window(window_id).map(|w| w.workspace_id);
-
-    let Some(workspace_id) = window_info else {
+    let Some(window) = state.get_window(window_id) else {
         tracing::debug!("tiling: window {window_id} was not tracked in state");
         // Still try to unregister from tab registry in case it was there
         tabs::unregister_tab(window_id);
+        state.take_released_window(window_id);
         return None;
     };
 
+    let workspace_id = window.workspace_id;
+    let was_focused_workspace = state.get_focus_state().focused_workspace_id == Some(workspace_id);
     tracing::debug!("tiling: window {window_id} workspace_id={workspace_id:?}");
 
+    // A tab of the same group may now be shown where the window was
+    let candidates = tabs::tab_candidates_for_app(window.pid);
+    let replacement = tabs::pick_tab_swap_target(&window.frame, &window.title, &candidates)
+        .and_then(|tab_id| candidates.into_iter().find(|tab| tab.window_id == tab_id));
+
+    let Some(tab) = replacement else {
+        untrack_window(state, window_id);
+
+        // Only the focused workspace is left, so focus isn't pulled to another screen
+        if was_focused_workspace {
+            leave_empty_workspace(state, workspace_id, get_config().tiling.on_empty_workspace);
+        }
+
+        tracing::debug!("tiling: returning workspace_id={workspace_id} for layout recalculation");
+        return Some(workspace_id);
+    };
+
     // Remove the window from state
     state.remove_window(window_id);
-    tracing::debug!("tiling: window {window_id} removed from state");
+    forget_window(state, window_id);
 
-    // Invalidate window cache entry for this window
-    get_window_cache().invalidate_window(window_id);
+    // The tab takes over the window's slot, so the window count stays as it was
+    tracing::debug!(
+        "tiling: tab {} takes the place of window {window_id}",
+        tab.window_id
+    );
+    tabs::unregister_tab(tab.window_id);
+    state.upsert_window(Window {
+        id: tab.window_id,
+        title: tab.title.clone(),
+        frame: tab.frame,
+        expected_frame: None,
+        inferred_minimum_size: None,
+        ..window
+    });
+    state.update_workspace(workspace_id, |ws| {
+        if let Some(index) = ws.window_ids.iter().position(|id| *id == window_id) {
+            ws.window_ids[index] = tab.window_id;
+        }
+    });
+
+    tracing::debug!("tiling: returning workspace_id={workspace_id} for layout recalculation");
+    Some(workspace_id)
+}
 
-    // Remove from workspace's window list
+/// Stops tracking a window without the side effects of it being closed.
+///
+/// Removes the window from state and from its workspace, and rebalances the
+/// workspace if the window was tiled. Unlike [`on_window_destroyed`], no tab
+/// takes its place and `tiling.onEmptyWorkspace` is not applied.
+///
+/// Returns the window as it was tracked.
+pub fn untrack_window(state: &mut TilingState, window_id: u32) -> Option<Window> {
+    let window = state.remove_window(window_id)?;
+    tracing::debug!("tiling: window {window_id} removed from state");
+    forget_window(state, window_id);
+
+    let workspace_id = window.workspace_id;
     state.update_workspace(workspace_id, |ws| {
         let before_count = ws.window_ids.len();
         ws.window_ids.retain(|id| *id != window_id);
@@ -196,19 +410,30 @@ pub fn on_window_destroyed(state: &mut TilingState, window_id: u32) -> Option<uu
         }
     });
 
+    if !window.is_effectively_floating() {
+        auto_balance_workspace(state, workspace_id);
+    }
+
+    Some(window)
+}
+
+/// Clears what is kept about a window outside of the window list: caches,
+/// focus, focus history and its original frame.
+fn forget_window(state: &mut TilingState, window_id: u32) {
+    // Invalidate window cache entry for this window
+    get_window_cache().invalidate_window(window_id);
+    applescript::unregister(window_id);
+
     // Clear focus if this was the focused window
     let focus = eyeball::Observable::get(&state.focus);
     if focus.focused_window_id == Some(window_id) {
-        tracing::debug!("tiling: cleared focus since destroyed window was focused");
+        tracing::debug!("tiling: cleared focus since removed window was focused");
         state.clear_focus();
     }
 
     // Remove window from focus history (it may have been the last focused window in some workspace)
     state.remove_window_from_focus_history(window_id);
-    tracing::debug!("tiling: removed window {window_id} from focus history");
-
-    tracing::debug!("tiling: returning workspace_id={workspace_id} for layout recalculation");
-    Some(workspace_id)
+    state.remove_original_frame(window_id);
 }
 
 /// Handles a window focused event.
@@ -222,6 +447,40 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
         return;
     };
 
+    // A window that opened in the background doesn't pull its workspace into view.
+    // Its app took keyboard focus, so give it back and hide the app again
+    if state.take_background_window(window_id)
+        && state.get_workspace(window.workspace_id).is_some_and(|ws| !ws.is_visible)
+    {
+        tracing::debug!("Window {window_id} opened in the background - keeping focus");
+        if let Some(previous_id) = window_to_refocus(state) {
+            let _ = window_ops::focus_window(previous_id);
+        }
+        sync_window_visibility_for_workspaces(state, &[], &[window.workspace_id]);
+        return;
+    }
+
+    // Scratchpad windows float over the current workspace, which stays focused
+    if state.get_workspace(window.workspace_id).is_some_and(|ws| ws.is_scratchpad) {
+        tracing::debug!("Scratchpad window {window_id} focused - keeping workspace");
+        state.set_focused_window(Some(window_id));
+        if let Some(handle) = get_subscriber_handle() {
+            handle.notify_focus_changed();
+        }
+        return;
+    }
+
+    // Sticky windows are shown on every workspace, so focusing one doesn't
+    // switch the screen back to its home workspace
+    if window.is_sticky {
+        tracing::debug!("Sticky window {window_id} focused - keeping workspace");
+        state.set_focused_window(Some(window_id));
+        if let Some(handle) = get_subscriber_handle() {
+            handle.notify_focus_changed();
+        }
+        return;
+    }
+
     tracing::debug!(
         "Window {} focused -> workspace {} (app: {})",
         window_id,
@@ -303,6 +562,7 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
             &workspaces_becoming_visible,
             &workspaces_becoming_hidden,
         );
+        show_workspace_wallpapers(state, &workspaces_becoming_visible);
 
         // Notify subscriber to apply layouts for newly visible workspaces
         if let Some(handle) = get_subscriber_handle() {
@@ -342,10 +602,29 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
     }
 }
 
+/// Returns the window to give keyboard focus back to: the focused window, or
+/// the last focused window of the focused workspace.
+fn window_to_refocus(state: &TilingState) -> Option<u32> {
+    let focus = state.get_focus_state();
+    focus
+        .focused_window_id
+        .filter(|&id| state.get_window(id).is_some())
+        .or_else(|| {
+            focus
+                .focused_workspace_id
+                .and_then(|id| state.get_workspace(id))
+                .and_then(|ws| ws.focused_window_id())
+        })
+}
+
 /// Syncs window visibility when workspaces change visibility.
 ///
 /// - Shows (unhides) apps that have windows in newly visible workspaces
 /// - Hides apps that have windows ONLY in hidden workspaces (not in any visible workspace)
+///
+/// Sticky windows count as visible wherever their workspace is, so their apps
+/// are never hidden, and so do scratchpad windows while the scratchpad is shown.
+/// Hiding is per app, so other windows of the same app stay visible as well.
 pub fn sync_window_visibility_for_workspaces(
     state: &TilingState,
     becoming_visible: &[Uuid],
@@ -364,9 +643,10 @@ pub fn sync_window_visibility_for_workspaces(
         "Syncing visibility - becoming_visible: {becoming_visible:?}, becoming_hidden: {becoming_hidden:?}"
     );
 
-    // Collect all currently visible workspace IDs
-    let visible_ws_ids: HashSet<Uuid> =
+    // Collect all currently visible workspace IDs, including a shown scratchpad
+    let mut visible_ws_ids: HashSet<Uuid> =
         state.get_visible_workspaces().iter().map(|ws| ws.id).collect();
+    visible_ws_ids.extend(state.get_shown_scratchpad_id());
 
     // Collect PIDs from windows in becoming-visible workspaces (need to unhide)
     let mut pids_to_show: HashSet<i32> = HashSet::new();
@@ -387,7 +667,7 @@ pub fn sync_window_visibility_for_workspaces(
     // Find PIDs that have windows in ANY visible workspace (shouldn't be hidden)
     let mut pids_in_visible: HashSet<i32> = HashSet::new();
     for window in state.windows.iter() {
-        if visible_ws_ids.contains(&window.workspace_id) {
+        if window.is_sticky || visible_ws_ids.contains(&window.workspace_id) {
             pids_in_visible.insert(window.pid);
         }
     }
@@ -516,6 +796,11 @@ fn detect_and_update_inferred_minimum(
         return None; // No expected frame set, can't detect mismatch
     };
 
+    // Fullscreen windows fill their own Space and aren't laid out
+    if window.is_fullscreen {
+        return None;
+    }
+
     // Check if the window already reports minimum_size (no need to infer)
     if window.minimum_size.is_some() {
         return None;
@@ -560,7 +845,10 @@ pub fn on_window_minimized(state: &mut TilingState, window_id: u32, minimized: b
 
     // Get workspace info before updating
     let workspace_info = state.get_window(window_id).and_then(|w| {
-        state.get_workspace(w.workspace_id).map(|ws| (ws.id, ws.name, ws.window_ids))
+        let changes_tiled_count = !w.is_effectively_floating() && w.is_minimized != minimized;
+        state
+            .get_workspace(w.workspace_id)
+            .map(|ws| (ws.id, ws.name, ws.window_ids, changes_tiled_count))
     });
 
     state.update_window(window_id, |w| {
@@ -568,7 +856,11 @@ pub fn on_window_minimized(state: &mut TilingState, window_id: u32, minimized: b
     });
 
     // Minimized state affects layout and window list
-    if let Some((ws_id, ws_name, window_ids)) = workspace_info {
+    if let Some((ws_id, ws_name, window_ids, changes_tiled_count)) = workspace_info {
+        if changes_tiled_count {
+            auto_balance_workspace(state, ws_id);
+        }
+
         // Notify subscriber to recalculate layout
         if let Some(handle) = get_subscriber_handle() {
             handle.notify_layout_changed(ws_id, false);
@@ -579,6 +871,68 @@ pub fn on_window_minimized(state: &mut TilingState, window_id: u32, minimized: b
     }
 }
 
+/// Returns the window of a workspace to restore: the last minimized one in
+/// its window order.
+fn minimized_window_to_restore(state: &TilingState, workspace_id: Uuid) -> Option<u32> {
+    let workspace = state.get_workspace(workspace_id)?;
+    workspace
+        .window_ids
+        .iter()
+        .rev()
+        .copied()
+        .find(|&id| state.get_window(id).is_some_and(|w| w.is_minimized))
+}
+
+/// Minimizes the focused window, or restores a minimized window of the
+/// focused workspace.
+///
+/// The state is updated right away instead of waiting for the AX
+/// notification, which arrives later and is then a no-op. A restored window
+/// goes back into the layout and is focused, since a minimized window can't
+/// be the focused one.
+pub fn on_set_minimized(
+    state: &mut TilingState,
+    minimized: bool,
+) -> CommandOutcome<MinimizeOutcome> {
+    let focus = state.get_focus_state();
+
+    let window_id = if minimized {
+        let Some(window_id) = focus.focused_window_id else {
+            return Err(CommandFailed::new("No focused window"));
+        };
+        if state.get_window(window_id).is_none_or(|w| w.is_minimized) {
+            return Err(CommandFailed::new("No focused window"));
+        }
+        window_id
+    } else {
+        let Some(workspace_id) = focus.focused_workspace_id else {
+            return Err(CommandFailed::new("No focused workspace"));
+        };
+        let Some(window_id) = minimized_window_to_restore(state, workspace_id) else {
+            return Err(CommandFailed::new(
+                "No minimized window in the focused workspace",
+            ));
+        };
+        window_id
+    };
+
+    // The subscriber minimizes the window, animating it out to
+    // `tiling.animations.closeTo` before the layout fills its space
+    match get_subscriber_handle() {
+        Some(handle) if minimized => handle.notify_minimize_window(window_id),
+        _ => {
+            let _ = window_ops::set_window_minimized(window_id, minimized);
+        }
+    }
+    on_window_minimized(state, window_id, minimized);
+
+    if !minimized {
+        let _ = window_ops::focus_window(window_id);
+    }
+
+    Ok(MinimizeOutcome::Done { window_id, minimized })
+}
+
 /// Handles a window title changed event.
 pub fn on_window_title_changed(state: &mut TilingState, window_id: u32, title: &str) {
     tracing::debug!("Handling window title changed: {window_id} to '{title}'");
@@ -589,6 +943,7 @@ pub fn on_window_title_changed(state: &mut TilingState, window_id: u32, title: &
     state.update_window(window_id, |w| {
         w.title = title.to_string();
     });
+    applescript::update_title(window_id, title);
 
     // Only emit event to frontend if window is in the focused workspace
     let focused_workspace_id = state.get_focus_state().focused_workspace_id;
@@ -597,21 +952,65 @@ pub fn on_window_title_changed(state: &mut TilingState, window_id: u32, title: &
     }
 }
 
+/// Handles a window whose title stopped changing.
+///
+/// Apps often open windows with a generic title and rename them later (e.g.
+/// "Untitled" to the project name), so title rules can't match when the
+/// window is created. Once the title settles, the window moves to the
+/// workspace its title rules now point to. Focus stays where it is, as a
+/// rename isn't a new window to switch to.
+pub fn on_window_title_settled(state: &mut TilingState, window_id: u32) {
+    let config = get_config();
+    let Some(workspace_id) =
+        find_workspace_by_title_rules(state, window_id, &config.tiling.workspaces)
+    else {
+        return;
+    };
+
+    tracing::debug!("Window {window_id} title matched a rule, moving to workspace {workspace_id}");
+    on_move_window_to_workspace(state, window_id, workspace_id);
+
+    if state.get_workspace(workspace_id).is_some_and(|ws| ws.is_visible) {
+        sync_window_visibility_for_workspaces(state, &[workspace_id], &[]);
+    } else {
+        sync_window_visibility_for_workspaces(state, &[], &[workspace_id]);
+    }
+}
+
 /// Handles a window fullscreen state changed event.
-pub fn on_window_fullscreen_changed(state: &mut TilingState, window_id: u32, fullscreen: bool) {
+///
+/// Native fullscreen windows live on their own Space, so they leave the
+/// layout while fullscreen and are laid out again when they exit.
+///
+/// Returns whether the window's workspace is laid out again, which only
+/// happens when the fullscreen state actually changed.
+pub fn on_window_fullscreen_changed(
+    state: &mut TilingState,
+    window_id: u32,
+    fullscreen: bool,
+) -> bool {
     tracing::debug!("Handling window fullscreen changed: {window_id} = {fullscreen}");
 
-    // Get workspace before updating
-    let workspace_id = state.get_window(window_id).map(|w| w.workspace_id);
+    // The event processor reports the first state it sees for each window,
+    // which usually matches what was tracked when the window was created
+    let Some(window) = state.get_window(window_id).filter(|w| w.is_fullscreen != fullscreen) else {
+        return false;
+    };
+    let ws_id = window.workspace_id;
 
     state.update_window(window_id, |w| {
         w.is_fullscreen = fullscreen;
     });
 
+    if !window.is_effectively_floating() {
+        auto_balance_workspace(state, ws_id);
+    }
+
     // Fullscreen state affects layout
-    if let (Some(ws_id), Some(handle)) = (workspace_id, get_subscriber_handle()) {
+    if let Some(handle) = get_subscriber_handle() {
         handle.notify_layout_changed(ws_id, false);
     }
+    true
 }
 
 /// Handles a batch of geometry updates.
@@ -626,6 +1025,7 @@ pub fn on_batched_geometry_updates(state: &mut TilingState, updates: &[GeometryU
     use crate::modules::tiling::events::{drag_state, mouse_monitor};
 
     let mouse_down = mouse_monitor::is_mouse_down();
+    retrack_released_windows(state, updates, mouse_down);
     let operation_in_progress = drag_state::is_operation_in_progress();
 
     // If mouse is down but no operation tracked yet, start tracking
@@ -657,7 +1057,7 @@ pub fn on_batched_geometry_updates(state: &mut TilingState, updates: &[GeometryU
                     .map(|w| drag_state::WindowSnapshot {
                         window_id: w.id,
                         original_frame: w.frame,
-                        is_floating: w.is_floating,
+                        is_floating: w.is_effectively_floating(),
                     })
                     .collect();
 
@@ -723,6 +1123,77 @@ pub fn on_batched_geometry_updates(state: &mut TilingState, updates: &[GeometryU
     }
 }
 
+/// Stops tracking the windows of a workspace that were dragged onto a screen
+/// listed in `tiling.ignoreScreens`, so they stay where the user put them.
+///
+/// The windows are untracked rather than destroyed, so the workspace isn't
+/// left by `tiling.onEmptyWorkspace`, and they are remembered so they can be
+/// tracked again once moved back to a managed screen.
+///
+/// Returns the IDs of the released windows.
+pub fn release_windows_on_ignored_screens(state: &mut TilingState, workspace_id: Uuid) -> Vec<u32> {
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        return Vec::new();
+    };
+
+    let released: Vec<u32> = workspace
+        .window_ids
+        .iter()
+        .copied()
+        .filter(|&id| state.get_window(id).is_some_and(|w| state.is_on_ignored_screen(&w.frame)))
+        .collect();
+
+    for &window_id in &released {
+        tracing::debug!("Window {window_id} was moved to an ignored screen, no longer tracking");
+        if let Some(window) = untrack_window(state, window_id) {
+            state.record_released_window(window);
+        }
+    }
+
+    released
+}
+
+/// Tracks released windows again once they are moved back off the ignored
+/// screens, as if they had just been created.
+///
+/// While the mouse is down the windows are tracked silently, so the drag is
+/// finished and laid out like any other.
+fn retrack_released_windows(state: &mut TilingState, updates: &[GeometryUpdate], mouse_down: bool) {
+    for update in updates {
+        if state.is_on_ignored_screen(&update.frame) {
+            continue;
+        }
+        let Some(window) = state.take_released_window(update.window_id) else {
+            continue;
+        };
+
+        tracing::debug!(
+            "Window {} was moved back to a managed screen, tracking",
+            window.id
+        );
+        let info = WindowCreatedInfo {
+            window_id: window.id,
+            pid: window.pid,
+            app_id: window.app_id,
+            app_name: window.app_name,
+            title: window.title,
+            role: window.role,
+            subrole: window.subrole,
+            frame: update.frame,
+            is_minimized: window.is_minimized,
+            is_fullscreen: window.is_fullscreen,
+            minimum_size: window.minimum_size,
+            tab_group_id: window.tab_group_id,
+            is_active_tab: window.is_active_tab,
+        };
+        if mouse_down {
+            on_window_created_silent(state, info);
+        } else {
+            on_window_created(state, info);
+        }
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -731,9 +1202,10 @@ pub fn on_batched_geometry_updates(state: &mut TilingState, updates: &[GeometryU
 ///
 /// Priority:
 /// 1. Window rules from config (match by `app_id`)
-/// 2. Focused workspace
-/// 3. First visible workspace
-/// 4. Create a default workspace
+/// 2. Float rules pinning the window to a screen
+/// 3. Focused workspace
+/// 4. First visible workspace
+/// 5. Create a default workspace
 fn find_workspace_for_window(state: &mut TilingState, info: &WindowCreatedInfo) -> Uuid {
     // Check window rules from config
     if let Some(workspace_id) = find_workspace_by_rules(state, info) {
@@ -746,6 +1218,19 @@ fn find_workspace_for_window(state: &mut TilingState, info: &WindowCreatedInfo)
         return workspace_id;
     }
 
+    // Check float rules that pin windows to a screen
+    if let Some(workspace_id) =
+        find_workspace_by_screen_rules(state, info, &get_config().tiling.float_rules)
+    {
+        tracing::debug!(
+            "Window {} (app={}) matched screen rule, assigned to workspace {:?}",
+            info.window_id,
+            info.app_id,
+            workspace_id
+        );
+        return workspace_id;
+    }
+
     // Try focused workspace as fallback
     if let Some(ws) = state.get_focused_workspace() {
         tracing::debug!(
@@ -777,12 +1262,22 @@ fn find_workspace_for_window(state: &mut TilingState, info: &WindowCreatedInfo)
 
 /// Finds a workspace for a window based on config rules.
 ///
-/// Checks each workspace's rules against the window's `app_id`/`app_name`/`title`.
+/// Apps claimed with `stache tiling workspace --claim-app` go to the claiming
+/// workspace first. Otherwise, checks each workspace's rules against the
+/// window's `app_id`/`app_name`/`title`.
 /// Returns the UUID of the first matching workspace, or None if no match.
 ///
 /// Rules use AND logic - all specified criteria must match.
 fn find_workspace_by_rules(state: &TilingState, info: &WindowCreatedInfo) -> Option<Uuid> {
-    use crate::config::get_config;
+    if !info.app_id.is_empty()
+        && let Some(ws) = state
+            .workspaces
+            .iter()
+            .find(|ws| ws.claimed_app_ids.iter().any(|id| id.eq_ignore_ascii_case(&info.app_id)))
+    {
+        tracing::debug!("Claim match: app_id='{}' → workspace '{}'", info.app_id, ws.name);
+        return Some(ws.id);
+    }
 
     let config = get_config();
     let workspace_configs = &config.tiling.workspaces;
@@ -807,6 +1302,70 @@ fn find_workspace_by_rules(state: &TilingState, info: &WindowCreatedInfo) -> Opt
     None
 }
 
+/// Finds the visible workspace of the screen a float rule pins a window to.
+///
+/// Only rules with a `screen` count, and the first matching one wins. Returns
+/// `None` if no rule matches or its screen isn't connected.
+fn find_workspace_by_screen_rules(
+    state: &TilingState,
+    info: &WindowCreatedInfo,
+    float_rules: &[FloatRule],
+) -> Option<Uuid> {
+    let screen = float_rules
+        .iter()
+        .filter_map(|float_rule| float_rule.rule.screen.as_deref().map(|s| (&float_rule.rule, s)))
+        .find(|(rule, _)| rule_matches_window(rule, info))
+        .map(|(_, screen)| screen)?;
+
+    let Some(screen_id) = resolve_screen(state, &TargetScreen::parse(screen)) else {
+        tracing::debug!(
+            "Screen rule for window {}: screen '{screen}' not found",
+            info.window_id
+        );
+        return None;
+    };
+
+    state
+        .workspaces
+        .iter()
+        .find(|ws| ws.screen_id == screen_id && ws.is_visible)
+        .map(|ws| ws.id)
+}
+
+/// Finds the workspace a window's title rules assign it to, if it isn't there yet.
+///
+/// Only a first matching rule that checks the title counts, so windows placed
+/// by app rules or moved by hand stay put. Windows of apps claimed with
+/// `stache tiling workspace --claim-app` are left alone as well.
+fn find_workspace_by_title_rules(
+    state: &TilingState,
+    window_id: u32,
+    workspace_configs: &[WorkspaceConfig],
+) -> Option<Uuid> {
+    let window = state.get_window(window_id)?;
+
+    let is_claimed = state
+        .workspaces
+        .iter()
+        .any(|ws| ws.claimed_app_ids.iter().any(|id| id.eq_ignore_ascii_case(&window.app_id)));
+    if is_claimed {
+        return None;
+    }
+
+    let rules = workspace_configs.iter().map(|ws| (ws.name.as_str(), ws.rules.as_slice()));
+    let matched = find_matching_workspace(&window, rules)?;
+    let rule = workspace_configs
+        .iter()
+        .find(|ws| ws.name == matched.workspace_name)
+        .and_then(|ws| ws.rules.get(matched.rule_index))?;
+    if !rule.matches_on_title() {
+        return None;
+    }
+
+    let workspace = state.get_workspace_by_name(&matched.workspace_name)?;
+    (workspace.id != window.workspace_id).then_some(workspace.id)
+}
+
 /// Checks if a rule matches a window.
 ///
 /// All specified criteria must match (AND logic).
@@ -842,11 +1401,16 @@ fn rule_matches_window(rule: &crate::config::WindowRule, info: &WindowCreatedInf
         return false;
     }
 
+    // Check role and subrole - case-insensitive exact match
+    if !rule.role_matches(info.role.as_deref(), info.subrole.as_deref()) {
+        return false;
+    }
+
     // All specified criteria matched
     true
 }
 
-/// Creates a default workspace.
+/// Creates a default workspace on the main screen, using that screen's layout.
 fn create_default_workspace(state: &TilingState) -> Workspace {
     let screen_id = state.get_main_screen().map_or(0, |s| s.id);
 
@@ -854,13 +1418,17 @@ fn create_default_workspace(state: &TilingState) -> Workspace {
         id: Uuid::now_v7(),
         name: "default".to_string(),
         screen_id,
-        layout: crate::modules::tiling::state::LayoutType::Dwindle,
+        layout: screen_default_layout(state, screen_id),
         is_visible: true,
         is_focused: true,
         window_ids: WindowIdList::new(),
         focused_window_index: None,
         split_ratios: Vec::new(),
+        master_count: None,
+        scroll_offset: 0,
         configured_screen: None,
+        claimed_app_ids: Vec::new(),
+        is_scratchpad: false,
     }
 }
 
@@ -872,7 +1440,7 @@ fn create_default_workspace(state: &TilingState) -> Workspace {
 #[allow(clippy::float_cmp)]
 mod tests {
     use super::*;
-    use crate::modules::tiling::state::LayoutType;
+    use crate::modules::tiling::state::{LayoutType, Screen};
 
     fn make_state_with_workspace() -> (TilingState, Uuid) {
         let mut state = TilingState::new();
@@ -887,7 +1455,11 @@ mod tests {
             window_ids: WindowIdList::new(),
             focused_window_index: None,
             split_ratios: Vec::new(),
+            master_count: None,
+            scroll_offset: 0,
             configured_screen: None,
+            claimed_app_ids: Vec::new(),
+            is_scratchpad: false,
         };
         let ws_id = ws.id;
         state.upsert_workspace(ws);
@@ -902,6 +1474,8 @@ mod tests {
             app_id: "com.test.app".to_string(),
             app_name: "Test App".to_string(),
             title: format!("Window {window_id}"),
+            role: None,
+            subrole: None,
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             is_minimized: false,
             is_fullscreen: false,
@@ -928,6 +1502,20 @@ mod tests {
         assert!(ws.window_ids.contains(&100));
     }
 
+    #[test]
+    fn test_default_workspace_uses_screen_layout() {
+        let mut state = TilingState::new();
+        state.upsert_screen(Screen {
+            id: 1,
+            is_main: true,
+            ..Default::default()
+        });
+
+        let ws = create_default_workspace(&state);
+        assert_eq!(ws.screen_id, 1);
+        assert_eq!(ws.layout, screen_default_layout(&state, 1));
+    }
+
     #[test]
     fn test_window_destroyed() {
         let (mut state, ws_id) = make_state_with_workspace();
@@ -944,6 +1532,26 @@ mod tests {
         assert!(!ws.window_ids.contains(&100));
     }
 
+    #[test]
+    fn test_window_created_records_original_frame() {
+        let (mut state, _) = make_state_with_workspace();
+        let mut info = make_window_info(100);
+        info.frame = Rect::new(120.0, 80.0, 640.0, 480.0);
+
+        on_window_created(&mut state, info);
+        on_window_moved(&mut state, 100, Rect::new(0.0, 0.0, 1920.0, 1080.0));
+
+        // A re-announced window keeps the frame it had when first tracked
+        on_window_created(&mut state, make_window_info(100));
+        assert_eq!(
+            state.get_original_frame(100),
+            Some(Rect::new(120.0, 80.0, 640.0, 480.0))
+        );
+
+        on_window_destroyed(&mut state, 100);
+        assert_eq!(state.get_original_frame(100), None);
+    }
+
     #[test]
     fn test_window_focused() {
         let (mut state, ws_id) = make_state_with_workspace();
@@ -962,6 +1570,263 @@ mod tests {
         assert!(ws.is_focused);
     }
 
+    #[test]
+    fn test_find_workspace_by_title_rules() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        let docs = Workspace::new("docs");
+        let docs_id = docs.id;
+        state.upsert_workspace(docs);
+
+        let configs: Vec<WorkspaceConfig> = serde_json::from_str(
+            r#"[
+                {"name": "docs", "rules": [{"title": "Project"}]},
+                {"name": "test", "rules": [{"appId": "com.test.app"}]}
+            ]"#,
+        )
+        .unwrap();
+
+        let mut info = make_window_info(100);
+        info.title = "Untitled".to_string();
+        on_window_created(&mut state, info);
+
+        // App rules don't move windows on title changes
+        assert_eq!(find_workspace_by_title_rules(&state, 100, &configs), None);
+
+        state.update_window(100, |w| w.title = "Project notes".to_string());
+        assert_eq!(
+            find_workspace_by_title_rules(&state, 100, &configs),
+            Some(docs_id)
+        );
+
+        // Claimed apps stay on the claiming workspace
+        state.update_workspace(ws_id, |ws| ws.claimed_app_ids = vec!["com.test.app".to_string()]);
+        assert_eq!(find_workspace_by_title_rules(&state, 100, &configs), None);
+        state.update_workspace(ws_id, |ws| ws.claimed_app_ids.clear());
+
+        // Nothing to do once the window is on the matching workspace
+        on_move_window_to_workspace(&mut state, 100, docs_id);
+        assert_eq!(find_workspace_by_title_rules(&state, 100, &configs), None);
+    }
+
+    #[test]
+    fn test_find_workspace_by_screen_rules() {
+        let (mut state, _) = make_state_with_workspace();
+        state.upsert_screen(Screen {
+            id: 2,
+            name: "Built-in Retina Display".to_string(),
+            is_main: false,
+            ..Default::default()
+        });
+        let mut laptop = Workspace::new("laptop");
+        laptop.screen_id = 2;
+        laptop.is_visible = true;
+        let laptop_id = laptop.id;
+        state.upsert_workspace(laptop);
+
+        let float_rules: Vec<FloatRule> = serde_json::from_str(
+            r#"[
+                {"appId": "com.test.app", "preset": "centered"},
+                {"appId": "com.test.app", "float": false, "screen": "secondary"}
+            ]"#,
+        )
+        .unwrap();
+
+        let info = make_window_info(100);
+        assert_eq!(
+            find_workspace_by_screen_rules(&state, &info, &float_rules),
+            Some(laptop_id)
+        );
+
+        // Other apps and disconnected screens are left to the usual fallbacks
+        let mut other = make_window_info(101);
+        other.app_id = "com.other.app".to_string();
+        assert_eq!(
+            find_workspace_by_screen_rules(&state, &other, &float_rules),
+            None
+        );
+
+        let float_rules: Vec<FloatRule> =
+            serde_json::from_str(r#"[{"appId": "com.test.app", "screen": "Studio Display"}]"#)
+                .unwrap();
+        assert_eq!(find_workspace_by_screen_rules(&state, &info, &float_rules), None);
+    }
+
+    #[test]
+    fn test_windows_on_ignored_screens_are_left_alone() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        state.set_ignored_screen_frames(vec![Rect::new(1920.0, 0.0, 1920.0, 1080.0)]);
+
+        // Windows already on the ignored screen at startup aren't tracked
+        let mut on_tv = make_window_info(100);
+        on_tv.frame = Rect::new(2200.0, 200.0, 800.0, 600.0);
+        on_window_created_silent(&mut state, on_tv);
+        on_window_created_silent(&mut state, make_window_info(101));
+        assert!(state.get_window(100).is_none());
+        assert_eq!(state.get_workspace(ws_id).unwrap().window_ids.to_vec(), vec![
+            101
+        ]);
+
+        // Windows dragged onto it are released
+        assert!(release_windows_on_ignored_screens(&mut state, ws_id).is_empty());
+        state.update_window(101, |w| w.frame = Rect::new(2400.0, 100.0, 800.0, 600.0));
+        assert_eq!(release_windows_on_ignored_screens(&mut state, ws_id), vec![101]);
+        assert!(state.get_window(101).is_none());
+        assert_eq!(state.released_window_ids(), vec![101]);
+
+        // Moving within the ignored screen keeps them released
+        let moved = |frame| GeometryUpdate {
+            window_id: 101,
+            frame,
+            update_type: GeometryUpdateType::Move,
+        };
+        retrack_released_windows(
+            &mut state,
+            &[moved(Rect::new(2600.0, 100.0, 800.0, 600.0))],
+            false,
+        );
+        assert!(state.get_window(101).is_none());
+
+        // Moving back to a managed screen tracks them again
+        retrack_released_windows(
+            &mut state,
+            &[moved(Rect::new(100.0, 100.0, 800.0, 600.0))],
+            false,
+        );
+        assert_eq!(state.get_window(101).map(|w| w.workspace_id), Some(ws_id));
+        assert!(state.released_window_ids().is_empty());
+    }
+
+    #[test]
+    fn test_untrack_window() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        on_window_created(&mut state, make_window_info(101));
+        on_window_focused(&mut state, 101);
+
+        let window = untrack_window(&mut state, 101).expect("window should be tracked");
+        assert_eq!(window.id, 101);
+        assert!(state.get_window(101).is_none());
+        assert!(state.get_focus_state().focused_window_id.is_none());
+        assert_eq!(state.get_workspace(ws_id).unwrap().window_ids.to_vec(), vec![
+            100
+        ]);
+        assert!(untrack_window(&mut state, 101).is_none());
+    }
+
+    /// Adds a hidden workspace on `screen_id` holding window 200.
+    fn add_hidden_workspace(state: &mut TilingState, screen_id: u32) -> Uuid {
+        let mut ws = Workspace::new("background");
+        ws.screen_id = screen_id;
+        ws.window_ids = WindowIdList::from_slice(&[200]);
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+        state.upsert_window(Window {
+            id: 200,
+            workspace_id: ws_id,
+            ..Default::default()
+        });
+        ws_id
+    }
+
+    #[test]
+    fn test_switches_to_new_window_policy() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        state.update_focus(|focus| focus.focused_screen_id = Some(1));
+        let same_screen_ws = add_hidden_workspace(&mut state, 1);
+
+        let mut other = Workspace::new("other");
+        other.screen_id = 2;
+        let other_screen_ws = other.id;
+        state.upsert_workspace(other);
+
+        // Visible workspaces never need a switch
+        assert!(switches_to_new_window(&state, ws_id, FocusNewWindows::Never));
+
+        assert!(switches_to_new_window(
+            &state,
+            same_screen_ws,
+            FocusNewWindows::Always
+        ));
+        assert!(switches_to_new_window(
+            &state,
+            other_screen_ws,
+            FocusNewWindows::Always
+        ));
+        assert!(!switches_to_new_window(
+            &state,
+            same_screen_ws,
+            FocusNewWindows::Never
+        ));
+        assert!(switches_to_new_window(
+            &state,
+            same_screen_ws,
+            FocusNewWindows::SameScreen
+        ));
+        assert!(!switches_to_new_window(
+            &state,
+            other_screen_ws,
+            FocusNewWindows::SameScreen
+        ));
+    }
+
+    #[test]
+    fn test_background_window_focus_keeps_workspace() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        on_window_focused(&mut state, 100);
+        let hidden_ws = add_hidden_workspace(&mut state, 1);
+
+        // The first focus of a background window is ignored
+        state.mark_background_window(200);
+        on_window_focused(&mut state, 200);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(100));
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(ws_id));
+        assert!(!state.get_workspace(hidden_ws).unwrap().is_visible);
+
+        // Keyboard focus goes back to the focused window
+        assert_eq!(window_to_refocus(&state), Some(100));
+    }
+
+    #[test]
+    fn test_window_to_refocus_falls_back_to_workspace() {
+        let (mut state, _) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        on_window_created(&mut state, make_window_info(101));
+        on_window_focused(&mut state, 101);
+
+        // Without a focused window, the workspace's last focused window is used
+        state.set_focused_window(None);
+        assert_eq!(window_to_refocus(&state), Some(101));
+    }
+
+    #[test]
+    fn test_sticky_window_focus_keeps_workspace() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        on_window_focused(&mut state, 100);
+        let home_ws = add_hidden_workspace(&mut state, 1);
+        state.update_window(200, |w| w.is_sticky = true);
+
+        on_window_focused(&mut state, 200);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(200));
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(ws_id));
+        assert!(state.get_workspace(ws_id).unwrap().is_visible);
+        assert!(!state.get_workspace(home_ws).unwrap().is_visible);
+    }
+
+    #[test]
+    fn test_unchanged_fullscreen_state_is_ignored() {
+        let (mut state, _) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+
+        // Reporting the tracked state doesn't lay the workspace out again
+        assert!(!on_window_fullscreen_changed(&mut state, 100, false));
+        assert!(on_window_fullscreen_changed(&mut state, 100, true));
+        assert!(state.get_window(100).unwrap().is_fullscreen);
+        assert!(!on_window_fullscreen_changed(&mut state, 100, true));
+        assert!(!on_window_fullscreen_changed(&mut state, 999, true));
+    }
+
     #[test]
     fn test_window_minimized() {
         let (mut state, _) = make_state_with_workspace();
@@ -978,6 +1843,22 @@ mod tests {
         assert!(!window.is_minimized);
     }
 
+    #[test]
+    fn test_minimized_window_to_restore() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        for id in [100, 200, 300] {
+            on_window_created(&mut state, make_window_info(id));
+        }
+        assert_eq!(minimized_window_to_restore(&state, ws_id), None);
+
+        on_window_minimized(&mut state, 100, true);
+        on_window_minimized(&mut state, 200, true);
+        assert_eq!(minimized_window_to_restore(&state, ws_id), Some(200));
+
+        on_window_minimized(&mut state, 200, false);
+        assert_eq!(minimized_window_to_restore(&state, ws_id), Some(100));
+    }
+
     #[test]
     fn test_window_moved() {
         let (mut state, _) = make_state_with_workspace();
@@ -1019,6 +1900,75 @@ mod tests {
         assert_eq!(state.get_window(200).unwrap().frame.x, 420.0);
     }
 
+    #[test]
+    fn test_empty_workspace_target() {
+        let (mut state, first_id) = make_state_with_workspace();
+        let mut other = Workspace::new("other");
+        other.screen_id = 1;
+        let other_id = other.id;
+        let mut last = Workspace::new("last");
+        last.screen_id = 1;
+        let last_id = last.id;
+        let mut elsewhere = Workspace::new("elsewhere");
+        elsewhere.screen_id = 2;
+        let elsewhere_id = elsewhere.id;
+        state.upsert_workspace(other);
+        state.upsert_workspace(last);
+        state.upsert_workspace(elsewhere);
+
+        // "other" was focused before "last"; "elsewhere" is on another screen
+        state.update_workspace(first_id, |ws| ws.is_visible = false);
+        state.update_workspace(last_id, |ws| ws.is_visible = true);
+        state.set_focused_workspace(Some(other_id));
+        state.set_focused_workspace(Some(elsewhere_id));
+        state.set_focused_workspace(Some(last_id));
+
+        let last = state.get_workspace(last_id).unwrap();
+        assert_eq!(
+            empty_workspace_target(&state, &last, OnEmptyWorkspace::Stay),
+            None
+        );
+        assert_eq!(
+            empty_workspace_target(&state, &last, OnEmptyWorkspace::SwitchPrev).as_deref(),
+            Some("other")
+        );
+        assert_eq!(
+            empty_workspace_target(&state, &last, OnEmptyWorkspace::SwitchFirst).as_deref(),
+            Some("test")
+        );
+
+        // The first workspace has nowhere to go with "switch-first"
+        let first = state.get_workspace(first_id).unwrap();
+        assert_eq!(
+            empty_workspace_target(&state, &first, OnEmptyWorkspace::SwitchFirst),
+            None
+        );
+    }
+
+    #[test]
+    fn test_leave_empty_workspace() {
+        let (mut state, first_id) = make_state_with_workspace();
+        let mut other = Workspace::new("other");
+        other.screen_id = 1;
+        let other_id = other.id;
+        state.upsert_workspace(other);
+        state.set_focused_workspace(Some(first_id));
+        on_switch_workspace(&mut state, "other");
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(other_id));
+
+        // Workspaces with windows, or with "stay", are kept
+        on_window_created(&mut state, make_window_info(100));
+        assert_eq!(state.get_window(100).map(|w| w.workspace_id), Some(other_id));
+        leave_empty_workspace(&mut state, other_id, OnEmptyWorkspace::SwitchPrev);
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(other_id));
+        untrack_window(&mut state, 100);
+        leave_empty_workspace(&mut state, other_id, OnEmptyWorkspace::Stay);
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(other_id));
+
+        leave_empty_workspace(&mut state, other_id, OnEmptyWorkspace::SwitchPrev);
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(first_id));
+    }
+
     #[test]
     fn test_destroy_focused_window_clears_focus() {
         let (mut state, _) = make_state_with_workspace();
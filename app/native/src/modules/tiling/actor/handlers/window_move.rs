@@ -1,14 +1,19 @@
 //! Window movement command handlers.
 //!
 //! These handlers manage moving windows between workspaces, swapping windows,
-//! toggling floating state, and sending windows to screens.
+//! toggling floating and sticky state, and sending windows to screens.
 
 use uuid::Uuid;
 
-use super::workspace::resolve_screen;
-use crate::modules::tiling::actor::messages::TargetScreen;
+use super::window::sync_window_visibility_for_workspaces;
+use super::workspace::{auto_balance_workspace, on_switch_workspace, resolve_screen};
+use crate::config::{FloatingPosition, get_config};
+use crate::modules::tiling::actor::messages::{
+    CommandFailed, CommandOutcome, SendWindowOutcome, TargetScreen, ToggleAction,
+};
 use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::state::TilingState;
+use crate::modules::tiling::layout::center_frame;
+use crate::modules::tiling::state::{LayoutType, TilingState};
 
 // ============================================================================
 // Move Window to Workspace
@@ -22,6 +27,7 @@ pub fn on_move_window_to_workspace(state: &mut TilingState, window_id: u32, work
     };
 
     let old_workspace_id = window.workspace_id;
+    let is_tiled = !window.is_effectively_floating();
     if old_workspace_id == workspace_id {
         tracing::debug!("move_window: window {window_id} already in workspace {workspace_id}");
         return;
@@ -54,6 +60,11 @@ pub fn on_move_window_to_workspace(state: &mut TilingState, window_id: u32, work
 
     tracing::debug!("Moved window {window_id} to workspace {workspace_id}");
 
+    if is_tiled {
+        auto_balance_workspace(state, old_workspace_id);
+        auto_balance_workspace(state, workspace_id);
+    }
+
     // Notify subscriber to recalculate layouts for both workspaces
     if let Some(handle) = get_subscriber_handle() {
         handle.notify_layout_changed(old_workspace_id, true);
@@ -61,6 +72,52 @@ pub fn on_move_window_to_workspace(state: &mut TilingState, window_id: u32, work
     }
 }
 
+/// Send the focused window to the named workspace.
+///
+/// With `follow`, switches to the workspace afterwards and keeps the window
+/// focused. Otherwise focus stays on the current workspace, and the window's
+/// app is hidden when the target workspace isn't visible.
+pub fn on_send_window_to_workspace(
+    state: &mut TilingState,
+    workspace: &str,
+    follow: bool,
+) -> CommandOutcome<SendWindowOutcome> {
+    let Some(target) = state.get_workspace_by_name(workspace) else {
+        return Err(CommandFailed::new(format!("Workspace '{workspace}' not found")));
+    };
+
+    let Some(window_id) = state.get_focus_state().focused_window_id else {
+        return Err(CommandFailed::new("No focused window"));
+    };
+
+    if state.get_window(window_id).is_none() {
+        return Err(CommandFailed::new(format!("Window {window_id} not found")));
+    }
+
+    on_move_window_to_workspace(state, window_id, target.id);
+
+    if follow {
+        // Switching focuses the workspace's last focused window, so make it this one
+        state.record_focus_history(target.id, window_id);
+        on_switch_workspace(state, workspace);
+    } else if !target.is_visible {
+        sync_window_visibility_for_workspaces(state, &[], &[target.id]);
+    }
+
+    let screen = state
+        .get_screen(target.screen_id)
+        .map_or_else(|| format!("screen-{}", target.screen_id), |s| s.name);
+
+    tracing::debug!("Sent window {window_id} to workspace '{workspace}' (follow={follow})");
+
+    Ok(SendWindowOutcome::Sent {
+        window_id,
+        workspace: target.name,
+        screen,
+        visible: follow || target.is_visible,
+    })
+}
+
 // ============================================================================
 // Swap Windows
 // ============================================================================
@@ -111,6 +168,66 @@ pub fn on_swap_windows(state: &mut TilingState, window_id_a: u32, window_id_b: u
     }
 }
 
+// ============================================================================
+// Promote Window
+// ============================================================================
+
+/// Move the focused window to the front of its workspace's window order.
+///
+/// In the master layout this makes it the master window; in the other tiled
+/// layouts it takes the first position. Does nothing for stacking (monocle,
+/// tabbed) and floating workspaces, or when the focused window isn't tiled.
+/// Focus stays on the promoted window.
+pub fn on_promote_window(state: &mut TilingState) {
+    let focus = state.get_focus_state();
+    let Some(window_id) = focus.focused_window_id else {
+        tracing::debug!("promote_window: no focused window");
+        return;
+    };
+
+    let Some(window) = state.get_window(window_id) else {
+        tracing::warn!("promote_window: window {window_id} not found");
+        return;
+    };
+    if !window.is_layoutable() {
+        tracing::debug!("promote_window: window {window_id} is not tiled");
+        return;
+    }
+
+    let workspace_id = window.workspace_id;
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        return;
+    };
+    if workspace.layout.is_stacking() || workspace.layout == LayoutType::Floating {
+        tracing::debug!(
+            "promote_window: {:?} layout has no primary position",
+            workspace.layout
+        );
+        return;
+    }
+
+    let first_tiled = workspace
+        .window_ids
+        .iter()
+        .find(|&&id| state.get_window(id).is_some_and(|w| w.is_layoutable()));
+    if first_tiled == Some(&window_id) {
+        tracing::debug!("promote_window: window {window_id} is already first");
+        return;
+    }
+
+    state.update_workspace(workspace_id, |ws| {
+        ws.window_ids.retain(|id| *id != window_id);
+        ws.window_ids.insert(0, window_id);
+        ws.focused_window_index = Some(0);
+    });
+
+    tracing::debug!("Promoted window {window_id} in workspace {workspace_id}");
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
 // ============================================================================
 // Toggle Floating
 // ============================================================================
@@ -123,11 +240,17 @@ pub fn on_toggle_floating(state: &mut TilingState, window_id: u32) {
     };
 
     let workspace_id = window.workspace_id;
+    let is_sticky = window.is_sticky;
     let new_floating = !window.is_floating;
     state.update_window(window_id, |w| {
         w.is_floating = new_floating;
     });
 
+    // Sticky windows stay out of the layout either way
+    if !is_sticky {
+        auto_balance_workspace(state, workspace_id);
+    }
+
     tracing::debug!("Window {window_id} floating = {new_floating}");
 
     // Notify subscriber about floating change and layout recalculation
@@ -137,6 +260,150 @@ pub fn on_toggle_floating(state: &mut TilingState, window_id: u32) {
     }
 }
 
+/// Change the floating state of the focused window.
+///
+/// Floating windows are left out of the tiled layout. When floating is turned
+/// on and `default_position` is `Center`, the window is centered on its
+/// screen; otherwise it stays where it is. Turning floating off puts the
+/// window back into the workspace order and re-applies the layout.
+pub fn on_set_floating(
+    state: &mut TilingState,
+    action: ToggleAction,
+    default_position: FloatingPosition,
+) {
+    let Some(window_id) = state.get_focus_state().focused_window_id else {
+        tracing::debug!("set_floating: no focused window");
+        return;
+    };
+
+    let Some(window) = state.get_window(window_id) else {
+        tracing::warn!("set_floating: window {window_id} not found");
+        return;
+    };
+
+    let workspace_id = window.workspace_id;
+    let is_sticky = window.is_sticky;
+    let current_frame = window.frame;
+    let new_floating = action.apply(window.is_floating);
+    if new_floating == window.is_floating {
+        return;
+    }
+
+    state.update_window(window_id, |w| {
+        w.is_floating = new_floating;
+    });
+
+    if new_floating {
+        let screen_frame = state
+            .get_workspace(workspace_id)
+            .and_then(|ws| state.get_screen(ws.screen_id))
+            .map(|screen| screen.visible_frame);
+
+        if default_position == FloatingPosition::Center
+            && let Some(screen_frame) = screen_frame
+        {
+            let target_frame = center_frame(&current_frame, &screen_frame);
+            state.update_window(window_id, |w| {
+                w.frame = target_frame;
+            });
+            let _ = crate::modules::tiling::effects::window_ops::set_window_frame(
+                window_id,
+                &target_frame,
+            );
+        }
+    } else {
+        state.update_workspace(workspace_id, |ws| {
+            if !ws.window_ids.contains(&window_id) {
+                ws.window_ids.push(window_id);
+            }
+        });
+    }
+
+    tracing::debug!("Window {window_id} floating = {new_floating}");
+
+    if !is_sticky {
+        auto_balance_workspace(state, workspace_id);
+    }
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_floating_changed(window_id, new_floating || is_sticky);
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
+// ============================================================================
+// Sticky Windows
+// ============================================================================
+
+/// Change the sticky state of the focused window.
+///
+/// Sticky windows stay visible on every workspace and, like floating windows,
+/// are excluded from the tiled layout. The flag lives in the tiling state, so
+/// it survives workspace switches for the rest of the session.
+pub fn on_set_sticky(state: &mut TilingState, action: ToggleAction) {
+    let Some(window_id) = state.get_focus_state().focused_window_id else {
+        tracing::debug!("set_sticky: no focused window");
+        return;
+    };
+
+    let Some(window) = state.get_window(window_id) else {
+        tracing::warn!("set_sticky: window {window_id} not found");
+        return;
+    };
+
+    let workspace_id = window.workspace_id;
+    let is_floating = window.is_floating;
+    let new_sticky = action.apply(window.is_sticky);
+    if new_sticky == window.is_sticky {
+        return;
+    }
+
+    state.update_window(window_id, |w| {
+        w.is_sticky = new_sticky;
+    });
+
+    tracing::debug!("Window {window_id} sticky = {new_sticky}");
+
+    if !is_floating {
+        auto_balance_workspace(state, workspace_id);
+    }
+
+    // Sticky windows get floating borders and leave the tiled layout
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_floating_changed(window_id, new_sticky || is_floating);
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
+/// Change whether the focused window is kept above tiled windows.
+///
+/// The choice is stored on the window and overrides `tiling.floatingAlwaysOnTop`
+/// for it. It only takes effect while the window is floating or sticky.
+pub fn on_set_always_on_top(state: &mut TilingState, action: ToggleAction) {
+    let Some(window_id) = state.get_focus_state().focused_window_id else {
+        tracing::debug!("set_always_on_top: no focused window");
+        return;
+    };
+
+    let Some(window) = state.get_window(window_id) else {
+        tracing::warn!("set_always_on_top: window {window_id} not found");
+        return;
+    };
+
+    let current = window.always_on_top.unwrap_or(get_config().tiling.floating_always_on_top);
+    let new_value = action.apply(current);
+    state.update_window(window_id, |w| {
+        w.always_on_top = Some(new_value);
+    });
+
+    tracing::debug!("Window {window_id} always-on-top = {new_value}");
+
+    // Re-stack right away instead of waiting for the next layout or focus change
+    if new_value && let Some(handle) = get_subscriber_handle() {
+        handle.notify_focus_changed();
+    }
+}
+
 // ============================================================================
 // Send Window to Screen
 // ============================================================================
@@ -204,7 +471,7 @@ pub fn on_send_window_to_screen(state: &mut TilingState, target_screen: &TargetS
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::tiling::state::{Screen, Window, Workspace};
+    use crate::modules::tiling::state::{Rect, Screen, Window, Workspace};
 
     fn create_test_state() -> TilingState {
         let mut state = TilingState::new();
@@ -274,6 +541,110 @@ mod tests {
         assert!(ws2.window_ids.contains(&100));
     }
 
+    #[test]
+    fn test_send_window_to_workspace_stay() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        let outcome = on_send_window_to_workspace(&mut state, "workspace2", false);
+
+        assert_eq!(
+            outcome,
+            Ok(SendWindowOutcome::Sent {
+                window_id: 100,
+                workspace: "workspace2".to_string(),
+                screen: "Test Screen".to_string(),
+                visible: false,
+            })
+        );
+        assert_eq!(state.get_window(100).unwrap().workspace_id, ws2_id);
+
+        // Focus stays on the current workspace
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(ws1_id));
+    }
+
+    #[test]
+    fn test_send_window_to_workspace_follow() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+
+        add_window_to_workspace(&mut state, 50, ws2_id);
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        let outcome = on_send_window_to_workspace(&mut state, "workspace2", true);
+
+        assert!(matches!(
+            outcome,
+            Ok(SendWindowOutcome::Sent { visible: true, .. })
+        ));
+        assert!(state.get_workspace(ws2_id).unwrap().is_visible);
+        assert!(!state.get_workspace(ws1_id).unwrap().is_visible);
+
+        // The sent window keeps focus rather than the workspace's first window
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_workspace_id, Some(ws2_id));
+        assert_eq!(focus.focused_window_id, Some(100));
+    }
+
+    #[test]
+    fn test_send_window_to_workspace_on_other_screen() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        state.upsert_screen(Screen {
+            id: 2,
+            name: "Secondary".to_string(),
+            ..Default::default()
+        });
+        let mut ws3 = Workspace::new("workspace3");
+        ws3.screen_id = 2;
+        ws3.is_visible = true;
+        let ws3_id = ws3.id;
+        state.upsert_workspace(ws3);
+
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        add_window_to_workspace(&mut state, 200, ws1_id);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        let outcome = on_send_window_to_workspace(&mut state, "workspace3", false);
+
+        assert_eq!(
+            outcome,
+            Ok(SendWindowOutcome::Sent {
+                window_id: 100,
+                workspace: "workspace3".to_string(),
+                screen: "Secondary".to_string(),
+                visible: true,
+            })
+        );
+        assert_eq!(state.get_window(100).unwrap().workspace_id, ws3_id);
+        assert_eq!(state.get_workspace(ws1_id).unwrap().window_ids.as_slice(), &[
+            200
+        ]);
+    }
+
+    #[test]
+    fn test_send_window_to_workspace_failures() {
+        let mut state = create_test_state();
+
+        assert!(matches!(
+            on_send_window_to_workspace(&mut state, "missing", false),
+            Err(CommandFailed { .. })
+        ));
+
+        // No focused window
+        assert!(matches!(
+            on_send_window_to_workspace(&mut state, "workspace2", false),
+            Err(CommandFailed { .. })
+        ));
+    }
+
     #[test]
     fn test_swap_windows() {
         let mut state = create_test_state();
@@ -295,6 +666,52 @@ mod tests {
         assert_eq!(ws.window_ids.as_slice(), &[300, 200, 100]);
     }
 
+    fn promote_in_layout(layout: LayoutType) -> Vec<u32> {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_workspace(ws_id, |ws| ws.layout = layout);
+
+        for id in [100, 200, 300] {
+            add_window_to_workspace(&mut state, id, ws_id);
+        }
+        state.update_focus(|focus| focus.focused_window_id = Some(300));
+
+        on_promote_window(&mut state);
+
+        let ws = state.get_workspace(ws_id).unwrap();
+        ws.window_ids.to_vec()
+    }
+
+    #[test]
+    fn test_promote_window_moves_to_front() {
+        assert_eq!(promote_in_layout(LayoutType::Master), vec![300, 100, 200]);
+        assert_eq!(promote_in_layout(LayoutType::Dwindle), vec![300, 100, 200]);
+    }
+
+    #[test]
+    fn test_promote_window_ignores_stacking_and_floating() {
+        assert_eq!(promote_in_layout(LayoutType::Monocle), vec![100, 200, 300]);
+        assert_eq!(promote_in_layout(LayoutType::Tabbed), vec![100, 200, 300]);
+        assert_eq!(promote_in_layout(LayoutType::Floating), vec![100, 200, 300]);
+    }
+
+    #[test]
+    fn test_promote_window_keeps_focus() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_workspace(ws_id, |ws| ws.layout = LayoutType::Master);
+
+        add_window_to_workspace(&mut state, 100, ws_id);
+        add_window_to_workspace(&mut state, 200, ws_id);
+        state.update_focus(|focus| focus.focused_window_id = Some(200));
+
+        on_promote_window(&mut state);
+
+        let ws = state.get_workspace(ws_id).unwrap();
+        assert_eq!(ws.focused_window_index, Some(0));
+        assert_eq!(state.get_focus_state().focused_window_id, Some(200));
+    }
+
     #[test]
     fn test_toggle_floating() {
         let mut state = create_test_state();
@@ -316,4 +733,118 @@ mod tests {
         let window = state.get_window(100).unwrap();
         assert!(!window.is_floating);
     }
+
+    #[test]
+    fn test_set_floating() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws_id);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        on_set_floating(&mut state, ToggleAction::Toggle, FloatingPosition::Default);
+        let window = state.get_window(100).unwrap();
+        assert!(window.is_floating);
+        assert!(!window.is_layoutable());
+
+        on_set_floating(&mut state, ToggleAction::On, FloatingPosition::Default);
+        assert!(state.get_window(100).unwrap().is_floating);
+
+        on_set_floating(&mut state, ToggleAction::Toggle, FloatingPosition::Default);
+        let window = state.get_window(100).unwrap();
+        assert!(!window.is_floating);
+        assert!(window.is_layoutable());
+        assert!(state.get_workspace(ws_id).unwrap().window_ids.contains(&100));
+    }
+
+    #[test]
+    fn test_set_floating_centers_window() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.upsert_screen(Screen {
+            id: 1,
+            name: "Test Screen".to_string(),
+            is_main: true,
+            visible_frame: Rect::new(0.0, 0.0, 1920.0, 1080.0),
+            ..Default::default()
+        });
+
+        add_window_to_workspace(&mut state, 100, ws_id);
+        state.update_window(100, |w| w.frame = Rect::new(0.0, 0.0, 800.0, 600.0));
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        on_set_floating(&mut state, ToggleAction::On, FloatingPosition::Center);
+        assert_eq!(
+            state.get_window(100).unwrap().frame,
+            Rect::new(560.0, 240.0, 800.0, 600.0)
+        );
+
+        // Turning floating off leaves the frame to the layout
+        on_set_floating(&mut state, ToggleAction::Off, FloatingPosition::Center);
+        assert!(!state.get_window(100).unwrap().is_floating);
+    }
+
+    #[test]
+    fn test_set_floating_keeps_position() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws_id);
+        let frame = Rect::new(10.0, 20.0, 800.0, 600.0);
+        state.update_window(100, |w| w.frame = frame);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        on_set_floating(&mut state, ToggleAction::On, FloatingPosition::Default);
+        assert_eq!(state.get_window(100).unwrap().frame, frame);
+    }
+
+    #[test]
+    fn test_set_sticky() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws_id);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        on_set_sticky(&mut state, ToggleAction::Toggle);
+        let window = state.get_window(100).unwrap();
+        assert!(window.is_sticky);
+        assert!(!window.is_layoutable());
+
+        on_set_sticky(&mut state, ToggleAction::On);
+        assert!(state.get_window(100).unwrap().is_sticky);
+
+        on_set_sticky(&mut state, ToggleAction::Toggle);
+        assert!(!state.get_window(100).unwrap().is_sticky);
+    }
+
+    #[test]
+    fn test_set_sticky_without_focus() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws_id);
+
+        on_set_sticky(&mut state, ToggleAction::On);
+        assert!(!state.get_window(100).unwrap().is_sticky);
+    }
+
+    #[test]
+    fn test_set_always_on_top() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws_id);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+        assert_eq!(state.get_window(100).unwrap().always_on_top, None);
+
+        on_set_always_on_top(&mut state, ToggleAction::On);
+        assert_eq!(state.get_window(100).unwrap().always_on_top, Some(true));
+
+        on_set_always_on_top(&mut state, ToggleAction::Toggle);
+        assert_eq!(state.get_window(100).unwrap().always_on_top, Some(false));
+
+        on_set_always_on_top(&mut state, ToggleAction::Toggle);
+        assert_eq!(state.get_window(100).unwrap().always_on_top, Some(true));
+    }
 }
@@ -1,14 +1,22 @@
 //! Workspace command handlers.
 //!
-//! These handlers manage workspace switching, cycling, balancing,
-//! and sending workspaces to different screens.
+//! These handlers manage workspace switching, cycling, jumping back to the
+//! previous workspace, creation, renaming, balancing, merging, and sending
+//! workspaces to different screens.
 
 use uuid::Uuid;
 
+use super::screen::screen_default_layout;
 use super::window::sync_window_visibility_for_workspaces;
-use crate::modules::tiling::actor::messages::TargetScreen;
+use crate::config::get_config;
+use crate::modules::tiling::actor::messages::{
+    ClaimAppOutcome, CommandFailed, CommandOutcome, MergeWorkspaceOutcome, TargetScreen,
+    WorkspaceEditOutcome,
+};
+use crate::modules::tiling::effects::{TransitionKind, window_ops};
 use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::state::TilingState;
+use crate::modules::tiling::layout::center_frame;
+use crate::modules::tiling::state::{TilingState, Workspace};
 
 // ============================================================================
 // Workspace Switching
@@ -26,6 +34,11 @@ pub fn on_switch_workspace(state: &mut TilingState, name: &str) {
         return;
     };
 
+    if workspace.is_scratchpad {
+        tracing::warn!("Workspace '{name}' is the scratchpad - use the scratchpad toggle instead");
+        return;
+    }
+
     let workspace_id = workspace.id;
     let screen_id = workspace.screen_id;
 
@@ -100,7 +113,7 @@ pub fn on_switch_workspace(state: &mut TilingState, name: &str) {
         }
         // Show borders and apply layout for new workspace
         handle.notify_visibility_changed(workspace_id, true);
-        handle.notify_layout_changed(workspace_id, true);
+        handle.notify_layout_changed_as(workspace_id, true, TransitionKind::WorkspaceSwitch);
     }
 
     // Focus a window in the new workspace, preferring focus history
@@ -141,6 +154,9 @@ pub fn on_switch_workspace(state: &mut TilingState, name: &str) {
         handle.notify_focus_changed();
     }
 
+    // Show the workspace's own wallpaper, if it has one
+    show_workspace_wallpapers(state, &[workspace_id]);
+
     // Emit workspace changed event to frontend
     let screen_name = state
         .get_screen(screen_id)
@@ -153,143 +169,244 @@ pub fn on_switch_workspace(state: &mut TilingState, name: &str) {
     );
 }
 
+/// Returns the `wallpaper` configured for a workspace.
+fn workspace_wallpaper(name: &str) -> Option<String> {
+    get_config()
+        .tiling
+        .workspaces
+        .iter()
+        .find(|ws| ws.name == name)
+        .and_then(|ws| ws.wallpaper.clone())
+}
+
+/// Shows the configured wallpapers of workspaces that became visible, each on
+/// its own screen. Workspaces without a `wallpaper` keep the current one.
+pub fn show_workspace_wallpapers(state: &TilingState, workspace_ids: &[Uuid]) {
+    for ws in workspace_ids.iter().filter_map(|id| state.get_workspace(*id)) {
+        if let Some(wallpaper) = workspace_wallpaper(&ws.name) {
+            crate::modules::wallpaper::show_workspace_wallpaper(ws.screen_id, &wallpaper);
+        }
+    }
+}
+
 // ============================================================================
 // Workspace Cycling
 // ============================================================================
 
 use crate::modules::tiling::actor::CycleDirection;
 
-/// Cycle through workspaces in a direction.
+/// Cycle through the workspaces of the focused screen.
 ///
-/// Cycles through visible workspaces on the currently focused screen.
-pub fn on_cycle_workspace(state: &mut TilingState, direction: CycleDirection) {
-    let focus = state.get_focus_state();
-    let Some(current_workspace_id) = focus.focused_workspace_id else {
-        tracing::debug!("cycle_workspace: no focused workspace");
-        return;
+/// Starting from the screen's visible workspace, switches to the next or
+/// previous workspace in configuration order, wrapping around at the ends.
+/// Workspaces assigned to other screens are skipped.
+///
+/// Returns the name of the workspace switched to, or `None` if there is no
+/// focused screen or no other workspace on it.
+pub fn on_cycle_workspace(state: &mut TilingState, direction: CycleDirection) -> Option<String> {
+    let Some(screen_id) = state.get_focus_state().focused_screen_id else {
+        tracing::debug!("cycle_workspace: no focused screen");
+        return None;
     };
 
-    let Some(screen_id) = focus.focused_screen_id else {
-        tracing::debug!("cycle_workspace: no focused screen");
-        return;
+    let mut screen_workspaces = state.get_workspaces_for_screen(screen_id);
+    screen_workspaces.retain(|ws| !ws.is_scratchpad);
+    if screen_workspaces.len() <= 1 {
+        tracing::debug!("cycle_workspace: only one workspace on screen");
+        return None;
+    }
+
+    let current_idx = screen_workspaces.iter().position(|ws| ws.is_visible).unwrap_or(0);
+    let next_idx = direction.step(current_idx, screen_workspaces.len());
+    let name = screen_workspaces[next_idx].name.clone();
+
+    tracing::debug!("Cycling to workspace '{name}' ({direction:?})");
+
+    // Switching takes care of visibility, focus history and events
+    on_switch_workspace(state, &name);
+
+    Some(name)
+}
+
+/// Switch to the previously visible workspace on the focused screen.
+///
+/// Returns the name of the workspace switched to, or `None` if there is no
+/// focused screen or no previous workspace on it.
+pub fn on_focus_last_workspace(state: &mut TilingState) -> Option<String> {
+    let Some(screen_id) = state.get_focus_state().focused_screen_id else {
+        tracing::debug!("focus_last_workspace: no focused screen");
+        return None;
     };
 
-    // Capture previous workspace name for event emission
-    let previous_workspace_name = state.get_workspace(current_workspace_id).map(|ws| ws.name);
+    let Some(workspace) = state.get_previous_workspace(screen_id) else {
+        tracing::debug!("focus_last_workspace: no previous workspace on screen {screen_id}");
+        return None;
+    };
 
-    // Record focus history for the workspace we're leaving
-    if let Some(current_window_id) = focus.focused_window_id {
-        state.record_focus_history(current_workspace_id, current_window_id);
-        tracing::debug!(
-            "Recorded focus history: workspace {current_workspace_id} -> window {current_window_id}"
-        );
-    }
+    on_switch_workspace(state, &workspace.name);
 
-    // Get all workspaces on this screen
-    let screen_workspaces: Vec<Uuid> = state
-        .workspaces
-        .iter()
-        .filter(|ws| ws.screen_id == screen_id)
-        .map(|ws| ws.id)
-        .collect();
+    Some(workspace.name)
+}
 
-    if screen_workspaces.len() <= 1 {
-        tracing::debug!("cycle_workspace: only one workspace on screen");
-        return;
+// ============================================================================
+// Workspace Creation and Renaming
+// ============================================================================
+
+/// Create a workspace on the focused screen.
+///
+/// The workspace uses `tiling.defaultLayout` and stays hidden until switched
+/// to. Runtime workspaces are not written back to the config and only last
+/// for the session.
+pub fn on_create_workspace(
+    state: &mut TilingState,
+    name: &str,
+) -> CommandOutcome<WorkspaceEditOutcome> {
+    let name = name.trim();
+    if let Err(reason) = validate_workspace_name(state, name) {
+        return Err(CommandFailed::new(reason));
     }
 
-    // Find current position
-    let current_idx =
-        screen_workspaces.iter().position(|&id| id == current_workspace_id).unwrap_or(0);
+    let Some(screen_id) = state
+        .get_focus_state()
+        .focused_screen_id
+        .or_else(|| state.get_main_screen().map(|s| s.id))
+    else {
+        return Err(CommandFailed::new("No screen to create the workspace on"));
+    };
+
+    let mut workspace = Workspace::new(name);
+    workspace.screen_id = screen_id;
+    workspace.layout = screen_default_layout(state, screen_id);
+    state.upsert_workspace(workspace);
 
-    // Calculate next index
-    let next_idx = match direction {
-        CycleDirection::Next => (current_idx + 1) % screen_workspaces.len(),
-        CycleDirection::Previous => {
-            if current_idx == 0 {
-                screen_workspaces.len() - 1
-            } else {
-                current_idx - 1
-            }
-        }
+    tracing::debug!("Created workspace '{name}' on screen {screen_id}");
+
+    Ok(WorkspaceEditOutcome::Created {
+        workspace: name.to_string(),
+        screen: screen_name(state, screen_id),
+    })
+}
+
+/// Rename a workspace, or the focused workspace when `workspace` is `None`.
+///
+/// Windows, focus state and focus history refer to workspaces by ID, so only
+/// the workspace itself changes. The new name is not written back to the
+/// config, and window rules keep targeting the configured name.
+pub fn on_rename_workspace(
+    state: &mut TilingState,
+    workspace: Option<&str>,
+    new_name: &str,
+) -> CommandOutcome<WorkspaceEditOutcome> {
+    let target = match workspace {
+        Some(name) => state.get_workspace_by_name(name),
+        None => state.get_focused_workspace(),
+    };
+    let Some(target) = target else {
+        return Err(CommandFailed::new(workspace.map_or_else(
+            || "No focused workspace".to_string(),
+            |name| format!("Workspace '{name}' not found"),
+        )));
     };
 
-    let next_workspace_id = screen_workspaces[next_idx];
+    let new_name = new_name.trim();
+    if new_name != target.name
+        && let Err(reason) = validate_workspace_name(state, new_name)
+    {
+        return Err(CommandFailed::new(reason));
+    }
 
-    // Switch to next workspace
-    // Mark current as not visible/focused
-    state.update_workspace(current_workspace_id, |ws| {
-        ws.is_visible = false;
-        ws.is_focused = false;
-    });
+    state.update_workspace(target.id, |ws| ws.name = new_name.to_string());
 
-    // Mark next as visible and focused
-    state.update_workspace(next_workspace_id, |ws| {
-        ws.is_visible = true;
-        ws.is_focused = true;
-    });
+    tracing::debug!("Renamed workspace '{}' to '{new_name}'", target.name);
 
-    // Update focus state
-    state.update_focus(|focus| {
-        focus.focused_workspace_id = Some(next_workspace_id);
-    });
+    // Let the frontend pick up the new name of the workspace it shows as focused
+    if target.is_focused {
+        crate::modules::tiling::init::emit_workspace_changed(
+            new_name,
+            &screen_name(state, target.screen_id),
+            Some(&target.name),
+        );
+    }
 
-    tracing::debug!("Cycled to workspace {next_workspace_id} ({direction:?})");
+    Ok(WorkspaceEditOutcome::Renamed {
+        from: target.name,
+        to: new_name.to_string(),
+    })
+}
 
-    // Focus a window in the new workspace, preferring focus history
-    if let Some(ws) = state.get_workspace(next_workspace_id) {
-        // Check focus history first - prefer the last focused window in this workspace
-        let target_window_id = state
-            .get_focus_history(next_workspace_id)
-            .filter(|&id| ws.window_ids.contains(&id))
-            .or_else(|| ws.window_ids.first().copied());
+/// Claim the focused window's app for the focused workspace, or release it.
+///
+/// New windows of a claimed app open on the claiming workspace, ahead of the
+/// configured rules. An app is claimed by at most one workspace, so claiming
+/// it moves the claim. Claims last for the session and are not written to the
+/// config.
+pub fn on_claim_app(state: &mut TilingState, claim: bool) -> CommandOutcome<ClaimAppOutcome> {
+    let Some(window) = state.get_focused_window().filter(|w| !w.app_id.is_empty()) else {
+        return Err(CommandFailed::new("No focused window with a bundle ID"));
+    };
+    let Some(workspace) = state.get_focused_workspace() else {
+        return Err(CommandFailed::new("No focused workspace"));
+    };
 
-        if let Some(window_id) = target_window_id {
-            tracing::debug!(
-                "Focusing window {} in workspace {} (from {})",
-                window_id,
-                next_workspace_id,
-                if state.get_focus_history(next_workspace_id).is_some_and(|id| id == window_id) {
-                    "focus history"
-                } else {
-                    "first window"
-                }
-            );
-            // Update focus state to track the new focused window
-            state.update_focus(|focus| {
-                focus.focused_window_id = Some(window_id);
-            });
-            // Use the window_ops to focus the window via AX API
-            let _ = crate::modules::tiling::effects::window_ops::focus_window(window_id);
-        } else {
-            // No windows in workspace - clear focused window
-            state.update_focus(|focus| {
-                focus.focused_window_id = None;
-            });
+    let app_id = window.app_id;
+    let is_claimed =
+        |ws: &Workspace| ws.claimed_app_ids.iter().any(|id| id.eq_ignore_ascii_case(&app_id));
+
+    if !claim {
+        if !is_claimed(&workspace) {
+            return Err(CommandFailed::new(format!(
+                "Workspace '{}' does not claim {app_id}",
+                workspace.name
+            )));
         }
+        state.update_workspace(workspace.id, |ws| {
+            ws.claimed_app_ids.retain(|id| !id.eq_ignore_ascii_case(&app_id));
+        });
+
+        tracing::debug!("Workspace '{}' released {app_id}", workspace.name);
+        return Ok(ClaimAppOutcome::Unclaimed {
+            workspace: workspace.name,
+            app_id,
+            app_name: window.app_name,
+        });
     }
 
-    // Notify subscriber about visibility and layout changes
-    if let Some(handle) = get_subscriber_handle() {
-        handle.notify_visibility_changed(current_workspace_id, false);
-        handle.notify_visibility_changed(next_workspace_id, true);
-        handle.notify_layout_changed(next_workspace_id, true);
-        // Notify about focus change to update borders
-        handle.notify_focus_changed();
+    // An app can only be claimed by one workspace
+    for other_id in state.get_all_workspace_ids() {
+        if other_id != workspace.id {
+            state.update_workspace(other_id, |ws| {
+                ws.claimed_app_ids.retain(|id| !id.eq_ignore_ascii_case(&app_id));
+            });
+        }
+    }
+    if !is_claimed(&workspace) {
+        state.update_workspace(workspace.id, |ws| ws.claimed_app_ids.push(app_id.clone()));
     }
 
-    // Emit workspace changed event to frontend
-    if let Some(next_ws) = state.get_workspace(next_workspace_id) {
-        let screen_name = state
-            .get_screen(screen_id)
-            .map_or_else(|| format!("screen-{screen_id}"), |s| s.name);
+    tracing::debug!("Workspace '{}' claimed {app_id}", workspace.name);
+    Ok(ClaimAppOutcome::Claimed {
+        workspace: workspace.name,
+        app_id,
+        app_name: window.app_name,
+    })
+}
 
-        crate::modules::tiling::init::emit_workspace_changed(
-            &next_ws.name,
-            &screen_name,
-            previous_workspace_name.as_deref(),
-        );
+/// Checks that `name` can be used for a new or renamed workspace.
+fn validate_workspace_name(state: &TilingState, name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Workspace name cannot be empty".to_string());
+    }
+    if state.get_workspace_by_name(name).is_some() {
+        return Err(format!("Workspace '{name}' already exists"));
     }
+    Ok(())
+}
+
+/// Returns the name of a screen, falling back to `screen-<id>`.
+fn screen_name(state: &TilingState, screen_id: u32) -> String {
+    state
+        .get_screen(screen_id)
+        .map_or_else(|| format!("screen-{screen_id}"), |s| s.name)
 }
 
 // ============================================================================
@@ -307,10 +424,7 @@ pub fn on_balance_workspace(state: &mut TilingState, workspace_id: Uuid) {
         .map(|ws| ws.window_ids.to_vec())
         .unwrap_or_default();
 
-    // Clear split ratios
-    state.update_workspace(workspace_id, |ws| {
-        ws.split_ratios.clear();
-    });
+    reset_split_ratios(state, workspace_id);
 
     // Clear inferred minimum sizes for all windows in the workspace
     // This resets the layout calculation to use only reported minimums
@@ -328,6 +442,130 @@ pub fn on_balance_workspace(state: &mut TilingState, workspace_id: Uuid) {
     }
 }
 
+/// Evens out the splits of a workspace whose tiled window count changed.
+///
+/// Does nothing unless `tiling.autoBalance` is enabled, so resized splits are
+/// kept by default. Must run before the layout is recomputed for the change.
+pub fn auto_balance_workspace(state: &mut TilingState, workspace_id: Uuid) {
+    if get_config().tiling.auto_balance {
+        tracing::debug!("Auto-balancing workspace {workspace_id}");
+        reset_split_ratios(state, workspace_id);
+    }
+}
+
+/// Resets the split ratios of a workspace to an even distribution.
+fn reset_split_ratios(state: &mut TilingState, workspace_id: Uuid) {
+    state.update_workspace(workspace_id, |ws| {
+        ws.split_ratios.clear();
+    });
+}
+
+// ============================================================================
+// Merge Workspaces
+// ============================================================================
+
+/// Move every window of the focused workspace into the named workspace.
+///
+/// The windows keep their order and are appended after the target's windows.
+/// Floating windows are centered on the target's screen when it differs;
+/// tiled windows are placed by the target's layout. The source workspace is
+/// kept, empty. With `follow`, switches to the target afterwards.
+pub fn on_merge_workspace(
+    state: &mut TilingState,
+    target: &str,
+    follow: bool,
+) -> CommandOutcome<MergeWorkspaceOutcome> {
+    let Some(source) = state.get_focused_workspace() else {
+        return Err(CommandFailed::new("No focused workspace"));
+    };
+
+    let Some(target_ws) = state.get_workspace_by_name(target) else {
+        return Err(CommandFailed::new(format!("Workspace '{target}' not found")));
+    };
+
+    if target_ws.id == source.id {
+        return Err(CommandFailed::new(format!(
+            "Workspace '{target}' is the focused workspace"
+        )));
+    }
+
+    if target_ws.is_scratchpad {
+        return Err(CommandFailed::new(format!(
+            "Workspace '{target}' is the scratchpad"
+        )));
+    }
+
+    let window_ids = source.window_ids.clone();
+
+    state.update_workspace(source.id, |ws| {
+        ws.window_ids.clear();
+        ws.focused_window_index = None;
+    });
+    state.update_workspace(target_ws.id, |ws| {
+        ws.window_ids.extend(window_ids.iter().copied());
+    });
+
+    let target_frame = (target_ws.screen_id != source.screen_id)
+        .then(|| state.get_screen(target_ws.screen_id))
+        .flatten()
+        .map(|screen| screen.visible_frame);
+
+    for &window_id in &window_ids {
+        let Some(window) = state.get_window(window_id) else {
+            continue;
+        };
+
+        let frame = target_frame
+            .filter(|_| window.is_effectively_floating())
+            .map(|screen_frame| center_frame(&window.frame, &screen_frame));
+        state.update_window(window_id, |w| {
+            w.workspace_id = target_ws.id;
+            if let Some(frame) = frame {
+                w.frame = frame;
+            }
+        });
+        if let Some(frame) = frame {
+            let _ = window_ops::set_window_frame(window_id, &frame);
+        }
+    }
+
+    tracing::debug!(
+        "Merged {} window(s) from workspace '{}' into '{target}'",
+        window_ids.len(),
+        source.name
+    );
+
+    auto_balance_workspace(state, source.id);
+    auto_balance_workspace(state, target_ws.id);
+
+    if follow {
+        if let Some(window_id) = state.get_focus_state().focused_window_id
+            && window_ids.contains(&window_id)
+        {
+            // Switching focuses the workspace's last focused window, so make it this one
+            state.record_focus_history(target_ws.id, window_id);
+        }
+        on_switch_workspace(state, &target_ws.name);
+    } else {
+        state.set_focused_window(None);
+        if !target_ws.is_visible {
+            sync_window_visibility_for_workspaces(state, &[], &[target_ws.id]);
+        }
+    }
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(source.id, true);
+        handle.notify_layout_changed(target_ws.id, true);
+    }
+
+    Ok(MergeWorkspaceOutcome::Merged {
+        source: source.name,
+        target: target_ws.name,
+        moved: window_ids.len(),
+        visible: follow || target_ws.is_visible,
+    })
+}
+
 // ============================================================================
 // Send Workspace to Screen
 // ============================================================================
@@ -431,14 +669,18 @@ pub fn on_send_workspace_to_screen(state: &mut TilingState, target_screen: &Targ
         &workspaces_becoming_visible,
         &workspaces_becoming_hidden,
     );
+    show_workspace_wallpapers(state, &workspaces_becoming_visible);
 
-    // Notify subscriber about visibility and layout changes
+    // Notify subscriber about visibility and layout changes. The moved
+    // workspace is laid out again within the target screen's frame.
     if let Some(handle) = get_subscriber_handle() {
         for ws_id in &workspaces_becoming_hidden {
             handle.notify_visibility_changed(*ws_id, false);
         }
-        handle.notify_visibility_changed(workspace_id, true);
-        handle.notify_layout_changed(workspace_id, true);
+        for ws_id in &workspaces_becoming_visible {
+            handle.notify_visibility_changed(*ws_id, true);
+            handle.notify_layout_changed(*ws_id, true);
+        }
     }
 }
 
@@ -467,7 +709,7 @@ pub fn resolve_screen(state: &TilingState, target: &TargetScreen) -> Option<u32>
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::tiling::state::{Screen, Workspace};
+    use crate::modules::tiling::state::{Screen, Window, Workspace};
 
     fn create_test_state() -> TilingState {
         let mut state = TilingState::new();
@@ -533,6 +775,254 @@ mod tests {
         assert!(ws1.is_focused);
     }
 
+    #[test]
+    fn test_focus_last_workspace() {
+        let mut state = create_test_state();
+        assert_eq!(on_focus_last_workspace(&mut state), None);
+
+        on_switch_workspace(&mut state, "workspace2");
+        assert_eq!(
+            on_focus_last_workspace(&mut state).as_deref(),
+            Some("workspace1")
+        );
+        assert!(state.get_workspace_by_name("workspace1").unwrap().is_focused);
+
+        // Going back again returns to where we came from
+        assert_eq!(
+            on_focus_last_workspace(&mut state).as_deref(),
+            Some("workspace2")
+        );
+        assert!(state.get_workspace_by_name("workspace2").unwrap().is_focused);
+    }
+
+    #[test]
+    fn test_create_workspace() {
+        let mut state = create_test_state();
+
+        let outcome = on_create_workspace(&mut state, " scratch ");
+        assert_eq!(
+            outcome,
+            Ok(WorkspaceEditOutcome::Created {
+                workspace: "scratch".to_string(),
+                screen: "Test Screen".to_string(),
+            })
+        );
+
+        let ws = state.get_workspace_by_name("scratch").unwrap();
+        assert_eq!(ws.screen_id, 1);
+        assert_eq!(ws.layout, screen_default_layout(&state, 1));
+        assert!(!ws.is_visible);
+        assert!(!ws.is_focused);
+        assert_eq!(state.workspaces.len(), 3);
+    }
+
+    #[test]
+    fn test_create_workspace_rejects_invalid_names() {
+        let mut state = create_test_state();
+
+        assert!(matches!(
+            on_create_workspace(&mut state, "workspace1"),
+            Err(CommandFailed { .. })
+        ));
+        assert!(matches!(
+            on_create_workspace(&mut state, "  "),
+            Err(CommandFailed { .. })
+        ));
+        assert_eq!(state.workspaces.len(), 2);
+    }
+
+    #[test]
+    fn test_rename_focused_workspace() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        let outcome = on_rename_workspace(&mut state, None, "code");
+        assert_eq!(
+            outcome,
+            Ok(WorkspaceEditOutcome::Renamed {
+                from: "workspace1".to_string(),
+                to: "code".to_string(),
+            })
+        );
+
+        let ws = state.get_workspace_by_name("code").unwrap();
+        assert_eq!(ws.id, ws1_id);
+        assert!(ws.is_focused);
+        assert!(state.get_workspace_by_name("workspace1").is_none());
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(ws1_id));
+    }
+
+    #[test]
+    fn test_rename_named_workspace() {
+        let mut state = create_test_state();
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+
+        on_rename_workspace(&mut state, Some("workspace2"), "chat").unwrap();
+        assert_eq!(state.get_workspace_by_name("chat").unwrap().id, ws2_id);
+
+        // Switching by the new name works
+        on_switch_workspace(&mut state, "chat");
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(ws2_id));
+    }
+
+    #[test]
+    fn test_rename_workspace_rejects_invalid_names() {
+        let mut state = create_test_state();
+
+        assert!(matches!(
+            on_rename_workspace(&mut state, None, "workspace2"),
+            Err(CommandFailed { .. })
+        ));
+        assert!(matches!(
+            on_rename_workspace(&mut state, None, ""),
+            Err(CommandFailed { .. })
+        ));
+        assert!(matches!(
+            on_rename_workspace(&mut state, Some("missing"), "other"),
+            Err(CommandFailed { .. })
+        ));
+        assert!(state.get_workspace_by_name("workspace1").is_some());
+        assert!(state.get_workspace_by_name("workspace2").is_some());
+    }
+
+    fn add_windows(state: &mut TilingState, workspace: &str, window_ids: &[u32]) {
+        let workspace_id = state.get_workspace_by_name(workspace).unwrap().id;
+        for &id in window_ids {
+            state.upsert_window(Window {
+                id,
+                workspace_id,
+                ..Default::default()
+            });
+            state.update_workspace(workspace_id, |ws| ws.window_ids.push(id));
+        }
+    }
+
+    #[test]
+    fn test_merge_workspace() {
+        let mut state = create_test_state();
+        add_windows(&mut state, "workspace1", &[100, 101]);
+        add_windows(&mut state, "workspace2", &[200]);
+        state.set_focused_window(Some(101));
+
+        let outcome = on_merge_workspace(&mut state, "workspace2", false);
+        assert_eq!(
+            outcome,
+            Ok(MergeWorkspaceOutcome::Merged {
+                source: "workspace1".to_string(),
+                target: "workspace2".to_string(),
+                moved: 2,
+                visible: false,
+            })
+        );
+
+        let ws1 = state.get_workspace_by_name("workspace1").unwrap();
+        let ws2 = state.get_workspace_by_name("workspace2").unwrap();
+        assert!(ws1.window_ids.is_empty());
+        assert_eq!(ws2.window_ids.as_slice(), &[200, 100, 101]);
+        assert_eq!(state.get_window(100).unwrap().workspace_id, ws2.id);
+        assert_eq!(state.get_window(101).unwrap().workspace_id, ws2.id);
+
+        // The source stays focused, without a window
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_workspace_id, Some(ws1.id));
+        assert_eq!(focus.focused_window_id, None);
+    }
+
+    #[test]
+    fn test_merge_workspace_follow() {
+        let mut state = create_test_state();
+        add_windows(&mut state, "workspace1", &[100, 101]);
+        state.set_focused_window(Some(100));
+
+        let outcome = on_merge_workspace(&mut state, "workspace2", true);
+        assert!(matches!(
+            outcome,
+            Ok(MergeWorkspaceOutcome::Merged { moved: 2, visible: true, .. })
+        ));
+
+        let ws2 = state.get_workspace_by_name("workspace2").unwrap();
+        assert!(ws2.is_visible);
+        assert!(ws2.is_focused);
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(ws2.id));
+    }
+
+    #[test]
+    fn test_merge_workspace_failures() {
+        let mut state = create_test_state();
+        assert!(matches!(
+            on_merge_workspace(&mut state, "missing", false),
+            Err(CommandFailed { .. })
+        ));
+        assert!(matches!(
+            on_merge_workspace(&mut state, "workspace1", false),
+            Err(CommandFailed { .. })
+        ));
+    }
+
+    fn focus_app_window(state: &mut TilingState, app_id: &str) {
+        let workspace_id = state.get_focus_state().focused_workspace_id.unwrap();
+        state.upsert_window(Window {
+            id: 100,
+            app_id: app_id.to_string(),
+            app_name: "Safari".to_string(),
+            workspace_id,
+            ..Default::default()
+        });
+        state.set_focused_window(Some(100));
+    }
+
+    #[test]
+    fn test_claim_app() {
+        let mut state = create_test_state();
+        focus_app_window(&mut state, "com.apple.Safari");
+
+        let outcome = on_claim_app(&mut state, true);
+        assert_eq!(
+            outcome,
+            Ok(ClaimAppOutcome::Claimed {
+                workspace: "workspace1".to_string(),
+                app_id: "com.apple.Safari".to_string(),
+                app_name: "Safari".to_string(),
+            })
+        );
+
+        // Claiming again doesn't duplicate the claim
+        on_claim_app(&mut state, true).unwrap();
+        let ws1 = state.get_workspace_by_name("workspace1").unwrap();
+        assert_eq!(ws1.claimed_app_ids, ["com.apple.Safari"]);
+
+        assert!(matches!(
+            on_claim_app(&mut state, false),
+            Ok(ClaimAppOutcome::Unclaimed { .. })
+        ));
+        let ws1 = state.get_workspace_by_name("workspace1").unwrap();
+        assert!(ws1.claimed_app_ids.is_empty());
+        assert!(matches!(
+            on_claim_app(&mut state, false),
+            Err(CommandFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_claim_app_moves_claim() {
+        let mut state = create_test_state();
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+        state.update_workspace(ws2_id, |ws| ws.claimed_app_ids.push("com.apple.safari".into()));
+        focus_app_window(&mut state, "com.apple.Safari");
+
+        on_claim_app(&mut state, true).unwrap();
+        assert!(state.get_workspace(ws2_id).unwrap().claimed_app_ids.is_empty());
+    }
+
+    #[test]
+    fn test_claim_app_requires_focused_window() {
+        let mut state = create_test_state();
+        assert!(matches!(
+            on_claim_app(&mut state, true),
+            Err(CommandFailed { .. })
+        ));
+    }
+
     #[test]
     fn test_cycle_workspace() {
         let mut state = create_test_state();
@@ -557,4 +1047,131 @@ mod tests {
         let focus = state.get_focus_state();
         assert_eq!(focus.focused_workspace_id, Some(ws2_id));
     }
+
+    #[test]
+    fn test_cycle_workspace_returns_name() {
+        let mut state = create_test_state();
+
+        let name = on_cycle_workspace(&mut state, CycleDirection::Previous);
+        assert_eq!(name.as_deref(), Some("workspace2"));
+
+        let ws1 = state.get_workspace_by_name("workspace1").unwrap();
+        let ws2 = state.get_workspace_by_name("workspace2").unwrap();
+        assert!(!ws1.is_visible);
+        assert!(ws2.is_visible && ws2.is_focused);
+    }
+
+    #[test]
+    fn test_cycle_workspace_skips_other_screens() {
+        let mut state = create_test_state();
+        state.upsert_screen(Screen {
+            id: 2,
+            name: "Other Screen".to_string(),
+            ..Default::default()
+        });
+
+        let mut other = Workspace::new("other");
+        other.screen_id = 2;
+        other.is_visible = true;
+        state.upsert_workspace(other);
+
+        let mut ws3 = Workspace::new("workspace3");
+        ws3.screen_id = 1;
+        state.upsert_workspace(ws3);
+
+        assert_eq!(
+            on_cycle_workspace(&mut state, CycleDirection::Next).as_deref(),
+            Some("workspace2")
+        );
+        assert_eq!(
+            on_cycle_workspace(&mut state, CycleDirection::Next).as_deref(),
+            Some("workspace3")
+        );
+        assert_eq!(
+            on_cycle_workspace(&mut state, CycleDirection::Next).as_deref(),
+            Some("workspace1")
+        );
+
+        // The other screen keeps its workspace
+        assert!(state.get_workspace_by_name("other").unwrap().is_visible);
+    }
+
+    #[test]
+    fn test_cycle_workspace_single_workspace() {
+        let mut state = create_test_state();
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+        state.update_workspace(ws2_id, |ws| ws.screen_id = 2);
+
+        assert_eq!(on_cycle_workspace(&mut state, CycleDirection::Next), None);
+        assert!(state.get_workspace_by_name("workspace1").unwrap().is_focused);
+    }
+
+    #[test]
+    fn test_balance_workspace_resets_split_ratios() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+        for id in [ws1_id, ws2_id] {
+            state.update_workspace(id, |ws| ws.split_ratios = vec![0.3, 0.7]);
+        }
+
+        on_balance_workspace(&mut state, ws1_id);
+
+        // Only the balanced workspace is affected
+        assert!(state.get_workspace(ws1_id).unwrap().split_ratios.is_empty());
+        assert_eq!(state.get_workspace(ws2_id).unwrap().split_ratios, vec![0.3, 0.7]);
+    }
+
+    #[test]
+    fn test_auto_balance_workspace_disabled_by_default() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_workspace(ws1_id, |ws| ws.split_ratios = vec![0.3, 0.7]);
+
+        // Resized splits are kept unless `tiling.autoBalance` is enabled
+        auto_balance_workspace(&mut state, ws1_id);
+        assert_eq!(state.get_workspace(ws1_id).unwrap().split_ratios, vec![0.3, 0.7]);
+    }
+
+    #[test]
+    fn test_send_workspace_to_screen() {
+        let mut state = create_test_state();
+        state.upsert_screen(Screen {
+            id: 2,
+            name: "Other Screen".to_string(),
+            ..Default::default()
+        });
+
+        let mut other = Workspace::new("other");
+        other.screen_id = 2;
+        other.is_visible = true;
+        state.upsert_workspace(other);
+
+        on_send_workspace_to_screen(&mut state, &TargetScreen::Secondary);
+
+        // The moved workspace replaces the visible one on the target screen
+        let ws1 = state.get_workspace_by_name("workspace1").unwrap();
+        assert_eq!(ws1.screen_id, 2);
+        assert!(ws1.is_visible);
+        assert!(ws1.is_focused);
+        assert!(!state.get_workspace_by_name("other").unwrap().is_visible);
+
+        // Another workspace takes its place on the source screen
+        assert!(state.get_workspace_by_name("workspace2").unwrap().is_visible);
+
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_workspace_id, Some(ws1.id));
+        assert_eq!(focus.focused_screen_id, Some(2));
+    }
+
+    #[test]
+    fn test_send_workspace_to_unknown_screen() {
+        let mut state = create_test_state();
+
+        on_send_workspace_to_screen(&mut state, &TargetScreen::Named("Missing".to_string()));
+
+        let ws1 = state.get_workspace_by_name("workspace1").unwrap();
+        assert_eq!(ws1.screen_id, 1);
+        assert!(ws1.is_visible);
+    }
 }
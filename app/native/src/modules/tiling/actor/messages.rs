@@ -8,6 +8,7 @@
 use tokio::sync::oneshot;
 use uuid::Uuid;
 
+use crate::modules::tiling::layout::DwindleNode;
 use crate::modules::tiling::state::{FocusState, LayoutType, Rect, Screen, Window, Workspace};
 
 // ============================================================================
@@ -44,6 +45,9 @@ pub enum StateMessage {
     /// Window title changed.
     WindowTitleChanged { window_id: u32, title: String },
 
+    /// Window title stopped changing; workspace title rules are re-applied.
+    WindowTitleSettled { window_id: u32 },
+
     /// Window fullscreen state changed.
     WindowFullscreenChanged { window_id: u32, fullscreen: bool },
 
@@ -88,8 +92,50 @@ pub enum StateMessage {
     /// Switch to workspace by name.
     SwitchWorkspace { name: String },
 
-    /// Switch to next/previous workspace.
-    CycleWorkspace { direction: CycleDirection },
+    /// Switch to next/previous workspace on the focused screen.
+    CycleWorkspace {
+        direction: CycleDirection,
+        /// Optional channel to report the workspace switched to.
+        respond_to: Option<oneshot::Sender<Option<String>>>,
+    },
+
+    /// Switch to the previously visible workspace on the focused screen.
+    FocusLastWorkspace {
+        /// Optional channel to report the workspace switched to.
+        respond_to: Option<oneshot::Sender<Option<String>>>,
+    },
+
+    /// Create a workspace on the focused screen.
+    CreateWorkspace {
+        name: String,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<WorkspaceEditOutcome>>>,
+    },
+
+    /// Rename a workspace (the focused one when `workspace` is `None`).
+    RenameWorkspace {
+        workspace: Option<String>,
+        name: String,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<WorkspaceEditOutcome>>>,
+    },
+
+    /// Move every window of the focused workspace into a workspace by name.
+    MergeWorkspace {
+        target: String,
+        /// Switch to the target workspace afterwards.
+        follow: bool,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<MergeWorkspaceOutcome>>>,
+    },
+
+    /// Claim the focused window's app for the focused workspace, or release
+    /// the claim when `claim` is false.
+    ClaimApp {
+        claim: bool,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<ClaimAppOutcome>>>,
+    },
 
     /// Change workspace layout.
     SetLayout {
@@ -100,9 +146,27 @@ pub enum StateMessage {
     /// Cycle through layouts.
     CycleLayout { workspace_id: Uuid },
 
+    /// Copy the layout and split ratios of a workspace by name to the focused
+    /// workspace, or exchange them between the two when `swap` is set.
+    CopyLayout {
+        source: String,
+        swap: bool,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<CopyLayoutOutcome>>>,
+    },
+
     /// Move window to different workspace.
     MoveWindowToWorkspace { window_id: u32, workspace_id: Uuid },
 
+    /// Send the focused window to a workspace by name.
+    SendWindowToWorkspace {
+        workspace: String,
+        /// Switch to the workspace afterwards.
+        follow: bool,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<SendWindowOutcome>>>,
+    },
+
     /// Swap two windows.
     SwapWindows { window_id_a: u32, window_id_b: u32 },
 
@@ -112,12 +176,59 @@ pub enum StateMessage {
     /// Focus window in a direction (spatial or cycle).
     FocusWindow { direction: FocusDirection },
 
+    /// Focus the previously focused window.
+    FocusLastWindow,
+
+    /// Focus the window at a 1-based position in the focused workspace.
+    FocusWindowAt {
+        index: usize,
+        /// Wrap positions past the last window around instead of failing.
+        wraps: bool,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<FocusIndexOutcome>>>,
+    },
+
     /// Swap focused window with another in a direction.
     SwapWindowInDirection { direction: FocusDirection },
 
+    /// Move the focused window to the first position of its workspace,
+    /// making it the master window in the master layout.
+    PromoteWindow,
+
+    /// Move the focused floating window by `step` pixels in a direction,
+    /// or swap a tiled window in that direction.
+    /// Uses `floating.moveStep` when `step` is None.
+    MoveWindowInDirection {
+        direction: FocusDirection,
+        step: Option<u32>,
+    },
+
     /// Toggle window floating state.
     ToggleFloating { window_id: u32 },
 
+    /// Change the sticky state of the focused window.
+    SetSticky { action: ToggleAction },
+
+    /// Change whether the focused window is kept above tiled windows.
+    SetAlwaysOnTop { action: ToggleAction },
+
+    /// Change the floating state of the focused window.
+    SetFloating { action: ToggleAction },
+
+    /// Minimize the focused window, or restore a minimized window of the
+    /// focused workspace.
+    SetMinimized {
+        minimized: bool,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<MinimizeOutcome>>>,
+    },
+
+    /// Move the focused window to the scratchpad.
+    MoveWindowToScratchpad,
+
+    /// Show or hide the scratchpad's windows.
+    ToggleScratchpad,
+
     /// Resize split ratio.
     ResizeSplit {
         workspace_id: Uuid,
@@ -138,11 +249,49 @@ pub enum StateMessage {
     ResizeFocusedWindow {
         dimension: ResizeDimension,
         amount: i32,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<ResizeOutcome>>>,
+    },
+
+    /// Set the split ratio at `index` in the focused workspace.
+    /// In the master layout, index 0 is the master ratio.
+    SetSplitRatio {
+        index: usize,
+        ratio: f64,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<ResizeOutcome>>>,
     },
 
     /// Apply a floating preset to the focused window.
     ApplyPreset { preset: String },
 
+    /// Center or maximize the focused floating window.
+    PlaceFloatingWindow {
+        placement: FloatingPlacement,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<PlaceWindowOutcome>>>,
+    },
+
+    /// Cascade or tile the floating windows of the focused workspace.
+    ArrangeFloatingWindows {
+        arrangement: FloatingArrangement,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<ArrangeFloatingOutcome>>>,
+    },
+
+    /// Change the number of master windows in the focused workspace.
+    AdjustMasterCount { change: MasterCountChange },
+
+    /// Scroll the focused scrolling workspace by one column.
+    ScrollWorkspace { direction: ScrollDirection },
+
+    /// Switch the gaps of all workspaces to a `tiling.gapPresets` entry.
+    SetGapPreset {
+        change: GapPresetChange,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<CommandOutcome<GapPresetOutcome>>>,
+    },
+
     /// Enable/disable tiling.
     SetEnabled { enabled: bool },
 
@@ -165,6 +314,17 @@ pub enum StateMessage {
     /// Used during startup to track all existing windows before applying layouts.
     BatchWindowsCreated(Vec<WindowCreatedInfo>),
 
+    /// Reconcile tracked windows with a fresh enumeration and re-apply all layouts.
+    ///
+    /// `window_ids` lists every window on the system; tracked windows missing from
+    /// it are untracked. `windows` are the trackable ones, tracked if they aren't yet.
+    Retile {
+        windows: Vec<WindowCreatedInfo>,
+        window_ids: Vec<u32>,
+        /// Optional channel to report the outcome back to the caller.
+        respond_to: Option<oneshot::Sender<RetileOutcome>>,
+    },
+
     // ════════════════════════════════════════════════════════════════════════
     // User-Initiated Drag Operations
     // ════════════════════════════════════════════════════════════════════════
@@ -211,6 +371,7 @@ impl StateMessage {
             Self::WindowResized { .. } => "WindowResized",
             Self::WindowMinimized { .. } => "WindowMinimized",
             Self::WindowTitleChanged { .. } => "WindowTitleChanged",
+            Self::WindowTitleSettled { .. } => "WindowTitleSettled",
             Self::WindowFullscreenChanged { .. } => "WindowFullscreenChanged",
 
             // App Events
@@ -227,20 +388,43 @@ impl StateMessage {
             // User Commands
             Self::SwitchWorkspace { .. } => "SwitchWorkspace",
             Self::CycleWorkspace { .. } => "CycleWorkspace",
+            Self::FocusLastWorkspace { .. } => "FocusLastWorkspace",
+            Self::CreateWorkspace { .. } => "CreateWorkspace",
+            Self::RenameWorkspace { .. } => "RenameWorkspace",
+            Self::MergeWorkspace { .. } => "MergeWorkspace",
+            Self::ClaimApp { .. } => "ClaimApp",
             Self::SetLayout { .. } => "SetLayout",
             Self::CycleLayout { .. } => "CycleLayout",
+            Self::CopyLayout { .. } => "CopyLayout",
             Self::MoveWindowToWorkspace { .. } => "MoveWindowToWorkspace",
+            Self::SendWindowToWorkspace { .. } => "SendWindowToWorkspace",
             Self::SwapWindows { .. } => "SwapWindows",
             Self::CycleFocus { .. } => "CycleFocus",
             Self::FocusWindow { .. } => "FocusWindow",
+            Self::FocusLastWindow => "FocusLastWindow",
+            Self::FocusWindowAt { .. } => "FocusWindowAt",
             Self::SwapWindowInDirection { .. } => "SwapWindowInDirection",
+            Self::PromoteWindow => "PromoteWindow",
+            Self::MoveWindowInDirection { .. } => "MoveWindowInDirection",
             Self::ToggleFloating { .. } => "ToggleFloating",
+            Self::SetSticky { .. } => "SetSticky",
+            Self::SetAlwaysOnTop { .. } => "SetAlwaysOnTop",
+            Self::SetFloating { .. } => "SetFloating",
+            Self::SetMinimized { .. } => "SetMinimized",
+            Self::MoveWindowToScratchpad => "MoveWindowToScratchpad",
+            Self::ToggleScratchpad => "ToggleScratchpad",
             Self::ResizeSplit { .. } => "ResizeSplit",
             Self::BalanceWorkspace { .. } => "BalanceWorkspace",
             Self::SendWindowToScreen { .. } => "SendWindowToScreen",
             Self::SendWorkspaceToScreen { .. } => "SendWorkspaceToScreen",
             Self::ResizeFocusedWindow { .. } => "ResizeFocusedWindow",
             Self::ApplyPreset { .. } => "ApplyPreset",
+            Self::SetSplitRatio { .. } => "SetSplitRatio",
+            Self::PlaceFloatingWindow { .. } => "PlaceFloatingWindow",
+            Self::ArrangeFloatingWindows { .. } => "ArrangeFloatingWindows",
+            Self::AdjustMasterCount { .. } => "AdjustMasterCount",
+            Self::ScrollWorkspace { .. } => "ScrollWorkspace",
+            Self::SetGapPreset { .. } => "SetGapPreset",
             Self::SetEnabled { .. } => "SetEnabled",
 
             // Queries
@@ -249,6 +433,7 @@ impl StateMessage {
             // Batched Events
             Self::BatchedGeometryUpdates(_) => "BatchedGeometryUpdates",
             Self::BatchWindowsCreated(_) => "BatchWindowsCreated",
+            Self::Retile { .. } => "Retile",
 
             // User Drag Operations
             Self::UserResizeCompleted { .. } => "UserResizeCompleted",
@@ -266,6 +451,22 @@ impl StateMessage {
 // Supporting Types
 // ============================================================================
 
+/// What a command sent to the actor changed, or why it changed nothing.
+pub type CommandOutcome<T> = Result<T, CommandFailed>;
+
+/// Why a command sent to the actor changed nothing.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{reason}")]
+pub struct CommandFailed {
+    pub reason: String,
+}
+
+impl CommandFailed {
+    /// Creates a failure with the given reason.
+    #[must_use]
+    pub fn new(reason: impl Into<String>) -> Self { Self { reason: reason.into() } }
+}
+
 /// Information about a newly created window.
 #[derive(Debug, Clone)]
 pub struct WindowCreatedInfo {
@@ -274,6 +475,10 @@ pub struct WindowCreatedInfo {
     pub app_id: String,
     pub app_name: String,
     pub title: String,
+    /// Accessibility role, if the window reports one.
+    pub role: Option<String>,
+    /// Accessibility subrole, if the window reports one.
+    pub subrole: Option<String>,
     pub frame: Rect,
     pub is_minimized: bool,
     pub is_fullscreen: bool,
@@ -308,6 +513,33 @@ pub enum CycleDirection {
     Previous,
 }
 
+impl CycleDirection {
+    /// Parses a cycle direction string (case-insensitive).
+    ///
+    /// Valid values: "next", "prev", "previous"
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "next" => Some(Self::Next),
+            "prev" | "previous" => Some(Self::Previous),
+            _ => None,
+        }
+    }
+
+    /// Returns the index one step away from `index`, wrapping within `len`.
+    #[must_use]
+    pub const fn step(self, index: usize, len: usize) -> usize {
+        if len == 0 {
+            return 0;
+        }
+
+        match self {
+            Self::Next => (index + 1) % len,
+            Self::Previous => (index + len - 1) % len,
+        }
+    }
+}
+
 /// Direction for spatial focus/swap operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FocusDirection {
@@ -349,6 +581,310 @@ impl ResizeDimension {
     }
 }
 
+/// Outcome of resizing the focused window.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResizeOutcome {
+    /// The split ratio at `ratio_index` was adjusted to `ratio`.
+    Resized { ratio_index: usize, ratio: f64 },
+    /// The layout has no adjustable splits (monocle, tabbed, master, floating).
+    Ignored { layout: LayoutType },
+}
+
+/// Placement of a floating window within the usable area of its screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatingPlacement {
+    /// Center the window, keeping its size.
+    Center,
+    /// Resize the window to fill the usable area.
+    Maximize,
+}
+
+impl FloatingPlacement {
+    /// Parses a placement string (case-insensitive).
+    ///
+    /// Valid values: "center", "maximize"
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "center" => Some(Self::Center),
+            "maximize" => Some(Self::Maximize),
+            _ => None,
+        }
+    }
+
+    /// Returns the placement as a lowercase string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Center => "center",
+            Self::Maximize => "maximize",
+        }
+    }
+}
+
+/// Outcome of centering or maximizing the focused floating window.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlaceWindowOutcome {
+    /// The window was moved to `frame`.
+    Placed { window_id: u32, frame: Rect },
+}
+
+/// Arrangement of the floating windows of a workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatingArrangement {
+    /// Stack the windows diagonally from the top-left corner, keeping their sizes.
+    Cascade,
+    /// Tile the windows in a grid, without making them tiled.
+    Grid,
+}
+
+impl FloatingArrangement {
+    /// Parses an arrangement string (case-insensitive).
+    ///
+    /// Valid values: "cascade", "grid"
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "cascade" => Some(Self::Cascade),
+            "grid" => Some(Self::Grid),
+            _ => None,
+        }
+    }
+
+    /// Returns the arrangement as a lowercase string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Cascade => "cascade",
+            Self::Grid => "grid",
+        }
+    }
+}
+
+/// Outcome of arranging the floating windows of the focused workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrangeFloatingOutcome {
+    /// `count` floating windows were arranged.
+    Arranged { count: usize },
+}
+
+/// Outcome of focusing a window by its position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FocusIndexOutcome {
+    /// The window `window_id` at 1-based `index` was focused.
+    Focused { window_id: u32, index: usize },
+}
+
+/// Outcome of minimizing or restoring a window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MinimizeOutcome {
+    /// The window was minimized or restored.
+    Done { window_id: u32, minimized: bool },
+}
+
+/// Outcome of sending the focused window to a workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SendWindowOutcome {
+    /// The window was moved to `workspace` on `screen`. `visible` tells
+    /// whether the window is on screen afterwards.
+    Sent {
+        window_id: u32,
+        workspace: String,
+        screen: String,
+        visible: bool,
+    },
+}
+
+/// Outcome of copying or swapping a layout between workspaces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CopyLayoutOutcome {
+    /// The focused workspace `target` now uses `layout`, taken from `source`.
+    /// With `swapped`, `source` got the previous layout of `target` in return.
+    Copied {
+        source: String,
+        target: String,
+        layout: LayoutType,
+        swapped: bool,
+    },
+}
+
+/// Outcome of merging the focused workspace into another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeWorkspaceOutcome {
+    /// `moved` windows were moved from `source` into `target`. `visible`
+    /// tells whether they are on screen afterwards.
+    Merged {
+        source: String,
+        target: String,
+        moved: usize,
+        visible: bool,
+    },
+}
+
+/// Outcome of creating or renaming a workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkspaceEditOutcome {
+    /// A workspace named `workspace` was created on `screen`.
+    Created { workspace: String, screen: String },
+    /// The workspace `from` is now named `to`.
+    Renamed { from: String, to: String },
+}
+
+/// Outcome of claiming an app for a workspace or releasing the claim.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClaimAppOutcome {
+    /// New windows of `app_id` now open on `workspace`.
+    Claimed {
+        workspace: String,
+        app_id: String,
+        app_name: String,
+    },
+    /// `workspace` no longer claims `app_id`.
+    Unclaimed {
+        workspace: String,
+        app_id: String,
+        app_name: String,
+    },
+}
+
+/// Change to the number of master windows in the master layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MasterCountChange {
+    /// Set the master count to an exact value.
+    Set(usize),
+    /// Add to (positive) or remove from (negative) the current master count.
+    Adjust(i32),
+}
+
+impl MasterCountChange {
+    /// Parses a master count change.
+    ///
+    /// "+N" and "-N" adjust the current count, a bare "N" sets it.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if s.starts_with(['+', '-']) {
+            s.parse().ok().map(Self::Adjust)
+        } else {
+            s.parse().ok().map(Self::Set)
+        }
+    }
+
+    /// Applies the change to `current`, never going below one master.
+    #[must_use]
+    pub fn apply(self, current: usize) -> usize {
+        let count = match self {
+            Self::Set(count) => count,
+            Self::Adjust(delta) => current.saturating_add_signed(delta as isize),
+        };
+        count.max(1)
+    }
+}
+
+/// Change to the active gap preset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GapPresetChange {
+    /// Switch to the named preset.
+    Set(String),
+    /// Switch to the preset after the active one, in alphabetical order.
+    Cycle,
+    /// Go back to the configured gaps.
+    Reset,
+}
+
+impl GapPresetChange {
+    /// Parses a gap preset change.
+    ///
+    /// "cycle" and "reset" are keywords; anything else names a preset.
+    #[must_use]
+    pub fn parse(s: &str) -> Self {
+        match s.trim() {
+            "cycle" => Self::Cycle,
+            "reset" => Self::Reset,
+            name => Self::Set(name.to_string()),
+        }
+    }
+}
+
+/// Outcome of changing the active gap preset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GapPresetOutcome {
+    /// The preset is now active; `None` when back to the configured gaps.
+    Applied { preset: Option<String> },
+}
+
+/// Outcome of reconciling tracked windows with the system's windows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetileOutcome {
+    /// Windows that weren't tracked yet and now are.
+    pub tracked: usize,
+    /// Tracked windows that no longer exist and were dropped.
+    pub untracked: usize,
+}
+
+/// Direction for scrolling a scrolling-layout workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollDirection {
+    Left,
+    Right,
+}
+
+impl ScrollDirection {
+    /// Parses a scroll direction string (case-insensitive).
+    ///
+    /// Valid values: "left", "right"
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    /// Applies one step in this direction to a column offset.
+    #[must_use]
+    pub const fn apply(self, offset: usize) -> usize {
+        match self {
+            Self::Left => offset.saturating_sub(1),
+            Self::Right => offset.saturating_add(1),
+        }
+    }
+}
+
+/// Change to an on/off window state, such as sticky or floating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToggleAction {
+    Toggle,
+    On,
+    Off,
+}
+
+impl ToggleAction {
+    /// Parses a toggle action string (case-insensitive).
+    ///
+    /// Valid values: "toggle", "on", "off"
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "toggle" => Some(Self::Toggle),
+            "on" => Some(Self::On),
+            "off" => Some(Self::Off),
+            _ => None,
+        }
+    }
+
+    /// Returns the new state given the current one.
+    #[must_use]
+    pub const fn apply(self, current: bool) -> bool {
+        match self {
+            Self::Toggle => !current,
+            Self::On => true,
+            Self::Off => false,
+        }
+    }
+}
+
 /// Target screen for send operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TargetScreen {
@@ -467,6 +1003,13 @@ pub enum StateQuery {
     GetWindowLayout {
         workspace_id: Uuid,
     },
+    /// Get the dwindle split tree of a workspace.
+    GetLayoutTree {
+        workspace_id: Uuid,
+    },
+    /// Get the frames windows had when first tracked, for windows that can be
+    /// restored. Returned as a `Layout` result.
+    GetOriginalFrames,
 
     // ════════════════════════════════════════════════════════════════════════
     // ID-Only Queries (zero-clone, for hot paths)
@@ -487,6 +1030,8 @@ pub enum StateQuery {
     },
     /// Get visible workspace IDs.
     GetVisibleWorkspaceIds,
+    /// Get recently focused window IDs, most recent first.
+    GetRecentWindowIds,
     /// Check if a window exists. O(1).
     HasWindow {
         id: u32,
@@ -514,6 +1059,8 @@ pub enum QueryResult {
     Focus(FocusState),
     Enabled(bool),
     Layout(Vec<(u32, Rect)>),
+    /// Dwindle split tree, `None` for other layouts or empty workspaces.
+    LayoutTree(Option<DwindleNode>),
 
     // ID-only results (zero-clone)
     ScreenIds(Vec<u32>),
@@ -605,6 +1152,15 @@ impl QueryResult {
         }
     }
 
+    /// Try to get a layout tree from the result.
+    #[must_use]
+    pub fn into_layout_tree(self) -> Option<Option<DwindleNode>> {
+        match self {
+            Self::LayoutTree(tree) => Some(tree),
+            _ => None,
+        }
+    }
+
     // ════════════════════════════════════════════════════════════════════════
     // ID-Only Result Conversions
     // ════════════════════════════════════════════════════════════════════════
@@ -654,6 +1210,12 @@ impl QueryResult {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_command_failed() {
+        let outcome: CommandOutcome<()> = Err(CommandFailed::new("No focused window"));
+        assert_eq!(outcome.unwrap_err().to_string(), "No focused window");
+    }
+
     #[test]
     fn test_cycle_direction() {
         assert_ne!(CycleDirection::Next, CycleDirection::Previous);
@@ -665,6 +1227,87 @@ mod tests {
         assert_ne!(GeometryUpdateType::Move, GeometryUpdateType::MoveResize);
     }
 
+    #[test]
+    fn test_master_count_change_parse() {
+        assert_eq!(
+            MasterCountChange::parse("+1"),
+            Some(MasterCountChange::Adjust(1))
+        );
+        assert_eq!(
+            MasterCountChange::parse("-2"),
+            Some(MasterCountChange::Adjust(-2))
+        );
+        assert_eq!(MasterCountChange::parse("3"), Some(MasterCountChange::Set(3)));
+        assert_eq!(MasterCountChange::parse("two"), None);
+        assert_eq!(MasterCountChange::parse("+"), None);
+    }
+
+    #[test]
+    fn test_gap_preset_change_parse() {
+        assert_eq!(GapPresetChange::parse("cycle"), GapPresetChange::Cycle);
+        assert_eq!(GapPresetChange::parse("reset"), GapPresetChange::Reset);
+        assert_eq!(
+            GapPresetChange::parse(" dense "),
+            GapPresetChange::Set("dense".to_string())
+        );
+    }
+
+    #[test]
+    fn test_master_count_change_apply() {
+        assert_eq!(MasterCountChange::Adjust(1).apply(1), 2);
+        assert_eq!(MasterCountChange::Adjust(-1).apply(2), 1);
+        assert_eq!(MasterCountChange::Adjust(-5).apply(2), 1);
+        assert_eq!(MasterCountChange::Set(3).apply(1), 3);
+        assert_eq!(MasterCountChange::Set(0).apply(2), 1);
+    }
+
+    #[test]
+    fn test_cycle_direction_parse() {
+        assert_eq!(CycleDirection::parse("next"), Some(CycleDirection::Next));
+        assert_eq!(CycleDirection::parse("Prev"), Some(CycleDirection::Previous));
+        assert_eq!(CycleDirection::parse("previous"), Some(CycleDirection::Previous));
+        assert_eq!(CycleDirection::parse("up"), None);
+    }
+
+    #[test]
+    fn test_cycle_direction_step() {
+        assert_eq!(CycleDirection::Next.step(0, 3), 1);
+        assert_eq!(CycleDirection::Next.step(2, 3), 0);
+        assert_eq!(CycleDirection::Previous.step(0, 3), 2);
+        assert_eq!(CycleDirection::Previous.step(2, 3), 1);
+        assert_eq!(CycleDirection::Next.step(0, 0), 0);
+    }
+
+    #[test]
+    fn test_scroll_direction_parse() {
+        assert_eq!(ScrollDirection::parse("left"), Some(ScrollDirection::Left));
+        assert_eq!(ScrollDirection::parse("RIGHT"), Some(ScrollDirection::Right));
+        assert_eq!(ScrollDirection::parse("up"), None);
+    }
+
+    #[test]
+    fn test_scroll_direction_apply() {
+        assert_eq!(ScrollDirection::Right.apply(0), 1);
+        assert_eq!(ScrollDirection::Left.apply(2), 1);
+        assert_eq!(ScrollDirection::Left.apply(0), 0);
+    }
+
+    #[test]
+    fn test_toggle_action_parse() {
+        assert_eq!(ToggleAction::parse("toggle"), Some(ToggleAction::Toggle));
+        assert_eq!(ToggleAction::parse("ON"), Some(ToggleAction::On));
+        assert_eq!(ToggleAction::parse("off"), Some(ToggleAction::Off));
+        assert_eq!(ToggleAction::parse("pin"), None);
+    }
+
+    #[test]
+    fn test_toggle_action_apply() {
+        assert!(ToggleAction::Toggle.apply(false));
+        assert!(!ToggleAction::Toggle.apply(true));
+        assert!(ToggleAction::On.apply(true));
+        assert!(!ToggleAction::Off.apply(true));
+    }
+
     #[test]
     fn test_query_result_conversions() {
         let screens_result = QueryResult::Screens(vec![]);
@@ -685,6 +1328,8 @@ mod tests {
             app_id: "com.test.app".to_string(),
             app_name: "Test App".to_string(),
             title: "Window Title".to_string(),
+            role: Some("AXWindow".to_string()),
+            subrole: Some("AXStandardWindow".to_string()),
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             is_minimized: false,
             is_fullscreen: false,
@@ -714,6 +1359,36 @@ mod tests {
         assert_eq!(ResizeDimension::Height.as_str(), "height");
     }
 
+    #[test]
+    fn test_floating_placement_parse() {
+        assert_eq!(
+            FloatingPlacement::parse("center"),
+            Some(FloatingPlacement::Center)
+        );
+        assert_eq!(
+            FloatingPlacement::parse("Maximize"),
+            Some(FloatingPlacement::Maximize)
+        );
+        assert_eq!(FloatingPlacement::parse("fill"), None);
+        assert_eq!(FloatingPlacement::Center.as_str(), "center");
+        assert_eq!(FloatingPlacement::Maximize.as_str(), "maximize");
+    }
+
+    #[test]
+    fn test_floating_arrangement_parse() {
+        assert_eq!(
+            FloatingArrangement::parse("cascade"),
+            Some(FloatingArrangement::Cascade)
+        );
+        assert_eq!(
+            FloatingArrangement::parse("Grid"),
+            Some(FloatingArrangement::Grid)
+        );
+        assert_eq!(FloatingArrangement::parse("stack"), None);
+        assert_eq!(FloatingArrangement::Cascade.as_str(), "cascade");
+        assert_eq!(FloatingArrangement::Grid.as_str(), "grid");
+    }
+
     #[test]
     fn test_target_screen_parse() {
         assert_eq!(TargetScreen::parse("main"), TargetScreen::Main);
@@ -13,7 +13,7 @@
 //!
 //! # Supported Layouts
 //!
-//! - **Split/SplitHorizontal/SplitVertical**: Linear split with cumulative ratios
+//! - **Split/SplitHorizontal/SplitVertical/Columns**: Linear split with cumulative ratios
 //! - **Dwindle**: Binary tree structure with per-level ratios
 //! - **Grid**: Grid-based layout with primary ratio adjustment
 
@@ -47,11 +47,8 @@ pub fn enforce_minimum_sizes_for_split(
     }
 
     // Determine if horizontal or vertical split
-    let is_horizontal = matches!(
-        layout,
-        LayoutType::SplitHorizontal
-            | LayoutType::Split if screen_frame.width >= screen_frame.height
-    );
+    let is_horizontal = matches!(layout, LayoutType::SplitHorizontal | LayoutType::Columns)
+        || (layout == LayoutType::Split && screen_frame.width >= screen_frame.height);
 
     // Get usable dimension (accounting for outer gaps)
     let usable_frame = gaps.apply_outer(screen_frame);
@@ -401,6 +398,7 @@ pub fn enforce_minimum_sizes_for_dwindle(
             gaps,
             &ratios,
             MasterPosition::Auto,
+            1,
         );
 
         // Check if violations are resolved
@@ -419,6 +417,7 @@ pub fn enforce_minimum_sizes_for_dwindle(
         gaps,
         &ratios,
         MasterPosition::Auto,
+        1,
     );
     Some(final_result)
 }
@@ -566,6 +565,7 @@ pub fn enforce_minimum_sizes_for_grid(
             gaps,
             &ratios,
             MasterPosition::Auto,
+            1,
         );
 
         // Check if violations are resolved
@@ -584,6 +584,7 @@ pub fn enforce_minimum_sizes_for_grid(
         gaps,
         &ratios,
         MasterPosition::Auto,
+        1,
     );
     Some(final_result)
 }
@@ -840,6 +841,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_enforce_minimum_sizes_columns_on_portrait_screen() {
+        use smallvec::smallvec;
+
+        // Columns stay side by side on portrait screens, so minimum widths apply
+        let initial_result: LayoutResult = smallvec![
+            (1, Rect::new(0.0, 0.0, 800.0, 1600.0)),
+            (2, Rect::new(810.0, 0.0, 190.0, 1600.0)),
+        ];
+        let layoutable_windows = vec![
+            Window {
+                id: 1,
+                minimum_size: None,
+                ..Default::default()
+            },
+            Window {
+                id: 2,
+                minimum_size: Some((400.0, 100.0)),
+                ..Default::default()
+            },
+        ];
+        let screen_frame = Rect::new(0.0, 0.0, 1000.0, 1600.0);
+
+        let result = enforce_minimum_sizes_for_split(
+            &initial_result,
+            &layoutable_windows,
+            &[1, 2],
+            &screen_frame,
+            &Gaps::uniform(10.0, 0.0),
+            LayoutType::Columns,
+            &[0.8],
+        )
+        .unwrap();
+
+        let (_, frame2) = result[1];
+        assert!(frame2.width >= 399.0, "got {}", frame2.width);
+        assert!((frame2.height - 1600.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn test_enforce_minimum_sizes_single_window() {
         use smallvec::smallvec;
@@ -23,15 +23,22 @@ use std::panic::{AssertUnwindSafe, catch_unwind};
 
 pub use handle::{ActorError, StateActorHandle};
 pub use messages::{
-    CycleDirection, FocusDirection, GeometryUpdate, GeometryUpdateType, QueryResult, StateMessage,
-    StateQuery, WindowCreatedInfo,
+    ArrangeFloatingOutcome, ClaimAppOutcome, CommandFailed, CommandOutcome, CopyLayoutOutcome,
+    CycleDirection, FloatingArrangement, FloatingPlacement, FocusDirection, FocusIndexOutcome,
+    GapPresetChange, GapPresetOutcome, GeometryUpdate, GeometryUpdateType, MasterCountChange,
+    MergeWorkspaceOutcome, MinimizeOutcome, PlaceWindowOutcome, QueryResult, ResizeDimension,
+    ResizeOutcome, RetileOutcome, ScrollDirection, SendWindowOutcome, StateMessage, StateQuery,
+    ToggleAction, WindowCreatedInfo, WorkspaceEditOutcome,
 };
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 
 use crate::config::get_config;
 use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::layout::{Gaps, MasterPosition, calculate_layout_full};
-use crate::modules::tiling::state::{LayoutType, Rect, TilingState};
+use crate::modules::tiling::layout::{
+    DwindleNode, Gaps, MasterPosition, ScrollingViewport, calculate_layout_full, dwindle_tree,
+};
+use crate::modules::tiling::rules::{find_rule_override, is_app_ignored, override_rules};
+use crate::modules::tiling::state::{LayoutType, Rect, Screen, TilingState, Window, Workspace};
 
 /// Channel buffer size for the state actor.
 ///
@@ -49,6 +56,9 @@ pub struct StateActor {
 
     /// Receiver for incoming messages.
     receiver: mpsc::Receiver<StateMessage>,
+
+    /// Apps (names or bundle IDs) whose windows are never tracked.
+    ignore_apps: Vec<String>,
 }
 
 impl StateActor {
@@ -63,6 +73,7 @@ impl StateActor {
         let actor = Self {
             state: TilingState::new(),
             receiver,
+            ignore_apps: get_config().tiling.ignore_apps.clone(),
         };
 
         // Spawn the actor task using Tauri's async runtime
@@ -119,7 +130,9 @@ impl StateActor {
         match msg {
             // Window events - delegated to handlers
             StateMessage::WindowCreated(info) => {
-                handlers::on_window_created(&mut self.state, info);
+                if !self.is_ignored(&info) {
+                    handlers::on_window_created(&mut self.state, info);
+                }
             }
             StateMessage::WindowDestroyed { window_id } => {
                 tracing::debug!(
@@ -148,6 +161,7 @@ impl StateActor {
             }
             StateMessage::WindowFocused { window_id } => {
                 handlers::on_window_focused(&mut self.state, window_id);
+                self.reveal_focused_column();
             }
             StateMessage::WindowUnfocused { window_id } => {
                 handlers::on_window_unfocused(&mut self.state, window_id);
@@ -164,6 +178,9 @@ impl StateActor {
             StateMessage::WindowTitleChanged { window_id, title } => {
                 handlers::on_window_title_changed(&mut self.state, window_id, &title);
             }
+            StateMessage::WindowTitleSettled { window_id } => {
+                handlers::on_window_title_settled(&mut self.state, window_id);
+            }
             StateMessage::WindowFullscreenChanged { window_id, fullscreen } => {
                 handlers::on_window_fullscreen_changed(&mut self.state, window_id, fullscreen);
             }
@@ -196,23 +213,70 @@ impl StateActor {
 
             // User commands (stubs for Phase 4+)
             StateMessage::SwitchWorkspace { name } => self.on_switch_workspace(&name),
-            StateMessage::CycleWorkspace { direction } => self.on_cycle_workspace(direction),
+            StateMessage::CycleWorkspace { direction, respond_to } => {
+                respond(respond_to, self.on_cycle_workspace(direction), "cycle");
+            }
+            StateMessage::FocusLastWorkspace { respond_to } => {
+                respond(respond_to, self.on_focus_last_workspace(), "focus");
+            }
+            StateMessage::CreateWorkspace { name, respond_to } => {
+                respond(respond_to, self.on_create_workspace(&name), "create");
+            }
+            StateMessage::RenameWorkspace { workspace, name, respond_to } => {
+                respond(
+                    respond_to,
+                    self.on_rename_workspace(workspace.as_deref(), &name),
+                    "rename",
+                );
+            }
+            StateMessage::MergeWorkspace { target, follow, respond_to } => {
+                respond(respond_to, self.on_merge_workspace(&target, follow), "merge");
+            }
+            StateMessage::ClaimApp { claim, respond_to } => {
+                respond(respond_to, self.on_claim_app(claim), "claim");
+            }
             StateMessage::SetLayout { workspace_id, layout } => {
                 self.on_set_layout(workspace_id, layout);
             }
             StateMessage::CycleLayout { workspace_id } => self.on_cycle_layout(workspace_id),
+            StateMessage::CopyLayout { source, swap, respond_to } => {
+                respond(respond_to, self.on_copy_layout(&source, swap), "copy layout");
+            }
             StateMessage::MoveWindowToWorkspace { window_id, workspace_id } => {
                 self.on_move_window_to_workspace(window_id, workspace_id);
             }
+            StateMessage::SendWindowToWorkspace { workspace, follow, respond_to } => {
+                respond(
+                    respond_to,
+                    self.on_send_window_to_workspace(&workspace, follow),
+                    "window",
+                );
+            }
             StateMessage::SwapWindows { window_id_a, window_id_b } => {
                 self.on_swap_windows(window_id_a, window_id_b);
             }
             StateMessage::CycleFocus { direction } => self.on_cycle_focus(direction),
             StateMessage::FocusWindow { direction } => self.on_focus_window(direction),
+            StateMessage::FocusLastWindow => self.on_focus_last_window(),
+            StateMessage::FocusWindowAt { index, wraps, respond_to } => {
+                respond(respond_to, self.on_focus_window_at(index, wraps), "focus index");
+            }
             StateMessage::SwapWindowInDirection { direction } => {
                 self.on_swap_window_in_direction(direction);
             }
+            StateMessage::PromoteWindow => self.on_promote_window(),
+            StateMessage::MoveWindowInDirection { direction, step } => {
+                self.on_move_window_in_direction(direction, step);
+            }
             StateMessage::ToggleFloating { window_id } => self.on_toggle_floating(window_id),
+            StateMessage::SetSticky { action } => self.on_set_sticky(action),
+            StateMessage::SetAlwaysOnTop { action } => self.on_set_always_on_top(action),
+            StateMessage::SetFloating { action } => self.on_set_floating(action),
+            StateMessage::SetMinimized { minimized, respond_to } => {
+                respond(respond_to, self.on_set_minimized(minimized), "minimize");
+            }
+            StateMessage::MoveWindowToScratchpad => self.on_move_window_to_scratchpad(),
+            StateMessage::ToggleScratchpad => self.on_toggle_scratchpad(),
             StateMessage::ResizeSplit {
                 workspace_id,
                 window_index,
@@ -227,12 +291,34 @@ impl StateActor {
             StateMessage::SendWorkspaceToScreen { target_screen } => {
                 self.on_send_workspace_to_screen(&target_screen);
             }
-            StateMessage::ResizeFocusedWindow { dimension, amount } => {
-                self.on_resize_focused_window(dimension, amount);
+            StateMessage::ResizeFocusedWindow { dimension, amount, respond_to } => {
+                respond(
+                    respond_to,
+                    self.on_resize_focused_window(dimension, amount),
+                    "resize",
+                );
+            }
+            StateMessage::SetSplitRatio { index, ratio, respond_to } => {
+                respond(respond_to, self.on_set_split_ratio(index, ratio), "split ratio");
             }
             StateMessage::ApplyPreset { preset } => {
                 self.on_apply_preset(&preset);
             }
+            StateMessage::PlaceFloatingWindow { placement, respond_to } => {
+                respond(respond_to, self.on_place_floating_window(placement), "placement");
+            }
+            StateMessage::ArrangeFloatingWindows { arrangement, respond_to } => {
+                respond(
+                    respond_to,
+                    self.on_arrange_floating_windows(arrangement),
+                    "arrangement",
+                );
+            }
+            StateMessage::AdjustMasterCount { change } => self.on_adjust_master_count(change),
+            StateMessage::ScrollWorkspace { direction } => self.on_scroll_workspace(direction),
+            StateMessage::SetGapPreset { change, respond_to } => {
+                respond(respond_to, self.on_set_gap_preset(change), "gap preset");
+            }
             StateMessage::SetEnabled { enabled } => self.on_set_enabled(enabled),
 
             // Queries
@@ -260,6 +346,8 @@ impl StateActor {
 
             // User-initiated move completed (no swap) - snap back to layout
             StateMessage::UserMoveCompleted { workspace_id } => {
+                // Windows dropped on an ignored screen stay where they are
+                handlers::release_windows_on_ignored_screens(&mut self.state, workspace_id);
                 if let Some(handle) = get_subscriber_handle() {
                     handle.notify_layout_changed(workspace_id, true);
                 }
@@ -270,6 +358,15 @@ impl StateActor {
                 self.on_batch_windows_created(windows);
             }
 
+            // Explicit resync requested by the user
+            StateMessage::Retile {
+                windows,
+                window_ids,
+                respond_to,
+            } => {
+                respond(respond_to, self.on_retile(windows, &window_ids), "retile");
+            }
+
             // Initialization complete - apply layouts
             StateMessage::InitComplete => {
                 self.on_init_complete();
@@ -355,6 +452,12 @@ impl StateActor {
             StateQuery::GetWindowLayout { workspace_id } => {
                 QueryResult::Layout(self.compute_layout(workspace_id))
             }
+            StateQuery::GetLayoutTree { workspace_id } => {
+                QueryResult::LayoutTree(self.compute_layout_tree(workspace_id))
+            }
+            StateQuery::GetOriginalFrames => {
+                QueryResult::Layout(self.state.get_restorable_original_frames())
+            }
 
             // ════════════════════════════════════════════════════════════════════════
             // ID-Only Queries (zero-clone, for hot paths)
@@ -373,6 +476,9 @@ impl StateActor {
             StateQuery::GetVisibleWorkspaceIds => {
                 QueryResult::WorkspaceIds(self.state.get_visible_workspace_ids())
             }
+            StateQuery::GetRecentWindowIds => {
+                QueryResult::WindowIds(self.state.get_recent_window_ids())
+            }
             StateQuery::HasWindow { id } => QueryResult::Exists(self.state.has_window(id)),
             StateQuery::HasWorkspace { id } => QueryResult::Exists(self.state.has_workspace(id)),
             StateQuery::HasScreen { id } => QueryResult::Exists(self.state.has_screen(id)),
@@ -413,83 +519,72 @@ impl StateActor {
             return Vec::new();
         }
 
-        // Extract window IDs in stack order
-        let window_ids: Vec<u32> = workspace
-            .window_ids
-            .iter()
-            .filter(|id| layoutable_windows.iter().any(|w| w.id == **id))
-            .copied()
-            .collect();
+        layout_frames(&workspace, &screen, &layoutable_windows, self.state.gap_preset())
+    }
 
-        if window_ids.is_empty() {
-            return Vec::new();
+    /// Compute the dwindle split tree for a workspace.
+    ///
+    /// Returns `None` for workspaces that don't use the dwindle layout or
+    /// have no layoutable windows. Ratios are the workspace's split ratios,
+    /// before any minimum size adjustments.
+    fn compute_layout_tree(&self, workspace_id: uuid::Uuid) -> Option<DwindleNode> {
+        let workspace = self.state.get_workspace(workspace_id)?;
+        if workspace.layout != LayoutType::Dwindle {
+            return None;
         }
 
-        // Get gaps from config with bar offset for main screen
-        let config = get_config();
-        let bar_offset = if config.bar.is_enabled() {
-            f64::from(config.bar.height) + f64::from(config.bar.padding)
-        } else {
-            0.0
+        let screen = self.state.get_screen(workspace.screen_id)?;
+        let layoutable_windows = self.state.get_layoutable_windows(workspace_id);
+        let window_ids = stack_order_ids(&workspace, &layoutable_windows);
+
+        // Same usable frame as `calculate_layout_full`
+        let frame = workspace_gaps(&workspace, &screen, window_ids.len(), self.state.gap_preset())
+            .apply_outer(&screen.visible_frame);
+        dwindle_tree(&window_ids, &frame, &workspace.split_ratios)
+    }
+
+    /// Returns how many columns of the focused workspace fit on its screen.
+    ///
+    /// Falls back to one column when the workspace or screen is unknown.
+    fn focused_visible_columns(&self) -> usize {
+        let Some(workspace) = self
+            .state
+            .get_focus_state()
+            .focused_workspace_id
+            .and_then(|id| self.state.get_workspace(id))
+        else {
+            return 1;
         };
-        let gaps = Gaps::from_config(&config.tiling.gaps, &screen.name, screen.is_main, bar_offset);
 
-        // Get master ratio from config (default 0.5)
-        let master_ratio = f64::from(config.tiling.master.ratio) / 100.0;
+        let Some(screen) = self.state.get_screen(workspace.screen_id) else {
+            return 1;
+        };
 
-        // Get split ratios from workspace (may be adjusted for minimum sizes)
-        let split_ratios = workspace.split_ratios.clone();
+        let window_count = self.state.get_layoutable_windows(workspace.id).len();
 
-        // Compute initial layout
-        let result = calculate_layout_full(
-            workspace.layout,
-            &window_ids,
+        ScrollingViewport::new(
             &screen.visible_frame,
-            master_ratio,
-            &gaps,
-            &split_ratios,
-            MasterPosition::Auto,
-        );
-
-        // Enforce minimum sizes by adjusting ratios if needed
-        let adjusted_result = match workspace.layout {
-            LayoutType::Split | LayoutType::SplitHorizontal | LayoutType::SplitVertical => {
-                minimum_size::enforce_minimum_sizes_for_split(
-                    &result,
-                    &layoutable_windows,
-                    &window_ids,
-                    &screen.visible_frame,
-                    &gaps,
-                    workspace.layout,
-                    &split_ratios,
-                )
-            }
-            LayoutType::Dwindle => minimum_size::enforce_minimum_sizes_for_dwindle(
-                &result,
-                &layoutable_windows,
-                &window_ids,
-                &screen.visible_frame,
-                &gaps,
-                &split_ratios,
-            ),
-            LayoutType::Grid => minimum_size::enforce_minimum_sizes_for_grid(
-                &result,
-                &layoutable_windows,
-                &window_ids,
-                &screen.visible_frame,
-                &gaps,
-                &split_ratios,
-            ),
-            // Floating/Monocle/Master don't need minimum size enforcement
-            _ => None,
-        };
+            &workspace_gaps(&workspace, &screen, window_count, self.state.gap_preset()),
+            &get_config().tiling.scrolling.column_width,
+        )
+        .visible_columns()
+    }
 
-        if let Some(adjusted) = adjusted_result {
-            return adjusted.into_vec();
+    /// Scrolls the focused workspace so the focused window's column is visible.
+    ///
+    /// Only affects workspaces using the scrolling layout.
+    fn reveal_focused_column(&mut self) {
+        let is_scrolling = self
+            .state
+            .get_focus_state()
+            .focused_workspace_id
+            .and_then(|id| self.state.get_workspace(id))
+            .is_some_and(|ws| ws.layout == LayoutType::Scrolling);
+
+        if is_scrolling {
+            let visible_columns = self.focused_visible_columns();
+            handlers::on_reveal_focused_column(&mut self.state, visible_columns);
         }
-
-        // Convert SmallVec to Vec for the query result
-        result.into_vec()
     }
 
     // ========================================================================
@@ -500,8 +595,36 @@ impl StateActor {
         handlers::on_switch_workspace(&mut self.state, name);
     }
 
-    fn on_cycle_workspace(&mut self, direction: CycleDirection) {
-        handlers::on_cycle_workspace(&mut self.state, direction);
+    fn on_cycle_workspace(&mut self, direction: CycleDirection) -> Option<String> {
+        handlers::on_cycle_workspace(&mut self.state, direction)
+    }
+
+    fn on_focus_last_workspace(&mut self) -> Option<String> {
+        handlers::on_focus_last_workspace(&mut self.state)
+    }
+
+    fn on_create_workspace(&mut self, name: &str) -> CommandOutcome<WorkspaceEditOutcome> {
+        handlers::on_create_workspace(&mut self.state, name)
+    }
+
+    fn on_rename_workspace(
+        &mut self,
+        workspace: Option<&str>,
+        name: &str,
+    ) -> CommandOutcome<WorkspaceEditOutcome> {
+        handlers::on_rename_workspace(&mut self.state, workspace, name)
+    }
+
+    fn on_merge_workspace(
+        &mut self,
+        target: &str,
+        follow: bool,
+    ) -> CommandOutcome<MergeWorkspaceOutcome> {
+        handlers::on_merge_workspace(&mut self.state, target, follow)
+    }
+
+    fn on_claim_app(&mut self, claim: bool) -> CommandOutcome<ClaimAppOutcome> {
+        handlers::on_claim_app(&mut self.state, claim)
     }
 
     fn on_set_layout(
@@ -513,33 +636,97 @@ impl StateActor {
     }
 
     fn on_cycle_layout(&mut self, workspace_id: uuid::Uuid) {
-        handlers::on_cycle_layout(&mut self.state, workspace_id);
+        let cycle: Vec<LayoutType> = get_config()
+            .tiling
+            .layout_cycle
+            .iter()
+            .map(|&layout| handlers::convert_layout_type(layout))
+            .collect();
+        handlers::on_cycle_layout(&mut self.state, workspace_id, &cycle);
+    }
+
+    fn on_copy_layout(&mut self, source: &str, swap: bool) -> CommandOutcome<CopyLayoutOutcome> {
+        handlers::on_copy_layout(&mut self.state, source, swap)
     }
 
     fn on_move_window_to_workspace(&mut self, window_id: u32, workspace_id: uuid::Uuid) {
         handlers::on_move_window_to_workspace(&mut self.state, window_id, workspace_id);
     }
 
+    fn on_send_window_to_workspace(
+        &mut self,
+        workspace: &str,
+        follow: bool,
+    ) -> CommandOutcome<SendWindowOutcome> {
+        handlers::on_send_window_to_workspace(&mut self.state, workspace, follow)
+    }
+
     fn on_swap_windows(&mut self, window_id_a: u32, window_id_b: u32) {
         handlers::on_swap_windows(&mut self.state, window_id_a, window_id_b);
     }
 
     fn on_cycle_focus(&mut self, direction: CycleDirection) {
         handlers::on_cycle_focus(&mut self.state, direction);
+        self.reveal_focused_column();
     }
 
     fn on_focus_window(&mut self, direction: FocusDirection) {
-        handlers::on_focus_window(&mut self.state, direction);
+        handlers::on_focus_window(&mut self.state, direction, get_config().tiling.focus_wraps);
+        self.reveal_focused_column();
+    }
+
+    fn on_focus_last_window(&mut self) {
+        handlers::on_focus_last_window(&mut self.state);
+        self.reveal_focused_column();
+    }
+
+    fn on_focus_window_at(
+        &mut self,
+        index: usize,
+        wraps: bool,
+    ) -> CommandOutcome<FocusIndexOutcome> {
+        let outcome = handlers::on_focus_window_at(&mut self.state, index, wraps);
+        self.reveal_focused_column();
+        outcome
     }
 
     fn on_swap_window_in_direction(&mut self, direction: FocusDirection) {
         handlers::on_swap_window_in_direction(&mut self.state, direction);
     }
 
+    fn on_promote_window(&mut self) { handlers::on_promote_window(&mut self.state); }
+
+    fn on_move_window_in_direction(&mut self, direction: FocusDirection, step: Option<u32>) {
+        handlers::on_move_window_in_direction(&mut self.state, direction, step);
+    }
+
     fn on_toggle_floating(&mut self, window_id: u32) {
         handlers::on_toggle_floating(&mut self.state, window_id);
     }
 
+    fn on_set_sticky(&mut self, action: ToggleAction) {
+        handlers::on_set_sticky(&mut self.state, action);
+    }
+
+    fn on_set_always_on_top(&mut self, action: ToggleAction) {
+        handlers::on_set_always_on_top(&mut self.state, action);
+    }
+
+    fn on_set_floating(&mut self, action: ToggleAction) {
+        let default_position = get_config().tiling.floating.default_position;
+        handlers::on_set_floating(&mut self.state, action, default_position);
+    }
+
+    fn on_set_minimized(&mut self, minimized: bool) -> CommandOutcome<MinimizeOutcome> {
+        handlers::on_set_minimized(&mut self.state, minimized)
+    }
+
+    fn on_move_window_to_scratchpad(&mut self) {
+        handlers::on_move_window_to_scratchpad(&mut self.state);
+    }
+
+    fn on_toggle_scratchpad(&mut self) { handlers::on_toggle_scratchpad(&mut self.state); }
+
     fn on_resize_split(&mut self, workspace_id: uuid::Uuid, window_index: usize, delta: f64) {
         handlers::on_resize_split(&mut self.state, workspace_id, window_index, delta);
     }
@@ -548,6 +735,21 @@ impl StateActor {
         handlers::on_balance_workspace(&mut self.state, workspace_id);
     }
 
+    fn on_adjust_master_count(&mut self, change: MasterCountChange) {
+        let default_count = get_config().tiling.master.max_masters as usize;
+        handlers::on_adjust_master_count(&mut self.state, change, default_count);
+    }
+
+    fn on_scroll_workspace(&mut self, direction: ScrollDirection) {
+        let visible_columns = self.focused_visible_columns();
+        handlers::on_scroll_workspace(&mut self.state, direction, visible_columns);
+    }
+
+    fn on_set_gap_preset(&mut self, change: GapPresetChange) -> CommandOutcome<GapPresetOutcome> {
+        let presets: Vec<String> = get_config().tiling.gap_presets.keys().cloned().collect();
+        handlers::on_set_gap_preset(&mut self.state, change, &presets)
+    }
+
     fn on_send_window_to_screen(&mut self, target_screen: &messages::TargetScreen) {
         handlers::on_send_window_to_screen(&mut self.state, target_screen);
     }
@@ -556,14 +758,36 @@ impl StateActor {
         handlers::on_send_workspace_to_screen(&mut self.state, target_screen);
     }
 
-    fn on_resize_focused_window(&mut self, dimension: messages::ResizeDimension, amount: i32) {
-        handlers::on_resize_focused_window(&mut self.state, dimension, amount);
+    fn on_resize_focused_window(
+        &mut self,
+        dimension: messages::ResizeDimension,
+        amount: i32,
+    ) -> CommandOutcome<ResizeOutcome> {
+        handlers::on_resize_focused_window(&mut self.state, dimension, amount)
+    }
+
+    fn on_set_split_ratio(&mut self, index: usize, ratio: f64) -> CommandOutcome<ResizeOutcome> {
+        handlers::on_set_split_ratio(&mut self.state, index, ratio)
     }
 
     fn on_apply_preset(&mut self, preset_name: &str) {
         handlers::on_apply_preset(&mut self.state, preset_name);
     }
 
+    fn on_place_floating_window(
+        &mut self,
+        placement: FloatingPlacement,
+    ) -> CommandOutcome<PlaceWindowOutcome> {
+        handlers::on_place_floating_window(&mut self.state, placement)
+    }
+
+    fn on_arrange_floating_windows(
+        &mut self,
+        arrangement: FloatingArrangement,
+    ) -> CommandOutcome<ArrangeFloatingOutcome> {
+        handlers::on_arrange_floating_windows(&mut self.state, arrangement)
+    }
+
     fn on_set_enabled(&mut self, enabled: bool) {
         tracing::debug!("Set enabled: {enabled}");
         self.state.set_enabled(enabled);
@@ -599,13 +823,18 @@ impl StateActor {
             windows.len()
         );
 
-        for info in windows {
+        for info in windows.into_iter().filter(|info| !self.is_ignored(info)) {
             // Use the window handler but it won't notify subscriber during init
             // because subscriber handle won't be stored yet
             handlers::on_window_created_silent(&mut self.state, info);
         }
     }
 
+    /// Returns whether the window belongs to an app listed in `tiling.ignoreApps`.
+    fn is_ignored(&self, info: &WindowCreatedInfo) -> bool {
+        is_app_ignored(&self.ignore_apps, &info.app_id, &info.app_name)
+    }
+
     /// Handles initialization complete.
     ///
     /// Triggers layout calculation for all visible workspaces and hides
@@ -630,25 +859,78 @@ impl StateActor {
         tracing::debug!("Initial layout notifications sent");
     }
 
+    /// Handles an explicit retile request.
+    ///
+    /// Untracks windows that no longer exist, tracks windows that were missed
+    /// (following the same rules as at startup), then re-applies the layouts of
+    /// all visible workspaces, moving every window back into place.
+    fn on_retile(&mut self, windows: Vec<WindowCreatedInfo>, window_ids: &[u32]) -> RetileOutcome {
+        let alive: std::collections::HashSet<u32> = window_ids.iter().copied().collect();
+        let closed: Vec<u32> = self
+            .state
+            .windows
+            .iter()
+            .map(|w| w.id)
+            .filter(|id| !alive.contains(id))
+            .collect();
+
+        // Windows closed behind our back are untracked; the workspace being
+        // left empty is not a reason to switch away while re-tiling
+        for window_id in &closed {
+            handlers::untrack_window(&mut self.state, *window_id);
+        }
+
+        let mut tracked = 0;
+        for info in windows.into_iter().filter(|info| !self.is_ignored(info)) {
+            let window_id = info.window_id;
+            if self.state.get_window(window_id).is_some() {
+                continue;
+            }
+            handlers::on_window_created_silent(&mut self.state, info);
+            if self.state.get_window(window_id).is_some() {
+                tracked += 1;
+            }
+        }
+
+        self.sync_window_visibility();
+
+        if let Some(handle) = crate::modules::tiling::init::get_subscriber_handle() {
+            for ws in self.state.get_visible_workspaces() {
+                handle.notify_layout_changed(ws.id, true);
+            }
+        }
+
+        let outcome = RetileOutcome {
+            tracked,
+            untracked: closed.len(),
+        };
+        tracing::info!(?outcome, "tiling: retiled");
+        outcome
+    }
+
     /// Syncs window visibility based on workspace visibility.
     ///
     /// - Shows (unhides) apps that have windows in visible workspaces
     /// - Hides apps that have windows ONLY in non-visible workspaces
+    ///
+    /// Sticky windows count as visible wherever their workspace is, and so do
+    /// scratchpad windows while the scratchpad is shown.
     fn sync_window_visibility(&self) {
         use std::collections::HashSet;
 
         use crate::modules::tiling::effects::window_ops::{hide_app, unhide_app};
 
         // Collect visible workspace IDs
-        let visible_ws_ids: HashSet<uuid::Uuid> =
+        let mut visible_ws_ids: HashSet<uuid::Uuid> =
             self.state.get_visible_workspaces().iter().map(|ws| ws.id).collect();
+        visible_ws_ids.extend(self.state.get_shown_scratchpad_id());
 
         // Collect PIDs for windows in visible vs non-visible workspaces
         let mut pids_in_visible: HashSet<i32> = HashSet::new();
         let mut pids_in_non_visible: HashSet<i32> = HashSet::new();
 
         for window in self.state.windows.iter() {
-            if visible_ws_ids.contains(&window.workspace_id) {
+            if window.is_sticky || visible_ws_ids.contains(&window.workspace_id) {
                 pids_in_visible.insert(window.pid);
             } else {
                 pids_in_non_visible.insert(window.pid);
@@ -677,12 +959,237 @@ impl StateActor {
     }
 }
 
+/// Sends the outcome of a `command` to the caller waiting for it, if any.
+fn respond<T>(respond_to: Option<oneshot::Sender<T>>, outcome: T, command: &str) {
+    if let Some(respond_to) = respond_to
+        && respond_to.send(outcome).is_err()
+    {
+        tracing::warn!("tiling: failed to send {command} outcome (channel closed)");
+    }
+}
+
+/// Computes the frames of a workspace's layoutable windows on a screen.
+///
+/// Pure layout math: reads the config but never touches real windows, so it
+/// backs both the actor's layout queries and [`dry_run_layout`]. Enforces
+/// minimum window sizes by adjusting split ratios when necessary, then applies
+/// the `gaps` of the window rules matching each window.
+/// `gap_preset` is the active `tiling.gapPresets` entry, if any.
+fn layout_frames(
+    workspace: &Workspace,
+    screen: &Screen,
+    layoutable_windows: &[Window],
+    gap_preset: Option<&str>,
+) -> Vec<(u32, Rect)> {
+    let mut frames = tiled_frames(workspace, screen, layoutable_windows, gap_preset);
+
+    let config = get_config();
+    let gaps = workspace_gaps(workspace, screen, frames.len(), gap_preset);
+    apply_rule_gaps(
+        &mut frames,
+        layoutable_windows,
+        &screen.visible_frame,
+        &gaps,
+        |window| find_rule_override(override_rules(&config.tiling), window, |rule| rule.gaps),
+    );
+
+    frames
+}
+
+/// Re-insets the frames of windows that have their own gap.
+///
+/// `gap_for` returns a window's gap, or `None` to keep the layout's gaps.
+fn apply_rule_gaps(
+    frames: &mut [(u32, Rect)],
+    windows: &[Window],
+    area: &Rect,
+    gaps: &Gaps,
+    gap_for: impl Fn(&Window) -> Option<f64>,
+) {
+    for (window_id, frame) in frames {
+        let Some(gap) = windows.iter().find(|w| w.id == *window_id).and_then(&gap_for) else {
+            continue;
+        };
+        *frame = gaps.reinset(frame, area, gap.max(0.0));
+    }
+}
+
+/// Computes the layout frames of a workspace's windows with the layout's gaps.
+fn tiled_frames(
+    workspace: &Workspace,
+    screen: &Screen,
+    layoutable_windows: &[Window],
+    gap_preset: Option<&str>,
+) -> Vec<(u32, Rect)> {
+    // Extract window IDs in stack order
+    let window_ids = stack_order_ids(workspace, layoutable_windows);
+    if window_ids.is_empty() {
+        return Vec::new();
+    }
+
+    let config = get_config();
+    let gaps = workspace_gaps(workspace, screen, window_ids.len(), gap_preset);
+
+    // Scrolling needs the configured column width and the workspace offset
+    if workspace.layout == LayoutType::Scrolling {
+        let viewport = ScrollingViewport::new(
+            &screen.visible_frame,
+            &gaps,
+            &config.tiling.scrolling.column_width,
+        );
+        return viewport.layout(&window_ids, workspace.scroll_offset).into_vec();
+    }
+
+    // Master ratio set on the workspace (split ratio 0), or the configured one
+    let master_ratio = workspace
+        .split_ratios
+        .first()
+        .copied()
+        .filter(|_| workspace.layout == LayoutType::Master)
+        .unwrap_or_else(|| f64::from(config.tiling.master.ratio) / 100.0);
+
+    // Workspace master count overrides the configured default
+    let master_count = workspace.master_count.unwrap_or(config.tiling.master.max_masters as usize);
+
+    // Get split ratios from workspace (may be adjusted for minimum sizes)
+    let split_ratios = workspace.split_ratios.clone();
+
+    // Compute initial layout
+    let result = calculate_layout_full(
+        workspace.layout,
+        &window_ids,
+        &screen.visible_frame,
+        master_ratio,
+        &gaps,
+        &split_ratios,
+        MasterPosition::Auto,
+        master_count,
+    );
+
+    // Enforce minimum sizes by adjusting ratios if needed
+    let adjusted_result = match workspace.layout {
+        LayoutType::Split
+        | LayoutType::SplitHorizontal
+        | LayoutType::SplitVertical
+        | LayoutType::Columns => minimum_size::enforce_minimum_sizes_for_split(
+            &result,
+            layoutable_windows,
+            &window_ids,
+            &screen.visible_frame,
+            &gaps,
+            workspace.layout,
+            &split_ratios,
+        ),
+        LayoutType::Dwindle => minimum_size::enforce_minimum_sizes_for_dwindle(
+            &result,
+            layoutable_windows,
+            &window_ids,
+            &screen.visible_frame,
+            &gaps,
+            &split_ratios,
+        ),
+        LayoutType::Grid => minimum_size::enforce_minimum_sizes_for_grid(
+            &result,
+            layoutable_windows,
+            &window_ids,
+            &screen.visible_frame,
+            &gaps,
+            &split_ratios,
+        ),
+        // Floating/Monocle/Master don't need minimum size enforcement
+        _ => None,
+    };
+
+    if let Some(adjusted) = adjusted_result {
+        return adjusted.into_vec();
+    }
+
+    // Convert SmallVec to Vec for the query result
+    result.into_vec()
+}
+
+/// Computes the frames a layout would give to a set of windows on a screen
+/// frame, without tracking or moving any window.
+///
+/// Uses the same path as the actor's layouts, with the configured gaps,
+/// master settings and scrolling column width. The windows report no minimum
+/// sizes and the frame is used as given, so no bar offset is added.
+#[must_use]
+pub fn dry_run_layout(layout: LayoutType, window_ids: &[u32], frame: Rect) -> Vec<(u32, Rect)> {
+    let workspace = Workspace {
+        layout,
+        window_ids: window_ids.iter().copied().collect(),
+        ..Default::default()
+    };
+    let screen = Screen {
+        frame,
+        visible_frame: frame,
+        ..Default::default()
+    };
+    let windows: Vec<Window> = window_ids
+        .iter()
+        .map(|&id| Window {
+            id,
+            workspace_id: workspace.id,
+            ..Default::default()
+        })
+        .collect();
+
+    layout_frames(&workspace, &screen, &windows, None)
+}
+
+/// Returns the IDs of the layoutable windows in the workspace's stack order.
+fn stack_order_ids(workspace: &Workspace, layoutable_windows: &[Window]) -> Vec<u32> {
+    workspace
+        .window_ids
+        .iter()
+        .filter(|id| layoutable_windows.iter().any(|w| w.id == **id))
+        .copied()
+        .collect()
+}
+
+/// Returns the gaps for a workspace on a screen, with the bar offset on the main screen.
+///
+/// The active gap preset, if any, replaces the workspace's gaps override. With
+/// `tiling.smartGaps`, a workspace with a single tiled window gets no gaps
+/// besides the bar offset.
+fn workspace_gaps(
+    workspace: &Workspace,
+    screen: &Screen,
+    tiled_count: usize,
+    gap_preset: Option<&str>,
+) -> Gaps {
+    let config = get_config();
+    let bar_offset = if config.bar.is_enabled() {
+        f64::from(config.bar.height) + f64::from(config.bar.padding)
+    } else {
+        0.0
+    };
+
+    let gaps = Gaps::from_config_for_workspace(
+        &config.tiling.gaps,
+        config.tiling.gaps_override(&workspace.name, gap_preset),
+        &screen.name,
+        screen.is_main,
+        bar_offset,
+    );
+
+    if config.tiling.smart_gaps {
+        let top_offset = if screen.is_main { bar_offset } else { 0.0 };
+        gaps.with_smart_gaps(tiled_count, top_offset)
+    } else {
+        gaps
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
 mod tests {
+    use tokio::sync::oneshot;
+
     use super::*;
 
     #[tokio::test]
@@ -739,4 +1246,318 @@ mod tests {
 
         handle.shutdown().unwrap();
     }
+
+    #[test]
+    fn test_actor_skips_ignored_apps() {
+        let (_sender, receiver) = mpsc::channel(1);
+        let mut actor = StateActor {
+            state: TilingState::new(),
+            receiver,
+            ignore_apps: vec!["Raycast".to_string(), "com.1password.1password".to_string()],
+        };
+
+        let mut ws = Workspace::new("test");
+        ws.screen_id = 1;
+        ws.is_visible = true;
+        ws.is_focused = true;
+        actor.state.upsert_workspace(ws);
+
+        let info = |window_id: u32, app_id: &str, app_name: &str| WindowCreatedInfo {
+            window_id,
+            pid: 1000,
+            app_id: app_id.to_string(),
+            app_name: app_name.to_string(),
+            title: format!("Window {window_id}"),
+            role: None,
+            subrole: None,
+            frame: Rect::new(0.0, 0.0, 800.0, 600.0),
+            is_minimized: false,
+            is_fullscreen: false,
+            minimum_size: None,
+            tab_group_id: None,
+            is_active_tab: true,
+        };
+
+        actor.handle_message(StateMessage::WindowCreated(info(
+            1,
+            "com.raycast.macos",
+            "raycast",
+        )));
+        actor.handle_message(StateMessage::BatchWindowsCreated(vec![
+            info(2, "com.1password.1password", "1Password"),
+            info(3, "com.test.app", "Test App"),
+        ]));
+
+        let windows = actor.execute_query(StateQuery::GetAllWindows).into_windows().unwrap();
+        let ids: Vec<u32> = windows.iter().map(|w| w.id).collect();
+        assert_eq!(ids, vec![3]);
+    }
+
+    #[test]
+    fn test_actor_retile_reconciles_windows() {
+        let (_sender, receiver) = mpsc::channel(1);
+        let mut actor = StateActor {
+            state: TilingState::new(),
+            receiver,
+            ignore_apps: Vec::new(),
+        };
+
+        let mut ws = Workspace::new("test");
+        ws.screen_id = 1;
+        ws.is_visible = true;
+        ws.is_focused = true;
+        ws.window_ids = vec![1, 2];
+        let ws_id = ws.id;
+        actor.state.upsert_workspace(ws);
+        for id in [1, 2] {
+            actor.state.upsert_window(Window {
+                id,
+                workspace_id: ws_id,
+                ..Default::default()
+            });
+        }
+
+        let info = |window_id: u32| WindowCreatedInfo {
+            window_id,
+            pid: 1000,
+            app_id: "com.test.app".to_string(),
+            app_name: "Test App".to_string(),
+            title: format!("Window {window_id}"),
+            role: None,
+            subrole: None,
+            frame: Rect::new(0.0, 0.0, 800.0, 600.0),
+            is_minimized: false,
+            is_fullscreen: false,
+            minimum_size: None,
+            tab_group_id: None,
+            is_active_tab: true,
+        };
+
+        // Window 1 closed unnoticed, window 3 opened unnoticed
+        let (tx, mut rx) = oneshot::channel();
+        actor.handle_message(StateMessage::Retile {
+            windows: vec![info(2), info(3)],
+            window_ids: vec![2, 3],
+            respond_to: Some(tx),
+        });
+
+        assert_eq!(rx.try_recv().unwrap(), RetileOutcome {
+            tracked: 1,
+            untracked: 1
+        });
+        let windows = actor.execute_query(StateQuery::GetAllWindows).into_windows().unwrap();
+        let mut ids: Vec<u32> = windows.iter().map(|w| w.id).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_actor_send_window_to_workspace_on_other_screen() {
+        let (_sender, receiver) = mpsc::channel(1);
+        let mut actor = StateActor {
+            state: TilingState::new(),
+            receiver,
+            ignore_apps: Vec::new(),
+        };
+
+        for (id, name, x) in [(1, "Main", 0.0), (2, "External", 1920.0)] {
+            actor.state.upsert_screen(Screen {
+                id,
+                name: name.to_string(),
+                frame: Rect::new(x, 0.0, 1920.0, 1080.0),
+                visible_frame: Rect::new(x, 0.0, 1920.0, 1080.0),
+                is_main: id == 1,
+                ..Default::default()
+            });
+        }
+
+        let mut source = Workspace::new("source");
+        source.screen_id = 1;
+        source.layout = LayoutType::Dwindle;
+        source.is_visible = true;
+        source.is_focused = true;
+        source.window_ids = vec![1, 2];
+        let source_id = source.id;
+        actor.state.upsert_workspace(source);
+
+        let mut target = Workspace::new("target");
+        target.screen_id = 2;
+        target.layout = LayoutType::Dwindle;
+        target.is_visible = true;
+        let target_id = target.id;
+        actor.state.upsert_workspace(target);
+
+        for id in [1, 2] {
+            actor.state.upsert_window(Window {
+                id,
+                workspace_id: source_id,
+                ..Default::default()
+            });
+        }
+        actor.state.update_focus(|focus| {
+            focus.focused_workspace_id = Some(source_id);
+            focus.focused_screen_id = Some(1);
+            focus.focused_window_id = Some(1);
+        });
+
+        let (tx, mut rx) = oneshot::channel();
+        actor.handle_message(StateMessage::SendWindowToWorkspace {
+            workspace: "target".to_string(),
+            follow: false,
+            respond_to: Some(tx),
+        });
+
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            Ok(SendWindowOutcome::Sent {
+                window_id: 1,
+                workspace: "target".to_string(),
+                screen: "External".to_string(),
+                visible: true,
+            })
+        );
+
+        let window = actor.state.get_window(1).unwrap();
+        assert_eq!(window.workspace_id, target_id);
+
+        // The source workspace is laid out without the sent window
+        let source_layout = actor
+            .execute_query(StateQuery::GetWindowLayout { workspace_id: source_id })
+            .into_layout()
+            .unwrap();
+        let ids: Vec<u32> = source_layout.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![2]);
+
+        // And the target workspace lays it out on the other screen
+        let target_layout = actor
+            .execute_query(StateQuery::GetWindowLayout { workspace_id: target_id })
+            .into_layout()
+            .unwrap();
+        assert_eq!(target_layout.len(), 1);
+        assert_eq!(target_layout[0].0, 1);
+        assert!(target_layout[0].1.x >= 1920.0);
+    }
+
+    #[test]
+    fn test_fullscreen_window_is_left_out_of_layout() {
+        let (_sender, receiver) = mpsc::channel(1);
+        let mut actor = StateActor {
+            state: TilingState::new(),
+            receiver,
+            ignore_apps: Vec::new(),
+        };
+
+        let frame = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        actor.state.upsert_screen(Screen {
+            id: 1,
+            frame,
+            visible_frame: frame,
+            ..Default::default()
+        });
+
+        let mut ws = Workspace::new("fullscreen");
+        ws.screen_id = 1;
+        ws.layout = LayoutType::Dwindle;
+        ws.window_ids = [1, 2].into_iter().collect();
+        let workspace_id = ws.id;
+        actor.state.upsert_workspace(ws);
+        for id in [1, 2] {
+            actor.state.upsert_window(Window {
+                id,
+                workspace_id,
+                ..Default::default()
+            });
+        }
+
+        let layout_ids = |actor: &StateActor| -> Vec<u32> {
+            actor
+                .execute_query(StateQuery::GetWindowLayout { workspace_id })
+                .into_layout()
+                .unwrap()
+                .iter()
+                .map(|(id, _)| *id)
+                .collect()
+        };
+
+        actor.handle_message(StateMessage::WindowFullscreenChanged {
+            window_id: 1,
+            fullscreen: true,
+        });
+        assert_eq!(layout_ids(&actor), vec![2]);
+
+        // Leaving fullscreen puts the window back into the layout
+        actor.handle_message(StateMessage::WindowFullscreenChanged {
+            window_id: 1,
+            fullscreen: false,
+        });
+        assert_eq!(layout_ids(&actor), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_dry_run_layout_matches_actor_layout() {
+        let (_sender, receiver) = mpsc::channel(1);
+        let mut actor = StateActor {
+            state: TilingState::new(),
+            receiver,
+            ignore_apps: Vec::new(),
+        };
+
+        let frame = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        actor.state.upsert_screen(Screen {
+            id: 1,
+            frame,
+            visible_frame: frame,
+            ..Default::default()
+        });
+
+        let mut ws = Workspace::new("dry");
+        ws.screen_id = 1;
+        ws.layout = LayoutType::Dwindle;
+        ws.window_ids = [1, 2, 3].into_iter().collect();
+        let workspace_id = ws.id;
+        actor.state.upsert_workspace(ws);
+        for id in [1, 2, 3] {
+            actor.state.upsert_window(Window {
+                id,
+                workspace_id,
+                ..Default::default()
+            });
+        }
+
+        let layout = actor
+            .execute_query(StateQuery::GetWindowLayout { workspace_id })
+            .into_layout()
+            .unwrap();
+        assert_eq!(layout.len(), 3);
+        assert_eq!(dry_run_layout(LayoutType::Dwindle, &[1, 2, 3], frame), layout);
+    }
+
+    #[test]
+    fn test_apply_rule_gaps_only_changes_matching_windows() {
+        let area = Rect::new(0.0, 0.0, 1000.0, 500.0);
+        let gaps = Gaps::uniform(10.0, 20.0);
+        let left = Rect::new(20.0, 20.0, 475.0, 460.0);
+        let right = Rect::new(505.0, 20.0, 475.0, 460.0);
+        let mut frames = vec![(1, left), (2, right)];
+        let windows: Vec<Window> =
+            [1, 2].map(|id| Window { id, ..Default::default() }).into_iter().collect();
+
+        apply_rule_gaps(&mut frames, &windows, &area, &gaps, |window| {
+            (window.id == 2).then_some(0.0)
+        });
+
+        assert_eq!(frames, vec![(1, left), (2, Rect::new(500.0, 0.0, 500.0, 500.0))]);
+    }
+
+    #[test]
+    fn test_dry_run_layout_floating_and_empty() {
+        let frame = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        assert!(dry_run_layout(LayoutType::Floating, &[1, 2], frame).is_empty());
+        assert!(dry_run_layout(LayoutType::Dwindle, &[], frame).is_empty());
+
+        // Monocle gives every window the same frame
+        let monocle = dry_run_layout(LayoutType::Monocle, &[1, 2], frame);
+        assert_eq!(monocle.len(), 2);
+        assert_eq!(monocle[0].1, monocle[1].1);
+    }
 }
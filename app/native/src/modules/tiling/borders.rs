@@ -19,8 +19,10 @@ use std::sync::OnceLock;
 use parking_lot::Mutex;
 
 use crate::config::{BorderColor, BorderStateConfig, Rgba, get_config, parse_hex_color};
-use crate::modules::tiling::rules::{SKIP_TILING_APP_NAMES, SKIP_TILING_BUNDLE_IDS};
-use crate::modules::tiling::state::LayoutType;
+use crate::modules::tiling::rules::{
+    SKIP_TILING_APP_NAMES, SKIP_TILING_BUNDLE_IDS, find_rule_override, override_rules,
+};
+use crate::modules::tiling::state::{LayoutType, Window};
 
 // ============================================================================
 // Constants
@@ -362,16 +364,27 @@ pub fn init() -> bool {
     }
 }
 
+/// Returns the `borderColor` set by the window rules matching a window.
+///
+/// Overlapping overrides resolve to the first matching rule that sets one.
+#[must_use]
+pub fn rule_border_color(window: &Window) -> Option<String> {
+    find_rule_override(override_rules(&get_config().tiling), window, |rule| {
+        rule.border_color.clone()
+    })
+}
+
 /// Updates borders based on workspace layout.
 ///
 /// Called when focus changes. Determines the correct active color based on:
-/// - Monocle layout → monocle config (if enabled)
+/// - A `borderColor` from the focused window's rules → that color
+/// - Monocle or tabbed layout → monocle config (if enabled)
 /// - Floating layout → floating config (if enabled)
 /// - Otherwise → focused config
 ///
 /// Always sends unfocused color as `inactive_color`.
 /// All settings are batched into a single `JankyBorders` call.
-pub fn on_focus_changed(layout: LayoutType, is_window_floating: bool) {
+pub fn on_focus_changed(layout: LayoutType, is_window_floating: bool, rule_color: Option<&str>) {
     let config = get_config();
     let borders = &config.tiling.borders;
 
@@ -380,7 +393,7 @@ pub fn on_focus_changed(layout: LayoutType, is_window_floating: bool) {
     }
 
     // Determine which config to use for active color
-    let active_config = if layout == LayoutType::Monocle && borders.monocle.is_enabled() {
+    let active_config = if layout.is_stacking() && borders.monocle.is_enabled() {
         &borders.monocle
     } else if (layout == LayoutType::Floating || is_window_floating)
         && borders.floating.is_enabled()
@@ -390,8 +403,9 @@ pub fn on_focus_changed(layout: LayoutType, is_window_floating: bool) {
         &borders.focused
     };
 
-    // Get colors and width
+    // Get colors and width, with the window's own color taking precedence
     let (active_color, width) = get_border_settings(active_config);
+    let active_color = rule_color.and_then(hex_to_janky).unwrap_or(active_color);
     let (inactive_color, _) = get_border_settings(&borders.unfocused);
 
     // Build and send command
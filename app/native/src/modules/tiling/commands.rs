@@ -17,10 +17,13 @@ pub fn layout_to_string_pub(layout: LayoutType) -> String {
         LayoutType::Floating => "floating",
         LayoutType::Dwindle => "dwindle",
         LayoutType::Monocle => "monocle",
+        LayoutType::Tabbed => "tabbed",
         LayoutType::Master => "master",
         LayoutType::Split | LayoutType::SplitVertical => "split",
         LayoutType::SplitHorizontal => "split-horizontal",
         LayoutType::Grid => "grid",
+        LayoutType::Columns => "columns",
+        LayoutType::Scrolling => "scrolling",
     }
     .to_string()
 }
@@ -34,6 +37,7 @@ mod tests {
         assert_eq!(layout_to_string_pub(LayoutType::Floating), "floating");
         assert_eq!(layout_to_string_pub(LayoutType::Dwindle), "dwindle");
         assert_eq!(layout_to_string_pub(LayoutType::Monocle), "monocle");
+        assert_eq!(layout_to_string_pub(LayoutType::Tabbed), "tabbed");
         assert_eq!(layout_to_string_pub(LayoutType::Master), "master");
         assert_eq!(layout_to_string_pub(LayoutType::Split), "split");
         assert_eq!(layout_to_string_pub(LayoutType::SplitVertical), "split");
@@ -41,6 +45,8 @@ mod tests {
             layout_to_string_pub(LayoutType::SplitHorizontal),
             "split-horizontal"
         );
+        assert_eq!(layout_to_string_pub(LayoutType::Scrolling), "scrolling");
         assert_eq!(layout_to_string_pub(LayoutType::Grid), "grid");
+        assert_eq!(layout_to_string_pub(LayoutType::Columns), "columns");
     }
 }
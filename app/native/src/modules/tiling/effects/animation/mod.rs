@@ -23,6 +23,10 @@
 //! ];
 //! animator.animate(transitions);
 //! ```
+//!
+//! Duration and easing can be overridden per [`TransitionKind`] through
+//! `tiling.animations.transitions`; kinds without an override use the global
+//! settings.
 
 mod easing;
 mod spring;
@@ -30,6 +34,7 @@ mod state;
 mod sync;
 mod transition;
 
+use std::collections::HashMap;
 use std::ffi::c_void;
 use std::time::{Duration, Instant};
 
@@ -48,9 +53,9 @@ pub use sync::{
     ca_transaction_begin_disabled, ca_transaction_commit, init_display_link, precision_sleep,
     set_high_priority_thread, target_fps, wait_for_next_frame,
 };
-pub use transition::WindowTransition;
+pub use transition::{TransitionKind, WindowTransition};
 
-use crate::config::{EasingType, get_config};
+use crate::config::{AnimationOverride, EasingType, SpringConfig, get_config};
 use crate::modules::tiling::effects::window_cache::get_cache;
 use crate::modules::tiling::ffi::skylight::UpdateGuard;
 use crate::modules::tiling::layout::LAYOUT_INLINE_CAP;
@@ -88,6 +93,15 @@ const REFERENCE_DISTANCE: f64 = 500.0;
 // Animation Config
 // ============================================================================
 
+/// Duration and easing used for one kind of transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransitionTiming {
+    /// Base animation duration (for large movements).
+    pub duration: Duration,
+    /// Easing function type.
+    pub easing: EasingType,
+}
+
 /// Configuration for the animation system.
 #[derive(Debug, Clone)]
 pub struct AnimationConfig {
@@ -97,6 +111,11 @@ pub struct AnimationConfig {
     pub duration: Duration,
     /// Easing function type.
     pub easing: EasingType,
+    /// Resolved per-transition overrides. Kinds without an entry use
+    /// `duration` and `easing`.
+    pub overrides: HashMap<TransitionKind, TransitionTiming>,
+    /// Physics of the spring easing.
+    pub spring: SpringConfig,
 }
 
 impl Default for AnimationConfig {
@@ -105,6 +124,8 @@ impl Default for AnimationConfig {
             enabled: false,
             duration: Duration::from_millis(200),
             easing: EasingType::EaseOut,
+            overrides: HashMap::new(),
+            spring: SpringConfig::default(),
         }
     }
 }
@@ -113,42 +134,96 @@ impl AnimationConfig {
     /// Creates animation config from the application configuration.
     #[must_use]
     pub fn from_config() -> Self {
-        let config = get_config();
-        let anim_config = &config.tiling.animations;
+        let settings = &get_config().tiling.animations;
+        spring::validate(&settings.spring);
+        Self::from_settings(settings)
+    }
+
+    /// Creates animation config from the tiling animation settings.
+    #[must_use]
+    pub fn from_settings(settings: &crate::config::AnimationConfig) -> Self {
+        let duration = clamped_duration(settings.duration);
+        let easing = settings.easing;
+
+        let overrides = TransitionKind::ALL
+            .into_iter()
+            .filter_map(|kind| {
+                let entry = transition_override(settings, kind)?;
+                let timing = TransitionTiming {
+                    duration: entry.duration.map_or(duration, clamped_duration),
+                    easing: entry.easing.unwrap_or(easing),
+                };
+                Some((kind, timing))
+            })
+            .collect();
 
         Self {
-            enabled: anim_config.enabled,
-            duration: Duration::from_millis(u64::from(
-                anim_config.duration.clamp(MIN_DURATION_MS, MAX_DURATION_MS),
-            )),
-            easing: anim_config.easing,
+            enabled: settings.enabled,
+            duration,
+            easing,
+            overrides,
+            spring: settings.spring,
         }
     }
 
+    /// Returns the duration and easing for a kind of transition.
+    #[must_use]
+    pub fn timing(&self, kind: TransitionKind) -> TransitionTiming {
+        self.overrides.get(&kind).copied().unwrap_or(TransitionTiming {
+            duration: self.duration,
+            easing: self.easing,
+        })
+    }
+
     /// Calculates the animation duration based on travel distance.
     #[must_use]
     pub fn calculate_duration(&self, max_distance: f64) -> Duration {
-        const MIN_DISTANCE: f64 = 20.0;
-        let min_duration = Duration::from_millis(MIN_DYNAMIC_DURATION_MS);
+        scale_duration(self.duration, max_distance)
+    }
+}
 
-        if max_distance <= MIN_DISTANCE {
-            return min_duration;
-        }
+/// Returns the configured override for a kind of transition.
+const fn transition_override(
+    settings: &crate::config::AnimationConfig,
+    kind: TransitionKind,
+) -> Option<AnimationOverride> {
+    let transitions = &settings.transitions;
+    match kind {
+        TransitionKind::Open => transitions.open,
+        TransitionKind::Close => transitions.close,
+        TransitionKind::Move => transitions.r#move,
+        TransitionKind::Resize => transitions.resize,
+        TransitionKind::WorkspaceSwitch => transitions.workspace_switch,
+    }
+}
 
-        if max_distance >= REFERENCE_DISTANCE {
-            return self.duration;
-        }
+/// Converts a configured duration in milliseconds, clamped to the supported range.
+fn clamped_duration(duration_ms: u32) -> Duration {
+    Duration::from_millis(u64::from(duration_ms.clamp(MIN_DURATION_MS, MAX_DURATION_MS)))
+}
 
-        let normalized = (max_distance / REFERENCE_DISTANCE).sqrt();
-        #[allow(clippy::cast_precision_loss)]
-        let min_ms = MIN_DYNAMIC_DURATION_MS as f64;
-        #[allow(clippy::cast_precision_loss)]
-        let max_ms = self.duration.as_millis() as f64;
-        let duration_ms = (max_ms - min_ms).mul_add(normalized, min_ms);
+/// Scales a base duration down for short travel distances.
+fn scale_duration(duration: Duration, max_distance: f64) -> Duration {
+    const MIN_DISTANCE: f64 = 20.0;
+    let min_duration = Duration::from_millis(MIN_DYNAMIC_DURATION_MS);
 
-        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        Duration::from_millis(duration_ms as u64)
+    if max_distance <= MIN_DISTANCE {
+        return min_duration;
     }
+
+    if max_distance >= REFERENCE_DISTANCE {
+        return duration;
+    }
+
+    let normalized = (max_distance / REFERENCE_DISTANCE).sqrt();
+    #[allow(clippy::cast_precision_loss)]
+    let min_ms = MIN_DYNAMIC_DURATION_MS as f64;
+    #[allow(clippy::cast_precision_loss)]
+    let max_ms = duration.as_millis() as f64;
+    let duration_ms = (max_ms - min_ms).mul_add(normalized, min_ms);
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Duration::from_millis(duration_ms as u64)
 }
 
 // ============================================================================
@@ -196,13 +271,27 @@ impl AnimationSystem {
 
     /// Animates a list of window transitions.
     ///
-    /// If animations are disabled, windows are moved instantly.
+    /// If animations are disabled, windows are moved instantly. Uses the
+    /// `resize` settings if any window changes size, `move` otherwise.
     ///
     /// # Returns
     ///
     /// Number of windows that were successfully positioned.
     #[must_use]
     pub fn animate(&self, transitions: Vec<WindowTransition>) -> usize {
+        let kind = TransitionKind::from_transitions(&transitions);
+        self.animate_as(kind, transitions)
+    }
+
+    /// Animates a list of window transitions with the settings for `kind`.
+    ///
+    /// If animations are disabled, windows are moved instantly.
+    ///
+    /// # Returns
+    ///
+    /// Number of windows that were successfully positioned.
+    #[must_use]
+    pub fn animate_as(&self, kind: TransitionKind, transitions: Vec<WindowTransition>) -> usize {
         if transitions.is_empty() {
             return 0;
         }
@@ -224,7 +313,7 @@ impl AnimationSystem {
 
         // Animate remaining transitions
         if !animated.is_empty() {
-            success_count += self.run_animation(&animated);
+            success_count += self.run_animation(&animated, kind);
         }
 
         success_count
@@ -246,22 +335,29 @@ impl AnimationSystem {
     }
 
     /// Runs the animation loop for the given transitions.
-    fn run_animation(&self, transitions: &[WindowTransition]) -> usize {
+    fn run_animation(&self, transitions: &[WindowTransition], kind: TransitionKind) -> usize {
         let max_distance =
             transitions.iter().map(WindowTransition::max_distance).fold(0.0_f64, f64::max);
 
-        let duration = self.config.calculate_duration(max_distance);
+        let timing = self.config.timing(kind);
+        let duration = scale_duration(timing.duration, max_distance);
 
-        match self.config.easing {
+        match timing.easing {
             EasingType::Spring => self.run_spring_animation(transitions, duration),
-            _ => self.run_eased_animation(transitions, duration),
+            easing => self.run_eased_animation(transitions, duration, easing),
         }
     }
 
     /// Runs a time-based eased animation.
     ///
     /// Uses the window element cache for efficient batch resolution.
-    fn run_eased_animation(&self, transitions: &[WindowTransition], duration: Duration) -> usize {
+    #[allow(clippy::unused_self)] // Self kept for consistency and future config access
+    fn run_eased_animation(
+        &self,
+        transitions: &[WindowTransition],
+        duration: Duration,
+        easing: EasingType,
+    ) -> usize {
         set_animation_active(true);
         init_display_link();
         set_high_priority_thread();
@@ -269,7 +365,6 @@ impl AnimationSystem {
         let fps = target_fps();
         let frame_duration = Duration::from_secs(1) / fps;
         let start = Instant::now();
-        let easing = self.config.easing;
 
         // Collect window IDs for batch resolution
         let window_ids: SmallVec<[u32; LAYOUT_INLINE_CAP]> =
@@ -337,7 +432,6 @@ impl AnimationSystem {
     /// Runs a physics-based spring animation.
     ///
     /// Uses the window element cache for efficient batch resolution.
-    #[allow(clippy::unused_self)] // Self kept for consistency and future config access
     fn run_spring_animation(&self, transitions: &[WindowTransition], duration: Duration) -> usize {
         set_animation_active(true);
         init_display_link();
@@ -371,8 +465,10 @@ impl AnimationSystem {
             return 0;
         }
 
-        let mut spring_states: Vec<SpringState> =
-            transitions.iter().map(|_| SpringState::new(duration)).collect();
+        let mut spring_states: Vec<SpringState> = transitions
+            .iter()
+            .map(|_| SpringState::new(duration, &self.config.spring))
+            .collect();
 
         loop {
             // Check for cancellation
@@ -524,6 +620,8 @@ mod tests {
             enabled: true,
             duration: Duration::from_millis(200),
             easing: EasingType::EaseOut,
+            overrides: HashMap::new(),
+            spring: SpringConfig::default(),
         };
 
         // Small distance gets minimum duration
@@ -540,6 +638,63 @@ mod tests {
         assert!(mid_duration < config.duration);
     }
 
+    #[test]
+    fn test_animation_config_transition_overrides() {
+        let settings: crate::config::AnimationConfig = serde_json::from_str(
+            r#"{
+                "enabled": true,
+                "duration": 300,
+                "easing": "ease-in-out",
+                "transitions": {
+                    "workspaceSwitch": {"duration": 120, "easing": "ease-out-expo"},
+                    "open": {"easing": "spring"},
+                    "close": {"duration": 5000}
+                }
+            }"#,
+        )
+        .unwrap();
+        let config = AnimationConfig::from_settings(&settings);
+
+        assert_eq!(
+            config.timing(TransitionKind::WorkspaceSwitch),
+            TransitionTiming {
+                duration: Duration::from_millis(120),
+                easing: EasingType::EaseOutExpo,
+            }
+        );
+        // Missing fields fall back to the global settings
+        assert_eq!(config.timing(TransitionKind::Open), TransitionTiming {
+            duration: Duration::from_millis(300),
+            easing: EasingType::Spring,
+        });
+        // Durations are clamped like the global one
+        assert_eq!(
+            config.timing(TransitionKind::Close).duration,
+            Duration::from_millis(u64::from(MAX_DURATION_MS))
+        );
+        // Kinds without an entry use the global settings
+        assert_eq!(config.timing(TransitionKind::Move), TransitionTiming {
+            duration: Duration::from_millis(300),
+            easing: EasingType::EaseInOut,
+        });
+        assert!(!config.overrides.contains_key(&TransitionKind::Resize));
+    }
+
+    #[test]
+    fn test_animation_config_spring_settings() {
+        let settings: crate::config::AnimationConfig = serde_json::from_str(
+            r#"{"easing": "spring", "spring": {"stiffness": 300, "damping": 20}}"#,
+        )
+        .unwrap();
+        let config = AnimationConfig::from_settings(&settings);
+
+        assert_eq!(config.spring, SpringConfig {
+            stiffness: Some(300.0),
+            damping: Some(20.0),
+            mass: None,
+        });
+    }
+
     #[test]
     fn test_animation_system_new() {
         let system = AnimationSystem::new();
@@ -9,11 +9,19 @@
 //! ```
 //!
 //! Where:
-//! - ζ (zeta) = damping ratio (controls bounciness)
+//! - ζ (zeta) = damping ratio (controls bounciness) = c / (2√(km))
 //! - ω₀ = natural frequency = √(k/m)
+//!
+//! Stiffness (k), damping (c) and mass (m) can be set with
+//! `tiling.animations.spring`; they are clamped so the update loop never sees
+//! a spring that diverges or produces NaN, and ω₀ is raised when needed so
+//! the spring settles before animations are cut off at `MAX_DURATION_MS`.
 
 use std::time::Duration;
 
+use super::MAX_DURATION_MS;
+use crate::config::SpringConfig;
+
 // ============================================================================
 // Constants
 // ============================================================================
@@ -29,16 +37,30 @@ const SPRING_POSITION_THRESHOLD: f64 = 0.01;
 /// We use 0.85 for a more responsive feel while keeping overshoot imperceptible.
 const SPRING_DAMPING_RATIO: f64 = 0.85;
 
-/// Spring mass (fixed at 1.0).
-#[allow(dead_code)] // Kept for documentation/reference
+/// Default spring mass.
 const SPRING_MASS: f64 = 1.0;
 
+/// Allowed range of the configured stiffness.
+const STIFFNESS_RANGE: (f64, f64) = (1.0, 5000.0);
+
+/// Allowed range of the configured mass.
+const MASS_RANGE: (f64, f64) = (0.1, 10.0);
+
+/// Allowed range of the resulting damping ratio. Lower ratios oscillate for
+/// too long, higher ones barely move.
+const DAMPING_RATIO_RANGE: (f64, f64) = (0.1, 2.0);
+
 /// Settling time multiplier.
 ///
 /// For critically damped: ~6.6 (99% settled)
 /// For underdamped (ζ=0.85): ~5.5 is sufficient due to faster initial response
 const SPRING_SETTLE_FACTOR: f64 = 5.5;
 
+/// Number of time constants (1 / decay rate) a spring needs to settle.
+///
+/// After 4 time constants the remaining displacement is below 2%.
+const SETTLE_TIME_CONSTANTS: f64 = 4.0;
+
 // ============================================================================
 // Spring Physics
 // ============================================================================
@@ -54,12 +76,72 @@ impl SpringParams {
     /// Creates spring parameters from a target duration.
     #[must_use]
     pub fn from_duration(duration: Duration) -> Self {
-        let target_secs = duration.as_secs_f64().max(0.01);
-        let omega_0 = SPRING_SETTLE_FACTOR / target_secs;
+        Self::new(duration, &SpringConfig::default())
+    }
 
-        Self {
-            omega_0,
-            damping_ratio: SPRING_DAMPING_RATIO,
+    /// Creates spring parameters from a target duration and the configured
+    /// spring physics.
+    ///
+    /// Without a stiffness, the spring settles within the target duration;
+    /// with one, the stiffness sets the speed and the duration is ignored.
+    /// Without a damping, it is slightly underdamped. Values that are not
+    /// positive numbers are ignored, the rest are clamped, and ω₀ is raised
+    /// for springs too slow to settle within `MAX_DURATION_MS`.
+    #[must_use]
+    pub fn new(duration: Duration, config: &SpringConfig) -> Self {
+        let mass =
+            positive(config.mass).map_or(SPRING_MASS, |m| m.clamp(MASS_RANGE.0, MASS_RANGE.1));
+
+        let omega_0 = positive(config.stiffness).map_or_else(
+            || SPRING_SETTLE_FACTOR / duration.as_secs_f64().max(0.01),
+            |k| (k.clamp(STIFFNESS_RANGE.0, STIFFNESS_RANGE.1) / mass).sqrt(),
+        );
+
+        // ζ = c / (2√(km)), and √(km) = ω₀m
+        let damping_ratio = positive(config.damping)
+            .map_or(SPRING_DAMPING_RATIO, |c| c / (2.0 * omega_0 * mass))
+            .clamp(DAMPING_RATIO_RANGE.0, DAMPING_RATIO_RANGE.1);
+
+        // The decay rate scales with ω₀, so raise ω₀ until the spring settles in time
+        let min_decay_rate = SETTLE_TIME_CONSTANTS / (f64::from(MAX_DURATION_MS) / 1000.0);
+        let omega_0 = omega_0.max(min_decay_rate / decay_rate(1.0, damping_ratio));
+
+        Self { omega_0, damping_ratio }
+    }
+}
+
+/// Returns the rate (1/s) at which the spring's displacement decays.
+///
+/// Underdamped and critically damped springs decay at ζω₀. Overdamped springs
+/// are held back by their slow mode, which decays at ω₀(ζ - √(ζ² - 1)).
+fn decay_rate(omega_0: f64, damping_ratio: f64) -> f64 {
+    if damping_ratio <= 1.0 {
+        damping_ratio * omega_0
+    } else {
+        omega_0 * (damping_ratio - damping_ratio.mul_add(damping_ratio, -1.0).sqrt())
+    }
+}
+
+/// Returns the value if it is a positive, finite number.
+fn positive(value: Option<f64>) -> Option<f64> {
+    value.filter(|value| value.is_finite() && *value > 0.0)
+}
+
+/// Logs a warning for each configured spring value that will be ignored.
+pub fn validate(config: &SpringConfig) {
+    let values = [
+        ("stiffness", config.stiffness),
+        ("damping", config.damping),
+        ("mass", config.mass),
+    ];
+
+    for (name, value) in values {
+        if let Some(value) = value
+            && positive(Some(value)).is_none()
+        {
+            tracing::warn!(
+                "tiling: ignoring animations.spring.{name} = {value}, it must be a positive number"
+            );
         }
     }
 }
@@ -72,12 +154,12 @@ pub struct SpringState {
 }
 
 impl SpringState {
-    /// Creates a new spring state with the given target duration.
+    /// Creates a new spring state with the given target duration and physics.
     #[must_use]
-    pub fn new(target_duration: Duration) -> Self {
+    pub fn new(target_duration: Duration, config: &SpringConfig) -> Self {
         Self {
             elapsed: 0.0,
-            params: SpringParams::from_duration(target_duration),
+            params: SpringParams::new(target_duration, config),
         }
     }
 
@@ -88,7 +170,9 @@ impl SpringState {
         self.elapsed += dt;
         let position = self.calculate_position(self.elapsed);
 
-        let is_settled = (position - 1.0).abs() < SPRING_POSITION_THRESHOLD && self.elapsed > 0.02;
+        // A position that isn't a number can't be shown, so jump to the end
+        let is_settled = !position.is_finite()
+            || ((position - 1.0).abs() < SPRING_POSITION_THRESHOLD && self.elapsed > 0.02);
         let final_position = if is_settled { 1.0 } else { position };
 
         (final_position.clamp(0.0, 1.5), is_settled)
@@ -131,16 +215,21 @@ impl SpringState {
     }
 
     /// Calculates position for an overdamped spring (ζ > 1).
+    ///
+    /// The decay is folded into the hyperbolic terms, as `cosh` and `sinh`
+    /// overflow for long or stiff springs long before the decay reaches zero.
     #[inline]
     #[must_use]
     pub fn overdamped_position(t: f64, omega_0: f64, zeta: f64) -> f64 {
         let zeta_sq_minus_one = zeta.mul_add(zeta, -1.0);
         let gamma = omega_0 * zeta_sq_minus_one.sqrt();
-        let decay = (-zeta * omega_0 * t).exp();
-        let cosh_term = (gamma * t).cosh();
-        let sinh_term = (zeta / zeta_sq_minus_one.sqrt()) * (gamma * t).sinh();
+        let ratio = zeta / zeta_sq_minus_one.sqrt();
+
+        // e^(-ζω₀t)·cosh(γt) and e^(-ζω₀t)·sinh(γt) as sums of decaying exponentials
+        let slow = (zeta.mul_add(-omega_0, gamma) * t).exp();
+        let fast = (-zeta.mul_add(omega_0, gamma) * t).exp();
 
-        decay.mul_add(-(cosh_term + sinh_term), 1.0)
+        0.5f64.mul_add(-(1.0 + ratio).mul_add(slow, (1.0 - ratio) * fast), 1.0)
     }
 }
 
@@ -163,7 +252,7 @@ mod tests {
 
     #[test]
     fn test_spring_state_progression() {
-        let mut state = SpringState::new(Duration::from_millis(200));
+        let mut state = SpringState::new(Duration::from_millis(200), &SpringConfig::default());
 
         let (pos0, _) = state.update(0.0);
         assert!(pos0 >= 0.0);
@@ -177,7 +266,7 @@ mod tests {
 
     #[test]
     fn test_spring_state_settles() {
-        let mut state = SpringState::new(Duration::from_millis(100));
+        let mut state = SpringState::new(Duration::from_millis(100), &SpringConfig::default());
 
         for _ in 0..100 {
             let (_, settled) = state.update(0.01);
@@ -190,6 +279,100 @@ mod tests {
         panic!("Spring did not settle within 100 iterations");
     }
 
+    #[test]
+    fn test_spring_params_default_config_matches_duration() {
+        let duration = Duration::from_millis(200);
+        let params = SpringParams::new(duration, &SpringConfig::default());
+        let expected = SpringParams::from_duration(duration);
+        assert!((params.omega_0 - SPRING_SETTLE_FACTOR / 0.2).abs() < 1e-9);
+        assert!((params.omega_0 - expected.omega_0).abs() < f64::EPSILON);
+        assert!((params.damping_ratio - SPRING_DAMPING_RATIO).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_spring_params_from_config() {
+        let config = SpringConfig {
+            stiffness: Some(1600.0),
+            damping: Some(80.0),
+            mass: Some(4.0),
+        };
+        let params = SpringParams::new(Duration::from_millis(200), &config);
+        // ω₀ = √(1600 / 4), ζ = 80 / (2 · √(1600 · 4))
+        assert!((params.omega_0 - 20.0).abs() < 1e-9);
+        assert!((params.damping_ratio - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spring_params_settle_within_max_duration() {
+        let max_settle_time = f64::from(MAX_DURATION_MS) / 1000.0 + 1e-9;
+        let slow_springs = [
+            SpringConfig {
+                stiffness: Some(STIFFNESS_RANGE.0),
+                damping: None,
+                mass: Some(MASS_RANGE.1),
+            },
+            SpringConfig {
+                stiffness: Some(STIFFNESS_RANGE.0),
+                damping: Some(1e6),
+                mass: Some(MASS_RANGE.1),
+            },
+            SpringConfig {
+                stiffness: None,
+                damping: Some(1e-6),
+                mass: None,
+            },
+        ];
+
+        for config in slow_springs {
+            let params = SpringParams::new(Duration::from_millis(200), &config);
+            let settle_time =
+                SETTLE_TIME_CONSTANTS / decay_rate(params.omega_0, params.damping_ratio);
+            assert!(
+                settle_time <= max_settle_time,
+                "{config:?} settles in {settle_time}s"
+            );
+        }
+    }
+
+    #[test]
+    fn test_spring_params_clamps_and_ignores_invalid_values() {
+        let config = SpringConfig {
+            stiffness: Some(1e9),
+            damping: Some(0.0),
+            mass: Some(f64::NAN),
+        };
+        let params = SpringParams::new(Duration::from_millis(200), &config);
+        assert!((params.omega_0 - STIFFNESS_RANGE.1.sqrt()).abs() < 1e-9);
+        assert!((params.damping_ratio - SPRING_DAMPING_RATIO).abs() < f64::EPSILON);
+
+        let bouncy = SpringConfig {
+            damping: Some(1e-6),
+            ..Default::default()
+        };
+        let params = SpringParams::new(Duration::from_millis(200), &bouncy);
+        assert!((params.damping_ratio - DAMPING_RATIO_RANGE.0).abs() < f64::EPSILON);
+
+        let stiff = SpringConfig {
+            damping: Some(1e6),
+            ..Default::default()
+        };
+        let params = SpringParams::new(Duration::from_millis(200), &stiff);
+        assert!((params.damping_ratio - DAMPING_RATIO_RANGE.1).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_overdamped_position_stays_finite() {
+        let omega_0 = STIFFNESS_RANGE.1.sqrt() / MASS_RANGE.0.sqrt();
+        let zeta = DAMPING_RATIO_RANGE.1;
+
+        assert!(SpringState::overdamped_position(0.0, omega_0, zeta).abs() < 1e-9);
+        for t in [0.01, 0.1, 1.0, 10.0, 1000.0] {
+            let position = SpringState::overdamped_position(t, omega_0, zeta);
+            assert!(position.is_finite() && (0.0..=1.0).contains(&position));
+        }
+        assert!((SpringState::overdamped_position(1000.0, omega_0, zeta) - 1.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_critically_damped_position() {
         let position_0 = SpringState::critically_damped_position(0.0, 33.0);
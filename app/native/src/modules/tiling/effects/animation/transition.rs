@@ -7,6 +7,44 @@ use crate::modules::tiling::state::Rect;
 // Types
 // ============================================================================
 
+/// The kind of change that moves windows, used to pick animation settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionKind {
+    /// Windows making room for a newly opened window.
+    Open,
+    /// Windows filling the space left by a closed window.
+    Close,
+    /// Windows moving without changing size.
+    Move,
+    /// Windows changing size.
+    Resize,
+    /// Windows settling into place after switching workspaces.
+    WorkspaceSwitch,
+}
+
+impl TransitionKind {
+    /// All transition kinds.
+    pub const ALL: [Self; 5] = [
+        Self::Open,
+        Self::Close,
+        Self::Move,
+        Self::Resize,
+        Self::WorkspaceSwitch,
+    ];
+
+    /// Infers the kind of a batch of transitions from their frames.
+    ///
+    /// Returns `Resize` if any window changes size, `Move` otherwise.
+    #[must_use]
+    pub fn from_transitions(transitions: &[WindowTransition]) -> Self {
+        if transitions.iter().any(WindowTransition::involves_resize) {
+            Self::Resize
+        } else {
+            Self::Move
+        }
+    }
+}
+
 /// A window transition from one frame to another.
 #[derive(Debug, Clone)]
 pub struct WindowTransition {
@@ -116,6 +154,29 @@ mod tests {
         assert!((at_half.height - 200.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_transition_kind_from_transitions() {
+        let moved = WindowTransition::new(
+            1,
+            Rect::new(0.0, 0.0, 100.0, 100.0),
+            Rect::new(50.0, 50.0, 100.0, 100.0),
+        );
+        let resized = WindowTransition::new(
+            2,
+            Rect::new(0.0, 0.0, 100.0, 100.0),
+            Rect::new(0.0, 0.0, 200.0, 100.0),
+        );
+
+        assert_eq!(
+            TransitionKind::from_transitions(&[moved.clone()]),
+            TransitionKind::Move
+        );
+        assert_eq!(
+            TransitionKind::from_transitions(&[moved, resized]),
+            TransitionKind::Resize
+        );
+    }
+
     #[test]
     fn test_window_transition_involves_resize() {
         let position_only = WindowTransition::new(
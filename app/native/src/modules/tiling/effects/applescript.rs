@@ -0,0 +1,409 @@
+//! `osascript` fallback for windows the accessibility API can't reach.
+//!
+//! Some apps (certain Electron and Java apps) don't report window frames
+//! through the accessibility API, or ignore frames set through it. Windows
+//! matching a rule with `useApplescriptFrames` read and set their frame
+//! through System Events instead, while `tiling.applescriptFallback` is
+//! enabled.
+//!
+//! The fallback only covers reading and setting frames. The window must still
+//! be enumerated through the accessibility API with a window ID, so windows
+//! the API doesn't list at all can't be tracked.
+//!
+//! # Performance
+//!
+//! Every call spawns `osascript`, which takes tens of milliseconds, so the
+//! fallback is only tried after the accessibility API fails and these windows
+//! are never animated. Frames are set by a single worker thread, which only
+//! keeps the latest pending frame per window, so callers never block on
+//! `osascript` and stale frames can't land after newer ones. Callers that
+//! can't wait for a read, like the mouse event tap, use
+//! [`last_window_frame`] instead.
+//!
+//! # Permissions
+//!
+//! Scripting System Events requires the Automation permission (System
+//! Settings > Privacy & Security > Automation). macOS asks for it on first
+//! use. While it is denied, calls fail with [`AppleScriptError::NotPermitted`]
+//! and a single warning is logged.
+
+use std::collections::HashMap;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, OnceLock, PoisonError};
+
+use dashmap::DashMap;
+
+use crate::config::get_config;
+use crate::modules::tiling::rules::uses_applescript_frames;
+use crate::modules::tiling::state::{Rect, Window};
+use crate::utils::thread::spawn_named_thread;
+
+/// Path to the `osascript` binary.
+const OSASCRIPT: &str = "/usr/bin/osascript";
+
+/// Error numbers macOS reports when Stache may not script System Events:
+/// Apple events not authorized (Automation) and no assistive access.
+const NOT_PERMITTED_ERRORS: [&str; 2] = ["-1743", "-25211"];
+
+/// Errors from running a System Events script.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AppleScriptError {
+    /// Stache isn't allowed to control System Events.
+    #[error(
+        "not permitted to control System Events; allow Stache in System Settings > \
+         Privacy & Security > Automation"
+    )]
+    NotPermitted,
+    /// The script failed, e.g. because the window no longer exists.
+    #[error("osascript failed: {0}")]
+    Failed(String),
+}
+
+/// How System Events finds a window: by its process and title.
+#[derive(Debug, Clone, PartialEq)]
+struct ScriptTarget {
+    pid: i32,
+    title: String,
+    /// The last frame read or set for the window.
+    frame: Rect,
+}
+
+/// Windows using the fallback, by window ID.
+static TARGETS: OnceLock<DashMap<u32, ScriptTarget>> = OnceLock::new();
+
+/// Frames waiting to be set by the worker thread.
+#[derive(Default)]
+struct FrameQueue {
+    /// The latest pending frame, by window ID.
+    pending: Mutex<HashMap<u32, Rect>>,
+    /// Wakes the worker when a frame is queued.
+    queued: Condvar,
+}
+
+/// Whether the missing permission was already reported.
+static PERMISSION_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Pending frames, created with the worker thread on first use.
+static FRAME_QUEUE: OnceLock<FrameQueue> = OnceLock::new();
+
+fn targets() -> &'static DashMap<u32, ScriptTarget> { TARGETS.get_or_init(DashMap::new) }
+
+fn target(window_id: u32) -> Option<ScriptTarget> {
+    targets().get(&window_id).map(|target| target.clone())
+}
+
+// ============================================================================
+// Registry
+// ============================================================================
+
+/// Starts using the fallback for a tracked window if one of its rules opts in.
+pub fn register(window: &Window) {
+    if uses_applescript_frames(&get_config().tiling, window) {
+        tracing::debug!("tiling: window {} uses AppleScript frames", window.id);
+        targets().insert(window.id, ScriptTarget {
+            pid: window.pid,
+            title: window.title.clone(),
+            frame: window.frame,
+        });
+    }
+}
+
+/// Stops using the fallback for a window.
+pub fn unregister(window_id: u32) { targets().remove(&window_id); }
+
+/// Updates the title used to find a window.
+pub fn update_title(window_id: u32, title: &str) {
+    if let Some(mut target) = targets().get_mut(&window_id) {
+        target.title = title.to_string();
+    }
+}
+
+/// Returns whether a window uses the fallback.
+#[must_use]
+pub fn is_registered(window_id: u32) -> bool { targets().contains_key(&window_id) }
+
+// ============================================================================
+// Frames
+// ============================================================================
+
+/// Gets the frame of a registered window through System Events.
+///
+/// Returns `None` for windows that don't use the fallback.
+#[must_use]
+pub fn get_window_frame(window_id: u32) -> Option<Rect> {
+    let target = target(window_id)?;
+    let frame = query_frame(target.pid, &target.title)
+        .map_err(|error| report(window_id, &error))
+        .ok()?;
+    remember_frame(window_id, frame);
+    Some(frame)
+}
+
+/// Returns the last frame read or set for a registered window, without
+/// running `osascript`.
+///
+/// Returns `None` for windows that don't use the fallback.
+#[must_use]
+pub fn last_window_frame(window_id: u32) -> Option<Rect> {
+    targets().get(&window_id).map(|target| target.frame)
+}
+
+/// Stores the latest known frame of a registered window.
+fn remember_frame(window_id: u32, frame: Rect) {
+    if let Some(mut target) = targets().get_mut(&window_id) {
+        target.frame = frame;
+    }
+}
+
+/// Queues a frame for a registered window, to be set through System Events.
+///
+/// Replaces any frame still pending for the window. Returns `false` for
+/// windows that don't use the fallback; `true` only means the frame was
+/// queued, as failures of the script itself are logged by the worker.
+#[must_use]
+pub fn set_window_frame(window_id: u32, frame: &Rect) -> bool {
+    if !is_registered(window_id) {
+        return false;
+    }
+    remember_frame(window_id, *frame);
+    let queue = frame_queue();
+    queue
+        .pending
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(window_id, *frame);
+    queue.queued.notify_one();
+    true
+}
+
+/// Gets the frame queue, starting the worker thread on first use.
+fn frame_queue() -> &'static FrameQueue {
+    FRAME_QUEUE.get_or_init(|| {
+        spawn_named_thread("tiling-applescript-frames", run_frame_worker);
+        FrameQueue::default()
+    })
+}
+
+/// Sets queued frames one at a time, for as long as the app runs.
+fn run_frame_worker() {
+    let queue = frame_queue();
+    loop {
+        let frames = {
+            let mut pending = queue.pending.lock().unwrap_or_else(PoisonError::into_inner);
+            while pending.is_empty() {
+                pending = queue.queued.wait(pending).unwrap_or_else(PoisonError::into_inner);
+            }
+            std::mem::take(&mut *pending)
+        };
+
+        for (window_id, frame) in frames {
+            apply_frame(window_id, &frame);
+        }
+    }
+}
+
+/// Sets the frame of a registered window, blocking until `osascript` exits.
+fn apply_frame(window_id: u32, frame: &Rect) {
+    // The window may have been unregistered while its frame was pending
+    let Some(target) = target(window_id) else {
+        return;
+    };
+    if let Err(error) = run(&frame_update_script(target.pid, &target.title, frame)) {
+        report(window_id, &error);
+    }
+}
+
+/// Gets the frame of a window that isn't tracked yet and whose accessibility
+/// frame is missing.
+///
+/// Returns `None` unless the fallback is enabled and one of the window's rules
+/// opts in.
+#[must_use]
+pub fn discover_frame(window: &Window) -> Option<Rect> {
+    if !uses_applescript_frames(&get_config().tiling, window) {
+        return None;
+    }
+    query_frame(window.pid, &window.title)
+        .map_err(|error| report(window.id, &error))
+        .ok()
+}
+
+/// Reads a window frame through System Events.
+fn query_frame(pid: i32, title: &str) -> Result<Rect, AppleScriptError> {
+    let output = run(&frame_query_script(pid, title))?;
+    parse_frame(&output).ok_or_else(|| AppleScriptError::Failed(format!("bad frame '{output}'")))
+}
+
+/// Logs a failed call. The missing permission is only reported once.
+fn report(window_id: u32, error: &AppleScriptError) {
+    match error {
+        AppleScriptError::NotPermitted => {
+            if !PERMISSION_WARNED.swap(true, Ordering::Relaxed) {
+                tracing::warn!("tiling: AppleScript frames unavailable: {error}");
+            }
+        }
+        AppleScriptError::Failed(_) => {
+            tracing::debug!("tiling: AppleScript frame for window {window_id} failed: {error}");
+        }
+    }
+}
+
+// ============================================================================
+// Scripts
+// ============================================================================
+
+/// Quotes a string as an AppleScript string literal.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns the script lines that open a `tell` block for a window.
+///
+/// Windows without a title fall back to the process's front window.
+fn tell_window(pid: i32, title: &str) -> String {
+    let window = if title.is_empty() {
+        "window 1".to_string()
+    } else {
+        format!("first window whose name is {}", quote(title))
+    };
+    format!(
+        "tell application \"System Events\"\n\
+         tell (first process whose unix id is {pid})\n\
+         tell ({window})\n"
+    )
+}
+
+/// Builds the script that prints a window's frame as `x, y, width, height`.
+fn frame_query_script(pid: i32, title: &str) -> String {
+    format!(
+        "{}get position & size\nend tell\nend tell\nend tell",
+        tell_window(pid, title)
+    )
+}
+
+/// Builds the script that sets a window's frame.
+///
+/// Sets the size, then the position, then the size again, like the
+/// accessibility path, so windows can shrink before moving.
+#[allow(clippy::cast_possible_truncation)]
+fn frame_update_script(pid: i32, title: &str, frame: &Rect) -> String {
+    let (x, y) = (frame.x.round() as i64, frame.y.round() as i64);
+    let (width, height) = (frame.width.round() as i64, frame.height.round() as i64);
+    format!(
+        "{}set size to {{{width}, {height}}}\n\
+         set position to {{{x}, {y}}}\n\
+         set size to {{{width}, {height}}}\n\
+         end tell\nend tell\nend tell",
+        tell_window(pid, title)
+    )
+}
+
+/// Parses `x, y, width, height` as printed by `osascript`.
+fn parse_frame(output: &str) -> Option<Rect> {
+    let values: Vec<f64> = output
+        .split(',')
+        .map(|value| value.trim().parse().ok())
+        .collect::<Option<_>>()?;
+    match values[..] {
+        [x, y, width, height] => Some(Rect::new(x, y, width, height)),
+        _ => None,
+    }
+}
+
+/// Maps `osascript` error output to an [`AppleScriptError`].
+fn classify_error(stderr: &str) -> AppleScriptError {
+    if NOT_PERMITTED_ERRORS.iter().any(|code| stderr.contains(code)) {
+        AppleScriptError::NotPermitted
+    } else {
+        AppleScriptError::Failed(stderr.trim().to_string())
+    }
+}
+
+/// Runs a script and returns its trimmed output.
+fn run(script: &str) -> Result<String, AppleScriptError> {
+    let output = Command::new(OSASCRIPT)
+        .arg("-e")
+        .arg(script)
+        .output()
+        .map_err(|error| AppleScriptError::Failed(error.to_string()))?;
+
+    if !output.status.success() {
+        return Err(classify_error(&String::from_utf8_lossy(&output.stderr)));
+    }
+
+    // Allow a warning again if the permission is revoked later
+    PERMISSION_WARNED.store(false, Ordering::Relaxed);
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_escapes_quotes_and_backslashes() {
+        assert_eq!(quote("Main"), r#""Main""#);
+        assert_eq!(quote(r#"say "hi" \o/"#), r#""say \"hi\" \\o/""#);
+    }
+
+    #[test]
+    fn test_frame_query_script() {
+        let script = frame_query_script(42, "Main \"Window\"");
+        assert!(script.contains("tell (first process whose unix id is 42)"));
+        assert!(script.contains(r#"tell (first window whose name is "Main \"Window\"")"#));
+        assert!(script.contains("get position & size"));
+
+        assert!(frame_query_script(42, "").contains("tell (window 1)"));
+    }
+
+    #[test]
+    fn test_frame_update_script_rounds_frame() {
+        let script = frame_update_script(7, "Main", &Rect::new(10.4, 20.6, 800.0, 600.5));
+        assert!(script.contains("set position to {10, 21}"));
+        assert_eq!(script.matches("set size to {800, 601}").count(), 2);
+        assert!(script.ends_with("end tell\nend tell\nend tell"));
+    }
+
+    #[test]
+    fn test_parse_frame() {
+        assert_eq!(
+            parse_frame("10, 20, 800, 600"),
+            Some(Rect::new(10.0, 20.0, 800.0, 600.0))
+        );
+        assert_eq!(
+            parse_frame("-1440, 25, 1440, 875"),
+            Some(Rect::new(-1440.0, 25.0, 1440.0, 875.0))
+        );
+        assert_eq!(parse_frame("10, 20, 800"), None);
+        assert_eq!(parse_frame("missing value"), None);
+        assert_eq!(parse_frame(""), None);
+    }
+
+    #[test]
+    fn test_classify_error() {
+        let denied =
+            "execution error: Not authorized to send Apple events to System Events. (-1743)";
+        assert_eq!(classify_error(denied), AppleScriptError::NotPermitted);
+
+        let assistive =
+            "System Events got an error: osascript is not allowed assistive access. (-25211)";
+        assert_eq!(classify_error(assistive), AppleScriptError::NotPermitted);
+
+        let missing = "System Events got an error: Can’t get window 1 of process 1. (-1719)\n";
+        assert_eq!(
+            classify_error(missing),
+            AppleScriptError::Failed(
+                "System Events got an error: Can’t get window 1 of process 1. (-1719)".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_unregistered_windows_skip_the_fallback() {
+        assert!(!is_registered(u32::MAX));
+        assert!(get_window_frame(u32::MAX).is_none());
+        assert!(last_window_frame(u32::MAX).is_none());
+        assert!(!set_window_frame(u32::MAX, &Rect::new(0.0, 0.0, 100.0, 100.0)));
+    }
+}
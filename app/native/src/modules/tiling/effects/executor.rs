@@ -8,26 +8,36 @@
 //! The executor receives effects from subscribers and:
 //! 1. Groups effects by type (frame updates, border updates, events)
 //! 2. Applies frame updates (potentially animated)
-//! 3. Batches border updates to `JankyBorders`
-//! 4. Emits frontend events via Tauri
+//! 3. Batches opacity changes into a single window server transaction
+//! 4. Batches border updates to `JankyBorders`
+//! 5. Emits frontend events via Tauri
 //!
 //! # Thread Safety
 //!
 //! The executor can be called from any async context. Window operations
 //! are thread-safe for different windows.
 
+use core_graphics::display::CGDisplay;
 use tauri::Emitter;
 
 use super::{
-    AnimationSystem, BorderState, TilingEffect, WindowTransition, get_interrupted_position,
-    window_cache, window_ops,
+    AnimationSystem, BorderState, TilingEffect, TransitionKind, WindowTransition, applescript,
+    get_interrupted_position, window_cache, window_ops,
 };
-use crate::modules::tiling::state::Rect;
+use crate::config::{CloseTo, OpenFrom, get_config};
+use crate::modules::tiling::events::mouse_monitor;
+use crate::modules::tiling::ffi::Transaction;
+use crate::modules::tiling::state::{Rect, Window};
 
 // ============================================================================
 // Effect Executor
 // ============================================================================
 
+/// Size of the frame a window grows from when opened from the screen center
+/// or the mouse pointer, relative to its layout frame. Windows shrink to the
+/// same size when they animate out.
+const OPEN_ORIGIN_SCALE: f64 = 0.2;
+
 /// Executes tiling effects on the system.
 ///
 /// The executor batches effects by type for efficient execution:
@@ -44,6 +54,12 @@ pub struct EffectExecutor {
 
     /// Animation system for smooth window transitions.
     animation_system: AnimationSystem,
+
+    /// Where newly opened windows animate from.
+    open_from: OpenFrom,
+
+    /// Where minimized windows animate out to.
+    close_to: CloseTo,
 }
 
 impl Default for EffectExecutor {
@@ -60,6 +76,8 @@ impl EffectExecutor {
             app_handle: None,
             borders_enabled: false,
             animation_system: AnimationSystem::from_config(),
+            open_from: get_config().tiling.animations.open_from,
+            close_to: get_config().tiling.animations.close_to,
         }
     }
 
@@ -70,6 +88,8 @@ impl EffectExecutor {
             app_handle: Some(app_handle),
             borders_enabled: false,
             animation_system: AnimationSystem::from_config(),
+            open_from: get_config().tiling.animations.open_from,
+            close_to: get_config().tiling.animations.close_to,
         }
     }
 
@@ -88,32 +108,69 @@ impl EffectExecutor {
     /// Number of effects successfully executed.
     #[must_use]
     pub fn execute_batch(&self, effects: Vec<TilingEffect>) -> usize {
+        self.execute_batch_as(None, effects)
+    }
+
+    /// Executes a batch of effects, animating frame updates as `transition`.
+    ///
+    /// With `None`, the animation settings are picked from the frames
+    /// (`resize` if any window changes size, `move` otherwise).
+    ///
+    /// # Returns
+    ///
+    /// Number of effects successfully executed.
+    #[must_use]
+    pub fn execute_batch_as(
+        &self,
+        transition: Option<TransitionKind>,
+        effects: Vec<TilingEffect>,
+    ) -> usize {
         if effects.is_empty() {
             return 0;
         }
 
         // Group effects by type
         let mut frame_updates: Vec<(u32, Rect, bool)> = Vec::new();
+        let mut open_origins: Vec<(u32, Rect)> = Vec::new();
         let mut border_updates: Vec<(u32, BorderState)> = Vec::new();
         let mut events: Vec<(String, serde_json::Value)> = Vec::new();
         let mut focus_ops: Vec<u32> = Vec::new();
         let mut raise_ops: Vec<u32> = Vec::new();
         let mut visibility_ops: Vec<(u32, bool)> = Vec::new();
+        let mut opacity_ops: Vec<(u32, f64)> = Vec::new();
+        let mut front_ops: Vec<u32> = Vec::new();
+        let mut minimize_ops: Vec<u32> = Vec::new();
 
         for effect in effects {
             match effect {
                 TilingEffect::SetWindowFrame { window_id, frame, animate } => {
                     frame_updates.push((window_id, frame, animate));
                 }
+                TilingEffect::OpenWindow { window_id, frame } => {
+                    let origin = self.open_origin(window_id, &frame);
+                    if let Some(origin) = origin {
+                        open_origins.push((window_id, origin));
+                    }
+                    frame_updates.push((window_id, frame, origin.is_some()));
+                }
+                TilingEffect::MinimizeWindow { window_id } => {
+                    minimize_ops.push(window_id);
+                }
                 TilingEffect::SetWindowVisible { window_id, visible } => {
                     visibility_ops.push((window_id, visible));
                 }
+                TilingEffect::SetWindowOpacity { window_id, opacity } => {
+                    opacity_ops.push((window_id, opacity));
+                }
                 TilingEffect::FocusWindow { window_id } => {
                     focus_ops.push(window_id);
                 }
                 TilingEffect::RaiseWindow { window_id } => {
                     raise_ops.push(window_id);
                 }
+                TilingEffect::BringToFront { window_id } => {
+                    front_ops.push(window_id);
+                }
                 TilingEffect::UpdateBorder { window_id, state } => {
                     border_updates.push((window_id, state));
                 }
@@ -135,8 +192,11 @@ impl EffectExecutor {
 
         let mut success_count = 0;
 
+        // Minimize first, so the windows are gone before the others fill their space
+        success_count += self.execute_minimize_ops(&minimize_ops);
+
         // Execute frame updates
-        success_count += self.execute_frame_updates(&frame_updates);
+        success_count += self.execute_frame_updates(&frame_updates, &open_origins, transition);
 
         // Execute focus operations
         success_count += self.execute_focus_ops(&focus_ops);
@@ -147,6 +207,12 @@ impl EffectExecutor {
         // Execute visibility operations
         success_count += self.execute_visibility_ops(&visibility_ops);
 
+        // Execute opacity operations
+        success_count += self.execute_opacity_ops(&opacity_ops);
+
+        // Bring windows to the front last, so focus and raise can't cover them
+        success_count += self.execute_front_ops(&front_ops);
+
         // Execute border updates (if enabled)
         if self.borders_enabled {
             success_count += self.execute_border_updates(&border_updates);
@@ -158,31 +224,92 @@ impl EffectExecutor {
         success_count
     }
 
+    /// Returns the frame a newly opened window animates from.
+    ///
+    /// `None` when the window should just appear: animations are disabled,
+    /// `openFrom` is `none`, or the origin can't be read.
+    fn open_origin(&self, window_id: u32, frame: &Rect) -> Option<Rect> {
+        if !self.animation_system.is_enabled() {
+            return None;
+        }
+
+        match self.open_from {
+            OpenFrom::None => None,
+            OpenFrom::Spawn => window_cache::get_cache().get_window_frame_fast(window_id),
+            OpenFrom::Center => {
+                screen_center(frame).map(|(x, y)| frame_around(x, y, frame, OPEN_ORIGIN_SCALE))
+            }
+            OpenFrom::Cursor => mouse_monitor::cursor_position()
+                .map(|(x, y)| frame_around(x, y, frame, OPEN_ORIGIN_SCALE)),
+        }
+    }
+
+    /// Minimizes windows, animating them out to the `closeTo` target first.
+    ///
+    /// Windows using the `osascript` fallback are too slow to animate and are
+    /// minimized in place.
+    fn execute_minimize_ops(&self, window_ids: &[u32]) -> usize {
+        if window_ids.is_empty() {
+            return 0;
+        }
+
+        if self.animation_system.is_enabled() {
+            let cache = window_cache::get_cache();
+            let transitions: Vec<WindowTransition> = window_ids
+                .iter()
+                .filter(|window_id| !applescript::is_registered(**window_id))
+                .filter_map(|window_id| {
+                    let from_frame = cache.get_window_frame_fast(*window_id)?;
+                    let to_frame = close_target(self.close_to, &from_frame)?;
+                    Some(WindowTransition::new(*window_id, from_frame, to_frame))
+                })
+                .collect();
+
+            if !transitions.is_empty() {
+                let _ = self.animation_system.animate_as(TransitionKind::Close, transitions);
+            }
+        }
+
+        window_ids
+            .iter()
+            .filter(|window_id| window_ops::set_window_minimized(**window_id, true))
+            .count()
+    }
+
     /// Executes frame update effects.
     ///
     /// Uses the window element cache for efficient batch resolution,
-    /// avoiding repeated O(n*m) lookups during animation setup.
-    fn execute_frame_updates(&self, updates: &[(u32, Rect, bool)]) -> usize {
+    /// avoiding repeated O(n*m) lookups during animation setup. Windows in
+    /// `open_origins` animate from the given frame instead of their current one.
+    fn execute_frame_updates(
+        &self,
+        updates: &[(u32, Rect, bool)],
+        open_origins: &[(u32, Rect)],
+        transition: Option<TransitionKind>,
+    ) -> usize {
         if updates.is_empty() {
             return 0;
         }
 
         let mut success_count = 0;
 
-        // Separate animated and immediate updates
+        // Separate animated and immediate updates. Windows using the
+        // `osascript` fallback are too slow to animate
         let (animated, immediate): (Vec<_>, Vec<_>) =
-            updates.iter().partition(|(_, _, animate)| *animate);
+            updates.iter().partition(|(window_id, _, animate)| {
+                *animate && !applescript::is_registered(*window_id)
+            });
 
-        // Execute immediate updates first using the cache
+        // Execute immediate updates first, all in one pass so large layouts
+        // don't visibly move one window at a time
         if !immediate.is_empty() {
-            let cache = window_cache::get_cache();
-            for (window_id, frame, _) in &immediate {
-                if cache.set_window_frame_fast(*window_id, frame) {
-                    success_count += 1;
-                } else {
-                    tracing::warn!("Failed to set frame for window {window_id}");
-                }
+            let frames: Vec<(u32, Rect)> =
+                immediate.iter().map(|(window_id, frame, _)| (*window_id, *frame)).collect();
+            let failed = window_cache::get_cache().set_window_frames_fast(&frames);
+            for window_id in &failed {
+                tracing::warn!("Failed to set frame for window {window_id}");
             }
+            success_count += frames.len() - failed.len();
         }
 
         // Execute animated updates using the animation system
@@ -196,15 +323,23 @@ impl EffectExecutor {
             let transitions: Vec<WindowTransition> = animated
                 .iter()
                 .filter_map(|(window_id, target_frame, _)| {
-                    // Check for interrupted position first, then fall back to cached frame
-                    let from_frame = get_interrupted_position(*window_id)
+                    // Opened windows start at their origin. Otherwise check for an
+                    // interrupted position first, then fall back to cached frame
+                    let from_frame = open_origins
+                        .iter()
+                        .find(|(id, _)| id == window_id)
+                        .map(|(_, origin)| *origin)
+                        .or_else(|| get_interrupted_position(*window_id))
                         .or_else(|| cache.get_window_frame_fast(*window_id))?;
                     Some(WindowTransition::new(*window_id, from_frame, *target_frame))
                 })
                 .collect();
 
             if !transitions.is_empty() {
-                success_count += self.animation_system.animate(transitions);
+                success_count += match transition {
+                    Some(kind) => self.animation_system.animate_as(kind, transitions),
+                    None => self.animation_system.animate(transitions),
+                };
             }
         }
 
@@ -251,6 +386,68 @@ impl EffectExecutor {
         0
     }
 
+    /// Executes opacity operations in a single window server transaction.
+    #[allow(clippy::unused_self)] // Self kept for consistency and future extensibility
+    fn execute_opacity_ops(&self, ops: &[(u32, f64)]) -> usize {
+        if ops.is_empty() {
+            return 0;
+        }
+
+        let mut transaction = match Transaction::new() {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                tracing::warn!("Failed to create transaction for opacity changes: {e}");
+                return 0;
+            }
+        };
+
+        let mut success_count = 0;
+        for (window_id, opacity) in ops {
+            match transaction.set_window_alpha(*window_id, *opacity) {
+                Ok(()) => success_count += 1,
+                Err(e) => tracing::warn!("Failed to set opacity for window {window_id}: {e}"),
+            }
+        }
+
+        if let Err(e) = transaction.commit_async() {
+            tracing::warn!("Failed to commit opacity changes: {e}");
+            return 0;
+        }
+
+        success_count
+    }
+
+    /// Brings windows to the front in a single window server transaction.
+    #[allow(clippy::unused_self)] // Self kept for consistency and future extensibility
+    fn execute_front_ops(&self, window_ids: &[u32]) -> usize {
+        if window_ids.is_empty() {
+            return 0;
+        }
+
+        let mut transaction = match Transaction::new() {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                tracing::warn!("Failed to create transaction for window ordering: {e}");
+                return 0;
+            }
+        };
+
+        let mut success_count = 0;
+        for window_id in window_ids {
+            match transaction.bring_to_front(*window_id) {
+                Ok(()) => success_count += 1,
+                Err(e) => tracing::warn!("Failed to bring window {window_id} to front: {e}"),
+            }
+        }
+
+        if let Err(e) = transaction.commit_async() {
+            tracing::warn!("Failed to commit window ordering: {e}");
+            return 0;
+        }
+
+        success_count
+    }
+
     /// Executes border updates.
     ///
     /// Note: Borders are now handled directly in the subscriber via
@@ -330,15 +527,24 @@ pub fn effects_from_layout_change(change: &super::LayoutChange) -> Vec<TilingEff
         if needs_update {
             // Determine if we should animate:
             // - Animate existing windows (those in old_positions) that are moving
-            // - Don't animate new windows (not in old_positions) - they just appear
-            // This means when a window is created/destroyed, existing windows
-            // animate to their new positions while the new window appears instantly.
-            let animate = old_positions.contains_key(window_id);
-
-            effects.push(TilingEffect::SetWindowFrame {
-                window_id: *window_id,
-                frame: *new_frame,
-                animate,
+            // - Windows that were just created are opened, which animates
+            //   them from the configured origin or makes them just appear
+            // - Other new windows (not in old_positions) just appear
+            // - Animate every window on a layout switch, so they move in sync
+            //   from where they are to the new layout
+            let animate = change.layout_switched || old_positions.contains_key(window_id);
+
+            effects.push(if !animate && change.opened_windows.contains(window_id) {
+                TilingEffect::OpenWindow {
+                    window_id: *window_id,
+                    frame: *new_frame,
+                }
+            } else {
+                TilingEffect::SetWindowFrame {
+                    window_id: *window_id,
+                    frame: *new_frame,
+                    animate,
+                }
             });
         }
     }
@@ -391,6 +597,102 @@ pub fn effects_from_focus_change(
     effects
 }
 
+/// Computes opacity effects from a focus change.
+///
+/// The newly focused window is restored to full opacity. The previously
+/// focused window is dimmed to `opacity` when `dim_previous` is set, which
+/// the caller decides based on whether it is a visible tiled window.
+///
+/// # Arguments
+///
+/// * `change` - The focus change to process.
+/// * `dim_previous` - Whether the previously focused window should be dimmed.
+/// * `opacity` - Opacity for unfocused windows.
+///
+/// # Returns
+///
+/// Vector of effects to execute.
+#[must_use]
+pub fn effects_from_focus_opacity(
+    change: &super::FocusChange,
+    dim_previous: bool,
+    opacity: f64,
+) -> Vec<TilingEffect> {
+    let mut effects = Vec::new();
+
+    if dim_previous
+        && let Some(old_id) = change.old_window_id
+        && change.new_window_id != Some(old_id)
+    {
+        effects.push(TilingEffect::SetWindowOpacity { window_id: old_id, opacity });
+    }
+
+    if let Some(new_id) = change.new_window_id {
+        effects.push(TilingEffect::SetWindowOpacity {
+            window_id: new_id,
+            opacity: 1.0,
+        });
+    }
+
+    effects
+}
+
+/// Computes effects that keep floating windows above tiled ones.
+///
+/// Every floating or sticky window kept on top (see [`Window::is_kept_on_top`])
+/// is brought to the front. Ordering isn't a window level, so windows that
+/// stop being kept on top simply fall back into the normal stacking order.
+///
+/// # Arguments
+///
+/// * `windows` - Windows of the visible workspaces.
+/// * `default` - The `tiling.floatingAlwaysOnTop` setting.
+///
+/// # Returns
+///
+/// Vector of effects to execute.
+#[must_use]
+pub fn effects_from_always_on_top(windows: &[Window], default: bool) -> Vec<TilingEffect> {
+    windows
+        .iter()
+        .filter(|w| w.is_kept_on_top(default) && !w.is_minimized && !w.is_hidden)
+        .map(|w| TilingEffect::BringToFront { window_id: w.id })
+        .collect()
+}
+
+/// Returns the center of the display containing the center of `frame`.
+fn screen_center(frame: &Rect) -> Option<(f64, f64)> {
+    let (x, y) = frame.center();
+    CGDisplay::active_displays()
+        .ok()?
+        .into_iter()
+        .map(|id| CGDisplay::new(id).bounds())
+        .map(|b| Rect::new(b.origin.x, b.origin.y, b.size.width, b.size.height))
+        .find(|bounds| bounds.contains_point(x, y))
+        .map(|bounds| bounds.center())
+}
+
+/// Returns the frame a window at `frame` animates out to before it is minimized.
+///
+/// `None` when `closeTo` is `none` or the target can't be read.
+fn close_target(close_to: CloseTo, frame: &Rect) -> Option<Rect> {
+    match close_to {
+        CloseTo::None => None,
+        CloseTo::Center => {
+            screen_center(frame).map(|(x, y)| frame_around(x, y, frame, OPEN_ORIGIN_SCALE))
+        }
+        CloseTo::Cursor => mouse_monitor::cursor_position()
+            .map(|(x, y)| frame_around(x, y, frame, OPEN_ORIGIN_SCALE)),
+    }
+}
+
+/// Returns `frame` scaled by `scale` and centered on the point `(x, y)`.
+const fn frame_around(x: f64, y: f64, frame: &Rect, scale: f64) -> Rect {
+    let width = frame.width * scale;
+    let height = frame.height * scale;
+    Rect::new(x - width / 2.0, y - height / 2.0, width, height)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -415,6 +717,14 @@ mod tests {
         assert_eq!(count, 0);
     }
 
+    #[test]
+    fn test_frame_around() {
+        let frame = Rect::new(0.0, 0.0, 1000.0, 500.0);
+        let origin = frame_around(300.0, 200.0, &frame, 0.2);
+        assert_eq!(origin, Rect::new(200.0, 150.0, 200.0, 100.0));
+        assert_eq!(origin.center(), (300.0, 200.0));
+    }
+
     #[test]
     fn test_effects_from_layout_change_no_changes() {
         let frame = Rect::new(0.0, 0.0, 100.0, 100.0);
@@ -496,6 +806,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_effects_from_layout_change_opened_window() {
+        let frame = Rect::new(0.0, 0.0, 100.0, 100.0);
+        let other = Rect::new(100.0, 0.0, 100.0, 100.0);
+        let mut change = super::super::LayoutChange::new(
+            Uuid::now_v7(),
+            vec![],
+            vec![(1, frame), (2, other)],
+            true,
+        );
+        change.opened_windows = vec![2];
+
+        let effects = effects_from_layout_change(&change);
+
+        // Only the created window is opened, the other one just appears
+        assert_eq!(effects, vec![
+            TilingEffect::SetWindowFrame {
+                window_id: 1,
+                frame,
+                animate: false,
+            },
+            TilingEffect::OpenWindow { window_id: 2, frame: other },
+        ]);
+    }
+
+    #[test]
+    fn test_close_target_none() {
+        let frame = Rect::new(0.0, 0.0, 1000.0, 500.0);
+        assert_eq!(close_target(CloseTo::None, &frame), None);
+    }
+
+    #[test]
+    fn test_effects_from_layout_change_layout_switch() {
+        // Switching from floating: the windows weren't positioned by a layout yet
+        let mut change = super::super::LayoutChange::new(
+            Uuid::now_v7(),
+            vec![],
+            vec![
+                (1, Rect::new(0.0, 0.0, 100.0, 100.0)),
+                (2, Rect::new(100.0, 0.0, 100.0, 100.0)),
+            ],
+            true,
+        );
+        change.layout_switched = true;
+
+        let effects = effects_from_layout_change(&change);
+        assert_eq!(effects.len(), 2);
+        assert!(
+            effects
+                .iter()
+                .all(|effect| matches!(effect, TilingEffect::SetWindowFrame { animate: true, .. }))
+        );
+    }
+
     #[test]
     fn test_effects_from_focus_change() {
         let change = super::super::FocusChange::new(Some(1), Some(2), None, None);
@@ -551,4 +915,92 @@ mod tests {
             _ => panic!("Expected UpdateBorder effect"),
         }
     }
+
+    #[test]
+    fn test_effects_from_focus_opacity_dims_previous() {
+        let change = super::super::FocusChange::new(Some(1), Some(2), None, None);
+
+        let effects = effects_from_focus_opacity(&change, true, 0.8);
+        assert_eq!(effects, vec![
+            TilingEffect::SetWindowOpacity { window_id: 1, opacity: 0.8 },
+            TilingEffect::SetWindowOpacity { window_id: 2, opacity: 1.0 },
+        ]);
+    }
+
+    #[test]
+    fn test_effects_from_focus_opacity_skips_previous() {
+        let change = super::super::FocusChange::new(Some(1), Some(2), None, None);
+
+        let effects = effects_from_focus_opacity(&change, false, 0.8);
+        assert_eq!(effects, vec![TilingEffect::SetWindowOpacity {
+            window_id: 2,
+            opacity: 1.0
+        }]);
+    }
+
+    #[test]
+    fn test_effects_from_focus_opacity_same_window() {
+        let change = super::super::FocusChange::new(Some(1), Some(1), None, None);
+
+        let effects = effects_from_focus_opacity(&change, true, 0.8);
+        assert_eq!(effects, vec![TilingEffect::SetWindowOpacity {
+            window_id: 1,
+            opacity: 1.0
+        }]);
+    }
+
+    #[test]
+    fn test_effects_from_always_on_top() {
+        let windows = [
+            Window { id: 1, ..Window::default() },
+            Window {
+                id: 2,
+                is_floating: true,
+                ..Window::default()
+            },
+            Window {
+                id: 3,
+                is_sticky: true,
+                ..Window::default()
+            },
+            Window {
+                id: 4,
+                is_floating: true,
+                is_minimized: true,
+                ..Window::default()
+            },
+            Window {
+                id: 5,
+                is_floating: true,
+                always_on_top: Some(false),
+                ..Window::default()
+            },
+        ];
+
+        let effects = effects_from_always_on_top(&windows, true);
+        assert_eq!(effects, vec![
+            TilingEffect::BringToFront { window_id: 2 },
+            TilingEffect::BringToFront { window_id: 3 },
+        ]);
+    }
+
+    #[test]
+    fn test_effects_from_always_on_top_override() {
+        let windows = [
+            Window {
+                id: 1,
+                is_floating: true,
+                ..Window::default()
+            },
+            Window {
+                id: 2,
+                is_floating: true,
+                always_on_top: Some(true),
+                ..Window::default()
+            },
+        ];
+
+        let effects = effects_from_always_on_top(&windows, false);
+        assert_eq!(effects, vec![TilingEffect::BringToFront { window_id: 2 }]);
+    }
 }
@@ -37,14 +37,15 @@
 //! - [`FocusChange`]: Describes a change in focus state
 
 pub mod animation;
+pub mod applescript;
 pub mod executor;
 pub mod subscriber;
 pub mod window_cache;
 pub mod window_ops;
 
 pub use animation::{
-    AnimationConfig, AnimationSystem, WindowTransition, begin_animation, cancel_animation,
-    get_interrupted_position, is_animation_active, is_animation_settling,
+    AnimationConfig, AnimationSystem, TransitionKind, WindowTransition, begin_animation,
+    cancel_animation, get_interrupted_position, is_animation_active, is_animation_settling,
     should_ignore_geometry_events,
 };
 pub use executor::EffectExecutor;
@@ -77,6 +78,24 @@ pub enum TilingEffect {
         animate: bool,
     },
 
+    /// Move a newly opened window to its layout frame.
+    ///
+    /// Animates from the `tiling.animations.openFrom` origin when set.
+    OpenWindow {
+        /// Window ID of the new window.
+        window_id: u32,
+        /// Target frame (position and size).
+        frame: Rect,
+    },
+
+    /// Minimize a window.
+    ///
+    /// Animates to the `tiling.animations.closeTo` target first when set.
+    MinimizeWindow {
+        /// Window ID to minimize.
+        window_id: u32,
+    },
+
     /// Show or hide a window.
     SetWindowVisible {
         /// Window ID to show/hide.
@@ -97,6 +116,20 @@ pub enum TilingEffect {
         window_id: u32,
     },
 
+    /// Order a window above the others at its level, without focusing it.
+    BringToFront {
+        /// Window ID to bring to the front.
+        window_id: u32,
+    },
+
+    /// Set the opacity of a window.
+    SetWindowOpacity {
+        /// Window ID to change.
+        window_id: u32,
+        /// Target opacity (0.0 = transparent, 1.0 = opaque).
+        opacity: f64,
+    },
+
     /// Update the border state of a window.
     UpdateBorder {
         /// Window ID to update border for.
@@ -184,6 +217,16 @@ pub struct LayoutChange {
 
     /// Whether this change was triggered by user action (should animate).
     pub user_triggered: bool,
+
+    /// Whether the workspace switched layouts. Every window then animates
+    /// from where it is, including windows the previous layout didn't
+    /// position (e.g. when leaving the floating layout).
+    pub layout_switched: bool,
+
+    /// Windows that were just created. Only these animate in from the
+    /// `openFrom` origin; other windows new to the layout (tracked at
+    /// startup, sent from another workspace, restored) just appear.
+    pub opened_windows: Vec<u32>,
 }
 
 impl LayoutChange {
@@ -200,6 +243,8 @@ impl LayoutChange {
             old_positions,
             new_positions,
             user_triggered,
+            layout_switched: false,
+            opened_windows: Vec::new(),
         }
     }
 
@@ -246,6 +291,25 @@ impl LayoutChange {
             .map(|(id, _)| *id)
             .collect()
     }
+
+    /// Returns the kind of transition implied by the change.
+    ///
+    /// `Open` when windows were added, `Close` when windows were removed, and
+    /// `None` otherwise, leaving move vs. resize to the animation system. A
+    /// layout switch only rearranges existing windows, so it is never an
+    /// open or close.
+    #[must_use]
+    pub fn transition_kind(&self) -> Option<TransitionKind> {
+        if self.layout_switched {
+            None
+        } else if !self.added_windows().is_empty() {
+            Some(TransitionKind::Open)
+        } else if !self.removed_windows().is_empty() {
+            Some(TransitionKind::Close)
+        } else {
+            None
+        }
+    }
 }
 
 /// Describes a change in focus state.
@@ -404,6 +468,29 @@ mod tests {
         assert!(change.has_changes());
     }
 
+    #[test]
+    fn test_layout_change_transition_kind() {
+        let frame = Rect::new(0.0, 0.0, 100.0, 100.0);
+        let other = Rect::new(100.0, 0.0, 100.0, 100.0);
+        let ws_id = Uuid::now_v7();
+
+        let opened =
+            LayoutChange::new(ws_id, vec![(1, frame)], vec![(1, frame), (2, other)], false);
+        assert_eq!(opened.transition_kind(), Some(TransitionKind::Open));
+
+        let closed =
+            LayoutChange::new(ws_id, vec![(1, frame), (2, other)], vec![(1, frame)], false);
+        assert_eq!(closed.transition_kind(), Some(TransitionKind::Close));
+
+        let moved = LayoutChange::new(ws_id, vec![(1, frame)], vec![(1, other)], true);
+        assert_eq!(moved.transition_kind(), None);
+
+        // Leaving the floating layout positions windows for the first time
+        let mut switched = LayoutChange::new(ws_id, vec![], vec![(1, frame), (2, other)], true);
+        switched.layout_switched = true;
+        assert_eq!(switched.transition_kind(), None);
+    }
+
     #[test]
     fn test_layout_change_no_changes_same() {
         let frame = Rect::new(0.0, 0.0, 100.0, 100.0);
@@ -42,10 +42,22 @@ use std::collections::HashMap;
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
-use super::executor::{EffectExecutor, effects_from_focus_change, effects_from_layout_change};
-use super::{FocusChange, LayoutChange, TilingEffect, begin_animation, cancel_animation};
+use super::executor::{
+    EffectExecutor, effects_from_always_on_top, effects_from_focus_change,
+    effects_from_focus_opacity, effects_from_layout_change,
+};
+use super::{
+    FocusChange, LayoutChange, TilingEffect, TransitionKind, begin_animation, cancel_animation,
+};
+use crate::config::get_config;
 use crate::modules::tiling::actor::{QueryResult, StateActorHandle, StateQuery};
+use crate::modules::tiling::borders;
+use crate::modules::tiling::commands::layout_to_string_pub;
+use crate::modules::tiling::init::{emit_tabs_changed, is_paused};
+use crate::modules::tiling::layout::build_tabs;
 use crate::modules::tiling::state::{FocusState, LayoutType, Rect};
+use crate::utils::ipc::{self, StacheNotification};
+use crate::utils::ipc_socket;
 
 // ============================================================================
 // Subscriber State
@@ -68,6 +80,15 @@ struct SubscriberState {
 
     /// Floating window IDs.
     floating_windows: std::collections::HashSet<u32>,
+
+    /// Workspaces whose tabs were last emitted (for clearing the tab strip).
+    tabbed_workspaces: std::collections::HashSet<Uuid>,
+
+    /// Workspaces whose layout changed since they were last laid out.
+    layout_switches: std::collections::HashSet<Uuid>,
+
+    /// Windows created since their workspace was last laid out.
+    opened_windows: std::collections::HashSet<u32>,
 }
 
 impl SubscriberState {
@@ -85,6 +106,7 @@ impl SubscriberState {
         user_triggered: bool,
     ) -> Option<LayoutChange> {
         let old_positions = self.layout_positions.get(&workspace_id).cloned().unwrap_or_default();
+        let layout_switched = self.layout_switches.remove(&workspace_id);
 
         // For user-triggered changes (like after a drag), always apply the layout
         // because the actual window positions might differ from our tracked positions.
@@ -93,12 +115,18 @@ impl SubscriberState {
             return None;
         }
 
-        let change = LayoutChange::new(
+        let mut change = LayoutChange::new(
             workspace_id,
             old_positions,
             new_positions.clone(),
             user_triggered,
         );
+        change.layout_switched = layout_switched;
+        change.opened_windows = new_positions
+            .iter()
+            .map(|(id, _)| *id)
+            .filter(|id| self.opened_windows.remove(id))
+            .collect();
 
         self.layout_positions.insert(workspace_id, new_positions);
 
@@ -139,10 +167,19 @@ impl SubscriberState {
     fn is_floating(&self, window_id: u32) -> bool { self.floating_windows.contains(&window_id) }
 
     /// Updates workspace layout tracking.
+    ///
+    /// A switch from another layout is remembered so the next layout change
+    /// of the workspace animates every window.
     fn update_workspace_layout(&mut self, workspace_id: Uuid, layout: LayoutType) {
-        self.workspace_layouts.insert(workspace_id, layout);
+        let previous = self.workspace_layouts.insert(workspace_id, layout);
+        if previous.is_some_and(|previous| previous != layout) {
+            self.layout_switches.insert(workspace_id);
+        }
     }
 
+    /// Remembers a created window, so its first layout opens it.
+    fn mark_window_opened(&mut self, window_id: u32) { self.opened_windows.insert(window_id); }
+
     /// Updates floating window tracking.
     fn set_window_floating(&mut self, window_id: u32, floating: bool) {
         if floating {
@@ -164,11 +201,19 @@ pub enum SubscriberNotification {
     LayoutChanged {
         workspace_id: Uuid,
         user_triggered: bool,
+        /// Kind of transition to animate, inferred from the change when `None`.
+        transition: Option<TransitionKind>,
     },
 
     /// Focus state changed.
     FocusChanged,
 
+    /// A window was created and should open when it is next laid out.
+    WindowOpened { window_id: u32 },
+
+    /// A window should be minimized.
+    MinimizeWindow { window_id: u32 },
+
     /// Workspace visibility changed.
     VisibilityChanged { workspace_id: Uuid, visible: bool },
 
@@ -212,10 +257,31 @@ pub struct EffectSubscriberHandle {
 impl EffectSubscriberHandle {
     /// Notifies the subscriber that a layout changed.
     pub fn notify_layout_changed(&self, workspace_id: Uuid, user_triggered: bool) {
-        if let Err(e) = self
-            .notification_tx
-            .try_send(SubscriberNotification::LayoutChanged { workspace_id, user_triggered })
-        {
+        self.send_layout_changed(workspace_id, user_triggered, None);
+    }
+
+    /// Notifies the subscriber that a layout changed, animating it as `transition`.
+    pub fn notify_layout_changed_as(
+        &self,
+        workspace_id: Uuid,
+        user_triggered: bool,
+        transition: TransitionKind,
+    ) {
+        self.send_layout_changed(workspace_id, user_triggered, Some(transition));
+    }
+
+    /// Sends a layout changed notification.
+    fn send_layout_changed(
+        &self,
+        workspace_id: Uuid,
+        user_triggered: bool,
+        transition: Option<TransitionKind>,
+    ) {
+        if let Err(e) = self.notification_tx.try_send(SubscriberNotification::LayoutChanged {
+            workspace_id,
+            user_triggered,
+            transition,
+        }) {
             tracing::warn!(
                 "tiling: dropped LayoutChanged notification for workspace {workspace_id}: {e}"
             );
@@ -229,6 +295,30 @@ impl EffectSubscriberHandle {
         }
     }
 
+    /// Notifies the subscriber that a window was created.
+    ///
+    /// Call before notifying the layout change that places the window.
+    pub fn notify_window_opened(&self, window_id: u32) {
+        if let Err(e) = self
+            .notification_tx
+            .try_send(SubscriberNotification::WindowOpened { window_id })
+        {
+            tracing::warn!("tiling: dropped WindowOpened notification for window {window_id}: {e}");
+        }
+    }
+
+    /// Asks the subscriber to minimize a window, animating it out first.
+    pub fn notify_minimize_window(&self, window_id: u32) {
+        if let Err(e) = self
+            .notification_tx
+            .try_send(SubscriberNotification::MinimizeWindow { window_id })
+        {
+            tracing::warn!(
+                "tiling: dropped MinimizeWindow notification for window {window_id}: {e}"
+            );
+        }
+    }
+
     /// Notifies the subscriber that workspace visibility changed.
     pub fn notify_visibility_changed(&self, workspace_id: Uuid, visible: bool) {
         if let Err(e) = self
@@ -339,6 +429,13 @@ impl EffectSubscriber {
     async fn handle_notification(&mut self, notification: SubscriberNotification) {
         tracing::debug!("tiling: subscriber received notification: {notification:?}");
 
+        // AX calls fail while the accessibility permission is revoked; layouts
+        // are reapplied once it is granted again
+        if is_paused() {
+            tracing::debug!("tiling: paused, skipping notification");
+            return;
+        }
+
         // For layout changes that may trigger animations, signal cancellation
         // of any ongoing animation so the new one can take priority, then
         // immediately decrement to indicate we're now the active command.
@@ -351,16 +448,44 @@ impl EffectSubscriber {
             begin_animation();
         }
 
-        let effects = match notification {
-            SubscriberNotification::LayoutChanged { workspace_id, user_triggered } => {
+        // Anything that can reorder windows may cover floating windows kept on top
+        let restacks = matches!(
+            notification,
+            SubscriberNotification::LayoutChanged { .. }
+                | SubscriberNotification::FocusChanged
+                | SubscriberNotification::VisibilityChanged { visible: true, .. }
+                | SubscriberNotification::FloatingChanged { .. }
+        );
+
+        let mut transition = None;
+        let mut laid_out_workspace = None;
+        let mut effects = match notification {
+            SubscriberNotification::LayoutChanged {
+                workspace_id,
+                user_triggered,
+                transition: requested,
+            } => {
                 tracing::debug!(
                     "tiling: subscriber handling LayoutChanged for workspace {workspace_id}"
                 );
-                self.handle_layout_changed(workspace_id, user_triggered).await
+                let (effects, kind) =
+                    self.handle_layout_changed(workspace_id, user_triggered).await;
+                transition = requested.or(kind);
+                laid_out_workspace = Some(workspace_id);
+                effects
             }
 
             SubscriberNotification::FocusChanged => self.handle_focus_changed().await,
 
+            SubscriberNotification::WindowOpened { window_id } => {
+                self.state.mark_window_opened(window_id);
+                Vec::new()
+            }
+
+            SubscriberNotification::MinimizeWindow { window_id } => {
+                vec![TilingEffect::MinimizeWindow { window_id }]
+            }
+
             SubscriberNotification::VisibilityChanged { workspace_id, visible } => {
                 self.handle_visibility_changed(workspace_id, visible).await
             }
@@ -378,19 +503,29 @@ impl EffectSubscriber {
             SubscriberNotification::Shutdown => Vec::new(),
         };
 
+        if restacks {
+            effects.extend(self.keep_on_top_effects().await);
+        }
+
         tracing::debug!("tiling: subscriber generated {} effects", effects.len());
         if !effects.is_empty() {
-            let count = self.executor.execute_batch(effects);
+            let count = self.executor.execute_batch_as(transition, effects);
             tracing::debug!("tiling: subscriber executed {count} effects");
         }
+
+        if let Some(workspace_id) = laid_out_workspace {
+            self.notify_layout_applied(workspace_id).await;
+        }
     }
 
     /// Handles a layout change notification.
+    ///
+    /// Returns the effects to apply and the kind of transition implied by the change.
     async fn handle_layout_changed(
         &mut self,
         workspace_id: Uuid,
         user_triggered: bool,
-    ) -> Vec<TilingEffect> {
+    ) -> (Vec<TilingEffect>, Option<TransitionKind>) {
         tracing::debug!(
             "tiling: handle_layout_changed for workspace {workspace_id}, user_triggered={user_triggered}"
         );
@@ -401,9 +536,12 @@ impl EffectSubscriber {
 
         let Ok(QueryResult::Layout(new_positions)) = layout_result else {
             tracing::warn!("tiling: failed to query layout for workspace {workspace_id}");
-            return Vec::new();
+            return (Vec::new(), None);
         };
 
+        // Keep the frontend tab strip in sync (windows added/removed, layout switched)
+        self.sync_tabs(workspace_id).await;
+
         tracing::debug!(
             "tiling: queried layout for workspace {workspace_id}: {} windows",
             new_positions.len()
@@ -426,7 +564,7 @@ impl EffectSubscriber {
             tracing::debug!(
                 "tiling: no actual layout change detected for workspace {workspace_id}"
             );
-            return Vec::new(); // No actual change
+            return (Vec::new(), None); // No actual change
         };
 
         tracing::debug!(
@@ -435,8 +573,10 @@ impl EffectSubscriber {
             change.new_positions.len()
         );
 
+        self.publish_layout_changed(workspace_id, change.new_positions.len()).await;
+
         // Convert change to effects
-        effects_from_layout_change(&change)
+        (effects_from_layout_change(&change), change.transition_kind())
     }
 
     /// Handles a focus change notification.
@@ -462,23 +602,184 @@ impl EffectSubscriber {
             self.actor_handle.query(StateQuery::GetFocusedWorkspace).await
         {
             layout = workspace.layout;
+
+            if let Some(window_id) = change.new_window_id
+                && change.old_window_id != change.new_window_id
+            {
+                ipc_socket::publish_event(
+                    ipc_socket::topics::WINDOW_FOCUSED,
+                    serde_json::json!({
+                        "windowId": window_id,
+                        "workspace": workspace.name,
+                    }),
+                );
+            }
+
+            // Focus moved between tabs
+            if layout == LayoutType::Tabbed {
+                self.sync_tabs(workspace.id).await;
+            }
         }
 
-        // Check if the focused window itself is floating
+        // Check if the focused window itself is floating or has its own border color
+        let mut rule_color = None;
         if let Some(window_id) = new_focus.focused_window_id
             && let Ok(QueryResult::Window(Some(window))) =
                 self.actor_handle.query(StateQuery::GetWindow { id: window_id }).await
         {
-            is_window_floating = window.is_floating;
+            is_window_floating = window.is_effectively_floating();
+            rule_color = borders::rule_border_color(&window);
         }
 
         // Update borders via the simple API
-        crate::modules::tiling::borders::on_focus_changed(layout, is_window_floating);
+        borders::on_focus_changed(layout, is_window_floating, rule_color.as_deref());
 
         // Generate effects for other systems (not borders - handled above)
-        let is_monocle = layout == LayoutType::Monocle;
+        let is_monocle = layout.is_stacking();
         let is_floating = layout == LayoutType::Floating || is_window_floating;
-        effects_from_focus_change(&change, is_monocle, is_floating)
+        let mut effects = effects_from_focus_change(&change, is_monocle, is_floating);
+
+        let tiling = &get_config().tiling;
+        if tiling.dims_unfocused_windows() {
+            let dim_previous = self.should_dim(change.old_window_id).await;
+            effects.extend(effects_from_focus_opacity(
+                &change,
+                dim_previous,
+                tiling.unfocused_opacity(),
+            ));
+        }
+
+        effects
+    }
+
+    /// Returns effects bringing floating windows kept on top back to the front.
+    ///
+    /// Covers floating and sticky windows of every visible workspace, using
+    /// `tiling.floatingAlwaysOnTop` unless a window has its own override.
+    async fn keep_on_top_effects(&self) -> Vec<TilingEffect> {
+        let Ok(QueryResult::Windows(windows)) =
+            self.actor_handle.query(StateQuery::GetAllWindows).await
+        else {
+            return Vec::new();
+        };
+
+        let visible: Vec<_> = windows
+            .into_iter()
+            .filter(|w| w.is_sticky || self.state.visible_workspaces.contains_key(&w.workspace_id))
+            .collect();
+
+        effects_from_always_on_top(&visible, get_config().tiling.floating_always_on_top)
+    }
+
+    /// Returns whether a window that lost focus should be dimmed.
+    ///
+    /// Only tiled windows on visible, non-floating workspaces are dimmed.
+    async fn should_dim(&self, window_id: Option<u32>) -> bool {
+        let Some(window_id) = window_id else {
+            return false;
+        };
+
+        let Ok(QueryResult::Window(Some(window))) =
+            self.actor_handle.query(StateQuery::GetWindow { id: window_id }).await
+        else {
+            return false;
+        };
+
+        window.is_layoutable()
+            && !window.is_effectively_floating()
+            && self.state.visible_workspaces.contains_key(&window.workspace_id)
+            && self
+                .state
+                .workspace_layouts
+                .get(&window.workspace_id)
+                .is_some_and(|layout| *layout != LayoutType::Floating)
+    }
+
+    /// Publishes a layout change to IPC event subscribers.
+    async fn publish_layout_changed(&self, workspace_id: Uuid, window_count: usize) {
+        if !ipc_socket::has_subscribers() {
+            return;
+        }
+
+        let Ok(QueryResult::Workspace(Some(workspace))) =
+            self.actor_handle.query(StateQuery::GetWorkspace { id: workspace_id }).await
+        else {
+            return;
+        };
+
+        ipc_socket::publish_event(
+            ipc_socket::topics::LAYOUT_CHANGED,
+            serde_json::json!({
+                "workspace": workspace.name,
+                "layout": layout_to_string_pub(workspace.layout),
+                "windowCount": window_count,
+            }),
+        );
+    }
+
+    /// Posts a `tiling-layout-applied` notification with the workspace's target frames.
+    ///
+    /// Sent after every layout pass, including passes that moved nothing, so
+    /// integration tests can wait for it instead of sleeping. Debug builds only.
+    async fn notify_layout_applied(&self, workspace_id: Uuid) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let Ok(QueryResult::Workspace(Some(workspace))) =
+            self.actor_handle.query(StateQuery::GetWorkspace { id: workspace_id }).await
+        else {
+            return;
+        };
+
+        let positions = self.state.layout_positions.get(&workspace_id);
+        ipc::send_notification(&StacheNotification::TilingLayoutApplied {
+            workspace: workspace.name,
+            frames: layout_frames_json(positions.map_or(&[], Vec::as_slice)),
+        });
+    }
+
+    /// Emits the tab list for a workspace in tabbed layout.
+    ///
+    /// Workspaces that left the tabbed layout get an empty list once, so the
+    /// frontend can clear its tab strip.
+    async fn sync_tabs(&mut self, workspace_id: Uuid) {
+        let Ok(QueryResult::Workspace(Some(workspace))) =
+            self.actor_handle.query(StateQuery::GetWorkspace { id: workspace_id }).await
+        else {
+            return;
+        };
+
+        if workspace.layout != LayoutType::Tabbed {
+            if self.state.tabbed_workspaces.remove(&workspace_id) {
+                emit_tabs_changed(&workspace.name, &[]);
+            }
+            return;
+        }
+
+        let mut windows = self
+            .actor_handle
+            .query(StateQuery::GetLayoutableWindows { workspace_id })
+            .await
+            .ok()
+            .and_then(QueryResult::into_windows)
+            .unwrap_or_default();
+
+        // Tabs follow the workspace window order
+        windows.sort_by_key(|w| {
+            workspace.window_ids.iter().position(|&id| id == w.id).unwrap_or(usize::MAX)
+        });
+
+        let focused_window_id = self
+            .actor_handle
+            .query(StateQuery::GetFocusState)
+            .await
+            .ok()
+            .and_then(QueryResult::into_focus)
+            .and_then(|focus| focus.focused_window_id);
+
+        self.state.tabbed_workspaces.insert(workspace_id);
+        emit_tabs_changed(&workspace.name, &build_tabs(&windows, focused_window_id));
     }
 
     /// Handles a visibility change notification.
@@ -488,6 +789,7 @@ impl EffectSubscriber {
         visible: bool,
     ) -> Vec<TilingEffect> {
         let mut effects = Vec::new();
+        let restore_opacity = get_config().tiling.dims_unfocused_windows();
 
         if visible {
             // Workspace became visible - need to apply layout and show borders
@@ -507,6 +809,9 @@ impl EffectSubscriber {
 
                 // Show borders for all windows in this workspace
                 let window_ids: Vec<u32> = positions.iter().map(|(id, _)| *id).collect();
+                if restore_opacity {
+                    effects.extend(full_opacity_effects(&window_ids));
+                }
                 if !window_ids.is_empty() {
                     effects.push(TilingEffect::ShowBorders { window_ids });
                 }
@@ -518,6 +823,9 @@ impl EffectSubscriber {
             // Workspace became hidden - hide borders for its windows
             if let Some(positions) = self.state.layout_positions.get(&workspace_id) {
                 let window_ids: Vec<u32> = positions.iter().map(|(id, _)| *id).collect();
+                if restore_opacity {
+                    effects.extend(full_opacity_effects(&window_ids));
+                }
                 if !window_ids.is_empty() {
                     effects.push(TilingEffect::HideBorders { window_ids });
                 }
@@ -589,7 +897,7 @@ impl EffectSubscriber {
 
         if let Ok(QueryResult::Windows(windows)) = windows_result {
             for window in windows {
-                if window.is_floating {
+                if window.is_effectively_floating() {
                     self.state.floating_windows.insert(window.id);
                 }
             }
@@ -615,19 +923,46 @@ impl EffectSubscriber {
             layout = workspace.layout;
         }
 
-        // Check if the focused window itself is floating
+        // Check if the focused window itself is floating or has its own border color
+        let mut rule_color = None;
         if let Some(window_id) = self.state.focus.focused_window_id
             && let Ok(QueryResult::Window(Some(window))) =
                 self.actor_handle.query(StateQuery::GetWindow { id: window_id }).await
         {
-            is_window_floating = window.is_floating;
+            is_window_floating = window.is_effectively_floating();
+            rule_color = borders::rule_border_color(&window);
         }
 
         // Update borders via the simple API
-        crate::modules::tiling::borders::on_focus_changed(layout, is_window_floating);
+        borders::on_focus_changed(layout, is_window_floating, rule_color.as_deref());
     }
 }
 
+/// Returns effects restoring the given windows to full opacity.
+fn full_opacity_effects(window_ids: &[u32]) -> impl Iterator<Item = TilingEffect> + '_ {
+    window_ids
+        .iter()
+        .map(|&window_id| TilingEffect::SetWindowOpacity { window_id, opacity: 1.0 })
+}
+
+/// Serializes target frames for the `tiling-layout-applied` notification.
+fn layout_frames_json(positions: &[(u32, Rect)]) -> String {
+    let frames: Vec<serde_json::Value> = positions
+        .iter()
+        .map(|(window_id, frame)| {
+            serde_json::json!({
+                "windowId": window_id,
+                "x": frame.x,
+                "y": frame.y,
+                "width": frame.width,
+                "height": frame.height,
+            })
+        })
+        .collect();
+
+    serde_json::Value::Array(frames).to_string()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -665,6 +1000,63 @@ mod tests {
         assert!(change.is_some());
     }
 
+    #[test]
+    fn test_subscriber_state_layout_switch() {
+        let mut state = SubscriberState::new();
+        let ws_id = Uuid::now_v7();
+        let positions = vec![(1, Rect::new(0.0, 0.0, 100.0, 100.0))];
+
+        // The first known layout isn't a switch
+        state.update_workspace_layout(ws_id, LayoutType::Floating);
+        let change = state.update_layout(ws_id, positions.clone(), true).unwrap();
+        assert!(!change.layout_switched);
+
+        state.update_workspace_layout(ws_id, LayoutType::Monocle);
+        let change = state.update_layout(ws_id, positions.clone(), true).unwrap();
+        assert!(change.layout_switched);
+
+        // Only the first layout after the switch is marked
+        let change = state.update_layout(ws_id, positions, true).unwrap();
+        assert!(!change.layout_switched);
+    }
+
+    #[test]
+    fn test_subscriber_state_opened_windows() {
+        let mut state = SubscriberState::new();
+        let ws_id = Uuid::now_v7();
+        let frame = Rect::new(0.0, 0.0, 100.0, 100.0);
+
+        // Windows laid out for the first time without being created aren't opened
+        let change = state.update_layout(ws_id, vec![(1, frame)], false).unwrap();
+        assert!(change.opened_windows.is_empty());
+
+        state.mark_window_opened(2);
+        let change = state.update_layout(ws_id, vec![(1, frame), (2, frame)], false).unwrap();
+        assert_eq!(change.opened_windows, vec![2]);
+
+        // A window only opens once
+        let change = state.update_layout(ws_id, vec![(2, frame)], false).unwrap();
+        assert!(change.opened_windows.is_empty());
+    }
+
+    #[test]
+    fn test_layout_frames_json() {
+        assert_eq!(layout_frames_json(&[]), "[]");
+
+        let json = layout_frames_json(&[(7, Rect::new(10.0, 20.0, 300.0, 400.0))]);
+        let frames: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            frames,
+            serde_json::json!([{
+                "windowId": 7,
+                "x": 10.0,
+                "y": 20.0,
+                "width": 300.0,
+                "height": 400.0,
+            }])
+        );
+    }
+
     #[test]
     fn test_subscriber_state_update_focus() {
         let mut state = SubscriberState::new();
@@ -728,11 +1120,31 @@ mod tests {
 
         let notification = rx.try_recv().unwrap();
         match notification {
-            SubscriberNotification::LayoutChanged { workspace_id, user_triggered } => {
+            SubscriberNotification::LayoutChanged {
+                workspace_id,
+                user_triggered,
+                transition,
+            } => {
                 assert_eq!(workspace_id, ws_id);
                 assert!(user_triggered);
+                assert_eq!(transition, None);
             }
             _ => panic!("Wrong notification type"),
         }
     }
+
+    #[test]
+    fn test_subscriber_handle_send_with_transition() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let handle = EffectSubscriberHandle { notification_tx: tx };
+
+        let ws_id = Uuid::now_v7();
+        handle.notify_layout_changed_as(ws_id, true, TransitionKind::WorkspaceSwitch);
+
+        let notification = rx.try_recv().unwrap();
+        assert!(matches!(notification, SubscriberNotification::LayoutChanged {
+            transition: Some(TransitionKind::WorkspaceSwitch),
+            ..
+        }));
+    }
 }
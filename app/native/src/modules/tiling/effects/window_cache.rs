@@ -22,6 +22,7 @@ use std::time::{Duration, Instant};
 
 use dashmap::DashMap;
 
+use super::applescript;
 use crate::modules::tiling::ffi::skylight;
 use crate::modules::tiling::state::Rect;
 
@@ -362,8 +363,11 @@ impl WindowElementCache {
     /// This is an efficient way to detect destroyed windows without enumerating
     /// all windows from macOS. Uses cached elements for O(1) validity checks
     /// where possible.
+    ///
+    /// Windows without a cached element are re-resolved when `requery` is set,
+    /// and assumed to still exist otherwise.
     #[must_use]
-    pub fn find_invalid_windows(&self, window_ids: &[u32]) -> Vec<u32> {
+    pub fn find_invalid_windows(&self, window_ids: &[u32], requery: bool) -> Vec<u32> {
         let mut invalid = Vec::new();
 
         for &window_id in window_ids {
@@ -381,7 +385,7 @@ impl WindowElementCache {
 
             // No cached element - try to resolve
             // If resolution fails, window is likely destroyed
-            if self.resolve(window_id).is_none() {
+            if requery && self.resolve(window_id).is_none() {
                 invalid.push(window_id);
             }
         }
@@ -442,6 +446,45 @@ impl WindowElementCache {
         unsafe { CFRelease(element.cast()) };
         result
     }
+
+    /// Sets the frames of several windows back to back.
+    ///
+    /// Every element is resolved before the first frame is set, so a cache
+    /// miss doesn't hold up the windows after it and they all move at once
+    /// instead of one after another.
+    ///
+    /// Windows using the `osascript` fallback are queued for it when their
+    /// element can't be resolved or set, after all the others have moved.
+    ///
+    /// Returns the IDs of the windows whose frame could not be set.
+    #[must_use]
+    pub fn set_window_frames_fast(&self, frames: &[(u32, Rect)]) -> Vec<u32> {
+        let mut window_ids: Vec<u32> = frames.iter().map(|(window_id, _)| *window_id).collect();
+        window_ids.sort_unstable();
+        window_ids.dedup();
+
+        let elements: std::collections::HashMap<u32, AXUIElementRef> =
+            self.batch_resolve(&window_ids).into_iter().collect();
+
+        let failed: Vec<&(u32, Rect)> = frames
+            .iter()
+            .filter(|(window_id, frame)| {
+                !elements
+                    .get(window_id)
+                    .is_some_and(|&element| set_frame_on_element(element, frame))
+            })
+            .collect();
+
+        for element in elements.into_values() {
+            unsafe { CFRelease(element.cast()) };
+        }
+
+        failed
+            .into_iter()
+            .filter(|(window_id, frame)| !applescript::set_window_frame(*window_id, frame))
+            .map(|(window_id, _)| *window_id)
+            .collect()
+    }
 }
 
 impl Default for WindowElementCache {
@@ -771,6 +814,13 @@ mod tests {
         cache.clear();
     }
 
+    #[test]
+    fn test_set_window_frames_fast_empty() {
+        let cache = WindowElementCache::new();
+        assert!(cache.set_window_frames_fast(&[]).is_empty());
+        assert_eq!(cache.stats(), (0, 0, 0, 0));
+    }
+
     #[test]
     fn test_global_cache() {
         let cache1 = get_cache();
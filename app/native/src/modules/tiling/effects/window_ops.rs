@@ -27,6 +27,7 @@ use core_foundation::base::TCFType;
 use core_foundation::boolean::CFBoolean;
 use core_foundation::string::CFString;
 
+use super::applescript;
 use crate::modules::tiling::state::Rect;
 
 // ============================================================================
@@ -83,6 +84,7 @@ thread_local! {
     static CF_MAIN: OnceCell<CFString> = const { OnceCell::new() };
     static CF_RAISE: OnceCell<CFString> = const { OnceCell::new() };
     static CF_ROLE: OnceCell<CFString> = const { OnceCell::new() };
+    static CF_MINIMIZED: OnceCell<CFString> = const { OnceCell::new() };
 }
 
 /// Gets or creates a cached `CFString`.
@@ -113,6 +115,9 @@ fn cf_raise() -> *const c_void { cached_cfstring!(CF_RAISE, "AXRaise") }
 #[inline]
 fn cf_role() -> *const c_void { cached_cfstring!(CF_ROLE, "AXRole") }
 
+#[inline]
+fn cf_minimized() -> *const c_void { cached_cfstring!(CF_MINIMIZED, "AXMinimized") }
+
 // ============================================================================
 // AX Element Resolution
 // ============================================================================
@@ -408,7 +413,9 @@ unsafe fn set_ax_size(element: AXUIElementRef, width: f64, height: f64) -> bool
 /// The window frame, or `None` if the window cannot be found.
 #[must_use]
 pub fn get_window_frame(window_id: u32) -> Option<Rect> {
-    let element = resolve_window_element(window_id)?;
+    let Some(element) = resolve_window_element(window_id) else {
+        return applescript::get_window_frame(window_id);
+    };
 
     let result = unsafe {
         let pos = get_ax_position(element)?;
@@ -417,7 +424,7 @@ pub fn get_window_frame(window_id: u32) -> Option<Rect> {
     };
 
     unsafe { CFRelease(element.cast()) };
-    result
+    result.or_else(|| applescript::get_window_frame(window_id))
 }
 
 /// Sets the frame of a window (position and size).
@@ -451,7 +458,9 @@ pub fn set_window_frame(window_id: u32, frame: &Rect) -> bool {
 /// Internal implementation of `set_window_frame` (runs on main thread).
 fn set_window_frame_impl(window_id: u32, frame: &Rect) {
     let Some(element) = resolve_window_element(window_id) else {
-        tracing::debug!("set_window_frame: could not resolve window {window_id}");
+        if !applescript::set_window_frame(window_id, frame) {
+            tracing::debug!("set_window_frame: could not resolve window {window_id}");
+        }
         return;
     };
 
@@ -465,7 +474,7 @@ fn set_window_frame_impl(window_id: u32, frame: &Rect) {
 
     unsafe { CFRelease(element.cast()) };
 
-    if !(pos_ok && (size_ok_1 || size_ok_2)) {
+    if !(pos_ok && (size_ok_1 || size_ok_2)) && !applescript::set_window_frame(window_id, frame) {
         tracing::debug!("set_window_frame: failed for window {window_id}");
     }
 }
@@ -802,6 +811,47 @@ fn raise_window_impl(window_id: u32) {
     };
 }
 
+/// Minimizes a window to the Dock, or restores it.
+///
+/// # Arguments
+///
+/// * `window_id` - The window ID to minimize or restore.
+/// * `minimized` - `true` to minimize, `false` to restore.
+///
+/// # Returns
+///
+/// `true` if the operation succeeded (optimistically, since execution is async).
+#[must_use]
+pub fn set_window_minimized(window_id: u32, minimized: bool) -> bool {
+    crate::utils::thread::dispatch_on_main(move || {
+        set_window_minimized_impl(window_id, minimized);
+    });
+
+    // Return true optimistically - the actual operation runs async
+    true
+}
+
+/// Internal implementation of `set_window_minimized` (runs on main thread).
+fn set_window_minimized_impl(window_id: u32, minimized: bool) {
+    let Some(element) = resolve_window_element(window_id) else {
+        tracing::debug!("set_window_minimized: could not resolve window {window_id}");
+        return;
+    };
+
+    unsafe {
+        let value = CFBoolean::from(minimized);
+        let result = AXUIElementSetAttributeValue(
+            element,
+            cf_minimized(),
+            value.as_concrete_TypeRef().cast(),
+        );
+        if result != K_AX_ERROR_SUCCESS {
+            tracing::debug!("set_window_minimized: AX error {result} for window {window_id}");
+        }
+        CFRelease(element.cast());
+    };
+}
+
 /// Sets multiple window frames in batch.
 ///
 /// Uses a single main thread dispatch for all frames, reducing IPC overhead
@@ -834,6 +884,23 @@ pub fn set_window_frames_batch(frames: &[(u32, Rect)]) -> usize {
     count
 }
 
+/// Sets multiple window frames on the calling thread.
+///
+/// Unlike [`set_window_frames_batch`], this returns only after every frame has
+/// been applied. Must be called from the main thread, e.g. while the app exits
+/// and work dispatched to the main queue would never run.
+///
+/// # Returns
+///
+/// Number of frames applied.
+pub fn set_window_frames_now(frames: &[(u32, Rect)]) -> usize {
+    for (window_id, frame) in frames {
+        set_window_frame_impl(*window_id, frame);
+    }
+
+    frames.len()
+}
+
 // ============================================================================
 // App Visibility Operations
 // ============================================================================
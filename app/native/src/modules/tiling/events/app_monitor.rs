@@ -40,10 +40,12 @@ use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 use parking_lot::RwLock;
 
+use crate::config::get_config;
 use crate::modules::tiling::events::EventProcessor;
 use crate::modules::tiling::events::observer::{
     add_observer_for_pid, remove_observer_for_pid, should_observe_app,
 };
+use crate::modules::tiling::rules::is_app_ignored;
 use crate::utils::objc::nsstring;
 
 // ============================================================================
@@ -145,6 +147,12 @@ impl AppMonitorAdapter {
 
         tracing::debug!("App launched: pid={pid}, bundle={bundle_id}, name={name}");
 
+        // Ignored apps are left entirely alone
+        if is_app_ignored(&get_config().tiling.ignore_apps, &bundle_id, &name) {
+            tracing::debug!("Skipping ignored app: pid={pid}, name={name}");
+            return;
+        }
+
         // Create AX observer for the new app (must happen on main thread)
         if should_observe_app(&bundle_id, &name)
             && let Err(e) = add_observer_for_pid(pid)
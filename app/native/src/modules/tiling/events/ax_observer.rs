@@ -40,10 +40,14 @@ use std::sync::{Arc, OnceLock};
 use parking_lot::RwLock;
 
 use super::types::{WindowEvent, WindowEventType};
+use crate::config::get_config;
 use crate::modules::tiling::actor::WindowCreatedInfo;
+use crate::modules::tiling::effects::applescript;
 use crate::modules::tiling::events::EventProcessor;
-use crate::modules::tiling::rules::is_pip_window;
-use crate::modules::tiling::state::Rect;
+use crate::modules::tiling::rules::{is_app_ignored, is_pip_window, uses_applescript_frames};
+use crate::modules::tiling::state::{Rect, Window};
+use crate::modules::tiling::window::is_manageable;
+use crate::utils::thread::spawn_named_thread;
 
 // ============================================================================
 // FFI Declarations (subset needed for window info extraction)
@@ -163,15 +167,6 @@ impl AXObserverAdapter {
     // ========================================================================
 
     fn handle_window_created(&self, pid: i32, ax_element: AXUIElementRef) {
-        // Minimum size thresholds
-        // Standard windows: 200x150 minimum
-        // Dialogs: 400x300 minimum (real dialogs like preferences are larger;
-        //          small dialogs are popups like date pickers, color pickers)
-        const MIN_STANDARD_WIDTH: f64 = 200.0;
-        const MIN_STANDARD_HEIGHT: f64 = 150.0;
-        const MIN_DIALOG_WIDTH: f64 = 400.0;
-        const MIN_DIALOG_HEIGHT: f64 = 300.0;
-
         // Extract window info from the AX element
         let Some(window_id) = get_window_id(ax_element) else {
             tracing::debug!("Window created event: could not get window ID");
@@ -179,7 +174,7 @@ impl AXObserverAdapter {
         };
 
         // Get role and subrole for filtering
-        let _role = get_element_role(ax_element);
+        let role = get_element_role(ax_element);
         let subrole = get_window_subrole(ax_element);
 
         // Skip PiP (Picture-in-Picture) windows - they have subrole AXFloatingWindow
@@ -190,32 +185,12 @@ impl AXObserverAdapter {
             return;
         }
 
-        // Get window properties early so we can use them in filtering
+        // Get window properties early so we can use them in filtering. The
+        // frame may only be available through the `osascript` fallback
         let title = get_window_title(ax_element).unwrap_or_default();
-        let frame = get_window_frame(ax_element).unwrap_or_default();
-
-        // Determine if window should be managed based on subrole and size
-        // Use blacklist approach: only reject known popup/sheet subroles
-        let should_manage = match subrole.as_deref() {
-            // Explicitly reject popup-like subroles
-            // Sheets and drawers are always attached to parent windows
-            // AXUnknown subrole indicates popup/panel windows without standard controls
-            // Examples: browser extension popups, toolbar popups, dropdown panels
-            // These windows typically have no close/minimize/zoom buttons
-            Some("AXSheet" | "AXDrawer" | "AXUnknown") => false,
-            Some("AXDialog") => {
-                // Dialogs - only accept large ones (preferences, settings)
-                // Small dialogs are popups (date pickers, color pickers, alerts)
-                frame.width >= MIN_DIALOG_WIDTH && frame.height >= MIN_DIALOG_HEIGHT
-            }
-            _ => {
-                // Standard windows, no subrole, or custom subroles - accept if large enough
-                // Many apps (like Ghostty) don't set subrole or use custom values
-                frame.width >= MIN_STANDARD_WIDTH && frame.height >= MIN_STANDARD_HEIGHT
-            }
-        };
+        let frame = get_window_frame(ax_element);
 
-        if !should_manage {
+        if frame.is_some_and(|frame| !is_manageable(subrole.as_deref(), &frame)) {
             return;
         }
 
@@ -226,17 +201,22 @@ impl AXObserverAdapter {
 
         // Get app info from PID
         let (app_id, app_name) = get_app_info_for_pid(pid);
+        if is_app_ignored(&get_config().tiling.ignore_apps, &app_id, &app_name) {
+            return;
+        }
 
         // Note: Tab detection is now handled in the window handler using the TabRegistry.
         // We pass tab_group_id=None and is_active_tab=true here; the handler will
         // check the TabRegistry and update accordingly.
-        let info = WindowCreatedInfo {
+        let mut info = WindowCreatedInfo {
             window_id,
             pid,
             app_id,
             app_name,
             title,
-            frame,
+            role,
+            subrole,
+            frame: frame.unwrap_or_default(),
             is_minimized,
             is_fullscreen,
             minimum_size,
@@ -244,7 +224,37 @@ impl AXObserverAdapter {
             is_active_tab: true,
         };
 
-        self.processor.on_window_created(info);
+        if frame.is_some() {
+            self.processor.on_window_created(info);
+            return;
+        }
+
+        // Ask System Events for windows whose rules opt in to the `osascript`
+        // fallback, off the main thread as it blocks until `osascript` exits
+        let window = Window {
+            id: window_id,
+            pid,
+            app_id: info.app_id.clone(),
+            app_name: info.app_name.clone(),
+            title: info.title.clone(),
+            role: info.role.clone(),
+            subrole: info.subrole.clone(),
+            ..Window::default()
+        };
+        if !uses_applescript_frames(&get_config().tiling, &window) {
+            return;
+        }
+
+        let processor = Arc::clone(&self.processor);
+        spawn_named_thread("tiling-frame-discovery", move || {
+            let Some(frame) = applescript::discover_frame(&window) else {
+                return;
+            };
+            if is_manageable(info.subrole.as_deref(), &frame) {
+                info.frame = frame;
+                processor.on_window_created(info);
+            }
+        });
     }
 
     fn handle_window_destroyed(&self, pid: i32, ax_element: AXUIElementRef) {
@@ -321,6 +331,11 @@ impl AXObserverAdapter {
             return;
         };
 
+        // Entering or leaving native fullscreen resizes the window
+        if let Some(fullscreen) = get_window_fullscreen(ax_element) {
+            self.processor.on_window_fullscreen_changed(window_id, fullscreen);
+        }
+
         let Some(frame) = get_window_frame(ax_element) else {
             return;
         };
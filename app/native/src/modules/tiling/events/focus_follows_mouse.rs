@@ -0,0 +1,281 @@
+//! Focus follows mouse for the tiling window manager.
+//!
+//! With `tiling.focusFollowsMouse`, a background thread samples the pointer
+//! position through the mouse monitor and focuses the tracked window under it
+//! once the pointer has rested there for [`HOVER_DELAY`]. Nothing happens
+//! while a mouse button is held or a window is being moved or resized, so the
+//! hover never fights a drag. The pointer has to move again before another
+//! window is focused, which leaves keyboard focus changes alone.
+//!
+//! The thread is only started when tiling starts, so toggling the setting
+//! takes effect after the app restarts.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use super::{drag_state, mouse_monitor};
+use crate::modules::tiling::actor::QueryResult;
+use crate::modules::tiling::effects::window_ops;
+use crate::modules::tiling::ffi::WindowQuery;
+use crate::modules::tiling::init::{get_handle, is_initialized, is_paused};
+use crate::modules::tiling::state::{Rect, Window};
+use crate::utils::thread::spawn_named_thread;
+
+/// How often the pointer position is sampled.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long the pointer must rest over a window before it is focused.
+const HOVER_DELAY: Duration = Duration::from_millis(150);
+
+/// Pointer movement below this many points is ignored.
+const MOVE_THRESHOLD: f64 = 1.0;
+
+/// Whether the focus-follows-mouse thread is running.
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Debounces pointer samples until the pointer comes to rest.
+#[derive(Debug)]
+struct HoverTracker {
+    /// Last sampled pointer position.
+    position: Option<(f64, f64)>,
+    /// When the pointer arrived at `position`.
+    since: Instant,
+    /// Whether the rest at `position` was already handled.
+    settled: bool,
+}
+
+impl HoverTracker {
+    fn new(now: Instant) -> Self {
+        Self {
+            position: None,
+            since: now,
+            settled: true,
+        }
+    }
+
+    /// Records a pointer sample.
+    ///
+    /// Returns `true` once per rest, when the pointer has stayed put for
+    /// [`HOVER_DELAY`]. The position at startup doesn't count as a rest.
+    fn update(&mut self, position: (f64, f64), now: Instant) -> bool {
+        let moved = self.position.is_none_or(|(x, y)| {
+            (x - position.0).abs() >= MOVE_THRESHOLD || (y - position.1).abs() >= MOVE_THRESHOLD
+        });
+
+        if moved {
+            self.settled = self.position.is_none();
+            self.position = Some(position);
+            self.since = now;
+            return false;
+        }
+
+        if self.settled || now.duration_since(self.since) < HOVER_DELAY {
+            return false;
+        }
+
+        self.settled = true;
+        true
+    }
+
+    /// Marks the current rest as handled, e.g. while a drag is in progress.
+    const fn settle(&mut self) { self.settled = true; }
+}
+
+/// Returns the on-screen windows, front to back.
+fn window_stack() -> Vec<(u32, Rect)> {
+    WindowQuery::all_on_screen()
+        .map(|query| {
+            query
+                .iter()
+                .filter(|info| info.is_visible())
+                .map(|info| (info.id, info.bounds))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the tracked window under a point.
+///
+/// `stack` lists the on-screen windows front to back. The topmost window at
+/// the point wins, and `None` is returned when it isn't tracked (e.g. a
+/// menu, a panel or an ignored app), so nothing is focused through it. Only
+/// windows on `visible_workspaces` (and sticky windows) are considered.
+fn window_at_point(
+    windows: &[Window],
+    visible_workspaces: &HashSet<Uuid>,
+    stack: &[(u32, Rect)],
+    (x, y): (f64, f64),
+) -> Option<u32> {
+    let (topmost_id, _) = stack.iter().find(|(_, bounds)| bounds.contains_point(x, y))?;
+
+    windows
+        .iter()
+        .find(|window| window.id == *topmost_id)
+        .filter(|window| {
+            !window.is_minimized
+                && !window.is_hidden
+                && (window.tab_group_id.is_none() || window.is_active_tab)
+                && (window.is_sticky || visible_workspaces.contains(&window.workspace_id))
+        })
+        .map(|window| window.id)
+}
+
+/// Focuses the tracked window under the pointer, if it isn't focused yet.
+async fn focus_window_under(position: (f64, f64)) {
+    let Some(handle) = get_handle() else {
+        return;
+    };
+
+    let enabled = handle.get_enabled().await.ok().and_then(QueryResult::into_enabled);
+    if enabled != Some(true) {
+        return;
+    }
+
+    let Some(windows) = handle.get_all_windows().await.ok().and_then(QueryResult::into_windows)
+    else {
+        return;
+    };
+    let Some(workspaces) =
+        handle.get_all_workspaces().await.ok().and_then(QueryResult::into_workspaces)
+    else {
+        return;
+    };
+    let visible: HashSet<Uuid> =
+        workspaces.iter().filter(|ws| ws.is_visible).map(|ws| ws.id).collect();
+
+    let Some(window_id) = window_at_point(&windows, &visible, &window_stack(), position) else {
+        return;
+    };
+
+    let focused = handle.get_focus_state().await.ok().and_then(QueryResult::into_focus);
+    if focused.is_some_and(|focus| focus.focused_window_id == Some(window_id)) {
+        return;
+    }
+
+    tracing::debug!("tiling: focus follows mouse to window {window_id}");
+    let _ = window_ops::focus_window(window_id);
+}
+
+/// Starts focusing the window under the pointer in the background.
+///
+/// Starting it twice does nothing.
+pub fn start() {
+    if STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    spawn_named_thread("tiling-focus-follows-mouse", || {
+        let Ok(rt) = tokio::runtime::Builder::new_current_thread().enable_all().build() else {
+            tracing::error!("tiling: focus follows mouse: failed to create runtime");
+            STARTED.store(false, Ordering::SeqCst);
+            return;
+        };
+
+        let mut tracker = HoverTracker::new(Instant::now());
+
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let Some(position) = mouse_monitor::cursor_position() else {
+                continue;
+            };
+
+            if mouse_monitor::is_mouse_down() || drag_state::is_operation_in_progress() {
+                tracker.settle();
+                continue;
+            }
+
+            if tracker.update(position, Instant::now()) && is_initialized() && !is_paused() {
+                rt.block_on(focus_window_under(position));
+            }
+        }
+    });
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hover_tracker_waits_for_rest() {
+        let start = Instant::now();
+        let mut tracker = HoverTracker::new(start);
+
+        // The pointer position at startup isn't a rest
+        assert!(!tracker.update((10.0, 10.0), start));
+        assert!(!tracker.update((10.0, 10.0), start + HOVER_DELAY * 2));
+
+        // Moving starts a new rest, reported once after the delay
+        assert!(!tracker.update((500.0, 300.0), start + HOVER_DELAY * 3));
+        assert!(!tracker.update((500.0, 300.0), start + HOVER_DELAY * 3 + POLL_INTERVAL));
+        assert!(tracker.update((500.0, 300.0), start + HOVER_DELAY * 4));
+        assert!(!tracker.update((500.0, 300.0), start + HOVER_DELAY * 5));
+    }
+
+    #[test]
+    fn test_hover_tracker_settle_skips_rest() {
+        let start = Instant::now();
+        let mut tracker = HoverTracker::new(start);
+        tracker.update((10.0, 10.0), start);
+
+        tracker.update((500.0, 300.0), start);
+        tracker.settle();
+        assert!(!tracker.update((500.0, 300.0), start + HOVER_DELAY * 2));
+    }
+
+    #[test]
+    fn test_window_at_point() {
+        let visible_ws = Uuid::new_v4();
+        let hidden_ws = Uuid::new_v4();
+        let window = |id: u32, workspace_id: Uuid, frame: Rect| Window {
+            id,
+            workspace_id,
+            frame,
+            ..Default::default()
+        };
+
+        let windows = vec![
+            window(1, visible_ws, Rect::new(0.0, 0.0, 960.0, 1080.0)),
+            window(2, visible_ws, Rect::new(960.0, 0.0, 960.0, 1080.0)),
+            window(3, visible_ws, Rect::new(800.0, 400.0, 400.0, 300.0)),
+            window(4, hidden_ws, Rect::new(0.0, 0.0, 1920.0, 1080.0)),
+        ];
+        let visible = HashSet::from([visible_ws]);
+
+        // Front to back: an untracked panel, then window 3 above the tiled ones
+        let stack: Vec<(u32, Rect)> = [(99, Rect::new(1500.0, 800.0, 200.0, 200.0))]
+            .into_iter()
+            .chain([3, 1, 2, 4].map(|id| (id, windows[id as usize - 1].frame)))
+            .collect();
+
+        assert_eq!(
+            window_at_point(&windows, &visible, &stack, (100.0, 100.0)),
+            Some(1)
+        );
+        assert_eq!(
+            window_at_point(&windows, &visible, &stack, (1500.0, 100.0)),
+            Some(2)
+        );
+        assert_eq!(
+            window_at_point(&windows, &visible, &stack, (900.0, 500.0)),
+            Some(3)
+        );
+        assert_eq!(
+            window_at_point(&windows, &visible, &stack, (100.0, 2000.0)),
+            None
+        );
+
+        // Nothing is focused through a window that isn't tracked
+        assert_eq!(
+            window_at_point(&windows, &visible, &stack, (1600.0, 900.0)),
+            None
+        );
+    }
+}
@@ -56,6 +56,7 @@ mod types;
 pub mod app_monitor;
 pub mod ax_observer;
 pub mod drag_state;
+pub mod focus_follows_mouse;
 pub mod mouse_monitor;
 pub mod observer;
 pub mod screen_monitor;
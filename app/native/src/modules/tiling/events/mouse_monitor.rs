@@ -23,6 +23,10 @@ use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use core_foundation::base::TCFType;
 use core_foundation::mach_port::CFMachPort;
 use core_foundation::runloop::{CFRunLoop, kCFRunLoopCommonModes};
+use core_graphics::display::CGDisplay;
+use core_graphics::event::CGEvent;
+use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+use core_graphics::geometry::CGPoint;
 
 // ============================================================================
 // FFI Declarations
@@ -97,6 +101,24 @@ pub fn is_mouse_down() -> bool { MOUSE_DOWN.load(Ordering::SeqCst) }
 #[must_use]
 pub fn drag_sequence() -> u32 { DRAG_SEQUENCE.load(Ordering::SeqCst) }
 
+/// Returns the current mouse pointer position in screen coordinates.
+///
+/// Reads the pointer directly, so it works whether or not the monitor is
+/// running.
+#[must_use]
+pub fn cursor_position() -> Option<(f64, f64)> {
+    let source = CGEventSource::new(CGEventSourceStateID::CombinedSessionState).ok()?;
+    let location = CGEvent::new(source).ok()?.location();
+    Some((location.x, location.y))
+}
+
+/// Moves the mouse pointer to a position in screen coordinates.
+///
+/// Returns `false` if the pointer couldn't be moved.
+pub fn warp_cursor(x: f64, y: f64) -> bool {
+    CGDisplay::warp_mouse_cursor_position(CGPoint::new(x, y)).is_ok()
+}
+
 /// Sets the callback to invoke when the mouse button is released.
 ///
 /// This callback is called once per mouse-up event, on the mouse monitor thread.
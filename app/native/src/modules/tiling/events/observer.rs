@@ -8,11 +8,20 @@
 //! The observer system uses a single-threaded model where all observer operations
 //! happen on the main thread. The global state is protected by a mutex for
 //! thread-safe access.
+//!
+//! # Permission Watcher
+//!
+//! The accessibility permission can be revoked while the app runs, after which
+//! AX calls fail silently. [`start_permission_watcher`] re-checks it
+//! periodically and reports when it is lost or restored.
+//! [`wait_for_permission`] covers the permission being granted only after
+//! launch, before tiling could start.
 
 use std::collections::HashMap;
 use std::ffi::c_void;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use core_foundation::base::TCFType;
 use core_foundation::runloop::CFRunLoop;
@@ -20,6 +29,9 @@ use core_foundation::string::CFString;
 use parking_lot::Mutex;
 
 use super::types::{WindowEvent, WindowEventType};
+use crate::config::get_config;
+use crate::modules::tiling::rules::is_app_ignored;
+use crate::utils::thread::spawn_named_thread;
 
 // ============================================================================
 // Thread-Safe Wrapper
@@ -298,9 +310,39 @@ pub fn remove_observer_for_pid(pid: i32) {
     }
 }
 
+/// Re-creates the observers for all running applications.
+///
+/// Observers created or kept while the accessibility permission was revoked
+/// never receive notifications, so they are dropped and added again.
+pub fn refresh_observers() {
+    let pids: Vec<i32> = OBSERVER_STATE
+        .lock()
+        .as_ref()
+        .map(|state| state.observers.keys().copied().collect())
+        .unwrap_or_default();
+    for pid in pids {
+        remove_observer_for_pid(pid);
+    }
+
+    let mut observed = 0;
+    for app in crate::modules::tiling::window::get_running_apps() {
+        if should_observe_app(&app.bundle_id, &app.name) && add_observer_for_pid(app.pid).is_ok() {
+            observed += 1;
+        }
+    }
+
+    tracing::info!("tiling: observers refreshed ({observed} apps)");
+}
+
 /// Checks if we should observe an app.
+///
+/// Apps listed in `tiling.ignoreApps` are never observed.
 #[must_use]
 pub fn should_observe_app(bundle_id: &str, name: &str) -> bool {
+    if is_app_ignored(&get_config().tiling.ignore_apps, bundle_id, name) {
+        return false;
+    }
+
     // Check bundle ID
     if !bundle_id.is_empty()
         && SKIP_OBSERVER_BUNDLE_IDS.iter().any(|&id| bundle_id.eq_ignore_ascii_case(id))
@@ -316,6 +358,77 @@ pub fn should_observe_app(bundle_id: &str, name: &str) -> bool {
     true
 }
 
+// ============================================================================
+// Permission Watcher
+// ============================================================================
+
+/// How often the accessibility permission is checked again while running.
+const PERMISSION_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the accessibility permission is checked while waiting for it to
+/// be granted for the first time. Shorter, as the user is likely looking at
+/// System Settings.
+const PERMISSION_GRANT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Whether the accessibility permission was revoked while running.
+static PERMISSION_LOST: AtomicBool = AtomicBool::new(false);
+
+/// Whether the permission watcher thread has been started.
+static PERMISSION_WATCHER_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Whether a thread is waiting for the permission to be granted.
+static PERMISSION_WAIT_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether the accessibility permission was revoked while running.
+#[must_use]
+pub fn is_permission_lost() -> bool { PERMISSION_LOST.load(Ordering::Acquire) }
+
+/// Records the result of a permission check.
+///
+/// Returns `true` if the permission was lost or restored since the last check.
+fn record_permission(granted: bool) -> bool {
+    PERMISSION_LOST.swap(!granted, Ordering::AcqRel) == granted
+}
+
+/// Starts re-checking the accessibility permission in the background.
+///
+/// `on_change` is called from the watcher thread with the new state whenever
+/// the permission is lost or restored. Starting the watcher twice does nothing.
+pub fn start_permission_watcher(on_change: fn(bool)) {
+    if PERMISSION_WATCHER_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    spawn_named_thread("tiling-permission-watcher", move || {
+        loop {
+            std::thread::sleep(PERMISSION_CHECK_INTERVAL);
+
+            // The startup check is cached, so ask the system each time
+            let granted = crate::utils::accessibility::is_trusted();
+            if record_permission(granted) {
+                on_change(granted);
+            }
+        }
+    });
+}
+
+/// Waits in the background for the accessibility permission to be granted.
+///
+/// `on_granted` is called once from the waiting thread as soon as the
+/// permission is granted, and the thread exits. Waiting twice does nothing.
+pub fn wait_for_permission(on_granted: fn()) {
+    if PERMISSION_WAIT_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    spawn_named_thread("tiling-permission-wait", move || {
+        while !crate::utils::accessibility::is_trusted() {
+            std::thread::sleep(PERMISSION_GRANT_CHECK_INTERVAL);
+        }
+        on_granted();
+    });
+}
+
 // ============================================================================
 // Observer Callback
 // ============================================================================
@@ -381,4 +494,15 @@ mod tests {
         assert!(should_observe_app("com.google.Chrome", "Google Chrome"));
         assert!(should_observe_app("", "SomeApp"));
     }
+
+    #[test]
+    fn test_record_permission() {
+        // Only changes are reported, and the check ends with the permission granted
+        assert!(!record_permission(true));
+        assert!(record_permission(false));
+        assert!(is_permission_lost());
+        assert!(!record_permission(false));
+        assert!(record_permission(true));
+        assert!(!is_permission_lost());
+    }
 }
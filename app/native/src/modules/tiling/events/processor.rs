@@ -4,6 +4,8 @@
 //! - Dispatching time-sensitive events immediately (focus, create, destroy)
 //! - Batching geometry events (move, resize) per display refresh rate
 //! - Coalescing multiple geometry updates for the same window
+//! - Debouncing title changes before workspace title rules are re-applied
+//! - Optionally holding new windows until their frame settles (`tiling.windowReady`)
 //!
 //! # Multi-Monitor Support
 //!
@@ -16,14 +18,16 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use dashmap::DashMap;
 use parking_lot::Mutex;
 
+use crate::config::{WindowReadyConfig, get_config};
 use crate::modules::tiling::actor::{
     GeometryUpdate, GeometryUpdateType, StateActorHandle, StateMessage, WindowCreatedInfo,
 };
+use crate::modules::tiling::effects::window_ops;
 use crate::modules::tiling::state::Rect;
 
 /// Default refresh rate if detection fails (60 Hz).
@@ -35,6 +39,35 @@ const MIN_REFRESH_RATE: f64 = 30.0;
 /// Maximum refresh rate to prevent too-slow batching.
 const MAX_REFRESH_RATE: f64 = 360.0;
 
+/// How long a window title must stay unchanged before title rules are
+/// re-applied, so apps that stream titles (e.g. progress in the title) don't
+/// move windows back and forth.
+const TITLE_SETTLE_DELAY: Duration = Duration::from_millis(500);
+
+/// Waits until a new window's frame stops changing and returns it.
+///
+/// Checks the frame every `poll_interval` after the initial delay, and gives up
+/// at the timeout. Returns `None` if the frame can't be read.
+async fn wait_for_settled_frame(window_id: u32, ready: &WindowReadyConfig) -> Option<Rect> {
+    tokio::time::sleep(ready.initial_delay()).await;
+
+    let deadline = Instant::now() + ready.timeout();
+    let mut frame = window_ops::get_window_frame(window_id)?;
+
+    while Instant::now() < deadline {
+        tokio::time::sleep(ready.poll_interval()).await;
+
+        let latest = window_ops::get_window_frame(window_id)?;
+        if latest == frame {
+            tracing::trace!("Window {window_id} frame settled");
+            break;
+        }
+        frame = latest;
+    }
+
+    Some(frame)
+}
+
 /// A batch queue for a single screen.
 struct ScreenBatch {
     /// Screen ID (`CGDirectDisplayID`).
@@ -89,6 +122,13 @@ pub struct EventProcessor {
     /// against current windows from macOS to find which one was destroyed.
     pid_windows: Arc<Mutex<HashMap<i32, HashSet<u32>>>>,
 
+    /// Window ID → number of the latest title change, used to debounce title rules.
+    title_changes: Arc<DashMap<u32, u64>>,
+
+    /// Window ID → last observed native fullscreen state, so the state is
+    /// only sent to the actor when it changes.
+    fullscreen_states: Arc<DashMap<u32, bool>>,
+
     /// Default screen ID for windows with unknown screen assignment.
     default_screen_id: AtomicU32,
 
@@ -109,6 +149,8 @@ impl EventProcessor {
             screen_batches: Arc::new(Mutex::new(HashMap::new())),
             window_screen_map: Arc::new(DashMap::new()),
             pid_windows: Arc::new(Mutex::new(HashMap::new())),
+            title_changes: Arc::new(DashMap::new()),
+            fullscreen_states: Arc::new(DashMap::new()),
             default_screen_id: AtomicU32::new(0),
             running: Arc::new(AtomicBool::new(false)),
         }
@@ -315,15 +357,37 @@ impl EventProcessor {
     // Immediate Dispatch (time-sensitive events)
     // ========================================================================
 
-    /// Dispatch a window created event immediately.
-    pub fn on_window_created(&self, info: WindowCreatedInfo) {
+    /// Dispatch a window created event.
+    ///
+    /// Dispatched immediately unless `tiling.windowReady` asks to wait for the
+    /// window's frame to settle first.
+    pub fn on_window_created(&self, mut info: WindowCreatedInfo) {
         let window_id = info.window_id;
         tracing::trace!("Window created: {window_id:?}");
 
         // Track this window for destroy detection
         self.pid_windows.lock().entry(info.pid).or_default().insert(info.window_id);
 
-        let _ = self.actor_handle.send(StateMessage::WindowCreated(info));
+        let ready = get_config().tiling.window_ready;
+        if ready.is_immediate() {
+            let _ = self.actor_handle.send(StateMessage::WindowCreated(info));
+            return;
+        }
+
+        let pid_windows = self.pid_windows.clone();
+        let actor_handle = self.actor_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Some(frame) = wait_for_settled_frame(window_id, &ready).await {
+                info.frame = frame;
+            }
+
+            // Skip windows destroyed while waiting
+            let tracked =
+                pid_windows.lock().get(&info.pid).is_some_and(|ids| ids.contains(&window_id));
+            if tracked {
+                let _ = actor_handle.send(StateMessage::WindowCreated(info));
+            }
+        });
     }
 
     /// Dispatch a window destroyed event immediately.
@@ -342,6 +406,10 @@ impl EventProcessor {
             batch.updates.remove(&window_id);
         }
 
+        // Drop any pending title rule check
+        self.title_changes.remove(&window_id);
+        self.fullscreen_states.remove(&window_id);
+
         // Remove from pid_windows tracking
         {
             let mut pid_windows = self.pid_windows.lock();
@@ -360,6 +428,10 @@ impl EventProcessor {
     ///
     /// Uses the window element cache to efficiently check which tracked
     /// windows are no longer valid, avoiding expensive AX enumeration.
+    ///
+    /// In observer-only tracking mode (`tiling.trackingMode`), windows without a cached element are
+    /// not re-resolved; they stay tracked until their own destroy event or the
+    /// next full sync.
     pub fn on_window_destroyed_for_pid(&self, pid: i32) {
         tracing::debug!("tiling: on_window_destroyed_for_pid called for pid={pid}");
 
@@ -378,8 +450,9 @@ impl EventProcessor {
 
         // Use window cache to efficiently find invalid windows
         // This uses O(1) validity checks on cached elements where possible
+        let requery = get_config().tiling.tracking_mode.allows_requery();
         let cache = crate::modules::tiling::effects::get_window_cache();
-        let invalid_windows = cache.find_invalid_windows(&tracked_window_ids);
+        let invalid_windows = cache.find_invalid_windows(&tracked_window_ids, requery);
 
         tracing::debug!(
             "tiling: found {} invalid windows for pid={pid}",
@@ -412,19 +485,52 @@ impl EventProcessor {
     }
 
     /// Dispatch a window title changed event immediately.
+    ///
+    /// Workspace title rules are re-applied separately, once the title stayed
+    /// unchanged for [`TITLE_SETTLE_DELAY`].
     pub fn on_window_title_changed(&self, window_id: u32, title: String) {
         tracing::trace!("Window title changed: {window_id} = '{title}'");
         let _ = self.actor_handle.send(StateMessage::WindowTitleChanged { window_id, title });
+
+        let change = {
+            let mut count = self.title_changes.entry(window_id).or_insert(0);
+            *count += 1;
+            *count
+        };
+
+        let title_changes = self.title_changes.clone();
+        let actor_handle = self.actor_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(TITLE_SETTLE_DELAY).await;
+
+            // A later title change restarts the delay
+            if title_changes.remove_if(&window_id, |_, latest| *latest == change).is_some() {
+                let _ = actor_handle.send(StateMessage::WindowTitleSettled { window_id });
+            }
+        });
     }
 
     /// Dispatch a window fullscreen changed event immediately.
+    ///
+    /// macOS has no notification for native fullscreen, so the state is read
+    /// on every resize. Only the first observation of a window and actual
+    /// changes are sent to the actor.
     pub fn on_window_fullscreen_changed(&self, window_id: u32, fullscreen: bool) {
+        if !self.record_fullscreen(window_id, fullscreen) {
+            return;
+        }
+
         tracing::trace!("Window fullscreen changed: {window_id} = {fullscreen}");
         let _ = self
             .actor_handle
             .send(StateMessage::WindowFullscreenChanged { window_id, fullscreen });
     }
 
+    /// Stores the observed fullscreen state and returns whether it changed.
+    fn record_fullscreen(&self, window_id: u32, fullscreen: bool) -> bool {
+        self.fullscreen_states.insert(window_id, fullscreen) != Some(fullscreen)
+    }
+
     // ========================================================================
     // Batched Dispatch (geometry events)
     // ========================================================================
@@ -621,6 +727,10 @@ impl EventProcessor {
     #[must_use]
     pub fn screen_count(&self) -> usize { self.screen_batches.lock().len() }
 
+    /// Get the number of windows whose title is waiting to settle.
+    #[must_use]
+    pub fn pending_title_count(&self) -> usize { self.title_changes.len() }
+
     /// Get the batch interval for a specific screen.
     #[must_use]
     pub fn batch_interval_for_screen(&self, screen_id: u32) -> Option<Duration> {
@@ -837,6 +947,39 @@ mod tests {
         handle.shutdown().unwrap();
     }
 
+    #[tokio::test]
+    async fn test_title_changes_are_debounced() {
+        let handle = StateActor::spawn();
+        let processor = EventProcessor::new(handle.clone());
+
+        processor.on_window_title_changed(100, "Untitled".to_string());
+        processor.on_window_title_changed(100, "Project".to_string());
+        processor.on_window_title_changed(200, "Notes".to_string());
+        assert_eq!(processor.pending_title_count(), 2);
+
+        tokio::time::sleep(TITLE_SETTLE_DELAY + Duration::from_millis(100)).await;
+        assert_eq!(processor.pending_title_count(), 0);
+
+        handle.shutdown().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fullscreen_changes_are_deduplicated() {
+        let handle = StateActor::spawn();
+        let processor = EventProcessor::new(handle.clone());
+
+        assert!(processor.record_fullscreen(100, false));
+        assert!(!processor.record_fullscreen(100, false));
+        assert!(processor.record_fullscreen(100, true));
+        assert!(!processor.record_fullscreen(100, true));
+
+        // A destroyed window starts over
+        processor.on_window_destroyed(100);
+        assert!(processor.record_fullscreen(100, true));
+
+        handle.shutdown().unwrap();
+    }
+
     #[tokio::test]
     async fn test_flush_all() {
         let handle = StateActor::spawn();
@@ -17,6 +17,11 @@
 //! └─────────────────────────────────────────────────────────────────────────┘
 //! ```
 //!
+//! If the accessibility permission is revoked while running, tiling pauses
+//! (no layouts, no drag snapping) until it is granted again, then rediscovers
+//! applications and windows and resumes. If it isn't granted at launch, tiling
+//! starts as soon as it is, without a restart.
+//!
 //! # Usage
 //!
 //! ```rust,ignore
@@ -100,11 +105,17 @@ pub fn is_initialized() -> bool { INITIALIZED.get().copied().unwrap_or(false) }
 #[must_use]
 pub fn is_enabled() -> bool { get_config().tiling.is_enabled() }
 
+/// Returns whether tiling is paused because the accessibility permission was
+/// revoked while running.
+#[must_use]
+pub fn is_paused() -> bool { super::events::observer::is_permission_lost() }
+
 /// Initializes the `tiling` window manager.
 ///
 /// This function:
 /// 1. Checks if tiling is enabled in configuration
-/// 2. Verifies accessibility permissions
+/// 2. Verifies accessibility permissions, or waits for them in the background
+///    and finishes initialization once they are granted
 /// 3. Creates and starts the state actor
 /// 4. Creates and starts the event processor
 /// 5. Creates and starts the effect subscriber
@@ -117,7 +128,8 @@ pub fn is_enabled() -> bool { get_config().tiling.is_enabled() }
 ///
 /// # Returns
 ///
-/// `true` if initialization succeeded, `false` otherwise.
+/// `true` if initialization succeeded, `false` otherwise (including while
+/// waiting for accessibility permissions).
 #[allow(clippy::needless_pass_by_value)] // AppHandle is intentionally passed by value for storage
 pub fn init(app_handle: tauri::AppHandle) -> bool {
     // Check if already initialized
@@ -135,17 +147,50 @@ pub fn init(app_handle: tauri::AppHandle) -> bool {
         return false;
     }
 
-    // Check accessibility permissions
+    // Store app handle for event emission
+    store_app_handle(app_handle.clone());
+
+    // Check accessibility permissions, starting once they are granted
     if !is_accessibility_granted() {
-        tracing::warn!("tiling: accessibility permissions not granted");
-        let _ = INITIALIZED.set(false);
+        tracing::warn!("tiling: accessibility permissions not granted, waiting for them");
+        super::events::observer::wait_for_permission(on_accessibility_granted);
         return false;
     }
 
-    // Store app handle for event emission
-    store_app_handle(app_handle.clone());
+    start(&app_handle)
+}
+
+/// Starts tiling once accessibility permissions are granted after launch.
+///
+/// Called from the permission wait thread, and starts tiling on the main
+/// thread like the initialization at launch. Does nothing if tiling was
+/// initialized in the meantime or has since been disabled in the config.
+fn on_accessibility_granted() {
+    crate::utils::thread::dispatch_on_main(|| {
+        if INITIALIZED.get().is_some() {
+            return;
+        }
+
+        if !is_enabled() {
+            tracing::info!("tiling: accessibility permission granted, but tiling is disabled");
+            return;
+        }
+
+        let Some(app_handle) = get_app_handle() else {
+            tracing::warn!("tiling: accessibility permission granted, but no app handle is stored");
+            return;
+        };
+
+        if start(&app_handle) {
+            tracing::info!("tiling enabled after permission grant");
+        }
+    });
+}
 
-    // Initialize the system
+/// Starts every tiling component and marks tiling as initialized.
+///
+/// Returns `true` if initialization succeeded.
+fn start(app_handle: &tauri::AppHandle) -> bool {
     match init_internal() {
         Ok(()) => {
             let _ = INITIALIZED.set(true);
@@ -159,6 +204,8 @@ pub fn init(app_handle: tauri::AppHandle) -> bool {
                 tracing::warn!("tiling: failed to emit initialized event: {e}");
             }
 
+            super::events::observer::start_permission_watcher(on_accessibility_changed);
+
             true
         }
         Err(e) => {
@@ -172,8 +219,17 @@ pub fn init(app_handle: tauri::AppHandle) -> bool {
 /// Shuts down the tiling system.
 ///
 /// This sends a shutdown message to the state actor and stops the processor.
+/// When `tiling.restoreOnExit` is enabled, windows are first moved back to
+/// their original frames. Must be called from the main thread.
 pub fn shutdown() {
     if let Some(handle) = HANDLE.get() {
+        if get_config().tiling.restore_on_exit
+            && let Some(frames) = get_original_frames()
+        {
+            let restored = super::effects::window_ops::set_window_frames_now(&frames);
+            tracing::info!("tiling: restored {restored} window(s) to their original frames");
+        }
+
         let _ = handle.send(StateMessage::Shutdown);
         tracing::info!("tiling: shutdown requested");
     }
@@ -183,6 +239,26 @@ pub fn shutdown() {
     }
 }
 
+/// Moves every tracked window back to the frame it had when first tracked.
+///
+/// Windows that were closed, are minimized or are fullscreen are skipped, and
+/// restoring twice leaves windows where the first restore put them. The next
+/// layout change tiles the windows again.
+///
+/// Returns the number of windows restored, or `None` if tiling isn't running.
+#[must_use]
+pub fn restore_original_frames() -> Option<usize> {
+    let frames = get_original_frames()?;
+    Some(super::effects::window_ops::set_window_frames_batch(&frames))
+}
+
+/// Queries the actor for the original frames of restorable windows.
+fn get_original_frames() -> Option<Vec<(u32, super::state::Rect)>> {
+    let handle = HANDLE.get()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+    rt.block_on(handle.get_original_frames()).ok()?.into_layout()
+}
+
 // ============================================================================
 // Internal Initialization
 // ============================================================================
@@ -272,6 +348,11 @@ fn init_internal() -> Result<(), String> {
         tracing::warn!("tiling: mouse monitor initialization failed");
     }
 
+    // Focus the window under the pointer after a short hover
+    if get_config().tiling.focus_follows_mouse {
+        super::events::focus_follows_mouse::start();
+    }
+
     // Initialize the border system (connects to JankyBorders if available)
     if !borders::init() {
         tracing::warn!("tiling: borders initialization failed (JankyBorders may not be installed)");
@@ -315,50 +396,46 @@ fn initialize_state(handle: &StateActorHandle) {
     track_existing_windows(handle);
 }
 
+/// Pauses or resumes tiling when the accessibility permission changes.
+///
+/// Called from the permission watcher thread. Resuming rediscovers
+/// applications and windows on the main thread, which reapplies layouts.
+fn on_accessibility_changed(granted: bool) {
+    if granted {
+        tracing::info!("tiling: accessibility permission restored, resuming");
+        crate::utils::thread::dispatch_on_main(|| {
+            super::events::observer::refresh_observers();
+            if let Some(handle) = get_handle() {
+                track_existing_windows(handle);
+            }
+        });
+    } else {
+        tracing::warn!("tiling: accessibility permission revoked, pausing until it is granted");
+    }
+
+    emit_accessibility_changed(granted);
+}
+
 // ============================================================================
 // Window Tracking
 // ============================================================================
 
-/// Tracks all existing windows at startup.
+/// Builds the creation info of the windows tiling should track.
 ///
-/// Enumerates all windows using the AX-first approach and sends
-/// a batch `BatchWindowsCreated` message to the actor.
-/// Also sends a `WindowFocused` message for the currently focused window,
-/// and an `InitComplete` message to trigger initial layouts.
-fn track_existing_windows(handle: &StateActorHandle) {
+/// Scans each app's tabs first so that tabs are left to the tab registry, and
+/// skips system windows that shouldn't be tiled.
+fn trackable_windows(
+    windows: &[super::window::WindowInfo],
+) -> Vec<super::actor::WindowCreatedInfo> {
     use super::actor::WindowCreatedInfo;
     use super::rules::should_tile_window;
-    use super::window::{get_all_windows_including_hidden, get_focused_window_id};
-
-    tracing::debug!("tiling: tracking existing windows...");
-
-    // Get the currently focused window ID first (before enumeration)
-    let focused_window_id = get_focused_window_id();
-    tracing::trace!("tiling: system focused window id = {focused_window_id:?}");
-
-    // Enumerate all windows including hidden ones
-    let windows = get_all_windows_including_hidden();
-
-    tracing::debug!("Found {} windows from system", windows.len());
-    for w in &windows {
-        tracing::trace!(
-            "  - id={}, pid={}, app='{}', title='{}', minimized={}, hidden={}",
-            w.id,
-            w.pid,
-            w.app_name,
-            w.title,
-            w.is_minimized,
-            w.is_hidden
-        );
-    }
 
-    // Collect all trackable windows
     let mut window_infos: Vec<WindowCreatedInfo> = Vec::new();
 
     // Group windows by PID for tab detection
     // Collect unique PIDs and scan for tabs
     let mut pids_seen: std::collections::HashSet<i32> = std::collections::HashSet::new();
-    for window in &windows {
+    for window in windows {
         if should_tile_window(&window.bundle_id, &window.app_name) {
             pids_seen.insert(window.pid);
         }
@@ -369,7 +446,7 @@ fn track_existing_windows(handle: &StateActorHandle) {
         crate::modules::tiling::tabs::scan_and_register_tabs_for_app(*pid);
     }
 
-    for window in &windows {
+    for window in windows {
         // Filter out system apps that shouldn't be tiled
         if !should_tile_window(&window.bundle_id, &window.app_name) {
             tracing::trace!(
@@ -392,6 +469,8 @@ fn track_existing_windows(handle: &StateActorHandle) {
             app_id: window.bundle_id.clone(),
             app_name: window.app_name.clone(),
             title: window.title.clone(),
+            role: window.role.clone(),
+            subrole: window.subrole.clone(),
             frame: window.frame,
             is_minimized: window.is_minimized,
             is_fullscreen: window.is_fullscreen,
@@ -403,15 +482,64 @@ fn track_existing_windows(handle: &StateActorHandle) {
         window_infos.push(info);
     }
 
-    let tracked_count = window_infos.len();
+    window_infos
+}
 
-    // Also track these windows in the event processor for destroy detection
-    // This is necessary because BatchWindowsCreated bypasses the processor
+/// Registers windows with the event processor for destroy detection.
+///
+/// Needed for windows tracked in a batch, which bypasses the processor.
+fn track_for_destroy_detection(window_infos: &[super::actor::WindowCreatedInfo]) {
     if let Some(processor) = get_processor() {
         let window_pids: Vec<(u32, i32)> =
             window_infos.iter().map(|w| (w.window_id, w.pid)).collect();
         processor.track_windows_for_destroy_detection(&window_pids);
     }
+}
+
+/// Tracks all existing windows at startup.
+///
+/// Enumerates all windows using the AX-first approach and sends
+/// a batch `BatchWindowsCreated` message to the actor.
+/// Also sends a `WindowFocused` message for the currently focused window,
+/// and an `InitComplete` message to trigger initial layouts.
+///
+/// Windows using the `osascript` fallback are tracked afterwards, from a
+/// background thread, as reading their frames would block the main thread.
+fn track_existing_windows(handle: &StateActorHandle) {
+    use super::rules::is_app_ignored;
+    use super::window::{get_all_windows_deferring_fallback, get_focused_window_id};
+
+    tracing::debug!("tiling: tracking existing windows...");
+
+    // Get the currently focused window ID first (before enumeration)
+    let focused_window_id = get_focused_window_id();
+    tracing::trace!("tiling: system focused window id = {focused_window_id:?}");
+
+    // Enumerate all windows including hidden ones, leaving ignored apps alone
+    let ignore_apps = &get_config().tiling.ignore_apps;
+    let (mut windows, mut pending) = get_all_windows_deferring_fallback();
+    windows.retain(|w| !is_app_ignored(ignore_apps, &w.bundle_id, &w.app_name));
+    pending.retain(|w| !is_app_ignored(ignore_apps, &w.bundle_id, &w.app_name));
+
+    tracing::debug!("Found {} windows from system", windows.len());
+    for w in &windows {
+        tracing::trace!(
+            "  - id={}, pid={}, app='{}', title='{}', minimized={}, hidden={}",
+            w.id,
+            w.pid,
+            w.app_name,
+            w.title,
+            w.is_minimized,
+            w.is_hidden
+        );
+    }
+
+    let window_infos = trackable_windows(&windows);
+
+    let tracked_count = window_infos.len();
+
+    // Also track these windows in the event processor for destroy detection
+    track_for_destroy_detection(&window_infos);
 
     // Send batch message (no individual layout notifications)
     if !window_infos.is_empty()
@@ -437,6 +565,34 @@ fn track_existing_windows(handle: &StateActorHandle) {
     if let Err(e) = handle.send(StateMessage::InitComplete) {
         tracing::error!("tiling: failed to send InitComplete: {e}");
     }
+
+    if !pending.is_empty() {
+        track_fallback_windows(handle.clone(), pending);
+    }
+}
+
+/// Tracks windows whose frame can only be read through the `osascript`
+/// fallback, once their frames are known.
+///
+/// Runs on a background thread and tracks the windows as a batch, like the
+/// other windows at startup, then lays out the visible workspaces again.
+fn track_fallback_windows(handle: StateActorHandle, windows: Vec<super::window::WindowInfo>) {
+    use super::window::discover_fallback_frames;
+
+    crate::utils::thread::spawn_named_thread("tiling-frame-discovery", move || {
+        let window_infos = trackable_windows(&discover_fallback_frames(windows));
+        track_for_destroy_detection(&window_infos);
+
+        if window_infos.is_empty() {
+            return;
+        }
+        if let Err(e) = handle.send(StateMessage::BatchWindowsCreated(window_infos)) {
+            tracing::error!("tiling: failed to send BatchWindowsCreated: {e}");
+        }
+        if let Err(e) = handle.send(StateMessage::InitComplete) {
+            tracing::error!("tiling: failed to send InitComplete: {e}");
+        }
+    });
 }
 
 // ============================================================================
@@ -460,16 +616,27 @@ pub fn get_app_handle() -> Option<tauri::AppHandle> {
 // Event Emission Helpers
 // ============================================================================
 
-/// Emits a workspace changed event to the frontend.
+/// Emits a workspace changed event to the frontend and IPC subscribers.
 pub fn emit_workspace_changed(workspace: &str, screen: &str, previous_workspace: Option<&str>) {
+    let payload = serde_json::json!({
+        "workspace": workspace,
+        "screen": screen,
+        "previousWorkspace": previous_workspace,
+    });
+
+    ipc_socket::publish_event(ipc_socket::topics::WORKSPACE_SWITCHED, &payload);
+
+    if let Some(handle) = get_app_handle() {
+        let _ = handle.emit(events::tiling::WORKSPACE_CHANGED, payload);
+    }
+}
+
+/// Emits an accessibility permission change to the frontend.
+fn emit_accessibility_changed(granted: bool) {
     if let Some(handle) = get_app_handle() {
         let _ = handle.emit(
-            events::tiling::WORKSPACE_CHANGED,
-            serde_json::json!({
-                "workspace": workspace,
-                "screen": screen,
-                "previousWorkspace": previous_workspace,
-            }),
+            events::tiling::ACCESSIBILITY_CHANGED,
+            serde_json::json!({ "granted": granted }),
         );
     }
 }
@@ -540,6 +707,21 @@ pub fn emit_window_title_changed(window_id: u32, title: &str) {
     }
 }
 
+/// Emits a tabs changed event to the frontend.
+///
+/// Sent for workspaces in tabbed layout; an empty list clears the tab strip.
+pub fn emit_tabs_changed(workspace: &str, tabs: &[super::layout::LayoutTab]) {
+    if let Some(handle) = get_app_handle() {
+        let _ = handle.emit(
+            events::tiling::TABS_CHANGED,
+            serde_json::json!({
+                "workspace": workspace,
+                "tabs": tabs,
+            }),
+        );
+    }
+}
+
 /// Emits a workspace windows changed event to the frontend.
 ///
 /// This is called when windows in a workspace change (added, removed, minimized, etc.).
@@ -559,7 +741,7 @@ pub fn emit_workspace_windows_changed(workspace: &str, window_ids: &[u32]) {
 // IPC Query Handler
 // ============================================================================
 
-use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
+use crate::utils::ipc_socket::{self, IpcQuery, IpcResponse};
 
 /// Handles IPC queries for tiling v2.
 ///
@@ -577,7 +759,7 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
         IpcQuery::V2Enabled => Some(IpcResponse::success(is_initialized() && is_enabled())),
 
         // Standard queries - handle when v2 is enabled
-        IpcQuery::Screens => handle_screens_query(),
+        IpcQuery::Screens { screen } => handle_screens_query(screen.as_deref()),
 
         IpcQuery::Workspaces { screen, focused_screen } => {
             handle_workspaces_query(screen.as_deref(), *focused_screen)
@@ -588,16 +770,71 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
             workspace,
             focused_screen,
             focused_workspace,
+            sort,
             ..
         } => handle_windows_query(
             screen.as_deref(),
             workspace.as_deref(),
             *focused_screen,
             *focused_workspace,
+            sort.as_deref(),
         ),
 
         IpcQuery::Apps => handle_apps_query(),
 
+        IpcQuery::Focused => handle_focused_query(),
+
+        IpcQuery::History { limit } => handle_history_query(*limit),
+
+        IpcQuery::Tree { workspace } => handle_tree_query(workspace.as_deref()),
+
+        IpcQuery::DryRunLayout { layout, window_ids, frame } => {
+            Some(handle_dry_run_layout_query(layout, window_ids, *frame))
+        }
+
+        IpcQuery::ResizeWindow { dimension, delta } => {
+            handle_resize_window_query(dimension, *delta)
+        }
+
+        IpcQuery::ResizeWindowStep { dimension, grow } => {
+            handle_resize_window_query(dimension, resize_step_delta(*grow))
+        }
+
+        IpcQuery::SetSplitRatio { index, ratio } => handle_set_split_ratio_query(*index, *ratio),
+
+        IpcQuery::PlaceWindow { placement } => handle_place_window_query(placement),
+        IpcQuery::ArrangeFloating { arrangement } => handle_arrange_floating_query(arrangement),
+
+        IpcQuery::FocusWindowIndex { index, wraps } => {
+            handle_focus_window_index_query(*index, *wraps)
+        }
+
+        IpcQuery::MinimizeWindow { minimized } => handle_minimize_window_query(*minimized),
+
+        IpcQuery::SendWindow { workspace, follow } => handle_send_window_query(workspace, *follow),
+
+        IpcQuery::CycleWorkspace { direction } => handle_cycle_workspace_query(direction),
+
+        IpcQuery::FocusLastWorkspace => handle_focus_last_workspace_query(),
+
+        IpcQuery::CreateWorkspace { name } => handle_create_workspace_query(name),
+
+        IpcQuery::RenameWorkspace { workspace, name } => {
+            handle_rename_workspace_query(workspace.as_deref(), name)
+        }
+
+        IpcQuery::MergeWorkspace { target, follow } => {
+            handle_merge_workspace_query(target, *follow)
+        }
+
+        IpcQuery::CopyLayout { workspace, swap } => handle_copy_layout_query(workspace, *swap),
+
+        IpcQuery::ClaimApp { claim } => handle_claim_app_query(*claim),
+
+        IpcQuery::RestoreWindows => handle_restore_windows_query(),
+        IpcQuery::SetGapPreset { preset } => handle_set_gap_preset_query(preset),
+        IpcQuery::Retile => handle_retile_query(),
+
         IpcQuery::V2State => {
             if !is_initialized() {
                 return Some(IpcResponse::error("Tiling v2 not initialized"));
@@ -732,6 +969,7 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
                             "layout": format!("{layout:?}").to_lowercase(),
                             "isVisible": ws.is_visible,
                             "isFocused": ws.is_focused,
+                            "isScratchpad": ws.is_scratchpad,
                             "windowCount": ws.window_ids.len(),
                             "windowIds": ws.window_ids,
                         })
@@ -770,6 +1008,7 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
                     .unwrap_or_default();
 
                 // Filter by workspace if specified
+                let on_top_default = get_config().tiling.floating_always_on_top;
                 let filtered_windows: Vec<_> = windows
                     .iter()
                     .filter(|w| {
@@ -782,6 +1021,8 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
                             "appId": w.app_id,
                             "appName": w.app_name,
                             "title": w.title,
+                            "role": w.role,
+                            "subrole": w.subrole,
                             "workspaceId": w.workspace_id.to_string(),
                             "frame": {
                                 "x": w.frame.x,
@@ -792,6 +1033,8 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
                             "isMinimized": w.is_minimized,
                             "isFullscreen": w.is_fullscreen,
                             "isFloating": w.is_floating,
+                            "isSticky": w.is_sticky,
+                            "isAlwaysOnTop": w.is_kept_on_top(on_top_default),
                             "isFocused": focused_window_id == Some(w.id),
                         })
                     })
@@ -800,6 +1043,37 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
                 Some(IpcResponse::success(filtered_windows))
             })
         }
+
+        // Handled by the wallpaper module
+        IpcQuery::WallpaperCurrent => None,
+
+        // Handled by the media bar component
+        IpcQuery::MediaControl { .. } => None,
+
+        // Handled by the keep-awake bar component
+        IpcQuery::KeepAwake { .. } => None,
+
+        // Handled by the focus mode module
+        IpcQuery::FocusMode { .. } => None,
+
+        // Handled by the bar module
+        IpcQuery::Bar { .. } => None,
+
+        // Handled by the capture module
+        IpcQuery::Capture { .. } => None,
+
+        // Handled by the audio module
+        IpcQuery::AudioVolume { .. }
+        | IpcQuery::AudioMute { .. }
+        | IpcQuery::AudioDevices
+        | IpcQuery::AudioSetDevice { .. }
+        | IpcQuery::AudioStatus => None,
+
+        // Handled by the socket server (keeps the connection open)
+        IpcQuery::Subscribe { .. } => None,
+
+        // Handled by the socket server, which runs each query through here
+        IpcQuery::Batch { .. } => None,
     }
 }
 
@@ -808,7 +1082,10 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
 // ============================================================================
 
 /// Handles the standard `screens` query using v2 state.
-fn handle_screens_query() -> Option<IpcResponse> {
+///
+/// With a `screen` filter ("main", "secondary" or a name), only that screen
+/// is returned, as an object rather than an array.
+fn handle_screens_query(screen: Option<&str>) -> Option<IpcResponse> {
     if !is_initialized() {
         return Some(IpcResponse::error("Tiling v2 not initialized"));
     }
@@ -824,36 +1101,85 @@ fn handle_screens_query() -> Option<IpcResponse> {
             .and_then(super::actor::QueryResult::into_screens)
             .unwrap_or_default();
 
-        // Format response to match v1 API format
-        let screen_infos: Vec<_> = screens
-            .iter()
-            .map(|s| {
-                serde_json::json!({
-                    "id": s.id,
-                    "name": s.name,
-                    "isMain": s.is_main,
-                    "isBuiltin": s.is_builtin,
-                    "scaleFactor": s.scale_factor,
-                    "frame": {
-                        "x": s.frame.x,
-                        "y": s.frame.y,
-                        "width": s.frame.width,
-                        "height": s.frame.height,
-                    },
-                    "visibleFrame": {
-                        "x": s.visible_frame.x,
-                        "y": s.visible_frame.y,
-                        "width": s.visible_frame.width,
-                        "height": s.visible_frame.height,
-                    },
-                })
-            })
-            .collect();
+        let focused_screen_id = handle
+            .query(super::actor::StateQuery::GetFocusState)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_focus)
+            .and_then(|focus| focus.focused_screen_id);
+
+        if let Some(name) = screen {
+            return Some(
+                match super::actor::handlers::find_screen_by_name(&screens, name) {
+                    Some(screen) => IpcResponse::success(screen_json(screen, focused_screen_id)),
+                    None => IpcResponse::error(format!("Screen '{name}' not found")),
+                },
+            );
+        }
+
+        let screen_infos: Vec<_> =
+            screens.iter().map(|s| screen_json(s, focused_screen_id)).collect();
 
         Some(IpcResponse::success(screen_infos))
     })
 }
 
+/// Formats a screen in the v1 API format.
+///
+/// `visibleFrame` is the usable area: the screen minus the menu bar, the
+/// Dock and `tiling.reserved`. `isFocused` marks the screen with focus.
+fn screen_json(s: &super::state::Screen, focused_screen_id: Option<u32>) -> serde_json::Value {
+    serde_json::json!({
+        "id": s.id,
+        "name": s.name,
+        "isMain": s.is_main,
+        "isBuiltin": s.is_builtin,
+        "isFocused": focused_screen_id == Some(s.id),
+        "scaleFactor": s.scale_factor,
+        "frame": {
+            "x": s.frame.x,
+            "y": s.frame.y,
+            "width": s.frame.width,
+            "height": s.frame.height,
+        },
+        "visibleFrame": {
+            "x": s.visible_frame.x,
+            "y": s.visible_frame.y,
+            "width": s.visible_frame.width,
+            "height": s.visible_frame.height,
+        },
+    })
+}
+
+/// Serializes a workspace for the `workspaces` query.
+///
+/// `isFocused` and `isVisible` are per-screen flags: every screen has a visible
+/// workspace. `isFocusedGlobally` marks the one workspace that has focus across
+/// all screens, and `isOnFocusedScreen` the workspaces on the screen with focus.
+fn workspace_json(
+    ws: &super::state::Workspace,
+    screen_name: &str,
+    focused_workspace_id: Option<uuid::Uuid>,
+    focused_screen_id: Option<u32>,
+) -> serde_json::Value {
+    let layout = ws.layout;
+    serde_json::json!({
+        "id": ws.id.to_string(),
+        "name": ws.name,
+        "screenName": screen_name,
+        "layout": format!("{layout:?}").to_lowercase(),
+        "isVisible": ws.is_visible,
+        "isFocused": ws.is_focused,
+        "isFocusedGlobally": focused_workspace_id == Some(ws.id),
+        "isVisibleOnScreen": ws.is_visible,
+        "isOnFocusedScreen": focused_screen_id == Some(ws.screen_id),
+        "isScratchpad": ws.is_scratchpad,
+        "windowCount": ws.window_ids.len(),
+        "splitRatios": ws.split_ratios,
+        "claimedApps": ws.claimed_app_ids,
+    })
+}
+
 /// Handles the standard `workspaces` query using v2 state.
 #[allow(clippy::too_many_lines)]
 fn handle_workspaces_query(screen: Option<&str>, focused_screen: bool) -> Option<IpcResponse> {
@@ -889,16 +1215,14 @@ fn handle_workspaces_query(screen: Option<&str>, focused_screen: bool) -> Option
             .and_then(super::actor::QueryResult::into_screens)
             .unwrap_or_default();
 
-        // Determine focused screen ID
-        let focused_screen_id = if focused_screen {
-            focus.and_then(|f| {
-                f.focused_workspace_id.and_then(|ws_id| {
-                    workspaces.iter().find(|ws| ws.id == ws_id).map(|ws| ws.screen_id)
-                })
+        // Without a recorded focused screen, the screen of the globally focused
+        // workspace is the focused screen
+        let focused_workspace_id = focus.as_ref().and_then(|f| f.focused_workspace_id);
+        let focused_screen_id = focus.and_then(|f| f.focused_screen_id).or_else(|| {
+            focused_workspace_id.and_then(|ws_id| {
+                workspaces.iter().find(|ws| ws.id == ws_id).map(|ws| ws.screen_id)
             })
-        } else {
-            None
-        };
+        });
 
         // Filter workspaces
         let filtered_workspaces: Vec<_> = workspaces
@@ -921,10 +1245,7 @@ fn handle_workspaces_query(screen: Option<&str>, focused_screen: bool) -> Option
                 }
                 // Filter by focused screen if requested
                 if focused_screen {
-                    if let Some(focused_id) = focused_screen_id {
-                        return ws.screen_id == focused_id;
-                    }
-                    return false;
+                    return focused_screen_id == Some(ws.screen_id);
                 }
                 true
             })
@@ -935,16 +1256,7 @@ fn handle_workspaces_query(screen: Option<&str>, focused_screen: bool) -> Option
                     .find(|s| s.id == ws.screen_id)
                     .map_or_else(|| format!("screen-{screen_id}"), |s| s.name.clone());
 
-                let layout = ws.layout;
-                serde_json::json!({
-                    "id": ws.id.to_string(),
-                    "name": ws.name,
-                    "screenName": screen_name,
-                    "layout": format!("{layout:?}").to_lowercase(),
-                    "isVisible": ws.is_visible,
-                    "isFocused": ws.is_focused,
-                    "windowCount": ws.window_ids.len(),
-                })
+                workspace_json(ws, &screen_name, focused_workspace_id, focused_screen_id)
             })
             .collect();
 
@@ -959,11 +1271,22 @@ fn handle_windows_query(
     workspace: Option<&str>,
     focused_screen: bool,
     focused_workspace: bool,
+    sort: Option<&str>,
 ) -> Option<IpcResponse> {
     if !is_initialized() {
         return Some(IpcResponse::error("Tiling v2 not initialized"));
     }
 
+    let sort = match sort.map(WindowSort::parse) {
+        Some(Some(sort)) => Some(sort),
+        Some(None) => {
+            return Some(IpcResponse::error(
+                "Invalid sort key. Expected 'app', 'title', 'workspace' or 'recent'",
+            ));
+        }
+        None => None,
+    };
+
     let handle = get_handle()?;
     let screen_filter = screen.map(ToString::to_string);
     let workspace_filter = workspace.map(ToString::to_string);
@@ -1009,7 +1332,7 @@ fn handle_windows_query(
             .and_then(|ws_id| workspaces.iter().find(|ws| ws.id == ws_id).map(|ws| ws.screen_id));
 
         // Filter windows
-        let filtered_windows: Vec<_> = windows
+        let mut filtered_windows: Vec<_> = windows
             .iter()
             .filter(|w| {
                 // Get window's workspace
@@ -1069,6 +1392,24 @@ fn handle_windows_query(
 
                 true
             })
+            .collect();
+
+        if let Some(sort) = sort {
+            let recent = if sort == WindowSort::Recent {
+                handle
+                    .query(super::actor::StateQuery::GetRecentWindowIds)
+                    .await
+                    .ok()
+                    .and_then(super::actor::QueryResult::into_window_ids)
+                    .unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+            sort_windows(&mut filtered_windows, sort, &workspaces, &recent);
+        }
+
+        let filtered_windows: Vec<_> = filtered_windows
+            .into_iter()
             .map(|w| {
                 let workspace_name = workspaces
                     .iter()
@@ -1082,6 +1423,8 @@ fn handle_windows_query(
                     "appId": w.app_id,
                     "appName": w.app_name,
                     "title": w.title,
+                    "role": w.role,
+                    "subrole": w.subrole,
                     "workspace": workspace_name,
                     "frame": {
                         "x": w.frame.x,
@@ -1092,6 +1435,8 @@ fn handle_windows_query(
                     "isMinimized": w.is_minimized,
                     "isFullscreen": w.is_fullscreen,
                     "isFloating": w.is_floating,
+                    "isSticky": w.is_sticky,
+                    "isAlwaysOnTop": w.is_kept_on_top(get_config().tiling.floating_always_on_top),
                     "isFocused": focused_window_id == Some(w.id),
                 })
             })
@@ -1101,35 +1446,868 @@ fn handle_windows_query(
     })
 }
 
-/// Handles the `apps` query - returns all running applications (excluding ignored apps).
-#[allow(clippy::unnecessary_wraps)] // Matches other handler signatures
-fn handle_apps_query() -> Option<IpcResponse> {
-    use super::rules::should_tile_window;
-    use super::window::get_running_apps;
+/// Sort order for the `windows` query (`tiling query windows --sort`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum WindowSort {
+    /// Sort by app name, then title.
+    App,
+    /// Sort by window title.
+    Title,
+    /// Sort by workspace, in workspace order.
+    Workspace,
+    /// Sort by focus history, most recently focused first.
+    Recent,
+}
 
-    // Get all running apps
-    let apps = get_running_apps();
+impl WindowSort {
+    /// Parses a sort key sent over IPC.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "app" => Some(Self::App),
+            "title" => Some(Self::Title),
+            "workspace" => Some(Self::Workspace),
+            "recent" => Some(Self::Recent),
+            _ => None,
+        }
+    }
 
-    // Filter out apps that match ignore rules and format response
-    let app_infos: Vec<_> = apps
-        .iter()
-        .filter(|app| should_tile_window(&app.bundle_id, &app.name))
-        .map(|app| {
-            serde_json::json!({
-                "pid": app.pid,
-                "name": app.name,
-                "bundleId": app.bundle_id,
-                "isHidden": app.is_hidden,
-            })
-        })
-        .collect();
+    /// Returns the sort key as sent over IPC.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::App => "app",
+            Self::Title => "title",
+            Self::Workspace => "workspace",
+            Self::Recent => "recent",
+        }
+    }
+}
 
-    Some(IpcResponse::success(app_infos))
+/// Sorts windows in place by the given key.
+///
+/// The sort is stable, so windows with equal keys keep their tracking order.
+/// For [`WindowSort::Recent`], `recent` holds window IDs most recent first;
+/// windows that were never focused come last.
+fn sort_windows(
+    windows: &mut [&super::state::Window],
+    sort: WindowSort,
+    workspaces: &[super::state::Workspace],
+    recent: &[u32],
+) {
+    match sort {
+        WindowSort::App => {
+            windows.sort_by_cached_key(|w| (w.app_name.to_lowercase(), w.title.to_lowercase()))
+        }
+        WindowSort::Title => windows.sort_by_cached_key(|w| w.title.to_lowercase()),
+        WindowSort::Workspace => windows.sort_by_key(|w| {
+            workspaces.iter().position(|ws| ws.id == w.workspace_id).unwrap_or(usize::MAX)
+        }),
+        WindowSort::Recent => {
+            windows.sort_by_key(|w| recent.iter().position(|&id| id == w.id).unwrap_or(usize::MAX))
+        }
+    }
 }
 
-// ============================================================================
-// Mouse Up Callback (Drag Completion)
-// ============================================================================
+/// Handles the `focused` query - returns the focused window with its workspace and screen.
+fn handle_focused_query() -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let focus = handle
+            .query(super::actor::StateQuery::GetFocusState)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_focus)
+            .unwrap_or_default();
+
+        let window = match focus.focused_window_id {
+            Some(window_id) => handle
+                .query(super::actor::StateQuery::GetWindow { id: window_id })
+                .await
+                .ok()
+                .and_then(super::actor::QueryResult::into_window)
+                .flatten(),
+            None => None,
+        };
+
+        let workspaces = handle
+            .query(super::actor::StateQuery::GetAllWorkspaces)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspaces)
+            .unwrap_or_default();
+
+        let screens = handle
+            .query(super::actor::StateQuery::GetAllScreens)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_screens)
+            .unwrap_or_default();
+
+        Some(IpcResponse::success(focused_context(
+            &focus,
+            window.as_ref(),
+            &workspaces,
+            &screens,
+        )))
+    })
+}
+
+/// Handles the `history` query - returns recently focused windows, most
+/// recent first.
+fn handle_history_query(limit: Option<usize>) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let recent = handle
+            .query(super::actor::StateQuery::GetRecentWindowIds)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_window_ids)
+            .unwrap_or_default();
+
+        let windows = handle
+            .query(super::actor::StateQuery::GetAllWindows)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_windows)
+            .unwrap_or_default();
+
+        let workspaces = handle
+            .query(super::actor::StateQuery::GetAllWorkspaces)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspaces)
+            .unwrap_or_default();
+
+        Some(IpcResponse::success(focus_history_entries(
+            &recent,
+            &windows,
+            &workspaces,
+            limit,
+        )))
+    })
+}
+
+/// Builds the `history` query response.
+///
+/// Windows that closed since they were focused are left out, so the list
+/// only holds windows that still exist.
+fn focus_history_entries(
+    recent: &[u32],
+    windows: &[super::state::Window],
+    workspaces: &[super::state::Workspace],
+    limit: Option<usize>,
+) -> Vec<serde_json::Value> {
+    recent
+        .iter()
+        .filter_map(|id| windows.iter().find(|w| w.id == *id))
+        .take(limit.unwrap_or(usize::MAX))
+        .map(|w| {
+            let workspace = workspaces.iter().find(|ws| ws.id == w.workspace_id);
+            serde_json::json!({
+                "id": w.id,
+                "appId": w.app_id,
+                "appName": w.app_name,
+                "title": w.title,
+                "workspace": workspace.map(|ws| ws.name.clone()),
+            })
+        })
+        .collect()
+}
+
+/// Handles the `tree` query - returns the dwindle split tree of a workspace.
+///
+/// Uses the named workspace, or the focused one when no name is given. The
+/// tree is `null` for workspaces that don't use the dwindle layout.
+fn handle_tree_query(workspace: Option<&str>) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let query = match workspace {
+            Some(name) => super::actor::StateQuery::GetWorkspaceByName { name: name.to_string() },
+            None => super::actor::StateQuery::GetFocusedWorkspace,
+        };
+
+        let Some(workspace) = handle
+            .query(query)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspace)
+            .flatten()
+        else {
+            return Some(IpcResponse::error(workspace.map_or_else(
+                || "No focused workspace".to_string(),
+                |name| format!("Workspace '{name}' not found"),
+            )));
+        };
+
+        let tree = handle
+            .query(super::actor::StateQuery::GetLayoutTree { workspace_id: workspace.id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_layout_tree)
+            .flatten();
+
+        Some(IpcResponse::success(serde_json::json!({
+            "workspace": workspace.name,
+            "layout": super::commands::layout_to_string_pub(workspace.layout),
+            "tree": tree,
+        })))
+    })
+}
+
+/// Builds the `focused` query response.
+///
+/// The workspace comes from the focused window when there is one, falling back
+/// to the focused workspace. Missing parts are `null`.
+fn focused_context(
+    focus: &super::state::FocusState,
+    window: Option<&super::state::Window>,
+    workspaces: &[super::state::Workspace],
+    screens: &[super::state::Screen],
+) -> serde_json::Value {
+    let workspace_id = window.map(|w| w.workspace_id).or(focus.focused_workspace_id);
+    let workspace = workspace_id.and_then(|id| workspaces.iter().find(|ws| ws.id == id));
+    let screen_id = workspace.map(|ws| ws.screen_id).or(focus.focused_screen_id);
+    let screen = screen_id.and_then(|id| screens.iter().find(|s| s.id == id));
+
+    serde_json::json!({
+        "window": window.map(|w| serde_json::json!({
+            "id": w.id,
+            "pid": w.pid,
+            "appId": w.app_id,
+            "appName": w.app_name,
+            "title": w.title,
+            "frame": {
+                "x": w.frame.x,
+                "y": w.frame.y,
+                "width": w.frame.width,
+                "height": w.frame.height,
+            },
+            "isFloating": w.is_floating,
+            "isSticky": w.is_sticky,
+            "isAlwaysOnTop": w.is_kept_on_top(get_config().tiling.floating_always_on_top),
+        })),
+        "workspace": workspace.map(|ws| serde_json::json!({
+            "name": ws.name,
+            "layout": super::commands::layout_to_string_pub(ws.layout),
+        })),
+        "screen": screen.map(|s| serde_json::json!({
+            "name": s.name,
+            "isMain": s.is_main,
+        })),
+    })
+}
+
+/// Handles the `apps` query - returns all running applications (excluding ignored apps).
+#[allow(clippy::unnecessary_wraps)] // Matches other handler signatures
+fn handle_apps_query() -> Option<IpcResponse> {
+    use super::rules::{is_app_ignored, should_tile_window};
+    use super::window::get_running_apps;
+
+    // Get all running apps
+    let apps = get_running_apps();
+    let ignore_apps = &get_config().tiling.ignore_apps;
+
+    // Filter out apps that match ignore rules and format response
+    let app_infos: Vec<_> = apps
+        .iter()
+        .filter(|app| should_tile_window(&app.bundle_id, &app.name))
+        .filter(|app| !is_app_ignored(ignore_apps, &app.bundle_id, &app.name))
+        .map(|app| {
+            serde_json::json!({
+                "pid": app.pid,
+                "name": app.name,
+                "bundleId": app.bundle_id,
+                "isHidden": app.is_hidden,
+            })
+        })
+        .collect();
+
+    Some(IpcResponse::success(app_infos))
+}
+
+/// Handles the `resizeWindow` command - resizes the focused window and reports the outcome.
+///
+/// Returns the adjusted split ratio on success. Layouts without adjustable
+/// splits (monocle, master, floating) produce a distinct "ignored" error so
+/// scripts can tell a no-op apart from a real failure.
+fn handle_resize_window_query(dimension: &str, delta: i32) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, ResizeDimension, ResizeOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let Some(dim) = ResizeDimension::parse(dimension) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid resize dimension '{dimension}'. Must be 'width' or 'height'."
+        )));
+    };
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    let outcome = match rt.block_on(handle.resize_focused_window_with_outcome(dim, delta)) {
+        Ok(outcome) => outcome,
+        Err(e) => return Some(IpcResponse::error(e.to_string())),
+    };
+
+    Some(match outcome {
+        Ok(ResizeOutcome::Resized { ratio_index, ratio }) => {
+            IpcResponse::success(serde_json::json!({
+                "dimension": dim.as_str(),
+                "delta": delta,
+                "ratioIndex": ratio_index,
+                "ratio": ratio,
+            }))
+        }
+        Ok(ResizeOutcome::Ignored { layout }) => IpcResponse::error(format!(
+            "Resize ignored: {layout:?} layout has no adjustable splits"
+        )),
+        Err(CommandFailed { reason }) => IpcResponse::error(format!("Resize failed: {reason}")),
+    })
+}
+
+/// Returns the pixel delta for one `tiling.resizeStep` grow or shrink.
+fn resize_step_delta(grow: bool) -> i32 {
+    let step = i32::try_from(get_config().tiling.resize_step).unwrap_or(i32::MAX);
+    if grow { step } else { -step }
+}
+
+/// Handles the `setSplitRatio` command - sets a split ratio of the focused workspace.
+///
+/// Returns the stored (clamped) ratio, or an error when the layout has no
+/// splits or the index doesn't exist.
+fn handle_set_split_ratio_query(index: usize, ratio: f64) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, ResizeOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    if !ratio.is_finite() {
+        return Some(IpcResponse::error(format!(
+            "Invalid split ratio '{ratio}'. Must be between 0.0 and 1.0."
+        )));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    let outcome = match rt.block_on(handle.set_split_ratio(index, ratio)) {
+        Ok(outcome) => outcome,
+        Err(e) => return Some(IpcResponse::error(e.to_string())),
+    };
+
+    Some(match outcome {
+        Ok(ResizeOutcome::Resized { ratio_index, ratio }) => {
+            IpcResponse::success(serde_json::json!({
+                "index": ratio_index,
+                "ratio": ratio,
+            }))
+        }
+        Ok(ResizeOutcome::Ignored { layout }) => {
+            IpcResponse::error(format!("{layout:?} layout has no adjustable splits"))
+        }
+        Err(CommandFailed { reason }) => {
+            IpcResponse::error(format!("Setting split ratio failed: {reason}"))
+        }
+    })
+}
+
+/// Handles the `placeWindow` command - centers or maximizes the focused floating window.
+///
+/// Returns the window's new frame on success. Tiled windows are rejected
+/// since their frame is owned by the layout.
+fn handle_place_window_query(placement: &str) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, FloatingPlacement, PlaceWindowOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let Some(parsed) = FloatingPlacement::parse(placement) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid placement '{placement}'. Must be 'center' or 'maximize'."
+        )));
+    };
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    let outcome = match rt.block_on(handle.place_floating_window(parsed)) {
+        Ok(outcome) => outcome,
+        Err(e) => return Some(IpcResponse::error(e.to_string())),
+    };
+
+    Some(match outcome {
+        Ok(PlaceWindowOutcome::Placed { window_id, frame }) => {
+            IpcResponse::success(serde_json::json!({
+                "windowId": window_id,
+                "placement": parsed.as_str(),
+                "frame": frame,
+            }))
+        }
+        Err(CommandFailed { reason }) => IpcResponse::error(reason),
+    })
+}
+
+/// Handles the `arrangeFloating` command - cascades or tiles the floating
+/// windows of the focused workspace.
+///
+/// Returns how many windows were arranged. The windows stay floating.
+fn handle_arrange_floating_query(arrangement: &str) -> Option<IpcResponse> {
+    use super::actor::{ArrangeFloatingOutcome, CommandFailed, FloatingArrangement};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let Some(parsed) = FloatingArrangement::parse(arrangement) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid arrangement '{arrangement}'. Must be 'cascade' or 'grid'."
+        )));
+    };
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    let outcome = match rt.block_on(handle.arrange_floating_windows(parsed)) {
+        Ok(outcome) => outcome,
+        Err(e) => return Some(IpcResponse::error(e.to_string())),
+    };
+
+    Some(match outcome {
+        Ok(ArrangeFloatingOutcome::Arranged { count }) => IpcResponse::success(serde_json::json!({
+            "arrangement": parsed.as_str(),
+            "count": count,
+        })),
+        Err(CommandFailed { reason }) => IpcResponse::error(reason),
+    })
+}
+
+/// Handles the `focusWindowIndex` command - focuses the window at a 1-based
+/// position in the focused workspace.
+///
+/// Returns the focused window's ID and position. Out of range positions wrap
+/// around with `wraps` and are an error otherwise.
+fn handle_focus_window_index_query(index: usize, wraps: bool) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, FocusIndexOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(match rt.block_on(handle.focus_window_at(index, wraps)) {
+        Ok(Ok(FocusIndexOutcome::Focused { window_id, index })) => {
+            IpcResponse::success(serde_json::json!({ "windowId": window_id, "index": index }))
+        }
+        Ok(Err(CommandFailed { reason })) => IpcResponse::error(reason),
+        Err(e) => IpcResponse::error(e.to_string()),
+    })
+}
+
+/// Handles the `minimizeWindow` command - minimizes the focused window or
+/// restores a minimized window of the focused workspace.
+///
+/// Returns the ID of the window that was minimized or restored.
+fn handle_minimize_window_query(minimized: bool) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, MinimizeOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    let outcome = match rt.block_on(handle.set_minimized(minimized)) {
+        Ok(outcome) => outcome,
+        Err(e) => return Some(IpcResponse::error(e.to_string())),
+    };
+
+    Some(match outcome {
+        Ok(MinimizeOutcome::Done { window_id, minimized }) => {
+            IpcResponse::success(serde_json::json!({
+                "windowId": window_id,
+                "minimized": minimized,
+            }))
+        }
+        Err(CommandFailed { reason }) => IpcResponse::error(reason),
+    })
+}
+
+/// Handles the `sendWindow` command - sends the focused window to a workspace.
+///
+/// Returns the workspace and screen the window ended up on, and whether it is
+/// visible (`false` when it was hidden along with an off-screen workspace).
+fn handle_send_window_query(workspace: &str, follow: bool) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, SendWindowOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(
+        match rt.block_on(handle.send_window_to_workspace(workspace, follow)) {
+            Ok(Ok(SendWindowOutcome::Sent {
+                window_id,
+                workspace,
+                screen,
+                visible,
+            })) => IpcResponse::success(serde_json::json!({
+                "windowId": window_id,
+                "workspace": workspace,
+                "screen": screen,
+                "visible": visible,
+            })),
+            Ok(Err(CommandFailed { reason })) => {
+                IpcResponse::error(format!("Send window failed: {reason}"))
+            }
+            Err(e) => IpcResponse::error(e.to_string()),
+        },
+    )
+}
+
+/// Handles the `cycleWorkspace` command - switches workspace on the focused screen.
+///
+/// Returns the name of the workspace switched to, or an error when the
+/// focused screen has no other workspace to cycle to.
+fn handle_cycle_workspace_query(direction: &str) -> Option<IpcResponse> {
+    use super::actor::CycleDirection;
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let Some(direction) = CycleDirection::parse(direction) else {
+        return Some(IpcResponse::error(format!(
+            "Invalid cycle direction '{direction}'. Must be 'next' or 'prev'."
+        )));
+    };
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(match rt.block_on(handle.cycle_workspace(direction)) {
+        Ok(Some(name)) => IpcResponse::success(serde_json::json!({ "workspace": name })),
+        Ok(None) => IpcResponse::error("No other workspace on the focused screen"),
+        Err(e) => IpcResponse::error(e.to_string()),
+    })
+}
+
+/// Handles the `focusLastWorkspace` command - switches back to the previous workspace.
+///
+/// Returns the name of the workspace switched to, or an error when the
+/// focused screen has no previous workspace.
+fn handle_focus_last_workspace_query() -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(match rt.block_on(handle.focus_last_workspace()) {
+        Ok(Some(name)) => IpcResponse::success(serde_json::json!({ "workspace": name })),
+        Ok(None) => IpcResponse::error("No previous workspace on the focused screen"),
+        Err(e) => IpcResponse::error(e.to_string()),
+    })
+}
+
+/// Handles the `createWorkspace` command - creates a workspace on the focused screen.
+fn handle_create_workspace_query(name: &str) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(workspace_edit_response(
+        rt.block_on(handle.create_workspace(name)),
+    ))
+}
+
+/// Handles the `renameWorkspace` command - renames a workspace for the session.
+fn handle_rename_workspace_query(workspace: Option<&str>, name: &str) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(workspace_edit_response(
+        rt.block_on(handle.rename_workspace(workspace, name)),
+    ))
+}
+
+/// Handles the `mergeWorkspace` command - moves the focused workspace's windows into another.
+///
+/// Returns the source and target workspaces, how many windows were moved, and
+/// whether they are visible (`false` when the target workspace is hidden).
+fn handle_merge_workspace_query(target: &str, follow: bool) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, MergeWorkspaceOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(match rt.block_on(handle.merge_workspace(target, follow)) {
+        Ok(Ok(MergeWorkspaceOutcome::Merged { source, target, moved, visible })) => {
+            IpcResponse::success(serde_json::json!({
+                "source": source,
+                "target": target,
+                "moved": moved,
+                "visible": visible,
+            }))
+        }
+        Ok(Err(CommandFailed { reason })) => {
+            IpcResponse::error(format!("Merge workspace failed: {reason}"))
+        }
+        Err(e) => IpcResponse::error(e.to_string()),
+    })
+}
+
+/// Handles the `copyLayout` command - copies a workspace's layout and split
+/// ratios to the focused workspace, or swaps them between the two.
+///
+/// Returns both workspaces and the layout the focused workspace now uses.
+fn handle_copy_layout_query(workspace: &str, swap: bool) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, CopyLayoutOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(match rt.block_on(handle.copy_layout(workspace, swap)) {
+        Ok(Ok(CopyLayoutOutcome::Copied {
+            source,
+            target,
+            layout,
+            swapped,
+        })) => IpcResponse::success(serde_json::json!({
+            "source": source,
+            "target": target,
+            "layout": layout,
+            "swapped": swapped,
+        })),
+        Ok(Err(CommandFailed { reason })) => IpcResponse::error(reason),
+        Err(e) => IpcResponse::error(e.to_string()),
+    })
+}
+
+/// Converts the outcome of creating or renaming a workspace to an IPC response.
+fn workspace_edit_response(
+    outcome: Result<
+        super::actor::CommandOutcome<super::actor::WorkspaceEditOutcome>,
+        super::actor::ActorError,
+    >,
+) -> IpcResponse {
+    use super::actor::{CommandFailed, WorkspaceEditOutcome};
+
+    match outcome {
+        Ok(Ok(WorkspaceEditOutcome::Created { workspace, screen })) => {
+            IpcResponse::success(serde_json::json!({ "workspace": workspace, "screen": screen }))
+        }
+        Ok(Ok(WorkspaceEditOutcome::Renamed { from, to })) => {
+            IpcResponse::success(serde_json::json!({ "workspace": to, "previousName": from }))
+        }
+        Ok(Err(CommandFailed { reason })) => IpcResponse::error(reason),
+        Err(e) => IpcResponse::error(e.to_string()),
+    }
+}
+
+/// Handles a dry-run layout query.
+///
+/// Runs the layout math on synthetic windows and reports the frames, without
+/// touching the tracked state or any real window. Doesn't need the tiling
+/// manager to be running.
+fn handle_dry_run_layout_query(layout: &str, window_ids: &[u32], frame: [f64; 4]) -> IpcResponse {
+    use super::state::{LayoutType, Rect};
+
+    // CLI layout names are kebab-case ("split-vertical"), `LayoutType` is lowercase
+    let Ok(layout_type) =
+        serde_json::from_value::<LayoutType>(serde_json::json!(layout.replace('-', "")))
+    else {
+        return IpcResponse::error(format!("Invalid layout '{layout}'"));
+    };
+
+    let [x, y, width, height] = frame;
+    if width <= 0.0 || height <= 0.0 {
+        return IpcResponse::error("Frame width and height must be positive");
+    }
+
+    let frames: Vec<serde_json::Value> =
+        super::actor::dry_run_layout(layout_type, window_ids, Rect::new(x, y, width, height))
+            .into_iter()
+            .map(|(id, frame)| {
+                serde_json::json!({
+                    "windowId": id,
+                    "frame": {
+                        "x": frame.x,
+                        "y": frame.y,
+                        "width": frame.width,
+                        "height": frame.height,
+                    },
+                })
+            })
+            .collect();
+
+    IpcResponse::success(serde_json::json!({ "layout": layout, "frames": frames }))
+}
+
+/// Handles the `claimApp` command - routes the focused window's app to the
+/// focused workspace for the session, or releases the claim.
+fn handle_claim_app_query(claim: bool) -> Option<IpcResponse> {
+    use super::actor::{ClaimAppOutcome, CommandFailed};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    Some(match rt.block_on(handle.claim_app(claim)) {
+        Ok(Ok(ClaimAppOutcome::Claimed { workspace, app_id, app_name })) => {
+            IpcResponse::success(serde_json::json!({
+                "workspace": workspace,
+                "appId": app_id,
+                "appName": app_name,
+                "claimed": true,
+            }))
+        }
+        Ok(Ok(ClaimAppOutcome::Unclaimed { workspace, app_id, app_name })) => {
+            IpcResponse::success(serde_json::json!({
+                "workspace": workspace,
+                "appId": app_id,
+                "appName": app_name,
+                "claimed": false,
+            }))
+        }
+        Ok(Err(CommandFailed { reason })) => IpcResponse::error(reason),
+        Err(e) => IpcResponse::error(e.to_string()),
+    })
+}
+
+/// Handles a restore windows query.
+///
+/// Moves tracked windows back to their original frames and reports how many
+/// were restored.
+fn handle_restore_windows_query() -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    Some(match restore_original_frames() {
+        Some(restored) => IpcResponse::success(serde_json::json!({ "restored": restored })),
+        None => IpcResponse::error("Failed to query original window frames"),
+    })
+}
+
+/// Handles the `setGapPreset` command - switches the gaps of all workspaces
+/// to a `tiling.gapPresets` entry.
+///
+/// Returns the active preset, `null` after a reset.
+fn handle_set_gap_preset_query(preset: &str) -> Option<IpcResponse> {
+    use super::actor::{CommandFailed, GapPresetOutcome};
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    let outcome = match rt.block_on(handle.set_gap_preset(preset)) {
+        Ok(outcome) => outcome,
+        Err(e) => return Some(IpcResponse::error(e.to_string())),
+    };
+
+    Some(match outcome {
+        Ok(GapPresetOutcome::Applied { preset }) => {
+            IpcResponse::success(serde_json::json!({ "preset": preset }))
+        }
+        Err(CommandFailed { reason }) => IpcResponse::error(reason),
+    })
+}
+
+/// Handles the `retile` command - re-enumerates the system's windows, tracks
+/// the ones tiling missed, drops the ones that are gone and re-applies the
+/// layouts of all visible workspaces.
+///
+/// Returns how many windows were tracked and untracked.
+fn handle_retile_query() -> Option<IpcResponse> {
+    use super::rules::is_app_ignored;
+    use super::window::get_all_windows_including_hidden;
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+    if is_paused() {
+        return Some(IpcResponse::error(
+            "Tiling is paused until accessibility permission is granted",
+        ));
+    }
+
+    let handle = get_handle()?;
+
+    let windows = get_all_windows_including_hidden();
+    let window_ids: Vec<u32> = windows.iter().map(|w| w.id).collect();
+    let ignore_apps = &get_config().tiling.ignore_apps;
+    let windows: Vec<_> = windows
+        .into_iter()
+        .filter(|w| !is_app_ignored(ignore_apps, &w.bundle_id, &w.app_name))
+        .collect();
+
+    let window_infos = trackable_windows(&windows);
+    track_for_destroy_detection(&window_infos);
+
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+    Some(match rt.block_on(handle.retile(window_infos, window_ids)) {
+        Ok(outcome) => IpcResponse::success(serde_json::json!({
+            "tracked": outcome.tracked,
+            "untracked": outcome.untracked,
+        })),
+        Err(e) => IpcResponse::error(e.to_string()),
+    })
+}
+
+// ============================================================================
+// Mouse Up Callback (Drag Completion)
+// ============================================================================
 
 /// Called when the mouse button is released after a drag/resize operation.
 ///
@@ -1143,6 +2321,11 @@ fn on_mouse_up() {
         return;
     };
 
+    // Snapping needs AX calls, which fail while tiling is paused
+    if is_paused() {
+        return;
+    }
+
     // Get the actor handle to send messages
     let Some(handle) = get_handle() else {
         return;
@@ -1228,12 +2411,16 @@ fn handle_resize_finished(info: &super::events::drag_state::DragInfo, handle: &S
 fn get_current_frames_for_snapshots(
     snapshots: &[super::events::drag_state::WindowSnapshot],
 ) -> Vec<(u32, super::state::Rect)> {
-    use super::effects::window_ops;
+    use super::effects::{applescript, window_ops};
 
     let mut frames = Vec::with_capacity(snapshots.len());
 
     for snapshot in snapshots {
-        if let Some(frame) = window_ops::get_window_frame(snapshot.window_id) {
+        // This runs on the mouse event tap, which must not wait for `osascript`,
+        // so windows using the fallback report the last frame read or set
+        let frame = applescript::last_window_frame(snapshot.window_id)
+            .or_else(|| window_ops::get_window_frame(snapshot.window_id));
+        if let Some(frame) = frame {
             frames.push((snapshot.window_id, frame));
         }
     }
@@ -1357,6 +2544,223 @@ mod tests {
         let _ = is_enabled();
     }
 
+    #[test]
+    fn test_screen_json_includes_geometry() {
+        use crate::modules::tiling::state::{Rect, Screen};
+
+        let screen = Screen {
+            id: 2,
+            name: "Studio Display".to_string(),
+            frame: Rect::new(0.0, 0.0, 2560.0, 1440.0),
+            visible_frame: Rect::new(0.0, 25.0, 2560.0, 1415.0),
+            scale_factor: 2.0,
+            is_main: true,
+            ..Default::default()
+        };
+
+        let json = screen_json(&screen, Some(2));
+        assert_eq!(json["name"], "Studio Display");
+        assert_eq!(json["isMain"], true);
+        assert_eq!(json["isFocused"], true);
+        assert_eq!(screen_json(&screen, Some(1))["isFocused"], false);
+        assert_eq!(screen_json(&screen, None)["isFocused"], false);
+        assert_eq!(json["scaleFactor"], 2.0);
+        assert_eq!(json["frame"]["width"], 2560.0);
+        assert_eq!(json["visibleFrame"]["y"], 25.0);
+        assert_eq!(json["visibleFrame"]["height"], 1415.0);
+    }
+
+    #[test]
+    fn test_workspace_json_distinguishes_global_focus() {
+        use crate::modules::tiling::state::Workspace;
+
+        let mut focused = Workspace::new("code");
+        focused.screen_id = 1;
+        focused.is_visible = true;
+        focused.is_focused = true;
+
+        // Visible and focused on its own screen, but that screen doesn't have focus
+        let mut other = Workspace::new("chat");
+        other.screen_id = 2;
+        other.is_visible = true;
+        other.is_focused = true;
+
+        let json = workspace_json(&focused, "Built-in", Some(focused.id), Some(1));
+        assert_eq!(json["isFocused"], true);
+        assert_eq!(json["isFocusedGlobally"], true);
+        assert_eq!(json["isVisibleOnScreen"], true);
+        assert_eq!(json["isOnFocusedScreen"], true);
+
+        let json = workspace_json(&other, "External", Some(focused.id), Some(1));
+        assert_eq!(json["isFocused"], true);
+        assert_eq!(json["isFocusedGlobally"], false);
+        assert_eq!(json["isVisibleOnScreen"], true);
+        assert_eq!(json["isOnFocusedScreen"], false);
+        assert_eq!(json["screenName"], "External");
+    }
+
+    #[test]
+    fn test_focused_context_with_window() {
+        use crate::modules::tiling::state::{FocusState, LayoutType, Screen, Window, Workspace};
+
+        let screen = Screen {
+            id: 1,
+            name: "Built-in".to_string(),
+            is_main: true,
+            ..Default::default()
+        };
+        let mut workspace = Workspace::new("code");
+        workspace.screen_id = 1;
+        workspace.layout = LayoutType::SplitVertical;
+        let window = Window {
+            id: 42,
+            app_name: "Ghostty".to_string(),
+            title: "zsh".to_string(),
+            workspace_id: workspace.id,
+            ..Default::default()
+        };
+        let focus = FocusState {
+            focused_window_id: Some(42),
+            focused_workspace_id: Some(workspace.id),
+            focused_screen_id: Some(1),
+        };
+
+        let json = focused_context(&focus, Some(&window), &[workspace], &[screen]);
+
+        assert_eq!(json["window"]["id"], 42);
+        assert_eq!(json["window"]["appName"], "Ghostty");
+        assert_eq!(json["window"]["title"], "zsh");
+        assert_eq!(json["workspace"]["name"], "code");
+        assert_eq!(json["workspace"]["layout"], "split-vertical");
+        assert_eq!(json["screen"]["name"], "Built-in");
+    }
+
+    #[test]
+    fn test_focused_context_without_window() {
+        use crate::modules::tiling::state::{FocusState, Workspace};
+
+        let workspace = Workspace::new("empty");
+        let focus = FocusState {
+            focused_window_id: None,
+            focused_workspace_id: Some(workspace.id),
+            focused_screen_id: None,
+        };
+
+        let json = focused_context(&focus, None, &[workspace], &[]);
+
+        assert!(json["window"].is_null());
+        assert_eq!(json["workspace"]["name"], "empty");
+        assert!(json["screen"].is_null());
+    }
+
+    #[test]
+    fn test_focus_history_entries_skip_closed_windows() {
+        use crate::modules::tiling::state::{Window, Workspace};
+
+        let workspace = Workspace::new("code");
+        let window = |id, title: &str| Window {
+            id,
+            title: title.to_string(),
+            workspace_id: workspace.id,
+            ..Default::default()
+        };
+        let windows = [window(1, "one"), window(2, "two"), window(3, "three")];
+
+        // Window 9 closed after it was focused
+        let entries = focus_history_entries(&[3, 9, 1, 2], &windows, &[workspace], None);
+        let ids: Vec<_> = entries.iter().map(|e| e["id"].as_u64().unwrap()).collect();
+        assert_eq!(ids, vec![3, 1, 2]);
+        assert_eq!(entries[0]["title"], "three");
+        assert_eq!(entries[0]["workspace"], "code");
+
+        let entries = focus_history_entries(&[3, 9, 1, 2], &windows, &[], Some(2));
+        let ids: Vec<_> = entries.iter().map(|e| e["id"].as_u64().unwrap()).collect();
+        assert_eq!(ids, vec![3, 1]);
+        assert!(entries[0]["workspace"].is_null());
+    }
+
+    #[test]
+    fn test_focused_context_nothing_focused() {
+        let json =
+            focused_context(&crate::modules::tiling::state::FocusState::new(), None, &[], &[]);
+
+        assert!(json["window"].is_null());
+        assert!(json["workspace"].is_null());
+        assert!(json["screen"].is_null());
+    }
+
+    #[test]
+    fn test_sort_windows() {
+        use crate::modules::tiling::state::{Window, Workspace};
+
+        let first = Workspace::new("first");
+        let second = Workspace::new("second");
+        let window = |id, app: &str, title: &str, workspace_id| Window {
+            id,
+            app_name: app.to_string(),
+            title: title.to_string(),
+            workspace_id,
+            ..Default::default()
+        };
+        let windows = [
+            window(1, "Safari", "docs", second.id),
+            window(2, "arc", "mail", first.id),
+            window(3, "Safari", "Blog", first.id),
+        ];
+        let workspaces = [first, second];
+        let sorted = |sort, recent: &[u32]| {
+            let mut refs: Vec<_> = windows.iter().collect();
+            sort_windows(&mut refs, sort, &workspaces, recent);
+            refs.iter().map(|w| w.id).collect::<Vec<_>>()
+        };
+
+        assert_eq!(sorted(WindowSort::App, &[]), vec![2, 3, 1]);
+        assert_eq!(sorted(WindowSort::Title, &[]), vec![3, 1, 2]);
+        assert_eq!(sorted(WindowSort::Workspace, &[]), vec![2, 3, 1]);
+        assert_eq!(sorted(WindowSort::Recent, &[3, 1]), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn test_window_sort_parse() {
+        assert_eq!(WindowSort::parse("app"), Some(WindowSort::App));
+        assert_eq!(WindowSort::parse("recent"), Some(WindowSort::Recent));
+        assert_eq!(WindowSort::parse("size"), None);
+
+        for sort in [
+            WindowSort::App,
+            WindowSort::Title,
+            WindowSort::Workspace,
+            WindowSort::Recent,
+        ] {
+            assert_eq!(WindowSort::parse(sort.as_str()), Some(sort));
+        }
+    }
+
+    #[test]
+    fn test_find_drag_swap_target_skips_floating() {
+        use super::super::events::drag_state::WindowSnapshot;
+        use crate::modules::tiling::state::Rect;
+
+        let snapshot = |window_id, x, is_floating| WindowSnapshot {
+            window_id,
+            original_frame: Rect::new(x, 0.0, 960.0, 1080.0),
+            is_floating,
+        };
+
+        // Window 1 is dragged onto window 2
+        let current = [(1, Rect::new(960.0, 0.0, 960.0, 1080.0))];
+        let tiled = [snapshot(1, 0.0, false), snapshot(2, 960.0, false)];
+        assert_eq!(find_drag_swap_target(&tiled, &current), Some((1, 2)));
+
+        // A floating target is never swapped with
+        let floating_target = [snapshot(1, 0.0, false), snapshot(2, 960.0, true)];
+        assert_eq!(find_drag_swap_target(&floating_target, &current), None);
+
+        // Dragging a floating window never swaps
+        let floating_dragged = [snapshot(1, 0.0, true), snapshot(2, 960.0, false)];
+        assert_eq!(find_drag_swap_target(&floating_dragged, &current), None);
+    }
+
     #[test]
     fn test_get_app_handle_without_store() {
         // Without storing, should return None
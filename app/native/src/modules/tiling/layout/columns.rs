@@ -0,0 +1,111 @@
+//! Columns layout - equal-width columns, left to right.
+//!
+//! Every window gets a full-height column. Unlike the auto split, the layout
+//! never switches to rows on portrait screens, and unlike dwindle it never
+//! subdivides, so windows stay in predictable side-by-side columns (e.g. for
+//! reading several documents at once).
+//!
+//! Split ratios are cumulative column boundaries (e.g. `[0.25, 0.75]` for
+//! three windows), so dragging a column border moves only that boundary and
+//! resizes the two neighboring columns.
+
+use super::{Gaps, LayoutResult, split};
+use crate::modules::tiling::state::Rect;
+
+/// Columns layout - windows side by side in full-height columns.
+///
+/// # Arguments
+///
+/// * `window_ids` - IDs of windows to arrange (left to right)
+/// * `screen_frame` - The visible frame of the screen (already has outer gaps applied)
+/// * `gaps` - Gap values for spacing between columns
+/// * `ratios` - Cumulative column boundaries (0.0-1.0).
+///   If empty or wrong length, columns get equal widths.
+#[must_use]
+pub fn layout(
+    window_ids: &[u32],
+    screen_frame: &Rect,
+    gaps: &Gaps,
+    ratios: &[f64],
+) -> LayoutResult {
+    split::layout_horizontal(window_ids, screen_frame, gaps, ratios)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    fn screen_frame() -> Rect { Rect::new(0.0, 0.0, 1920.0, 1080.0) }
+
+    #[test]
+    fn test_columns_empty() {
+        let result = layout(&[], &screen_frame(), &Gaps::default(), &[]);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_columns_three_equal_widths() {
+        let frame = screen_frame();
+        let result = layout(&[1, 2, 3], &frame, &Gaps::default(), &[]);
+
+        let ids: Vec<u32> = result.iter().map(|(id, _)| *id).collect();
+        let xs: Vec<f64> = result.iter().map(|(_, f)| f.x).collect();
+        let widths: Vec<f64> = result.iter().map(|(_, f)| f.width).collect();
+        assert_eq!(ids, [1, 2, 3]);
+        assert_eq!(xs, [0.0, 640.0, 1280.0]);
+        assert_eq!(widths, [640.0, 640.0, 640.0]);
+        assert!(result.iter().all(|(_, f)| f.y == frame.y && f.height == frame.height));
+    }
+
+    #[test]
+    fn test_columns_with_gaps() {
+        let frame = screen_frame();
+        let gaps = Gaps::uniform(30.0, 0.0);
+        let result = layout(&[1, 2, 3], &frame, &gaps, &[]);
+
+        // (1920 - 2 gaps of 30) / 3 columns
+        let widths: Vec<f64> = result.iter().map(|(_, f)| f.width).collect();
+        assert_eq!(widths, [620.0, 620.0, 620.0]);
+
+        let (_, first) = result[0];
+        let (_, second) = result[1];
+        assert_eq!(second.x - (first.x + first.width), 30.0);
+
+        let (_, last) = result[2];
+        assert_eq!(last.x + last.width, frame.width);
+    }
+
+    #[test]
+    fn test_columns_with_boundaries() {
+        let frame = screen_frame();
+        // Boundaries at 25% and 75%: columns of 25%, 50% and 25%
+        let result = layout(&[1, 2, 3], &frame, &Gaps::default(), &[0.25, 0.75]);
+
+        let widths: Vec<f64> = result.iter().map(|(_, f)| f.width).collect();
+        assert_eq!(widths, [480.0, 960.0, 480.0]);
+    }
+
+    #[test]
+    fn test_columns_ignore_mismatched_ratios() {
+        let frame = screen_frame();
+        // Ratios left over from a different window count fall back to equal widths
+        let result = layout(&[1, 2], &frame, &Gaps::default(), &[0.25, 0.75]);
+
+        let widths: Vec<f64> = result.iter().map(|(_, f)| f.width).collect();
+        assert_eq!(widths, [960.0, 960.0]);
+    }
+
+    #[test]
+    fn test_columns_stay_columns_on_portrait_screens() {
+        let frame = Rect::new(0.0, 0.0, 1080.0, 1920.0);
+        let result = layout(&[1, 2], &frame, &Gaps::default(), &[]);
+
+        assert!(result.iter().all(|(_, f)| f.height == frame.height));
+        assert_eq!(result[1].1.x, 540.0);
+    }
+}
@@ -51,11 +51,61 @@
 //!             └──┴──┘
 //! ```
 
+use serde::Serialize;
 use smallvec::{SmallVec, smallvec};
 
 use super::{Gaps, LAYOUT_INLINE_CAP, LayoutResult, helpers};
 use crate::modules::tiling::state::Rect;
 
+/// Direction of a split in the dwindle tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitOrientation {
+    /// Side by side (left/right).
+    Horizontal,
+    /// Stacked (top/bottom).
+    Vertical,
+}
+
+/// A node of the dwindle split tree.
+///
+/// Every split keeps its window in the first half and hands the second
+/// half to the rest of the spiral.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum DwindleNode {
+    /// A single window.
+    Leaf {
+        #[serde(rename = "windowId")]
+        window_id: u32,
+    },
+    /// A split of the parent area into two halves.
+    Split {
+        orientation: SplitOrientation,
+        /// Proportion of the area given to the first half.
+        ratio: f64,
+        first: Box<DwindleNode>,
+        second: Box<DwindleNode>,
+    },
+}
+
+/// Returns the orientation of split `index` (1-based).
+///
+/// Splits alternate starting from the orientation-appropriate direction:
+/// horizontal first in landscape, vertical first in portrait.
+const fn split_orientation(index: usize, is_landscape: bool) -> SplitOrientation {
+    if (index % 2 == 1) == is_landscape {
+        SplitOrientation::Horizontal
+    } else {
+        SplitOrientation::Vertical
+    }
+}
+
+/// Returns the ratio of split `index` (1-based), defaulting to 0.5.
+fn split_ratio(ratios: &[f64], index: usize) -> f64 {
+    ratios.get(index - 1).copied().unwrap_or(0.5).clamp(0.05, 0.95)
+}
+
 /// Dwindle layout - windows arranged in a dwindling spiral pattern.
 ///
 /// Each new window splits the last window's space, alternating between
@@ -102,24 +152,17 @@ pub fn layout(
         // Get the frame we're going to split (the last one)
         let parent_frame = frames[i - 1];
 
-        // Alternate split direction starting from the orientation-appropriate direction
-        // For landscape: odd index = horizontal, even = vertical
-        // For portrait: odd index = vertical, even = horizontal
-        let split_horizontal = if is_landscape {
-            i % 2 == 1 // 1st split horizontal, 2nd vertical, 3rd horizontal...
-        } else {
-            i % 2 == 0 // 1st split vertical, 2nd horizontal, 3rd vertical...
-        };
-
-        // Get the ratio for this split (default 0.5 if not provided)
         // ratios[0] controls split 1 (i=1), ratios[1] controls split 2 (i=2), etc.
-        let ratio = ratios.get(i - 1).copied().unwrap_or(0.5).clamp(0.05, 0.95);
+        let ratio = split_ratio(ratios, i);
 
         // Split the parent frame
-        let (first_half, second_half) = if split_horizontal {
-            helpers::split_horizontal(&parent_frame, ratio, gaps.inner_h)
-        } else {
-            helpers::split_vertical(&parent_frame, ratio, gaps.inner_v)
+        let (first_half, second_half) = match split_orientation(i, is_landscape) {
+            SplitOrientation::Horizontal => {
+                helpers::split_horizontal(&parent_frame, ratio, gaps.inner_h)
+            }
+            SplitOrientation::Vertical => {
+                helpers::split_vertical(&parent_frame, ratio, gaps.inner_v)
+            }
         };
 
         // Update the parent window's frame to first half
@@ -137,6 +180,30 @@ pub fn layout(
     result
 }
 
+/// Builds the split tree that [`layout`] applies to the given windows.
+///
+/// Uses the same orientation and ratio rules as [`layout`], so the tree
+/// matches the computed frames. Returns `None` when there are no windows.
+#[must_use]
+pub fn tree(window_ids: &[u32], screen_frame: &Rect, ratios: &[f64]) -> Option<DwindleNode> {
+    let (&last, rest) = window_ids.split_last()?;
+    let is_landscape = screen_frame.width >= screen_frame.height;
+
+    // Build bottom-up: the last window is the innermost leaf
+    let mut node = DwindleNode::Leaf { window_id: last };
+    for (index, &window_id) in rest.iter().enumerate().rev() {
+        let split = index + 1;
+        node = DwindleNode::Split {
+            orientation: split_orientation(split, is_landscape),
+            ratio: split_ratio(ratios, split),
+            first: Box::new(DwindleNode::Leaf { window_id }),
+            second: Box::new(node),
+        };
+    }
+
+    Some(node)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -517,4 +584,70 @@ mod tests {
         assert!((frame2.height - frame.height.mul_add(0.5, 0.0)).abs() < 1.0);
         assert!((frame3.width - (frame.width * 0.4).mul_add(0.5, 0.0)).abs() < 1.0);
     }
+
+    // ========================================================================
+    // Tree Tests
+    // ========================================================================
+
+    fn leaf(window_id: u32) -> Box<DwindleNode> { Box::new(DwindleNode::Leaf { window_id }) }
+
+    #[test]
+    fn test_tree_empty_and_single() {
+        assert_eq!(tree(&[], &landscape_frame(), &[]), None);
+        assert_eq!(
+            tree(&[7], &landscape_frame(), &[]),
+            Some(DwindleNode::Leaf { window_id: 7 })
+        );
+    }
+
+    #[test]
+    fn test_tree_landscape_spiral() {
+        let result = tree(&[1, 2, 3], &landscape_frame(), &[0.6]).unwrap();
+
+        assert_eq!(result, DwindleNode::Split {
+            orientation: SplitOrientation::Horizontal,
+            ratio: 0.6,
+            first: leaf(1),
+            second: Box::new(DwindleNode::Split {
+                orientation: SplitOrientation::Vertical,
+                ratio: 0.5,
+                first: leaf(2),
+                second: leaf(3),
+            }),
+        });
+    }
+
+    #[test]
+    fn test_tree_portrait_starts_vertical() {
+        let Some(DwindleNode::Split { orientation, .. }) = tree(&[1, 2], &portrait_frame(), &[])
+        else {
+            panic!("Expected a split");
+        };
+        assert_eq!(orientation, SplitOrientation::Vertical);
+    }
+
+    #[test]
+    fn test_tree_clamps_ratios() {
+        let Some(DwindleNode::Split { ratio, .. }) = tree(&[1, 2], &landscape_frame(), &[1.5])
+        else {
+            panic!("Expected a split");
+        };
+        assert_eq!(ratio, 0.95);
+    }
+
+    #[test]
+    fn test_tree_serialization() {
+        let json = serde_json::to_value(tree(&[1, 2], &landscape_frame(), &[]).unwrap()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "split",
+                "orientation": "horizontal",
+                "ratio": 0.5,
+                "first": { "type": "leaf", "windowId": 1 },
+                "second": { "type": "leaf", "windowId": 2 },
+            })
+        );
+    }
 }
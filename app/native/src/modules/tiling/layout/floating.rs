@@ -3,15 +3,34 @@
 //! This module provides functionality for floating windows, including
 //! preset-based positioning where windows can be quickly placed at
 //! predefined sizes and positions.
+//!
+//! Besides the configured presets, `floating.grid` generates one preset per
+//! grid cell (`grid-r1c1` .. `grid-r{rows}c{cols}`).
 
 use super::Gaps;
-use crate::config::{DimensionValue, FloatingPreset, get_config};
+use crate::config::{DimensionValue, FloatingConfig, FloatingGridCell, FloatingPreset, get_config};
 use crate::modules::tiling::state::Rect;
 
 // ============================================================================
 // Preset Functions
 // ============================================================================
 
+/// Returns the configured presets followed by the generated grid presets.
+fn all_presets(config: &FloatingConfig) -> Vec<FloatingPreset> {
+    let mut presets = config.presets.clone();
+    if let Some(grid) = &config.grid {
+        presets.extend(grid.presets());
+    }
+    presets
+}
+
+/// Finds a preset by name in the floating configuration.
+///
+/// Configured presets take precedence over generated grid presets.
+fn find_preset_in(config: &FloatingConfig, name: &str) -> Option<FloatingPreset> {
+    all_presets(config).into_iter().find(|p| p.name.eq_ignore_ascii_case(name))
+}
+
 /// Finds a preset by name from the configuration.
 ///
 /// # Arguments
@@ -23,21 +42,13 @@ use crate::modules::tiling::state::Rect;
 /// The preset if found, or `None` if no preset with that name exists.
 #[must_use]
 pub fn find_preset(name: &str) -> Option<FloatingPreset> {
-    let config = get_config();
-    config
-        .tiling
-        .floating
-        .presets
-        .iter()
-        .find(|p| p.name.eq_ignore_ascii_case(name))
-        .cloned()
+    find_preset_in(&get_config().tiling.floating, name)
 }
 
-/// Returns a list of all available preset names.
+/// Returns a list of all available preset names, including grid presets.
 #[must_use]
 pub fn list_preset_names() -> Vec<String> {
-    let config = get_config();
-    config.tiling.floating.presets.iter().map(|p| p.name.clone()).collect()
+    all_presets(&get_config().tiling.floating).into_iter().map(|p| p.name).collect()
 }
 
 // ============================================================================
@@ -61,6 +72,10 @@ pub fn list_preset_names() -> Vec<String> {
 /// The calculated window frame as a `Rect`.
 #[must_use]
 pub fn calculate_preset_frame(preset: &FloatingPreset, screen_frame: &Rect, gaps: &Gaps) -> Rect {
+    if let Some(cell) = &preset.grid_cell {
+        return calculate_grid_cell_frame(cell, screen_frame, gaps);
+    }
+
     // Apply outer gaps to get the usable area
     let usable = gaps.apply_outer(screen_frame);
 
@@ -125,6 +140,50 @@ pub fn calculate_preset_frame(preset: &FloatingPreset, screen_frame: &Rect, gaps
     Rect::new(x, y, width, height)
 }
 
+/// Calculates the window frame for a grid cell.
+///
+/// The usable area (outer gaps applied) is split into equally sized cells
+/// with inner gaps between them.
+#[must_use]
+pub fn calculate_grid_cell_frame(
+    cell: &FloatingGridCell,
+    screen_frame: &Rect,
+    gaps: &Gaps,
+) -> Rect {
+    let usable = gaps.apply_outer(screen_frame);
+
+    let cols = f64::from(cell.cols.max(1));
+    let rows = f64::from(cell.rows.max(1));
+    let col = f64::from(cell.col.clamp(1, cell.cols.max(1)) - 1);
+    let row = f64::from(cell.row.clamp(1, cell.rows.max(1)) - 1);
+
+    let width = ((usable.width - gaps.inner_h * (cols - 1.0)) / cols).max(1.0);
+    let height = ((usable.height - gaps.inner_v * (rows - 1.0)) / rows).max(1.0);
+
+    Rect::new(
+        usable.x + col * (width + gaps.inner_h),
+        usable.y + row * (height + gaps.inner_v),
+        width,
+        height,
+    )
+}
+
+/// Centers a frame within an area, keeping its size.
+///
+/// Frames larger than the area are shrunk to fit.
+#[must_use]
+pub fn center_frame(frame: &Rect, area: &Rect) -> Rect {
+    let width = frame.width.min(area.width);
+    let height = frame.height.min(area.height);
+
+    Rect::new(
+        area.x + (area.width - width) / 2.0,
+        area.y + (area.height - height) / 2.0,
+        width,
+        height,
+    )
+}
+
 /// Checks if a dimension value is exactly 50%.
 fn is_half_percentage(dim: &DimensionValue) -> bool {
     match dim {
@@ -144,6 +203,7 @@ fn is_half_percentage(dim: &DimensionValue) -> bool {
 #[allow(clippy::float_cmp)]
 mod tests {
     use super::*;
+    use crate::config::FloatingGridConfig;
 
     fn screen_frame() -> Rect { Rect::new(0.0, 0.0, 1920.0, 1080.0) }
 
@@ -155,6 +215,14 @@ mod tests {
             x: None,
             y: None,
             center,
+            grid_cell: None,
+        }
+    }
+
+    fn grid_config(cols: u32, rows: u32) -> FloatingConfig {
+        FloatingConfig {
+            grid: Some(FloatingGridConfig { cols, rows }),
+            ..Default::default()
         }
     }
 
@@ -193,6 +261,95 @@ mod tests {
         assert!((frame.width - (1920.0 - 10.0) / 2.0).abs() < 1.0);
     }
 
+    #[test]
+    fn test_grid_cell_frame() {
+        let cell = FloatingGridCell {
+            row: 2,
+            col: 3,
+            rows: 2,
+            cols: 3,
+        };
+        let frame = calculate_grid_cell_frame(&cell, &screen_frame(), &Gaps::zero());
+
+        assert_eq!(frame, Rect::new(1280.0, 540.0, 640.0, 540.0));
+    }
+
+    #[test]
+    fn test_grid_cell_frame_with_gaps() {
+        let gaps = Gaps::uniform(10.0, 20.0);
+        let first = FloatingGridCell {
+            row: 1,
+            col: 1,
+            rows: 2,
+            cols: 2,
+        };
+        let last = FloatingGridCell {
+            row: 2,
+            col: 2,
+            rows: 2,
+            cols: 2,
+        };
+
+        let first = calculate_grid_cell_frame(&first, &screen_frame(), &gaps);
+        let last = calculate_grid_cell_frame(&last, &screen_frame(), &gaps);
+
+        // (1880 - 10) / 2 and (1040 - 10) / 2
+        assert_eq!(first, Rect::new(20.0, 20.0, 935.0, 515.0));
+        assert_eq!(last, Rect::new(965.0, 545.0, 935.0, 515.0));
+        // Last cell ends at the outer gap
+        assert_eq!(last.x + last.width, 1900.0);
+        assert_eq!(last.y + last.height, 1060.0);
+    }
+
+    #[test]
+    fn test_preset_frame_uses_grid_cell() {
+        let preset = find_preset_in(&grid_config(2, 1), "grid-r1c2").unwrap();
+        let frame = calculate_preset_frame(&preset, &screen_frame(), &Gaps::zero());
+
+        assert_eq!(frame, Rect::new(960.0, 0.0, 960.0, 1080.0));
+    }
+
+    #[test]
+    fn test_find_preset_in_grid() {
+        let config = grid_config(3, 2);
+
+        assert!(find_preset_in(&config, "GRID-R2C3").is_some());
+        assert!(find_preset_in(&config, "grid-r3c1").is_none());
+
+        let names: Vec<String> = all_presets(&config).into_iter().map(|p| p.name).collect();
+        assert_eq!(names.len(), 6);
+    }
+
+    #[test]
+    fn test_configured_preset_overrides_grid() {
+        let mut config = grid_config(2, 2);
+        let mut preset = test_preset("100%", "100%", false);
+        preset.name = "grid-r1c1".to_string();
+        config.presets.push(preset);
+
+        let found = find_preset_in(&config, "grid-r1c1").unwrap();
+        assert!(found.grid_cell.is_none());
+    }
+
+    #[test]
+    fn test_center_frame() {
+        let frame = Rect::new(10.0, 20.0, 800.0, 600.0);
+        let centered = center_frame(&frame, &screen_frame());
+
+        assert_eq!(centered, Rect::new(560.0, 240.0, 800.0, 600.0));
+    }
+
+    #[test]
+    fn test_center_frame_shrinks_to_area() {
+        let frame = Rect::new(0.0, 0.0, 2400.0, 600.0);
+        let area = Rect::new(100.0, 50.0, 1920.0, 1080.0);
+
+        assert_eq!(
+            center_frame(&frame, &area),
+            Rect::new(100.0, 290.0, 1920.0, 600.0)
+        );
+    }
+
     #[test]
     fn test_is_half_percentage() {
         assert!(is_half_percentage(&DimensionValue::Percentage(
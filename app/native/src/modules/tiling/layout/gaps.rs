@@ -6,7 +6,7 @@
 
 use std::hash::{Hash, Hasher};
 
-use crate::config::{GapsConfig, GapsConfigValue};
+use crate::config::{GapValue, GapsConfig, GapsConfigValue, WorkspaceGapsConfig};
 use crate::modules::tiling::state::Rect;
 
 /// Gap values for layout calculations.
@@ -98,6 +98,45 @@ impl Gaps {
         )
     }
 
+    /// Re-inset a frame laid out in `area` with these gaps so it uses `gap`
+    /// all around instead.
+    ///
+    /// Edges on the outer edge of the usable area move to `gap` from the edge
+    /// of `area`. Inner edges move to half of `gap` from the line halfway to
+    /// the neighbouring window, so two windows using the same gap are `gap`
+    /// apart.
+    #[must_use]
+    pub fn reinset(&self, frame: &Rect, area: &Rect, gap: f64) -> Rect {
+        const EPSILON: f64 = 0.5;
+
+        let usable = self.apply_outer(area);
+        let at = |edge: f64, outer: f64| (edge - outer).abs() < EPSILON;
+        let (right, bottom) = (frame.x + frame.width, frame.y + frame.height);
+
+        let left = if at(frame.x, usable.x) {
+            area.x + gap
+        } else {
+            frame.x - (self.inner_h - gap) / 2.0
+        };
+        let right = if at(right, usable.x + usable.width) {
+            area.x + area.width - gap
+        } else {
+            right + (self.inner_h - gap) / 2.0
+        };
+        let top = if at(frame.y, usable.y) {
+            area.y + gap
+        } else {
+            frame.y - (self.inner_v - gap) / 2.0
+        };
+        let bottom = if at(bottom, usable.y + usable.height) {
+            area.y + area.height - gap
+        } else {
+            bottom + (self.inner_v - gap) / 2.0
+        };
+
+        Rect::new(left, top, (right - left).max(0.0), (bottom - top).max(0.0))
+    }
+
     /// Compute a hash of the gap values for cache validation.
     #[must_use]
     pub fn compute_hash(&self) -> u64 {
@@ -121,6 +160,20 @@ impl Gaps {
         self
     }
 
+    /// Applies smart gaps for a workspace with `window_count` tiled windows.
+    ///
+    /// A single window gets no inner or outer gaps, keeping only `top_offset`
+    /// (the bar offset on the main screen, zero elsewhere). Any other count
+    /// keeps the gaps unchanged.
+    #[must_use]
+    pub const fn with_smart_gaps(self, window_count: usize, top_offset: f64) -> Self {
+        if window_count == 1 {
+            Self::zero().with_top_offset(top_offset)
+        } else {
+            self
+        }
+    }
+
     /// Resolves gaps from configuration for a specific screen.
     ///
     /// On the main screen, the bar offset (bar height + padding) is automatically
@@ -138,6 +191,30 @@ impl Gaps {
         screen_name: &str,
         is_main_screen: bool,
         bar_offset: f64,
+    ) -> Self {
+        Self::from_config_for_workspace(config, None, screen_name, is_main_screen, bar_offset)
+    }
+
+    /// Resolves gaps for a workspace, honoring its gaps override.
+    ///
+    /// Resolution order is workspace > per-screen > global. Each override
+    /// field (inner/outer) replaces only its part of the screen gaps. The bar
+    /// offset is added on top of the resolved outer gaps on the main screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The gaps configuration value
+    /// * `workspace_gaps` - The workspace gaps override, if any
+    /// * `screen_name` - Name of the screen to resolve gaps for
+    /// * `is_main_screen` - Whether this is the main screen
+    /// * `bar_offset` - Additional top offset for the status bar (only applied on main screen)
+    #[must_use]
+    pub fn from_config_for_workspace(
+        config: &GapsConfigValue,
+        workspace_gaps: Option<&WorkspaceGapsConfig>,
+        screen_name: &str,
+        is_main_screen: bool,
+        bar_offset: f64,
     ) -> Self {
         let mut gaps = match config {
             GapsConfigValue::Global(g) => Self::from_gaps_config(g),
@@ -170,6 +247,15 @@ impl Gaps {
             }
         };
 
+        if let Some(overrides) = workspace_gaps {
+            if let Some(inner) = &overrides.inner {
+                gaps.set_inner(inner);
+            }
+            if let Some(outer) = &overrides.outer {
+                gaps.set_outer(outer);
+            }
+        }
+
         // Add bar offset to top gap on main screen only
         if is_main_screen {
             gaps.outer_top += bar_offset;
@@ -178,6 +264,22 @@ impl Gaps {
         gaps
     }
 
+    /// Replaces the inner gaps with the given value.
+    fn set_inner(&mut self, value: &GapValue) {
+        let (inner_h, inner_v) = value.as_inner();
+        self.inner_h = f64::from(inner_h);
+        self.inner_v = f64::from(inner_v);
+    }
+
+    /// Replaces the outer gaps with the given value.
+    fn set_outer(&mut self, value: &GapValue) {
+        let (outer_top, outer_right, outer_bottom, outer_left) = value.as_outer();
+        self.outer_top = f64::from(outer_top);
+        self.outer_right = f64::from(outer_right);
+        self.outer_bottom = f64::from(outer_bottom);
+        self.outer_left = f64::from(outer_left);
+    }
+
     /// Converts a [`GapsConfig`] to [`Gaps`].
     fn from_gaps_config(config: &GapsConfig) -> Self {
         let (inner_h, inner_v) = config.inner.as_inner();
@@ -202,6 +304,8 @@ impl Gaps {
 #[allow(clippy::float_cmp)]
 mod tests {
     use super::*;
+    use crate::modules::tiling::layout::{MasterPosition, calculate_layout_full};
+    use crate::modules::tiling::state::LayoutType;
 
     #[test]
     fn test_gaps_zero() {
@@ -223,6 +327,36 @@ mod tests {
         assert!(!gaps.is_zero());
     }
 
+    #[test]
+    fn test_gaps_reinset() {
+        let gaps = Gaps::uniform(10.0, 20.0);
+        let area = Rect::new(0.0, 0.0, 1000.0, 500.0);
+        let left = Rect::new(20.0, 20.0, 475.0, 460.0);
+        let right = Rect::new(505.0, 20.0, 475.0, 460.0);
+
+        // Edge-to-edge: the windows touch each other and the screen edges
+        assert_eq!(
+            gaps.reinset(&left, &area, 0.0),
+            Rect::new(0.0, 0.0, 500.0, 500.0)
+        );
+        assert_eq!(
+            gaps.reinset(&right, &area, 0.0),
+            Rect::new(500.0, 0.0, 500.0, 500.0)
+        );
+
+        // Half the gap on the side shared with the neighbour
+        assert_eq!(
+            gaps.reinset(&left, &area, 4.0),
+            Rect::new(4.0, 4.0, 494.0, 492.0)
+        );
+
+        // The inner gap keeps the shared edge in place, outer edges still move
+        assert_eq!(
+            gaps.reinset(&right, &area, 10.0),
+            Rect::new(505.0, 10.0, 485.0, 480.0)
+        );
+    }
+
     #[test]
     fn test_gaps_new() {
         let gaps = Gaps::new(5.0, 10.0, 15.0, 20.0, 25.0, 30.0);
@@ -294,4 +428,95 @@ mod tests {
         let gaps2 = Gaps::new(10.0, 10.0, 25.0, 20.0, 20.0, 20.0);
         assert_ne!(gaps1.compute_hash(), gaps2.compute_hash());
     }
+
+    fn global_config(inner: u32, outer: u32) -> GapsConfigValue {
+        GapsConfigValue::Global(GapsConfig {
+            inner: GapValue::Uniform(inner),
+            outer: GapValue::Uniform(outer),
+        })
+    }
+
+    #[test]
+    fn test_from_config_for_workspace_without_override() {
+        let config = global_config(10, 20);
+        let gaps = Gaps::from_config_for_workspace(&config, None, "Built-in", false, 0.0);
+
+        assert_eq!(gaps.inner_h, 10.0);
+        assert_eq!(gaps.outer_top, 20.0);
+    }
+
+    #[test]
+    fn test_from_config_for_workspace_inner_only_keeps_global_outer() {
+        let config = global_config(10, 20);
+        let overrides = WorkspaceGapsConfig {
+            inner: Some(GapValue::Uniform(0)),
+            outer: None,
+        };
+        let gaps =
+            Gaps::from_config_for_workspace(&config, Some(&overrides), "Built-in", false, 0.0);
+
+        assert_eq!(gaps.inner_h, 0.0);
+        assert_eq!(gaps.inner_v, 0.0);
+        assert_eq!(gaps.outer_top, 20.0);
+        assert_eq!(gaps.outer_left, 20.0);
+    }
+
+    #[test]
+    fn test_from_config_for_workspace_overrides_per_screen() {
+        let config =
+            GapsConfigValue::PerScreen(vec![crate::config::types::gaps::ScreenGapsConfig {
+                screen: "main".to_string(),
+                inner: GapValue::Uniform(8),
+                outer: GapValue::Uniform(12),
+            }]);
+        let overrides = WorkspaceGapsConfig {
+            inner: None,
+            outer: Some(GapValue::Uniform(80)),
+        };
+        let gaps =
+            Gaps::from_config_for_workspace(&config, Some(&overrides), "Built-in", true, 0.0);
+
+        assert_eq!(gaps.inner_h, 8.0);
+        assert_eq!(gaps.outer_top, 80.0);
+        assert_eq!(gaps.outer_bottom, 80.0);
+    }
+
+    #[test]
+    fn test_from_config_for_workspace_keeps_bar_offset() {
+        let config = global_config(10, 20);
+        let overrides = WorkspaceGapsConfig {
+            inner: None,
+            outer: Some(GapValue::Uniform(0)),
+        };
+        let gaps =
+            Gaps::from_config_for_workspace(&config, Some(&overrides), "Built-in", true, 40.0);
+
+        assert_eq!(gaps.outer_top, 40.0);
+        assert_eq!(gaps.outer_bottom, 0.0);
+    }
+
+    #[test]
+    fn test_smart_gaps_single_window_fills_usable_frame() {
+        let screen = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        let gaps = Gaps::from_config(&global_config(10, 20), "Built-in", true, 40.0);
+
+        let single = gaps.with_smart_gaps(1, 40.0);
+        let result = calculate_layout_full(
+            LayoutType::Dwindle,
+            &[1],
+            &screen,
+            0.5,
+            &single,
+            &[],
+            MasterPosition::Auto,
+            1,
+        );
+        assert_eq!(result[0].1, Rect::new(0.0, 40.0, 1920.0, 1040.0));
+
+        // A second window restores the configured gaps
+        let pair = gaps.with_smart_gaps(2, 40.0);
+        assert_eq!(pair.outer_left, 20.0);
+        assert_eq!(pair.inner_h, 10.0);
+        assert_eq!(pair.outer_top, 60.0);
+    }
 }
@@ -1,7 +1,9 @@
-//! Master layout - one master window with remaining windows in a stack.
+//! Master layout - master windows with remaining windows in a stack.
 //!
 //! The first window is the "master" and gets a larger portion of the screen.
-//! Remaining windows are arranged in the remaining space.
+//! Remaining windows are arranged in the remaining space. When more than one
+//! master is requested, the first N windows share the master area the same way
+//! stack windows share theirs.
 //!
 //! The master position can be configured:
 //! - **Left**: Master on left, stack on right (stacked vertically)
@@ -34,7 +36,7 @@
 //! └────┴────┘       └─────────┘
 //! ```
 
-use smallvec::{SmallVec, smallvec};
+use smallvec::SmallVec;
 
 use super::{Gaps, LAYOUT_INLINE_CAP, LayoutResult};
 use crate::modules::tiling::state::Rect;
@@ -55,19 +57,20 @@ pub enum MasterPosition {
     Auto,
 }
 
-/// Master layout - one master window with remaining windows in a stack.
+/// Master layout - master windows with remaining windows in a stack.
 ///
-/// The first window is the "master" and gets a larger portion of the screen.
-/// The master position can be configured, or set to auto to adapt to screen orientation.
+/// The first `master_count` windows are the "masters" and share a larger portion
+/// of the screen. The master position can be configured, or set to auto to adapt
+/// to screen orientation.
 ///
 /// # Arguments
 ///
-/// * `window_ids` - IDs of windows to arrange (first one is master)
+/// * `window_ids` - IDs of windows to arrange (first ones are masters)
 /// * `screen_frame` - The visible frame of the screen
-/// * `master_ratio` - Ratio of screen for master window (0.0-1.0, clamped to 0.1-0.9)
+/// * `master_ratio` - Ratio of screen for the master area (0.0-1.0, clamped to 0.1-0.9)
 /// * `gaps` - Gap values for spacing
-/// * `position` - Position of the master window (left/right/top/bottom/auto)
-#[allow(clippy::cast_precision_loss)] // Window counts won't exceed f64 precision
+/// * `position` - Position of the master area (left/right/top/bottom/auto)
+/// * `master_count` - Number of master windows (clamped to 1..=window count)
 #[must_use]
 pub fn layout(
     window_ids: &[u32],
@@ -75,6 +78,7 @@ pub fn layout(
     master_ratio: f64,
     gaps: &Gaps,
     position: MasterPosition,
+    master_count: usize,
 ) -> LayoutResult {
     if window_ids.is_empty() {
         return SmallVec::new();
@@ -83,11 +87,6 @@ pub fn layout(
     // Clamp ratio to valid range
     let ratio = master_ratio.clamp(0.1, 0.9);
 
-    // Single window - takes full screen
-    if window_ids.len() == 1 {
-        return smallvec![(window_ids[0], *screen_frame)];
-    }
-
     // Resolve auto position based on screen orientation
     let resolved_position = match position {
         MasterPosition::Auto => {
@@ -100,129 +99,155 @@ pub fn layout(
         other => other,
     };
 
+    let (masters, stack) = window_ids.split_at(master_count.clamp(1, window_ids.len()));
+    let mut result: LayoutResult = SmallVec::with_capacity(window_ids.len().min(LAYOUT_INLINE_CAP));
+
+    // Only masters - they share the full screen
+    if stack.is_empty() {
+        match resolved_position {
+            MasterPosition::Top | MasterPosition::Bottom => {
+                push_row(&mut result, masters, screen_frame, gaps.inner_h);
+            }
+            _ => push_column(&mut result, masters, screen_frame, gaps.inner_v),
+        }
+        return result;
+    }
+
     match resolved_position {
-        MasterPosition::Left => layout_left(window_ids, screen_frame, ratio, gaps),
-        MasterPosition::Right => layout_right(window_ids, screen_frame, ratio, gaps),
-        MasterPosition::Top => layout_top(window_ids, screen_frame, ratio, gaps),
-        MasterPosition::Bottom => layout_bottom(window_ids, screen_frame, ratio, gaps),
+        MasterPosition::Left => layout_left(&mut result, masters, stack, screen_frame, ratio, gaps),
+        MasterPosition::Right => {
+            layout_right(&mut result, masters, stack, screen_frame, ratio, gaps);
+        }
+        MasterPosition::Top => layout_top(&mut result, masters, stack, screen_frame, ratio, gaps),
+        MasterPosition::Bottom => {
+            layout_bottom(&mut result, masters, stack, screen_frame, ratio, gaps);
+        }
         MasterPosition::Auto => unreachable!(), // Already resolved above
     }
-}
 
-/// Master on left, stack on right (stacked vertically).
-#[allow(clippy::cast_precision_loss)]
-fn layout_left(window_ids: &[u32], screen_frame: &Rect, ratio: f64, gaps: &Gaps) -> LayoutResult {
-    let mut result: LayoutResult = SmallVec::with_capacity(window_ids.len().min(LAYOUT_INLINE_CAP));
+    result
+}
 
+/// Master on left, stack on right (both stacked vertically).
+fn layout_left(
+    result: &mut LayoutResult,
+    masters: &[u32],
+    stack: &[u32],
+    screen_frame: &Rect,
+    ratio: f64,
+    gaps: &Gaps,
+) {
     // Account for gap between master and stack
     let available_width = screen_frame.width - gaps.inner_h;
 
-    // Master window (left side)
+    // Master area (left side)
     let master_width = available_width * ratio;
-    let master_frame = Rect::new(screen_frame.x, screen_frame.y, master_width, screen_frame.height);
-    result.push((window_ids[0], master_frame));
+    let master_area = Rect::new(screen_frame.x, screen_frame.y, master_width, screen_frame.height);
+    push_column(result, masters, &master_area, gaps.inner_v);
 
-    // Stack windows (right side, stacked vertically)
+    // Stack area (right side)
     let stack_x = screen_frame.x + master_width + gaps.inner_h;
     let stack_width = available_width - master_width;
-    let stack_count = window_ids.len() - 1;
-    let total_stack_gap = gaps.inner_v * (stack_count - 1) as f64;
-    let stack_height = (screen_frame.height - total_stack_gap) / stack_count as f64;
-
-    for (i, &id) in window_ids.iter().skip(1).enumerate() {
-        let y = (i as f64).mul_add(stack_height + gaps.inner_v, screen_frame.y);
-        let frame = Rect::new(stack_x, y, stack_width, stack_height);
-        result.push((id, frame));
-    }
-
-    result
+    let stack_area = Rect::new(stack_x, screen_frame.y, stack_width, screen_frame.height);
+    push_column(result, stack, &stack_area, gaps.inner_v);
 }
 
-/// Master on right, stack on left (stacked vertically).
-#[allow(clippy::cast_precision_loss)]
-fn layout_right(window_ids: &[u32], screen_frame: &Rect, ratio: f64, gaps: &Gaps) -> LayoutResult {
-    let mut result: LayoutResult = SmallVec::with_capacity(window_ids.len().min(LAYOUT_INLINE_CAP));
-
+/// Master on right, stack on left (both stacked vertically).
+fn layout_right(
+    result: &mut LayoutResult,
+    masters: &[u32],
+    stack: &[u32],
+    screen_frame: &Rect,
+    ratio: f64,
+    gaps: &Gaps,
+) {
     // Account for gap between master and stack
     let available_width = screen_frame.width - gaps.inner_h;
-
-    // Stack windows (left side, stacked vertically)
     let stack_width = available_width * (1.0 - ratio);
-    let stack_count = window_ids.len() - 1;
-    let total_stack_gap = gaps.inner_v * (stack_count - 1) as f64;
-    let stack_height = (screen_frame.height - total_stack_gap) / stack_count as f64;
 
-    // Master window (right side)
+    // Master area (right side)
     let master_width = available_width * ratio;
     let master_x = screen_frame.x + stack_width + gaps.inner_h;
-    let master_frame = Rect::new(master_x, screen_frame.y, master_width, screen_frame.height);
-    result.push((window_ids[0], master_frame));
+    let master_area = Rect::new(master_x, screen_frame.y, master_width, screen_frame.height);
+    push_column(result, masters, &master_area, gaps.inner_v);
 
-    for (i, &id) in window_ids.iter().skip(1).enumerate() {
-        let y = (i as f64).mul_add(stack_height + gaps.inner_v, screen_frame.y);
-        let frame = Rect::new(screen_frame.x, y, stack_width, stack_height);
-        result.push((id, frame));
-    }
-
-    result
+    // Stack area (left side)
+    let stack_area = Rect::new(screen_frame.x, screen_frame.y, stack_width, screen_frame.height);
+    push_column(result, stack, &stack_area, gaps.inner_v);
 }
 
-/// Master on top, stack below (arranged horizontally).
-#[allow(clippy::cast_precision_loss)]
-fn layout_top(window_ids: &[u32], screen_frame: &Rect, ratio: f64, gaps: &Gaps) -> LayoutResult {
-    let mut result: LayoutResult = SmallVec::with_capacity(window_ids.len().min(LAYOUT_INLINE_CAP));
-
+/// Master on top, stack below (both arranged horizontally).
+fn layout_top(
+    result: &mut LayoutResult,
+    masters: &[u32],
+    stack: &[u32],
+    screen_frame: &Rect,
+    ratio: f64,
+    gaps: &Gaps,
+) {
     // Account for gap between master and stack
     let available_height = screen_frame.height - gaps.inner_v;
 
-    // Master window (top)
+    // Master area (top)
     let master_height = available_height * ratio;
-    let master_frame = Rect::new(screen_frame.x, screen_frame.y, screen_frame.width, master_height);
-    result.push((window_ids[0], master_frame));
+    let master_area = Rect::new(screen_frame.x, screen_frame.y, screen_frame.width, master_height);
+    push_row(result, masters, &master_area, gaps.inner_h);
 
-    // Stack windows (bottom, arranged horizontally)
+    // Stack area (bottom)
     let stack_y = screen_frame.y + master_height + gaps.inner_v;
     let stack_height = available_height - master_height;
-    let stack_count = window_ids.len() - 1;
-    let total_stack_gap = gaps.inner_h * (stack_count - 1) as f64;
-    let stack_width = (screen_frame.width - total_stack_gap) / stack_count as f64;
-
-    for (i, &id) in window_ids.iter().skip(1).enumerate() {
-        let x = (i as f64).mul_add(stack_width + gaps.inner_h, screen_frame.x);
-        let frame = Rect::new(x, stack_y, stack_width, stack_height);
-        result.push((id, frame));
-    }
-
-    result
+    let stack_area = Rect::new(screen_frame.x, stack_y, screen_frame.width, stack_height);
+    push_row(result, stack, &stack_area, gaps.inner_h);
 }
 
-/// Master on bottom, stack above (arranged horizontally).
-#[allow(clippy::cast_precision_loss)]
-fn layout_bottom(window_ids: &[u32], screen_frame: &Rect, ratio: f64, gaps: &Gaps) -> LayoutResult {
-    let mut result: LayoutResult = SmallVec::with_capacity(window_ids.len().min(LAYOUT_INLINE_CAP));
-
+/// Master on bottom, stack above (both arranged horizontally).
+fn layout_bottom(
+    result: &mut LayoutResult,
+    masters: &[u32],
+    stack: &[u32],
+    screen_frame: &Rect,
+    ratio: f64,
+    gaps: &Gaps,
+) {
     // Account for gap between master and stack
     let available_height = screen_frame.height - gaps.inner_v;
-
-    // Stack windows (top, arranged horizontally)
     let stack_height = available_height * (1.0 - ratio);
-    let stack_count = window_ids.len() - 1;
-    let total_stack_gap = gaps.inner_h * (stack_count - 1) as f64;
-    let stack_width = (screen_frame.width - total_stack_gap) / stack_count as f64;
 
-    // Master window (bottom)
+    // Master area (bottom)
     let master_height = available_height * ratio;
     let master_y = screen_frame.y + stack_height + gaps.inner_v;
-    let master_frame = Rect::new(screen_frame.x, master_y, screen_frame.width, master_height);
-    result.push((window_ids[0], master_frame));
+    let master_area = Rect::new(screen_frame.x, master_y, screen_frame.width, master_height);
+    push_row(result, masters, &master_area, gaps.inner_h);
 
-    for (i, &id) in window_ids.iter().skip(1).enumerate() {
-        let x = (i as f64).mul_add(stack_width + gaps.inner_h, screen_frame.x);
-        let frame = Rect::new(x, screen_frame.y, stack_width, stack_height);
-        result.push((id, frame));
+    // Stack area (top)
+    let stack_area = Rect::new(screen_frame.x, screen_frame.y, screen_frame.width, stack_height);
+    push_row(result, stack, &stack_area, gaps.inner_h);
+}
+
+/// Divides `area` evenly between `window_ids`, stacked top to bottom.
+#[allow(clippy::cast_precision_loss)] // Window counts won't exceed f64 precision
+fn push_column(result: &mut LayoutResult, window_ids: &[u32], area: &Rect, gap: f64) {
+    let count = window_ids.len();
+    let total_gap = gap * (count - 1) as f64;
+    let height = (area.height - total_gap) / count as f64;
+
+    for (i, &id) in window_ids.iter().enumerate() {
+        let y = (i as f64).mul_add(height + gap, area.y);
+        result.push((id, Rect::new(area.x, y, area.width, height)));
     }
+}
 
-    result
+/// Divides `area` evenly between `window_ids`, arranged left to right.
+#[allow(clippy::cast_precision_loss)] // Window counts won't exceed f64 precision
+fn push_row(result: &mut LayoutResult, window_ids: &[u32], area: &Rect, gap: f64) {
+    let count = window_ids.len();
+    let total_gap = gap * (count - 1) as f64;
+    let width = (area.width - total_gap) / count as f64;
+
+    for (i, &id) in window_ids.iter().enumerate() {
+        let x = (i as f64).mul_add(width + gap, area.x);
+        result.push((id, Rect::new(x, area.y, width, area.height)));
+    }
 }
 
 // ============================================================================
@@ -246,14 +271,14 @@ mod tests {
 
     #[test]
     fn test_master_empty() {
-        let result = layout(&[], &landscape_frame(), 0.6, &no_gaps(), MasterPosition::Auto);
+        let result = layout(&[], &landscape_frame(), 0.6, &no_gaps(), MasterPosition::Auto, 1);
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_master_single_window_landscape() {
         let frame = landscape_frame();
-        let result = layout(&[1], &frame, 0.6, &no_gaps(), MasterPosition::Auto);
+        let result = layout(&[1], &frame, 0.6, &no_gaps(), MasterPosition::Auto, 1);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0], (1, frame));
@@ -262,7 +287,7 @@ mod tests {
     #[test]
     fn test_master_single_window_portrait() {
         let frame = portrait_frame();
-        let result = layout(&[1], &frame, 0.6, &no_gaps(), MasterPosition::Auto);
+        let result = layout(&[1], &frame, 0.6, &no_gaps(), MasterPosition::Auto, 1);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0], (1, frame));
@@ -276,7 +301,7 @@ mod tests {
     fn test_auto_landscape_two_windows() {
         let frame = landscape_frame();
         let ratio = 0.6;
-        let result = layout(&[1, 2], &frame, ratio, &no_gaps(), MasterPosition::Auto);
+        let result = layout(&[1, 2], &frame, ratio, &no_gaps(), MasterPosition::Auto, 1);
 
         assert_eq!(result.len(), 2);
 
@@ -301,7 +326,7 @@ mod tests {
     fn test_auto_portrait_two_windows() {
         let frame = portrait_frame();
         let ratio = 0.6;
-        let result = layout(&[1, 2], &frame, ratio, &no_gaps(), MasterPosition::Auto);
+        let result = layout(&[1, 2], &frame, ratio, &no_gaps(), MasterPosition::Auto, 1);
 
         assert_eq!(result.len(), 2);
 
@@ -330,7 +355,7 @@ mod tests {
     fn test_left_position() {
         let frame = landscape_frame();
         let ratio = 0.6;
-        let result = layout(&[1, 2, 3], &frame, ratio, &no_gaps(), MasterPosition::Left);
+        let result = layout(&[1, 2, 3], &frame, ratio, &no_gaps(), MasterPosition::Left, 1);
 
         assert_eq!(result.len(), 3);
 
@@ -356,7 +381,7 @@ mod tests {
     fn test_right_position() {
         let frame = landscape_frame();
         let ratio = 0.6;
-        let result = layout(&[1, 2, 3], &frame, ratio, &no_gaps(), MasterPosition::Right);
+        let result = layout(&[1, 2, 3], &frame, ratio, &no_gaps(), MasterPosition::Right, 1);
 
         assert_eq!(result.len(), 3);
 
@@ -382,7 +407,7 @@ mod tests {
     fn test_top_position() {
         let frame = landscape_frame();
         let ratio = 0.6;
-        let result = layout(&[1, 2, 3], &frame, ratio, &no_gaps(), MasterPosition::Top);
+        let result = layout(&[1, 2, 3], &frame, ratio, &no_gaps(), MasterPosition::Top, 1);
 
         assert_eq!(result.len(), 3);
 
@@ -408,7 +433,7 @@ mod tests {
     fn test_bottom_position() {
         let frame = landscape_frame();
         let ratio = 0.6;
-        let result = layout(&[1, 2, 3], &frame, ratio, &no_gaps(), MasterPosition::Bottom);
+        let result = layout(&[1, 2, 3], &frame, ratio, &no_gaps(), MasterPosition::Bottom, 1);
 
         assert_eq!(result.len(), 3);
 
@@ -434,7 +459,7 @@ mod tests {
     fn test_left_with_gaps() {
         let frame = landscape_frame();
         let gaps = Gaps::uniform(16.0, 0.0);
-        let result = layout(&[1, 2, 3], &frame, 0.5, &gaps, MasterPosition::Left);
+        let result = layout(&[1, 2, 3], &frame, 0.5, &gaps, MasterPosition::Left, 1);
 
         let (_, master) = result[0];
         let (_, stack1) = result[1];
@@ -453,7 +478,7 @@ mod tests {
     fn test_top_with_gaps() {
         let frame = landscape_frame();
         let gaps = Gaps::uniform(16.0, 0.0);
-        let result = layout(&[1, 2, 3], &frame, 0.5, &gaps, MasterPosition::Top);
+        let result = layout(&[1, 2, 3], &frame, 0.5, &gaps, MasterPosition::Top, 1);
 
         let (_, master) = result[0];
         let (_, stack1) = result[1];
@@ -475,7 +500,7 @@ mod tests {
     #[test]
     fn test_master_ratio_clamping_low() {
         let frame = landscape_frame();
-        let result = layout(&[1, 2], &frame, 0.0, &no_gaps(), MasterPosition::Left);
+        let result = layout(&[1, 2], &frame, 0.0, &no_gaps(), MasterPosition::Left, 1);
 
         let (_, master) = result[0];
         // Should be clamped to 10%
@@ -485,7 +510,7 @@ mod tests {
     #[test]
     fn test_master_ratio_clamping_high() {
         let frame = landscape_frame();
-        let result = layout(&[1, 2], &frame, 1.0, &no_gaps(), MasterPosition::Left);
+        let result = layout(&[1, 2], &frame, 1.0, &no_gaps(), MasterPosition::Left, 1);
 
         let (_, master) = result[0];
         // Should be clamped to 90%
@@ -499,7 +524,14 @@ mod tests {
     #[test]
     fn test_left_many_windows() {
         let frame = landscape_frame();
-        let result = layout(&[1, 2, 3, 4, 5], &frame, 0.5, &no_gaps(), MasterPosition::Left);
+        let result = layout(
+            &[1, 2, 3, 4, 5],
+            &frame,
+            0.5,
+            &no_gaps(),
+            MasterPosition::Left,
+            1,
+        );
 
         assert_eq!(result.len(), 5);
 
@@ -515,7 +547,7 @@ mod tests {
     #[test]
     fn test_top_many_windows() {
         let frame = portrait_frame();
-        let result = layout(&[1, 2, 3, 4, 5], &frame, 0.5, &no_gaps(), MasterPosition::Top);
+        let result = layout(&[1, 2, 3, 4, 5], &frame, 0.5, &no_gaps(), MasterPosition::Top, 1);
 
         assert_eq!(result.len(), 5);
 
@@ -528,11 +560,104 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // Multiple Masters Tests
+    // ========================================================================
+
+    #[test]
+    fn test_left_two_masters() {
+        let frame = landscape_frame();
+        let result = layout(
+            &[1, 2, 3, 4, 5],
+            &frame,
+            0.6,
+            &no_gaps(),
+            MasterPosition::Left,
+            2,
+        );
+
+        assert_eq!(result.len(), 5);
+
+        // Masters share the left column, stacked vertically
+        let (_, master1) = result[0];
+        let (_, master2) = result[1];
+        assert_eq!(master1.x, 0.0);
+        assert_eq!(master2.x, 0.0);
+        assert!((master1.width - frame.width * 0.6).abs() < 1.0);
+        assert!((master1.height - frame.height / 2.0).abs() < 1.0);
+        assert!((master2.y - frame.height / 2.0).abs() < 1.0);
+
+        // Remaining three windows share the stack column
+        for (_, stack_frame) in result.iter().skip(2) {
+            assert!(stack_frame.x > master1.x);
+            assert!((stack_frame.height - frame.height / 3.0).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_top_two_masters() {
+        let frame = portrait_frame();
+        let result = layout(&[1, 2, 3], &frame, 0.5, &no_gaps(), MasterPosition::Top, 2);
+
+        let (_, master1) = result[0];
+        let (_, master2) = result[1];
+        let (_, stack) = result[2];
+
+        // Masters share the top row, arranged horizontally
+        assert_eq!(master1.y, 0.0);
+        assert_eq!(master2.y, 0.0);
+        assert!((master1.width - frame.width / 2.0).abs() < 1.0);
+        assert!(master2.x > master1.x);
+
+        // Single stack window fills the bottom row
+        assert_eq!(stack.width, frame.width);
+        assert!(stack.y > master1.y);
+    }
+
+    #[test]
+    fn test_masters_with_gaps() {
+        let frame = landscape_frame();
+        let gaps = Gaps::uniform(16.0, 0.0);
+        let result = layout(&[1, 2, 3], &frame, 0.5, &gaps, MasterPosition::Left, 2);
+
+        let (_, master1) = result[0];
+        let (_, master2) = result[1];
+
+        // Gap between masters (vertical)
+        let gap_v = master2.y - (master1.y + master1.height);
+        assert!((gap_v - 16.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_all_windows_masters_fill_screen() {
+        let frame = landscape_frame();
+        let result = layout(&[1, 2], &frame, 0.6, &no_gaps(), MasterPosition::Left, 3);
+
+        assert_eq!(result.len(), 2);
+
+        // No stack - masters split the full frame
+        for (_, master) in &result {
+            assert_eq!(master.width, frame.width);
+            assert!((master.height - frame.height / 2.0).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_zero_masters_treated_as_one() {
+        let frame = landscape_frame();
+        let result = layout(&[1, 2, 3], &frame, 0.6, &no_gaps(), MasterPosition::Left, 0);
+
+        assert_eq!(
+            result,
+            layout(&[1, 2, 3], &frame, 0.6, &no_gaps(), MasterPosition::Left, 1)
+        );
+    }
+
     #[test]
     fn test_square_screen_auto_uses_left() {
         // Square screens (width == height) with Auto should use Left
         let frame = Rect::new(0.0, 0.0, 1000.0, 1000.0);
-        let result = layout(&[1, 2], &frame, 0.6, &no_gaps(), MasterPosition::Auto);
+        let result = layout(&[1, 2], &frame, 0.6, &no_gaps(), MasterPosition::Auto, 1);
 
         let (_, master) = result[0];
         let (_, stack) = result[1];
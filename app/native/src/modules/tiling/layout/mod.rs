@@ -8,10 +8,13 @@
 //!
 //! - **Floating**: Windows keep their current positions (no tiling)
 //! - **Monocle**: All windows maximized to fill the screen
+//! - **Tabbed**: Like monocle, with the stacked windows exposed as tabs
 //! - **Dwindle**: Binary Space Partitioning - windows arranged in a dwindling spiral
 //! - **Split**: Windows split evenly (auto, vertical, or horizontal)
 //! - **Master**: One master window with remaining windows in a stack
 //! - **Grid**: Windows arranged in a balanced grid pattern
+//! - **Columns**: Equal-width, full-height columns from left to right
+//! - **Scrolling**: Fixed-width columns on a horizontally scrolling strip
 //!
 //! # Performance
 //!
@@ -44,6 +47,7 @@
 //! }
 //! ```
 
+mod columns;
 mod dwindle;
 mod floating;
 mod gaps;
@@ -51,13 +55,18 @@ mod grid;
 mod helpers;
 mod master;
 mod monocle;
+mod scrolling;
 mod split;
+mod tabbed;
 
-pub use floating::{calculate_preset_frame, find_preset, list_preset_names};
+pub use dwindle::{DwindleNode, SplitOrientation, tree as dwindle_tree};
+pub use floating::{calculate_preset_frame, center_frame, find_preset, list_preset_names};
 pub use gaps::Gaps;
 pub use grid::MAX_GRID_WINDOWS;
 pub use master::MasterPosition;
+pub use scrolling::{ScrollingViewport, clamp_offset, reveal_offset};
 use smallvec::SmallVec;
+pub use tabbed::{LayoutTab, build_tabs};
 
 use crate::modules::tiling::state::{LayoutType, Rect};
 
@@ -132,6 +141,7 @@ pub fn calculate_layout_with_gaps(
         gaps,
         &[],
         MasterPosition::Auto,
+        1,
     )
 }
 
@@ -146,6 +156,7 @@ pub fn calculate_layout_with_gaps(
 /// * `gaps` - Gap values for spacing
 /// * `split_ratios` - Custom split ratios for split layouts (cumulative 0.0-1.0)
 /// * `master_position` - Position of master window (left/right/top/bottom/auto)
+/// * `master_count` - Number of master windows for master layout
 ///
 /// # Returns
 ///
@@ -159,6 +170,7 @@ pub fn calculate_layout_full(
     gaps: &Gaps,
     split_ratios: &[f64],
     master_position: MasterPosition,
+    master_count: usize,
 ) -> LayoutResult {
     if window_ids.is_empty() {
         return SmallVec::new();
@@ -170,6 +182,7 @@ pub fn calculate_layout_full(
     match layout {
         LayoutType::Floating => SmallVec::new(), // No repositioning for floating
         LayoutType::Monocle => monocle::layout(window_ids, &usable_frame),
+        LayoutType::Tabbed => tabbed::layout(window_ids, &usable_frame),
         LayoutType::Dwindle => dwindle::layout(window_ids, &usable_frame, gaps, split_ratios),
         LayoutType::Split => split::layout_auto(window_ids, &usable_frame, gaps, split_ratios),
         LayoutType::SplitVertical => {
@@ -178,10 +191,24 @@ pub fn calculate_layout_full(
         LayoutType::SplitHorizontal => {
             split::layout_horizontal(window_ids, &usable_frame, gaps, split_ratios)
         }
-        LayoutType::Master => {
-            master::layout(window_ids, &usable_frame, master_ratio, gaps, master_position)
-        }
+        LayoutType::Master => master::layout(
+            window_ids,
+            &usable_frame,
+            master_ratio,
+            gaps,
+            master_position,
+            master_count,
+        ),
         LayoutType::Grid => grid::layout(window_ids, &usable_frame, gaps, split_ratios),
+        LayoutType::Columns => columns::layout(window_ids, &usable_frame, gaps, split_ratios),
+        // Without workspace context, use the default column width and no scrolling.
+        // The actor uses `ScrollingViewport` directly to apply both.
+        LayoutType::Scrolling => ScrollingViewport::with_column_width(
+            usable_frame,
+            gaps,
+            usable_frame.width * scrolling::DEFAULT_COLUMN_RATIO,
+        )
+        .layout(window_ids, 0),
     }
 }
 
@@ -211,12 +238,15 @@ mod tests {
         // Each layout type should produce results
         let layouts = [
             LayoutType::Monocle,
+            LayoutType::Tabbed,
             LayoutType::Dwindle,
             LayoutType::Split,
             LayoutType::SplitVertical,
             LayoutType::SplitHorizontal,
             LayoutType::Master,
             LayoutType::Grid,
+            LayoutType::Columns,
+            LayoutType::Scrolling,
         ];
 
         for layout in layouts {
@@ -287,6 +317,7 @@ mod tests {
             &gaps,
             &[],
             MasterPosition::Right,
+            1,
         );
 
         assert_eq!(result.len(), 2);
@@ -312,6 +343,7 @@ mod tests {
             &gaps,
             &ratios,
             MasterPosition::Auto,
+            1,
         );
 
         assert_eq!(result.len(), 2);
@@ -0,0 +1,223 @@
+//! Scrolling layout - fixed-width columns on a horizontal strip.
+//!
+//! Every window gets its own full-height column. Columns are laid out left
+//! to right and may extend past the screen edge; the workspace's scroll
+//! offset (the index of the leftmost visible column) decides which part of
+//! the strip is on screen.
+
+use super::{Gaps, LayoutResult};
+use crate::config::DimensionValue;
+use crate::modules::tiling::state::Rect;
+
+/// Fraction of the usable width taken by a column when no width is configured.
+pub const DEFAULT_COLUMN_RATIO: f64 = 0.5;
+
+/// Resolved geometry of a scrolling workspace.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollingViewport {
+    /// Usable frame of the screen (outer gaps applied).
+    frame: Rect,
+    /// Width of a single column in pixels.
+    column_width: f64,
+    /// Horizontal gap between columns.
+    gap: f64,
+}
+
+impl ScrollingViewport {
+    /// Creates a viewport for the given screen frame and configured column width.
+    ///
+    /// Percentages are resolved against the usable width (outer gaps applied).
+    #[must_use]
+    pub fn new(screen_frame: &Rect, gaps: &Gaps, column_width: &DimensionValue) -> Self {
+        let frame = gaps.apply_outer(screen_frame);
+        Self::with_column_width(frame, gaps, column_width.resolve(frame.width))
+    }
+
+    /// Creates a viewport from an already usable frame and a column width in pixels.
+    ///
+    /// Non-positive widths fall back to [`DEFAULT_COLUMN_RATIO`] and widths
+    /// larger than the frame are capped to it.
+    #[must_use]
+    pub fn with_column_width(frame: Rect, gaps: &Gaps, column_width: f64) -> Self {
+        let column_width = if column_width > 0.0 {
+            column_width.min(frame.width)
+        } else {
+            frame.width * DEFAULT_COLUMN_RATIO
+        };
+
+        Self {
+            frame,
+            column_width,
+            gap: gaps.inner_h,
+        }
+    }
+
+    /// Returns how many columns fit on screen at once (at least one).
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn visible_columns(&self) -> usize {
+        let step = self.column_width + self.gap;
+        if step <= 0.0 {
+            return 1;
+        }
+
+        // Small epsilon so that e.g. two 50% columns count as two
+        (((self.frame.width + self.gap) / step) + 1e-6).floor().max(1.0) as usize
+    }
+
+    /// Arranges the windows as columns, starting at the column `offset`.
+    ///
+    /// The offset is clamped so the strip never scrolls past its last column.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn layout(&self, window_ids: &[u32], offset: usize) -> LayoutResult {
+        let offset = clamp_offset(offset, window_ids.len(), self.visible_columns());
+        let step = self.column_width + self.gap;
+
+        window_ids
+            .iter()
+            .enumerate()
+            .map(|(index, &id)| {
+                let column = index as f64 - offset as f64;
+                let frame = Rect::new(
+                    self.frame.x + column * step,
+                    self.frame.y,
+                    self.column_width,
+                    self.frame.height,
+                );
+                (id, frame)
+            })
+            .collect()
+    }
+}
+
+/// Clamps a scroll offset so the last column is never scrolled past.
+#[must_use]
+pub fn clamp_offset(offset: usize, column_count: usize, visible_columns: usize) -> usize {
+    offset.min(column_count.saturating_sub(visible_columns.max(1)))
+}
+
+/// Returns the smallest change to `offset` that brings column `index` on screen.
+#[must_use]
+pub fn reveal_offset(index: usize, offset: usize, visible_columns: usize) -> usize {
+    let visible_columns = visible_columns.max(1);
+
+    if index < offset {
+        index
+    } else if index >= offset + visible_columns {
+        index + 1 - visible_columns
+    } else {
+        offset
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    fn screen_frame() -> Rect { Rect::new(0.0, 0.0, 1920.0, 1080.0) }
+
+    fn viewport(column_width: f64) -> ScrollingViewport {
+        ScrollingViewport::with_column_width(screen_frame(), &Gaps::default(), column_width)
+    }
+
+    #[test]
+    fn test_scrolling_empty() {
+        assert!(viewport(960.0).layout(&[], 0).is_empty());
+    }
+
+    #[test]
+    fn test_scrolling_columns_left_to_right() {
+        let result = viewport(960.0).layout(&[1, 2, 3], 0);
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0], (1, Rect::new(0.0, 0.0, 960.0, 1080.0)));
+        assert_eq!(result[1], (2, Rect::new(960.0, 0.0, 960.0, 1080.0)));
+        // Third column is off screen to the right
+        assert_eq!(result[2], (3, Rect::new(1920.0, 0.0, 960.0, 1080.0)));
+    }
+
+    #[test]
+    fn test_scrolling_offset_shifts_columns() {
+        let result = viewport(960.0).layout(&[1, 2, 3], 1);
+
+        assert_eq!(result[0].1.x, -960.0);
+        assert_eq!(result[1].1.x, 0.0);
+        assert_eq!(result[2].1.x, 960.0);
+    }
+
+    #[test]
+    fn test_scrolling_offset_is_clamped() {
+        // Only one column can be hidden with three 50% columns
+        let result = viewport(960.0).layout(&[1, 2, 3], 5);
+        assert_eq!(result[2].1.x, 960.0);
+
+        // Everything fits, so there is nothing to scroll
+        let result = viewport(960.0).layout(&[1, 2], 3);
+        assert_eq!(result[0].1.x, 0.0);
+    }
+
+    #[test]
+    fn test_scrolling_respects_gaps() {
+        let gaps = Gaps::new(10.0, 10.0, 20.0, 20.0, 20.0, 20.0);
+        let frame = gaps.apply_outer(&screen_frame());
+        let result = ScrollingViewport::with_column_width(frame, &gaps, 800.0).layout(&[1, 2], 0);
+
+        assert_eq!(result[0].1, Rect::new(20.0, 20.0, 800.0, 1040.0));
+        assert_eq!(result[1].1.x, 830.0);
+    }
+
+    #[test]
+    fn test_scrolling_column_width_fallback() {
+        let result = viewport(0.0).layout(&[1], 0);
+        assert_eq!(result[0].1.width, 960.0);
+
+        let result = viewport(5000.0).layout(&[1], 0);
+        assert_eq!(result[0].1.width, 1920.0);
+    }
+
+    #[test]
+    fn test_scrolling_resolves_percentage() {
+        let viewport = ScrollingViewport::new(
+            &screen_frame(),
+            &Gaps::default(),
+            &DimensionValue::Percentage("25%".to_string()),
+        );
+
+        assert_eq!(viewport.visible_columns(), 4);
+        assert_eq!(viewport.layout(&[1], 0)[0].1.width, 480.0);
+    }
+
+    #[test]
+    fn test_visible_columns() {
+        assert_eq!(viewport(960.0).visible_columns(), 2);
+        assert_eq!(viewport(1000.0).visible_columns(), 1);
+        assert_eq!(viewport(1920.0).visible_columns(), 1);
+        assert_eq!(viewport(640.0).visible_columns(), 3);
+    }
+
+    #[test]
+    fn test_clamp_offset() {
+        assert_eq!(clamp_offset(3, 5, 2), 3);
+        assert_eq!(clamp_offset(4, 5, 2), 3);
+        assert_eq!(clamp_offset(2, 1, 2), 0);
+        assert_eq!(clamp_offset(2, 3, 0), 2);
+    }
+
+    #[test]
+    fn test_reveal_offset() {
+        // Already visible
+        assert_eq!(reveal_offset(1, 0, 2), 0);
+        // Past the right edge scrolls just far enough
+        assert_eq!(reveal_offset(2, 0, 2), 1);
+        assert_eq!(reveal_offset(5, 0, 2), 4);
+        // Past the left edge aligns the column to the left
+        assert_eq!(reveal_offset(0, 3, 2), 0);
+        assert_eq!(reveal_offset(2, 3, 2), 2);
+    }
+}
@@ -0,0 +1,134 @@
+//! Tabbed layout - all windows share one region with a tab strip.
+//!
+//! Positioning is identical to monocle: every window gets the full usable
+//! frame. The difference is that the frontend is told which windows are
+//! stacked (via the tabs event) so it can render a tab strip for them.
+
+use serde::Serialize;
+
+use super::{LayoutResult, monocle};
+use crate::modules::tiling::state::{Rect, Window};
+
+/// A single tab in a tabbed workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutTab {
+    /// The window this tab represents.
+    pub window_id: u32,
+    /// The window title.
+    pub title: String,
+    /// The owning application name.
+    pub app_name: String,
+    /// Whether this is the currently focused tab.
+    pub is_focused: bool,
+}
+
+/// Tabbed layout - all windows share the same frame.
+///
+/// Reuses the monocle positioning so every window fills the usable frame.
+///
+/// # Arguments
+///
+/// * `window_ids` - IDs of windows to arrange
+/// * `screen_frame` - The visible frame of the screen (already has outer gaps applied)
+#[must_use]
+pub fn layout(window_ids: &[u32], screen_frame: &Rect) -> LayoutResult {
+    monocle::layout(window_ids, screen_frame)
+}
+
+/// Builds the tab list for the given windows, in workspace order.
+///
+/// # Arguments
+///
+/// * `windows` - The layoutable windows of the workspace, in order
+/// * `focused_window_id` - The currently focused window, if any
+#[must_use]
+pub fn build_tabs(windows: &[Window], focused_window_id: Option<u32>) -> Vec<LayoutTab> {
+    windows
+        .iter()
+        .map(|window| LayoutTab {
+            window_id: window.id,
+            title: window.title.clone(),
+            app_name: window.app_name.clone(),
+            is_focused: focused_window_id == Some(window.id),
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen_frame() -> Rect { Rect::new(0.0, 0.0, 1920.0, 1080.0) }
+
+    fn window(id: u32, title: &str) -> Window {
+        Window {
+            id,
+            title: title.to_string(),
+            app_name: "Safari".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_tabbed_empty() {
+        let result = layout(&[], &screen_frame());
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_tabbed_matches_monocle() {
+        let frame = screen_frame();
+        let ids = [3, 1, 2];
+
+        assert_eq!(layout(&ids, &frame), monocle::layout(&ids, &frame));
+    }
+
+    #[test]
+    fn test_tabbed_tabs_preserve_order() {
+        let windows = vec![window(5, "First"), window(3, "Second"), window(8, "Third")];
+        let tabs = build_tabs(&windows, Some(3));
+
+        assert_eq!(tabs.len(), 3);
+        assert_eq!(tabs[0].window_id, 5);
+        assert_eq!(tabs[1].window_id, 3);
+        assert_eq!(tabs[2].window_id, 8);
+        assert_eq!(tabs[1].title, "Second");
+    }
+
+    #[test]
+    fn test_tabbed_tabs_mark_focused() {
+        let windows = vec![window(1, "One"), window(2, "Two")];
+        let tabs = build_tabs(&windows, Some(2));
+
+        assert!(!tabs[0].is_focused);
+        assert!(tabs[1].is_focused);
+    }
+
+    #[test]
+    fn test_tabbed_tabs_without_focus() {
+        let windows = vec![window(1, "One"), window(2, "Two")];
+        let tabs = build_tabs(&windows, None);
+
+        assert!(tabs.iter().all(|tab| !tab.is_focused));
+    }
+
+    #[test]
+    fn test_tabbed_tab_serialization() {
+        let tab = LayoutTab {
+            window_id: 1,
+            title: "Docs".to_string(),
+            app_name: "Safari".to_string(),
+            is_focused: true,
+        };
+        let json = serde_json::to_value(&tab).unwrap();
+
+        assert_eq!(json["windowId"], 1);
+        assert_eq!(json["appName"], "Safari");
+        assert_eq!(json["isFocused"], true);
+    }
+}
@@ -56,23 +56,23 @@ pub use actor::{StateActor, StateActorHandle, StateMessage, StateQuery};
 pub use commands::{is_tiling_enabled, layout_to_string_pub};
 pub use effects::{
     AnimationConfig, AnimationSystem, BorderState, EffectExecutor, EffectSubscriber,
-    EffectSubscriberHandle, FocusChange, LayoutChange, TilingEffect, VisibilityChange,
-    WindowTransition, begin_animation, cancel_animation, focus_window, get_interrupted_position,
-    get_window_frame, is_animation_active, is_animation_settling, raise_window, set_window_frame,
-    set_window_frame_fast, should_ignore_geometry_events,
+    EffectSubscriberHandle, FocusChange, LayoutChange, TilingEffect, TransitionKind,
+    VisibilityChange, WindowTransition, begin_animation, cancel_animation, focus_window,
+    get_interrupted_position, get_window_frame, is_animation_active, is_animation_settling,
+    raise_window, set_window_frame, set_window_frame_fast, should_ignore_geometry_events,
 };
 pub use events::{
     AXObserverAdapter, AppMonitorAdapter, EventProcessor, ScreenMonitorAdapter, WindowEvent,
     WindowEventType,
 };
 pub use init::{
-    emit_layout_applied, emit_window_focus_changed, emit_window_tracked, emit_window_untracked,
-    emit_workspace_changed, get_handle, get_subscriber_handle, init, is_enabled, is_initialized,
-    shutdown,
+    WindowSort, emit_layout_applied, emit_tabs_changed, emit_window_focus_changed,
+    emit_window_tracked, emit_window_untracked, emit_workspace_changed, get_handle,
+    get_subscriber_handle, init, is_enabled, is_initialized, shutdown,
 };
 pub use layout::{
-    Gaps, LAYOUT_INLINE_CAP, LayoutResult, MAX_GRID_WINDOWS, MasterPosition, calculate_layout,
-    calculate_layout_full, calculate_layout_with_gaps,
+    Gaps, LAYOUT_INLINE_CAP, LayoutResult, LayoutTab, MAX_GRID_WINDOWS, MasterPosition, build_tabs,
+    calculate_layout, calculate_layout_full, calculate_layout_with_gaps,
 };
 pub use state::{FocusState, LayoutType, Rect, Screen, TilingState, Window, Workspace};
 pub use window::{
@@ -16,9 +16,12 @@
 //!
 //! // Rule: app-id = "com.apple.Safari", title = "Settings"
 //! // Matches: Safari windows with "Settings" in title (AND logic)
+//!
+//! // Rule: title-regex = "^Figma - "
+//! // Matches: Windows whose title starts with "Figma - "
 //! ```
 
-use crate::config::WindowRule;
+use crate::config::{FloatRule, TilingConfig, WindowRule};
 use crate::modules::tiling::state::Window;
 
 /// Checks if a window matches a rule.
@@ -31,6 +34,12 @@ use crate::modules::tiling::state::Window;
 /// - `app_id`: Exact match against bundle identifier (case-insensitive)
 /// - `app_name`: Case-insensitive substring match
 /// - `title`: Case-insensitive substring match
+/// - `app_name_regex`: Regex match against the application name
+/// - `title_regex`: Regex match against the window title
+/// - `role`, `subrole`: Exact match against the accessibility role and subrole
+///   (case-insensitive)
+///
+/// Invalid regex patterns never match.
 #[must_use]
 pub fn matches_window(rule: &WindowRule, window: &Window) -> bool {
     // Rule must have at least one criterion
@@ -85,7 +94,17 @@ pub fn matches_window(rule: &WindowRule, window: &Window) -> bool {
         }
     }
 
-    true
+    // Check regex patterns - compiled once by prepare()
+    if rule.app_name_regex.is_some() && !rule.app_name_regex_matches(&window.app_name) {
+        return false;
+    }
+
+    if rule.title_regex.is_some() && !rule.title_regex_matches(&window.title) {
+        return false;
+    }
+
+    // Check role and subrole - case-insensitive exact match
+    rule.role_matches(window.role.as_deref(), window.subrole.as_deref())
 }
 
 /// Result of finding a workspace match for a window.
@@ -140,6 +159,55 @@ pub fn any_rule_matches(rules: &[WindowRule], window: &Window) -> bool {
     rules.iter().any(|rule| matches_window(rule, window))
 }
 
+/// Finds the first float rule that matches a window.
+///
+/// # Arguments
+///
+/// * `rules` - The float rules from `tiling.floatRules`
+/// * `window` - The window to check
+#[must_use]
+pub fn find_float_rule<'a>(rules: &'a [FloatRule], window: &Window) -> Option<&'a FloatRule> {
+    rules.iter().find(|float_rule| matches_window(&float_rule.rule, window))
+}
+
+/// Returns the rules that can carry per-window overrides such as `gaps` and
+/// `borderColor`, in the order they are checked: each workspace's rules in
+/// config order, then `tiling.floatRules`.
+pub fn override_rules(tiling: &TilingConfig) -> impl Iterator<Item = &WindowRule> {
+    tiling
+        .workspaces
+        .iter()
+        .flat_map(|workspace| &workspace.rules)
+        .chain(tiling.float_rules.iter().map(|float_rule| &float_rule.rule))
+}
+
+/// Finds the override `field` reads from the rules matching a window.
+///
+/// Overlapping overrides resolve to the first matching rule that sets one.
+#[must_use]
+pub fn find_rule_override<'a, T>(
+    rules: impl IntoIterator<Item = &'a WindowRule>,
+    window: &Window,
+    field: impl Fn(&'a WindowRule) -> Option<T>,
+) -> Option<T> {
+    rules
+        .into_iter()
+        .find_map(|rule| field(rule).filter(|_| matches_window(rule, window)))
+}
+
+/// Returns whether a window's frame goes through the `osascript` fallback.
+///
+/// True when `tiling.applescriptFallback` is enabled and the first matching
+/// rule that sets `useApplescriptFrames` enables it.
+#[must_use]
+pub fn uses_applescript_frames(tiling: &TilingConfig, window: &Window) -> bool {
+    tiling.applescript_fallback
+        && find_rule_override(override_rules(tiling), window, |rule| {
+            rule.use_applescript_frames
+        })
+        .unwrap_or(false)
+}
+
 /// Counts how many rules match a window.
 ///
 /// Useful for debugging and testing rule configurations.
@@ -226,6 +294,18 @@ pub fn should_tile_window(bundle_id: &str, app_name: &str) -> bool {
     true
 }
 
+/// Checks if an app is in the `tiling.ignoreApps` list.
+///
+/// Entries match either the bundle ID or the app name, exactly and
+/// case-insensitively. Ignored apps are never tracked at all.
+#[must_use]
+pub fn is_app_ignored(ignore_apps: &[String], bundle_id: &str, app_name: &str) -> bool {
+    ignore_apps.iter().any(|entry| {
+        (!bundle_id.is_empty() && entry.eq_ignore_ascii_case(bundle_id))
+            || (!app_name.is_empty() && entry.eq_ignore_ascii_case(app_name))
+    })
+}
+
 /// Checks if an app name should be skipped for tiling.
 #[must_use]
 pub fn should_skip_app_by_name(name: &str) -> bool {
@@ -263,6 +343,8 @@ mod tests {
             app_id: bundle_id.to_string(),
             app_name: app_name.to_string(),
             title: title.to_string(),
+            role: None,
+            subrole: None,
             workspace_id: Uuid::now_v7(),
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             minimum_size: None,
@@ -272,6 +354,8 @@ mod tests {
             is_fullscreen: false,
             is_floating: false,
             is_hidden: false,
+            is_sticky: false,
+            always_on_top: None,
             tab_group_id: None,
             is_active_tab: true,
             matched_rule: None,
@@ -284,9 +368,18 @@ mod tests {
             app_id: app_id.map(String::from),
             app_name: app_name.map(String::from),
             title: title.map(String::from),
-            app_id_lower: None,
-            app_name_lower: None,
-            title_lower: None,
+            ..Default::default()
+        };
+        rule.prepare();
+        rule
+    }
+
+    /// Creates a prepared rule with regex patterns.
+    fn make_regex_rule(app_name_regex: Option<&str>, title_regex: Option<&str>) -> WindowRule {
+        let mut rule = WindowRule {
+            app_name_regex: app_name_regex.map(String::from),
+            title_regex: title_regex.map(String::from),
+            ..Default::default()
         };
         rule.prepare();
         rule
@@ -497,10 +590,186 @@ mod tests {
         assert_eq!(count_matching_rules(&rules, &window), 3);
     }
 
+    #[test]
+    fn test_find_float_rule_first_match_wins() {
+        let window = make_window("com.apple.systempreferences", "System Settings", "Wi-Fi");
+
+        let rules = [
+            FloatRule {
+                rule: make_rule(Some("com.apple.finder"), None, None),
+                ..Default::default()
+            },
+            FloatRule {
+                rule: make_rule(None, Some("System Settings"), None),
+                float: true,
+                preset: Some("centered".to_string()),
+            },
+            FloatRule {
+                rule: make_rule(None, None, Some("Wi-Fi")),
+                float: false,
+                preset: None,
+            },
+        ];
+
+        let found = find_float_rule(&rules, &window).unwrap();
+        assert_eq!(found.preset.as_deref(), Some("centered"));
+
+        let other = make_window("com.apple.Safari", "Safari", "Start Page");
+        assert!(find_float_rule(&rules, &other).is_none());
+    }
+
+    #[test]
+    fn test_find_rule_override_first_matching_rule_wins() {
+        let window = make_window("com.mitchellh.ghostty", "Ghostty", "zsh");
+
+        let with_gaps = |mut rule: WindowRule, gaps: Option<f64>| {
+            rule.gaps = gaps;
+            rule
+        };
+        let rules = [
+            with_gaps(make_rule(Some("com.apple.finder"), None, None), Some(12.0)),
+            with_gaps(make_rule(None, Some("Ghostty"), None), None),
+            with_gaps(make_rule(None, None, Some("zsh")), Some(0.0)),
+            with_gaps(make_rule(None, Some("Ghostty"), None), Some(4.0)),
+        ];
+
+        // The second rule matches but sets no gaps, so the third one wins
+        assert_eq!(find_rule_override(&rules, &window, |rule| rule.gaps), Some(0.0));
+        assert_eq!(
+            find_rule_override(&rules, &window, |rule| rule.border_color.as_deref()),
+            None
+        );
+
+        let other = make_window("com.apple.Safari", "Safari", "Start Page");
+        assert_eq!(find_rule_override(&rules, &other, |rule| rule.gaps), None);
+    }
+
+    #[test]
+    fn test_uses_applescript_frames_requires_fallback_enabled() {
+        let window = make_window("com.example.java", "JavaApp", "Main");
+        let mut rule = make_rule(None, Some("JavaApp"), None);
+        rule.use_applescript_frames = Some(true);
+
+        let mut tiling = TilingConfig {
+            float_rules: vec![FloatRule {
+                rule,
+                float: false,
+                preset: None,
+            }],
+            ..Default::default()
+        };
+        assert!(!uses_applescript_frames(&tiling, &window));
+
+        tiling.applescript_fallback = true;
+        assert!(uses_applescript_frames(&tiling, &window));
+
+        let other = make_window("com.apple.Safari", "Safari", "Start Page");
+        assert!(!uses_applescript_frames(&tiling, &other));
+    }
+
+    #[test]
+    fn test_find_float_rule_by_subrole() {
+        let rules = [FloatRule {
+            rule: WindowRule {
+                subrole: Some("AXDialog".to_string()),
+                ..Default::default()
+            },
+            float: true,
+            preset: None,
+        }];
+
+        let mut dialog = make_window("com.apple.Safari", "Safari", "Clear History");
+        dialog.role = Some("AXWindow".to_string());
+        dialog.subrole = Some("AXDialog".to_string());
+        assert!(find_float_rule(&rules, &dialog).is_some());
+
+        let mut standard = make_window("com.apple.Safari", "Safari", "Start Page");
+        standard.subrole = Some("AXStandardWindow".to_string());
+        assert!(find_float_rule(&rules, &standard).is_none());
+
+        // Windows that don't report a subrole never match
+        let unknown = make_window("com.apple.Safari", "Safari", "Start Page");
+        assert!(find_float_rule(&rules, &unknown).is_none());
+    }
+
     // ========================================================================
     // Window filtering tests
     // ========================================================================
 
+    // ========================================================================
+    // Regex matching tests
+    // ========================================================================
+
+    #[test]
+    fn test_matches_window_title_regex() {
+        let rule = make_regex_rule(None, Some("^Figma - "));
+
+        assert!(matches_window(
+            &rule,
+            &make_window("com.figma.Desktop", "Figma", "Figma - Home")
+        ));
+        assert!(!matches_window(
+            &rule,
+            &make_window("com.figma.Desktop", "Figma", "Home - Figma")
+        ));
+    }
+
+    #[test]
+    fn test_matches_window_app_name_regex() {
+        let rule = make_regex_rule(Some("(?i)^(safari|firefox)$"), None);
+
+        assert!(matches_window(
+            &rule,
+            &make_window("com.apple.Safari", "Safari", "Docs")
+        ));
+        assert!(matches_window(
+            &rule,
+            &make_window("org.mozilla.firefox", "Firefox", "Docs")
+        ));
+        assert!(!matches_window(
+            &rule,
+            &make_window("com.apple.Safari", "Safari Beta", "Docs")
+        ));
+    }
+
+    #[test]
+    fn test_matches_window_regex_combined_with_substring() {
+        let mut rule = make_rule(Some("com.apple.Safari"), None, None);
+        rule.title_regex = Some(r"\d+ tabs$".to_string());
+        rule.prepare();
+
+        assert!(matches_window(
+            &rule,
+            &make_window("com.apple.Safari", "Safari", "Window - 3 tabs")
+        ));
+        assert!(!matches_window(
+            &rule,
+            &make_window("com.apple.Safari", "Safari", "Window")
+        ));
+        assert!(!matches_window(
+            &rule,
+            &make_window("com.google.Chrome", "Chrome", "3 tabs")
+        ));
+    }
+
+    #[test]
+    fn test_matches_window_invalid_regex_never_matches() {
+        let rule = make_regex_rule(None, Some("(unclosed"));
+        assert!(!matches_window(
+            &rule,
+            &make_window("com.test", "Test", "(unclosed")
+        ));
+
+        // An invalid pattern also fails the other criteria it is combined with
+        let mut rule = make_rule(Some("com.test"), None, None);
+        rule.app_name_regex = Some("[".to_string());
+        rule.prepare();
+        assert!(!matches_window(
+            &rule,
+            &make_window("com.test", "Test", "Anything")
+        ));
+    }
+
     #[test]
     fn test_should_tile_window_system_apps() {
         assert!(!should_tile_window("com.apple.dock", "Dock"));
@@ -517,6 +786,22 @@ mod tests {
         assert!(should_tile_window("com.microsoft.vscode", "Visual Studio Code"));
     }
 
+    #[test]
+    fn test_is_app_ignored() {
+        let ignore_apps = vec!["Raycast".to_string(), "com.1password.1password".to_string()];
+
+        assert!(is_app_ignored(&ignore_apps, "com.raycast.macos", "raycast"));
+        assert!(is_app_ignored(
+            &ignore_apps,
+            "com.1Password.1password",
+            "1Password"
+        ));
+        assert!(!is_app_ignored(&ignore_apps, "com.apple.Safari", "Safari"));
+        // Exact match only, not substrings
+        assert!(!is_app_ignored(&ignore_apps, "", "Raycast Helper"));
+        assert!(!is_app_ignored(&[], "com.raycast.macos", "Raycast"));
+    }
+
     #[test]
     fn test_should_skip_app_by_name() {
         assert!(should_skip_app_by_name("Dock"));
@@ -0,0 +1,94 @@
+//! Bounded most-recently-used focus history.
+//!
+//! Used to jump back to the previously focused window or workspace.
+
+use std::collections::VecDeque;
+
+/// A most-recently-used list with a fixed capacity.
+///
+/// The most recent entry comes first. Pushing an entry that is already in the
+/// list moves it to the front, and the oldest entry is dropped once the list
+/// is full.
+#[derive(Debug, Clone)]
+pub struct FocusHistory<T> {
+    entries: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T: Copy + PartialEq> FocusHistory<T> {
+    /// Create an empty history holding at most `capacity` entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Push an entry to the front, dropping the oldest entry when full.
+    pub fn push(&mut self, entry: T) {
+        self.remove(entry);
+        self.entries.push_front(entry);
+        self.entries.truncate(self.capacity);
+    }
+
+    /// Remove an entry from the history.
+    pub fn remove(&mut self, entry: T) { self.entries.retain(|&e| e != entry); }
+
+    /// Iterate over the entries, most recent first.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ { self.entries.iter().copied() }
+
+    /// Number of entries in the history.
+    #[must_use]
+    pub fn len(&self) -> usize { self.entries.len() }
+
+    /// Whether the history is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_orders_most_recent_first() {
+        let mut history = FocusHistory::new(4);
+        history.push(1);
+        history.push(2);
+        history.push(3);
+        assert_eq!(history.iter().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_push_moves_existing_entry_to_front() {
+        let mut history = FocusHistory::new(4);
+        history.push(1);
+        history.push(2);
+        history.push(1);
+        assert_eq!(history.iter().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_push_drops_oldest_when_full() {
+        let mut history = FocusHistory::new(2);
+        history.push(1);
+        history.push(2);
+        history.push(3);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.iter().collect::<Vec<_>>(), vec![3, 2]);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut history = FocusHistory::new(4);
+        history.push(1);
+        history.push(2);
+        history.remove(1);
+        history.remove(5);
+        assert_eq!(history.iter().collect::<Vec<_>>(), vec![2]);
+
+        history.remove(2);
+        assert!(history.is_empty());
+    }
+}
@@ -4,8 +4,10 @@
 //! - Core types (`Screen`, `Workspace`, `Window`, `Rect`, etc.)
 //! - The main `TilingState` struct with reactive collections
 
+mod focus_history;
 mod tiling_state;
 mod types;
 
+pub use focus_history::FocusHistory;
 pub use tiling_state::TilingState;
 pub use types::{FocusState, LayoutType, Rect, Screen, Window, WindowIdList, Workspace};
@@ -10,12 +10,20 @@
 //! directly manipulating the vectors.
 
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use eyeball::Observable;
 use eyeball_im::ObservableVector;
 use uuid::Uuid;
 
-use super::types::{FocusState, Screen, Window, Workspace};
+use super::focus_history::FocusHistory;
+use super::types::{FocusState, Rect, Screen, Window, Workspace};
+
+/// Number of previously focused windows and workspaces to remember.
+const RECENT_FOCUS_CAPACITY: usize = 32;
+
+/// How long after opening a background window's first focus event is ignored.
+const BACKGROUND_WINDOW_GRACE: Duration = Duration::from_secs(2);
 
 /// The root state container for the tiling window manager.
 ///
@@ -55,6 +63,33 @@ pub struct TilingState {
     /// Maps `workspace_id` -> `window_id`.
     focus_history: HashMap<Uuid, u32>,
 
+    /// Windows that lost focus, most recent first.
+    recent_windows: FocusHistory<u32>,
+
+    /// Workspaces that lost focus, most recent first.
+    recent_workspaces: FocusHistory<Uuid>,
+
+    /// Frames windows had when they were first tracked, before any layout.
+    /// Maps `window_id` -> frame.
+    original_frames: HashMap<u32, Rect>,
+
+    /// New windows that opened on a hidden workspace without switching to it.
+    /// Maps `window_id` -> when the window was tracked.
+    background_windows: HashMap<u32, Instant>,
+
+    /// Whether the scratchpad's windows are shown over the current workspace.
+    scratchpad_visible: bool,
+
+    /// Name of the `tiling.gapPresets` entry used instead of the configured gaps.
+    gap_preset: Option<String>,
+
+    /// Frames of the screens listed in `tiling.ignoreScreens`.
+    ignored_screen_frames: Vec<Rect>,
+
+    /// Windows released because they were moved to an ignored screen.
+    /// Maps `window_id` -> the window as it was last tracked.
+    released_windows: HashMap<u32, Window>,
+
     // ════════════════════════════════════════════════════════════════════════
     // Auxiliary Indices for O(1) Lookups
     // ════════════════════════════════════════════════════════════════════════
@@ -83,6 +118,14 @@ impl TilingState {
             focus: Observable::new(FocusState::new()),
             enabled: Observable::new(true),
             focus_history: HashMap::new(),
+            recent_windows: FocusHistory::new(RECENT_FOCUS_CAPACITY),
+            recent_workspaces: FocusHistory::new(RECENT_FOCUS_CAPACITY),
+            original_frames: HashMap::new(),
+            background_windows: HashMap::new(),
+            scratchpad_visible: false,
+            gap_preset: None,
+            ignored_screen_frames: Vec::new(),
+            released_windows: HashMap::new(),
             screen_idx: HashMap::new(),
             workspace_idx: HashMap::new(),
             window_idx: HashMap::new(),
@@ -210,6 +253,7 @@ impl TilingState {
     pub fn remove_workspace(&mut self, id: Uuid) -> Option<Workspace> {
         let idx = self.workspace_idx.remove(&id)?;
         let workspace = self.workspaces.remove(idx);
+        self.recent_workspaces.remove(id);
 
         // Update indices for all workspaces that shifted down
         for (&ws_id, stored_idx) in &mut self.workspace_idx {
@@ -311,6 +355,7 @@ impl TilingState {
     pub fn remove_window(&mut self, id: u32) -> Option<Window> {
         let idx = self.window_idx.remove(&id)?;
         let window = self.windows.remove(idx);
+        self.background_windows.remove(&id);
 
         // Update indices for all windows that shifted down
         for (&win_id, stored_idx) in &mut self.window_idx {
@@ -350,7 +395,7 @@ impl TilingState {
         workspace_id: Option<Uuid>,
         screen_id: Option<u32>,
     ) {
-        Observable::set(&mut self.focus, FocusState {
+        self.replace_focus(FocusState {
             focused_window_id: window_id,
             focused_workspace_id: workspace_id,
             focused_screen_id: screen_id,
@@ -358,7 +403,7 @@ impl TilingState {
     }
 
     /// Clear all focus.
-    pub fn clear_focus(&mut self) { Observable::set(&mut self.focus, FocusState::new()); }
+    pub fn clear_focus(&mut self) { self.replace_focus(FocusState::new()); }
 
     /// Get the current focus state.
     #[must_use]
@@ -369,6 +414,28 @@ impl TilingState {
     where F: FnOnce(&mut FocusState) {
         let mut focus = Observable::get(&self.focus).clone();
         f(&mut focus);
+        self.replace_focus(focus);
+    }
+
+    /// Replace the focus state, remembering the window and workspace that lost focus.
+    ///
+    /// Focus events echoing a focus change the state already made (e.g. the
+    /// window focused while switching workspaces) change nothing and are not
+    /// recorded.
+    fn replace_focus(&mut self, focus: FocusState) {
+        let previous = Observable::get(&self.focus);
+
+        if previous.focused_window_id != focus.focused_window_id
+            && let Some(window_id) = previous.focused_window_id
+        {
+            self.recent_windows.push(window_id);
+        }
+        if previous.focused_workspace_id != focus.focused_workspace_id
+            && let Some(workspace_id) = previous.focused_workspace_id
+        {
+            self.recent_workspaces.push(workspace_id);
+        }
+
         Observable::set(&mut self.focus, focus);
     }
 
@@ -430,6 +497,163 @@ impl TilingState {
     /// Call this when a window is destroyed to clean up stale references.
     pub fn remove_window_from_focus_history(&mut self, window_id: u32) {
         self.focus_history.retain(|_, &mut id| id != window_id);
+        self.recent_windows.remove(window_id);
+    }
+
+    /// Get the most recently focused window other than the focused one.
+    ///
+    /// Skips windows that are no longer tracked, minimized or hidden.
+    #[must_use]
+    pub fn get_previous_window(&self) -> Option<u32> {
+        let focused = self.get_focus_state().focused_window_id;
+        self.recent_windows.iter().find(|&id| {
+            Some(id) != focused
+                && self.get_window(id).is_some_and(|w| !w.is_minimized && !w.is_hidden)
+        })
+    }
+
+    /// Get the IDs of recently focused windows, most recent first.
+    ///
+    /// The focused window comes first, followed by the focus history.
+    #[must_use]
+    pub fn get_recent_window_ids(&self) -> Vec<u32> {
+        let focused = self.get_focus_state().focused_window_id;
+        focused
+            .into_iter()
+            .chain(self.recent_windows.iter().filter(|&id| Some(id) != focused))
+            .collect()
+    }
+
+    /// Get the most recently focused workspace on a screen that is not visible.
+    #[must_use]
+    pub fn get_previous_workspace(&self, screen_id: u32) -> Option<Workspace> {
+        self.recent_workspaces
+            .iter()
+            .filter_map(|id| self.get_workspace(id))
+            .find(|ws| ws.screen_id == screen_id && !ws.is_visible)
+    }
+
+    // ========================================================================
+    // Original Frames
+    // ========================================================================
+
+    /// Record the frame a window had when it was first tracked.
+    ///
+    /// Only the first recorded frame is kept, so later layouts don't overwrite it.
+    pub fn record_original_frame(&mut self, window_id: u32, frame: Rect) {
+        self.original_frames.entry(window_id).or_insert(frame);
+    }
+
+    /// Get the frame a window had when it was first tracked.
+    #[must_use]
+    pub fn get_original_frame(&self, window_id: u32) -> Option<Rect> {
+        self.original_frames.get(&window_id).copied()
+    }
+
+    /// Remove the recorded original frame of a window.
+    ///
+    /// Call this when a window is destroyed to clean up stale entries.
+    pub fn remove_original_frame(&mut self, window_id: u32) {
+        self.original_frames.remove(&window_id);
+    }
+
+    /// Get the original frames of all windows that can be restored. O(n).
+    ///
+    /// Skips windows that are no longer tracked, minimized or fullscreen.
+    #[must_use]
+    pub fn get_restorable_original_frames(&self) -> Vec<(u32, Rect)> {
+        self.windows
+            .iter()
+            .filter(|w| !w.is_minimized && !w.is_fullscreen)
+            .filter_map(|w| self.get_original_frame(w.id).map(|frame| (w.id, frame)))
+            .collect()
+    }
+
+    // ========================================================================
+    // Background Windows
+    // ========================================================================
+
+    /// Mark a new window as opened in the background.
+    ///
+    /// Its first focus event, if it arrives shortly after, must not switch
+    /// to the window's workspace.
+    pub fn mark_background_window(&mut self, window_id: u32) {
+        self.background_windows.insert(window_id, Instant::now());
+    }
+
+    /// Take the background mark of a window.
+    ///
+    /// Returns true if the window was marked recently enough for its focus
+    /// event to be ignored. The mark is cleared either way.
+    pub fn take_background_window(&mut self, window_id: u32) -> bool {
+        self.background_windows
+            .remove(&window_id)
+            .is_some_and(|marked_at| marked_at.elapsed() < BACKGROUND_WINDOW_GRACE)
+    }
+
+    // ========================================================================
+    // Scratchpad
+    // ========================================================================
+
+    /// Get the scratchpad workspace, if it has been created. O(n).
+    #[must_use]
+    pub fn get_scratchpad_workspace(&self) -> Option<Workspace> {
+        self.workspaces.iter().find(|w| w.is_scratchpad).cloned()
+    }
+
+    /// Check if the scratchpad's windows are shown over the current workspace.
+    #[must_use]
+    pub const fn is_scratchpad_visible(&self) -> bool { self.scratchpad_visible }
+
+    /// Show or hide the scratchpad's windows.
+    pub const fn set_scratchpad_visible(&mut self, visible: bool) {
+        self.scratchpad_visible = visible;
+    }
+
+    /// Get the name of the active gap preset, if any.
+    #[must_use]
+    pub fn gap_preset(&self) -> Option<&str> { self.gap_preset.as_deref() }
+
+    /// Set the active gap preset, or go back to the configured gaps with `None`.
+    pub fn set_gap_preset(&mut self, preset: Option<String>) { self.gap_preset = preset; }
+
+    /// Set the frames of the screens tiling leaves alone.
+    pub fn set_ignored_screen_frames(&mut self, frames: Vec<Rect>) {
+        self.ignored_screen_frames = frames;
+    }
+
+    /// Check if a window frame is on a screen tiling leaves alone, going by
+    /// its center. O(n) in the number of ignored screens.
+    #[must_use]
+    pub fn is_on_ignored_screen(&self, frame: &Rect) -> bool {
+        let (x, y) = frame.center();
+        self.ignored_screen_frames.iter().any(|screen| screen.contains_point(x, y))
+    }
+
+    /// Remember a window released to an ignored screen, so it can be tracked
+    /// again when it moves back to a managed screen.
+    pub fn record_released_window(&mut self, window: Window) {
+        self.released_windows.insert(window.id, window);
+    }
+
+    /// Take a released window back, e.g. to track it again.
+    pub fn take_released_window(&mut self, window_id: u32) -> Option<Window> {
+        self.released_windows.remove(&window_id)
+    }
+
+    /// Get the IDs of all windows released to ignored screens.
+    #[must_use]
+    pub fn released_window_ids(&self) -> Vec<u32> {
+        self.released_windows.keys().copied().collect()
+    }
+
+    /// Get the ID of the scratchpad workspace while its windows are shown. O(n).
+    #[must_use]
+    pub fn get_shown_scratchpad_id(&self) -> Option<Uuid> {
+        if !self.scratchpad_visible {
+            return None;
+        }
+        self.workspaces.iter().find(|w| w.is_scratchpad).map(|w| w.id)
     }
 
     // ========================================================================
@@ -515,7 +739,7 @@ impl TilingState {
 #[allow(clippy::float_cmp)]
 mod tests {
     use super::*;
-    use crate::modules::tiling::state::types::{LayoutType, Rect, WindowIdList};
+    use crate::modules::tiling::state::types::{LayoutType, WindowIdList};
 
     fn make_screen(id: u32, name: &str, is_main: bool) -> Screen {
         Screen {
@@ -541,7 +765,11 @@ mod tests {
             window_ids: WindowIdList::new(),
             focused_window_index: None,
             split_ratios: Vec::new(),
+            master_count: None,
+            scroll_offset: 0,
             configured_screen: None,
+            claimed_app_ids: Vec::new(),
+            is_scratchpad: false,
         }
     }
 
@@ -552,6 +780,8 @@ mod tests {
             app_id: "com.test.app".to_string(),
             app_name: "Test App".to_string(),
             title: format!("Window {id}"),
+            role: None,
+            subrole: None,
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             minimum_size: None,
             inferred_minimum_size: None,
@@ -561,6 +791,8 @@ mod tests {
             is_fullscreen: false,
             is_hidden: false,
             is_floating: false,
+            is_sticky: false,
+            always_on_top: None,
             tab_group_id: None,
             is_active_tab: true,
             matched_rule: None,
@@ -678,6 +910,82 @@ mod tests {
         assert!(state.get_focused_window().is_none());
     }
 
+    #[test]
+    fn test_previous_window() {
+        let mut state = TilingState::new();
+        let ws = make_workspace("dev", 1);
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+        for id in [100, 200, 300] {
+            state.upsert_window(make_window(id, ws_id));
+        }
+
+        state.set_focus(Some(100), Some(ws_id), Some(1));
+        assert_eq!(state.get_previous_window(), None);
+
+        state.set_focused_window(Some(200));
+        assert_eq!(state.get_previous_window(), Some(100));
+
+        // Echoed focus events don't push the focused window
+        state.set_focus(Some(200), Some(ws_id), Some(1));
+        assert_eq!(state.get_previous_window(), Some(100));
+
+        // Going back and forth toggles between the two windows
+        state.set_focused_window(Some(100));
+        assert_eq!(state.get_previous_window(), Some(200));
+
+        // Minimized and destroyed windows are skipped
+        state.update_window(200, |w| w.is_minimized = true);
+        state.set_focused_window(Some(300));
+        assert_eq!(state.get_previous_window(), Some(100));
+
+        state.remove_window_from_focus_history(100);
+        assert_eq!(state.get_previous_window(), None);
+    }
+
+    #[test]
+    fn test_recent_window_ids() {
+        let mut state = TilingState::new();
+        let ws = make_workspace("dev", 1);
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+        assert!(state.get_recent_window_ids().is_empty());
+
+        state.set_focus(Some(100), Some(ws_id), Some(1));
+        state.set_focused_window(Some(200));
+        state.set_focused_window(Some(300));
+        assert_eq!(state.get_recent_window_ids(), vec![300, 200, 100]);
+
+        state.set_focused_window(Some(100));
+        assert_eq!(state.get_recent_window_ids(), vec![100, 300, 200]);
+    }
+
+    #[test]
+    fn test_previous_workspace() {
+        let mut state = TilingState::new();
+        let mut ws1 = make_workspace("one", 1);
+        ws1.is_visible = true;
+        let ws1_id = ws1.id;
+        let ws2 = make_workspace("two", 1);
+        let ws2_id = ws2.id;
+        let ws3 = make_workspace("three", 2);
+        let ws3_id = ws3.id;
+        state.upsert_workspace(ws1);
+        state.upsert_workspace(ws2);
+        state.upsert_workspace(ws3);
+
+        state.set_focused_workspace(Some(ws2_id));
+        state.set_focused_workspace(Some(ws3_id));
+        state.set_focused_workspace(Some(ws1_id));
+
+        // ws3 is on another screen, ws1 is visible
+        assert_eq!(state.get_previous_workspace(1).map(|ws| ws.id), Some(ws2_id));
+        assert_eq!(state.get_previous_workspace(2).map(|ws| ws.id), Some(ws3_id));
+
+        state.remove_workspace(ws2_id);
+        assert!(state.get_previous_workspace(1).is_none());
+    }
+
     #[test]
     fn test_tab_group_queries() {
         let mut state = TilingState::new();
@@ -720,4 +1028,93 @@ mod tests {
         state.set_enabled(true);
         assert!(state.is_enabled());
     }
+
+    #[test]
+    fn test_original_frames_keep_first_frame() {
+        let mut state = TilingState::new();
+        let first = Rect::new(100.0, 100.0, 640.0, 480.0);
+
+        state.record_original_frame(100, first);
+        state.record_original_frame(100, Rect::new(0.0, 0.0, 1920.0, 1080.0));
+        assert_eq!(state.get_original_frame(100), Some(first));
+
+        state.remove_original_frame(100);
+        assert_eq!(state.get_original_frame(100), None);
+    }
+
+    #[test]
+    fn test_background_window_mark_is_taken_once() {
+        let mut state = TilingState::new();
+
+        assert!(!state.take_background_window(100));
+
+        state.mark_background_window(100);
+        assert!(state.take_background_window(100));
+        assert!(!state.take_background_window(100));
+    }
+
+    #[test]
+    fn test_shown_scratchpad_id() {
+        let mut state = TilingState::new();
+        state.upsert_workspace(make_workspace("main", 1));
+        state.set_scratchpad_visible(true);
+        assert!(state.get_shown_scratchpad_id().is_none());
+
+        let mut scratchpad = make_workspace("scratchpad", 1);
+        scratchpad.is_scratchpad = true;
+        let scratchpad_id = scratchpad.id;
+        state.upsert_workspace(scratchpad);
+        assert_eq!(
+            state.get_scratchpad_workspace().map(|ws| ws.id),
+            Some(scratchpad_id)
+        );
+        assert_eq!(state.get_shown_scratchpad_id(), Some(scratchpad_id));
+
+        state.set_scratchpad_visible(false);
+        assert!(!state.is_scratchpad_visible());
+        assert!(state.get_shown_scratchpad_id().is_none());
+    }
+
+    #[test]
+    fn test_gap_preset() {
+        let mut state = TilingState::new();
+        assert!(state.gap_preset().is_none());
+
+        state.set_gap_preset(Some("dense".to_string()));
+        assert_eq!(state.gap_preset(), Some("dense"));
+
+        state.set_gap_preset(None);
+        assert!(state.gap_preset().is_none());
+    }
+
+    #[test]
+    fn test_is_on_ignored_screen() {
+        let mut state = TilingState::new();
+        let window = Rect::new(2000.0, 100.0, 800.0, 600.0);
+        assert!(!state.is_on_ignored_screen(&window));
+
+        state.set_ignored_screen_frames(vec![Rect::new(1920.0, 0.0, 1920.0, 1080.0)]);
+        assert!(state.is_on_ignored_screen(&window));
+        assert!(!state.is_on_ignored_screen(&Rect::new(100.0, 100.0, 800.0, 600.0)));
+    }
+
+    #[test]
+    fn test_restorable_original_frames() {
+        let mut state = TilingState::new();
+        let ws_id = Uuid::now_v7();
+
+        let mut minimized = make_window(200, ws_id);
+        minimized.is_minimized = true;
+        state.upsert_window(make_window(100, ws_id));
+        state.upsert_window(minimized);
+        state.upsert_window(make_window(300, ws_id));
+
+        let frame = Rect::new(50.0, 50.0, 400.0, 300.0);
+        state.record_original_frame(100, frame);
+        state.record_original_frame(200, frame);
+        // Window 400 is no longer tracked
+        state.record_original_frame(400, frame);
+
+        assert_eq!(state.get_restorable_original_frames(), vec![(100, frame)]);
+    }
 }
@@ -91,7 +91,7 @@ pub struct Screen {
     /// Full frame including menu bar area.
     pub frame: Rect,
 
-    /// Usable frame (excluding dock, menu bar).
+    /// Usable frame (excluding dock, menu bar and `tiling.reserved` space).
     pub visible_frame: Rect,
 
     /// `HiDPI` scale factor (e.g., 2.0 for Retina).
@@ -146,6 +146,9 @@ pub enum LayoutType {
     /// All windows maximized, stacked on top of each other.
     Monocle,
 
+    /// All windows maximized and stacked, exposed to the frontend as tabs.
+    Tabbed,
+
     /// One master window with remaining windows in a stack.
     Master,
 
@@ -160,12 +163,18 @@ pub enum LayoutType {
 
     /// Balanced grid pattern.
     Grid,
+
+    /// Equal-width, full-height columns from left to right.
+    Columns,
+
+    /// Fixed-width columns on a horizontally scrolling strip.
+    Scrolling,
 }
 
 impl LayoutType {
     /// Returns true if this layout stacks windows on top of each other.
     #[must_use]
-    pub const fn is_stacking(&self) -> bool { matches!(self, Self::Monocle) }
+    pub const fn is_stacking(&self) -> bool { matches!(self, Self::Monocle | Self::Tabbed) }
 
     /// Returns true if this layout allows manual window positioning.
     #[must_use]
@@ -216,8 +225,23 @@ pub struct Workspace {
     /// Each ratio represents the proportion of space for a split.
     pub split_ratios: Vec<f64>,
 
+    /// Number of master windows for the master layout, overriding the config.
+    pub master_count: Option<usize>,
+
+    /// Index of the leftmost visible column in the scrolling layout.
+    pub scroll_offset: usize,
+
     /// Configured screen name (for reconnection after screen hotplug).
     pub configured_screen: Option<String>,
+
+    /// Bundle IDs claimed with `stache tiling workspace --claim-app`.
+    /// New windows of these apps open here, ahead of the configured rules.
+    /// Session-only; never written to the config.
+    pub claimed_app_ids: Vec<String>,
+
+    /// Is this the scratchpad workspace?
+    /// Its windows are hidden until shown over the current workspace.
+    pub is_scratchpad: bool,
 }
 
 impl Default for Workspace {
@@ -232,7 +256,11 @@ impl Default for Workspace {
             window_ids: WindowIdList::new(),
             focused_window_index: None,
             split_ratios: Vec::new(),
+            master_count: None,
+            scroll_offset: 0,
             configured_screen: None,
+            claimed_app_ids: Vec::new(),
+            is_scratchpad: false,
         }
     }
 }
@@ -295,6 +323,12 @@ pub struct Window {
     /// Window title.
     pub title: String,
 
+    /// Accessibility role (e.g., "AXWindow").
+    pub role: Option<String>,
+
+    /// Accessibility subrole (e.g., "AXStandardWindow" or "AXDialog").
+    pub subrole: Option<String>,
+
     /// Current frame (position and size).
     pub frame: Rect,
 
@@ -327,6 +361,13 @@ pub struct Window {
     /// Is the window floating (excluded from tiling)?
     pub is_floating: bool,
 
+    /// Is the window sticky (shown on every workspace, excluded from tiling)?
+    pub is_sticky: bool,
+
+    /// Per-window override of `tiling.floatingAlwaysOnTop`.
+    /// `None` follows the config.
+    pub always_on_top: Option<bool>,
+
     /// Tab group ID if this window is part of a tab group.
     pub tab_group_id: Option<Uuid>,
 
@@ -345,6 +386,8 @@ impl Default for Window {
             app_id: String::new(),
             app_name: String::new(),
             title: String::new(),
+            role: None,
+            subrole: None,
             frame: Rect::zero(),
             minimum_size: None,
             inferred_minimum_size: None,
@@ -354,6 +397,8 @@ impl Default for Window {
             is_fullscreen: false,
             is_hidden: false,
             is_floating: false,
+            is_sticky: false,
+            always_on_top: None,
             tab_group_id: None,
             is_active_tab: true,
             matched_rule: None,
@@ -369,9 +414,29 @@ impl Window {
             && !self.is_hidden
             && !self.is_fullscreen
             && !self.is_floating
+            && !self.is_sticky
             && (self.tab_group_id.is_none() || self.is_active_tab)
     }
 
+    /// Check if this window floats above the tiled layout (floating or sticky).
+    #[must_use]
+    pub const fn is_effectively_floating(&self) -> bool { self.is_floating || self.is_sticky }
+
+    /// Check if this window is kept above tiled windows.
+    ///
+    /// Only floating and sticky windows are kept on top. `default` is the
+    /// `tiling.floatingAlwaysOnTop` setting, used when the window has no override.
+    #[must_use]
+    pub const fn is_kept_on_top(&self, default: bool) -> bool {
+        if !self.is_effectively_floating() {
+            return false;
+        }
+        match self.always_on_top {
+            Some(value) => value,
+            None => default,
+        }
+    }
+
     /// Check if this window is in a tab group.
     #[must_use]
     pub const fn is_tabbed(&self) -> bool { self.tab_group_id.is_some() }
@@ -584,9 +649,16 @@ mod tests {
             assert!(!LayoutType::Monocle.is_tiling());
             assert!(!LayoutType::Monocle.is_floating());
 
+            assert!(LayoutType::Tabbed.is_stacking());
+            assert!(!LayoutType::Tabbed.is_tiling());
+            assert!(!LayoutType::Tabbed.is_floating());
+
             assert!(LayoutType::Dwindle.is_tiling());
             assert!(!LayoutType::Dwindle.is_stacking());
             assert!(!LayoutType::Dwindle.is_floating());
+
+            assert!(LayoutType::Scrolling.is_tiling());
+            assert!(!LayoutType::Scrolling.is_stacking());
         }
     }
 
@@ -663,6 +735,14 @@ mod tests {
             };
             assert!(!window.is_layoutable());
 
+            let window = Window {
+                id: 1,
+                is_sticky: true,
+                ..Window::default()
+            };
+            assert!(!window.is_layoutable());
+            assert!(window.is_effectively_floating());
+
             // Tab that's not active
             let window = Window {
                 id: 1,
@@ -691,6 +771,26 @@ mod tests {
             assert!(window.is_tabbed());
         }
 
+        #[test]
+        fn test_window_is_kept_on_top() {
+            let mut window = Window::default();
+            assert!(!window.is_kept_on_top(true));
+
+            window.is_floating = true;
+            assert!(window.is_kept_on_top(true));
+            assert!(!window.is_kept_on_top(false));
+
+            // The per-window override wins over the config
+            window.always_on_top = Some(false);
+            assert!(!window.is_kept_on_top(true));
+            window.always_on_top = Some(true);
+            assert!(window.is_kept_on_top(false));
+
+            // Tiled windows are never kept on top
+            window.is_floating = false;
+            assert!(!window.is_kept_on_top(false));
+        }
+
         #[test]
         fn test_window_minimum_size_helpers() {
             let mut window = Window::default();
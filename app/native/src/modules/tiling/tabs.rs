@@ -18,6 +18,7 @@
 //! This allows us to:
 //! - Skip layout recalculations when tabs are created/destroyed
 //! - Properly identify when a window operation is actually a tab operation
+//! - Hand a closed window's layout slot to the tab shown in its place
 
 use std::collections::HashSet;
 use std::ffi::c_void;
@@ -25,6 +26,9 @@ use std::sync::OnceLock;
 
 use parking_lot::RwLock;
 
+use super::ffi::AXElement;
+use super::state::Rect;
+
 // ============================================================================
 // FFI for accessing tab window IDs
 // ============================================================================
@@ -155,6 +159,10 @@ pub fn register_tab(window_id: u32, pid: i32) {
 /// Unregisters a window ID from the tab registry.
 pub fn unregister_tab(window_id: u32) { get_registry().write().unregister(window_id); }
 
+/// Gets all tab window IDs for a given PID.
+#[must_use]
+pub fn tabs_for_pid(pid: i32) -> Vec<u32> { get_registry().read().tabs_for_pid(pid) }
+
 /// Clears all tab entries for a given PID.
 pub fn clear_tabs_for_pid(pid: i32) { get_registry().write().clear_for_pid(pid); }
 
@@ -397,6 +405,79 @@ pub fn is_new_window_a_tab(pid: i32, new_window_id: u32, workspace_window_ids: &
     false
 }
 
+// ============================================================================
+// Tab Swaps
+// ============================================================================
+
+/// Frames closer than this (in points) are treated as the same tab group frame.
+const TAB_FRAME_EPSILON: f64 = 2.0;
+
+/// A registered tab that could take the place of a closed window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabCandidate {
+    /// Window ID of the tab.
+    pub window_id: u32,
+    /// Current frame of the tab.
+    pub frame: Rect,
+    /// Current `AXTitle` of the tab.
+    pub title: String,
+}
+
+/// Reads the frame and title of an app's registered tabs that are exposed as
+/// accessibility windows.
+#[must_use]
+pub fn tab_candidates_for_app(pid: i32) -> Vec<TabCandidate> {
+    let tab_ids: HashSet<u32> = tabs_for_pid(pid).into_iter().collect();
+    if tab_ids.is_empty() {
+        return Vec::new();
+    }
+
+    let Some(app) = AXElement::application(pid) else {
+        return Vec::new();
+    };
+
+    app.windows()
+        .into_iter()
+        .filter_map(|window| {
+            let window_id = window.window_id().filter(|id| tab_ids.contains(id))?;
+            Some(TabCandidate {
+                window_id,
+                frame: window.frame()?,
+                title: window.title().unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+/// Picks the tab that takes the place of a closed window.
+///
+/// Tabs share their group's frame, so only candidates at `frame` qualify.
+/// When several do (e.g. two same-size browser windows), the one whose title
+/// shares the most words with `title` wins, and the first one on a tie.
+#[must_use]
+pub fn pick_tab_swap_target(frame: &Rect, title: &str, candidates: &[TabCandidate]) -> Option<u32> {
+    let mut best: Option<(u32, usize)> = None;
+
+    for candidate in candidates.iter().filter(|c| c.frame.approx_eq(frame, TAB_FRAME_EPSILON)) {
+        let score = title_similarity(title, &candidate.title);
+        if best.is_none_or(|(_, best_score)| score > best_score) {
+            best = Some((candidate.window_id, score));
+        }
+    }
+
+    best.map(|(window_id, _)| window_id)
+}
+
+/// Counts the distinct words two titles share, ignoring case.
+fn title_similarity(a: &str, b: &str) -> usize {
+    let words: HashSet<String> = a.split_whitespace().map(str::to_lowercase).collect();
+    b.split_whitespace()
+        .map(str::to_lowercase)
+        .filter(|word| words.contains(word))
+        .collect::<HashSet<_>>()
+        .len()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -453,4 +534,57 @@ mod tests {
         registry.clear();
         assert_eq!(registry.count(), 0);
     }
+
+    fn candidate(window_id: u32, frame: Rect, title: &str) -> TabCandidate {
+        TabCandidate {
+            window_id,
+            frame,
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_pick_tab_swap_target_requires_matching_frame() {
+        let frame = Rect::new(0.0, 0.0, 800.0, 600.0);
+        let candidates = vec![
+            candidate(1, Rect::new(800.0, 0.0, 800.0, 600.0), "Inbox - Mail"),
+            candidate(2, Rect::new(0.5, 0.0, 800.0, 600.0), "Docs"),
+        ];
+
+        assert_eq!(
+            pick_tab_swap_target(&frame, "Inbox - Mail", &candidates),
+            Some(2)
+        );
+        assert_eq!(pick_tab_swap_target(&frame, "Inbox", &candidates[..1]), None);
+    }
+
+    #[test]
+    fn test_pick_tab_swap_target_two_identical_frames() {
+        let frame = Rect::new(0.0, 0.0, 800.0, 600.0);
+        let candidates = vec![
+            candidate(1, frame, "Weather Forecast - Firefox"),
+            candidate(2, frame, "Rust Docs: std::vec - Firefox"),
+        ];
+
+        // The title decides between windows sharing a frame, not the order
+        assert_eq!(
+            pick_tab_swap_target(&frame, "Rust Docs: std::string - Firefox", &candidates),
+            Some(2)
+        );
+        assert_eq!(
+            pick_tab_swap_target(&frame, "weather forecast - firefox", &candidates),
+            Some(1)
+        );
+
+        // Equally similar titles keep the first match
+        assert_eq!(pick_tab_swap_target(&frame, "Firefox", &candidates), Some(1));
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        assert_eq!(title_similarity("Inbox - Mail", "inbox - mail"), 3);
+        assert_eq!(title_similarity("Inbox", "Drafts"), 0);
+        assert_eq!(title_similarity("", "Drafts"), 0);
+        assert_eq!(title_similarity("a a b", "a a"), 1);
+    }
 }
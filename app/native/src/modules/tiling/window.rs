@@ -16,9 +16,11 @@ use std::collections::HashMap;
 use objc::runtime::{BOOL, Class, Object, YES};
 use objc::{msg_send, sel, sel_impl};
 
+use super::effects::applescript;
 use super::ffi::accessibility::AXElement;
-use super::rules::is_pip_window;
-use super::state::Rect;
+use super::rules::{is_pip_window, uses_applescript_frames};
+use super::state::{Rect, Window};
+use crate::config::get_config;
 
 // ============================================================================
 // FFI for NSString
@@ -146,6 +148,10 @@ pub struct WindowInfo {
     pub app_name: String,
     /// Window title.
     pub title: String,
+    /// Accessibility role (e.g., "AXWindow").
+    pub role: Option<String>,
+    /// Accessibility subrole (e.g., "AXStandardWindow" or "AXDialog").
+    pub subrole: Option<String>,
     /// Window frame (position and size).
     pub frame: Rect,
     /// Minimum size constraints (width, height) if the window reports them.
@@ -170,20 +176,30 @@ pub struct WindowInfo {
 /// It enumerates windows using the AX-first approach to ensure we get
 /// accurate window information.
 ///
+/// Frames of windows using the `osascript` fallback are read through System
+/// Events, which blocks. On the main thread, use
+/// [`get_all_windows_deferring_fallback`] instead.
+///
 /// # Returns
 ///
 /// A vector of [`WindowInfo`] structs for all trackable windows.
 #[must_use]
 pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
-    // Minimum size thresholds
-    // Standard windows: 200x150 minimum
-    // Dialogs: 400x300 minimum (real dialogs like preferences are larger;
-    //          small dialogs are popups like date pickers, color pickers)
-    const MIN_STANDARD_WIDTH: f64 = 200.0;
-    const MIN_STANDARD_HEIGHT: f64 = 150.0;
-    const MIN_DIALOG_WIDTH: f64 = 400.0;
-    const MIN_DIALOG_HEIGHT: f64 = 300.0;
+    let (mut windows, pending) = get_all_windows_deferring_fallback();
+    windows.extend(discover_fallback_frames(pending));
+    windows
+}
 
+/// Gets all windows like [`get_all_windows_including_hidden`], without
+/// running `osascript`, so it's safe to call on the main thread.
+///
+/// # Returns
+///
+/// The trackable windows, and the windows whose frame can only be read
+/// through the `osascript` fallback. The latter have an empty frame until
+/// passed to [`discover_fallback_frames`].
+#[must_use]
+pub fn get_all_windows_deferring_fallback() -> (Vec<WindowInfo>, Vec<WindowInfo>) {
     let apps = get_running_apps();
 
     // Build a map of PID -> (bundle_id, app_name, is_hidden)
@@ -197,7 +213,9 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
         })
         .collect();
 
+    let tiling = &get_config().tiling;
     let mut result = Vec::new();
+    let mut pending = Vec::new();
 
     for app in &apps {
         // Get all AX windows for this app
@@ -209,10 +227,9 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
                 continue;
             };
 
-            // Get frame (required)
-            let Some(frame) = ax_window.frame() else {
-                continue;
-            };
+            // Get frame, which may only be available through the `osascript`
+            // fallback
+            let frame = ax_window.frame();
 
             // Get subrole for filtering
             let subrole = ax_window.subrole();
@@ -222,28 +239,7 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
                 continue;
             }
 
-            // Determine if window should be managed based on subrole and size
-            // Use blacklist approach: only reject known popup/sheet subroles
-            let should_manage = match subrole.as_deref() {
-                // Explicitly reject popup-like subroles
-                // Sheets and drawers are always attached to parent windows
-                // AXUnknown subrole indicates popup/panel windows without standard controls
-                // Examples: browser extension popups, toolbar popups, dropdown panels
-                // These windows typically have no close/minimize/zoom buttons
-                Some("AXSheet" | "AXDrawer" | "AXUnknown") => false,
-                Some("AXDialog") => {
-                    // Dialogs - only accept large ones (preferences, settings)
-                    // Small dialogs are popups (date pickers, color pickers, alerts)
-                    frame.width >= MIN_DIALOG_WIDTH && frame.height >= MIN_DIALOG_HEIGHT
-                }
-                _ => {
-                    // Standard windows, no subrole, or custom subroles - accept if large enough
-                    // Many apps (like Ghostty) don't set subrole or use custom values
-                    frame.width >= MIN_STANDARD_WIDTH && frame.height >= MIN_STANDARD_HEIGHT
-                }
-            };
-
-            if !should_manage {
+            if frame.is_some_and(|frame| !is_manageable(subrole.as_deref(), &frame)) {
                 continue;
             }
 
@@ -253,6 +249,7 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
             let is_focused = ax_window.is_focused().unwrap_or(false);
             let is_fullscreen = ax_window.is_fullscreen().unwrap_or(false);
             let minimum_size = ax_window.minimum_size();
+            let role = ax_window.role();
 
             // Get app info
             let (bundle_id, app_name, is_hidden) =
@@ -264,23 +261,97 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
                 continue;
             }
 
-            result.push(WindowInfo {
+            let window = WindowInfo {
                 id: window_id,
                 pid: app.pid,
                 bundle_id: bundle_id.to_string(),
                 app_name: app_name.to_string(),
                 title,
-                frame,
+                role,
+                subrole,
+                frame: frame.unwrap_or_default(),
                 minimum_size,
                 is_minimized,
                 is_hidden,
                 is_focused,
                 is_fullscreen,
-            });
+            };
+
+            if frame.is_some() {
+                result.push(window);
+            } else if uses_applescript_frames(tiling, &fallback_window(&window)) {
+                pending.push(window);
+            }
         }
     }
 
-    result
+    (result, pending)
+}
+
+/// Reads the frames of windows deferred by
+/// [`get_all_windows_deferring_fallback`] through System Events.
+///
+/// Blocks until `osascript` exits for each window, so it must not run on the
+/// main thread. Drops the windows whose frame can't be read or that are too
+/// small to manage.
+#[must_use]
+pub fn discover_fallback_frames(windows: Vec<WindowInfo>) -> Vec<WindowInfo> {
+    windows
+        .into_iter()
+        .filter_map(|mut window| {
+            window.frame = applescript::discover_frame(&fallback_window(&window))?;
+            is_manageable(window.subrole.as_deref(), &window.frame).then_some(window)
+        })
+        .collect()
+}
+
+/// Returns whether a window should be managed based on its subrole and size.
+///
+/// Uses a blacklist approach: only known popup and sheet subroles are
+/// rejected, and everything else must be large enough.
+#[must_use]
+pub fn is_manageable(subrole: Option<&str>, frame: &Rect) -> bool {
+    // Minimum size thresholds
+    // Standard windows: 200x150 minimum
+    // Dialogs: 400x300 minimum (real dialogs like preferences are larger;
+    //          small dialogs are popups like date pickers, color pickers)
+    const MIN_STANDARD_WIDTH: f64 = 200.0;
+    const MIN_STANDARD_HEIGHT: f64 = 150.0;
+    const MIN_DIALOG_WIDTH: f64 = 400.0;
+    const MIN_DIALOG_HEIGHT: f64 = 300.0;
+
+    match subrole {
+        // Explicitly reject popup-like subroles
+        // Sheets and drawers are always attached to parent windows
+        // AXUnknown subrole indicates popup/panel windows without standard controls
+        // Examples: browser extension popups, toolbar popups, dropdown panels
+        // These windows typically have no close/minimize/zoom buttons
+        Some("AXSheet" | "AXDrawer" | "AXUnknown") => false,
+        Some("AXDialog") => {
+            // Dialogs - only accept large ones (preferences, settings)
+            // Small dialogs are popups (date pickers, color pickers, alerts)
+            frame.width >= MIN_DIALOG_WIDTH && frame.height >= MIN_DIALOG_HEIGHT
+        }
+        _ => {
+            // Standard windows, no subrole, or custom subroles - accept if large enough
+            // Many apps (like Ghostty) don't set subrole or use custom values
+            frame.width >= MIN_STANDARD_WIDTH && frame.height >= MIN_STANDARD_HEIGHT
+        }
+    }
+}
+
+/// Builds the untracked [`Window`] that rules are matched against.
+fn fallback_window(window: &WindowInfo) -> Window {
+    Window {
+        id: window.id,
+        pid: window.pid,
+        app_id: window.bundle_id.clone(),
+        app_name: window.app_name.clone(),
+        title: window.title.clone(),
+        role: window.role.clone(),
+        subrole: window.subrole.clone(),
+        ..Window::default()
+    }
 }
 
 /// Gets only visible (on-screen) windows.
@@ -424,6 +495,20 @@ mod tests {
         let _ = windows.len();
     }
 
+    #[test]
+    fn test_is_manageable() {
+        let small = Rect::new(0.0, 0.0, 300.0, 200.0);
+        let large = Rect::new(0.0, 0.0, 800.0, 600.0);
+
+        assert!(is_manageable(None, &small));
+        assert!(is_manageable(Some("AXStandardWindow"), &small));
+        assert!(!is_manageable(None, &Rect::new(0.0, 0.0, 100.0, 100.0)));
+        assert!(!is_manageable(Some("AXDialog"), &small));
+        assert!(is_manageable(Some("AXDialog"), &large));
+        assert!(!is_manageable(Some("AXSheet"), &large));
+        assert!(!is_manageable(Some("AXUnknown"), &large));
+    }
+
     #[test]
     fn test_get_visible_windows_filters_hidden() {
         let visible = get_visible_windows();
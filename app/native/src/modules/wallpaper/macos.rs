@@ -39,6 +39,43 @@ impl std::error::Error for WallpaperError {}
 #[inline]
 pub fn screen_count() -> usize { processing::get_screen_count() }
 
+/// Returns the index of the screen showing a display, for the `*_for_screen` functions.
+///
+/// `display_id` is the `CGDirectDisplayID` (`NSScreenNumber`) of the display.
+#[must_use]
+pub fn screen_index_for_display(display_id: u32) -> Option<usize> {
+    unsafe {
+        let screen_class = Class::get("NSScreen")?;
+        let screens: *mut Object = msg_send![screen_class, screens];
+        if screens.is_null() {
+            return None;
+        }
+
+        let count: usize = msg_send![screens, count];
+        let screen_number_key = crate::utils::objc::nsstring("NSScreenNumber");
+
+        (0..count).find(|&index| {
+            let screen: *mut Object = msg_send![screens, objectAtIndex: index];
+            if screen.is_null() {
+                return false;
+            }
+
+            let description: *mut Object = msg_send![screen, deviceDescription];
+            if description.is_null() {
+                return false;
+            }
+
+            let number: *mut Object = msg_send![description, objectForKey: screen_number_key];
+            if number.is_null() {
+                return false;
+            }
+
+            let screen_id: u32 = msg_send![number, unsignedIntValue];
+            screen_id == display_id
+        })
+    }
+}
+
 /// Sets the desktop wallpaper for all screens.
 ///
 /// # Arguments
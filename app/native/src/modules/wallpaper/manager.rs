@@ -8,15 +8,32 @@ use std::time::{Duration, Instant};
 
 use rand::Rng;
 use rayon::prelude::*;
+use serde::Serialize;
 
 use super::macos;
 use super::processing::{self, ProcessingError};
+use super::schedule::{ScheduledWallpaper, WallpaperSchedule};
 use crate::config::{WallpaperConfig, WallpaperMode};
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
 use crate::utils::path::expand;
+use crate::utils::time_of_day::local_minute_of_day;
 
 /// Global wallpaper manager instance.
 static MANAGER: OnceLock<Arc<WallpaperManager>> = OnceLock::new();
 
+/// How often the timer checks the schedule when one is configured.
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Snapshot of the wallpaper state, reported by `stache wallpaper current`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WallpaperStatus {
+    /// The last wallpaper source image set by the manager.
+    pub current: Option<String>,
+    /// The schedule entry matching the current local time, if any.
+    pub schedule_entry: Option<ScheduledWallpaper>,
+}
+
 /// Actions that can be performed on the wallpaper.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WallpaperAction {
@@ -83,6 +100,12 @@ pub struct WallpaperManager {
     wallpapers: Vec<PathBuf>,
     /// Configuration for processing.
     config: WallpaperConfig,
+    /// Time-of-day schedule (takes precedence over rotation while active).
+    schedule: WallpaperSchedule,
+    /// Config index of the schedule entry last applied, if any.
+    active_schedule: Mutex<Option<usize>>,
+    /// Source path of the last wallpaper set.
+    current_path: Mutex<Option<PathBuf>>,
     /// Current wallpaper index (for sequential mode).
     current_index: AtomicUsize,
     /// Whether the cycling timer is running.
@@ -99,14 +122,18 @@ impl WallpaperManager {
     /// Returns an error if the path is invalid or no wallpapers are found.
     pub fn new(config: &WallpaperConfig) -> Result<Self, WallpaperManagerError> {
         let wallpapers = Self::load_wallpapers(config)?;
+        let schedule = WallpaperSchedule::from_config(&config.schedule);
 
-        if wallpapers.is_empty() {
+        if wallpapers.is_empty() && schedule.is_empty() {
             return Err(WallpaperManagerError::NoWallpapers);
         }
 
         Ok(Self {
             wallpapers,
             config: config.clone(),
+            schedule,
+            active_schedule: Mutex::new(None),
+            current_path: Mutex::new(None),
             current_index: AtomicUsize::new(0),
             timer_running: AtomicBool::new(false),
             change_lock: Mutex::new(()),
@@ -172,9 +199,17 @@ impl WallpaperManager {
 
     /// Sets the wallpaper at the given index.
     fn set_wallpaper_at_index(&self, index: usize) -> Result<(), WallpaperManagerError> {
-        let _lock = self.change_lock.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        self.set_wallpaper_from_path(&self.wallpapers[index])?;
 
-        let source = &self.wallpapers[index];
+        // Update the current index
+        self.current_index.store(index, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    /// Processes and sets the given source image on all screens.
+    fn set_wallpaper_from_path(&self, source: &Path) -> Result<(), WallpaperManagerError> {
+        let _lock = self.change_lock.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
 
         // Process the image (applies blur and rounded corners, uses cache if available)
         let processed_path = processing::process_image(source, &self.config)?;
@@ -182,12 +217,61 @@ impl WallpaperManager {
         // Set the wallpaper using macOS APIs
         macos::set_wallpaper(&processed_path)?;
 
-        // Update the current index
-        self.current_index.store(index, Ordering::SeqCst);
+        *self.current_path.lock().unwrap_or_else(std::sync::PoisonError::into_inner) =
+            Some(source.to_path_buf());
 
         Ok(())
     }
 
+    /// Returns the schedule entry matching the current local time.
+    fn active_schedule_entry(&self) -> Option<&ScheduledWallpaper> {
+        if self.schedule.is_empty() {
+            return None;
+        }
+        self.schedule.active_at(local_minute_of_day())
+    }
+
+    /// Applies the active schedule entry, if any.
+    ///
+    /// The image is only set when the active entry changes, so manual changes
+    /// made while an entry is active are kept until the next transition.
+    ///
+    /// Returns `None` when no entry is active, otherwise the result of
+    /// setting the scheduled image.
+    fn apply_schedule(&self) -> Option<Result<(), WallpaperManagerError>> {
+        let mut active =
+            self.active_schedule.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let Some(entry) = self.active_schedule_entry() else {
+            *active = None;
+            return None;
+        };
+
+        if *active == Some(entry.index) {
+            return Some(Ok(()));
+        }
+
+        tracing::debug!(
+            index = entry.index,
+            from = %entry.from,
+            to = %entry.to,
+            "applying wallpaper schedule entry"
+        );
+        let result = self.set_wallpaper_from_path(&entry.image);
+        if result.is_ok() {
+            *active = Some(entry.index);
+        }
+        Some(result)
+    }
+
+    /// Returns whether rotation wallpapers (path/list) are available.
+    const fn can_rotate(&self) -> bool { !self.wallpapers.is_empty() }
+
+    /// Returns whether the cycling timer should run.
+    const fn needs_timer(&self) -> bool {
+        (self.config.interval > 0 && self.can_rotate()) || !self.schedule.is_empty()
+    }
+
     /// Sets the wallpaper at the given index for a specific screen.
     fn set_wallpaper_at_index_for_screen(
         &self,
@@ -210,6 +294,10 @@ impl WallpaperManager {
 
     /// Sets a random wallpaper for each screen.
     fn set_random_wallpapers_per_screen(&self) -> Result<(), WallpaperManagerError> {
+        if !self.can_rotate() {
+            return Err(WallpaperManagerError::NoWallpapers);
+        }
+
         let screen_count = macos::screen_count();
         let mut rng = rand::rng();
 
@@ -228,10 +316,34 @@ impl WallpaperManager {
     /// Sets the initial wallpaper on startup.
     #[cfg_attr(debug_assertions, allow(dead_code))]
     pub fn set_initial_wallpaper(&self) -> Result<(), WallpaperManagerError> {
+        if let Some(result) = self.apply_schedule() {
+            return result;
+        }
+
+        if !self.can_rotate() {
+            return Ok(());
+        }
+
         let index = self.select_initial_index();
         self.set_wallpaper_at_index(index)
     }
 
+    /// Returns the current wallpaper and the active schedule entry.
+    #[must_use]
+    pub fn status(&self) -> WallpaperStatus {
+        let current = self
+            .current_path
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .as_ref()
+            .map(|p| p.display().to_string());
+
+        WallpaperStatus {
+            current,
+            schedule_entry: self.active_schedule_entry().cloned(),
+        }
+    }
+
     /// Returns a list of all available wallpaper paths.
     #[must_use]
     pub fn list_wallpapers(&self) -> Vec<String> {
@@ -255,6 +367,9 @@ impl WallpaperManager {
                         screen_count - 1
                     )));
                 }
+                if !self.can_rotate() {
+                    return Err(WallpaperManagerError::NoWallpapers);
+                }
                 let mut rng = rand::rng();
                 let index = rng.random_range(0..self.wallpapers.len());
                 self.set_wallpaper_at_index_for_screen(index, *screen_index)
@@ -299,9 +414,12 @@ impl WallpaperManager {
 
     /// Starts the automatic wallpaper cycling timer.
     ///
-    /// Does nothing if the interval is 0.
+    /// Does nothing if the interval is 0 and no schedule is configured.
+    /// With a schedule, the timer ticks at least every minute to catch entry
+    /// transitions; rotation still happens only every `interval` seconds and
+    /// only while no schedule entry is active.
     pub fn start_timer(self: &Arc<Self>) {
-        if self.config.interval == 0 {
+        if !self.needs_timer() {
             return;
         }
 
@@ -312,15 +430,47 @@ impl WallpaperManager {
 
         let manager = Arc::clone(self);
         let interval = Duration::from_secs(self.config.interval);
+        let tick = if self.schedule.is_empty() {
+            interval
+        } else if self.config.interval == 0 {
+            SCHEDULE_CHECK_INTERVAL
+        } else {
+            interval.min(SCHEDULE_CHECK_INTERVAL)
+        };
 
         std::thread::spawn(move || {
+            let mut last_rotation = Instant::now();
+
             loop {
-                std::thread::sleep(interval);
+                std::thread::sleep(tick);
 
                 if !manager.timer_running.load(Ordering::SeqCst) {
                     break;
                 }
 
+                let was_scheduled = manager
+                    .active_schedule
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .is_some();
+
+                match manager.apply_schedule() {
+                    Some(Ok(())) => continue,
+                    Some(Err(err)) => {
+                        tracing::warn!(error = %err, "wallpaper timer failed to apply schedule");
+                        continue;
+                    }
+                    None => {}
+                }
+
+                // Rotate when the interval elapsed, or right away when a schedule entry ended
+                let rotation_due =
+                    manager.config.interval > 0 && last_rotation.elapsed() >= interval;
+                if !manager.can_rotate() || !(rotation_due || was_scheduled) {
+                    continue;
+                }
+
+                last_rotation = Instant::now();
                 let next_index = manager.select_next_index();
                 if let Err(err) = manager.set_wallpaper_at_index(next_index) {
                     tracing::warn!(error = %err, "wallpaper timer failed to set wallpaper");
@@ -411,14 +561,38 @@ pub fn perform_action(action: &WallpaperAction) -> Result<(), WallpaperManagerEr
 
     manager.perform_action(action)?;
 
-    // Reset timer if interval is set (to restart from current moment)
-    if manager.config.interval > 0 {
+    // Reset timer if it is running (to restart from current moment)
+    if manager.needs_timer() {
         manager.reset_timer();
     }
 
     Ok(())
 }
 
+/// Returns the current wallpaper and the active schedule entry.
+///
+/// # Errors
+///
+/// Returns an error if the manager is not initialized.
+pub fn current_status() -> Result<WallpaperStatus, WallpaperManagerError> {
+    let manager = get_manager().ok_or(WallpaperManagerError::NotInitialized)?;
+    Ok(manager.status())
+}
+
+/// Handles wallpaper IPC queries.
+///
+/// Returns `None` for queries that are not wallpaper-related.
+#[must_use]
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    match query {
+        IpcQuery::WallpaperCurrent => Some(match current_status() {
+            Ok(status) => IpcResponse::success(status),
+            Err(err) => IpcResponse::error(err.to_string()),
+        }),
+        _ => None,
+    }
+}
+
 /// Returns a list of all available wallpaper paths.
 ///
 /// # Errors
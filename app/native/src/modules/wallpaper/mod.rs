@@ -7,12 +7,19 @@
 //! - Automatic wallpaper cycling based on interval settings
 //! - Manual wallpaper control via CLI commands
 //! - Multi-screen support with per-screen wallpapers
+//! - Time-of-day schedules that override rotation while active
+//! - Per-workspace wallpapers shown when a tiling workspace becomes visible
 
 mod macos;
 mod manager;
 mod processing;
+mod schedule;
+mod workspace;
 
 pub use manager::{
-    WallpaperAction, WallpaperManagerError, generate_all_streaming, get_manager, init,
-    list_wallpapers, perform_action, setup,
+    WallpaperAction, WallpaperManagerError, WallpaperStatus, current_status,
+    generate_all_streaming, get_manager, handle_ipc_query, init, list_wallpapers, perform_action,
+    setup,
 };
+pub use schedule::ScheduledWallpaper;
+pub use workspace::show_workspace_wallpaper;
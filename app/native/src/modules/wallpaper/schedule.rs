@@ -0,0 +1,190 @@
+//! Time-of-day wallpaper scheduling.
+//!
+//! Schedule entries pick a fixed image for a local time window, e.g.
+//! "06:00"–"18:00". Windows whose end is before their start wrap around
+//! midnight ("22:00"–"06:00").
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::config::WallpaperScheduleEntry;
+use crate::utils::path::expand;
+use crate::utils::time_of_day::{parse_time_of_day, window_contains};
+
+/// A validated schedule entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledWallpaper {
+    /// Position of the entry in the `schedule` config array.
+    pub index: usize,
+    /// Start time as configured.
+    pub from: String,
+    /// End time as configured.
+    pub to: String,
+    /// Expanded image path.
+    pub image: PathBuf,
+    /// Start time in minutes since midnight.
+    #[serde(skip)]
+    from_minute: u32,
+    /// End time in minutes since midnight.
+    #[serde(skip)]
+    to_minute: u32,
+}
+
+impl ScheduledWallpaper {
+    /// Creates a schedule entry from config, returning `None` if a time is invalid.
+    #[must_use]
+    pub fn from_config(index: usize, entry: &WallpaperScheduleEntry) -> Option<Self> {
+        Some(Self {
+            index,
+            from: entry.from.clone(),
+            to: entry.to.clone(),
+            image: expand(&entry.image),
+            from_minute: parse_time_of_day(&entry.from)?,
+            to_minute: parse_time_of_day(&entry.to)?,
+        })
+    }
+
+    /// Returns whether the entry is active at the given minute of the day.
+    ///
+    /// The start is inclusive and the end exclusive. Equal start and end
+    /// times cover the whole day.
+    #[must_use]
+    pub const fn contains(&self, minute: u32) -> bool {
+        window_contains(self.from_minute, self.to_minute, minute)
+    }
+}
+
+/// The configured wallpaper schedule.
+#[derive(Debug, Clone, Default)]
+pub struct WallpaperSchedule {
+    entries: Vec<ScheduledWallpaper>,
+}
+
+impl WallpaperSchedule {
+    /// Builds the schedule from config, skipping entries with invalid times.
+    #[must_use]
+    pub fn from_config(entries: &[WallpaperScheduleEntry]) -> Self {
+        let entries = entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                let scheduled = ScheduledWallpaper::from_config(index, entry);
+                if scheduled.is_none() {
+                    tracing::warn!(
+                        index,
+                        from = %entry.from,
+                        to = %entry.to,
+                        "ignoring wallpaper schedule entry with invalid time (expected HH:MM)"
+                    );
+                }
+                scheduled
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Returns whether the schedule has no valid entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    /// Returns the first entry active at the given minute of the day.
+    #[must_use]
+    pub fn active_at(&self, minute: u32) -> Option<&ScheduledWallpaper> {
+        self.entries.iter().find(|entry| entry.contains(minute))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(from: &str, to: &str, image: &str) -> WallpaperScheduleEntry {
+        WallpaperScheduleEntry {
+            from: from.to_string(),
+            to: to.to_string(),
+            image: image.to_string(),
+        }
+    }
+
+    fn at(time: &str) -> u32 { parse_time_of_day(time).unwrap() }
+
+    #[test]
+    fn test_scheduled_wallpaper_daytime_window() {
+        let day = ScheduledWallpaper::from_config(0, &entry("06:00", "18:00", "/day.jpg")).unwrap();
+
+        assert!(day.contains(at("06:00")));
+        assert!(day.contains(at("12:00")));
+        assert!(day.contains(at("17:59")));
+        assert!(!day.contains(at("18:00")));
+        assert!(!day.contains(at("05:59")));
+    }
+
+    #[test]
+    fn test_scheduled_wallpaper_wraps_midnight() {
+        let night =
+            ScheduledWallpaper::from_config(0, &entry("22:00", "06:00", "/night.jpg")).unwrap();
+
+        assert!(night.contains(at("22:00")));
+        assert!(night.contains(at("23:59")));
+        assert!(night.contains(at("00:00")));
+        assert!(night.contains(at("05:59")));
+        assert!(!night.contains(at("06:00")));
+        assert!(!night.contains(at("12:00")));
+    }
+
+    #[test]
+    fn test_scheduled_wallpaper_equal_times_cover_whole_day() {
+        let always =
+            ScheduledWallpaper::from_config(0, &entry("08:00", "08:00", "/a.jpg")).unwrap();
+
+        assert!(always.contains(at("00:00")));
+        assert!(always.contains(at("08:00")));
+        assert!(always.contains(at("23:59")));
+    }
+
+    #[test]
+    fn test_schedule_skips_invalid_entries() {
+        let schedule = WallpaperSchedule::from_config(&[
+            entry("25:00", "06:00", "/bad.jpg"),
+            entry("06:00", "18:00", "/day.jpg"),
+        ]);
+
+        let active = schedule.active_at(at("12:00")).unwrap();
+        assert_eq!(active.index, 1);
+        assert_eq!(active.image, PathBuf::from("/day.jpg"));
+    }
+
+    #[test]
+    fn test_schedule_first_match_wins() {
+        let schedule = WallpaperSchedule::from_config(&[
+            entry("06:00", "18:00", "/day.jpg"),
+            entry("12:00", "13:00", "/lunch.jpg"),
+        ]);
+
+        assert_eq!(schedule.active_at(at("12:30")).unwrap().index, 0);
+    }
+
+    #[test]
+    fn test_schedule_no_match() {
+        let schedule = WallpaperSchedule::from_config(&[entry("06:00", "18:00", "/day.jpg")]);
+
+        assert!(schedule.active_at(at("20:00")).is_none());
+        assert!(WallpaperSchedule::default().active_at(at("20:00")).is_none());
+        assert!(WallpaperSchedule::default().is_empty());
+    }
+
+    #[test]
+    fn test_scheduled_wallpaper_serialization() {
+        let day = ScheduledWallpaper::from_config(2, &entry("06:00", "18:00", "/day.jpg")).unwrap();
+        let json = serde_json::to_value(&day).unwrap();
+
+        assert_eq!(json["index"], 2);
+        assert_eq!(json["from"], "06:00");
+        assert_eq!(json["to"], "18:00");
+        assert_eq!(json["image"], "/day.jpg");
+        assert!(json.get("fromMinute").is_none());
+    }
+}
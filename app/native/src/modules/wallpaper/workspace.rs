@@ -0,0 +1,123 @@
+//! Per-workspace wallpapers.
+//!
+//! A tiling workspace with a `wallpaper` in its config shows that image on its
+//! screen whenever it becomes visible, whether it is switched to, one of its
+//! windows is focused or it is sent to another screen. Workspaces without one
+//! keep whatever wallpaper is already shown. Requests are debounced per
+//! screen, so cycling quickly through workspaces only sets the wallpaper of
+//! the one that ends up visible.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+use super::manager::WallpaperManagerError;
+use super::{macos, processing};
+use crate::config::get_config;
+use crate::utils::path::expand;
+use crate::utils::thread::spawn_named_thread;
+
+/// How long a screen's wallpaper must stay requested before it is set.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Channel to the thread that sets workspace wallpapers.
+static SENDER: OnceLock<Sender<WallpaperRequest>> = OnceLock::new();
+
+/// A request to show an image on a display.
+#[derive(Debug)]
+struct WallpaperRequest {
+    /// `CGDirectDisplayID` of the display.
+    display_id: u32,
+    /// Image path from the workspace config.
+    image: String,
+}
+
+/// Shows a workspace's wallpaper on the display it became visible on.
+///
+/// Returns right away; the image is set in the background once no other
+/// request for the same display arrives for a moment.
+pub fn show_workspace_wallpaper(display_id: u32, image: &str) {
+    let sender = SENDER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        spawn_named_thread("workspace-wallpaper", move || run(&receiver));
+        sender
+    });
+
+    let request = WallpaperRequest {
+        display_id,
+        image: image.to_string(),
+    };
+    if sender.send(request).is_err() {
+        tracing::warn!("workspace wallpaper thread is not running");
+    }
+}
+
+/// Collects requests and applies the latest one per display once they settle.
+fn run(receiver: &mpsc::Receiver<WallpaperRequest>) {
+    let mut pending: HashMap<u32, String> = HashMap::new();
+
+    loop {
+        let next = if pending.is_empty() {
+            receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        } else {
+            receiver.recv_timeout(DEBOUNCE)
+        };
+
+        match next {
+            Ok(request) => {
+                pending.insert(request.display_id, request.image);
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                for (display_id, image) in pending.drain() {
+                    if let Err(err) = apply(display_id, &image) {
+                        tracing::warn!(error = %err, image, "failed to set workspace wallpaper");
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+/// Resolves a configured image path, expanding `~`.
+fn resolve_image(image: &str) -> Result<PathBuf, WallpaperManagerError> {
+    let path = expand(image);
+    if !path.is_file() || !processing::is_supported_image(&path) {
+        return Err(WallpaperManagerError::FileNotFound(image.to_string()));
+    }
+    Ok(path)
+}
+
+/// Processes the image like rotation wallpapers and sets it on the display.
+fn apply(display_id: u32, image: &str) -> Result<(), WallpaperManagerError> {
+    let source = resolve_image(image)?;
+    let screen_index = macos::screen_index_for_display(display_id).ok_or_else(|| {
+        WallpaperManagerError::InvalidScreen(format!("No screen for display {display_id}"))
+    })?;
+
+    let config = &get_config().wallpapers;
+    let processed = processing::process_image_for_screen(&source, config, screen_index)?;
+    macos::set_wallpaper_for_screen(&processed, screen_index)?;
+
+    tracing::debug!(display_id, image, "set workspace wallpaper");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_image_rejects_missing_file() {
+        let result = resolve_image("/nonexistent/stache/wallpaper.jpg");
+        assert!(matches!(result, Err(WallpaperManagerError::FileNotFound(_))));
+    }
+
+    #[test]
+    fn test_resolve_image_rejects_directory() {
+        let dir = std::env::temp_dir();
+        assert!(resolve_image(&dir.display().to_string()).is_err());
+    }
+}
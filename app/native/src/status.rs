@@ -0,0 +1,98 @@
+//! App status reporting.
+//!
+//! Answers the `status` IPC query with the app version, whether the
+//! accessibility permission is granted and which subsystems are running, so
+//! `stache status` can diagnose a broken install in one command. Subsystems
+//! that are disabled or failed to start are reported as not running.
+
+use serde::Serialize;
+
+use crate::modules::{audio, notunes, tiling, wallpaper};
+use crate::utils::accessibility;
+use crate::utils::ipc_socket::{IpcQuery, IpcResponse};
+
+/// Application version from Cargo.toml.
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Status of the app and its subsystems.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AppStatus {
+    /// App version.
+    version: &'static str,
+    /// Whether the accessibility permission is granted right now.
+    accessibility_granted: bool,
+    /// Whether tiling is enabled in the config.
+    tiling_enabled: bool,
+    /// Whether the tiling manager finished initializing.
+    tiling_initialized: bool,
+    /// Whether tiling is paused because the accessibility permission was
+    /// revoked while running.
+    tiling_paused: bool,
+    /// Whether the audio device watcher (`proxyAudio`) is running.
+    audio_watcher_running: bool,
+    /// Whether noTunes is running.
+    notunes_running: bool,
+    /// Whether the wallpaper manager is running.
+    wallpaper_running: bool,
+}
+
+/// Reads the status of the app and its subsystems from their running flags.
+fn app_status() -> AppStatus {
+    AppStatus {
+        version: APP_VERSION,
+        accessibility_granted: accessibility::is_trusted(),
+        tiling_enabled: tiling::init::is_enabled(),
+        tiling_initialized: tiling::init::is_initialized(),
+        tiling_paused: tiling::init::is_paused(),
+        audio_watcher_running: audio::is_watcher_running(),
+        notunes_running: notunes::is_running(),
+        wallpaper_running: wallpaper::get_manager().is_some(),
+    }
+}
+
+/// Handles the `status` IPC query.
+///
+/// Returns `None` for any other query so the next handler can answer it.
+pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
+    matches!(query, IpcQuery::Status).then(|| IpcResponse::success(app_status()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_ipc_query_ignores_other_queries() {
+        assert!(handle_ipc_query(&IpcQuery::Ping).is_none());
+    }
+
+    #[test]
+    fn test_app_status_serialization() {
+        let status = AppStatus {
+            version: "1.2.3",
+            accessibility_granted: true,
+            tiling_enabled: true,
+            tiling_initialized: false,
+            tiling_paused: false,
+            audio_watcher_running: false,
+            notunes_running: true,
+            wallpaper_running: false,
+        };
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "version": "1.2.3",
+                "accessibilityGranted": true,
+                "tilingEnabled": true,
+                "tilingInitialized": false,
+                "tilingPaused": false,
+                "audioWatcherRunning": false,
+                "notunesRunning": true,
+                "wallpaperRunning": false,
+            })
+        );
+    }
+}
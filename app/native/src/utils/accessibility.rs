@@ -14,7 +14,6 @@ use core_foundation::string::CFString;
 // FFI declarations for Accessibility API
 #[link(name = "ApplicationServices", kind = "framework")]
 unsafe extern "C" {
-    #[allow(dead_code)] // Used by is_trusted() which is a public API for non-prompting checks
     fn AXIsProcessTrusted() -> bool;
     fn AXIsProcessTrustedWithOptions(options: *const c_void) -> bool;
 }
@@ -31,7 +30,6 @@ const K_AX_TRUSTED_CHECK_OPTION_PROMPT: &str = "AXTrustedCheckOptionPrompt";
 ///
 /// Returns `true` if the application has accessibility permissions, `false` otherwise.
 #[must_use]
-#[allow(dead_code)] // Public API for checking permissions without prompting
 pub fn is_trusted() -> bool { unsafe { AXIsProcessTrusted() } }
 
 /// Checks accessibility permissions and optionally prompts the user to grant them.
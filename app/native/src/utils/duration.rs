@@ -0,0 +1,70 @@
+//! Human-friendly duration strings.
+//!
+//! Durations are written as one or more `<number><unit>` parts, e.g. `90m`,
+//! `2h` or `1h30m`. Supported units are `s`, `m` and `h`.
+
+use std::time::Duration;
+
+/// Parses a duration string such as `90m`, `2h` or `1h30m`.
+///
+/// Returns `None` for malformed strings, unknown units and zero durations.
+#[must_use]
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let mut total: u64 = 0;
+    let mut number = String::new();
+
+    for ch in value.chars() {
+        if ch.is_ascii_digit() {
+            number.push(ch);
+            continue;
+        }
+
+        let multiplier = match ch.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            _ => return None,
+        };
+        let amount: u64 = number.parse().ok()?;
+        total = total.checked_add(amount.checked_mul(multiplier)?)?;
+        number.clear();
+    }
+
+    // Every number must be followed by a unit
+    if !number.is_empty() || total == 0 {
+        return None;
+    }
+
+    Some(Duration::from_secs(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_single_unit() {
+        assert_eq!(parse_duration("45s"), Some(Duration::from_secs(45)));
+        assert_eq!(parse_duration("90m"), Some(Duration::from_secs(90 * 60)));
+        assert_eq!(parse_duration("2h"), Some(Duration::from_secs(2 * 60 * 60)));
+        assert_eq!(parse_duration(" 2H "), Some(Duration::from_secs(2 * 60 * 60)));
+    }
+
+    #[test]
+    fn test_parse_duration_combined_units() {
+        assert_eq!(parse_duration("1h30m"), Some(Duration::from_secs(90 * 60)));
+        assert_eq!(parse_duration("1m30s"), Some(Duration::from_secs(90)));
+    }
+
+    #[test]
+    fn test_parse_duration_invalid() {
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("90"), None);
+        assert_eq!(parse_duration("m"), None);
+        assert_eq!(parse_duration("2d"), None);
+        assert_eq!(parse_duration("1h 30m"), None);
+        assert_eq!(parse_duration("0m"), None);
+        assert_eq!(parse_duration("-5m"), None);
+    }
+}
@@ -36,8 +36,17 @@ pub enum StacheNotification {
     TilingSetLayout(String),
     /// Focus window in direction or by ID.
     TilingWindowFocus(String),
+    /// Focus the previously focused window.
+    TilingWindowFocusLast,
     /// Swap focused window with neighbor in direction.
     TilingWindowSwap(String),
+    /// Move the focused window to the first position of its workspace.
+    TilingWindowPromote,
+    /// Move focused floating window by `step` pixels, or swap a tiled one, in direction.
+    TilingWindowMove {
+        direction: String,
+        step: Option<u32>,
+    },
     /// Resize focused window.
     TilingWindowResize { dimension: String, amount: i32 },
     /// Apply floating preset to focused window.
@@ -46,10 +55,31 @@ pub enum StacheNotification {
     TilingWindowSendToWorkspace(String),
     /// Send focused window to screen.
     TilingWindowSendToScreen(String),
+    /// Change the sticky state of the focused window ("toggle", "on" or "off").
+    TilingWindowSticky(String),
+    /// Change whether the focused window is kept on top ("toggle", "on" or "off").
+    TilingWindowAlwaysOnTop(String),
+    /// Change the floating state of the focused window ("toggle", "on" or "off").
+    TilingWindowFloat(String),
+    /// Move the focused window to the scratchpad.
+    TilingWindowToScratchpad,
+    /// Show or hide the scratchpad's windows.
+    TilingScratchpadToggle,
     /// Balance focused workspace.
     TilingWorkspaceBalance,
     /// Send focused workspace to screen.
     TilingWorkspaceSendToScreen(String),
+    /// Change the master window count of the focused workspace ("+1", "-1" or "2").
+    TilingWorkspaceMasters(String),
+    /// Scroll the focused scrolling workspace by one column ("left" or "right").
+    TilingWorkspaceScroll(String),
+
+    // Desktop app notifications
+    /// A layout pass finished for a workspace (debug builds only).
+    ///
+    /// `frames` is a JSON array of `{ windowId, x, y, width, height }` target
+    /// frames, so tests can wait for a layout instead of sleeping.
+    TilingLayoutApplied { workspace: String, frames: String },
 }
 
 impl StacheNotification {
@@ -63,13 +93,24 @@ impl StacheNotification {
             Self::TilingFocusWorkspace(_) => "tiling-focus-workspace",
             Self::TilingSetLayout(_) => "tiling-set-layout",
             Self::TilingWindowFocus(_) => "tiling-window-focus",
+            Self::TilingWindowFocusLast => "tiling-window-focus-last",
             Self::TilingWindowSwap(_) => "tiling-window-swap",
+            Self::TilingWindowPromote => "tiling-window-promote",
+            Self::TilingWindowMove { .. } => "tiling-window-move",
             Self::TilingWindowResize { .. } => "tiling-window-resize",
             Self::TilingWindowPreset(_) => "tiling-window-preset",
             Self::TilingWindowSendToWorkspace(_) => "tiling-window-send-to-workspace",
             Self::TilingWindowSendToScreen(_) => "tiling-window-send-to-screen",
+            Self::TilingWindowSticky(_) => "tiling-window-sticky",
+            Self::TilingWindowAlwaysOnTop(_) => "tiling-window-always-on-top",
+            Self::TilingWindowFloat(_) => "tiling-window-float",
+            Self::TilingWindowToScratchpad => "tiling-window-to-scratchpad",
+            Self::TilingScratchpadToggle => "tiling-scratchpad-toggle",
             Self::TilingWorkspaceBalance => "tiling-workspace-balance",
             Self::TilingWorkspaceSendToScreen(_) => "tiling-workspace-send-to-screen",
+            Self::TilingWorkspaceMasters(_) => "tiling-workspace-masters",
+            Self::TilingWorkspaceScroll(_) => "tiling-workspace-scroll",
+            Self::TilingLayoutApplied { .. } => "tiling-layout-applied",
         };
         format!("{NOTIFICATION_PREFIX}{suffix}")
     }
@@ -83,6 +124,13 @@ impl StacheNotification {
             Self::TilingSetLayout(layout) => Some(vec![("layout", layout.clone())]),
             Self::TilingWindowFocus(target) => Some(vec![("target", target.clone())]),
             Self::TilingWindowSwap(direction) => Some(vec![("direction", direction.clone())]),
+            Self::TilingWindowMove { direction, step } => {
+                let mut info = vec![("direction", direction.clone())];
+                if let Some(step) = step {
+                    info.push(("step", step.to_string()));
+                }
+                Some(info)
+            }
             Self::TilingWindowResize { dimension, amount } => Some(vec![
                 ("dimension", dimension.clone()),
                 ("amount", amount.to_string()),
@@ -94,6 +142,15 @@ impl StacheNotification {
             Self::TilingWindowSendToScreen(screen) | Self::TilingWorkspaceSendToScreen(screen) => {
                 Some(vec![("screen", screen.clone())])
             }
+            Self::TilingWindowSticky(action)
+            | Self::TilingWindowAlwaysOnTop(action)
+            | Self::TilingWindowFloat(action) => Some(vec![("action", action.clone())]),
+            Self::TilingWorkspaceMasters(masters) => Some(vec![("masters", masters.clone())]),
+            Self::TilingWorkspaceScroll(direction) => Some(vec![("direction", direction.clone())]),
+            Self::TilingLayoutApplied { workspace, frames } => Some(vec![
+                ("workspace", workspace.clone()),
+                ("frames", frames.clone()),
+            ]),
             _ => None,
         }
     }
@@ -129,11 +186,19 @@ impl StacheNotification {
                     user_info.and_then(|info| info.get("target")).cloned().unwrap_or_default();
                 Some(Self::TilingWindowFocus(target))
             }
+            "tiling-window-focus-last" => Some(Self::TilingWindowFocusLast),
             "tiling-window-swap" => {
                 let direction =
                     user_info.and_then(|info| info.get("direction")).cloned().unwrap_or_default();
                 Some(Self::TilingWindowSwap(direction))
             }
+            "tiling-window-promote" => Some(Self::TilingWindowPromote),
+            "tiling-window-move" => {
+                let direction =
+                    user_info.and_then(|info| info.get("direction")).cloned().unwrap_or_default();
+                let step = user_info.and_then(|info| info.get("step")).and_then(|s| s.parse().ok());
+                Some(Self::TilingWindowMove { direction, step })
+            }
             "tiling-window-resize" => {
                 let dimension =
                     user_info.and_then(|info| info.get("dimension")).cloned().unwrap_or_default();
@@ -158,12 +223,46 @@ impl StacheNotification {
                     user_info.and_then(|info| info.get("screen")).cloned().unwrap_or_default();
                 Some(Self::TilingWindowSendToScreen(screen))
             }
+            "tiling-window-sticky" => {
+                let action =
+                    user_info.and_then(|info| info.get("action")).cloned().unwrap_or_default();
+                Some(Self::TilingWindowSticky(action))
+            }
+            "tiling-window-always-on-top" => {
+                let action =
+                    user_info.and_then(|info| info.get("action")).cloned().unwrap_or_default();
+                Some(Self::TilingWindowAlwaysOnTop(action))
+            }
+            "tiling-window-float" => {
+                let action =
+                    user_info.and_then(|info| info.get("action")).cloned().unwrap_or_default();
+                Some(Self::TilingWindowFloat(action))
+            }
+            "tiling-window-to-scratchpad" => Some(Self::TilingWindowToScratchpad),
+            "tiling-scratchpad-toggle" => Some(Self::TilingScratchpadToggle),
             "tiling-workspace-balance" => Some(Self::TilingWorkspaceBalance),
             "tiling-workspace-send-to-screen" => {
                 let screen =
                     user_info.and_then(|info| info.get("screen")).cloned().unwrap_or_default();
                 Some(Self::TilingWorkspaceSendToScreen(screen))
             }
+            "tiling-workspace-masters" => {
+                let masters =
+                    user_info.and_then(|info| info.get("masters")).cloned().unwrap_or_default();
+                Some(Self::TilingWorkspaceMasters(masters))
+            }
+            "tiling-workspace-scroll" => {
+                let direction =
+                    user_info.and_then(|info| info.get("direction")).cloned().unwrap_or_default();
+                Some(Self::TilingWorkspaceScroll(direction))
+            }
+            "tiling-layout-applied" => {
+                let workspace =
+                    user_info.and_then(|info| info.get("workspace")).cloned().unwrap_or_default();
+                let frames =
+                    user_info.and_then(|info| info.get("frames")).cloned().unwrap_or_default();
+                Some(Self::TilingLayoutApplied { workspace, frames })
+            }
             _ => None,
         }
     }
@@ -292,13 +391,23 @@ pub fn start_notification_listener() {
             format!("{NOTIFICATION_PREFIX}tiling-focus-workspace"),
             format!("{NOTIFICATION_PREFIX}tiling-set-layout"),
             format!("{NOTIFICATION_PREFIX}tiling-window-focus"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-focus-last"),
             format!("{NOTIFICATION_PREFIX}tiling-window-swap"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-promote"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-move"),
             format!("{NOTIFICATION_PREFIX}tiling-window-resize"),
             format!("{NOTIFICATION_PREFIX}tiling-window-preset"),
             format!("{NOTIFICATION_PREFIX}tiling-window-send-to-workspace"),
             format!("{NOTIFICATION_PREFIX}tiling-window-send-to-screen"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-sticky"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-always-on-top"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-float"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-to-scratchpad"),
+            format!("{NOTIFICATION_PREFIX}tiling-scratchpad-toggle"),
             format!("{NOTIFICATION_PREFIX}tiling-workspace-balance"),
             format!("{NOTIFICATION_PREFIX}tiling-workspace-send-to-screen"),
+            format!("{NOTIFICATION_PREFIX}tiling-workspace-masters"),
+            format!("{NOTIFICATION_PREFIX}tiling-workspace-scroll"),
         ];
 
         for notification_name in &notifications {
@@ -495,6 +604,210 @@ mod tests {
         assert_eq!(notification, Some(StacheNotification::Reload));
     }
 
+    #[test]
+    fn test_from_notification_tiling_workspace_masters() {
+        let notification = StacheNotification::TilingWorkspaceMasters("+1".to_string());
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-workspace-masters"
+        );
+
+        let mut user_info = std::collections::HashMap::new();
+        user_info.insert("masters".to_string(), "+1".to_string());
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-workspace-masters",
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_from_notification_tiling_workspace_scroll() {
+        let notification = StacheNotification::TilingWorkspaceScroll("right".to_string());
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-workspace-scroll"
+        );
+
+        let mut user_info = std::collections::HashMap::new();
+        user_info.insert("direction".to_string(), "right".to_string());
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-workspace-scroll",
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_from_notification_tiling_window_focus_last() {
+        let notification = StacheNotification::TilingWindowFocusLast;
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-window-focus-last"
+        );
+        assert!(notification.user_info().is_none());
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-window-focus-last",
+            None,
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_from_notification_tiling_window_promote() {
+        let notification = StacheNotification::TilingWindowPromote;
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-window-promote"
+        );
+        assert!(notification.user_info().is_none());
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-window-promote",
+            None,
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_from_notification_tiling_layout_applied() {
+        let notification = StacheNotification::TilingLayoutApplied {
+            workspace: "coding".to_string(),
+            frames: r#"[{"windowId":1,"x":0.0,"y":0.0,"width":800.0,"height":600.0}]"#.to_string(),
+        };
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-layout-applied"
+        );
+
+        let user_info: std::collections::HashMap<String, String> = notification
+            .user_info()
+            .unwrap()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-layout-applied",
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_from_notification_tiling_window_move() {
+        let notification = StacheNotification::TilingWindowMove {
+            direction: "left".to_string(),
+            step: Some(20),
+        };
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-window-move"
+        );
+
+        let mut user_info = std::collections::HashMap::new();
+        user_info.insert("direction".to_string(), "left".to_string());
+        user_info.insert("step".to_string(), "20".to_string());
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-window-move",
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+
+        // The step is optional
+        user_info.remove("step");
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-window-move",
+            Some(&user_info),
+        );
+        assert_eq!(
+            parsed,
+            Some(StacheNotification::TilingWindowMove {
+                direction: "left".to_string(),
+                step: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_notification_tiling_window_float() {
+        let notification = StacheNotification::TilingWindowFloat("toggle".to_string());
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-window-float"
+        );
+
+        let mut user_info = std::collections::HashMap::new();
+        user_info.insert("action".to_string(), "toggle".to_string());
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-window-float",
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_from_notification_tiling_window_sticky() {
+        let notification = StacheNotification::TilingWindowSticky("toggle".to_string());
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-window-sticky"
+        );
+
+        let mut user_info = std::collections::HashMap::new();
+        user_info.insert("action".to_string(), "toggle".to_string());
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-window-sticky",
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_from_notification_tiling_window_always_on_top() {
+        let notification = StacheNotification::TilingWindowAlwaysOnTop("off".to_string());
+        assert_eq!(
+            notification.notification_name(),
+            "com.marcosmoura.stache.tiling-window-always-on-top"
+        );
+
+        let mut user_info = std::collections::HashMap::new();
+        user_info.insert("action".to_string(), "off".to_string());
+
+        let parsed = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-window-always-on-top",
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_from_notification_tiling_scratchpad() {
+        for (notification, name) in [
+            (
+                StacheNotification::TilingWindowToScratchpad,
+                "tiling-window-to-scratchpad",
+            ),
+            (
+                StacheNotification::TilingScratchpadToggle,
+                "tiling-scratchpad-toggle",
+            ),
+        ] {
+            let full_name = format!("com.marcosmoura.stache.{name}");
+            assert_eq!(notification.notification_name(), full_name);
+            assert!(notification.user_info().is_none());
+
+            let parsed = StacheNotification::from_notification(&full_name, None);
+            assert_eq!(parsed, Some(notification));
+        }
+    }
+
     #[test]
     fn test_from_notification_unknown() {
         let notification =
@@ -27,15 +27,42 @@
 //! {"data": [...]}
 //! {"error": "Tiling not initialized"}
 //! ```
+//!
+//! # Event Stream
+//!
+//! A `subscribe` query keeps the connection open. The server acknowledges
+//! with a normal response, then writes one JSON event per line until the
+//! client disconnects:
+//!
+//! ```json
+//! {"type": "subscribe", "topics": ["window-focused", "workspace-switched"]}
+//! {"data": {"subscribed": ["window-focused", "workspace-switched"]}}
+//! {"event": "workspace-switched", "data": {"workspace": "code", ...}}
+//! ```
+//!
+//! An empty `topics` list subscribes to every topic. Clients that stop
+//! reading are dropped once they fall behind the event buffer.
+//!
+//! # Batches
+//!
+//! A `batch` query runs several queries in one exchange, in order, and
+//! responds with their responses as an array. A failing query reports its
+//! error in place without aborting the rest:
+//!
+//! ```json
+//! {"type": "batch", "queries": [{"type": "screens"}, {"type": "v2Workspaces"}]}
+//! {"data": [{"data": [...]}, {"error": "Tiling v2 not initialized"}]}
+//! ```
 
 use std::io::{BufRead, BufReader, Write};
 use std::os::unix::net::{UnixListener, UnixStream};
 use std::path::PathBuf;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
 use std::thread;
 
 use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
 
 use crate::cache::get_cache_dir;
 
@@ -51,9 +78,21 @@ const MAX_RETRIES: u32 = 3;
 /// Delay between retry attempts in milliseconds.
 const RETRY_DELAY_MS: u64 = 100;
 
+/// Number of buffered events per subscriber before it is considered too slow.
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Write timeout for subscription streams in milliseconds.
+const SUBSCRIBER_WRITE_TIMEOUT_MS: u64 = 1000;
+
+/// Maximum number of queries in a single batch.
+const MAX_BATCH_SIZE: usize = 32;
+
 /// Whether the server is running.
 static SERVER_RUNNING: AtomicBool = AtomicBool::new(false);
 
+/// Broadcast channel feeding subscription streams.
+static EVENTS: OnceLock<broadcast::Sender<IpcEvent>> = OnceLock::new();
+
 // ============================================================================
 // Query Types
 // ============================================================================
@@ -62,8 +101,15 @@ static SERVER_RUNNING: AtomicBool = AtomicBool::new(false);
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum IpcQuery {
-    /// Query all screens (v1).
-    Screens,
+    /// Query screens (v1).
+    ///
+    /// Without a filter, all screens are returned as an array. With one,
+    /// only the matching screen is returned, as an object.
+    Screens {
+        /// Screen to return: "main", "secondary", or a screen name.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        screen: Option<String>,
+    },
 
     /// Query workspaces with optional filters (v1).
     Workspaces {
@@ -86,14 +132,262 @@ pub enum IpcQuery {
         /// Include detailed information about each window.
         #[serde(default)]
         detailed: bool,
+        /// Sort key: "app", "title", "workspace" or "recent".
+        /// Windows keep their tracking order when omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sort: Option<String>,
     },
 
     /// Query all running applications (excluding ignored apps).
     Apps,
 
+    /// Query the focused window with its workspace and screen.
+    Focused,
+
+    /// Query recently focused windows, most recent first.
+    History {
+        /// Maximum number of windows to return.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<usize>,
+    },
+
+    /// Query the dwindle split tree of a workspace (the focused one by default).
+    Tree {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        workspace: Option<String>,
+    },
+
+    /// Compute the frames a layout would give to synthetic windows on a
+    /// frame, without tracking or moving any real window.
+    DryRunLayout {
+        /// Layout to compute, e.g. "dwindle" or "split-vertical".
+        layout: String,
+        /// Window IDs in stack order.
+        #[serde(rename = "windowIds")]
+        window_ids: Vec<u32>,
+        /// Frame to tile within: x, y, width and height.
+        frame: [f64; 4],
+    },
+
+    /// Resize the focused window and report the outcome.
+    ResizeWindow {
+        /// Dimension to resize: "width" or "height".
+        dimension: String,
+        /// Pixels to add (positive) or remove (negative).
+        delta: i32,
+    },
+
+    /// Grow or shrink the focused window by `tiling.resizeStep` and report
+    /// the outcome.
+    ResizeWindowStep {
+        /// Dimension to resize: "width" or "height".
+        dimension: String,
+        /// Whether to grow (`true`) or shrink (`false`) the window.
+        grow: bool,
+    },
+
+    /// Set a split ratio of the focused workspace and report the stored value.
+    SetSplitRatio {
+        /// Index in the workspace's split ratios (0 is the master ratio in
+        /// the master layout).
+        index: usize,
+        /// Ratio to set, clamped to 0.1 - 0.9.
+        ratio: f64,
+    },
+
+    /// Center or maximize the focused floating window and report its frame.
+    PlaceWindow {
+        /// Placement to apply: "center" or "maximize".
+        placement: String,
+    },
+
+    /// Cascade or tile the floating windows of the focused workspace.
+    ArrangeFloating {
+        /// Arrangement to apply: "cascade" or "grid".
+        arrangement: String,
+    },
+
+    /// Focus the window at a 1-based position in the focused workspace and
+    /// report its ID.
+    FocusWindowIndex {
+        /// Position of the window, starting at 1.
+        index: usize,
+        /// Wrap positions past the last window around instead of failing.
+        #[serde(default)]
+        wraps: bool,
+    },
+
+    /// Minimize the focused window, or restore a minimized window of the
+    /// focused workspace, and report which window changed.
+    MinimizeWindow {
+        /// `true` to minimize, `false` to restore.
+        minimized: bool,
+    },
+
+    /// Send the focused window to a workspace and report where it ended up.
+    SendWindow {
+        /// Name of the target workspace.
+        workspace: String,
+        /// Switch to the workspace afterwards.
+        #[serde(default)]
+        follow: bool,
+    },
+
+    /// Switch to the next/previous workspace on the focused screen and report
+    /// the workspace switched to.
+    CycleWorkspace {
+        /// Direction to cycle: "next" or "prev".
+        direction: String,
+    },
+
+    /// Switch to the previously visible workspace on the focused screen and
+    /// report the workspace switched to.
+    FocusLastWorkspace,
+
+    /// Create a workspace on the focused screen for the rest of the session.
+    CreateWorkspace {
+        /// Name of the new workspace.
+        name: String,
+    },
+
+    /// Rename a workspace for the rest of the session.
+    RenameWorkspace {
+        /// Workspace to rename (defaults to the focused workspace).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        workspace: Option<String>,
+        /// New name of the workspace.
+        name: String,
+    },
+
+    /// Move every window of the focused workspace into another workspace and
+    /// report how many were moved.
+    MergeWorkspace {
+        /// Name of the workspace receiving the windows.
+        target: String,
+        /// Switch to the target workspace afterwards.
+        #[serde(default)]
+        follow: bool,
+    },
+
+    /// Copy the layout and split ratios of a workspace to the focused one,
+    /// or exchange them between the two.
+    CopyLayout {
+        /// Name of the workspace to copy the layout from.
+        workspace: String,
+        /// Give the named workspace the focused workspace's layout in return.
+        #[serde(default)]
+        swap: bool,
+    },
+
+    /// Route new windows of the focused window's app to the focused
+    /// workspace for the rest of the session, or release the claim.
+    ClaimApp {
+        /// Whether to claim the app (`true`) or release it (`false`).
+        claim: bool,
+    },
+
+    /// Move tracked windows back to their frames from before tiling and
+    /// report how many were restored.
+    RestoreWindows,
+
+    /// Switch the gaps of all workspaces to a `tiling.gapPresets` entry and
+    /// report the active preset.
+    SetGapPreset {
+        /// Preset name, "cycle" for the next preset, or "reset" for the
+        /// configured gaps.
+        preset: String,
+    },
+
+    /// Track windows tiling missed, untrack windows that are gone and
+    /// re-apply the layouts of all visible workspaces, reporting how many
+    /// windows were tracked and untracked.
+    Retile,
+
+    /// Send a playback command to the active media app and report the
+    /// updated media info.
+    MediaControl {
+        /// Command: "play-pause", "next" or "previous".
+        command: String,
+    },
+
+    /// Capture the focused window or workspace to a PNG file and report its path.
+    Capture {
+        /// What to capture: "window" or "workspace".
+        target: String,
+    },
+
+    /// Keep the system awake for a duration (e.g. "90m"), then release it.
+    KeepAwake {
+        /// Duration string such as "90m", "2h" or "1h30m".
+        duration: String,
+    },
+
+    /// Turn focus mode on or off, or query it, and report its state.
+    FocusMode {
+        /// Action: "on", "off" or "status".
+        action: String,
+    },
+
+    /// Show or hide the status bar and report whether it is visible.
+    Bar {
+        /// Action: "show", "hide" or "toggle".
+        action: String,
+    },
+
+    /// Change the volume of the default output device and report the
+    /// resulting volume and mute state.
+    AudioVolume {
+        /// Action: "set", "up" or "down".
+        action: String,
+        /// Volume for "set", or step for "up"/"down", in percent.
+        /// Clamped to 0 - 100.
+        #[serde(default)]
+        value: i32,
+    },
+
+    /// Change the mute state of the default output device and report the
+    /// resulting volume and mute state.
+    AudioMute {
+        /// Action: "toggle".
+        action: String,
+    },
+
+    /// List audio devices with their IDs and whether each is a default device.
+    AudioDevices,
+
+    /// Make the device matching a name substring (case-insensitive) the
+    /// default output or input device, and report it.
+    AudioSetDevice {
+        /// Direction: "output" or "input".
+        direction: String,
+        /// Substring of the device name.
+        name: String,
+    },
+
+    /// Query whether automatic device switching is running and whether quiet
+    /// hours are currently suppressing it.
+    AudioStatus,
+
+    /// Query the current wallpaper and active schedule entry.
+    WallpaperCurrent,
+
+    /// Keep the connection open and stream events for the given topics.
+    /// An empty list subscribes to all topics.
+    Subscribe {
+        #[serde(default)]
+        topics: Vec<String>,
+    },
+
+    /// Run several queries in one exchange and respond with an array of
+    /// their responses, in order. Batches can't be nested or subscribe.
+    Batch { queries: Vec<Self> },
+
     /// Ping to check if app is running.
     Ping,
 
+    /// Query the app version and which subsystems are running.
+    Status,
+
     // ========================================================================
     // Tiling v2 queries
     // ========================================================================
@@ -143,6 +437,63 @@ impl IpcResponse {
     pub fn error(message: impl Into<String>) -> Self { Self::Error { error: message.into() } }
 }
 
+// ============================================================================
+// Event Stream
+// ============================================================================
+
+/// Event topics available to `subscribe` clients.
+pub mod topics {
+    /// A window gained focus.
+    pub const WINDOW_FOCUSED: &str = "window-focused";
+
+    /// The focused workspace changed.
+    pub const WORKSPACE_SWITCHED: &str = "workspace-switched";
+
+    /// A workspace layout was recomputed.
+    pub const LAYOUT_CHANGED: &str = "layout-changed";
+
+    /// All known topics.
+    pub const ALL: &[&str] = &[WINDOW_FOCUSED, WORKSPACE_SWITCHED, LAYOUT_CHANGED];
+}
+
+/// An event written to subscription streams.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpcEvent {
+    /// The event topic.
+    pub event: String,
+    /// The event payload.
+    pub data: serde_json::Value,
+}
+
+/// Returns the event broadcast sender, creating it on first use.
+fn event_sender() -> &'static broadcast::Sender<IpcEvent> {
+    EVENTS.get_or_init(|| broadcast::channel(EVENT_CHANNEL_CAPACITY).0)
+}
+
+/// Returns whether any client is subscribed to the event stream.
+///
+/// Lets publishers skip building payloads that nobody will read.
+#[must_use]
+pub fn has_subscribers() -> bool { EVENTS.get().is_some_and(|tx| tx.receiver_count() > 0) }
+
+/// Publishes an event to all subscribed clients.
+///
+/// Never blocks: slow clients lag behind and are dropped by their own
+/// connection thread.
+pub fn publish_event(topic: &str, data: impl Serialize) {
+    if !has_subscribers() {
+        return;
+    }
+
+    let event = IpcEvent {
+        event: topic.to_string(),
+        data: serde_json::to_value(data).unwrap_or(serde_json::Value::Null),
+    };
+
+    // Only fails when the last subscriber disconnected in the meantime
+    let _ = event_sender().send(event);
+}
+
 // ============================================================================
 // Socket Path
 // ============================================================================
@@ -256,6 +607,11 @@ where F: Fn(IpcQuery) -> IpcResponse {
 
     // Parse query
     let response = match serde_json::from_str::<IpcQuery>(line.trim()) {
+        Ok(IpcQuery::Subscribe { topics }) => {
+            stream_events(reader.into_inner(), &topics);
+            return;
+        }
+        Ok(IpcQuery::Batch { queries }) => handle_batch(queries, handler),
         Ok(query) => handler(query),
         Err(e) => IpcResponse::error(format!("Invalid query: {e}")),
     };
@@ -269,6 +625,102 @@ where F: Fn(IpcQuery) -> IpcResponse {
     let _ = writeln!(stream, "{response_json}");
 }
 
+/// Runs the queries of a batch in order and collects their responses.
+///
+/// A query that fails, or can't be batched, gets an error response in its
+/// place; the other queries still run.
+fn handle_batch<F>(queries: Vec<IpcQuery>, handler: &F) -> IpcResponse
+where F: Fn(IpcQuery) -> IpcResponse {
+    if queries.len() > MAX_BATCH_SIZE {
+        return IpcResponse::error(format!(
+            "Batch too large: {} queries (max {MAX_BATCH_SIZE})",
+            queries.len()
+        ));
+    }
+
+    let responses: Vec<IpcResponse> = queries
+        .into_iter()
+        .map(|query| match query {
+            IpcQuery::Batch { .. } => IpcResponse::error("Batches can't be nested"),
+            IpcQuery::Subscribe { .. } => IpcResponse::error("Subscribe can't be batched"),
+            query => handler(query),
+        })
+        .collect();
+
+    IpcResponse::success(responses)
+}
+
+/// Resolves requested topics, defaulting to all topics when empty.
+///
+/// Returns the first unknown topic as an error.
+fn resolve_topics(requested: &[String]) -> Result<Vec<&'static str>, String> {
+    if requested.is_empty() {
+        return Ok(topics::ALL.to_vec());
+    }
+
+    requested
+        .iter()
+        .map(|topic| {
+            topics::ALL
+                .iter()
+                .copied()
+                .find(|known| *known == topic)
+                .ok_or_else(|| format!("Unknown topic: {topic}"))
+        })
+        .collect()
+}
+
+/// Writes a value as a single JSON line.
+fn write_json_line(stream: &mut UnixStream, value: &impl Serialize) -> std::io::Result<()> {
+    let json = serde_json::to_string(value).map_err(std::io::Error::other)?;
+    writeln!(stream, "{json}")
+}
+
+/// Streams events to a subscribed client until it disconnects or falls behind.
+///
+/// Runs on the connection thread; publishers are never blocked by it.
+fn stream_events(mut stream: UnixStream, requested: &[String]) {
+    let topics = match resolve_topics(requested) {
+        Ok(topics) => topics,
+        Err(error) => {
+            let _ = write_json_line(&mut stream, &IpcResponse::error(error));
+            return;
+        }
+    };
+
+    // Subscribe before acknowledging so no event is missed in between
+    let mut receiver = event_sender().subscribe();
+    let _ = stream.set_write_timeout(Some(std::time::Duration::from_millis(
+        SUBSCRIBER_WRITE_TIMEOUT_MS,
+    )));
+
+    let ack = IpcResponse::success(serde_json::json!({ "subscribed": topics }));
+    if write_json_line(&mut stream, &ack).is_err() {
+        return;
+    }
+
+    tracing::debug!(?topics, "ipc client subscribed");
+
+    loop {
+        match receiver.blocking_recv() {
+            Ok(event) => {
+                if !topics.contains(&event.event.as_str()) {
+                    continue;
+                }
+                if let Err(e) = write_json_line(&mut stream, &event) {
+                    tracing::debug!(error = %e, "ipc subscriber disconnected");
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing::warn!(skipped, "ipc subscriber too slow, closing stream");
+                break;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
 /// Stops the IPC server.
 #[allow(dead_code)]
 pub fn stop_server() {
@@ -423,7 +875,7 @@ mod tests {
 
     #[test]
     fn test_ipc_query_serialization() {
-        let query = IpcQuery::Screens;
+        let query = IpcQuery::Screens { screen: None };
         let json = serde_json::to_string(&query).unwrap();
         assert_eq!(json, r#"{"type":"screens"}"#);
 
@@ -433,11 +885,624 @@ mod tests {
             focused_screen: false,
             focused_workspace: true,
             detailed: false,
+            sort: None,
         };
         let json = serde_json::to_string(&query).unwrap();
         assert!(json.contains(r#""type":"windows""#));
         assert!(json.contains(r#""screen":"main""#));
         assert!(json.contains(r#""focusedWorkspace":true"#));
+        assert!(!json.contains("sort"));
+    }
+
+    #[test]
+    fn test_ipc_query_screens_filter_serialization() {
+        let query = IpcQuery::Screens {
+            screen: Some("main".to_string()),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"screens","screen":"main"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(r#"{"type":"screens"}"#).unwrap();
+        assert!(matches!(parsed, IpcQuery::Screens { screen: None }));
+    }
+
+    #[test]
+    fn test_ipc_query_windows_sort_serialization() {
+        let query = IpcQuery::Windows {
+            screen: None,
+            workspace: None,
+            focused_screen: false,
+            focused_workspace: false,
+            detailed: false,
+            sort: Some("recent".to_string()),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert!(json.contains(r#""sort":"recent""#));
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::Windows { sort: Some(sort), .. } if sort == "recent"));
+
+        // Older clients don't send a sort key
+        let parsed: IpcQuery = serde_json::from_str(r#"{"type":"windows"}"#).unwrap();
+        assert!(matches!(parsed, IpcQuery::Windows { sort: None, .. }));
+    }
+
+    #[test]
+    fn test_ipc_query_resize_window_serialization() {
+        let query = IpcQuery::ResizeWindow {
+            dimension: "width".to_string(),
+            delta: -40,
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"resizeWindow","dimension":"width","delta":-40}"#
+        );
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::ResizeWindow { dimension, delta: -40 } if dimension == "width"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_resize_window_step_serialization() {
+        let query = IpcQuery::ResizeWindowStep {
+            dimension: "height".to_string(),
+            grow: false,
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"resizeWindowStep","dimension":"height","grow":false}"#
+        );
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::ResizeWindowStep { dimension, grow: false } if dimension == "height"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_set_split_ratio_serialization() {
+        let query = IpcQuery::SetSplitRatio { index: 1, ratio: 0.25 };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"setSplitRatio","index":1,"ratio":0.25}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::SetSplitRatio { index: 1, ratio } if (ratio - 0.25).abs() < f64::EPSILON
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_place_window_serialization() {
+        let query = IpcQuery::PlaceWindow {
+            placement: "center".to_string(),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"placeWindow","placement":"center"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::PlaceWindow { placement } if placement == "center"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_arrange_floating_serialization() {
+        let query = IpcQuery::ArrangeFloating {
+            arrangement: "cascade".to_string(),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"arrangeFloating","arrangement":"cascade"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::ArrangeFloating { arrangement } if arrangement == "cascade"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_focus_window_index_serialization() {
+        let query = IpcQuery::FocusWindowIndex { index: 2, wraps: true };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"focusWindowIndex","index":2,"wraps":true}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::FocusWindowIndex {
+            index: 2,
+            wraps: true
+        }));
+
+        let parsed: IpcQuery =
+            serde_json::from_str(r#"{"type":"focusWindowIndex","index":2}"#).unwrap();
+        assert!(matches!(parsed, IpcQuery::FocusWindowIndex { wraps: false, .. }));
+    }
+
+    #[test]
+    fn test_ipc_query_minimize_window_serialization() {
+        let query = IpcQuery::MinimizeWindow { minimized: false };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"minimizeWindow","minimized":false}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::MinimizeWindow { minimized: false }));
+    }
+
+    #[test]
+    fn test_ipc_query_send_window_serialization() {
+        let query = IpcQuery::SendWindow {
+            workspace: "coding".to_string(),
+            follow: true,
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"sendWindow","workspace":"coding","follow":true}"#
+        );
+
+        let parsed: IpcQuery =
+            serde_json::from_str(r#"{"type":"sendWindow","workspace":"coding"}"#).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::SendWindow { workspace, follow: false } if workspace == "coding"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_copy_layout_serialization() {
+        let query = IpcQuery::CopyLayout {
+            workspace: "coding".to_string(),
+            swap: true,
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"copyLayout","workspace":"coding","swap":true}"#);
+
+        let parsed: IpcQuery =
+            serde_json::from_str(r#"{"type":"copyLayout","workspace":"coding"}"#).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::CopyLayout { workspace, swap: false } if workspace == "coding"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_merge_workspace_serialization() {
+        let query = IpcQuery::MergeWorkspace {
+            target: "coding".to_string(),
+            follow: false,
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"mergeWorkspace","target":"coding","follow":false}"#
+        );
+
+        let parsed: IpcQuery =
+            serde_json::from_str(r#"{"type":"mergeWorkspace","target":"coding"}"#).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::MergeWorkspace { target, follow: false } if target == "coding"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_cycle_workspace_serialization() {
+        let query = IpcQuery::CycleWorkspace { direction: "next".to_string() };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"cycleWorkspace","direction":"next"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::CycleWorkspace { direction } if direction == "next"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_media_control_serialization() {
+        let query = IpcQuery::MediaControl {
+            command: "play-pause".to_string(),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"mediaControl","command":"play-pause"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::MediaControl { command } if command == "play-pause"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_focus_last_workspace_serialization() {
+        let json = serde_json::to_string(&IpcQuery::FocusLastWorkspace).unwrap();
+        assert_eq!(json, r#"{"type":"focusLastWorkspace"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::FocusLastWorkspace));
+    }
+
+    #[test]
+    fn test_ipc_query_create_workspace_serialization() {
+        let query = IpcQuery::CreateWorkspace { name: "scratch".to_string() };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"createWorkspace","name":"scratch"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::CreateWorkspace { name } if name == "scratch"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_rename_workspace_serialization() {
+        let query = IpcQuery::RenameWorkspace {
+            workspace: None,
+            name: "code".to_string(),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"renameWorkspace","name":"code"}"#);
+
+        let parsed: IpcQuery =
+            serde_json::from_str(r#"{"type":"renameWorkspace","workspace":"dev","name":"code"}"#)
+                .unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::RenameWorkspace { workspace: Some(ws), name } if ws == "dev" && name == "code"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_dry_run_layout_serialization() {
+        let query = IpcQuery::DryRunLayout {
+            layout: "dwindle".to_string(),
+            window_ids: vec![1, 2],
+            frame: [0.0, 0.0, 1920.0, 1080.0],
+        };
+        let json = serde_json::to_value(&query).unwrap();
+        assert_eq!(json["type"], "dryRunLayout");
+        assert_eq!(json["windowIds"], serde_json::json!([1, 2]));
+        assert_eq!(json["frame"], serde_json::json!([0.0, 0.0, 1920.0, 1080.0]));
+
+        let parsed: IpcQuery = serde_json::from_value(json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::DryRunLayout { layout, window_ids, .. }
+                if layout == "dwindle" && window_ids == [1, 2]
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_status_serialization() {
+        let json = serde_json::to_string(&IpcQuery::Status).unwrap();
+        assert_eq!(json, r#"{"type":"status"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::Status));
+    }
+
+    #[test]
+    fn test_ipc_query_claim_app_serialization() {
+        let json = serde_json::to_string(&IpcQuery::ClaimApp { claim: true }).unwrap();
+        assert_eq!(json, r#"{"type":"claimApp","claim":true}"#);
+
+        let parsed: IpcQuery =
+            serde_json::from_str(r#"{"type":"claimApp","claim":false}"#).unwrap();
+        assert!(matches!(parsed, IpcQuery::ClaimApp { claim: false }));
+    }
+
+    #[test]
+    fn test_ipc_query_restore_windows_serialization() {
+        let json = serde_json::to_string(&IpcQuery::RestoreWindows).unwrap();
+        assert_eq!(json, r#"{"type":"restoreWindows"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::RestoreWindows));
+    }
+
+    #[test]
+    fn test_ipc_query_set_gap_preset_serialization() {
+        let query = IpcQuery::SetGapPreset { preset: "dense".to_string() };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"setGapPreset","preset":"dense"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::SetGapPreset { preset } if preset == "dense"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_retile_serialization() {
+        let json = serde_json::to_string(&IpcQuery::Retile).unwrap();
+        assert_eq!(json, r#"{"type":"retile"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::Retile));
+    }
+
+    #[test]
+    fn test_ipc_query_capture_serialization() {
+        let query = IpcQuery::Capture { target: "window".to_string() };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"capture","target":"window"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::Capture { target } if target == "window"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_keep_awake_serialization() {
+        let query = IpcQuery::KeepAwake { duration: "90m".to_string() };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"keepAwake","duration":"90m"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::KeepAwake { duration } if duration == "90m"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_focus_mode_serialization() {
+        let query = IpcQuery::FocusMode { action: "on".to_string() };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"focusMode","action":"on"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::FocusMode { action } if action == "on"));
+    }
+
+    #[test]
+    fn test_ipc_query_bar_serialization() {
+        let query = IpcQuery::Bar { action: "toggle".to_string() };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"bar","action":"toggle"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::Bar { action } if action == "toggle"));
+    }
+
+    #[test]
+    fn test_ipc_query_audio_volume_serialization() {
+        let query = IpcQuery::AudioVolume {
+            action: "up".to_string(),
+            value: 5,
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"audioVolume","action":"up","value":5}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::AudioVolume { action, value: 5 } if action == "up"
+        ));
+
+        let parsed: IpcQuery =
+            serde_json::from_str(r#"{"type":"audioVolume","action":"set"}"#).unwrap();
+        assert!(matches!(parsed, IpcQuery::AudioVolume { value: 0, .. }));
+    }
+
+    #[test]
+    fn test_ipc_query_audio_devices_serialization() {
+        let json = serde_json::to_string(&IpcQuery::AudioDevices).unwrap();
+        assert_eq!(json, r#"{"type":"audioDevices"}"#);
+
+        let json = serde_json::to_string(&IpcQuery::AudioStatus).unwrap();
+        assert_eq!(json, r#"{"type":"audioStatus"}"#);
+
+        let query = IpcQuery::AudioSetDevice {
+            direction: "output".to_string(),
+            name: "airpods".to_string(),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"audioSetDevice","direction":"output","name":"airpods"}"#
+        );
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::AudioSetDevice { direction, name }
+                if direction == "output" && name == "airpods"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_audio_mute_serialization() {
+        let query = IpcQuery::AudioMute { action: "toggle".to_string() };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"audioMute","action":"toggle"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::AudioMute { action } if action == "toggle"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_focused_serialization() {
+        let json = serde_json::to_string(&IpcQuery::Focused).unwrap();
+        assert_eq!(json, r#"{"type":"focused"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::Focused));
+    }
+
+    #[test]
+    fn test_ipc_query_history_serialization() {
+        let json = serde_json::to_string(&IpcQuery::History { limit: None }).unwrap();
+        assert_eq!(json, r#"{"type":"history"}"#);
+
+        let json = serde_json::to_string(&IpcQuery::History { limit: Some(5) }).unwrap();
+        assert_eq!(json, r#"{"type":"history","limit":5}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::History { limit: Some(5) }));
+    }
+
+    #[test]
+    fn test_ipc_query_tree_serialization() {
+        let json = serde_json::to_string(&IpcQuery::Tree { workspace: None }).unwrap();
+        assert_eq!(json, r#"{"type":"tree"}"#);
+
+        let query = IpcQuery::Tree {
+            workspace: Some("coding".to_string()),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"tree","workspace":"coding"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            IpcQuery::Tree { workspace: Some(name) } if name == "coding"
+        ));
+    }
+
+    #[test]
+    fn test_ipc_query_wallpaper_current_serialization() {
+        let json = serde_json::to_string(&IpcQuery::WallpaperCurrent).unwrap();
+        assert_eq!(json, r#"{"type":"wallpaperCurrent"}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::WallpaperCurrent));
+    }
+
+    #[test]
+    fn test_ipc_query_subscribe_serialization() {
+        let query = IpcQuery::Subscribe {
+            topics: vec!["window-focused".to_string()],
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"subscribe","topics":["window-focused"]}"#);
+
+        let parsed: IpcQuery = serde_json::from_str(r#"{"type":"subscribe"}"#).unwrap();
+        assert!(matches!(parsed, IpcQuery::Subscribe { topics } if topics.is_empty()));
+    }
+
+    #[test]
+    fn test_ipc_query_batch_serialization() {
+        let query = IpcQuery::Batch {
+            queries: vec![IpcQuery::Ping, IpcQuery::Focused],
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"batch","queries":[{"type":"ping"},{"type":"focused"}]}"#
+        );
+
+        let parsed: IpcQuery = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, IpcQuery::Batch { queries } if queries.len() == 2));
+    }
+
+    #[test]
+    fn test_handle_batch_keeps_errors_in_place() {
+        let handler = |query: IpcQuery| match query {
+            IpcQuery::Ping => IpcResponse::success("pong"),
+            _ => IpcResponse::error("Unknown query"),
+        };
+        let queries = vec![
+            IpcQuery::Ping,
+            IpcQuery::Focused,
+            IpcQuery::Batch { queries: Vec::new() },
+            IpcQuery::Ping,
+        ];
+
+        let response = serde_json::to_value(handle_batch(queries, &handler)).unwrap();
+        assert_eq!(
+            response,
+            serde_json::json!({"data": [
+                {"data": "pong"},
+                {"error": "Unknown query"},
+                {"error": "Batches can't be nested"},
+                {"data": "pong"},
+            ]})
+        );
+    }
+
+    #[test]
+    fn test_handle_batch_rejects_oversized_batch() {
+        let queries = vec![IpcQuery::Ping; MAX_BATCH_SIZE + 1];
+        let response = handle_batch(queries, &|_| IpcResponse::success("pong"));
+        assert!(matches!(response, IpcResponse::Error { error } if error.contains("too large")));
+    }
+
+    #[test]
+    fn test_resolve_topics_defaults_to_all() {
+        assert_eq!(resolve_topics(&[]).unwrap(), topics::ALL.to_vec());
+    }
+
+    #[test]
+    fn test_resolve_topics_filters() {
+        let requested = vec!["layout-changed".to_string(), "window-focused".to_string()];
+        assert_eq!(resolve_topics(&requested).unwrap(), vec![
+            topics::LAYOUT_CHANGED,
+            topics::WINDOW_FOCUSED
+        ]);
+    }
+
+    #[test]
+    fn test_resolve_topics_rejects_unknown() {
+        let requested = vec!["window-focused".to_string(), "bogus".to_string()];
+        assert_eq!(resolve_topics(&requested).unwrap_err(), "Unknown topic: bogus");
+    }
+
+    #[test]
+    fn test_ipc_event_serialization() {
+        let event = IpcEvent {
+            event: topics::WORKSPACE_SWITCHED.to_string(),
+            data: serde_json::json!({"workspace": "code"}),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"event":"workspace-switched","data":{"workspace":"code"}}"#
+        );
+    }
+
+    #[test]
+    fn test_subscription_stream_delivers_events() {
+        let (server, client) = UnixStream::pair().unwrap();
+        let topics = vec![topics::WORKSPACE_SWITCHED.to_string()];
+        let handle = thread::spawn(move || stream_events(server, &topics));
+
+        let mut reader = BufReader::new(client);
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        assert!(line.contains("subscribed"));
+
+        // Filtered out, then delivered
+        publish_event(topics::LAYOUT_CHANGED, serde_json::json!({}));
+        publish_event(
+            topics::WORKSPACE_SWITCHED,
+            serde_json::json!({"workspace": "code"}),
+        );
+
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+        let event: IpcEvent = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(event.event, topics::WORKSPACE_SWITCHED);
+        assert_eq!(event.data["workspace"], "code");
+
+        // Dropping the client ends the stream on the next write
+        drop(reader);
+        publish_event(topics::WORKSPACE_SWITCHED, serde_json::json!({}));
+        handle.join().unwrap();
     }
 
     #[test]
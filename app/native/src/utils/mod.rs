@@ -1,8 +1,10 @@
 pub mod accessibility;
 pub mod command;
+pub mod duration;
 pub mod ipc;
 pub mod ipc_socket;
 pub mod objc;
 pub mod path;
 pub mod thread;
+pub mod time_of_day;
 pub mod window;
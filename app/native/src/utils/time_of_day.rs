@@ -0,0 +1,114 @@
+//! Local time-of-day helpers.
+//!
+//! Time windows are configured as 24-hour "HH:MM" strings, e.g.
+//! "06:00"–"18:00". Windows whose end is before their start wrap around
+//! midnight ("22:00"–"06:00").
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use core_foundation::date::CFDate;
+use core_foundation::timezone::CFTimeZone;
+
+/// Number of minutes in a day.
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Parses a 24-hour "HH:MM" string into minutes since midnight.
+///
+/// Returns `None` for malformed or out-of-range values.
+#[must_use]
+pub fn parse_time_of_day(value: &str) -> Option<u32> {
+    let (hours, minutes) = value.trim().split_once(':')?;
+    let hours: u32 = hours.parse().ok()?;
+    let minutes: u32 = minutes.parse().ok()?;
+
+    if hours >= 24 || minutes >= 60 {
+        return None;
+    }
+
+    Some(hours * 60 + minutes)
+}
+
+/// Converts a Unix timestamp and a UTC offset into minutes since local midnight.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn minute_of_day(unix_secs: i64, utc_offset_secs: i64) -> u32 {
+    (unix_secs + utc_offset_secs).div_euclid(60).rem_euclid(MINUTES_PER_DAY as i64) as u32
+}
+
+/// Returns the current local time as seconds since the Unix epoch.
+///
+/// Uses the system time zone, so DST changes are picked up on the next call.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub fn local_time_secs() -> i64 {
+    let unix_secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+    let offset_secs = CFTimeZone::system().seconds_from_gmt(CFDate::now()) as i64;
+
+    unix_secs + offset_secs
+}
+
+/// Returns the current local time as minutes since midnight.
+#[must_use]
+pub fn local_minute_of_day() -> u32 { minute_of_day(local_time_secs(), 0) }
+
+/// Returns whether `minute` falls in the window from `from` to `to`.
+///
+/// All values are minutes since midnight. The start is inclusive and the end
+/// exclusive. Equal start and end times cover the whole day.
+#[must_use]
+pub const fn window_contains(from: u32, to: u32, minute: u32) -> bool {
+    if from == to {
+        true
+    } else if from < to {
+        minute >= from && minute < to
+    } else {
+        // Wraps around midnight
+        minute >= from || minute < to
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(time: &str) -> u32 { parse_time_of_day(time).unwrap() }
+
+    #[test]
+    fn test_parse_time_of_day() {
+        assert_eq!(parse_time_of_day("00:00"), Some(0));
+        assert_eq!(parse_time_of_day("06:30"), Some(390));
+        assert_eq!(parse_time_of_day("23:59"), Some(1439));
+        assert_eq!(parse_time_of_day(" 7:05 "), Some(425));
+    }
+
+    #[test]
+    fn test_parse_time_of_day_invalid() {
+        assert_eq!(parse_time_of_day("24:00"), None);
+        assert_eq!(parse_time_of_day("12:60"), None);
+        assert_eq!(parse_time_of_day("noon"), None);
+        assert_eq!(parse_time_of_day("12"), None);
+        assert_eq!(parse_time_of_day(""), None);
+    }
+
+    #[test]
+    fn test_minute_of_day() {
+        assert_eq!(minute_of_day(0, 0), 0);
+        assert_eq!(minute_of_day(3_600, 0), 60);
+        // 01:00 UTC at UTC-2 is 23:00 of the previous day
+        assert_eq!(minute_of_day(3_600, -7_200), 23 * 60);
+        // 23:30 UTC at UTC+1 is 00:30
+        assert_eq!(minute_of_day(84_600, 3_600), 30);
+    }
+
+    #[test]
+    fn test_window_contains() {
+        assert!(window_contains(at("06:00"), at("18:00"), at("06:00")));
+        assert!(!window_contains(at("06:00"), at("18:00"), at("18:00")));
+
+        assert!(window_contains(at("22:00"), at("06:00"), at("23:59")));
+        assert!(window_contains(at("22:00"), at("06:00"), at("00:00")));
+        assert!(!window_contains(at("22:00"), at("06:00"), at("12:00")));
+
+        assert!(window_contains(at("08:00"), at("08:00"), at("03:00")));
+    }
+}
@@ -16,6 +16,7 @@ type ObjcId = *mut objc::runtime::Object;
 const NS_WINDOW_STYLE_MASK_NONACTIVATING_PANEL: u64 = 1 << 7;
 const NS_WINDOW_COLLECTION_BEHAVIOR_CAN_JOIN_ALL_SPACES: u64 = 1 << 0;
 const NS_WINDOW_COLLECTION_BEHAVIOR_STATIONARY: u64 = 1 << 4;
+const NS_WINDOW_COLLECTION_BEHAVIOR_FULL_SCREEN_AUXILIARY: u64 = 1 << 8;
 const NSTRACKING_MOUSE_ENTERED_AND_EXITED: u64 = 0x1;
 const NSTRACKING_MOUSE_MOVED: u64 = 0x2;
 const NSTRACKING_ACTIVE_ALWAYS: u64 = 0x80;
@@ -159,6 +160,29 @@ pub fn set_window_level(window: &WebviewWindow, level: i32) {
     }
 }
 
+/// Returns the collection behavior of a window shown on every space, and on
+/// fullscreen spaces too when `fullscreen_spaces` is set.
+const fn collection_behavior(fullscreen_spaces: bool) -> u64 {
+    let behaviors = NS_WINDOW_COLLECTION_BEHAVIOR_CAN_JOIN_ALL_SPACES
+        | NS_WINDOW_COLLECTION_BEHAVIOR_STATIONARY;
+    if fullscreen_spaces {
+        behaviors | NS_WINDOW_COLLECTION_BEHAVIOR_FULL_SCREEN_AUXILIARY
+    } else {
+        behaviors
+    }
+}
+
+/// Sets whether a window shown on every space also shows on fullscreen spaces.
+pub fn set_window_fullscreen_spaces(window: &WebviewWindow, fullscreen_spaces: bool) {
+    if let Ok(ns_win_ptr) = window.ns_window() {
+        unsafe {
+            let ns_win: ObjcId = ns_win_ptr as ObjcId;
+            let _: () =
+                msg_send![ns_win, setCollectionBehavior: collection_behavior(fullscreen_spaces)];
+        }
+    }
+}
+
 pub fn set_window_below_menu(window: &WebviewWindow) {
     // Menu level
     set_window_level(window, 8);
@@ -193,10 +217,7 @@ fn enforce_non_activating_click_behavior(ns_win: ObjcId) {
         }
 
         // Avoid FULL_SCREEN_AUXILIARY so fullscreen spaces stay unaffected by the bar.
-        let behaviors = NS_WINDOW_COLLECTION_BEHAVIOR_CAN_JOIN_ALL_SPACES
-            | NS_WINDOW_COLLECTION_BEHAVIOR_STATIONARY;
-
-        let _: () = msg_send![ns_win, setCollectionBehavior: behaviors];
+        let _: () = msg_send![ns_win, setCollectionBehavior: collection_behavior(false)];
         if msg_send![ns_win, respondsToSelector: sel!(setFloatingPanel:)] {
             let _: () = msg_send![ns_win, setFloatingPanel: true];
         }
@@ -309,6 +330,13 @@ mod tests {
         assert_eq!(NS_WINDOW_STYLE_MASK_NONACTIVATING_PANEL, 1 << 7);
         assert_eq!(NS_WINDOW_COLLECTION_BEHAVIOR_CAN_JOIN_ALL_SPACES, 1 << 0);
         assert_eq!(NS_WINDOW_COLLECTION_BEHAVIOR_STATIONARY, 1 << 4);
+        assert_eq!(NS_WINDOW_COLLECTION_BEHAVIOR_FULL_SCREEN_AUXILIARY, 1 << 8);
+    }
+
+    #[test]
+    fn collection_behavior_adds_fullscreen_auxiliary() {
+        assert_eq!(collection_behavior(false), (1 << 0) | (1 << 4));
+        assert_eq!(collection_behavior(true), (1 << 0) | (1 << 4) | (1 << 8));
     }
 
     #[test]
@@ -35,6 +35,9 @@ const createMockBattery = (overrides: Partial<BatteryInfo> = {}): BatteryInfo =>
   cycle_count: null,
   time_to_full: null,
   time_to_empty: null,
+  time_to_full_minutes: null,
+  time_to_empty_minutes: null,
+  is_charging: false,
   vendor: null,
   model: null,
   serial_number: null,
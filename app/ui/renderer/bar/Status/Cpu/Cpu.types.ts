@@ -1,4 +1,6 @@
 export type CPUInfo = {
   usage: number;
   temperature: number | null;
+  perCore: number[];
+  loadAvg: [number, number, number];
 };
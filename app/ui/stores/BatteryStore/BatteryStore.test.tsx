@@ -39,6 +39,9 @@ const createMockBatteryInfo = (overrides: Partial<BatteryInfo> = {}): BatteryInf
   cycle_count: 150,
   time_to_full: null,
   time_to_empty: 10800,
+  time_to_full_minutes: null,
+  time_to_empty_minutes: 180,
+  is_charging: false,
   vendor: 'Apple',
   model: 'MacBook Pro Battery',
   serial_number: 'ABC123',
@@ -38,6 +38,12 @@ export type BatteryInfo = {
   time_to_full: number | null;
   /** Time until empty in seconds (if discharging) */
   time_to_empty: number | null;
+  /** Estimated minutes until fully charged (null while the OS is still estimating) */
+  time_to_full_minutes: number | null;
+  /** Estimated minutes until empty (null while the OS is still estimating) */
+  time_to_empty_minutes: number | null;
+  /** Whether the battery is currently charging */
+  is_charging: boolean;
   /** Battery vendor (if available) */
   vendor: string | null;
   /** Battery model (if available) */
@@ -21,7 +21,7 @@ console.error = (...args: unknown[]) => {
 const defaultInvokeMocks: Record<string, unknown> = {
   get_current_media_info: {},
   get_battery_info: { percentage: 100, state: 'Full' },
-  get_cpu_info: { usage: 25, temperature: 50 },
+  get_cpu_info: { usage: 25, temperature: 50, perCore: [20, 30], loadAvg: [1, 1, 1] },
   is_system_awake: false,
   get_weather_config: {},
   get_tiling_workspaces: [
@@ -96,4 +96,8 @@ export const TilingEvents = {
   WINDOW_FOCUS_CHANGED: 'stache://tiling/window-focus-changed',
   /** Emitted when a window's title changes. Payload: { windowId: number, title: string } */
   WINDOW_TITLE_CHANGED: 'stache://tiling/window-title-changed',
+  /** Emitted when the tabs of a tabbed workspace change. Payload: { workspace: string, tabs: { windowId: number, title: string, appName: string, isFocused: boolean }[] } */
+  TABS_CHANGED: 'stache://tiling/tabs-changed',
+  /** Emitted when the accessibility permission is revoked or granted again; tiling is paused without it. Payload: { granted: boolean } */
+  ACCESSIBILITY_CHANGED: 'stache://tiling/accessibility-changed',
 } as const;